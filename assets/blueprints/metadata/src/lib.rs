@@ -80,5 +80,10 @@ mod metadata {
             let global: Global<MetadataTest> = Runtime::global_address().into();
             global.get_metadata(key).unwrap().unwrap()
         }
+
+        pub fn get_standard(&self) -> (Option<String>, Option<Vec<String>>) {
+            let metadata = Metadata::attached(Runtime::global_address().into());
+            (metadata.get_name().unwrap(), metadata.get_tags().unwrap())
+        }
     }
 }
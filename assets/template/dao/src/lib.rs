@@ -0,0 +1,160 @@
+use scrypto::prelude::*;
+
+#[derive(ScryptoSbor, Clone, Debug, PartialEq, Eq)]
+pub struct Proposal {
+    pub description: String,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub closed: bool,
+}
+
+#[derive(ScryptoSbor, NonFungibleData)]
+pub struct Member {
+    pub name: String,
+}
+
+#[blueprint]
+mod dao {
+    enable_method_auth! {
+        methods {
+            add_member => restrict_to: [OWNER];
+            close_proposal => restrict_to: [OWNER];
+            create_proposal => PUBLIC;
+            vote => PUBLIC;
+            proposal => PUBLIC;
+        }
+    }
+
+    struct Dao {
+        // The badge which is allowed to mint member badges, kept by the component itself
+        minter_badge: FungibleVault,
+        // The badges of the members, each of whom has one vote on every proposal
+        member_badge: ResourceManager,
+        proposals: KeyValueStore<u64, Proposal>,
+        // The members who have voted on each proposal
+        votes: KeyValueStore<(u64, NonFungibleLocalId), bool>,
+        next_proposal_id: u64,
+        next_member_id: u64,
+    }
+
+    impl Dao {
+        // Creates a DAO with no members. The returned owner badge is required to add members and
+        // to close proposals.
+        pub fn instantiate_dao(name: String) -> (Global<Dao>, FungibleBucket) {
+            let owner_badge = ResourceBuilder::new_fungible(OwnerRole::None)
+                .divisibility(DIVISIBILITY_NONE)
+                .metadata(metadata! {
+                    init {
+                        "name" => format!("{} Owner Badge", name), locked;
+                    }
+                })
+                .mint_initial_supply(1);
+
+            let minter_badge = ResourceBuilder::new_fungible(OwnerRole::None)
+                .divisibility(DIVISIBILITY_NONE)
+                .mint_initial_supply(1);
+
+            let member_badge = ResourceBuilder::new_integer_non_fungible::<Member>(OwnerRole::None)
+                .metadata(metadata! {
+                    init {
+                        "name" => format!("{} Member Badge", name), locked;
+                    }
+                })
+                .mint_roles(mint_roles! {
+                    minter => rule!(require(minter_badge.resource_address()));
+                    minter_updater => rule!(deny_all);
+                })
+                .create_with_no_initial_supply();
+
+            let component = Self {
+                minter_badge: FungibleVault::with_bucket(minter_badge),
+                member_badge,
+                proposals: KeyValueStore::new(),
+                votes: KeyValueStore::new(),
+                next_proposal_id: 0,
+                next_member_id: 1,
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::Fixed(rule!(require(
+                owner_badge.resource_address()
+            ))))
+            .globalize();
+
+            (component, owner_badge)
+        }
+
+        // Mints the badge of a new member
+        pub fn add_member(&mut self, name: String) -> Bucket {
+            let local_id = NonFungibleLocalId::integer(self.next_member_id);
+            self.next_member_id += 1;
+
+            self.minter_badge.authorize_with_amount(1, || {
+                self.member_badge
+                    .mint_non_fungible(&local_id, Member { name })
+            })
+        }
+
+        // Creates a proposal for the members to vote on, and returns its id
+        pub fn create_proposal(&mut self, member: Proof, description: String) -> u64 {
+            member.check(self.member_badge.address());
+
+            let proposal_id = self.next_proposal_id;
+            self.next_proposal_id += 1;
+            self.proposals.insert(
+                proposal_id,
+                Proposal {
+                    description,
+                    votes_for: 0,
+                    votes_against: 0,
+                    closed: false,
+                },
+            );
+
+            proposal_id
+        }
+
+        // Votes on a proposal, which each member can only do once
+        pub fn vote(&mut self, member: Proof, proposal_id: u64, in_favor: bool) {
+            let member_id = member
+                .check(self.member_badge.address())
+                .as_non_fungible()
+                .non_fungible_local_id();
+
+            let mut proposal = self
+                .proposals
+                .get_mut(&proposal_id)
+                .expect("The proposal does not exist");
+            assert!(!proposal.closed, "The proposal is closed");
+            assert!(
+                self.votes.get(&(proposal_id, member_id.clone())).is_none(),
+                "The member has already voted"
+            );
+
+            if in_favor {
+                proposal.votes_for += 1;
+            } else {
+                proposal.votes_against += 1;
+            }
+            self.votes.insert((proposal_id, member_id), in_favor);
+        }
+
+        // Closes a proposal, and returns whether it passed
+        pub fn close_proposal(&mut self, proposal_id: u64) -> bool {
+            let mut proposal = self
+                .proposals
+                .get_mut(&proposal_id)
+                .expect("The proposal does not exist");
+            assert!(!proposal.closed, "The proposal is already closed");
+            proposal.closed = true;
+
+            proposal.votes_for > proposal.votes_against
+        }
+
+        pub fn proposal(&self, proposal_id: u64) -> Proposal {
+            self.proposals
+                .get(&proposal_id)
+                .expect("The proposal does not exist")
+                .clone()
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use radix_engine_interface::prelude::*;
+use scrypto::this_package;
+use scrypto_test::prelude::*;
+use scrypto_unit::*;
+
+use ${wasm_name}::Proposal;
+
+struct Setup {
+    test_runner: DefaultTestRunner,
+    public_key: Secp256k1PublicKey,
+    account: ComponentAddress,
+    component: ComponentAddress,
+    owner_badge: ResourceAddress,
+    member_badge: ResourceAddress,
+}
+
+fn setup() -> Setup {
+    // Setup the environment
+    let mut test_runner = TestRunnerBuilder::new().build();
+
+    // Create an account
+    let (public_key, _private_key, account) = test_runner.new_allocated_account();
+
+    // Publish package
+    let package_address = test_runner.compile_and_publish(this_package!());
+
+    // Instantiate a DAO, and add the account as its first member
+    let manifest = ManifestBuilder::new()
+        .call_function(
+            package_address,
+            "Dao",
+            "instantiate_dao",
+            manifest_args!("My DAO".to_string()),
+        )
+        .deposit_batch(account)
+        .build();
+    let receipt = test_runner.execute_manifest_ignoring_fee(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    let result = receipt.expect_commit_success();
+    let component = result.new_component_addresses()[0];
+    // The owner badge, the minter badge and the member badge are created in this order
+    let owner_badge = result.new_resource_addresses()[0];
+    let member_badge = result.new_resource_addresses()[2];
+
+    let manifest = ManifestBuilder::new()
+        .create_proof_from_account_of_amount(account, owner_badge, dec!("1"))
+        .call_method(component, "add_member", manifest_args!("Alice".to_string()))
+        .deposit_batch(account)
+        .build();
+    let receipt = test_runner.execute_manifest_ignoring_fee(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    receipt.expect_commit_success();
+
+    Setup {
+        test_runner,
+        public_key,
+        account,
+        component,
+        owner_badge,
+        member_badge,
+    }
+}
+
+fn create_proposal(setup: &mut Setup) -> u64 {
+    let manifest = ManifestBuilder::new()
+        .create_proof_from_account_of_non_fungibles(
+            setup.account,
+            setup.member_badge,
+            [NonFungibleLocalId::integer(1)],
+        )
+        .pop_from_auth_zone("member")
+        .call_method_with_name_lookup(setup.component, "create_proposal", |lookup| {
+            (lookup.proof("member"), "Fund the treasury".to_string())
+        })
+        .build();
+    let receipt = setup.test_runner.execute_manifest_ignoring_fee(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&setup.public_key)],
+    );
+    receipt.expect_commit_success().output(3)
+}
+
+fn vote(setup: &mut Setup, proposal_id: u64) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .create_proof_from_account_of_non_fungibles(
+            setup.account,
+            setup.member_badge,
+            [NonFungibleLocalId::integer(1)],
+        )
+        .pop_from_auth_zone("member")
+        .call_method_with_name_lookup(setup.component, "vote", |lookup| {
+            (lookup.proof("member"), proposal_id, true)
+        })
+        .build();
+    setup.test_runner.execute_manifest_ignoring_fee(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&setup.public_key)],
+    )
+}
+
+#[test]
+fn test_vote_and_close_proposal() {
+    let mut setup = setup();
+    let proposal_id = create_proposal(&mut setup);
+
+    // Test the `vote` method.
+    vote(&mut setup, proposal_id).expect_commit_success();
+
+    // Test the `close_proposal` method.
+    let manifest = ManifestBuilder::new()
+        .create_proof_from_account_of_amount(setup.account, setup.owner_badge, dec!("1"))
+        .call_method(setup.component, "close_proposal", manifest_args!(proposal_id))
+        .call_method(setup.component, "proposal", manifest_args!(proposal_id))
+        .build();
+    let receipt = setup.test_runner.execute_manifest_ignoring_fee(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&setup.public_key)],
+    );
+    println!("{:?}\n", receipt);
+    let result = receipt.expect_commit_success();
+
+    let passed: bool = result.output(2);
+    let proposal: Proposal = result.output(3);
+    assert!(passed);
+    assert_eq!(
+        proposal,
+        Proposal {
+            description: "Fund the treasury".to_string(),
+            votes_for: 1,
+            votes_against: 0,
+            closed: true,
+        }
+    );
+}
+
+#[test]
+fn test_voting_twice_fails() {
+    let mut setup = setup();
+    let proposal_id = create_proposal(&mut setup);
+    vote(&mut setup, proposal_id).expect_commit_success();
+
+    vote(&mut setup, proposal_id).expect_commit_failure();
+}
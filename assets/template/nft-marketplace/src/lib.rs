@@ -0,0 +1,96 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod nft_marketplace {
+    enable_method_auth! {
+        methods {
+            list => restrict_to: [OWNER];
+            withdraw_earnings => restrict_to: [OWNER];
+            buy => PUBLIC;
+            price => PUBLIC;
+        }
+    }
+
+    struct NftMarketplace {
+        // The NFTs for sale, in one vault per resource
+        nfts: KeyValueStore<ResourceAddress, NonFungibleVault>,
+        // The price of each NFT for sale
+        prices: KeyValueStore<NonFungibleGlobalId, Decimal>,
+        // The payments received for the NFTs sold, which the owner can withdraw
+        earnings: Vault,
+    }
+
+    impl NftMarketplace {
+        // Creates a marketplace where NFTs are sold for the given resource, e.g. XRD.
+        // The returned owner badge is required to list NFTs and to withdraw the earnings.
+        pub fn instantiate_nft_marketplace(
+            payment_resource: ResourceAddress,
+        ) -> (Global<NftMarketplace>, FungibleBucket) {
+            let owner_badge = ResourceBuilder::new_fungible(OwnerRole::None)
+                .divisibility(DIVISIBILITY_NONE)
+                .metadata(metadata! {
+                    init {
+                        "name" => "NFT Marketplace Owner Badge", locked;
+                    }
+                })
+                .mint_initial_supply(1);
+
+            let component = Self {
+                nfts: KeyValueStore::new(),
+                prices: KeyValueStore::new(),
+                earnings: Vault::new(payment_resource),
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::Fixed(rule!(require(
+                owner_badge.resource_address()
+            ))))
+            .globalize();
+
+            (component, owner_badge)
+        }
+
+        // Puts the given NFTs up for sale, all at the same price
+        pub fn list(&mut self, nfts: NonFungibleBucket, price: Decimal) {
+            assert!(price.is_positive(), "The price must be positive");
+
+            let resource_address = nfts.resource_address();
+            for local_id in nfts.non_fungible_local_ids() {
+                self.prices
+                    .insert(NonFungibleGlobalId::new(resource_address, local_id), price);
+            }
+
+            if self.nfts.get(&resource_address).is_none() {
+                self.nfts
+                    .insert(resource_address, NonFungibleVault::new(resource_address));
+            }
+            self.nfts.get_mut(&resource_address).unwrap().put(nfts);
+        }
+
+        // Buys an NFT, returning it together with the change of the payment
+        pub fn buy(
+            &mut self,
+            nft: NonFungibleGlobalId,
+            mut payment: Bucket,
+        ) -> (NonFungibleBucket, Bucket) {
+            let price = self.prices.remove(&nft).expect("The NFT is not for sale");
+            self.earnings.put(payment.take(price));
+
+            let bought = self
+                .nfts
+                .get_mut(&nft.resource_address())
+                .unwrap()
+                .take_non_fungible(nft.local_id());
+
+            (bought, payment)
+        }
+
+        // Returns the price of an NFT, if it is for sale
+        pub fn price(&self, nft: NonFungibleGlobalId) -> Option<Decimal> {
+            self.prices.get(&nft).map(|price| *price)
+        }
+
+        pub fn withdraw_earnings(&mut self) -> Bucket {
+            self.earnings.take_all()
+        }
+    }
+}
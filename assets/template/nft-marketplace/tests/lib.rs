@@ -0,0 +1,177 @@
+use radix_engine_interface::prelude::*;
+use scrypto::this_package;
+use scrypto_test::prelude::*;
+use scrypto_unit::*;
+
+struct Setup {
+    test_runner: DefaultTestRunner,
+    seller_key: Secp256k1PublicKey,
+    seller: ComponentAddress,
+    buyer_key: Secp256k1PublicKey,
+    buyer: ComponentAddress,
+    component: ComponentAddress,
+    owner_badge: ResourceAddress,
+    nft: ResourceAddress,
+}
+
+fn setup() -> Setup {
+    // Setup the environment
+    let mut test_runner = TestRunnerBuilder::new().build();
+
+    // Create the accounts of the seller and the buyer
+    let (seller_key, _, seller) = test_runner.new_allocated_account();
+    let (buyer_key, _, buyer) = test_runner.new_allocated_account();
+
+    // Create NFTs #1#, #2# and #3#, owned by the seller
+    let nft = test_runner.create_non_fungible_resource(seller);
+
+    // Publish package
+    let package_address = test_runner.compile_and_publish(this_package!());
+
+    // Instantiate a marketplace selling NFTs for XRD
+    let manifest = ManifestBuilder::new()
+        .call_function(
+            package_address,
+            "NftMarketplace",
+            "instantiate_nft_marketplace",
+            manifest_args!(XRD),
+        )
+        .deposit_batch(seller)
+        .build();
+    let receipt = test_runner.execute_manifest_ignoring_fee(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&seller_key)],
+    );
+    let result = receipt.expect_commit_success();
+    let component = result.new_component_addresses()[0];
+    let owner_badge = result.new_resource_addresses()[0];
+
+    Setup {
+        test_runner,
+        seller_key,
+        seller,
+        buyer_key,
+        buyer,
+        component,
+        owner_badge,
+        nft,
+    }
+}
+
+fn list_nft(setup: &mut Setup, local_id: NonFungibleLocalId, price: Decimal) {
+    let manifest = ManifestBuilder::new()
+        .create_proof_from_account_of_amount(setup.seller, setup.owner_badge, dec!("1"))
+        .withdraw_non_fungibles_from_account(setup.seller, setup.nft, [local_id])
+        .take_all_from_worktop(setup.nft, "nfts")
+        .call_method_with_name_lookup(setup.component, "list", |lookup| {
+            (lookup.bucket("nfts"), price)
+        })
+        .build();
+    let receipt = setup.test_runner.execute_manifest_ignoring_fee(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&setup.seller_key)],
+    );
+    println!("{:?}\n", receipt);
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn test_buy_listed_nft() {
+    let mut setup = setup();
+    let local_id = NonFungibleLocalId::integer(1);
+    list_nft(&mut setup, local_id.clone(), dec!("100"));
+    let buyer_balance = setup.test_runner.get_component_balance(setup.buyer, XRD);
+
+    // Test the `buy` method, paying more than the price.
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(setup.buyer, XRD, dec!("150"))
+        .take_all_from_worktop(XRD, "payment")
+        .call_method_with_name_lookup(setup.component, "buy", |lookup| {
+            (
+                NonFungibleGlobalId::new(setup.nft, local_id.clone()),
+                lookup.bucket("payment"),
+            )
+        })
+        .deposit_batch(setup.buyer)
+        .build();
+    let receipt = setup.test_runner.execute_manifest_ignoring_fee(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&setup.buyer_key)],
+    );
+    println!("{:?}\n", receipt);
+    receipt.expect_commit_success();
+
+    assert_eq!(
+        setup
+            .test_runner
+            .get_component_balance(setup.buyer, setup.nft),
+        dec!("1")
+    );
+    assert_eq!(
+        setup.test_runner.get_component_balance(setup.buyer, XRD),
+        buyer_balance - dec!("100")
+    );
+
+    // Test the `withdraw_earnings` method.
+    let seller_balance = setup.test_runner.get_component_balance(setup.seller, XRD);
+    let manifest = ManifestBuilder::new()
+        .create_proof_from_account_of_amount(setup.seller, setup.owner_badge, dec!("1"))
+        .call_method(setup.component, "withdraw_earnings", manifest_args!())
+        .deposit_batch(setup.seller)
+        .build();
+    let receipt = setup.test_runner.execute_manifest_ignoring_fee(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&setup.seller_key)],
+    );
+    receipt.expect_commit_success();
+
+    assert_eq!(
+        setup.test_runner.get_component_balance(setup.seller, XRD),
+        seller_balance + dec!("100")
+    );
+}
+
+#[test]
+fn test_buy_unlisted_nft_fails() {
+    let mut setup = setup();
+    list_nft(&mut setup, NonFungibleLocalId::integer(1), dec!("100"));
+
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(setup.buyer, XRD, dec!("100"))
+        .take_all_from_worktop(XRD, "payment")
+        .call_method_with_name_lookup(setup.component, "buy", |lookup| {
+            (
+                NonFungibleGlobalId::new(setup.nft, NonFungibleLocalId::integer(2)),
+                lookup.bucket("payment"),
+            )
+        })
+        .deposit_batch(setup.buyer)
+        .build();
+    let receipt = setup.test_runner.execute_manifest_ignoring_fee(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&setup.buyer_key)],
+    );
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_list_without_owner_badge_fails() {
+    let mut setup = setup();
+
+    let manifest = ManifestBuilder::new()
+        .withdraw_non_fungibles_from_account(
+            setup.seller,
+            setup.nft,
+            [NonFungibleLocalId::integer(1)],
+        )
+        .take_all_from_worktop(setup.nft, "nfts")
+        .call_method_with_name_lookup(setup.component, "list", |lookup| {
+            (lookup.bucket("nfts"), dec!("100"))
+        })
+        .build();
+    let receipt = setup.test_runner.execute_manifest_ignoring_fee(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&setup.seller_key)],
+    );
+    receipt.expect_commit_failure();
+}
@@ -0,0 +1,93 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod token {
+    enable_method_auth! {
+        methods {
+            mint => restrict_to: [OWNER];
+            burn => PUBLIC;
+            total_supply => PUBLIC;
+        }
+    }
+
+    struct Token {
+        // The badge which is allowed to mint and burn the token, kept by the component itself
+        minter_badge: FungibleVault,
+        // The token managed by the component
+        token: ResourceManager,
+    }
+
+    impl Token {
+        // Creates a new token with an initial supply, and a component to mint and burn it.
+        // The returned owner badge is required to mint more of the token.
+        pub fn instantiate_token(
+            name: String,
+            symbol: String,
+            initial_supply: Decimal,
+        ) -> (Global<Token>, FungibleBucket, FungibleBucket) {
+            let owner_badge = ResourceBuilder::new_fungible(OwnerRole::None)
+                .divisibility(DIVISIBILITY_NONE)
+                .metadata(metadata! {
+                    init {
+                        "name" => format!("{} Owner Badge", name), locked;
+                    }
+                })
+                .mint_initial_supply(1);
+
+            let minter_badge = ResourceBuilder::new_fungible(OwnerRole::None)
+                .divisibility(DIVISIBILITY_NONE)
+                .mint_initial_supply(1);
+
+            // Only the holder of the minter badge may mint and burn the token
+            let initial_tokens = ResourceBuilder::new_fungible(OwnerRole::None)
+                .divisibility(DIVISIBILITY_MAXIMUM)
+                .metadata(metadata! {
+                    init {
+                        "name" => name, locked;
+                        "symbol" => symbol, locked;
+                    }
+                })
+                .mint_roles(mint_roles! {
+                    minter => rule!(require(minter_badge.resource_address()));
+                    minter_updater => rule!(deny_all);
+                })
+                .burn_roles(burn_roles! {
+                    burner => rule!(require(minter_badge.resource_address()));
+                    burner_updater => rule!(deny_all);
+                })
+                .mint_initial_supply(initial_supply);
+
+            let component = Self {
+                minter_badge: FungibleVault::with_bucket(minter_badge),
+                token: initial_tokens.resource_manager(),
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::Fixed(rule!(require(
+                owner_badge.resource_address()
+            ))))
+            .globalize();
+
+            (component, owner_badge, initial_tokens)
+        }
+
+        // Mints new tokens, which only the owner of the component is allowed to do
+        pub fn mint(&mut self, amount: Decimal) -> Bucket {
+            self.minter_badge
+                .authorize_with_amount(1, || self.token.mint(amount))
+        }
+
+        // Burns the given tokens, which anyone is allowed to do
+        pub fn burn(&mut self, tokens: Bucket) {
+            assert_eq!(
+                tokens.resource_address(),
+                self.token.address(),
+                "Only the token of this component can be burnt"
+            );
+            self.minter_badge.authorize_with_amount(1, || tokens.burn());
+        }
+
+        pub fn total_supply(&self) -> Decimal {
+            self.token.total_supply().unwrap()
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use radix_engine_interface::prelude::*;
+use scrypto::this_package;
+use scrypto_test::prelude::*;
+use scrypto_unit::*;
+
+struct Setup {
+    test_runner: DefaultTestRunner,
+    public_key: Secp256k1PublicKey,
+    account: ComponentAddress,
+    component: ComponentAddress,
+    owner_badge: ResourceAddress,
+    token: ResourceAddress,
+}
+
+fn setup() -> Setup {
+    // Setup the environment
+    let mut test_runner = TestRunnerBuilder::new().build();
+
+    // Create an account
+    let (public_key, _private_key, account) = test_runner.new_allocated_account();
+
+    // Publish package
+    let package_address = test_runner.compile_and_publish(this_package!());
+
+    // Instantiate a token with an initial supply of 1000
+    let manifest = ManifestBuilder::new()
+        .call_function(
+            package_address,
+            "Token",
+            "instantiate_token",
+            manifest_args!("My Token".to_string(), "MTK".to_string(), dec!("1000")),
+        )
+        .deposit_batch(account)
+        .build();
+    let receipt = test_runner.execute_manifest_ignoring_fee(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    let result = receipt.expect_commit_success();
+    let component = result.new_component_addresses()[0];
+    // The owner badge, the minter badge and the token are created in this order
+    let owner_badge = result.new_resource_addresses()[0];
+    let token = result.new_resource_addresses()[2];
+
+    Setup {
+        test_runner,
+        public_key,
+        account,
+        component,
+        owner_badge,
+        token,
+    }
+}
+
+#[test]
+fn test_instantiate_token() {
+    let Setup {
+        mut test_runner,
+        account,
+        token,
+        ..
+    } = setup();
+
+    assert_eq!(
+        test_runner.get_component_balance(account, token),
+        dec!("1000")
+    );
+}
+
+#[test]
+fn test_mint_with_owner_badge() {
+    let Setup {
+        mut test_runner,
+        public_key,
+        account,
+        component,
+        owner_badge,
+        token,
+    } = setup();
+
+    // Test the `mint` method.
+    let manifest = ManifestBuilder::new()
+        .create_proof_from_account_of_amount(account, owner_badge, dec!("1"))
+        .call_method(component, "mint", manifest_args!(dec!("500")))
+        .deposit_batch(account)
+        .build();
+    let receipt = test_runner.execute_manifest_ignoring_fee(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    println!("{:?}\n", receipt);
+    receipt.expect_commit_success();
+
+    assert_eq!(
+        test_runner.get_component_balance(account, token),
+        dec!("1500")
+    );
+}
+
+#[test]
+fn test_mint_without_owner_badge_fails() {
+    let Setup {
+        mut test_runner,
+        public_key,
+        account,
+        component,
+        ..
+    } = setup();
+
+    let manifest = ManifestBuilder::new()
+        .call_method(component, "mint", manifest_args!(dec!("500")))
+        .deposit_batch(account)
+        .build();
+    let receipt = test_runner.execute_manifest_ignoring_fee(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_burn() {
+    let Setup {
+        mut test_runner,
+        public_key,
+        account,
+        component,
+        token,
+        ..
+    } = setup();
+
+    // Test the `burn` method.
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account, token, dec!("400"))
+        .take_all_from_worktop(token, "tokens")
+        .call_method_with_name_lookup(component, "burn", |lookup| (lookup.bucket("tokens"),))
+        .call_method(component, "total_supply", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest_ignoring_fee(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    let total_supply: Decimal = receipt.expect_commit_success().output(4);
+
+    assert_eq!(total_supply, dec!("600"));
+    assert_eq!(
+        test_runner.get_component_balance(account, token),
+        dec!("600")
+    );
+}
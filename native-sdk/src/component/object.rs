@@ -3,10 +3,11 @@ use crate::modules::role_assignment::RoleAssignment;
 use radix_engine_common::prelude::ScryptoEncode;
 use radix_engine_common::types::GlobalAddress;
 use radix_engine_interface::api::node_modules::metadata::{
-    MetadataSetInput, MetadataVal, METADATA_SET_IDENT,
+    MetadataConversionError, MetadataGetInput, MetadataGetOutput, MetadataSetInput,
+    MetadataStandard, MetadataVal, UncheckedUrl, METADATA_GET_IDENT, METADATA_SET_IDENT,
 };
 use radix_engine_interface::api::{AttachedModuleId, ClientApi, FieldIndex, ModuleId};
-use radix_engine_interface::data::scrypto::{scrypto_encode, ScryptoDecode};
+use radix_engine_interface::data::scrypto::{scrypto_decode, scrypto_encode, ScryptoDecode};
 use radix_engine_interface::prelude::node_modules::ModuleConfig;
 use radix_engine_interface::prelude::GlobalAddressReservation;
 use radix_engine_interface::prelude::OwnerRole;
@@ -48,6 +49,89 @@ impl BorrowedObject {
 
         Ok(())
     }
+
+    /// Reads a metadata entry, converted to the requested type.
+    ///
+    /// The outer error is the one of the underlying call, the inner one a type mismatch.
+    pub fn get_metadata<Y, E, S, V>(
+        &self,
+        key: S,
+        api: &mut Y,
+    ) -> Result<Result<Option<V>, MetadataConversionError>, E>
+    where
+        Y: ClientApi<E>,
+        S: AsRef<str>,
+        V: MetadataVal,
+        E: Debug + ScryptoDecode,
+    {
+        let rtn = api.call_module_method(
+            &self.0,
+            AttachedModuleId::Metadata,
+            METADATA_GET_IDENT,
+            scrypto_encode(&MetadataGetInput {
+                key: key.as_ref().to_owned(),
+            })
+            .unwrap(),
+        )?;
+        let value: MetadataGetOutput = scrypto_decode(&rtn).unwrap();
+
+        Ok(value.map(V::from_metadata_value).transpose())
+    }
+
+    pub fn get_metadata_name<Y, E>(
+        &self,
+        api: &mut Y,
+    ) -> Result<Result<Option<String>, MetadataConversionError>, E>
+    where
+        Y: ClientApi<E>,
+        E: Debug + ScryptoDecode,
+    {
+        self.get_metadata(MetadataStandard::Name.key(), api)
+    }
+
+    pub fn get_metadata_symbol<Y, E>(
+        &self,
+        api: &mut Y,
+    ) -> Result<Result<Option<String>, MetadataConversionError>, E>
+    where
+        Y: ClientApi<E>,
+        E: Debug + ScryptoDecode,
+    {
+        self.get_metadata(MetadataStandard::Symbol.key(), api)
+    }
+
+    pub fn get_metadata_icon_url<Y, E>(
+        &self,
+        api: &mut Y,
+    ) -> Result<Result<Option<UncheckedUrl>, MetadataConversionError>, E>
+    where
+        Y: ClientApi<E>,
+        E: Debug + ScryptoDecode,
+    {
+        self.get_metadata(MetadataStandard::IconUrl.key(), api)
+    }
+
+    pub fn get_metadata_tags<Y, E>(
+        &self,
+        api: &mut Y,
+    ) -> Result<Result<Option<Vec<String>>, MetadataConversionError>, E>
+    where
+        Y: ClientApi<E>,
+        E: Debug + ScryptoDecode,
+    {
+        self.get_metadata(MetadataStandard::Tags.key(), api)
+    }
+
+    pub fn get_metadata_dapp_definitions<Y, E>(
+        &self,
+        api: &mut Y,
+    ) -> Result<Result<Option<Vec<GlobalAddress>>, MetadataConversionError>, E>
+    where
+        Y: ClientApi<E>,
+        E: Debug + ScryptoDecode,
+    {
+        self.get_metadata(MetadataStandard::DappDefinitions.key(), api)
+    }
 }
 
 pub fn globalize_object<Y, E>(
@@ -188,4 +188,26 @@ pub trait RoleAssignmentObject {
 
         Ok(())
     }
+
+    fn get_owner_role<Y: ClientApi<E>, E: Debug + ScryptoDecode>(
+        &self,
+        api: &mut Y,
+    ) -> Result<OwnerRoleEntry, E> {
+        let (node_id, module_id) = self.self_id();
+        let rtn = match module_id {
+            None => api.call_method(
+                node_id,
+                ROLE_ASSIGNMENT_GET_OWNER_ROLE_IDENT,
+                scrypto_encode(&RoleAssignmentGetOwnerRoleInput {}).unwrap(),
+            )?,
+            Some(module_id) => api.call_module_method(
+                node_id,
+                module_id,
+                ROLE_ASSIGNMENT_GET_OWNER_ROLE_IDENT,
+                scrypto_encode(&RoleAssignmentGetOwnerRoleInput {}).unwrap(),
+            )?,
+        };
+
+        Ok(scrypto_decode(&rtn).unwrap())
+    }
 }
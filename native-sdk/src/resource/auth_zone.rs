@@ -79,6 +79,14 @@ pub trait NativeAuthZone {
     ) -> Result<(), E>
     where
         Y: ClientApi<E>;
+
+    fn push_composite<Y, E: Debug + ScryptoCategorize + ScryptoDecode>(
+        &self,
+        proofs: Vec<Proof>,
+        api: &mut Y,
+    ) -> Result<(), E>
+    where
+        Y: ClientApi<E>;
 }
 
 impl NativeAuthZone for AuthZoneRef {
@@ -237,4 +245,21 @@ impl NativeAuthZone for AuthZoneRef {
 
         Ok(())
     }
+
+    fn push_composite<Y, E: Debug + ScryptoCategorize + ScryptoDecode>(
+        &self,
+        proofs: Vec<Proof>,
+        api: &mut Y,
+    ) -> Result<(), E>
+    where
+        Y: ClientApi<E>,
+    {
+        let _rtn = api.call_method(
+            &self.0,
+            AUTH_ZONE_PUSH_COMPOSITE_IDENT,
+            scrypto_encode(&AuthZonePushCompositeInput { proofs }).unwrap(),
+        )?;
+
+        Ok(())
+    }
 }
@@ -281,6 +281,18 @@ impl ResourceManager {
         Ok(scrypto_decode(&rtn).unwrap())
     }
 
+    pub fn max_supply<Y, E: Debug + ScryptoDecode>(&self, api: &mut Y) -> Result<Option<Decimal>, E>
+    where
+        Y: ClientObjectApi<E>,
+    {
+        let rtn = api.call_method(
+            self.0.as_node_id(),
+            RESOURCE_MANAGER_GET_MAX_SUPPLY_IDENT,
+            scrypto_encode(&ResourceManagerGetMaxSupplyInput {}).unwrap(),
+        )?;
+        Ok(scrypto_decode(&rtn).unwrap())
+    }
+
     pub fn new_empty_bucket<Y, E: Debug + ScryptoDecode>(&self, api: &mut Y) -> Result<Bucket, E>
     where
         Y: ClientObjectApi<E>,
@@ -304,4 +316,79 @@ impl ResourceManager {
         )?;
         Ok(scrypto_decode(&rtn).unwrap())
     }
+
+    /// Recalls some amount of resource from a vault, which requires the `recaller` role of its
+    /// resource.
+    pub fn recall_from_vault<Y, E: Debug + ScryptoDecode>(
+        vault: InternalAddress,
+        amount: Decimal,
+        api: &mut Y,
+    ) -> Result<Bucket, E>
+    where
+        Y: ClientObjectApi<E>,
+    {
+        let rtn = api.call_direct_access_method(
+            vault.as_node_id(),
+            VAULT_RECALL_IDENT,
+            scrypto_encode(&VaultRecallInput { amount }).unwrap(),
+        )?;
+        Ok(scrypto_decode(&rtn).unwrap())
+    }
+
+    /// Recalls specific non-fungibles from a vault, which requires the `recaller` role of its
+    /// resource.
+    pub fn recall_non_fungibles_from_vault<Y, E: Debug + ScryptoDecode>(
+        vault: InternalAddress,
+        non_fungible_local_ids: IndexSet<NonFungibleLocalId>,
+        api: &mut Y,
+    ) -> Result<Bucket, E>
+    where
+        Y: ClientObjectApi<E>,
+    {
+        let rtn = api.call_direct_access_method(
+            vault.as_node_id(),
+            NON_FUNGIBLE_VAULT_RECALL_NON_FUNGIBLES_IDENT,
+            scrypto_encode(&NonFungibleVaultRecallNonFungiblesInput {
+                non_fungible_local_ids,
+            })
+            .unwrap(),
+        )?;
+        Ok(scrypto_decode(&rtn).unwrap())
+    }
+
+    /// Freezes the given operations on a vault, which requires the `freezer` role of its
+    /// resource.
+    pub fn freeze_vault<Y, E: Debug + ScryptoDecode>(
+        vault: InternalAddress,
+        to_freeze: VaultFreezeFlags,
+        api: &mut Y,
+    ) -> Result<(), E>
+    where
+        Y: ClientObjectApi<E>,
+    {
+        let rtn = api.call_direct_access_method(
+            vault.as_node_id(),
+            VAULT_FREEZE_IDENT,
+            scrypto_encode(&VaultFreezeInput { to_freeze }).unwrap(),
+        )?;
+        Ok(scrypto_decode(&rtn).unwrap())
+    }
+
+    /// Unfreezes the given operations on a vault, which requires the `freezer` role of its
+    /// resource.
+    pub fn unfreeze_vault<Y, E: Debug + ScryptoDecode>(
+        vault: InternalAddress,
+        to_unfreeze: VaultFreezeFlags,
+        api: &mut Y,
+    ) -> Result<(), E>
+    where
+        Y: ClientObjectApi<E>,
+    {
+        let rtn = api.call_direct_access_method(
+            vault.as_node_id(),
+            VAULT_UNFREEZE_IDENT,
+            scrypto_encode(&VaultUnfreezeInput { to_unfreeze }).unwrap(),
+        )?;
+        Ok(scrypto_decode(&rtn).unwrap())
+    }
 }
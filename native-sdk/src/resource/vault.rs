@@ -75,6 +75,15 @@ pub trait NativeFungibleVault {
     where
         Y: ClientApi<E>;
 
+    fn lock_fee_with_refund<Y, E: Debug + ScryptoDecode>(
+        &mut self,
+        api: &mut Y,
+        amount: Decimal,
+        refund_vault: InternalAddress,
+    ) -> Result<(), E>
+    where
+        Y: ClientApi<E>;
+
     fn create_proof_of_amount<Y, E: Debug + ScryptoDecode>(
         &self,
         amount: Decimal,
@@ -93,6 +102,15 @@ pub trait NativeNonFungibleVault {
     where
         Y: ClientApi<E>;
 
+    fn non_fungible_local_ids_page<Y, E: Debug + ScryptoDecode>(
+        &self,
+        limit: u32,
+        cursor: Option<NonFungibleLocalId>,
+        api: &mut Y,
+    ) -> Result<NonFungibleLocalIdsPage, E>
+    where
+        Y: ClientApi<E>;
+
     fn take_non_fungibles<Y, E: Debug + ScryptoDecode>(
         &mut self,
         non_fungible_local_ids: IndexSet<NonFungibleLocalId>,
@@ -282,6 +300,28 @@ impl NativeFungibleVault for Vault {
         Ok(scrypto_decode(&rtn).unwrap())
     }
 
+    fn lock_fee_with_refund<Y, E: Debug + ScryptoDecode>(
+        &mut self,
+        api: &mut Y,
+        amount: Decimal,
+        refund_vault: InternalAddress,
+    ) -> Result<(), E>
+    where
+        Y: ClientApi<E>,
+    {
+        let rtn = api.call_method(
+            self.0.as_node_id(),
+            FUNGIBLE_VAULT_LOCK_FEE_WITH_REFUND_IDENT,
+            scrypto_encode(&FungibleVaultLockFeeWithRefundInput {
+                amount,
+                contingent: false,
+                refund_vault,
+            })
+            .unwrap(),
+        )?;
+        Ok(scrypto_decode(&rtn).unwrap())
+    }
+
     fn create_proof_of_amount<Y, E: Debug + ScryptoDecode>(
         &self,
         amount: Decimal,
@@ -374,4 +414,23 @@ impl NativeNonFungibleVault for Vault {
 
         Ok(scrypto_decode(&rtn).unwrap())
     }
+
+    fn non_fungible_local_ids_page<Y, E: Debug + ScryptoDecode>(
+        &self,
+        limit: u32,
+        cursor: Option<NonFungibleLocalId>,
+        api: &mut Y,
+    ) -> Result<NonFungibleLocalIdsPage, E>
+    where
+        Y: ClientApi<E>,
+    {
+        let rtn = api.call_method(
+            self.0.as_node_id(),
+            NON_FUNGIBLE_VAULT_GET_NON_FUNGIBLE_LOCAL_IDS_PAGE_IDENT,
+            scrypto_encode(&NonFungibleVaultGetNonFungibleLocalIdsPageInput { limit, cursor })
+                .unwrap(),
+        )?;
+
+        Ok(scrypto_decode(&rtn).unwrap())
+    }
 }
@@ -7,7 +7,7 @@ use radix_engine_interface::data::scrypto::{
 };
 use radix_engine_interface::math::Decimal;
 use radix_engine_interface::types::*;
-use sbor::rust::collections::IndexSet;
+use sbor::rust::collections::{IndexMap, IndexSet};
 use sbor::rust::fmt::Debug;
 use sbor::rust::vec::Vec;
 
@@ -167,6 +167,37 @@ impl Worktop {
         Ok(())
     }
 
+    pub fn assert_resources_only<Y, E: Debug + ScryptoCategorize + ScryptoDecode>(
+        &self,
+        resource_addresses: IndexSet<ResourceAddress>,
+        api: &mut Y,
+    ) -> Result<(), E>
+    where
+        Y: ClientApi<E>,
+    {
+        let _rtn = api.call_method(
+            self.0.as_node_id(),
+            WORKTOP_ASSERT_RESOURCES_ONLY_IDENT,
+            scrypto_encode(&WorktopAssertResourcesOnlyInput { resource_addresses }).unwrap(),
+        )?;
+        Ok(())
+    }
+
+    pub fn contents<Y, E: Debug + ScryptoCategorize + ScryptoDecode>(
+        &self,
+        api: &mut Y,
+    ) -> Result<IndexMap<ResourceAddress, Decimal>, E>
+    where
+        Y: ClientApi<E>,
+    {
+        let rtn = api.call_method(
+            self.0.as_node_id(),
+            WORKTOP_CONTENTS_IDENT,
+            scrypto_encode(&WorktopContentsInput {}).unwrap(),
+        )?;
+        Ok(scrypto_decode(&rtn).unwrap())
+    }
+
     pub fn drain<Y, E: Debug + ScryptoCategorize + ScryptoDecode>(
         &self,
         api: &mut Y,
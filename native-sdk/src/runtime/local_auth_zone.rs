@@ -107,4 +107,15 @@ impl LocalAuthZone {
         let auth_zone = api.actor_get_node_id(ACTOR_REF_AUTH_ZONE)?;
         AuthZoneRef(auth_zone).push(proof, api)
     }
+
+    pub fn push_composite<Y, E: Debug + ScryptoCategorize + ScryptoDecode>(
+        proofs: Vec<Proof>,
+        api: &mut Y,
+    ) -> Result<(), E>
+    where
+        Y: ClientApi<E>,
+    {
+        let auth_zone = api.actor_get_node_id(ACTOR_REF_AUTH_ZONE)?;
+        AuthZoneRef(auth_zone).push_composite(proofs, api)
+    }
 }
@@ -106,6 +106,14 @@ impl Runtime {
         api.generate_ruid()
     }
 
+    pub fn generate_ruid_seeded<Y, E>(domain: &[u8], api: &mut Y) -> Result<[u8; 32], E>
+    where
+        Y: ClientApi<E>,
+        E: Debug + ScryptoCategorize + ScryptoDecode,
+    {
+        api.generate_ruid_seeded(domain)
+    }
+
     pub fn assert_access_rule<Y, E>(rule: AccessRule, api: &mut Y) -> Result<(), E>
     where
         Y: ClientApi<E>,
@@ -0,0 +1,286 @@
+use super::hrpset::HrpSet;
+use crate::address::{AddressBech32EncodeError, AddressBech32Encoder};
+use crate::network::NetworkDefinition;
+use crate::types::*;
+use bech32::{self, FromBase32, Variant};
+use sbor::rust::prelude::*;
+use utils::copy_u8_array;
+
+/// The entity-specific parts of the HRPs in an [`HrpSet`], e.g. `account` in `account_rdx`.
+const ENTITY_HRP_PREFIXES: [&str; 14] = [
+    "package",
+    "resource",
+    "component",
+    "account",
+    "identity",
+    "consensusmanager",
+    "validator",
+    "accesscontroller",
+    "pool",
+    "locker",
+    "transactiontracker",
+    "internal_vault",
+    "internal_component",
+    "internal_keyvaluestore",
+];
+
+/// An address of any kind, as decoded by [`AddressBech32Codec::try_from_bech32_any`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnyAddress {
+    Package(PackageAddress),
+    Resource(ResourceAddress),
+    Component(ComponentAddress),
+    Internal(InternalAddress),
+}
+
+impl AnyAddress {
+    pub fn as_node_id(&self) -> &NodeId {
+        match self {
+            AnyAddress::Package(address) => address.as_node_id(),
+            AnyAddress::Resource(address) => address.as_node_id(),
+            AnyAddress::Component(address) => address.as_node_id(),
+            AnyAddress::Internal(address) => address.as_node_id(),
+        }
+    }
+
+    pub fn entity_type(&self) -> EntityType {
+        // Safe to unwrap, as the entity type is checked when decoding
+        self.as_node_id().entity_type().unwrap()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AddressBech32CodecError {
+    /// The string isn't valid Bech32, e.g. it contains invalid characters or has an invalid length.
+    Bech32mDecodingError(bech32::Error),
+    /// The string is well-formed, but its checksum doesn't match, e.g. due to a typo.
+    InvalidChecksum,
+    InvalidVariant(bech32::Variant),
+    /// The HRP isn't of the form `<entity>_<network HRP suffix>`.
+    MalformedHrp(String),
+    /// The address is well-formed, but belongs to another network.
+    WrongNetwork {
+        expected_hrp_suffix: String,
+        actual_hrp_suffix: String,
+    },
+    MissingEntityTypeByte,
+    InvalidEntityTypeId(u8),
+    /// The HRP is for a different kind of entity than the entity type byte.
+    EntityTypeMismatch {
+        hrp: String,
+        entity_type: EntityType,
+    },
+    InvalidLength(usize),
+    EncodeError(AddressBech32EncodeError),
+}
+
+#[cfg(not(feature = "alloc"))]
+impl std::error::Error for AddressBech32CodecError {}
+
+impl fmt::Display for AddressBech32CodecError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Encodes and decodes the Bech32m addresses of all entity types on a given network.
+///
+/// Unlike [`AddressBech32Decoder`](crate::address::AddressBech32Decoder), decoding fully
+/// validates the address and tells apart an address of another network from a malformed one.
+#[derive(Debug)]
+pub struct AddressBech32Codec {
+    network: NetworkDefinition,
+    encoder: AddressBech32Encoder,
+}
+
+impl AddressBech32Codec {
+    pub fn for_simulator() -> Self {
+        Self::new(&NetworkDefinition::simulator())
+    }
+
+    pub fn new(network: &NetworkDefinition) -> Self {
+        Self {
+            network: network.clone(),
+            encoder: AddressBech32Encoder::new(network),
+        }
+    }
+
+    pub fn network(&self) -> &NetworkDefinition {
+        &self.network
+    }
+
+    pub fn hrp_set(&self) -> &HrpSet {
+        &self.encoder.hrp_set
+    }
+
+    pub fn encode(&self, full_data: &[u8]) -> Result<String, AddressBech32CodecError> {
+        if full_data.len() != NodeId::LENGTH {
+            return Err(AddressBech32CodecError::InvalidLength(full_data.len()));
+        }
+        self.encoder
+            .encode(full_data)
+            .map_err(AddressBech32CodecError::EncodeError)
+    }
+
+    /// Decodes an address of any entity type, returning its entity type and node id.
+    pub fn decode(&self, address: &str) -> Result<(EntityType, NodeId), AddressBech32CodecError> {
+        let (hrp, data, variant) = bech32::decode(address).map_err(|err| match err {
+            bech32::Error::InvalidChecksum => AddressBech32CodecError::InvalidChecksum,
+            err => AddressBech32CodecError::Bech32mDecodingError(err),
+        })?;
+        if variant != Variant::Bech32m {
+            return Err(AddressBech32CodecError::InvalidVariant(variant));
+        }
+
+        // Check the network before looking at the data, as an address of another network is
+        // more useful to report than anything else which might be wrong about it
+        let hrp_suffix = Self::hrp_suffix(&hrp)
+            .ok_or_else(|| AddressBech32CodecError::MalformedHrp(hrp.clone()))?;
+        if hrp_suffix != self.network.hrp_suffix {
+            return Err(AddressBech32CodecError::WrongNetwork {
+                expected_hrp_suffix: self.network.hrp_suffix.clone(),
+                actual_hrp_suffix: hrp_suffix.to_string(),
+            });
+        }
+
+        let data =
+            Vec::<u8>::from_base32(&data).map_err(AddressBech32CodecError::Bech32mDecodingError)?;
+        let entity_type_id = *data
+            .first()
+            .ok_or(AddressBech32CodecError::MissingEntityTypeByte)?;
+        let entity_type = EntityType::from_repr(entity_type_id)
+            .ok_or(AddressBech32CodecError::InvalidEntityTypeId(entity_type_id))?;
+        if hrp != self.hrp_set().get_entity_hrp(&entity_type) {
+            return Err(AddressBech32CodecError::EntityTypeMismatch { hrp, entity_type });
+        }
+        if data.len() != NodeId::LENGTH {
+            return Err(AddressBech32CodecError::InvalidLength(data.len()));
+        }
+
+        Ok((entity_type, NodeId(copy_u8_array(&data))))
+    }
+
+    /// Decodes an address of any entity type into the matching kind of address.
+    pub fn try_from_bech32_any(
+        &self,
+        address: &str,
+    ) -> Result<AnyAddress, AddressBech32CodecError> {
+        let (entity_type, node_id) = self.decode(address)?;
+        // The conversions can't fail, as the entity type has been checked
+        let address = if entity_type.is_global_package() {
+            AnyAddress::Package(PackageAddress::try_from(node_id).unwrap())
+        } else if entity_type.is_global_resource_manager() {
+            AnyAddress::Resource(ResourceAddress::try_from(node_id).unwrap())
+        } else if entity_type.is_global_component() {
+            AnyAddress::Component(ComponentAddress::try_from(node_id).unwrap())
+        } else {
+            AnyAddress::Internal(InternalAddress::try_from(node_id).unwrap())
+        };
+        Ok(address)
+    }
+
+    /// Returns the network suffix of an HRP, if it's well-formed.
+    fn hrp_suffix(hrp: &str) -> Option<&str> {
+        ENTITY_HRP_PREFIXES.iter().find_map(|prefix| {
+            hrp.strip_prefix(*prefix)
+                .and_then(|rest| rest.strip_prefix('_'))
+                .filter(|suffix| !suffix.is_empty())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::address::bech32_encode_to_fmt;
+    use crate::address::test_addresses::*;
+    use bech32::ToBase32;
+
+    fn encode_with_hrp(hrp: &str, data: &[u8]) -> String {
+        let mut address = String::new();
+        bech32_encode_to_fmt(&mut address, hrp, data.to_base32(), Variant::Bech32m)
+            .unwrap()
+            .unwrap();
+        address
+    }
+
+    #[test]
+    fn encode_and_decode_roundtrip() {
+        let codec = AddressBech32Codec::for_simulator();
+
+        let address = codec.encode(FUNGIBLE_RESOURCE.as_ref()).unwrap();
+
+        assert_eq!(address, FUNGIBLE_RESOURCE_SIM_ADDRESS);
+        assert_eq!(
+            codec.decode(&address),
+            Ok((
+                EntityType::GlobalFungibleResourceManager,
+                FUNGIBLE_RESOURCE_NODE_ID
+            ))
+        );
+        assert_eq!(
+            codec.try_from_bech32_any(&address),
+            Ok(AnyAddress::Resource(FUNGIBLE_RESOURCE))
+        );
+    }
+
+    #[test]
+    fn address_of_another_network_is_reported() {
+        let codec = AddressBech32Codec::new(&NetworkDefinition::mainnet());
+
+        assert_eq!(
+            codec.decode(FUNGIBLE_RESOURCE_SIM_ADDRESS),
+            Err(AddressBech32CodecError::WrongNetwork {
+                expected_hrp_suffix: "rdx".to_string(),
+                actual_hrp_suffix: "sim".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn mistyped_address_is_reported() {
+        let codec = AddressBech32Codec::for_simulator();
+        let mut address = FUNGIBLE_RESOURCE_SIM_ADDRESS.to_string();
+        address.pop();
+        address.push('q');
+
+        assert_eq!(
+            codec.decode(&address),
+            Err(AddressBech32CodecError::InvalidChecksum)
+        );
+    }
+
+    #[test]
+    fn malformed_hrp_is_reported() {
+        let codec = AddressBech32Codec::for_simulator();
+
+        assert_eq!(
+            codec.decode(&encode_with_hrp(
+                "resources_sim",
+                FUNGIBLE_RESOURCE.as_ref()
+            )),
+            Err(AddressBech32CodecError::MalformedHrp(
+                "resources_sim".to_string()
+            ))
+        );
+        assert_eq!(
+            codec.decode(&encode_with_hrp("resource_", FUNGIBLE_RESOURCE.as_ref())),
+            Err(AddressBech32CodecError::MalformedHrp(
+                "resource_".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn entity_type_mismatch_is_reported() {
+        let codec = AddressBech32Codec::for_simulator();
+
+        assert_eq!(
+            codec.decode(&encode_with_hrp("account_sim", FUNGIBLE_RESOURCE.as_ref())),
+            Err(AddressBech32CodecError::EntityTypeMismatch {
+                hrp: "account_sim".to_string(),
+                entity_type: EntityType::GlobalFungibleResourceManager,
+            })
+        );
+    }
+}
@@ -1,9 +1,11 @@
+mod codec;
 mod decoder;
 mod display;
 mod encoder;
 mod errors;
 mod hrpset;
 
+pub use codec::*;
 pub use decoder::*;
 pub use display::*;
 pub use encoder::*;
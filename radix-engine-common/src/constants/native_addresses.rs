@@ -143,6 +143,12 @@ pub const POOL_PACKAGE: PackageAddress = PackageAddress::new_or_panic([
     148, 250, 42, 166, 49, 140, 99, 24, 198,
 ]);
 
+/// The native package for account lockers.
+pub const LOCKER_PACKAGE: PackageAddress = PackageAddress::new_or_panic([
+    13, 144, 99, 24, 198, 49, 140, 111, 226, 217, 25, 140, 99, 24, 198, 49, 141, 90, 190, 52, 211,
+    223, 167, 213, 230, 49, 140, 99, 24, 198,
+]);
+
 /// The native package for the transaction processor.
 pub const TRANSACTION_PROCESSOR_PACKAGE: PackageAddress = PackageAddress::new_or_panic([
     13, 144, 99, 24, 198, 49, 140, 101, 154, 97, 48, 204, 99, 24, 198, 49, 140, 247, 168, 186, 82,
@@ -333,6 +339,11 @@ mod tests {
             EntityType::GlobalPackage,
             "package_rdx1pkgxxxxxxxxxplxxxxxxxxxxxxx020379220524xxxxxxxxxplxxxx",
         );
+        check_address(
+            LOCKER_PACKAGE.as_ref(),
+            EntityType::GlobalPackage,
+            "package_rdx1pkgxxxxxxxxxlckerxxxxxxxxxx440356006040xxxxxxxxxlckerx",
+        );
         check_address(
             TRANSACTION_PROCESSOR_PACKAGE.as_ref(),
             EntityType::GlobalPackage,
@@ -58,6 +58,11 @@ pub const MAX_NUMBER_OF_EVENTS: usize = 256;
 /// The max number of logs
 pub const MAX_NUMBER_OF_LOGS: usize = 256;
 
+/// The max size of the WASM linear memory of a call frame, which matches the max memory size of
+/// Scrypto modules by default: 64 * 64KiB = 4MiB
+pub const MAX_WASM_MEMORY_PER_CALL_FRAME: usize =
+    super::MAX_MEMORY_SIZE_IN_PAGES as usize * 64 * 1024;
+
 /// The max SBOR size of metadata key
 pub const MAX_METADATA_KEY_STRING_LEN: usize = 100;
 
@@ -110,6 +110,24 @@ pub fn manifest_decode_with_depth_limit<T: ManifestDecode>(
     ManifestDecoder::new(buf, depth_limit).decode_payload(MANIFEST_SBOR_V1_PAYLOAD_PREFIX)
 }
 
+/// Re-encodes a payload into its canonical byte form, suitable for hashing.
+pub fn manifest_canonicalize(buf: &[u8]) -> Result<Vec<u8>, CanonicalizationError> {
+    canonicalize::<ManifestCustomValueKind, ManifestCustomValue>(
+        buf,
+        MANIFEST_SBOR_V1_PAYLOAD_PREFIX,
+        MANIFEST_SBOR_V1_MAX_DEPTH,
+    )
+}
+
+/// Returns whether a payload is valid and already in its canonical byte form.
+pub fn manifest_is_canonical(buf: &[u8]) -> bool {
+    is_canonical::<ManifestCustomValueKind, ManifestCustomValue>(
+        buf,
+        MANIFEST_SBOR_V1_PAYLOAD_PREFIX,
+        MANIFEST_SBOR_V1_MAX_DEPTH,
+    )
+}
+
 pub fn to_manifest_value<T: ManifestEncode + ?Sized>(
     value: &T,
 ) -> Result<ManifestValue, RustToManifestValueError> {
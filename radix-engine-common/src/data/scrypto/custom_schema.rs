@@ -39,6 +39,7 @@ pub enum OwnValidation {
     IsKeyValueStore,
     IsGlobalAddressReservation,
     IsTypedObject(Option<PackageAddress>, String),
+    IsIndexStore,
 }
 
 impl OwnValidation {
@@ -48,6 +49,7 @@ impl OwnValidation {
             OwnValidation::IsProof => false,
             OwnValidation::IsVault => false,
             OwnValidation::IsKeyValueStore => false,
+            OwnValidation::IsIndexStore => false,
             OwnValidation::IsGlobalAddressReservation => false,
             // Hard to validate without knowing package addresses from engine, assume fine
             OwnValidation::IsTypedObject(_, _) => true,
@@ -60,6 +62,7 @@ impl OwnValidation {
             OwnValidation::IsProof => true,
             OwnValidation::IsVault => false,
             OwnValidation::IsKeyValueStore => false,
+            OwnValidation::IsIndexStore => false,
             OwnValidation::IsGlobalAddressReservation => false,
             // Hard to validate without knowing package addresses from engine, assume fine
             OwnValidation::IsTypedObject(_, _) => true,
@@ -72,6 +75,7 @@ impl OwnValidation {
             OwnValidation::IsProof => false,
             OwnValidation::IsVault => false,
             OwnValidation::IsKeyValueStore => false,
+            OwnValidation::IsIndexStore => false,
             OwnValidation::IsGlobalAddressReservation => true,
             OwnValidation::IsTypedObject(_, _) => false,
         }
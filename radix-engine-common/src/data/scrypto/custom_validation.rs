@@ -83,6 +83,8 @@ fn apply_static_custom_validation_to_custom_value(
                 OwnValidation::IsProof => node_id.is_internal(),
                 OwnValidation::IsVault => node_id.is_internal_vault(),
                 OwnValidation::IsKeyValueStore => node_id.is_internal_kv_store(),
+                // Index stores share the entity type of key value stores
+                OwnValidation::IsIndexStore => node_id.is_internal_kv_store(),
                 OwnValidation::IsGlobalAddressReservation => true,
                 OwnValidation::IsTypedObject(_, _) => true,
             };
@@ -320,6 +320,17 @@ create_well_known_lookup!(
                 )),
             )
         ),
+        (
+            OWN_INDEX_STORE,
+            OWNED_ENTITIES_START + 12,
+            named_custom_type_kind(
+                "IndexStore",
+                ScryptoCustomTypeKind::Own,
+                Some(ScryptoCustomTypeValidation::Own(
+                    OwnValidation::IsIndexStore
+                )),
+            )
+        ),
         // Other well known scrypto types.
         // A combination of custom value kinds, composite types, or semantic transparent types
         //
@@ -459,6 +470,16 @@ create_well_known_lookup!(
                     (0u8, named_tuple("ProofRule", [PROOF_RULE_TYPE])),
                     (1u8, named_tuple("AnyOf", [ACCESS_RULE_NODE_LIST_TYPE])),
                     (2u8, named_tuple("AllOf", [ACCESS_RULE_NODE_LIST_TYPE])),
+                    (
+                        3u8,
+                        named_struct(
+                            "WeightedThreshold",
+                            [
+                                ("threshold", U32_TYPE),
+                                ("weights", WEIGHTED_ACCESS_RULE_NODE_LIST_TYPE)
+                            ]
+                        )
+                    ),
                 ],
             )
         ),
@@ -529,6 +550,18 @@ create_well_known_lookup!(
             ROLE_ASSIGNMENT_TYPES_START + 7,
             named_transparent("RoleKey", string_type_data(),)
         ),
+        (
+            WEIGHTED_ACCESS_RULE_NODE,
+            ROLE_ASSIGNMENT_TYPES_START + 8,
+            TypeData::unnamed(TypeKind::Tuple {
+                field_types: vec![ACCESS_RULE_NODE_TYPE.into(), U32_TYPE.into()],
+            })
+        ),
+        (
+            WEIGHTED_ACCESS_RULE_NODE_LIST,
+            ROLE_ASSIGNMENT_TYPES_START + 9,
+            array_of(WEIGHTED_ACCESS_RULE_NODE_TYPE)
+        ),
         // OTHER MODULE TYPES
         (
             MODULE_ID,
@@ -6,6 +6,8 @@ pub use crate::constants::SCRYPTO_SBOR_V1_PAYLOAD_PREFIX;
 pub type ScryptoEncoder<'a> = VecEncoder<'a, ScryptoCustomValueKind>;
 pub type ScryptoDecoder<'a> = VecDecoder<'a, ScryptoCustomValueKind>;
 pub type ScryptoTraverser<'a> = VecTraverser<'a, ScryptoCustomTraversal>;
+pub type ScryptoStreamDecoder<S> = StreamDecoder<S, ScryptoCustomValueKind>;
+pub type ScryptoStreamTraverser<S> = StreamTraverser<S, ScryptoCustomTraversal, ScryptoCustomValue>;
 pub type ScryptoValueKind = ValueKind<ScryptoCustomValueKind>;
 pub type ScryptoValue = Value<ScryptoCustomValueKind, ScryptoCustomValue>;
 pub type RawScryptoValue<'a> = RawValue<'a, ScryptoCustomExtension>;
@@ -69,3 +71,21 @@ pub fn scrypto_decode_with_depth_limit<T: ScryptoDecode>(
 ) -> Result<T, DecodeError> {
     ScryptoDecoder::new(buf, depth_limit).decode_payload(SCRYPTO_SBOR_V1_PAYLOAD_PREFIX)
 }
+
+/// Re-encodes a payload into its canonical byte form, suitable for hashing.
+pub fn scrypto_canonicalize(buf: &[u8]) -> Result<Vec<u8>, CanonicalizationError> {
+    canonicalize::<ScryptoCustomValueKind, ScryptoCustomValue>(
+        buf,
+        SCRYPTO_SBOR_V1_PAYLOAD_PREFIX,
+        SCRYPTO_SBOR_V1_MAX_DEPTH,
+    )
+}
+
+/// Returns whether a payload is valid and already in its canonical byte form.
+pub fn scrypto_is_canonical(buf: &[u8]) -> bool {
+    is_canonical::<ScryptoCustomValueKind, ScryptoCustomValue>(
+        buf,
+        SCRYPTO_SBOR_V1_PAYLOAD_PREFIX,
+        SCRYPTO_SBOR_V1_MAX_DEPTH,
+    )
+}
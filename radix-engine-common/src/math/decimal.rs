@@ -409,6 +409,46 @@ impl CheckedDiv<Decimal> for Decimal {
     }
 }
 
+impl SaturatingAdd<Decimal> for Decimal {
+    type Output = Self;
+
+    #[inline]
+    fn saturating_add(self, other: Self) -> Self::Output {
+        self.checked_add(other).unwrap_or(if other.is_negative() {
+            Self::MIN
+        } else {
+            Self::MAX
+        })
+    }
+}
+
+impl SaturatingSub<Decimal> for Decimal {
+    type Output = Self;
+
+    #[inline]
+    fn saturating_sub(self, other: Self) -> Self::Output {
+        self.checked_sub(other).unwrap_or(if other.is_negative() {
+            Self::MAX
+        } else {
+            Self::MIN
+        })
+    }
+}
+
+impl SaturatingMul<Decimal> for Decimal {
+    type Output = Self;
+
+    #[inline]
+    fn saturating_mul(self, other: Self) -> Self::Output {
+        self.checked_mul(other)
+            .unwrap_or(if self.is_negative() != other.is_negative() {
+                Self::MIN
+            } else {
+                Self::MAX
+            })
+    }
+}
+
 impl Neg for Decimal {
     type Output = Self;
 
@@ -1023,6 +1063,29 @@ mod tests {
         assert!(Decimal::MAX.checked_add(Decimal::ONE).is_none());
     }
 
+    #[test]
+    fn test_saturating_add_decimal() {
+        assert_eq!(Decimal::MAX.saturating_add(Decimal::ONE), Decimal::MAX);
+        assert_eq!(Decimal::MIN.saturating_add(-Decimal::ONE), Decimal::MIN);
+        assert_eq!(
+            Decimal::from(5u32).saturating_add(Decimal::from(7u32)),
+            Decimal::from(12u32)
+        );
+    }
+
+    #[test]
+    fn test_saturating_sub_decimal() {
+        assert_eq!(Decimal::MIN.saturating_sub(Decimal::ONE), Decimal::MIN);
+        assert_eq!(Decimal::MAX.saturating_sub(-Decimal::ONE), Decimal::MAX);
+    }
+
+    #[test]
+    fn test_saturating_mul_decimal() {
+        assert_eq!(Decimal::MAX.saturating_mul(Decimal::TEN), Decimal::MAX);
+        assert_eq!(Decimal::MAX.saturating_mul(-Decimal::TEN), Decimal::MIN);
+        assert_eq!(Decimal::MIN.saturating_mul(-Decimal::TEN), Decimal::MAX);
+    }
+
     #[test]
     fn test_sub_decimal() {
         let a = Decimal::from(5u32);
@@ -434,6 +434,46 @@ impl CheckedDiv<PreciseDecimal> for PreciseDecimal {
     }
 }
 
+impl SaturatingAdd<PreciseDecimal> for PreciseDecimal {
+    type Output = Self;
+
+    #[inline]
+    fn saturating_add(self, other: Self) -> Self::Output {
+        self.checked_add(other).unwrap_or(if other.is_negative() {
+            Self::MIN
+        } else {
+            Self::MAX
+        })
+    }
+}
+
+impl SaturatingSub<PreciseDecimal> for PreciseDecimal {
+    type Output = Self;
+
+    #[inline]
+    fn saturating_sub(self, other: Self) -> Self::Output {
+        self.checked_sub(other).unwrap_or(if other.is_negative() {
+            Self::MAX
+        } else {
+            Self::MIN
+        })
+    }
+}
+
+impl SaturatingMul<PreciseDecimal> for PreciseDecimal {
+    type Output = Self;
+
+    #[inline]
+    fn saturating_mul(self, other: Self) -> Self::Output {
+        self.checked_mul(other)
+            .unwrap_or(if self.is_negative() != other.is_negative() {
+                Self::MIN
+            } else {
+                Self::MAX
+            })
+    }
+}
+
 impl Neg for PreciseDecimal {
     type Output = Self;
 
@@ -1110,6 +1150,26 @@ mod tests {
             .is_none());
     }
 
+    #[test]
+    fn test_saturating_add_precise_decimal() {
+        assert_eq!(
+            PreciseDecimal::MAX.saturating_add(PreciseDecimal::ONE),
+            PreciseDecimal::MAX
+        );
+        assert_eq!(
+            PreciseDecimal::MIN.saturating_sub(PreciseDecimal::ONE),
+            PreciseDecimal::MIN
+        );
+    }
+
+    #[test]
+    fn test_saturating_mul_precise_decimal() {
+        assert_eq!(
+            PreciseDecimal::MAX.saturating_mul(-PreciseDecimal::TEN),
+            PreciseDecimal::MIN
+        );
+    }
+
     #[test]
     fn test_sub_precise_decimal() {
         let a = PreciseDecimal::from(5u32);
@@ -37,3 +37,27 @@ pub trait CheckedNeg<Rhs = Self> {
     where
         Self: Sized;
 }
+
+pub trait SaturatingAdd<Rhs = Self> {
+    type Output;
+
+    fn saturating_add(self, other: Rhs) -> Self::Output
+    where
+        Self: Sized;
+}
+
+pub trait SaturatingSub<Rhs = Self> {
+    type Output;
+
+    fn saturating_sub(self, other: Rhs) -> Self::Output
+    where
+        Self: Sized;
+}
+
+pub trait SaturatingMul<Rhs = Self> {
+    type Output;
+
+    fn saturating_mul(self, other: Rhs) -> Self::Output
+    where
+        Self: Sized;
+}
@@ -56,6 +56,9 @@ pub enum EntityType {
     /// A global native pool entity (197 in decimal). Gives Bech32 prefix: `c` followed by one of `c`, `e`, `6` or `m`.
     GlobalMultiResourcePool = 0b11000110, //--------- 11000 => c, 110xx => ce6m (101 = pool)
 
+    /// A global native account locker entity (199 in decimal). Gives Bech32 prefix: `c` followed by one of `u`, `a`, `7` or `l`.
+    GlobalAccountLocker = 0b11000111, //------------- 11000 => c, 111xx => ua7l (111 = account locker)
+
     //=========================================================================
     // Secp256k1 Virtual Global Components (start with char 6 for Secp256k1)
     //=========================================================================
@@ -126,6 +129,7 @@ impl EntityType {
             | EntityType::GlobalOneResourcePool
             | EntityType::GlobalTwoResourcePool
             | EntityType::GlobalMultiResourcePool
+            | EntityType::GlobalAccountLocker
             | EntityType::GlobalTransactionTracker => true,
             EntityType::InternalFungibleVault
             | EntityType::InternalNonFungibleVault
@@ -153,6 +157,7 @@ impl EntityType {
             | EntityType::GlobalOneResourcePool
             | EntityType::GlobalTwoResourcePool
             | EntityType::GlobalMultiResourcePool
+            | EntityType::GlobalAccountLocker
             | EntityType::GlobalTransactionTracker => true,
             EntityType::GlobalPackage
             | EntityType::GlobalFungibleResourceManager
@@ -176,6 +181,15 @@ impl EntityType {
         matches!(self, EntityType::GlobalValidator)
     }
 
+    pub const fn is_global_account(&self) -> bool {
+        matches!(
+            self,
+            EntityType::GlobalAccount
+                | EntityType::GlobalVirtualSecp256k1Account
+                | EntityType::GlobalVirtualEd25519Account
+        )
+    }
+
     pub const fn is_global_resource_manager(&self) -> bool {
         matches!(
             self,
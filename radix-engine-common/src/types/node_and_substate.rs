@@ -82,6 +82,10 @@ impl NodeId {
         matches!(self.entity_type(), Some(t) if t.is_global_validator())
     }
 
+    pub const fn is_global_account(&self) -> bool {
+        matches!(self.entity_type(), Some(t) if t.is_global_account())
+    }
+
     pub const fn is_global_resource_manager(&self) -> bool {
         matches!(self.entity_type(), Some(t) if t.is_global_resource_manager())
     }
@@ -81,6 +81,38 @@ pub trait ClientActorIndexApi<E> {
         Ok(entries)
     }
 
+    /// Scans elements of count from an index in a stable order, starting after the given key.
+    /// The last returned key can be used to continue the scan.
+    fn actor_index_scan_keys_after(
+        &mut self,
+        object_handle: ActorStateHandle,
+        collection_index: CollectionIndex,
+        after: Option<Vec<u8>>,
+        limit: u32,
+    ) -> Result<Vec<Vec<u8>>, E>;
+
+    /// Scans elements of count from an index in a stable order, starting after the given key.
+    /// The last returned key can be used to continue the scan.
+    fn actor_index_scan_keys_after_typed<K: ScryptoEncode + ScryptoDecode>(
+        &mut self,
+        object_handle: ActorStateHandle,
+        collection_index: CollectionIndex,
+        after: Option<&K>,
+        limit: u32,
+    ) -> Result<Vec<K>, E> {
+        let after = after.map(|key| scrypto_encode(key).unwrap());
+        let entries = self
+            .actor_index_scan_keys_after(object_handle, collection_index, after, limit)?
+            .into_iter()
+            .map(|key| {
+                let key: K = scrypto_decode(&key).unwrap();
+                key
+            })
+            .collect();
+
+        Ok(entries)
+    }
+
     /// Removes and returns arbitrary elements of count from an index
     fn actor_index_drain(
         &mut self,
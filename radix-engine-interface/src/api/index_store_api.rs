@@ -0,0 +1,37 @@
+use radix_engine_common::types::*;
+use radix_engine_interface::api::KeyValueStoreDataSchema;
+use sbor::rust::prelude::*;
+
+/// An index store is a key value store whose entries are ordered by a sort prefix, so that
+/// they can be scanned in order.
+///
+/// Entries with the same sort prefix are scanned in an arbitrary (but deterministic) order.
+pub trait ClientIndexStoreApi<E> {
+    /// Creates a new index store with a given schema, which must not allow ownership
+    fn index_store_new(&mut self, data_schema: KeyValueStoreDataSchema) -> Result<NodeId, E>;
+
+    /// Inserts an entry into an index store, replacing the existing entry (if any)
+    fn index_store_insert(
+        &mut self,
+        node_id: &NodeId,
+        sorted_key: SortedKey,
+        buffer: Vec<u8>,
+    ) -> Result<(), E>;
+
+    /// Removes an entry from an index store
+    fn index_store_remove(
+        &mut self,
+        node_id: &NodeId,
+        sorted_key: &SortedKey,
+    ) -> Result<Option<Vec<u8>>, E>;
+
+    /// Scans the first elements of count from an index store, starting from the given sort
+    /// prefix (inclusive) and, if given, ending at the given sort prefix (exclusive)
+    fn index_store_scan(
+        &mut self,
+        node_id: &NodeId,
+        from_sort_prefix: [u8; 2],
+        to_sort_prefix: Option<[u8; 2]>,
+        count: u32,
+    ) -> Result<Vec<(SortedKey, Vec<u8>)>, E>;
+}
@@ -4,6 +4,7 @@ pub mod actor_key_value_entry_api;
 pub mod actor_sorted_index_api;
 pub mod blueprint_api;
 pub mod field_api;
+pub mod index_store_api;
 pub mod key_value_entry_api;
 pub mod key_value_store_api;
 pub mod node_modules;
@@ -17,10 +18,12 @@ pub use actor_key_value_entry_api::*;
 pub use actor_sorted_index_api::*;
 pub use blueprint_api::*;
 pub use field_api::*;
+pub use index_store_api::*;
 pub use key_value_entry_api::*;
 pub use key_value_store_api::*;
 pub use object_api::*;
 pub use system_modules::costing_api::ClientCostingApi;
+pub use system_modules::crypto_utils_api::ClientCryptoUtilsApi;
 pub use system_modules::execution_trace_api::ClientExecutionTraceApi;
 pub use system_modules::transaction_runtime_api::ClientTransactionRuntimeApi;
 
@@ -48,6 +51,7 @@ pub trait ClientApi<E: sbor::rust::fmt::Debug>:
     + ClientObjectApi<E>
     + ClientKeyValueStoreApi<E>
     + ClientKeyValueEntryApi<E>
+    + ClientIndexStoreApi<E>
     + ClientActorSortedIndexApi<E>
     + ClientActorIndexApi<E>
     + ClientFieldApi<E>
@@ -55,6 +59,7 @@ pub trait ClientApi<E: sbor::rust::fmt::Debug>:
     + ClientCostingApi<E>
     + ClientTransactionRuntimeApi<E>
     + ClientExecutionTraceApi<E>
+    + ClientCryptoUtilsApi<E>
 {
 }
 
@@ -7,7 +7,15 @@ pub trait ClientCostingApi<E> {
     fn start_lock_fee(&mut self, amount: Decimal) -> Result<bool, E>;
 
     /// Add cost units to the reserve. This should never fail.
-    fn lock_fee(&mut self, locked_fee: LiquidFungibleResource, contingent: bool);
+    ///
+    /// The unused part of the fee is refunded to `refund_vault_id` if given, or to the vault the
+    /// fee is locked from otherwise.
+    fn lock_fee(
+        &mut self,
+        locked_fee: LiquidFungibleResource,
+        contingent: bool,
+        refund_vault_id: Option<NodeId>,
+    );
 
     fn consume_cost_units(&mut self, costing_entry: ClientCostingEntry) -> Result<(), E>;
 
@@ -23,7 +31,16 @@ pub trait ClientCostingApi<E> {
 
     fn max_per_function_royalty_in_xrd(&mut self) -> Result<Decimal, E>;
 
+    /// Returns the tip as a whole percentage, rounded down, e.g. a tip of 250 basis points is
+    /// reported as 2%. Use [`Self::tip_basis_points`] for the exact tip.
     fn tip_percentage(&mut self) -> Result<u32, E>;
 
+    /// Returns the tip in basis points, where 10_000 means 100%.
+    fn tip_basis_points(&mut self) -> Result<u32, E>;
+
     fn fee_balance(&mut self) -> Result<Decimal, E>;
+
+    /// Limits the execution cost units which each of the following transaction instructions may
+    /// consume. Only the transaction processor may set this.
+    fn set_instruction_cost_unit_limit(&mut self, limit: u32) -> Result<(), E>;
 }
@@ -0,0 +1,39 @@
+use radix_engine_common::crypto::{
+    Bls12381G1PublicKey, Bls12381G2Signature, Ed25519PublicKey, Ed25519Signature, Hash,
+    Secp256k1PublicKey, Secp256k1Signature,
+};
+
+pub trait ClientCryptoUtilsApi<E> {
+    /// Verifies an Ed25519 signature of the Blake2b-256 hash of the given message, i.e. one made
+    /// the same way as a transaction signature.
+    fn ed25519_verify(
+        &mut self,
+        message: &[u8],
+        public_key: &Ed25519PublicKey,
+        signature: &Ed25519Signature,
+    ) -> Result<bool, E>;
+
+    /// Verifies an ECDSA Secp256k1 signature of the Blake2b-256 hash of the given message, i.e.
+    /// one made the same way as a transaction signature.
+    fn secp256k1_verify(
+        &mut self,
+        message: &[u8],
+        public_key: &Secp256k1PublicKey,
+        signature: &Secp256k1Signature,
+    ) -> Result<bool, E>;
+
+    /// Verifies a BLS12-381 signature of the given message, using the ciphersuite
+    /// `BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_`.
+    fn bls12381_v1_verify(
+        &mut self,
+        message: &[u8],
+        public_key: &Bls12381G1PublicKey,
+        signature: &Bls12381G2Signature,
+    ) -> Result<bool, E>;
+
+    /// Computes the Keccak-256 hash of the given data, as used by Ethereum.
+    fn keccak256_hash(&mut self, data: &[u8]) -> Result<Hash, E>;
+
+    /// Computes the Blake2b-256 hash of the given data, as used by Radix.
+    fn blake2b_256_hash(&mut self, data: &[u8]) -> Result<Hash, E>;
+}
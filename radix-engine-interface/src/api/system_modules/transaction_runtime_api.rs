@@ -10,7 +10,24 @@ pub trait ClientTransactionRuntimeApi<E> {
 
     fn generate_ruid(&mut self) -> Result<[u8; 32], E>;
 
+    /// Generates a RUID from the transaction hash, the given domain and a per-transaction counter
+    /// of the domain, such that repeated calls and different domains yield unrelated values, and
+    /// calls in one domain don't shift the values of another.
+    ///
+    /// This is deterministic given the transaction, so it must not be relied upon for secrecy
+    /// against whoever submits the transaction.
+    fn generate_ruid_seeded(&mut self, domain: &[u8]) -> Result<[u8; 32], E>;
+
     fn emit_log(&mut self, level: Level, message: String) -> Result<(), E>;
 
+    /// Emits a log under the given topic, with an optional SBOR-encoded payload.
+    fn emit_log_record(
+        &mut self,
+        level: Level,
+        topic: String,
+        message: String,
+        payload: Option<Vec<u8>>,
+    ) -> Result<(), E>;
+
     fn panic(&mut self, message: String) -> Result<(), E>;
 }
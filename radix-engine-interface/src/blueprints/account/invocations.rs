@@ -105,6 +105,21 @@ pub struct AccountLockContingentFeeInput {
 
 pub type AccountLockContingentFeeOutput = ();
 
+//==============================
+// Account Lock Fee With Refund
+//==============================
+
+pub const ACCOUNT_LOCK_FEE_WITH_REFUND_IDENT: &str = "lock_fee_with_refund";
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct AccountLockFeeWithRefundInput {
+    pub amount: Decimal,
+    /// The XRD vault the unused part of the fee is refunded to, e.g. of a treasury component
+    pub refund_vault: InternalAddress,
+}
+
+pub type AccountLockFeeWithRefundOutput = ();
+
 //=================
 // Account Deposit
 //=================
@@ -35,6 +35,36 @@ where
     }
 }
 
+// Implemented manually, as deriving would require the marker types to implement them too.
+
+impl<T: TypeInfoMarker> Debug for Global<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Global").field(&self.0).finish()
+    }
+}
+
+impl<T: TypeInfoMarker> PartialEq for Global<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: TypeInfoMarker> Eq for Global<T> {}
+
+impl<T: TypeInfoMarker> Debug for Owned<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Owned").field(&self.0).finish()
+    }
+}
+
+impl<T: TypeInfoMarker> PartialEq for Owned<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<T: TypeInfoMarker> Eq for Owned<T> {}
+
 impl<O: TypeInfoMarker> Categorize<ScryptoCustomValueKind> for Global<O> {
     #[inline]
     fn value_kind() -> ValueKind<ScryptoCustomValueKind> {
@@ -296,6 +296,18 @@ pub struct LeaderProposalHistory {
 
 pub type ConsensusManagerNextRoundOutput = ();
 
+pub const CONSENSUS_MANAGER_UPDATE_CONFIG_IDENT: &str = "update_config";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct ConsensusManagerUpdateConfigInput {
+    /// The new configuration, which replaces the current one as a whole.
+    /// The parts of it which are applied on epoch change, such as the validator set size and the
+    /// emissions, take effect from the next epoch change.
+    pub config: ConsensusManagerConfig,
+}
+
+pub type ConsensusManagerUpdateConfigOutput = ();
+
 pub const CONSENSUS_MANAGER_CREATE_VALIDATOR_IDENT: &str = "create_validator";
 
 #[derive(Debug, Eq, PartialEq, ScryptoSbor)]
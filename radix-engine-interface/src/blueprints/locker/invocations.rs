@@ -0,0 +1,98 @@
+use crate::blueprints::account::*;
+use crate::blueprints::component::*;
+use crate::blueprints::macros::*;
+use crate::blueprints::resource::*;
+use radix_engine_common::data::manifest::model::*;
+use radix_engine_common::math::*;
+use radix_engine_common::prelude::*;
+use radix_engine_common::*;
+
+pub const ACCOUNT_LOCKER_BLUEPRINT: &str = "AccountLocker";
+
+define_type_info_marker!(Some(LOCKER_PACKAGE), AccountLocker);
+
+define_invocation! {
+    blueprint_name: AccountLocker,
+    function_name: instantiate,
+    input: struct {
+        owner_role: OwnerRole,
+        storer_role: AccessRule,
+        storer_updater_role: AccessRule,
+        recoverer_role: AccessRule,
+        recoverer_updater_role: AccessRule,
+        claim_period_in_minutes: u32,
+        address_reservation: Option<GlobalAddressReservation>
+    },
+    output: type Global<AccountLockerObjectTypeInfo>,
+    manifest_input: struct {
+        owner_role: OwnerRole,
+        storer_role: AccessRule,
+        storer_updater_role: AccessRule,
+        recoverer_role: AccessRule,
+        recoverer_updater_role: AccessRule,
+        claim_period_in_minutes: u32,
+        address_reservation: Option<ManifestAddressReservation>
+    }
+}
+
+define_invocation! {
+    blueprint_name: AccountLocker,
+    function_name: store,
+    input: struct {
+        claimant: Global<AccountObjectTypeInfo>,
+        bucket: Bucket,
+        try_direct_send: bool
+    },
+    output: type (),
+    manifest_input: struct {
+        claimant: ComponentAddress,
+        bucket: ManifestBucket,
+        try_direct_send: bool
+    }
+}
+
+define_invocation! {
+    blueprint_name: AccountLocker,
+    function_name: claim,
+    input: struct {
+        claimant: Global<AccountObjectTypeInfo>,
+        resource_address: ResourceAddress,
+        amount: Decimal
+    },
+    output: type Bucket,
+    manifest_input: struct {
+        claimant: ComponentAddress,
+        resource_address: ResourceAddress,
+        amount: Decimal
+    }
+}
+
+define_invocation! {
+    blueprint_name: AccountLocker,
+    function_name: recover,
+    input: struct {
+        claimant: Global<AccountObjectTypeInfo>,
+        resource_address: ResourceAddress,
+        amount: Decimal
+    },
+    output: type Bucket,
+    manifest_input: struct {
+        claimant: ComponentAddress,
+        resource_address: ResourceAddress,
+        amount: Decimal
+    }
+}
+
+define_invocation! {
+    blueprint_name: AccountLocker,
+    function_name: get_amount,
+    input: struct {
+        claimant: Global<AccountObjectTypeInfo>,
+        resource_address: ResourceAddress
+    },
+    output: type Decimal,
+    manifest_input: struct {
+        claimant: ComponentAddress,
+        resource_address: ResourceAddress
+    }
+}
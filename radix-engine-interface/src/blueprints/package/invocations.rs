@@ -87,6 +87,44 @@ pub struct PackageClaimRoyaltiesInput {}
 
 pub type PackageClaimRoyaltiesOutput = Bucket;
 
+pub const PACKAGE_SET_BLUEPRINT_ROYALTY_CLAIMER_IDENT: &str =
+    "PackageRoyalty_set_blueprint_royalty_claimer";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct PackageSetBlueprintRoyaltyClaimerInput {
+    pub blueprint_name: String,
+    /// The rule which must be satisfied to claim the blueprint's royalties
+    pub claimer: AccessRule,
+}
+
+pub type PackageSetBlueprintRoyaltyClaimerManifestInput = PackageSetBlueprintRoyaltyClaimerInput;
+
+pub type PackageSetBlueprintRoyaltyClaimerOutput = ();
+
+pub const PACKAGE_CLAIM_ROYALTIES_FOR_BLUEPRINT_IDENT: &str =
+    "PackageRoyalty_claim_royalties_for_blueprint";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct PackageClaimRoyaltiesForBlueprintInput {
+    pub blueprint_name: String,
+}
+
+pub type PackageClaimRoyaltiesForBlueprintManifestInput = PackageClaimRoyaltiesForBlueprintInput;
+
+pub type PackageClaimRoyaltiesForBlueprintOutput = Bucket;
+
+pub const PACKAGE_DEPRECATE_IDENT: &str = "deprecate";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct PackageDeprecateInput {
+    /// The package integrators should move to instead
+    pub successor: PackageAddress,
+}
+
+pub type PackageDeprecateManifestInput = PackageDeprecateInput;
+
+pub type PackageDeprecateOutput = ();
+
 #[derive(Debug, Clone, Eq, PartialEq, Default, ScryptoSbor, ManifestSbor)]
 pub struct PackageDefinition {
     pub blueprints: IndexMap<String, BlueprintDefinitionInit>,
@@ -6,6 +6,12 @@ use radix_engine_common::math::*;
 use radix_engine_common::prelude::*;
 use radix_engine_common::*;
 
+pub const MULTI_RESOURCE_POOL_BLUEPRINT: &str = "MultiResourcePool";
+
+/// The max number of resources a multi-resource pool can be created with, as contributions and
+/// redemptions go through all of its vaults.
+pub const MULTI_RESOURCE_POOL_MAX_RESOURCES: usize = 20;
+
 define_type_info_marker!(Some(POOL_PACKAGE), MultiResourcePool);
 
 define_invocation! {
@@ -34,7 +40,7 @@ define_invocation! {
     },
     output: type (Bucket, Vec<Bucket>),
     manifest_input: struct {
-        buckets: (ManifestBucket, ManifestBucket)
+        buckets: Vec<ManifestBucket>
     }
 }
 
@@ -37,6 +37,23 @@ impl Clone for AuthZonePushInput {
 
 pub type AuthZonePushOutput = ();
 
+pub const AUTH_ZONE_PUSH_COMPOSITE_IDENT: &str = "push_composite";
+
+#[derive(Debug, Eq, PartialEq, ScryptoSbor)]
+pub struct AuthZonePushCompositeInput {
+    pub proofs: Vec<Proof>,
+}
+
+impl Clone for AuthZonePushCompositeInput {
+    fn clone(&self) -> Self {
+        Self {
+            proofs: self.proofs.iter().map(|proof| Proof(proof.0)).collect(),
+        }
+    }
+}
+
+pub type AuthZonePushCompositeOutput = ();
+
 pub const AUTH_ZONE_CREATE_PROOF_OF_AMOUNT_IDENT: &str = "create_proof_of_amount";
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
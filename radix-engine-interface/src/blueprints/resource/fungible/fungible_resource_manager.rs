@@ -107,6 +107,36 @@ pub struct FungibleResourceManagerCreateWithInitialSupplyManifestInput {
 
 pub type FungibleResourceManagerCreateWithInitialSupplyOutput = (ResourceAddress, Bucket);
 
+pub const FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_MAX_SUPPLY_IDENT: &str = "create_with_max_supply";
+
+/// Creates a resource whose total supply is tracked, and capped at `max_supply`.
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct FungibleResourceManagerCreateWithMaxSupplyInput {
+    pub owner_role: OwnerRole,
+    pub divisibility: u8,
+    pub max_supply: Decimal,
+    pub initial_supply: Option<Decimal>,
+    pub resource_roles: FungibleResourceRoles,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<GlobalAddressReservation>,
+}
+
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct FungibleResourceManagerCreateWithMaxSupplyManifestInput {
+    pub owner_role: OwnerRole,
+    pub divisibility: u8,
+    pub max_supply: Decimal,
+    pub initial_supply: Option<Decimal>,
+    pub resource_roles: FungibleResourceRoles,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<ManifestAddressReservation>,
+}
+
+/// The bucket holds the initial supply, if any was requested.
+pub type FungibleResourceManagerCreateWithMaxSupplyOutput = (ResourceAddress, Option<Bucket>);
+
 pub const FUNGIBLE_RESOURCE_MANAGER_MINT_IDENT: &str = "mint";
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
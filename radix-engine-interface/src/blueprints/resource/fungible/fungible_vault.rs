@@ -1,5 +1,6 @@
 use crate::blueprints::resource::Proof;
 use crate::math::*;
+use crate::types::InternalAddress;
 use crate::*;
 use sbor::rust::prelude::*;
 
@@ -15,6 +16,18 @@ pub struct FungibleVaultLockFeeInput {
 
 pub type FungibleVaultLockFeeOutput = ();
 
+pub const FUNGIBLE_VAULT_LOCK_FEE_WITH_REFUND_IDENT: &str = "lock_fee_with_refund";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct FungibleVaultLockFeeWithRefundInput {
+    pub amount: Decimal,
+    pub contingent: bool,
+    /// The XRD vault the unused part of the fee is refunded to
+    pub refund_vault: InternalAddress,
+}
+
+pub type FungibleVaultLockFeeWithRefundOutput = ();
+
 pub const FUNGIBLE_VAULT_LOCK_FUNGIBLE_AMOUNT_IDENT: &str = "lock_amount";
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
@@ -2,7 +2,7 @@ use crate::blueprints::resource::*;
 use crate::*;
 #[cfg(feature = "radix_engine_fuzzing")]
 use arbitrary::{Arbitrary, Result, Unstructured};
-use radix_engine_common::data::manifest::model::ManifestAddressReservation;
+use radix_engine_common::data::manifest::model::{ManifestAddressReservation, ManifestBlobRef};
 use radix_engine_common::data::manifest::ManifestValue;
 use radix_engine_common::data::scrypto::{
     ScryptoCustomTypeKind, ScryptoValue, VersionedScryptoSchema,
@@ -196,6 +196,54 @@ pub struct NonFungibleResourceManagerCreateRuidWithInitialSupplyGenericInput<S,
 
 pub type NonFungibleResourceManagerCreateRuidWithInitialSupplyOutput = (ResourceAddress, Bucket);
 
+pub const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_MAX_SUPPLY_IDENT: &str =
+    "create_with_max_supply";
+
+/// Creates a resource whose total supply is tracked, and capped at `max_supply`.
+/// Initial entries can't be provided for RUID resources.
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleResourceManagerCreateWithMaxSupplyInput {
+    pub owner_role: OwnerRole,
+    pub id_type: NonFungibleIdType,
+    pub non_fungible_schema: NonFungibleDataSchema,
+    pub max_supply: Decimal,
+    pub entries: Option<IndexMap<NonFungibleLocalId, (ScryptoValue,)>>,
+    pub resource_roles: NonFungibleResourceRoles,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<GlobalAddressReservation>,
+}
+
+/// For manifest
+#[cfg_attr(feature = "radix_engine_fuzzing", derive(Arbitrary))]
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct NonFungibleResourceManagerCreateWithMaxSupplyManifestInput {
+    pub owner_role: OwnerRole,
+    pub id_type: NonFungibleIdType,
+    pub non_fungible_schema: NonFungibleDataSchema,
+    pub max_supply: Decimal,
+    pub entries: Option<IndexMap<NonFungibleLocalId, (ManifestValue,)>>,
+    pub resource_roles: NonFungibleResourceRoles,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<ManifestAddressReservation>,
+}
+
+/// For typed value, to skip any codec
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleResourceManagerCreateWithMaxSupplyGenericInput<S, T> {
+    pub owner_role: OwnerRole,
+    pub id_type: NonFungibleIdType,
+    pub non_fungible_schema: S,
+    pub max_supply: Decimal,
+    pub entries: Option<IndexMap<NonFungibleLocalId, (T,)>>,
+    pub resource_roles: NonFungibleResourceRoles,
+    pub metadata: ModuleConfig<MetadataInit>,
+    pub address_reservation: Option<GlobalAddressReservation>,
+}
+
+/// The bucket holds the initial entries, if any were provided.
+pub type NonFungibleResourceManagerCreateWithMaxSupplyOutput = (ResourceAddress, Option<Bucket>);
+
 pub const NON_FUNGIBLE_RESOURCE_MANAGER_UPDATE_DATA_IDENT: &str = "update_non_fungible_data";
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
@@ -301,6 +349,41 @@ pub struct NonFungibleResourceManagerMintSingleRuidGenericInput<T> {
 
 pub type NonFungibleResourceManagerMintSingleRuidOutput = (Bucket, NonFungibleLocalId);
 
+pub const NON_FUNGIBLE_RESOURCE_MANAGER_MINT_WITH_BLOBS_IDENT: &str = "mint_with_blobs";
+
+/// Mints non-fungibles along with the large, immutable blobs their data refers to.
+/// Each blob is keyed by the hash of its content, which is checked at mint time.
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleResourceManagerMintWithBlobsInput {
+    pub entries: IndexMap<NonFungibleLocalId, (ScryptoValue,)>,
+    pub blobs: IndexMap<Hash, Vec<u8>>,
+}
+
+/// For manifest
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct NonFungibleResourceManagerMintWithBlobsManifestInput {
+    pub entries: IndexMap<NonFungibleLocalId, (ManifestValue,)>,
+    pub blobs: IndexMap<Hash, ManifestBlobRef>,
+}
+
+/// For typed value, to skip any codec
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleResourceManagerMintWithBlobsGenericInput<T> {
+    pub entries: IndexMap<NonFungibleLocalId, (T,)>,
+    pub blobs: IndexMap<Hash, Vec<u8>>,
+}
+
+pub type NonFungibleResourceManagerMintWithBlobsOutput = Bucket;
+
+pub const NON_FUNGIBLE_RESOURCE_MANAGER_GET_NON_FUNGIBLE_BLOB_IDENT: &str = "get_non_fungible_blob";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor, ManifestSbor)]
+pub struct NonFungibleResourceManagerGetNonFungibleBlobInput {
+    pub hash: Hash,
+}
+
+pub type NonFungibleResourceManagerGetNonFungibleBlobOutput = Option<Vec<u8>>;
+
 pub const NON_FUNGIBLE_DATA_SCHEMA_VARIANT_LOCAL: u8 = 0;
 pub const NON_FUNGIBLE_DATA_SCHEMA_VARIANT_REMOTE: u8 = 1;
 
@@ -25,6 +25,26 @@ pub struct NonFungibleVaultGetNonFungibleLocalIdsInput {
 
 pub type NonFungibleVaultGetNonFungibleLocalIdsOutput = IndexSet<NonFungibleLocalId>;
 
+pub const NON_FUNGIBLE_VAULT_GET_NON_FUNGIBLE_LOCAL_IDS_PAGE_IDENT: &str =
+    "get_non_fungible_local_ids_page";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleVaultGetNonFungibleLocalIdsPageInput {
+    pub limit: u32,
+    /// The `next_cursor` of the previous page, or `None` for the first page.
+    pub cursor: Option<NonFungibleLocalId>,
+}
+
+pub type NonFungibleVaultGetNonFungibleLocalIdsPageOutput = NonFungibleLocalIdsPage;
+
+/// A page of the non-fungible local IDs held by a vault.
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct NonFungibleLocalIdsPage {
+    pub ids: IndexSet<NonFungibleLocalId>,
+    /// The cursor of the next page, or `None` if the end of the vault has been reached.
+    pub next_cursor: Option<NonFungibleLocalId>,
+}
+
 pub const NON_FUNGIBLE_VAULT_CONTAINS_NON_FUNGIBLE_IDENT: &str = "contains_non_fungible";
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
@@ -135,6 +135,11 @@ pub enum AccessRuleNode {
     ProofRule(ProofRule),
     AnyOf(Vec<AccessRuleNode>),
     AllOf(Vec<AccessRuleNode>),
+    /// Satisfied if the summed weights of the satisfied nodes reach the threshold.
+    WeightedThreshold {
+        threshold: u32,
+        weights: Vec<(AccessRuleNode, u32)>,
+    },
 }
 
 impl Describe<ScryptoCustomTypeKind> for AccessRuleNode {
@@ -212,6 +217,21 @@ where
     AccessRuleNode::ProofRule(ProofRule::CountOf(count.into(), list.list))
 }
 
+/// Requires the weights of the satisfied rules to sum up to at least `threshold`, e.g.
+/// `rule!(require_weighted_threshold(3, vec![(require(a), 2), (require(b), 1), (require(c), 1)]))`.
+pub fn require_weighted_threshold<T>(threshold: u32, weights: Vec<(T, u32)>) -> AccessRuleNode
+where
+    T: Into<AccessRuleNode>,
+{
+    AccessRuleNode::WeightedThreshold {
+        threshold,
+        weights: weights
+            .into_iter()
+            .map(|(node, weight)| (node.into(), weight))
+            .collect(),
+    }
+}
+
 pub fn require_amount<D, T>(amount: D, resource: T) -> AccessRuleNode
 where
     D: Into<Decimal>,
@@ -290,6 +310,11 @@ impl AccessRuleNode {
                     node.dfs_traverse_recursive(visitor, depth + 1)?;
                 }
             }
+            AccessRuleNode::WeightedThreshold { weights, .. } => {
+                for (node, _) in weights {
+                    node.dfs_traverse_recursive(visitor, depth + 1)?;
+                }
+            }
         }
 
         Ok(())
@@ -83,6 +83,13 @@ pub struct ResourceManagerGetTotalSupplyInput {}
 
 pub type ResourceManagerGetTotalSupplyOutput = Option<Decimal>;
 
+pub const RESOURCE_MANAGER_GET_MAX_SUPPLY_IDENT: &str = "get_max_supply";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct ResourceManagerGetMaxSupplyInput {}
+
+pub type ResourceManagerGetMaxSupplyOutput = Option<Decimal>;
+
 pub const RESOURCE_MANAGER_GET_AMOUNT_FOR_WITHDRAWAL_IDENT: &str = "amount_for_withdrawal";
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
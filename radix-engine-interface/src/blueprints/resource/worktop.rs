@@ -116,6 +116,22 @@ pub struct WorktopAssertContainsNonFungiblesInput {
 
 pub type WorktopAssertContainsNonFungiblesOutput = ();
 
+pub const WORKTOP_ASSERT_RESOURCES_ONLY_IDENT: &str = "Worktop_assert_resources_only";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct WorktopAssertResourcesOnlyInput {
+    pub resource_addresses: IndexSet<ResourceAddress>,
+}
+
+pub type WorktopAssertResourcesOnlyOutput = ();
+
+pub const WORKTOP_CONTENTS_IDENT: &str = "Worktop_contents";
+
+#[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
+pub struct WorktopContentsInput {}
+
+pub type WorktopContentsOutput = IndexMap<ResourceAddress, Decimal>;
+
 pub const WORKTOP_DRAIN_IDENT: &str = "Worktop_drain";
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
@@ -1,8 +1,25 @@
-use radix_engine_common::data::scrypto::{ScryptoDecode, ScryptoDescribe, ScryptoEncode};
+use crate::types::EventTypeIdentifier;
+use radix_engine_common::data::scrypto::{
+    scrypto_decode, ScryptoDecode, ScryptoDescribe, ScryptoEncode,
+};
 
 pub trait ScryptoEvent
 where
     Self: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
 {
     const EVENT_NAME: &'static str;
+
+    /// Decodes an emitted event (e.g. from a transaction receipt) as this event type.
+    ///
+    /// Returns `None` if the event has a different name, or if it can't be decoded.
+    fn try_decode_event(
+        event_type_identifier: &EventTypeIdentifier,
+        event_data: &[u8],
+    ) -> Option<Self> {
+        if event_type_identifier.1 == Self::EVENT_NAME {
+            scrypto_decode(event_data).ok()
+        } else {
+            None
+        }
+    }
 }
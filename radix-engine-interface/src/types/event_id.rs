@@ -2,8 +2,10 @@ use crate::api::ModuleId;
 use crate::types::BlueprintId;
 use crate::ScryptoSbor;
 use radix_engine_common::address::AddressDisplayContext;
+use radix_engine_common::prelude::*;
 use radix_engine_common::types::NodeId;
 use sbor::rust::fmt;
+use sbor::rust::ops::Deref;
 use sbor::rust::string::String;
 use utils::ContextualDisplay;
 
@@ -61,3 +63,65 @@ impl EventTypeIdentifier {
         emitter_size + self.1.len()
     }
 }
+
+/// The schema type name of [`Indexed`] event fields.
+pub const INDEXED_EVENT_FIELD_TYPE_NAME: &str = "IndexedEventField";
+/// The schema field name of the value wrapped by an [`Indexed`] event field.
+///
+/// Together with [`INDEXED_EVENT_FIELD_TYPE_NAME`], this marks the type in event schemas, so that
+/// an unrelated type which happens to be called `Indexed` is not mistaken for an indexed field.
+pub const INDEXED_EVENT_FIELD_VALUE_NAME: &str = "indexed_event_field_value";
+
+/// Marks a field of an event struct as indexed.
+///
+/// The hashes of the values of indexed fields are included in the event index of the
+/// transaction receipt, which allows filtering events without decoding their payloads.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    ScryptoCategorize,
+    ScryptoEncode,
+    ScryptoDecode,
+)]
+pub struct Indexed<T>(pub T);
+
+impl<T: Describe<ScryptoCustomTypeKind>> Describe<ScryptoCustomTypeKind> for Indexed<T> {
+    const TYPE_ID: RustTypeId = RustTypeId::novel(INDEXED_EVENT_FIELD_TYPE_NAME, &[T::TYPE_ID]);
+
+    fn type_data() -> ScryptoTypeData<RustTypeId> {
+        TypeData::struct_with_named_fields(
+            INDEXED_EVENT_FIELD_TYPE_NAME,
+            vec![(INDEXED_EVENT_FIELD_VALUE_NAME, T::TYPE_ID)],
+        )
+    }
+
+    fn add_all_dependencies(aggregator: &mut TypeAggregator<ScryptoCustomTypeKind>) {
+        aggregator.add_child_type_and_descendents::<T>();
+    }
+}
+
+impl<T> Indexed<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> Deref for Indexed<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> From<T> for Indexed<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
@@ -1,6 +1,7 @@
 use core::cell::RefCell;
 use radix_engine_common::data::scrypto::*;
 use radix_engine_common::types::*;
+use sbor::path::query::{SborPathQuery, SborPathQueryError};
 use sbor::representations::*;
 use sbor::rust::cell::Ref;
 use sbor::rust::fmt;
@@ -100,6 +101,60 @@ impl IndexedScryptoValue {
         Self::new(vec)
     }
 
+    /// Validates and indexes a payload in a single streamed pass over the source.
+    ///
+    /// Unlike [`from_vec`](Self::from_vec), this doesn't require the payload to be read into memory
+    /// before validation starts, so an invalid payload is rejected as soon as the error is reached.
+    pub fn from_reader<S: ByteSource>(source: S) -> Result<Self, DecodeError> {
+        let mut bytes = Vec::new();
+        let mut references = Vec::<NodeId>::new();
+        let mut owned_nodes = Vec::<NodeId>::new();
+        {
+            let mut traverser = ScryptoStreamTraverser::new(
+                RecordingByteSource::new(source, &mut bytes),
+                SCRYPTO_SBOR_V1_MAX_DEPTH,
+                ExpectedStart::PayloadPrefix(SCRYPTO_SBOR_V1_PAYLOAD_PREFIX),
+                true,
+            );
+            loop {
+                let event = traverser.next_event();
+                match event.event {
+                    StreamTraversalEvent::ContainerStart(_) => {}
+                    StreamTraversalEvent::ContainerEnd(_) => {}
+                    StreamTraversalEvent::TerminalValue(value) => {
+                        if let ScryptoValue::Custom { value } = value {
+                            match value {
+                                ScryptoCustomValue::Reference(node_id) => {
+                                    references.push(node_id.0.into());
+                                }
+                                ScryptoCustomValue::Own(node_id) => {
+                                    owned_nodes.push(node_id.0.into());
+                                }
+                                ScryptoCustomValue::Decimal(_)
+                                | ScryptoCustomValue::PreciseDecimal(_)
+                                | ScryptoCustomValue::NonFungibleLocalId(_) => {}
+                            }
+                        }
+                    }
+                    StreamTraversalEvent::TerminalValueBatch(_) => {}
+                    StreamTraversalEvent::End => {
+                        break;
+                    }
+                    StreamTraversalEvent::DecodeError(e) => {
+                        return Err(e);
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            bytes,
+            references,
+            owned_nodes,
+            scrypto_value: RefCell::new(None),
+        })
+    }
+
     pub fn to_scrypto_value(&self) -> ScryptoValue {
         self.get_scrypto_value().clone()
     }
@@ -108,6 +163,32 @@ impl IndexedScryptoValue {
         self.get_scrypto_value()
     }
 
+    /// Evaluates the path query against the value, without resolving any field names.
+    pub fn query(&self, query: &SborPathQuery) -> Result<Ref<ScryptoValue>, SborPathQueryError> {
+        let value = self.get_scrypto_value();
+        query.evaluate(&value)?;
+        Ok(Ref::map(value, |value| {
+            query.evaluate(value).expect("Checked above")
+        }))
+    }
+
+    /// Evaluates the path query against the value of the given type, resolving field names from
+    /// the schema.
+    pub fn query_with_schema(
+        &self,
+        query: &SborPathQuery,
+        schema: &Schema<ScryptoCustomSchema>,
+        type_id: LocalTypeId,
+    ) -> Result<Ref<ScryptoValue>, SborPathQueryError> {
+        let value = self.get_scrypto_value();
+        query.evaluate_with_schema(&value, schema, type_id)?;
+        Ok(Ref::map(value, |value| {
+            query
+                .evaluate_with_schema(value, schema, type_id)
+                .expect("Checked above")
+        }))
+    }
+
     pub fn as_typed<T: ScryptoDecode>(&self) -> Result<T, DecodeError> {
         scrypto_decode(&self.bytes)
     }
@@ -171,3 +252,84 @@ impl<'s, 'a> ContextualDisplay<ValueDisplayParameters<'s, 'a, ScryptoCustomExten
         ScryptoRawPayload::new_from_valid_slice(self.as_slice()).format(f, *context)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use radix_engine_common::constants::XRD;
+    use radix_engine_common::data::scrypto::model::{Own, Reference};
+
+    fn payload() -> Vec<u8> {
+        let owned_node_id = NodeId([EntityType::InternalFungibleVault as u8; NodeId::LENGTH]);
+        scrypto_encode(&(
+            Reference(XRD.into_node_id()),
+            vec![Own(owned_node_id)],
+            "value".to_string(),
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_from_reader_matches_from_slice() {
+        let bytes = payload();
+
+        let value = IndexedScryptoValue::from_reader(bytes.as_slice()).unwrap();
+
+        assert_eq!(value, IndexedScryptoValue::from_slice(&bytes).unwrap());
+        assert_eq!(value.as_slice(), bytes.as_slice());
+        assert_eq!(value.references(), &vec![XRD.into_node_id()]);
+        assert_eq!(
+            value.owned_nodes(),
+            &vec![NodeId(
+                [EntityType::InternalFungibleVault as u8; NodeId::LENGTH]
+            )]
+        );
+    }
+
+    #[test]
+    fn test_from_reader_rejects_invalid_payloads() {
+        let bytes = payload();
+
+        assert!(matches!(
+            IndexedScryptoValue::from_reader(&bytes[..bytes.len() - 1]),
+            Err(DecodeError::BufferUnderflow { .. })
+        ));
+
+        let mut trailing_bytes = bytes.clone();
+        trailing_bytes.push(0);
+        assert_eq!(
+            IndexedScryptoValue::from_reader(trailing_bytes.as_slice()),
+            Err(DecodeError::ExtraTrailingBytes(1))
+        );
+
+        let mut wrong_prefix = bytes.clone();
+        wrong_prefix[0] = SCRYPTO_SBOR_V1_PAYLOAD_PREFIX + 1;
+        assert_eq!(
+            IndexedScryptoValue::from_reader(wrong_prefix.as_slice()),
+            Err(DecodeError::UnexpectedPayloadPrefix {
+                expected: SCRYPTO_SBOR_V1_PAYLOAD_PREFIX,
+                actual: SCRYPTO_SBOR_V1_PAYLOAD_PREFIX + 1,
+            })
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_from_reader_reports_io_errors() {
+        struct FailingReader;
+
+        impl std::io::Read for FailingReader {
+            fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::new(
+                    std::io::ErrorKind::Other,
+                    "disk failure",
+                ))
+            }
+        }
+
+        assert_eq!(
+            IndexedScryptoValue::from_reader(IoByteSource::new(FailingReader)),
+            Err(DecodeError::ByteSourceError)
+        );
+    }
+}
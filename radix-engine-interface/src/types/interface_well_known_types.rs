@@ -30,7 +30,7 @@ mod tests {
         let access_rule = AccessRule::Protected(access_rule_node.clone());
 
         test_equivalence(ACCESS_RULE_TYPE, access_rule);
-        test_equivalence(ACCESS_RULE_NODE_TYPE, access_rule_node);
+        test_equivalence(ACCESS_RULE_NODE_TYPE, access_rule_node.clone());
         test_statically_valid(ACCESS_RULE_NODE_LIST_TYPE, access_rule_node_list);
         test_equivalence(PROOF_RULE_TYPE, proof_rule);
         test_equivalence(RESOURCE_OR_NON_FUNGIBLE_TYPE, resource_or_non_fungible_1);
@@ -41,6 +41,22 @@ mod tests {
         );
         test_equivalence(OWNER_ROLE_TYPE, OwnerRole::None);
         test_equivalence(ROLE_KEY_TYPE, RoleKey::from("MyRoleName"));
+        let weighted_access_rule_node = (access_rule_node.clone(), 2u32);
+        test_statically_valid(
+            WEIGHTED_ACCESS_RULE_NODE_TYPE,
+            weighted_access_rule_node.clone(),
+        );
+        test_statically_valid(
+            WEIGHTED_ACCESS_RULE_NODE_LIST_TYPE,
+            vec![weighted_access_rule_node.clone()],
+        );
+        test_equivalence(
+            ACCESS_RULE_NODE_TYPE,
+            AccessRuleNode::WeightedThreshold {
+                threshold: 2,
+                weights: vec![weighted_access_rule_node],
+            },
+        );
 
         // OTHER MODULE TYPES
         test_equivalence(MODULE_ID_TYPE, ModuleId::Main);
@@ -34,6 +34,7 @@ pub const ROYALTY_CONFIG_PARTITION_OFFSET: PartitionOffset = PartitionOffset(1u8
 #[derive(Debug, Copy, Clone, Sbor, PartialEq, Eq, Hash, PartialOrd, Ord, FromRepr)]
 pub enum RoyaltyField {
     RoyaltyAccumulator,
+    RoyaltyTransactionLimits,
 }
 
 pub const ROLE_ASSIGNMENT_BASE_PARTITION: PartitionNumber = PartitionNumber(5u8);
@@ -168,6 +169,8 @@ blueprint_partition_offset!(
         CodeVmTypeKeyValue,
         CodeOriginalCodeKeyValue,
         CodeInstrumentedCodeKeyValue,
+        DeprecationKeyValue,
+        BlueprintRoyaltyVaultKeyValue,
     }
 );
 
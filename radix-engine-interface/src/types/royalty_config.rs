@@ -15,6 +15,31 @@ pub struct ComponentRoyaltyConfig {
     pub royalty_amounts: IndexMap<String, (RoyaltyAmount, bool)>,
 }
 
+/// Royalty rules, with limits on the royalties charged within a single transaction
+#[cfg_attr(
+    feature = "radix_engine_fuzzing",
+    derive(Arbitrary, serde::Serialize, serde::Deserialize)
+)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, ScryptoSbor, ManifestSbor)]
+pub struct ComponentRoyaltyConfigV2 {
+    pub royalty_amounts: IndexMap<String, (RoyaltyAmount, bool)>,
+    /// The maximum total royalty charged by the component within a single transaction, if any
+    pub max_royalty_per_transaction: Option<RoyaltyAmount>,
+    /// The number of royalty-bearing method calls to the component which are free of charge in
+    /// each transaction
+    pub free_calls_per_transaction: u32,
+}
+
+impl From<ComponentRoyaltyConfig> for ComponentRoyaltyConfigV2 {
+    fn from(value: ComponentRoyaltyConfig) -> Self {
+        Self {
+            royalty_amounts: value.royalty_amounts,
+            max_royalty_per_transaction: None,
+            free_calls_per_transaction: 0,
+        }
+    }
+}
+
 /// Royalty rules
 #[cfg_attr(
     feature = "radix_engine_fuzzing",
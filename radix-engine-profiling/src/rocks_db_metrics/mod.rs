@@ -56,7 +56,7 @@ impl SubstateStoreWithMetrics<RocksDBWithMerkleTreeSubstateStore> {
         opt.set_block_based_table_factory(&factory_opts);
 
         Self {
-            db: RocksDBWithMerkleTreeSubstateStore::with_options(&opt, path, true),
+            db: RocksDBWithMerkleTreeSubstateStore::with_options(&opt, path, true, false),
             commit_set_metrics: RefCell::new(BTreeMap::new()),
             commit_delete_metrics: RefCell::new(BTreeMap::new()),
             read_metrics: RefCell::new(BTreeMap::new()),
@@ -108,6 +108,14 @@ impl<S: SubstateDatabase + CommittableSubstateDatabase> SubstateDatabase
     ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
         self.db.list_entries(partition_key)
     }
+
+    fn list_entries_from(
+        &self,
+        partition_key: &DbPartitionKey,
+        from_sort_key: Option<&DbSortKey>,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        self.db.list_entries_from(partition_key, from_sort_key)
+    }
 }
 
 impl<S: SubstateDatabase + CommittableSubstateDatabase> CommittableSubstateDatabase
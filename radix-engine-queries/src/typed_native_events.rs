@@ -4,6 +4,7 @@
 
 use crate::typed_substate_layout::*;
 use radix_engine::blueprints::account;
+use radix_engine::blueprints::locker;
 use radix_engine::blueprints::native_schema::*;
 use radix_engine::types::*;
 
@@ -79,6 +80,12 @@ fn resolve_typed_event_key_from_event_type_identifier(
                 &event_name,
             )
             .map(TypedNativeEventKey::from),
+            LOCKER_PACKAGE => TypedLockerPackageEventKey::new(
+                &LOCKER_PACKAGE_DEFINITION,
+                &blueprint_id.blueprint_name,
+                &event_name,
+            )
+            .map(TypedNativeEventKey::from),
             TRANSACTION_PROCESSOR_PACKAGE => TypedTransactionProcessorPackageEventKey::new(
                 &TRANSACTION_PROCESSOR_PACKAGE_DEFINITION,
                 &blueprint_id.blueprint_name,
@@ -156,6 +163,9 @@ fn resolve_typed_event_key_from_event_type_identifier(
                 TypedMultiResourcePoolBlueprintEventKey::new(&event_name)
                     .map(TypedNativeEventKey::from)
             }
+            EntityType::GlobalAccountLocker => {
+                TypedAccountLockerBlueprintEventKey::new(&event_name).map(TypedNativeEventKey::from)
+            }
             EntityType::GlobalFungibleResourceManager => {
                 TypedFungibleResourceManagerBlueprintEventKey::new(&event_name)
                     .map(TypedNativeEventKey::from)
@@ -216,7 +226,8 @@ define_structure! {
     ConsensusManager => {
         ConsensusManager => [
             RoundChangeEvent,
-            EpochChangeEvent
+            EpochChangeEvent,
+            ConfigChangeEvent
         ],
         Validator => [
             RegisterValidatorEvent,
@@ -250,6 +261,13 @@ define_structure! {
             MultiResourcePoolDepositEvent,
         ],
     },
+    Locker => {
+        AccountLocker => [
+            AccountLockerStoreEvent,
+            AccountLockerClaimEvent,
+            AccountLockerRecoverEvent,
+        ]
+    },
     Resource => {
         FungibleVault => [
             FungibleVaultLockFeeEvent,
@@ -285,6 +303,7 @@ define_structure! {
     RoleAssignment => {
         RoleAssignment => [
             SetRoleEvent,
+            SetRolesEvent,
             SetOwnerRoleEvent,
             LockOwnerRoleEvent,
         ]
@@ -335,6 +354,10 @@ type AccountSetDefaultDepositRuleEvent = account::SetDefaultDepositRuleEvent;
 type AccountAddAuthorizedDepositorEvent = account::AddAuthorizedDepositorEvent;
 type AccountRemoveAuthorizedDepositorEvent = account::RemoveAuthorizedDepositorEvent;
 
+type AccountLockerStoreEvent = locker::StoreEvent;
+type AccountLockerClaimEvent = locker::ClaimEvent;
+type AccountLockerRecoverEvent = locker::RecoverEvent;
+
 /// This enum uses some special syntax to define the structure of events. This makes the code for
 /// model definitions very compact, allows for very easy addition of more packages, blueprints or
 /// events in the future, keeps various models all in sync, and implements various functions and
@@ -5,6 +5,13 @@ pub use radix_engine::blueprints::access_controller::*;
 pub use radix_engine::blueprints::account::{AccountBlueprint, AccountError, AccountNativePackage};
 use radix_engine::blueprints::account::{AccountTypedSubstateKey, AccountTypedSubstateValue};
 pub use radix_engine::blueprints::consensus_manager::*;
+pub use radix_engine::blueprints::locker::{
+    AccountLockerBlueprint, AccountLockerClaimKey, AccountLockerError, AccountLockerSubstate,
+    LockerNativePackage,
+};
+use radix_engine::blueprints::locker::{
+    AccountLockerPartitionOffset, AccountLockerTypedSubstateKey, AccountLockerTypedSubstateValue,
+};
 pub use radix_engine::blueprints::package::*;
 pub use radix_engine::blueprints::pool::multi_resource_pool;
 use radix_engine::blueprints::pool::multi_resource_pool::{
@@ -146,6 +153,7 @@ pub enum TypedMainModuleSubstateKey {
     OneResourcePool(OneResourcePoolTypedSubstateKey),
     TwoResourcePool(TwoResourcePoolTypedSubstateKey),
     MultiResourcePool(MultiResourcePoolTypedSubstateKey),
+    AccountLocker(AccountLockerTypedSubstateKey),
     TransactionTrackerField(TransactionTrackerField),
     TransactionTrackerCollectionEntry(IntentHash),
     // Objects - Generic Scrypto Components
@@ -330,6 +338,12 @@ fn to_typed_object_substate_key_internal(
                 substate_key,
             )?,
         ),
+        EntityType::GlobalAccountLocker => TypedMainModuleSubstateKey::AccountLocker(
+            AccountLockerTypedSubstateKey::for_key_in_partition(
+                &AccountLockerPartitionOffset::try_from(partition_offset)?,
+                substate_key,
+            )?,
+        ),
         EntityType::GlobalTransactionTracker => {
             if partition_offset == PartitionOffset(0) {
                 TypedMainModuleSubstateKey::TransactionTrackerField(
@@ -378,6 +392,7 @@ pub enum TypedRoleAssignmentModuleSubstateValue {
 #[derive(Debug)]
 pub enum TypedRoyaltyModuleSubstateValue {
     ComponentRoyalty(FieldSubstate<ComponentRoyaltyAccumulatorFieldPayload>),
+    ComponentRoyaltyTransactionLimits(FieldSubstate<ComponentRoyaltyTransactionLimitsFieldPayload>),
     ComponentMethodRoyalty(KeyValueEntrySubstate<ComponentRoyaltyMethodAmountEntryPayload>),
 }
 
@@ -402,6 +417,7 @@ pub enum TypedMainModuleSubstateValue {
     OneResourcePool(OneResourcePoolTypedSubstateValue),
     TwoResourcePool(TwoResourcePoolTypedSubstateValue),
     MultiResourcePool(MultiResourcePoolTypedSubstateValue),
+    AccountLocker(AccountLockerTypedSubstateValue),
     TransactionTracker(TypedTransactionTrackerFieldValue),
     TransactionTrackerCollectionEntry(KeyValueEntrySubstate<TransactionStatusSubstateContents>),
     // Generic Scrypto Components and KV Stores
@@ -461,6 +477,11 @@ fn to_typed_substate_value_internal(
                 TypedRoyaltyModuleSubstateKey::RoyaltyField(RoyaltyField::RoyaltyAccumulator) => {
                     TypedRoyaltyModuleSubstateValue::ComponentRoyalty(scrypto_decode(data)?)
                 }
+                TypedRoyaltyModuleSubstateKey::RoyaltyField(
+                    RoyaltyField::RoyaltyTransactionLimits,
+                ) => TypedRoyaltyModuleSubstateValue::ComponentRoyaltyTransactionLimits(
+                    scrypto_decode(data)?,
+                ),
                 TypedRoyaltyModuleSubstateKey::RoyaltyMethodRoyaltyEntryKey(_) => {
                     TypedRoyaltyModuleSubstateValue::ComponentMethodRoyalty(scrypto_decode(data)?)
                 }
@@ -549,6 +570,11 @@ fn to_typed_object_substate_value(
                 MultiResourcePoolTypedSubstateValue::from_key_and_data(key, data)?,
             )
         }
+        TypedMainModuleSubstateKey::AccountLocker(key) => {
+            TypedMainModuleSubstateValue::AccountLocker(
+                AccountLockerTypedSubstateValue::from_key_and_data(key, data)?,
+            )
+        }
 
         TypedMainModuleSubstateKey::TransactionTrackerField(offset) => {
             TypedMainModuleSubstateValue::TransactionTracker(match offset {
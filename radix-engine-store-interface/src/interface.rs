@@ -140,12 +140,46 @@ pub trait SubstateDatabase {
         sort_key: &DbSortKey,
     ) -> Option<DbSubstateValue>;
 
+    /// Reads the values of multiple substates at once, in the order of the given keys, with
+    /// [`Option::None`] for the missing ones.
+    ///
+    /// The default implementation reads the substates one by one; vendors which support batched
+    /// reads should override it.
+    fn get_substates(&self, substate_keys: &[DbSubstateKey]) -> Vec<Option<DbSubstateValue>> {
+        substate_keys
+            .iter()
+            .map(|(partition_key, sort_key)| self.get_substate(partition_key, sort_key))
+            .collect()
+    }
+
     /// Iterates over all entries of the given partition, in a lexicographical order (ascending)
     /// of the [`DbSortKey`]s.
     fn list_entries(
         &self,
         partition_key: &DbPartitionKey,
     ) -> Box<dyn Iterator<Item = PartitionEntry> + '_>;
+
+    /// Iterates over the entries of the given partition, starting from the given [`DbSortKey`]
+    /// (inclusive), in a lexicographical order (ascending) of the [`DbSortKey`]s.
+    ///
+    /// The default implementation skips over the preceding entries; vendors which can seek
+    /// directly to the starting key should override it.
+    fn list_entries_from(
+        &self,
+        partition_key: &DbPartitionKey,
+        from_sort_key: Option<&DbSortKey>,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        match from_sort_key {
+            Some(from_sort_key) => {
+                let from_sort_key = from_sort_key.clone();
+                Box::new(
+                    self.list_entries(partition_key)
+                        .skip_while(move |(sort_key, _)| *sort_key < from_sort_key),
+                )
+            }
+            None => self.list_entries(partition_key),
+        }
+    }
 }
 
 /// A write interface between Track and a database vendor.
@@ -159,3 +193,21 @@ pub trait ListableSubstateDatabase {
     /// Iterates over all partition keys, in an arbitrary order.
     fn list_partition_keys(&self) -> Box<dyn Iterator<Item = DbPartitionKey> + '_>;
 }
+
+/// A pruning interface for database vendors which retain historical state (e.g. stale tree nodes
+/// or past substate values).
+pub trait PrunableSubstateDatabase {
+    /// Drops all the historical data which is only needed to serve state versions older than the
+    /// given one. The given version, and all the later ones, remain fully available.
+    fn prune_before_version(&mut self, state_version: u64) -> PruningMetrics;
+}
+
+/// The amounts of data dropped by [`PrunableSubstateDatabase::prune_before_version()`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PruningMetrics {
+    pub pruned_tree_nodes: usize,
+    pub pruned_history_entries: usize,
+    /// The total size of the deleted keys and values (which may differ from the disk space
+    /// eventually reclaimed by the vendor).
+    pub reclaimed_bytes: u64,
+}
@@ -78,7 +78,7 @@ impl InternalNode {
 }
 
 impl Node<Version> {
-    fn from(key: &NodeKey, tree_node: &TreeNode) -> Self {
+    pub fn from(key: &NodeKey, tree_node: &TreeNode) -> Self {
         match tree_node {
             TreeNode::Internal(internal_node) => Node::Internal(InternalNode::from(internal_node)),
             TreeNode::Leaf(leaf_node) => Node::Leaf(LeafNode::from(key, leaf_node)),
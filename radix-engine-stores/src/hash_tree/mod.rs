@@ -1,13 +1,17 @@
 use crate::hash_tree::tree_store::StaleTreePart;
-use crate::hash_tree::types::{LeafKey, LeafNode, SPARSE_MERKLE_PLACEHOLDER_HASH};
+use crate::hash_tree::types::{
+    IteratedLeafKey, LeafKey, LeafNode, SparseMerkleInternalNode, SparseMerkleLeafNode,
+    SPARSE_MERKLE_PLACEHOLDER_HASH,
+};
 use jellyfish::JellyfishMerkleTree;
 use radix_engine_common::crypto::{hash, Hash};
 use radix_engine_store_interface::interface::{
     DatabaseUpdate, DatabaseUpdates, DbNodeKey, DbPartitionKey, DbPartitionNum, DbSortKey,
     DbSubstateValue, NodeDatabaseUpdates, PartitionDatabaseUpdates,
 };
+use sbor::*;
 use tree_store::{ReadableTreeStore, TreeNode, TreeStore, WriteableTreeStore};
-use types::{NibblePath, NodeKey, Version};
+use types::{NibblePath, Node, NodeKey, Version};
 use utils::copy_u8_array;
 use utils::prelude::vec;
 use utils::rust::collections::{index_map_new, IndexMap};
@@ -107,8 +111,337 @@ pub fn list_substate_hashes_at_version<S: ReadableTreeStore>(
     by_db_partition
 }
 
+/// A Merkle proof of a single substate's value, against the root hash of the "3-Tier JMT".
+/// Each tier's part of the proof holds the sibling hashes on the path from that tier's leaf to that
+/// tier's root, ordered from the bottom level to the root level. The leaves themselves are not
+/// needed: each one is determined by the proven substate (i.e. its key and value) and the root hash
+/// of the tier below.
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Sbor)]
+pub struct SubstateInclusionProof {
+    pub substate_tier_siblings: Vec<Hash>,
+    pub partition_tier_siblings: Vec<Hash>,
+    pub node_tier_siblings: Vec<Hash>,
+}
+
+/// Produces a proof that the substate under the given key exists in the "3-Tier JMT" at the given
+/// version. Returns `None` if no such substate exists at that version.
+///
+/// # Panics
+/// Panics if any tree node required by the proof is not found in the storage (e.g. if the given
+/// version has already been pruned).
+pub fn get_substate_inclusion_proof<S: ReadableTreeStore>(
+    node_tier_store: &S,
+    node_root_version: Version,
+    partition_key: &DbPartitionKey,
+    sort_key: &DbSortKey,
+) -> Option<SubstateInclusionProof> {
+    let mut node_tier_store = node_tier_store;
+    let (partition_root_version, node_tier_siblings) =
+        get_tier_leaf_with_siblings(node_tier_store, node_root_version, &partition_key.node_key)?;
+    let mut partition_tier_store =
+        NestedTreeStore::new(&mut node_tier_store, partition_key.node_key.clone());
+    let partition_key_bytes = vec![partition_key.partition_num];
+    let (substate_root_version, partition_tier_siblings) = get_tier_leaf_with_siblings(
+        &partition_tier_store,
+        partition_root_version,
+        &partition_key_bytes,
+    )?;
+    let substate_tier_store = NestedTreeStore::new(&mut partition_tier_store, partition_key_bytes);
+    let (_, substate_tier_siblings) =
+        get_tier_leaf_with_siblings(&substate_tier_store, substate_root_version, &sort_key.0)?;
+    Some(SubstateInclusionProof {
+        substate_tier_siblings,
+        partition_tier_siblings,
+        node_tier_siblings,
+    })
+}
+
+/// Verifies that the given proof (see [`get_substate_inclusion_proof()`]) shows the substate under
+/// the given key to have the given value, in the state represented by the given root hash.
+/// This function does not need any access to the tree.
+pub fn verify_substate_inclusion_proof(
+    root_hash: &Hash,
+    partition_key: &DbPartitionKey,
+    sort_key: &DbSortKey,
+    value: &DbSubstateValue,
+    proof: &SubstateInclusionProof,
+) -> bool {
+    let Some(substate_tier_root_hash) =
+        compute_tier_root_hash(&sort_key.0, hash(value), &proof.substate_tier_siblings)
+    else {
+        return false;
+    };
+    let Some(partition_tier_root_hash) = compute_tier_root_hash(
+        &[partition_key.partition_num],
+        substate_tier_root_hash,
+        &proof.partition_tier_siblings,
+    ) else {
+        return false;
+    };
+    let Some(node_tier_root_hash) = compute_tier_root_hash(
+        &partition_key.node_key,
+        partition_tier_root_hash,
+        &proof.node_tier_siblings,
+    ) else {
+        return false;
+    };
+    node_tier_root_hash == *root_hash
+}
+
+/// An inconsistency found while verifying a "3-Tier JMT" against the substates it represents.
+/// All the tree node keys are physical, i.e. as stored in the `TreeStore`.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum TreeVerificationIssue {
+    /// A tree node referenced by its parent is not found in the storage.
+    MissingNode(NodeKey),
+    /// The hash of a child cached by its parent differs from the hash recomputed from the child.
+    ChildHashMismatch {
+        child: NodeKey,
+        cached: Hash,
+        recomputed: Hash,
+    },
+    /// The hash of a ReNode-Tier or Partition-Tier leaf differs from the recomputed root hash of
+    /// the tree it leads to.
+    LowerTierRootHashMismatch {
+        leaf: NodeKey,
+        cached: Hash,
+        recomputed: Hash,
+    },
+    /// The hash of a Substate-Tier leaf differs from the hash of the stored substate value.
+    SubstateValueHashMismatch {
+        partition_key: DbPartitionKey,
+        sort_key: DbSortKey,
+        cached: Hash,
+        recomputed: Hash,
+    },
+    /// A substate is present in the tree, but not in the substate store.
+    SubstateMissingFromStore {
+        partition_key: DbPartitionKey,
+        sort_key: DbSortKey,
+    },
+    /// A substate is present in the substate store, but not in the tree.
+    SubstateMissingFromTree {
+        partition_key: DbPartitionKey,
+        sort_key: DbSortKey,
+    },
+    /// The root hash recomputed from the substates differs from the expected one.
+    RootHashMismatch { expected: Hash, recomputed: Hash },
+    /// A tree node is neither reachable from the current root, nor awaiting pruning.
+    OrphanedNode(NodeKey),
+    /// A tree node is stored, but its value can't be decoded.
+    CorruptNode(NodeKey),
+    /// A key of the tree node store can't be decoded into a node key.
+    CorruptNodeKey(Vec<u8>),
+    /// A key of the substate store can't be decoded into a substate key.
+    CorruptSubstateKey(Vec<u8>),
+    /// The stale tree parts recorded under the given key can't be decoded.
+    CorruptStaleTreeParts(Vec<u8>),
+}
+
+/// Recomputes all the hashes of the "3-Tier JMT" at the given version bottom-up, starting from the
+/// substate values returned by the given lookup (called once for every substate in the tree), and
+/// reports every hash not matching its recomputed counterpart, as well as every referenced node
+/// missing from the storage.
+/// Returns the recomputed root hash (in which the mismatched hashes are replaced, and the hashes of
+/// missing nodes are kept as cached by their parents) together with the found issues.
+pub fn verify_tree_at_version<S: ReadableTreeStore>(
+    node_tier_store: &S,
+    node_root_version: Version,
+    expected_root_hash: &Hash,
+    get_substate_value: impl FnMut(&DbPartitionKey, &DbSortKey) -> Option<DbSubstateValue>,
+) -> (Hash, Vec<TreeVerificationIssue>) {
+    let mut verifier = TreeVerifier {
+        store: node_tier_store,
+        get_substate_value,
+        issues: Vec::new(),
+    };
+    let recomputed_root_hash = verifier
+        .verify_tier(&[], NodeKey::new_empty_path(node_root_version))
+        .unwrap_or(*expected_root_hash);
+    if recomputed_root_hash != *expected_root_hash {
+        verifier
+            .issues
+            .push(TreeVerificationIssue::RootHashMismatch {
+                expected: *expected_root_hash,
+                recomputed: recomputed_root_hash,
+            });
+    }
+    (recomputed_root_hash, verifier.issues)
+}
+
 // only internals below
 
+struct TreeVerifier<'s, S, F> {
+    store: &'s S,
+    get_substate_value: F,
+    issues: Vec<TreeVerificationIssue>,
+}
+
+impl<'s, S, F> TreeVerifier<'s, S, F>
+where
+    S: ReadableTreeStore,
+    F: FnMut(&DbPartitionKey, &DbSortKey) -> Option<DbSubstateValue>,
+{
+    /// Verifies the subtree under the given key of the tier located by the given leaf keys of the
+    /// higher tiers, returning its recomputed hash, or `None` if the subtree's root is missing.
+    fn verify_tier(&mut self, tier_leaf_keys: &[&[u8]], key: NodeKey) -> Option<Hash> {
+        let physical_key = tier_node_key(tier_leaf_keys, &key);
+        let Some(node) = self.store.get_node(&physical_key) else {
+            self.issues
+                .push(TreeVerificationIssue::MissingNode(physical_key));
+            return None;
+        };
+        let recomputed_hash = match node {
+            TreeNode::Internal(mut internal) => {
+                for child in internal.children.iter_mut() {
+                    let child_key = key.gen_child_node_key(child.version, child.nibble);
+                    let Some(recomputed) = self.verify_tier(tier_leaf_keys, child_key.clone())
+                    else {
+                        continue;
+                    };
+                    if recomputed != child.hash {
+                        self.issues.push(TreeVerificationIssue::ChildHashMismatch {
+                            child: tier_node_key(tier_leaf_keys, &child_key),
+                            cached: child.hash,
+                            recomputed,
+                        });
+                        child.hash = recomputed;
+                    }
+                }
+                Node::<Version>::from(&key, &TreeNode::Internal(internal)).hash()
+            }
+            TreeNode::Leaf(leaf) => {
+                let leaf = LeafNode::<Version>::from(&key, &leaf);
+                let recomputed_value_hash = self.verify_leaf_value(tier_leaf_keys, &key, &leaf);
+                LeafNode::new(
+                    leaf.leaf_key().clone(),
+                    recomputed_value_hash,
+                    leaf.payload().clone(),
+                    leaf.version(),
+                )
+                .leaf_hash()
+            }
+            TreeNode::Null => SPARSE_MERKLE_PLACEHOLDER_HASH,
+        };
+        Some(recomputed_hash)
+    }
+
+    fn verify_leaf_value(
+        &mut self,
+        tier_leaf_keys: &[&[u8]],
+        key: &NodeKey,
+        leaf: &LeafNode<Version>,
+    ) -> Hash {
+        let cached = leaf.value_hash();
+        let leaf_key_bytes = leaf.leaf_key().bytes.as_slice();
+        match tier_leaf_keys {
+            [node_key, partition_num] => {
+                let partition_key = DbPartitionKey {
+                    node_key: node_key.to_vec(),
+                    partition_num: partition_num[0],
+                };
+                let sort_key = DbSortKey(leaf_key_bytes.to_vec());
+                let Some(value) = (self.get_substate_value)(&partition_key, &sort_key) else {
+                    self.issues
+                        .push(TreeVerificationIssue::SubstateMissingFromStore {
+                            partition_key,
+                            sort_key,
+                        });
+                    return cached;
+                };
+                let recomputed = hash(value);
+                if recomputed != cached {
+                    self.issues
+                        .push(TreeVerificationIssue::SubstateValueHashMismatch {
+                            partition_key,
+                            sort_key,
+                            cached,
+                            recomputed,
+                        });
+                }
+                recomputed
+            }
+            _ => {
+                let lower_tier_leaf_keys = [tier_leaf_keys, &[leaf_key_bytes]].concat();
+                let Some(recomputed) = self.verify_tier(
+                    &lower_tier_leaf_keys,
+                    NodeKey::new_empty_path(*leaf.payload()),
+                ) else {
+                    return cached;
+                };
+                if recomputed != cached {
+                    self.issues
+                        .push(TreeVerificationIssue::LowerTierRootHashMismatch {
+                            leaf: tier_node_key(tier_leaf_keys, key),
+                            cached,
+                            recomputed,
+                        });
+                }
+                recomputed
+            }
+        }
+    }
+}
+
+/// Returns the physical key of a node of the tier located by the given leaf keys of the higher
+/// tiers (see [`NestedTreeStore`]).
+fn tier_node_key(tier_leaf_keys: &[&[u8]], key: &NodeKey) -> NodeKey {
+    let mut key_prefix_bytes = Vec::new();
+    for leaf_key in tier_leaf_keys {
+        key_prefix_bytes.extend_from_slice(leaf_key);
+        key_prefix_bytes.push(TIER_SEPARATOR);
+    }
+    prefixed_node_key(key_prefix_bytes, key)
+}
+
+fn prefixed_node_key(key_prefix_bytes: Vec<u8>, key: &NodeKey) -> NodeKey {
+    NodeKey::new(
+        key.version(),
+        NibblePath::from_iter(
+            NibblePath::new_even(key_prefix_bytes)
+                .nibbles()
+                .chain(key.nibble_path().nibbles()),
+        ),
+    )
+}
+
+const TIER_SEPARATOR: u8 = b'_';
+
+fn get_tier_leaf_with_siblings<S: ReadableTreeStore>(
+    tree_store: &S,
+    version: Version,
+    leaf_bytes: &[u8],
+) -> Option<(Version, Vec<Hash>)> {
+    let (leaf_node_data, proof) = JellyfishMerkleTree::new(tree_store)
+        .get_with_proof(&LeafKey::new(leaf_bytes), version)
+        .unwrap();
+    leaf_node_data.map(|(_hash, last_hash_change_version, _version)| {
+        (last_hash_change_version, proof.siblings().to_vec())
+    })
+}
+
+fn compute_tier_root_hash(leaf_bytes: &[u8], value_hash: Hash, siblings: &[Hash]) -> Option<Hash> {
+    let leaf_key = LeafKey::new(leaf_bytes);
+    let path_bits = leaf_key
+        .iter_bits()
+        .take(siblings.len())
+        .collect::<Vec<_>>();
+    if path_bits.len() != siblings.len() {
+        return None;
+    }
+    let leaf_hash = SparseMerkleLeafNode::new(leaf_key, value_hash).hash();
+    Some(siblings.iter().zip(path_bits.into_iter().rev()).fold(
+        leaf_hash,
+        |current_hash, (sibling_hash, is_right)| {
+            if is_right {
+                SparseMerkleInternalNode::new(*sibling_hash, current_hash).hash()
+            } else {
+                SparseMerkleInternalNode::new(current_hash, *sibling_hash).hash()
+            }
+        },
+    ))
+}
+
 fn list_leaves<S: ReadableTreeStore>(
     tree_store: &mut S,
     version: Version,
@@ -326,11 +659,9 @@ struct NestedTreeStore<'s, S> {
 }
 
 impl<'s, S> NestedTreeStore<'s, S> {
-    const TIER_SEPARATOR: u8 = b'_';
-
     pub fn new(underlying: &'s mut S, parent_tier_key_bytes: Vec<u8>) -> NestedTreeStore<'s, S> {
         let mut key_prefix_bytes = parent_tier_key_bytes;
-        key_prefix_bytes.push(Self::TIER_SEPARATOR);
+        key_prefix_bytes.push(TIER_SEPARATOR);
         NestedTreeStore {
             underlying,
             key_prefix_bytes,
@@ -338,14 +669,13 @@ impl<'s, S> NestedTreeStore<'s, S> {
     }
 
     fn prefixed(&self, key: &NodeKey) -> NodeKey {
-        NodeKey::new(
-            key.version(),
-            NibblePath::from_iter(
-                NibblePath::new_even(self.key_prefix_bytes.clone())
-                    .nibbles()
-                    .chain(key.nibble_path().nibbles()),
-            ),
-        )
+        prefixed_node_key(self.key_prefix_bytes.clone(), key)
+    }
+}
+
+impl<'s, S: ReadableTreeStore> ReadableTreeStore for &'s S {
+    fn get_node(&self, key: &NodeKey) -> Option<TreeNode> {
+        (**self).get_node(key)
     }
 }
 
@@ -1,11 +1,14 @@
 use super::types::{Nibble, NibblePath, Version, SPARSE_MERKLE_PLACEHOLDER_HASH};
 use crate::hash_tree::jellyfish::JellyfishMerkleTree;
-use crate::hash_tree::put_at_next_version;
 use crate::hash_tree::tree_store::{
-    SerializedInMemoryTreeStore, StaleTreePart, TreeChildEntry, TreeInternalNode, TreeLeafNode,
-    TreeNode, TreeStore, TypedInMemoryTreeStore,
+    decode_key, encode_key, SerializedInMemoryTreeStore, StaleTreePart, TreeChildEntry,
+    TreeInternalNode, TreeLeafNode, TreeNode, TreeStore, TypedInMemoryTreeStore,
 };
 use crate::hash_tree::types::{LeafKey, NodeKey};
+use crate::hash_tree::{
+    get_substate_inclusion_proof, put_at_next_version, verify_substate_inclusion_proof,
+    verify_tree_at_version, TreeVerificationIssue,
+};
 use itertools::Itertools;
 use radix_engine_common::crypto::{hash, Hash};
 use radix_engine_common::data::scrypto::{scrypto_decode, scrypto_encode};
@@ -418,6 +421,213 @@ fn serialized_keys_are_strictly_increasing() {
     assert!(min_next_key > max_previous_key);
 }
 
+#[test]
+fn substate_inclusion_proof_verifies_against_root_hash() {
+    let mut tester = HashTreeTester::new_empty();
+    let root_hash = tester.put_substate_changes(vec![
+        change_exact(vec![1, 3, 3, 7], 99, vec![253], Some(vec![1])),
+        change_exact(vec![1, 3, 3, 7], 99, vec![66], Some(vec![2])),
+        change_exact(vec![123, 12, 1, 0], 88, vec![6, 6, 6], Some(vec![3])),
+        change_exact(vec![123, 12, 1, 0], 88, vec![6, 6, 7], Some(vec![4])),
+        change_exact(vec![123, 12, 1, 0], 66, vec![1, 2, 3], Some(vec![5])),
+    ]);
+    let partition_key = DbPartitionKey {
+        node_key: vec![123, 12, 1, 0],
+        partition_num: 88,
+    };
+    let sort_key = DbSortKey(vec![6, 6, 7]);
+
+    let proof =
+        get_substate_inclusion_proof(&tester.tree_store, 1, &partition_key, &sort_key).unwrap();
+
+    assert!(verify_substate_inclusion_proof(
+        &root_hash,
+        &partition_key,
+        &sort_key,
+        &vec![4],
+        &proof
+    ));
+    assert!(!verify_substate_inclusion_proof(
+        &root_hash,
+        &partition_key,
+        &sort_key,
+        &vec![5],
+        &proof
+    ));
+    assert!(!verify_substate_inclusion_proof(
+        &root_hash,
+        &partition_key,
+        &DbSortKey(vec![6, 6, 6]),
+        &vec![4],
+        &proof
+    ));
+    assert!(!verify_substate_inclusion_proof(
+        &hash([7]),
+        &partition_key,
+        &sort_key,
+        &vec![4],
+        &proof
+    ));
+}
+
+#[test]
+fn substate_inclusion_proof_is_available_for_historical_versions() {
+    let mut tester = HashTreeTester::new_empty();
+    let hash_v1 = tester.put_substate_changes(vec![change(1, 6, 2, Some(30))]);
+    let hash_v2 =
+        tester.put_substate_changes(vec![change(1, 6, 2, Some(70)), change(3, 7, 1, Some(40))]);
+    let ((partition_key, sort_key), _) = change(1, 6, 2, None);
+
+    let proof_v1 =
+        get_substate_inclusion_proof(&tester.tree_store, 1, &partition_key, &sort_key).unwrap();
+    let proof_v2 =
+        get_substate_inclusion_proof(&tester.tree_store, 2, &partition_key, &sort_key).unwrap();
+
+    assert!(verify_substate_inclusion_proof(
+        &hash_v1,
+        &partition_key,
+        &sort_key,
+        &from_seed(30),
+        &proof_v1
+    ));
+    assert!(verify_substate_inclusion_proof(
+        &hash_v2,
+        &partition_key,
+        &sort_key,
+        &from_seed(70),
+        &proof_v2
+    ));
+    assert!(!verify_substate_inclusion_proof(
+        &hash_v2,
+        &partition_key,
+        &sort_key,
+        &from_seed(30),
+        &proof_v1
+    ));
+}
+
+#[test]
+fn substate_inclusion_proof_is_none_for_missing_substate() {
+    let mut tester = HashTreeTester::new_empty();
+    tester.put_substate_changes(vec![change(1, 6, 2, Some(30))]);
+
+    let ((partition_key, _), _) = change(1, 6, 2, None);
+    let ((other_partition_key, other_sort_key), _) = change(4, 1, 5, None);
+    assert_eq!(
+        get_substate_inclusion_proof(
+            &tester.tree_store,
+            1,
+            &partition_key,
+            &DbSortKey(from_seed(5))
+        ),
+        None
+    );
+    assert_eq!(
+        get_substate_inclusion_proof(&tester.tree_store, 1, &other_partition_key, &other_sort_key),
+        None
+    );
+}
+
+#[test]
+fn decoded_keys_equal_encoded_ones() {
+    for key in [
+        NodeKey::new_empty_path(1),
+        NodeKey::new(7, nibbles("1a3")),
+        NodeKey::new(u64::MAX, nibbles("5f0e")),
+    ] {
+        assert_eq!(decode_key(&encode_key(&key)), key);
+    }
+}
+
+#[test]
+fn tree_verification_finds_no_issues_in_consistent_tree() {
+    let mut tester = HashTreeTester::new_empty();
+    tester.put_substate_changes(vec![change(1, 6, 2, Some(30)), change(3, 7, 1, Some(40))]);
+    let root_hash = tester.put_substate_changes(vec![
+        change(1, 6, 2, Some(70)),
+        change(1, 6, 3, Some(50)),
+        change(3, 7, 1, None),
+    ]);
+    let values = hashmap!(
+        change(1, 6, 2, None).0 => from_seed(70),
+        change(1, 6, 3, None).0 => from_seed(50),
+    );
+
+    let (recomputed_root_hash, issues) = verify_tree_at_version(
+        &tester.tree_store,
+        2,
+        &root_hash,
+        |partition_key, sort_key| {
+            values
+                .get(&(partition_key.clone(), sort_key.clone()))
+                .cloned()
+        },
+    );
+
+    assert_eq!(recomputed_root_hash, root_hash);
+    assert_eq!(issues, vec![]);
+}
+
+#[test]
+fn tree_verification_finds_changed_and_missing_substates() {
+    let mut tester = HashTreeTester::new_empty();
+    let root_hash =
+        tester.put_substate_changes(vec![change(1, 6, 2, Some(30)), change(3, 7, 1, Some(40))]);
+    let ((changed_partition_key, changed_sort_key), _) = change(1, 6, 2, None);
+    let ((missing_partition_key, missing_sort_key), _) = change(3, 7, 1, None);
+
+    let (recomputed_root_hash, issues) =
+        verify_tree_at_version(&tester.tree_store, 1, &root_hash, |partition_key, _| {
+            if *partition_key == changed_partition_key {
+                Some(from_seed(31))
+            } else {
+                None
+            }
+        });
+
+    assert_ne!(recomputed_root_hash, root_hash);
+    let issues = issues.into_iter().collect::<HashSet<_>>();
+    assert!(
+        issues.contains(&TreeVerificationIssue::SubstateValueHashMismatch {
+            partition_key: changed_partition_key,
+            sort_key: changed_sort_key,
+            cached: hash(from_seed(30)),
+            recomputed: hash(from_seed(31)),
+        })
+    );
+    assert!(
+        issues.contains(&TreeVerificationIssue::SubstateMissingFromStore {
+            partition_key: missing_partition_key,
+            sort_key: missing_sort_key,
+        })
+    );
+    assert!(issues.contains(&TreeVerificationIssue::RootHashMismatch {
+        expected: root_hash,
+        recomputed: recomputed_root_hash,
+    }));
+}
+
+#[test]
+fn tree_verification_finds_missing_node() {
+    let mut tester = HashTreeTester::new_empty();
+    let root_hash =
+        tester.put_substate_changes(vec![change(1, 6, 2, Some(30)), change(1, 6, 3, Some(40))]);
+    let leaf_key = tester
+        .tree_store
+        .tree_nodes
+        .iter()
+        .find(|(_, node)| matches!(node, TreeNode::Leaf(_)))
+        .map(|(key, _)| key.clone())
+        .unwrap();
+    tester.tree_store.tree_nodes.remove(&leaf_key);
+
+    let (_, issues) = verify_tree_at_version(&tester.tree_store, 1, &root_hash, |_, _| {
+        Some(from_seed(30))
+    });
+
+    assert!(issues.contains(&TreeVerificationIssue::MissingNode(leaf_key)));
+}
+
 type SingleSubstateChange = (DbSubstateKey, DatabaseUpdate);
 
 fn change(
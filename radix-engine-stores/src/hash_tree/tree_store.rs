@@ -201,6 +201,30 @@ pub fn encode_key(key: &NodeKey) -> Vec<u8> {
     [version_bytes, nibble_path_bytes, parity_byte].concat()
 }
 
+/// Decodes a node key encoded by [`encode_key()`].
+pub fn decode_key(bytes: &[u8]) -> NodeKey {
+    try_decode_key(bytes).expect("Invalid node key encoding")
+}
+
+/// Decodes a node key encoded by [`encode_key()`], or returns `None` if the bytes are not such an
+/// encoding (e.g. due to storage corruption).
+pub fn try_decode_key(bytes: &[u8]) -> Option<NodeKey> {
+    if bytes.len() < 9 {
+        return None;
+    }
+    let (version_bytes, rest) = bytes.split_at(8);
+    let (nibble_path_bytes, parity_byte) = rest.split_at(rest.len() - 1);
+    let version = Version::from_be_bytes(version_bytes.try_into().unwrap());
+    let nibble_path = match parity_byte[0] {
+        0 => NibblePath::new_even(nibble_path_bytes.to_vec()),
+        1 if matches!(nibble_path_bytes.last(), Some(byte) if byte & 0x0F == 0) => {
+            NibblePath::new_odd(nibble_path_bytes.to_vec())
+        }
+        _ => return None,
+    };
+    Some(NodeKey::new(version, nibble_path))
+}
+
 // Note: We need completely custom serialization scheme only for the node keys. The remaining
 // structures can simply use SBOR, with only the most efficiency-sensitive parts having custom
 // codecs, implemented below:
@@ -276,7 +276,7 @@ impl SparseMerkleInternalNode {
         }
     }
 
-    fn hash(&self) -> Hash {
+    pub fn hash(&self) -> Hash {
         hash([self.left_child.0, self.right_child.0].concat())
     }
 }
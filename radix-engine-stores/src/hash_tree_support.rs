@@ -2,8 +2,8 @@ use crate::hash_tree::tree_store::{TypedInMemoryTreeStore, Version};
 use crate::hash_tree::{list_substate_hashes_at_version, put_at_next_version};
 use radix_engine_common::prelude::*;
 use radix_engine_store_interface::interface::{
-    CommittableSubstateDatabase, DatabaseUpdates, DbPartitionKey, DbSortKey, DbSubstateValue,
-    ListableSubstateDatabase, PartitionEntry, SubstateDatabase,
+    CommittableSubstateDatabase, DatabaseUpdates, DbPartitionKey, DbSortKey, DbSubstateKey,
+    DbSubstateValue, ListableSubstateDatabase, PartitionEntry, SubstateDatabase,
 };
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -55,12 +55,25 @@ impl<D: SubstateDatabase> SubstateDatabase for HashTreeUpdatingDatabase<D> {
         self.underlying.get_substate(partition_key, sort_key)
     }
 
+    fn get_substates(&self, substate_keys: &[DbSubstateKey]) -> Vec<Option<DbSubstateValue>> {
+        self.underlying.get_substates(substate_keys)
+    }
+
     fn list_entries(
         &self,
         partition_key: &DbPartitionKey,
     ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
         self.underlying.list_entries(partition_key)
     }
+
+    fn list_entries_from(
+        &self,
+        partition_key: &DbPartitionKey,
+        from_sort_key: Option<&DbSortKey>,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        self.underlying
+            .list_entries_from(partition_key, from_sort_key)
+    }
 }
 
 impl<D: ListableSubstateDatabase> ListableSubstateDatabase for HashTreeUpdatingDatabase<D> {
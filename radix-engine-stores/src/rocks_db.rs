@@ -1,5 +1,6 @@
 use itertools::Itertools;
 use radix_engine_store_interface::interface::*;
+use rocksdb::checkpoint::Checkpoint;
 pub use rocksdb::{BlockBasedOptions, LogLevel, Options};
 use rocksdb::{
     ColumnFamily, ColumnFamilyDescriptor, DBWithThreadMode, Direction, IteratorMode,
@@ -36,6 +37,15 @@ impl RocksdbSubstateStore {
         Self { db }
     }
 
+    /// Creates a consistent, point-in-time copy of this database at the given path, which must
+    /// not exist yet. The copy is a database of its own, and can be opened with [`Self::standard`].
+    ///
+    /// Immutable database files are hard-linked rather than copied when possible, which makes
+    /// checkpoints cheap to create.
+    pub fn create_checkpoint(&self, checkpoint_path: PathBuf) -> Result<(), rocksdb::Error> {
+        Checkpoint::new(&self.db)?.create_checkpoint(checkpoint_path)
+    }
+
     fn cf(&self) -> &ColumnFamily {
         self.db.cf_handle(Self::THE_ONLY_CF).unwrap()
     }
@@ -51,12 +61,33 @@ impl SubstateDatabase for RocksdbSubstateStore {
         self.db.get_cf(self.cf(), &key_bytes).expect("IO Error")
     }
 
+    fn get_substates(&self, substate_keys: &[DbSubstateKey]) -> Vec<Option<DbSubstateValue>> {
+        let cf = self.cf();
+        self.db
+            .multi_get_cf(substate_keys.iter().map(|(partition_key, sort_key)| {
+                (cf, encode_to_rocksdb_bytes(partition_key, sort_key))
+            }))
+            .into_iter()
+            .map(|result| result.expect("IO Error"))
+            .collect()
+    }
+
     fn list_entries(
         &self,
         partition_key: &DbPartitionKey,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        self.list_entries_from(partition_key, None)
+    }
+
+    fn list_entries_from(
+        &self,
+        partition_key: &DbPartitionKey,
+        from_sort_key: Option<&DbSortKey>,
     ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
         let partition_key = partition_key.clone();
-        let start_key_bytes = encode_to_rocksdb_bytes(&partition_key, &DbSortKey(vec![]));
+        let empty_sort_key = DbSortKey(vec![]);
+        let from_sort_key = from_sort_key.unwrap_or(&empty_sort_key);
+        let start_key_bytes = encode_to_rocksdb_bytes(&partition_key, from_sort_key);
         let iter = self
             .db
             .iterator_cf(
@@ -152,13 +183,19 @@ pub fn encode_to_rocksdb_bytes(partition_key: &DbPartitionKey, sort_key: &DbSort
 }
 
 pub fn decode_from_rocksdb_bytes(buffer: &[u8]) -> DbSubstateKey {
+    try_decode_from_rocksdb_bytes(buffer).expect("Invalid substate key encoding")
+}
+
+/// Decodes a substate key encoded by [`encode_to_rocksdb_bytes()`], or returns `None` if the
+/// bytes are not such an encoding (e.g. due to storage corruption).
+pub fn try_decode_from_rocksdb_bytes(buffer: &[u8]) -> Option<DbSubstateKey> {
     let partition_key_len =
-        usize::try_from(u32::from_be_bytes(copy_u8_array(&buffer[..4]))).unwrap();
-    let partition_byte_offset = 4 + partition_key_len;
+        usize::try_from(u32::from_be_bytes(copy_u8_array(buffer.get(..4)?))).ok()?;
+    let partition_byte_offset = 4usize.checked_add(partition_key_len)?;
     let partition_key = DbPartitionKey {
-        node_key: buffer[4..partition_byte_offset].to_vec(),
-        partition_num: buffer[partition_byte_offset],
+        node_key: buffer.get(4..partition_byte_offset)?.to_vec(),
+        partition_num: *buffer.get(partition_byte_offset)?,
     };
     let sort_key = DbSortKey(buffer[partition_byte_offset + 1..].to_vec());
-    (partition_key, sort_key)
+    Some((partition_key, sort_key))
 }
@@ -1,10 +1,18 @@
+pub use crate::hash_tree::{
+    verify_substate_inclusion_proof, SubstateInclusionProof, TreeVerificationIssue,
+};
+
 use crate::hash_tree::tree_store::{
-    encode_key, NodeKey, ReadableTreeStore, StaleTreePart, TreeNode, TreeNodeV1, VersionedTreeNode,
+    encode_key, try_decode_key, NodeKey, ReadableTreeStore, StaleTreePart, TreeNode, TreeNodeV1,
+    VersionedTreeNode,
 };
+use crate::hash_tree::{get_substate_inclusion_proof, verify_tree_at_version};
 use itertools::Itertools;
-use radix_engine_common::data::scrypto::{scrypto_decode, scrypto_encode};
+use radix_engine_common::data::scrypto::{scrypto_decode, scrypto_encode, ScryptoDecode};
 use radix_engine_common::prelude::Hash;
+use radix_engine_common::types::{NodeId, PartitionNumber, SubstateKey};
 use radix_engine_derive::ScryptoSbor;
+use radix_engine_store_interface::db_key_mapper::DatabaseKeyMapper;
 use radix_engine_store_interface::interface::*;
 pub use rocksdb::{BlockBasedOptions, LogLevel, Options};
 use rocksdb::{
@@ -12,20 +20,49 @@ use rocksdb::{
     SingleThreaded, WriteBatch, DB,
 };
 use sbor::prelude::*;
+use std::cell::RefCell;
 use std::path::PathBuf;
 
 mod state_tree;
-use crate::rocks_db::{decode_from_rocksdb_bytes, encode_to_rocksdb_bytes};
+#[cfg(test)]
+mod test;
+use crate::rocks_db::{
+    decode_from_rocksdb_bytes, encode_to_rocksdb_bytes, try_decode_from_rocksdb_bytes,
+};
 use state_tree::*;
 
 const META_CF: &str = "meta";
 const SUBSTATES_CF: &str = "substates";
 const MERKLE_NODES_CF: &str = "merkle_nodes";
 const STALE_MERKLE_TREE_PARTS_CF: &str = "stale_merkle_tree_parts";
+const SUBSTATE_HISTORY_CF: &str = "substate_history";
+
+/// The key (within [`META_CF`]) of the first state version not affected by pruning.
+const PRUNED_BEFORE_VERSION_KEY: &[u8] = b"pruned_before_version";
+/// The key (within [`META_CF`]) of the first state version covered by the substate history.
+const HISTORY_START_VERSION_KEY: &[u8] = b"history_start_version";
+
+/// The number of updates after which the long-running maintenance operations (which go over an
+/// entire column family) write out their batch, rather than buffering everything in memory.
+const MAX_WRITE_BATCH_LEN: usize = 10_000;
 
 pub struct RocksDBWithMerkleTreeSubstateStore {
     db: DBWithThreadMode<SingleThreaded>,
     pruning_enabled: bool,
+    /// The first state version covered by the substate history, if it is enabled.
+    history_start_version: Option<u64>,
+}
+
+/// An error of [`RocksDBWithMerkleTreeSubstateStore::get_substate_at_version()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubstateHistoryError {
+    /// The substate history was never enabled on this database.
+    HistoryNotEnabled,
+    /// The requested version precedes the substate history, as it was only enabled later.
+    VersionBeforeHistoryStart { history_start_version: u64 },
+    /// The requested version's history was dropped by
+    /// [`PrunableSubstateDatabase::prune_before_version()`].
+    VersionPruned { pruned_before_version: u64 },
 }
 
 impl RocksDBWithMerkleTreeSubstateStore {
@@ -42,10 +79,27 @@ impl RocksDBWithMerkleTreeSubstateStore {
         let mut options = Options::default();
         options.create_if_missing(true);
         options.create_missing_column_families(true);
-        Self::with_options(&options, root, true)
+        Self::with_options(&options, root, true, false)
+    }
+
+    /// Like [`Self::standard()`], but also retains every past value of every substate, so that
+    /// it can be read back using [`Self::get_substate_at_version()`].
+    ///
+    /// Once enabled, the history is recorded on every later commit, regardless of the options
+    /// the database is re-opened with - otherwise, it would silently miss the skipped versions.
+    pub fn standard_with_history(root: PathBuf) -> Self {
+        let mut options = Options::default();
+        options.create_if_missing(true);
+        options.create_missing_column_families(true);
+        Self::with_options(&options, root, true, true)
     }
 
-    pub fn with_options(options: &Options, root: PathBuf, pruning_enabled: bool) -> Self {
+    pub fn with_options(
+        options: &Options,
+        root: PathBuf,
+        pruning_enabled: bool,
+        history_enabled: bool,
+    ) -> Self {
         let db = DB::open_cf_descriptors(
             options,
             root.as_path(),
@@ -54,15 +108,72 @@ impl RocksDBWithMerkleTreeSubstateStore {
                 SUBSTATES_CF,
                 MERKLE_NODES_CF,
                 STALE_MERKLE_TREE_PARTS_CF,
+                SUBSTATE_HISTORY_CF,
             ]
             .into_iter()
             .map(|name| ColumnFamilyDescriptor::new(name, Options::default()))
             .collect::<Vec<_>>(),
         )
         .unwrap();
-        Self {
+        let mut store = Self {
             db,
             pruning_enabled,
+            history_start_version: None,
+        };
+        store.history_start_version = store.read_history_start_version();
+        if history_enabled && store.history_start_version.is_none() {
+            store.enable_history();
+        }
+        store
+    }
+
+    fn read_history_start_version(&self) -> Option<u64> {
+        self.db
+            .get_cf(self.cf(META_CF), HISTORY_START_VERSION_KEY)
+            .unwrap()
+            .map(|bytes| u64::from_be_bytes(bytes.try_into().unwrap()))
+    }
+
+    /// Starts the substate history at the current version, by recording the current values of
+    /// all the substates as written at it.
+    fn enable_history(&mut self) {
+        let state_version = self.get_current_version();
+        let mut batch = WriteBatch::default();
+        for kv in self
+            .db
+            .iterator_cf(self.cf(SUBSTATES_CF), IteratorMode::Start)
+        {
+            let (key_bytes, value_bytes) = kv.unwrap();
+            let (partition_key, sort_key) = decode_from_rocksdb_bytes(&key_bytes);
+            self.put_substate_history(
+                &mut batch,
+                state_version,
+                &partition_key,
+                &sort_key,
+                Some(&value_bytes.to_vec()),
+            );
+            self.write_if_full(&mut batch);
+        }
+        // Only recorded with the last batch, so that an interrupted start is redone on re-opening
+        batch.put_cf(
+            self.cf(META_CF),
+            HISTORY_START_VERSION_KEY,
+            state_version.to_be_bytes(),
+        );
+        self.db.write(batch).unwrap();
+        self.history_start_version = Some(state_version);
+    }
+
+    /// Returns the first state version which can be read using
+    /// [`Self::get_substate_at_version()`], or `None` if the substate history is not enabled.
+    pub fn get_history_start_version(&self) -> Option<u64> {
+        self.history_start_version
+    }
+
+    /// Writes out the given batch (leaving it empty) if it reached [`MAX_WRITE_BATCH_LEN`].
+    fn write_if_full(&self, batch: &mut WriteBatch) {
+        if batch.len() >= MAX_WRITE_BATCH_LEN {
+            self.db.write(std::mem::take(batch)).unwrap();
         }
     }
 
@@ -93,6 +204,365 @@ impl RocksDBWithMerkleTreeSubstateStore {
             })
             .unwrap_or(Hash([0u8; Hash::LENGTH]))
     }
+
+    /// Returns the state version before which the historical data was dropped by
+    /// [`PrunableSubstateDatabase::prune_before_version()`] (or 0 if it was never pruned).
+    pub fn get_pruned_before_version(&self) -> u64 {
+        self.db
+            .get_cf(self.cf(META_CF), PRUNED_BEFORE_VERSION_KEY)
+            .unwrap()
+            .map(|bytes| u64::from_be_bytes(bytes.try_into().unwrap()))
+            .unwrap_or(0)
+    }
+
+    /// Checks the state tree at the current version for silent corruption: recomputes all its
+    /// hashes bottom-up from the substate values (see [`verify_tree_at_version()`]), checks that
+    /// every stored substate is present in the tree, and finds the orphaned tree nodes (i.e. the
+    /// ones neither reachable from the current root, nor recorded as stale and awaiting pruning).
+    ///
+    /// Data which can't be decoded is reported as an issue as well, rather than panicking.
+    ///
+    /// Note: this walks the entire tree and substate store, keeping all their keys in memory.
+    pub fn verify_state_tree(&self) -> StateTreeVerificationReport {
+        let state_version = self.get_current_version();
+        let mut report = StateTreeVerificationReport {
+            state_version,
+            ..Default::default()
+        };
+        if state_version == 0 {
+            return report;
+        }
+
+        let recording_store = NodeRecordingTreeStore {
+            underlying: self,
+            read_node_keys: RefCell::new(hash_set_new()),
+            corrupt_node_keys: RefCell::new(hash_set_new()),
+        };
+        let mut tree_substate_keys = hash_set_new();
+        let (_, issues) = verify_tree_at_version(
+            &recording_store,
+            state_version,
+            &self.get_current_root_hash(),
+            |partition_key, sort_key| {
+                tree_substate_keys.insert(encode_to_rocksdb_bytes(partition_key, sort_key));
+                self.get_substate(partition_key, sort_key)
+            },
+        );
+        // A node which can't be decoded is seen as missing by the verification
+        let corrupt_node_keys = recording_store.corrupt_node_keys.into_inner();
+        report
+            .issues
+            .extend(issues.into_iter().map(|issue| match issue {
+                TreeVerificationIssue::MissingNode(node_key)
+                    if corrupt_node_keys.contains(&node_key) =>
+                {
+                    TreeVerificationIssue::CorruptNode(node_key)
+                }
+                issue => issue,
+            }));
+        report.verified_substates = tree_substate_keys.len();
+
+        for kv in self
+            .db
+            .iterator_cf(self.cf(SUBSTATES_CF), IteratorMode::Start)
+        {
+            let (key_bytes, _) = kv.unwrap();
+            if tree_substate_keys.contains(key_bytes.as_ref()) {
+                continue;
+            }
+            report
+                .issues
+                .push(match try_decode_from_rocksdb_bytes(&key_bytes) {
+                    Some((partition_key, sort_key)) => {
+                        TreeVerificationIssue::SubstateMissingFromTree {
+                            partition_key,
+                            sort_key,
+                        }
+                    }
+                    None => TreeVerificationIssue::CorruptSubstateKey(key_bytes.to_vec()),
+                });
+        }
+
+        let mut known_node_keys = recording_store.read_node_keys.into_inner();
+        report.verified_tree_nodes = known_node_keys.len();
+        for kv in self
+            .db
+            .iterator_cf(self.cf(STALE_MERKLE_TREE_PARTS_CF), IteratorMode::Start)
+        {
+            let (key_bytes, value_bytes) = kv.unwrap();
+            let Ok(parts) = scrypto_decode::<Vec<StaleTreePart>>(&value_bytes) else {
+                report
+                    .issues
+                    .push(TreeVerificationIssue::CorruptStaleTreeParts(
+                        key_bytes.to_vec(),
+                    ));
+                continue;
+            };
+            for part in parts {
+                match part {
+                    StaleTreePart::Node(node_key) => {
+                        known_node_keys.insert(encode_key(&node_key));
+                    }
+                    StaleTreePart::Subtree(node_key) => {
+                        self.collect_subtree_node_keys(
+                            node_key,
+                            &mut known_node_keys,
+                            &mut report.issues,
+                        );
+                    }
+                }
+            }
+        }
+        for kv in self
+            .db
+            .iterator_cf(self.cf(MERKLE_NODES_CF), IteratorMode::Start)
+        {
+            let (key_bytes, _) = kv.unwrap();
+            if known_node_keys.contains(key_bytes.as_ref()) {
+                continue;
+            }
+            report.issues.push(match try_decode_key(&key_bytes) {
+                Some(node_key) => TreeVerificationIssue::OrphanedNode(node_key),
+                None => TreeVerificationIssue::CorruptNodeKey(key_bytes.to_vec()),
+            });
+        }
+
+        report
+    }
+
+    /// Adds the encoded keys of all the nodes of the given subtree which are still stored, and
+    /// reports the ones which can't be decoded (so their children can't be found).
+    fn collect_subtree_node_keys(
+        &self,
+        node_key: NodeKey,
+        node_keys: &mut HashSet<Vec<u8>>,
+        issues: &mut Vec<TreeVerificationIssue>,
+    ) {
+        let mut queue = VecDeque::new();
+        queue.push_back(node_key);
+        while let Some(node_key) = queue.pop_front() {
+            let key_bytes = encode_key(&node_key);
+            let Some(bytes) = self
+                .db
+                .get_cf(self.cf(MERKLE_NODES_CF), &key_bytes)
+                .unwrap()
+            else {
+                continue;
+            };
+            node_keys.insert(key_bytes);
+            let Ok(value) = scrypto_decode::<VersionedTreeNode>(&bytes) else {
+                issues.push(TreeVerificationIssue::CorruptNode(node_key));
+                continue;
+            };
+            if let TreeNodeV1::Internal(x) = value.into_latest() {
+                for child in x.children {
+                    queue.push_back(node_key.gen_child_node_key(child.version, child.nibble))
+                }
+            }
+        }
+    }
+
+    /// Produces a Merkle proof of the given substate's value at the given state version, which can
+    /// be checked against that version's root hash using [`verify_substate_inclusion_proof()`].
+    /// Returns `None` if the substate does not exist at that version, or if the version is not
+    /// yet committed.
+    ///
+    /// Note: with pruning enabled, only proofs at the current state version are guaranteed to be
+    /// available (and requesting a proof at an already-pruned version will panic). The same holds
+    /// for the versions before [`Self::get_pruned_before_version()`].
+    pub fn get_substate_inclusion_proof(
+        &self,
+        state_version: u64,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Option<SubstateInclusionProof> {
+        if state_version == 0 || state_version > self.get_current_version() {
+            return None;
+        }
+        get_substate_inclusion_proof(self, state_version, partition_key, sort_key)
+    }
+
+    /// Reads the given substate's value as of the given state version, i.e. the value written by
+    /// the latest commit at or before that version.
+    /// Returns `None` if the substate did not exist at that version, or if the version is not yet
+    /// committed.
+    ///
+    /// Only the versions since [`Self::get_history_start_version()`] which were not yet pruned
+    /// (see [`Self::get_pruned_before_version()`]) can be read.
+    pub fn get_substate_at_version<M: DatabaseKeyMapper, D: ScryptoDecode>(
+        &self,
+        state_version: u64,
+        node_id: &NodeId,
+        partition_num: PartitionNumber,
+        substate_key: &SubstateKey,
+    ) -> Result<Option<D>, SubstateHistoryError> {
+        let Some(history_start_version) = self.history_start_version else {
+            return Err(SubstateHistoryError::HistoryNotEnabled);
+        };
+        if state_version < history_start_version {
+            return Err(SubstateHistoryError::VersionBeforeHistoryStart {
+                history_start_version,
+            });
+        }
+        let pruned_before_version = self.get_pruned_before_version();
+        if state_version < pruned_before_version {
+            return Err(SubstateHistoryError::VersionPruned {
+                pruned_before_version,
+            });
+        }
+        if state_version == 0 || state_version > self.get_current_version() {
+            return Ok(None);
+        }
+
+        let prefix = encode_substate_history_prefix(
+            &M::to_db_partition_key(node_id, partition_num),
+            &M::to_db_sort_key(substate_key),
+        );
+        let mut seek_key = prefix.clone();
+        seek_key.extend(state_version.to_be_bytes());
+        let value = self
+            .db
+            .iterator_cf(
+                self.cf(SUBSTATE_HISTORY_CF),
+                IteratorMode::From(&seek_key, Direction::Reverse),
+            )
+            .next()
+            .map(|kv| kv.unwrap())
+            .filter(|(iter_key_bytes, _)| iter_key_bytes.starts_with(&prefix))
+            .and_then(|(_, iter_value)| {
+                scrypto_decode::<Option<DbSubstateValue>>(&iter_value).unwrap()
+            })
+            .map(|value| scrypto_decode(&value).unwrap());
+        Ok(value)
+    }
+
+    fn put_substate_history(
+        &self,
+        batch: &mut WriteBatch,
+        state_version: u64,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+        value: Option<&DbSubstateValue>,
+    ) {
+        let mut key_bytes = encode_substate_history_prefix(partition_key, sort_key);
+        key_bytes.extend(state_version.to_be_bytes());
+        batch.put_cf(
+            self.cf(SUBSTATE_HISTORY_CF),
+            key_bytes,
+            scrypto_encode(&value.cloned()).unwrap(),
+        );
+    }
+
+    /// Deletes the tree nodes of the given stale part, returning the number of deleted nodes and
+    /// their total size.
+    fn delete_stale_tree_part(&self, part: StaleTreePart) -> (usize, u64) {
+        let mut deleted_nodes = 0;
+        let mut deleted_bytes = 0;
+        match part {
+            StaleTreePart::Node(node_key) => {
+                let key_bytes = encode_key(&node_key);
+                if let Some(bytes) = self
+                    .db
+                    .get_cf(self.cf(MERKLE_NODES_CF), &key_bytes)
+                    .unwrap()
+                {
+                    deleted_nodes += 1;
+                    deleted_bytes += (key_bytes.len() + bytes.len()) as u64;
+                }
+                self.db
+                    .delete_cf(self.cf(MERKLE_NODES_CF), key_bytes)
+                    .unwrap();
+            }
+            StaleTreePart::Subtree(node_key) => {
+                let mut queue = VecDeque::new();
+                queue.push_back(node_key);
+
+                while let Some(node_key) = queue.pop_front() {
+                    let key_bytes = encode_key(&node_key);
+                    if let Some(bytes) = self
+                        .db
+                        .get_cf(self.cf(MERKLE_NODES_CF), &key_bytes)
+                        .unwrap()
+                    {
+                        deleted_nodes += 1;
+                        deleted_bytes += (key_bytes.len() + bytes.len()) as u64;
+                        self.db
+                            .delete_cf(self.cf(MERKLE_NODES_CF), key_bytes)
+                            .unwrap();
+                        let value: VersionedTreeNode = scrypto_decode(&bytes).unwrap();
+                        match value.into_latest() {
+                            TreeNodeV1::Internal(x) => {
+                                for child in x.children {
+                                    queue.push_back(
+                                        node_key.gen_child_node_key(child.version, child.nibble),
+                                    )
+                                }
+                            }
+                            TreeNodeV1::Leaf(_) => {}
+                            TreeNodeV1::Null => {}
+                        }
+                    }
+                }
+            }
+        }
+        (deleted_nodes, deleted_bytes)
+    }
+
+    /// Deletes the history entries which no state version at or after the given one can read,
+    /// i.e. for each substate, all the entries preceding its latest entry at or before the given
+    /// version (and that entry too, if it records a deletion).
+    fn prune_substate_history(&self, state_version: u64, metrics: &mut PruningMetrics) {
+        let mut batch = WriteBatch::default();
+        // The last entry (at or before the given version) of the substate being iterated over
+        let mut previous: Option<(Vec<u8>, usize)> = None;
+        for kv in self
+            .db
+            .iterator_cf(self.cf(SUBSTATE_HISTORY_CF), IteratorMode::Start)
+        {
+            let (key_bytes, value_bytes) = kv.unwrap();
+            let (prefix, version_bytes) = key_bytes.split_at(key_bytes.len() - 8);
+            let version = u64::from_be_bytes(version_bytes.try_into().unwrap());
+            if version > state_version {
+                // Readable at later versions, and so is the previous entry
+                previous = None;
+                continue;
+            }
+            if let Some((previous_key_bytes, previous_size)) = previous.take() {
+                if previous_key_bytes[..previous_key_bytes.len() - 8] == *prefix {
+                    batch.delete_cf(self.cf(SUBSTATE_HISTORY_CF), previous_key_bytes);
+                    metrics.pruned_history_entries += 1;
+                    metrics.reclaimed_bytes += previous_size as u64;
+                }
+            }
+            let is_deletion = scrypto_decode::<Option<DbSubstateValue>>(&value_bytes)
+                .unwrap()
+                .is_none();
+            if is_deletion {
+                // A deletion reads the same as a missing entry
+                batch.delete_cf(self.cf(SUBSTATE_HISTORY_CF), &key_bytes);
+                metrics.pruned_history_entries += 1;
+                metrics.reclaimed_bytes += (key_bytes.len() + value_bytes.len()) as u64;
+            } else {
+                previous = Some((key_bytes.to_vec(), key_bytes.len() + value_bytes.len()));
+            }
+            self.write_if_full(&mut batch);
+        }
+        self.db.write(batch).unwrap();
+    }
+}
+
+/// Encodes the substate key with its length up-front, so that the history entries of different
+/// substates never share a prefix, and the entries of a single substate are ordered by version.
+fn encode_substate_history_prefix(partition_key: &DbPartitionKey, sort_key: &DbSortKey) -> Vec<u8> {
+    let substate_key_bytes = encode_to_rocksdb_bytes(partition_key, sort_key);
+    let mut buffer = Vec::new();
+    buffer.extend(
+        u32::try_from(substate_key_bytes.len())
+            .unwrap()
+            .to_be_bytes(),
+    );
+    buffer.extend(substate_key_bytes);
+    buffer
 }
 
 impl SubstateDatabase for RocksDBWithMerkleTreeSubstateStore {
@@ -107,12 +577,33 @@ impl SubstateDatabase for RocksDBWithMerkleTreeSubstateStore {
             .expect("IO Error")
     }
 
+    fn get_substates(&self, substate_keys: &[DbSubstateKey]) -> Vec<Option<DbSubstateValue>> {
+        let cf = self.cf(SUBSTATES_CF);
+        self.db
+            .multi_get_cf(substate_keys.iter().map(|(partition_key, sort_key)| {
+                (cf, encode_to_rocksdb_bytes(partition_key, sort_key))
+            }))
+            .into_iter()
+            .map(|result| result.expect("IO Error"))
+            .collect()
+    }
+
     fn list_entries(
         &self,
         partition_key: &DbPartitionKey,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        self.list_entries_from(partition_key, None)
+    }
+
+    fn list_entries_from(
+        &self,
+        partition_key: &DbPartitionKey,
+        from_sort_key: Option<&DbSortKey>,
     ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
         let partition_key = partition_key.clone();
-        let start_key_bytes = encode_to_rocksdb_bytes(&partition_key, &DbSortKey(vec![]));
+        let empty_sort_key = DbSortKey(vec![]);
+        let from_sort_key = from_sort_key.unwrap_or(&empty_sort_key);
+        let start_key_bytes = encode_to_rocksdb_bytes(&partition_key, from_sort_key);
         let iter = self
             .db
             .iterator_cf(
@@ -159,6 +650,19 @@ impl CommittableSubstateDatabase for RocksDBWithMerkleTreeSubstateStore {
                 match partition_updates {
                     PartitionDatabaseUpdates::Delta { substate_updates } => {
                         for (sort_key, update) in substate_updates {
+                            if self.history_start_version.is_some() {
+                                let value = match update {
+                                    DatabaseUpdate::Set(value_bytes) => Some(value_bytes),
+                                    DatabaseUpdate::Delete => None,
+                                };
+                                self.put_substate_history(
+                                    &mut batch,
+                                    next_state_version,
+                                    &partition_key,
+                                    sort_key,
+                                    value,
+                                );
+                            }
                             let key_bytes = encode_to_rocksdb_bytes(&partition_key, sort_key);
                             match update {
                                 DatabaseUpdate::Set(value_bytes) => {
@@ -175,6 +679,32 @@ impl CommittableSubstateDatabase for RocksDBWithMerkleTreeSubstateStore {
                     PartitionDatabaseUpdates::Reset {
                         new_substate_values,
                     } => {
+                        if self.history_start_version.is_some() {
+                            // The reset implicitly deletes all the substates which are not re-set
+                            let deleted_sort_keys = self
+                                .list_entries(&partition_key)
+                                .map(|(sort_key, _)| sort_key)
+                                .filter(|sort_key| !new_substate_values.contains_key(sort_key))
+                                .collect::<Vec<_>>();
+                            for sort_key in &deleted_sort_keys {
+                                self.put_substate_history(
+                                    &mut batch,
+                                    next_state_version,
+                                    &partition_key,
+                                    sort_key,
+                                    None,
+                                );
+                            }
+                            for (sort_key, value_bytes) in new_substate_values {
+                                self.put_substate_history(
+                                    &mut batch,
+                                    next_state_version,
+                                    &partition_key,
+                                    sort_key,
+                                    Some(value_bytes),
+                                );
+                            }
+                        }
                         // Note: a plain `delete_range()` is missing from rocksdb's API, and
                         // (at the moment of writing) this is the only reason of having CF.
                         self.db
@@ -230,49 +760,73 @@ impl CommittableSubstateDatabase for RocksDBWithMerkleTreeSubstateStore {
 
         if self.pruning_enabled {
             for part in state_hash_tree_update.stale_tree_parts {
-                match part {
-                    StaleTreePart::Node(node_key) => {
-                        self.db
-                            .delete_cf(self.cf(MERKLE_NODES_CF), encode_key(&node_key))
-                            .unwrap();
-                    }
-                    StaleTreePart::Subtree(node_key) => {
-                        let mut queue = VecDeque::new();
-                        queue.push_back(node_key);
-
-                        while let Some(node_key) = queue.pop_front() {
-                            if let Some(bytes) = self
-                                .db
-                                .get_cf(self.cf(MERKLE_NODES_CF), encode_key(&node_key))
-                                .unwrap()
-                            {
-                                self.db
-                                    .delete_cf(self.cf(MERKLE_NODES_CF), encode_key(&node_key))
-                                    .unwrap();
-                                let value: VersionedTreeNode = scrypto_decode(&bytes).unwrap();
-                                match value.into_latest() {
-                                    TreeNodeV1::Internal(x) => {
-                                        for child in x.children {
-                                            queue.push_back(
-                                                node_key.gen_child_node_key(
-                                                    child.version,
-                                                    child.nibble,
-                                                ),
-                                            )
-                                        }
-                                    }
-                                    TreeNodeV1::Leaf(_) => {}
-                                    TreeNodeV1::Null => {}
-                                }
-                            }
-                        }
-                    }
-                }
+                self.delete_stale_tree_part(part);
             }
         }
     }
 }
 
+impl PrunableSubstateDatabase for RocksDBWithMerkleTreeSubstateStore {
+    /// Deletes the tree nodes which became stale at or before the given version (which are only
+    /// recorded with pruning disabled - otherwise, they are deleted right away), and the substate
+    /// history entries which only older versions can read. The affected column families are
+    /// compacted afterwards, so that the disk space is actually reclaimed.
+    fn prune_before_version(&mut self, state_version: u64) -> PruningMetrics {
+        let state_version = state_version.min(self.get_current_version());
+        let mut metrics = PruningMetrics::default();
+        if state_version <= self.get_pruned_before_version() {
+            return metrics;
+        }
+
+        // Recorded up-front, so that the versions are never readable while partially pruned (an
+        // interrupted pruning leaves some unreadable data behind, which the next one deletes)
+        self.db
+            .put_cf(
+                self.cf(META_CF),
+                PRUNED_BEFORE_VERSION_KEY,
+                state_version.to_be_bytes(),
+            )
+            .unwrap();
+
+        let stale_parts_upper_bound = (state_version + 1).to_be_bytes();
+        for kv in self
+            .db
+            .iterator_cf(self.cf(STALE_MERKLE_TREE_PARTS_CF), IteratorMode::Start)
+        {
+            let (key_bytes, value_bytes) = kv.unwrap();
+            if *key_bytes >= stale_parts_upper_bound[..] {
+                break;
+            }
+            for part in scrypto_decode::<Vec<StaleTreePart>>(&value_bytes).unwrap() {
+                let (deleted_nodes, deleted_bytes) = self.delete_stale_tree_part(part);
+                metrics.pruned_tree_nodes += deleted_nodes;
+                metrics.reclaimed_bytes += deleted_bytes;
+            }
+            metrics.reclaimed_bytes += (key_bytes.len() + value_bytes.len()) as u64;
+        }
+        self.db
+            .delete_range_cf(
+                self.cf(STALE_MERKLE_TREE_PARTS_CF),
+                0u64.to_be_bytes(),
+                stale_parts_upper_bound,
+            )
+            .unwrap();
+
+        self.prune_substate_history(state_version, &mut metrics);
+
+        for cf in [
+            MERKLE_NODES_CF,
+            STALE_MERKLE_TREE_PARTS_CF,
+            SUBSTATE_HISTORY_CF,
+        ] {
+            self.db
+                .compact_range_cf(self.cf(cf), None::<&[u8]>, None::<&[u8]>);
+        }
+
+        metrics
+    }
+}
+
 impl ListableSubstateDatabase for RocksDBWithMerkleTreeSubstateStore {
     fn list_partition_keys(&self) -> Box<dyn Iterator<Item = DbPartitionKey> + '_> {
         Box::new(
@@ -300,6 +854,51 @@ impl ReadableTreeStore for RocksDBWithMerkleTreeSubstateStore {
     }
 }
 
+/// The outcome of [`RocksDBWithMerkleTreeSubstateStore::verify_state_tree()`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StateTreeVerificationReport {
+    /// The verified state version.
+    pub state_version: u64,
+    /// The number of tree nodes reachable from the root.
+    pub verified_tree_nodes: usize,
+    /// The number of substates in the tree.
+    pub verified_substates: usize,
+    pub issues: Vec<TreeVerificationIssue>,
+}
+
+impl StateTreeVerificationReport {
+    pub fn is_consistent(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// Records the keys of all the nodes read through it.
+struct NodeRecordingTreeStore<'s> {
+    underlying: &'s RocksDBWithMerkleTreeSubstateStore,
+    read_node_keys: RefCell<HashSet<Vec<u8>>>,
+    /// The nodes which are stored, but can't be decoded (and so are returned as missing)
+    corrupt_node_keys: RefCell<HashSet<NodeKey>>,
+}
+
+impl<'s> ReadableTreeStore for NodeRecordingTreeStore<'s> {
+    fn get_node(&self, key: &NodeKey) -> Option<TreeNode> {
+        let key_bytes = encode_key(key);
+        let bytes = self
+            .underlying
+            .db
+            .get_cf(self.underlying.cf(MERKLE_NODES_CF), &key_bytes)
+            .unwrap()?;
+        self.read_node_keys.borrow_mut().insert(key_bytes);
+        match scrypto_decode::<VersionedTreeNode>(&bytes) {
+            Ok(versioned) => Some(versioned.into_latest()),
+            Err(_) => {
+                self.corrupt_node_keys.borrow_mut().insert(key.clone());
+                None
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, Hash, PartialEq, Eq, ScryptoSbor)]
 struct Metadata {
     current_state_version: u64,
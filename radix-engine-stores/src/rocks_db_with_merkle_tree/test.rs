@@ -0,0 +1,346 @@
+use super::*;
+use crate::hash_tree::tree_store::NibblePath;
+use radix_engine_store_interface::db_key_mapper::{
+    MappedCommittableSubstateDatabase, SpreadPrefixKeyMapper,
+};
+use sbor::prelude::indexmap::indexmap;
+
+const NODE_ID: NodeId = NodeId([7; NodeId::LENGTH]);
+const PARTITION_NUM: PartitionNumber = PartitionNumber(3);
+
+fn substate_key() -> SubstateKey {
+    SubstateKey::Field(1)
+}
+
+/// A fresh database directory, unique to the calling test.
+fn database_dir(test_name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!(
+        "rocks_db_with_merkle_tree_{}_{}",
+        test_name,
+        std::process::id()
+    ));
+    if dir.exists() {
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+    dir
+}
+
+fn put(store: &mut RocksDBWithMerkleTreeSubstateStore, value: u32) {
+    store.put_mapped::<SpreadPrefixKeyMapper, _>(&NODE_ID, PARTITION_NUM, &substate_key(), &value);
+}
+
+fn delete(store: &mut RocksDBWithMerkleTreeSubstateStore) {
+    store.commit(&DatabaseUpdates::from_delta_maps(indexmap!(
+        SpreadPrefixKeyMapper::to_db_partition_key(&NODE_ID, PARTITION_NUM) => indexmap!(
+            SpreadPrefixKeyMapper::to_db_sort_key(&substate_key()) => DatabaseUpdate::Delete
+        )
+    )));
+}
+
+fn get_at_version(
+    store: &RocksDBWithMerkleTreeSubstateStore,
+    state_version: u64,
+) -> Result<Option<u32>, SubstateHistoryError> {
+    store.get_substate_at_version::<SpreadPrefixKeyMapper, u32>(
+        state_version,
+        &NODE_ID,
+        PARTITION_NUM,
+        &substate_key(),
+    )
+}
+
+#[test]
+fn substate_is_read_as_of_each_version() {
+    let dir = database_dir("substate_is_read_as_of_each_version");
+    let mut store = RocksDBWithMerkleTreeSubstateStore::standard_with_history(dir.clone());
+    put(&mut store, 10);
+    put(&mut store, 20);
+    delete(&mut store);
+    put(&mut store, 40);
+
+    assert_eq!(get_at_version(&store, 0), Ok(None));
+    assert_eq!(get_at_version(&store, 1), Ok(Some(10)));
+    assert_eq!(get_at_version(&store, 2), Ok(Some(20)));
+    assert_eq!(get_at_version(&store, 3), Ok(None));
+    assert_eq!(get_at_version(&store, 4), Ok(Some(40)));
+    assert_eq!(get_at_version(&store, 5), Ok(None));
+    drop(store);
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn reading_without_history_is_an_error() {
+    let dir = database_dir("reading_without_history_is_an_error");
+    let mut store = RocksDBWithMerkleTreeSubstateStore::standard(dir.clone());
+    put(&mut store, 10);
+
+    assert_eq!(store.get_history_start_version(), None);
+    assert_eq!(
+        get_at_version(&store, 1),
+        Err(SubstateHistoryError::HistoryNotEnabled)
+    );
+    drop(store);
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn history_stays_enabled_when_reopened_without_it() {
+    let dir = database_dir("history_stays_enabled_when_reopened_without_it");
+    let mut store = RocksDBWithMerkleTreeSubstateStore::standard_with_history(dir.clone());
+    put(&mut store, 10);
+    drop(store);
+
+    let mut store = RocksDBWithMerkleTreeSubstateStore::standard(dir.clone());
+    put(&mut store, 20);
+
+    assert_eq!(store.get_history_start_version(), Some(0));
+    assert_eq!(get_at_version(&store, 1), Ok(Some(10)));
+    assert_eq!(get_at_version(&store, 2), Ok(Some(20)));
+    drop(store);
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn enabling_history_on_existing_database_covers_its_current_substates() {
+    let dir = database_dir("enabling_history_on_existing_database_covers_its_current_substates");
+    let mut store = RocksDBWithMerkleTreeSubstateStore::standard(dir.clone());
+    put(&mut store, 10);
+    put(&mut store, 20);
+    drop(store);
+
+    let mut store = RocksDBWithMerkleTreeSubstateStore::standard_with_history(dir.clone());
+    put(&mut store, 30);
+
+    assert_eq!(store.get_history_start_version(), Some(2));
+    assert_eq!(
+        get_at_version(&store, 1),
+        Err(SubstateHistoryError::VersionBeforeHistoryStart {
+            history_start_version: 2
+        })
+    );
+    assert_eq!(get_at_version(&store, 2), Ok(Some(20)));
+    assert_eq!(get_at_version(&store, 3), Ok(Some(30)));
+    drop(store);
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn reading_pruned_version_is_an_error() {
+    let dir = database_dir("reading_pruned_version_is_an_error");
+    let mut store = RocksDBWithMerkleTreeSubstateStore::standard_with_history(dir.clone());
+    put(&mut store, 10);
+    put(&mut store, 20);
+    put(&mut store, 30);
+    store.prune_before_version(2);
+
+    assert_eq!(
+        get_at_version(&store, 1),
+        Err(SubstateHistoryError::VersionPruned {
+            pruned_before_version: 2
+        })
+    );
+    assert_eq!(get_at_version(&store, 2), Ok(Some(20)));
+    assert_eq!(get_at_version(&store, 3), Ok(Some(30)));
+    drop(store);
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn pruning_drops_only_entries_unreadable_at_later_versions() {
+    let dir = database_dir("pruning_drops_only_entries_unreadable_at_later_versions");
+    let mut store = RocksDBWithMerkleTreeSubstateStore::standard_with_history(dir.clone());
+    put(&mut store, 10);
+    put(&mut store, 20);
+    delete(&mut store);
+    put(&mut store, 40);
+
+    let metrics = store.prune_before_version(3);
+
+    // The entries of versions 1 and 2 are superseded, and the one of version 3 is a deletion
+    assert_eq!(metrics.pruned_history_entries, 3);
+    assert_eq!(store.get_pruned_before_version(), 3);
+    assert_eq!(get_at_version(&store, 3), Ok(None));
+    assert_eq!(get_at_version(&store, 4), Ok(Some(40)));
+    drop(store);
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn pruning_keeps_the_entry_readable_at_the_given_version() {
+    let dir = database_dir("pruning_keeps_the_entry_readable_at_the_given_version");
+    let mut store = RocksDBWithMerkleTreeSubstateStore::standard_with_history(dir.clone());
+    put(&mut store, 10);
+    put(&mut store, 20);
+    put(&mut store, 30);
+    put(&mut store, 40);
+
+    let metrics = store.prune_before_version(3);
+
+    assert_eq!(metrics.pruned_history_entries, 2);
+    assert_eq!(get_at_version(&store, 3), Ok(Some(30)));
+    assert_eq!(get_at_version(&store, 4), Ok(Some(40)));
+    drop(store);
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn pruning_is_capped_at_current_version_and_never_goes_back() {
+    let dir = database_dir("pruning_is_capped_at_current_version_and_never_goes_back");
+    let mut store = RocksDBWithMerkleTreeSubstateStore::standard_with_history(dir.clone());
+    put(&mut store, 10);
+    put(&mut store, 20);
+
+    store.prune_before_version(100);
+    assert_eq!(store.get_pruned_before_version(), 2);
+    assert_eq!(get_at_version(&store, 2), Ok(Some(20)));
+
+    assert_eq!(store.prune_before_version(1), PruningMetrics::default());
+    assert_eq!(store.get_pruned_before_version(), 2);
+    drop(store);
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn pruning_is_persisted_across_reopening() {
+    let dir = database_dir("pruning_is_persisted_across_reopening");
+    let mut store = RocksDBWithMerkleTreeSubstateStore::standard_with_history(dir.clone());
+    put(&mut store, 10);
+    put(&mut store, 20);
+    store.prune_before_version(2);
+    drop(store);
+
+    let store = RocksDBWithMerkleTreeSubstateStore::standard(dir.clone());
+
+    assert_eq!(store.get_pruned_before_version(), 2);
+    assert_eq!(
+        get_at_version(&store, 1),
+        Err(SubstateHistoryError::VersionPruned {
+            pruned_before_version: 2
+        })
+    );
+    assert_eq!(get_at_version(&store, 2), Ok(Some(20)));
+    drop(store);
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn verifying_consistent_state_tree_reports_no_issues() {
+    let dir = database_dir("verifying_consistent_state_tree_reports_no_issues");
+    let mut store = RocksDBWithMerkleTreeSubstateStore::standard(dir.clone());
+    put(&mut store, 10);
+    put(&mut store, 20);
+
+    let report = store.verify_state_tree();
+
+    assert_eq!(report.state_version, 2);
+    assert_eq!(report.issues, vec![]);
+    assert_eq!(report.verified_substates, 1);
+    drop(store);
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn substate_missing_from_tree_is_reported() {
+    let dir = database_dir("substate_missing_from_tree_is_reported");
+    let mut store = RocksDBWithMerkleTreeSubstateStore::standard(dir.clone());
+    put(&mut store, 10);
+    let partition_key = SpreadPrefixKeyMapper::to_db_partition_key(&NODE_ID, PartitionNumber(4));
+    let sort_key = SpreadPrefixKeyMapper::to_db_sort_key(&substate_key());
+    store
+        .db
+        .put_cf(
+            store.cf(SUBSTATES_CF),
+            encode_to_rocksdb_bytes(&partition_key, &sort_key),
+            scrypto_encode(&10u32).unwrap(),
+        )
+        .unwrap();
+
+    let report = store.verify_state_tree();
+
+    assert_eq!(
+        report.issues,
+        vec![TreeVerificationIssue::SubstateMissingFromTree {
+            partition_key,
+            sort_key
+        }]
+    );
+    drop(store);
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn orphaned_node_is_reported() {
+    let dir = database_dir("orphaned_node_is_reported");
+    let mut store = RocksDBWithMerkleTreeSubstateStore::standard(dir.clone());
+    put(&mut store, 10);
+    let root_key = NodeKey::new_empty_path(store.get_current_version());
+    let root_bytes = store
+        .db
+        .get_cf(store.cf(MERKLE_NODES_CF), encode_key(&root_key))
+        .unwrap()
+        .unwrap();
+    let orphan_key = NodeKey::new(99, NibblePath::new_even(vec![0x12]));
+    store
+        .db
+        .put_cf(
+            store.cf(MERKLE_NODES_CF),
+            encode_key(&orphan_key),
+            root_bytes,
+        )
+        .unwrap();
+
+    let report = store.verify_state_tree();
+
+    assert_eq!(
+        report.issues,
+        vec![TreeVerificationIssue::OrphanedNode(orphan_key)]
+    );
+    drop(store);
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn undecodable_data_is_reported_rather_than_panicking() {
+    let dir = database_dir("undecodable_data_is_reported_rather_than_panicking");
+    let mut store = RocksDBWithMerkleTreeSubstateStore::standard(dir.clone());
+    put(&mut store, 10);
+    let root_key = NodeKey::new_empty_path(store.get_current_version());
+    store
+        .db
+        .put_cf(store.cf(MERKLE_NODES_CF), encode_key(&root_key), [0xFF])
+        .unwrap();
+    store
+        .db
+        .put_cf(store.cf(MERKLE_NODES_CF), [1, 2, 3], [0xFF])
+        .unwrap();
+    store
+        .db
+        .put_cf(store.cf(SUBSTATES_CF), [0, 0, 0, 9], [0xFF])
+        .unwrap();
+    store
+        .db
+        .put_cf(
+            store.cf(STALE_MERKLE_TREE_PARTS_CF),
+            7u64.to_be_bytes(),
+            [0xFF],
+        )
+        .unwrap();
+
+    let report = store.verify_state_tree();
+
+    for issue in [
+        TreeVerificationIssue::CorruptNode(root_key),
+        TreeVerificationIssue::CorruptNodeKey(vec![1, 2, 3]),
+        TreeVerificationIssue::CorruptSubstateKey(vec![0, 0, 0, 9]),
+        TreeVerificationIssue::CorruptStaleTreeParts(7u64.to_be_bytes().to_vec()),
+    ] {
+        assert!(
+            report.issues.contains(&issue),
+            "{:?} not in {:?}",
+            issue,
+            report.issues
+        );
+    }
+    drop(store);
+    std::fs::remove_dir_all(dir).unwrap();
+}
@@ -16,7 +16,7 @@ use radix_engine_queries::typed_substate_layout::{CodeHash, PackageDefinition};
 use sbor::rust::iter;
 use scrypto_unit::TestRunnerBuilder;
 use transaction::{
-    prelude::{Secp256k1PrivateKey, TransactionCostingParameters},
+    prelude::{Secp256k1PrivateKey, TipBasisPoints, TransactionCostingParameters},
     validation::{recover_secp256k1, verify_secp256k1},
 };
 use wabt::wat2wasm;
@@ -112,7 +112,7 @@ fn bench_spin_loop(c: &mut Criterion) {
                 &CostingParameters::default(),
                 &TransactionCostingParameters {
                     free_credit_in_xrd: Decimal::try_from(PREVIEW_CREDIT_IN_XRD).unwrap(),
-                    tip_percentage: DEFAULT_TIP_PERCENTAGE,
+                    tip: TipBasisPoints::from_percentage(DEFAULT_TIP_PERCENTAGE),
                 },
                 false,
             );
@@ -514,11 +514,7 @@ impl AccountDepositModesTestRunner {
         sign: bool,
     ) -> TransactionReceipt {
         let manifest = ManifestBuilder::new()
-            .call_method(
-                self.component_address,
-                ACCOUNT_SET_DEFAULT_DEPOSIT_RULE_IDENT,
-                AccountSetDefaultDepositRuleInput { default },
-            )
+            .set_default_deposit_rule(self.component_address, default)
             .build();
         self.execute_manifest(manifest, sign)
     }
@@ -530,13 +526,10 @@ impl AccountDepositModesTestRunner {
         sign: bool,
     ) -> TransactionReceipt {
         let manifest = ManifestBuilder::new()
-            .call_method(
+            .set_resource_preference(
                 self.component_address,
-                ACCOUNT_SET_RESOURCE_PREFERENCE_IDENT,
-                AccountSetResourcePreferenceInput {
-                    resource_address,
-                    resource_preference,
-                },
+                resource_address,
+                resource_preference,
             )
             .build();
         self.execute_manifest(manifest, sign)
@@ -548,11 +541,7 @@ impl AccountDepositModesTestRunner {
         sign: bool,
     ) -> TransactionReceipt {
         let manifest = ManifestBuilder::new()
-            .call_method(
-                self.component_address,
-                ACCOUNT_REMOVE_RESOURCE_PREFERENCE_IDENT,
-                AccountRemoveResourcePreferenceInput { resource_address },
-            )
+            .remove_resource_preference(self.component_address, resource_address)
             .build();
         self.execute_manifest(manifest, sign)
     }
@@ -0,0 +1,287 @@
+use radix_engine::blueprints::locker::*;
+use radix_engine::errors::{ApplicationError, RuntimeError, SystemError, SystemModuleError};
+use radix_engine::system::system_modules::auth::AuthError;
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use std::time::Duration;
+use transaction::prelude::*;
+
+const CLAIM_PERIOD_IN_MINUTES: u32 = 60;
+
+struct TestEnvironment {
+    test_runner: DefaultTestRunner,
+    dapp_badge: NonFungibleGlobalId,
+    dapp_account: ComponentAddress,
+    claimant_badge: NonFungibleGlobalId,
+    claimant_account: ComponentAddress,
+    resource_address: ResourceAddress,
+    locker: ComponentAddress,
+}
+
+impl TestEnvironment {
+    fn new() -> Self {
+        let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+        let (dapp_public_key, _, dapp_account) = test_runner.new_allocated_account();
+        let (claimant_public_key, _, claimant_account) = test_runner.new_allocated_account();
+        let dapp_badge = NonFungibleGlobalId::from_public_key(&dapp_public_key);
+        let claimant_badge = NonFungibleGlobalId::from_public_key(&claimant_public_key);
+        let resource_address = test_runner.create_fungible_resource(dec!(100), 18, dapp_account);
+
+        let manifest = ManifestBuilder::new()
+            .create_account_locker(
+                OwnerRole::None,
+                rule!(require(dapp_badge.clone())),
+                rule!(require(dapp_badge.clone())),
+                CLAIM_PERIOD_IN_MINUTES,
+            )
+            .build();
+        let locker = test_runner
+            .execute_manifest_ignoring_fee(manifest, vec![])
+            .expect_commit_success()
+            .new_component_addresses()[0];
+
+        Self {
+            test_runner,
+            dapp_badge,
+            dapp_account,
+            claimant_badge,
+            claimant_account,
+            resource_address,
+            locker,
+        }
+    }
+
+    fn store(&mut self, amount: Decimal, try_direct_send: bool) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(self.dapp_account, self.resource_address, amount)
+            .take_from_worktop(self.resource_address, amount, "bucket")
+            .store_in_account_locker(
+                self.locker,
+                self.claimant_account,
+                "bucket",
+                try_direct_send,
+            )
+            .build();
+        self.test_runner
+            .execute_manifest_ignoring_fee(manifest, vec![self.dapp_badge.clone()])
+    }
+
+    fn claim(&mut self, amount: Decimal, signer: NonFungibleGlobalId) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .claim_from_account_locker(
+                self.locker,
+                self.claimant_account,
+                self.resource_address,
+                amount,
+            )
+            .try_deposit_entire_worktop_or_abort(self.claimant_account, None)
+            .build();
+        self.test_runner
+            .execute_manifest_ignoring_fee(manifest, vec![signer])
+    }
+
+    fn recover(&mut self, amount: Decimal, signer: NonFungibleGlobalId) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .recover_from_account_locker(
+                self.locker,
+                self.claimant_account,
+                self.resource_address,
+                amount,
+            )
+            .try_deposit_entire_worktop_or_abort(self.dapp_account, None)
+            .build();
+        self.test_runner
+            .execute_manifest_ignoring_fee(manifest, vec![signer])
+    }
+
+    fn balance(&mut self, account: ComponentAddress) -> Decimal {
+        self.test_runner
+            .get_component_balance(account, self.resource_address)
+    }
+}
+
+#[test]
+fn claimant_can_claim_stored_resources() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    env.store(dec!(10), false).expect_commit_success();
+    assert_eq!(env.balance(env.claimant_account), dec!(0));
+
+    // Act
+    let claimant_badge = env.claimant_badge.clone();
+    let receipt = env.claim(dec!(4), claimant_badge);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(env.balance(env.claimant_account), dec!(4));
+    assert_eq!(env.balance(env.locker), dec!(6));
+}
+
+#[test]
+fn only_the_claimant_can_claim_stored_resources() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    env.store(dec!(10), false).expect_commit_success();
+
+    // Act
+    let dapp_badge = env.dapp_badge.clone();
+    let receipt = env.claim(dec!(10), dapp_badge);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemError(SystemError::AssertAccessRuleFailed)
+        )
+    });
+}
+
+#[test]
+fn cannot_claim_resources_which_were_never_stored() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+
+    // Act
+    let claimant_badge = env.claimant_badge.clone();
+    let receipt = env.claim(dec!(1), claimant_badge);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::AccountLockerError(
+                AccountLockerError::NothingStored { .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn storing_requires_the_storer_role() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let manifest = ManifestBuilder::new()
+        .get_free_xrd_from_faucet()
+        .take_from_worktop(XRD, dec!(10), "bucket")
+        .store_in_account_locker(env.locker, env.claimant_account, "bucket", false)
+        .build();
+
+    // Act
+    let receipt = env
+        .test_runner
+        .execute_manifest_ignoring_fee(manifest, vec![env.claimant_badge.clone()]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::AuthError(AuthError::Unauthorized(
+                ..
+            )))
+        )
+    });
+}
+
+#[test]
+fn cannot_recover_stored_resources_before_the_claim_period_ends() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    env.store(dec!(10), false).expect_commit_success();
+    env.test_runner
+        .advance_time(Duration::from_secs(
+            (CLAIM_PERIOD_IN_MINUTES as u64 - 1) * 60,
+        ))
+        .expect_commit_success();
+
+    // Act
+    let dapp_badge = env.dapp_badge.clone();
+    let receipt = env.recover(dec!(10), dapp_badge);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::AccountLockerError(
+                AccountLockerError::RecoveryNotYetAllowed { .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn can_recover_stored_resources_after_the_claim_period_ends() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    env.store(dec!(10), false).expect_commit_success();
+    env.test_runner
+        .advance_time(Duration::from_secs(CLAIM_PERIOD_IN_MINUTES as u64 * 60))
+        .expect_commit_success();
+
+    // Act
+    let dapp_badge = env.dapp_badge.clone();
+    let receipt = env.recover(dec!(10), dapp_badge);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(env.balance(env.dapp_account), dec!(100));
+    assert_eq!(env.balance(env.locker), dec!(0));
+}
+
+#[test]
+fn recovering_requires_the_recoverer_role() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    env.store(dec!(10), false).expect_commit_success();
+    env.test_runner
+        .advance_time(Duration::from_secs(CLAIM_PERIOD_IN_MINUTES as u64 * 60))
+        .expect_commit_success();
+
+    // Act
+    let claimant_badge = env.claimant_badge.clone();
+    let receipt = env.recover(dec!(10), claimant_badge);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::AuthError(AuthError::Unauthorized(
+                ..
+            )))
+        )
+    });
+}
+
+#[test]
+fn direct_send_deposits_into_an_accepting_claimant_account() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+
+    // Act
+    let receipt = env.store(dec!(10), true);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(env.balance(env.claimant_account), dec!(10));
+    assert_eq!(env.balance(env.locker), dec!(0));
+}
+
+#[test]
+fn direct_send_stores_resources_rejected_by_the_claimant_account() {
+    // Arrange
+    let mut env = TestEnvironment::new();
+    let manifest = ManifestBuilder::new()
+        .set_default_deposit_rule(env.claimant_account, DefaultDepositRule::Reject)
+        .build();
+    env.test_runner
+        .execute_manifest_ignoring_fee(manifest, vec![env.claimant_badge.clone()])
+        .expect_commit_success();
+
+    // Act
+    let receipt = env.store(dec!(10), true);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(env.balance(env.claimant_account), dec!(0));
+    assert_eq!(env.balance(env.locker), dec!(10));
+}
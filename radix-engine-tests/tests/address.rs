@@ -1,13 +1,18 @@
 mod package_loader;
 
 use package_loader::PackageLoader;
-use radix_engine::errors::{KernelError, RuntimeError, SystemError};
+use radix_engine::blueprints::transaction_processor::TransactionProcessorError;
+use radix_engine::errors::{ApplicationError, KernelError, RuntimeError, SystemError};
 use radix_engine::types::*;
-use radix_engine_interface::blueprints::account::ACCOUNT_BLUEPRINT;
+use radix_engine_interface::blueprints::account::{
+    ACCOUNT_BLUEPRINT, ACCOUNT_CREATE_ADVANCED_IDENT,
+};
 use radix_engine_interface::blueprints::transaction_processor::TRANSACTION_PROCESSOR_BLUEPRINT;
 use radix_engine_queries::typed_substate_layout::PACKAGE_BLUEPRINT;
 use scrypto_unit::*;
+use transaction::errors::{ManifestIdValidationError, TransactionValidationError};
 use transaction::prelude::*;
+use transaction::validation::NotarizedTransactionValidator;
 
 #[test]
 fn get_global_address_in_local_in_function_should_fail() {
@@ -590,3 +595,102 @@ fn test_pass_static_global_addresses() {
     // Assert
     receipt.expect_commit_success();
 }
+
+/// Allocates the address of an account, then creates the account and sets its metadata through
+/// its named address - in that order, or with the metadata set before the account exists.
+fn create_account_and_set_its_metadata(set_metadata_first: bool) -> TransactionManifestV1 {
+    let set_metadata = |builder: ManifestBuilder| {
+        builder.with_name_lookup(|builder, lookup| {
+            builder.set_metadata(lookup.named_address("account_address"), "name", "Alice")
+        })
+    };
+    let create_account = |builder: ManifestBuilder| {
+        builder.call_function_with_name_lookup(
+            ACCOUNT_PACKAGE,
+            ACCOUNT_BLUEPRINT,
+            ACCOUNT_CREATE_ADVANCED_IDENT,
+            |lookup| {
+                manifest_args!(
+                    OwnerRole::Fixed(AccessRule::AllowAll),
+                    Some(lookup.address_reservation("account_address_reservation"))
+                )
+            },
+        )
+    };
+
+    let builder = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .allocate_global_address(
+            ACCOUNT_PACKAGE,
+            ACCOUNT_BLUEPRINT,
+            "account_address_reservation",
+            "account_address",
+        );
+    if set_metadata_first {
+        create_account(set_metadata(builder)).build()
+    } else {
+        set_metadata(create_account(builder)).build()
+    }
+}
+
+#[test]
+fn can_call_named_address_after_its_reservation_is_used() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+
+    // Act
+    let manifest = create_account_and_set_its_metadata(false);
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn calling_named_address_before_its_reservation_is_used_should_fail() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+
+    // Act
+    let manifest = create_account_and_set_its_metadata(true);
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::TransactionProcessorError(
+                TransactionProcessorError::AddressNotYetInstantiated(0)
+            ))
+        )
+    })
+}
+
+#[test]
+fn static_validation_accepts_calling_named_address_after_its_reservation_is_used() {
+    // Arrange
+    let manifest = create_account_and_set_its_metadata(false);
+
+    // Act
+    let result = NotarizedTransactionValidator::validate_instructions_v1(&manifest.instructions);
+
+    // Assert
+    assert_eq!(result, Ok(()));
+}
+
+#[test]
+fn static_validation_rejects_calling_named_address_before_its_reservation_is_used() {
+    // Arrange
+    let manifest = create_account_and_set_its_metadata(true);
+
+    // Act
+    let result = NotarizedTransactionValidator::validate_instructions_v1(&manifest.instructions);
+
+    // Assert
+    assert_eq!(
+        result,
+        Err(TransactionValidationError::IdValidationError(
+            ManifestIdValidationError::AddressNotYetInstantiated(0)
+        ))
+    );
+}
@@ -115,6 +115,23 @@ fn can_withdraw_from_my_2_of_3_account_with_2_signatures() {
     );
 }
 
+#[test]
+fn can_withdraw_from_my_weighted_threshold_account_only_with_enough_weight() {
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (pk0, _, auth0) = test_runner.new_key_pair_with_auth_address();
+    let (pk1, _, auth1) = test_runner.new_key_pair_with_auth_address();
+    let (pk2, _, auth2) = test_runner.new_key_pair_with_auth_address();
+    let auth = rule!(require_weighted_threshold(
+        3,
+        vec![(auth0, 2), (auth1, 1), (auth2, 1)]
+    ));
+
+    test_auth_rule(&mut test_runner, &auth, &[pk0.into(), pk1.into()], true);
+    test_auth_rule(&mut test_runner, &auth, &[pk0.into(), pk2.into()], true);
+    test_auth_rule(&mut test_runner, &auth, &[pk0.into()], false);
+    test_auth_rule(&mut test_runner, &auth, &[pk1.into(), pk2.into()], false);
+}
+
 #[test]
 fn can_withdraw_from_my_complex_account() {
     let mut test_runner = TestRunnerBuilder::new().build();
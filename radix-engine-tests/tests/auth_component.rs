@@ -204,3 +204,26 @@ fn root_auth_zone_does_not_carry_over_cross_component_calls() {
     // Assert
     receipt.expect_specific_failure(is_auth_error);
 }
+
+#[test]
+fn can_call_secured_method_readonly() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("component"));
+    let (secured_component, _) =
+        create_resource_secured_component(&mut test_runner, account, package_address);
+
+    // Act
+    let state: String = test_runner.call_method_readonly(
+        secured_component,
+        "get_component_state",
+        manifest_args!(),
+    );
+
+    // Assert
+    assert_eq!(state, "Secret");
+    test_runner
+        .call_method(secured_component, "get_component_state", manifest_args!())
+        .expect_specific_failure(is_auth_error);
+}
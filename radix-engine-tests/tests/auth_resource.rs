@@ -1,5 +1,8 @@
 extern crate core;
 
+use radix_engine::errors::{RuntimeError, SystemModuleError};
+use radix_engine::system::system_modules::auth::*;
+use radix_engine::transaction::TransactionReceipt;
 use radix_engine::types::*;
 use radix_engine_interface::api::ModuleId;
 use radix_engine_interface::blueprints::resource::{require, FromPublicKey};
@@ -198,3 +201,105 @@ fn cannot_deposit_with_wrong_auth() {
 fn can_deposit_with_right_auth() {
     test_resource_auth(Action::Deposit, true, true, false);
 }
+
+fn mint_with_proof_of(
+    test_runner: &mut DefaultTestRunner,
+    public_key: &Secp256k1PublicKey,
+    account: ComponentAddress,
+    token_address: ResourceAddress,
+    auth_to_use: ResourceAddress,
+) -> TransactionReceipt {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(account, auth_to_use, dec!(1))
+        .mint_fungible(token_address, dec!(1))
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    test_runner.execute_manifest(
+        manifest,
+        btreeset![NonFungibleGlobalId::from_public_key(public_key)],
+    )
+}
+
+fn find_mint_check(receipt: &TransactionReceipt) -> AuthCheckTrace {
+    receipt
+        .auth_trace
+        .as_ref()
+        .unwrap()
+        .checks
+        .iter()
+        .find(|check| check.fn_identifier.ident == FUNGIBLE_RESOURCE_MANAGER_MINT_IDENT)
+        .unwrap()
+        .clone()
+}
+
+#[test]
+fn auth_trace_explains_unauthorized_access() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (token_address, _, burn_auth, _, _, _, _, _) = test_runner.create_restricted_token(account);
+
+    // Act
+    let receipt = mint_with_proof_of(
+        &mut test_runner,
+        &public_key,
+        account,
+        token_address,
+        burn_auth,
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::AuthError(AuthError::Unauthorized(
+                ..
+            )))
+        )
+    });
+    let check = find_mint_check(&receipt);
+    assert!(matches!(
+        check.requirement,
+        AuthRequirement::RoleList { role_assignment_of, .. }
+            if role_assignment_of == GlobalAddress::from(token_address)
+    ));
+    assert!(matches!(
+        check.outcome,
+        AuthCheckOutcome::Unauthorized(FailedAccessRules::RoleList(..))
+    ));
+    let transaction_auth_zone = &check.global_caller_auth_zones[0];
+    assert!(transaction_auth_zone
+        .proofs
+        .iter()
+        .any(|proof| proof.resource_address() == burn_auth));
+    assert!(transaction_auth_zone
+        .virtual_non_fungibles
+        .contains(&NonFungibleGlobalId::from_public_key(&public_key)));
+}
+
+#[test]
+fn auth_trace_records_authorized_access() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (token_address, mint_auth, _, _, _, _, _, _) = test_runner.create_restricted_token(account);
+
+    // Act
+    let receipt = mint_with_proof_of(
+        &mut test_runner,
+        &public_key,
+        account,
+        token_address,
+        mint_auth,
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    let check = find_mint_check(&receipt);
+    assert_eq!(check.outcome, AuthCheckOutcome::Authorized);
+    assert!(check.global_caller_auth_zones[0]
+        .proofs
+        .iter()
+        .any(|proof| proof.resource_address() == mint_auth));
+}
@@ -128,3 +128,31 @@ fn test_auth_zone_create_proof_of_all_for_non_fungible() {
     // Assert
     receipt.expect_commit_success();
 }
+
+#[test]
+fn test_auth_zone_push_composite() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let badge_address = test_runner.create_fungible_resource(dec!(1), 0, account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_standard_test_fee(account)
+        .create_proof_from_account_of_amount(account, XRD, 10)
+        .pop_from_auth_zone("xrd_proof")
+        .create_proof_from_account_of_amount(account, badge_address, 1)
+        .pop_from_auth_zone("badge_proof")
+        .push_composite_to_auth_zone(["xrd_proof", "badge_proof"])
+        .create_proof_from_auth_zone_of_amount(XRD, 10, "xrd_proof_2")
+        .create_proof_from_auth_zone_of_amount(badge_address, 1, "badge_proof_2")
+        .drop_all_proofs()
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
@@ -0,0 +1,81 @@
+use radix_engine::errors::{RuntimeError, SystemModuleError};
+use radix_engine::system::system_modules::auth::AuthError;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn transfer(
+    from: ComponentAddress,
+    to: ComponentAddress,
+    amount: Decimal,
+) -> TransactionManifestV1 {
+    ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(from, XRD, amount)
+        .try_deposit_entire_worktop_or_abort(to, None)
+        .build()
+}
+
+#[test]
+fn batch_of_successful_transactions_is_executed_in_order() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let balance = test_runner.get_component_balance(account, XRD);
+
+    // Act
+    let batch = test_runner.execute_batch(
+        vec![
+            transfer(account, other_account, dec!(10)),
+            transfer(account, other_account, dec!(20)),
+        ],
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    assert!(batch.is_success());
+    assert_eq!(batch.expect_success().len(), 2);
+    assert_eq!(
+        test_runner.get_component_balance(account, XRD),
+        balance - dec!(30)
+    );
+}
+
+#[test]
+fn batch_stops_at_the_first_failed_transaction() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let balance = test_runner.get_component_balance(account, XRD);
+
+    // Act
+    let batch = test_runner.execute_batch(
+        vec![
+            transfer(account, other_account, dec!(10)),
+            transfer(other_account, account, dec!(10)),
+            transfer(account, other_account, dec!(20)),
+        ],
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    assert_eq!(batch.failed_at, Some(1));
+    assert_eq!(batch.receipts.len(), 2);
+    batch
+        .failed_receipt()
+        .unwrap()
+        .expect_specific_failure(|e| {
+            matches!(
+                e,
+                RuntimeError::SystemModuleError(SystemModuleError::AuthError(
+                    AuthError::Unauthorized(..)
+                ))
+            )
+        });
+    assert_eq!(
+        test_runner.get_component_balance(account, XRD),
+        balance - dec!(10)
+    );
+}
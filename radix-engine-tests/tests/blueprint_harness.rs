@@ -0,0 +1,183 @@
+mod package_loader;
+
+use package_loader::PackageLoader;
+use radix_engine::types::*;
+use radix_engine_interface::types::Level;
+use scrypto_unit::*;
+
+fn target_component() -> ComponentAddress {
+    ComponentAddress::new_or_panic(
+        NodeId::new(
+            EntityType::GlobalGenericComponent as u8,
+            &hash("target").lower_bytes(),
+        )
+        .0,
+    )
+}
+
+#[test]
+fn harness_can_call_function() {
+    // Arrange
+    let mut harness = BlueprintHarness::new(PackageLoader::get("logger"));
+
+    // Act
+    let result = harness.call_function("Logger", "emit_log", scrypto_args!("Hello".to_owned()));
+
+    // Assert
+    assert!(result.is_ok());
+    assert_eq!(harness.logs(), &[(Level::Info, "Hello".to_owned())]);
+}
+
+#[test]
+fn harness_reads_and_writes_scripted_state() {
+    // Arrange
+    let mut harness =
+        BlueprintHarness::new(PackageLoader::get("local_component")).with_state(&(12345u32,));
+
+    // Act
+    harness
+        .call_method("Secret", "set_secret", scrypto_args!(99999u32))
+        .unwrap();
+    let secret = harness
+        .call_method("Secret", "get_secret", scrypto_args!())
+        .unwrap();
+
+    // Assert
+    assert_eq!(secret.as_typed::<u32>().unwrap(), 99999u32);
+    assert_eq!(harness.state::<(u32,)>(), Some((99999u32,)));
+}
+
+#[test]
+fn harness_answers_calls_with_stubs() {
+    // Arrange
+    let target = target_component();
+    let mut harness = BlueprintHarness::new(PackageLoader::get("external_blueprint_caller"))
+        .with_state(&())
+        .stub_method(*target.as_node_id(), "get_value_via_ref", |_| {
+            scrypto_encode(&("test_1".to_owned(),)).unwrap()
+        })
+        .stub_method(*target.as_node_id(), "get_value_via_mut_ref", |_| {
+            scrypto_encode(&ScryptoValue::Enum {
+                discriminator: 0,
+                fields: vec![],
+            })
+            .unwrap()
+        });
+
+    // Act
+    let result = harness.call_method(
+        "ExternalBlueprintCaller",
+        "run_tests_with_external_component",
+        scrypto_args!(target),
+    );
+
+    // Assert
+    assert!(result.is_ok());
+}
+
+#[test]
+fn harness_reports_unstubbed_calls() {
+    // Arrange
+    let target = target_component();
+    let mut harness = BlueprintHarness::new(PackageLoader::get("external_blueprint_caller"))
+        .with_state(&())
+        .stub_method(*target.as_node_id(), "get_value_via_ref", |_| {
+            scrypto_encode(&("test_1".to_owned(),)).unwrap()
+        });
+
+    // Act
+    let result = harness.call_method(
+        "ExternalBlueprintCaller",
+        "run_tests_with_external_component",
+        scrypto_args!(target),
+    );
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(BlueprintHarnessError::UnstubbedCall(call)) if call.ends_with("get_value_via_mut_ref")
+    ));
+}
+
+#[test]
+fn harness_reports_panics() {
+    // Arrange
+    let target = target_component();
+    let mut harness = BlueprintHarness::new(PackageLoader::get("external_blueprint_caller"))
+        .with_state(&())
+        .stub_method(*target.as_node_id(), "get_value_via_ref", |_| {
+            scrypto_encode(&("unexpected".to_owned(),)).unwrap()
+        });
+
+    // Act
+    let result = harness.call_method(
+        "ExternalBlueprintCaller",
+        "run_tests_with_external_component",
+        scrypto_args!(target),
+    );
+
+    // Assert
+    assert!(matches!(
+        result,
+        Err(BlueprintHarnessError::Panic(message)) if message.contains("Ref call failed")
+    ));
+}
+
+#[test]
+fn harness_instantiates_and_globalizes_components() {
+    // Arrange
+    let mut harness = BlueprintHarness::new(PackageLoader::get("kv_store"));
+
+    // Act
+    let component = harness
+        .call_function(
+            "Basic",
+            "new_with_entry",
+            scrypto_args!("hello".to_owned(), "world".to_owned()),
+        )
+        .unwrap()
+        .as_typed::<ComponentAddress>()
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        harness
+            .object_blueprint_id(component.as_node_id())
+            .map(|blueprint_id| blueprint_id.blueprint_name.as_str()),
+        Some("Basic")
+    );
+    let (map,) = harness
+        .object_state::<(Own,)>(component.as_node_id())
+        .unwrap();
+    assert_eq!(
+        harness.key_value_store_entry::<_, String>(map.as_node_id(), &"hello".to_owned()),
+        Some("world".to_owned())
+    );
+}
+
+#[test]
+fn harness_calls_methods_on_globalized_components() {
+    // Arrange
+    let mut harness = BlueprintHarness::new(PackageLoader::get("kv_store"));
+    let component = harness
+        .call_function(
+            "Basic",
+            "new_with_entry",
+            scrypto_args!("hello".to_owned(), "world".to_owned()),
+        )
+        .unwrap()
+        .as_typed::<ComponentAddress>()
+        .unwrap();
+    let mut harness = harness.with_component_address(component);
+
+    // Act
+    let removed = harness
+        .call_method("Basic", "remove", scrypto_args!("hello".to_owned()))
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        removed.as_typed::<Option<String>>().unwrap(),
+        Some("world".to_owned())
+    );
+}
@@ -0,0 +1,57 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+#[features(full)]
+mod feature_flags {
+    struct Counter {
+        count: u32,
+        history: Vec<u32>,
+    }
+
+    impl Counter {
+        pub fn new_lite() -> Global<Counter> {
+            Self {
+                count: 0,
+                history: Vec::new(),
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .globalize()
+        }
+
+        pub fn new_full() -> Global<Counter> {
+            Self {
+                count: 0,
+                history: Vec::new(),
+            }
+            .instantiate_with_features(vec!["full"])
+            .prepare_to_globalize(OwnerRole::None)
+            .globalize()
+        }
+
+        pub fn new_with_features(features: Vec<String>) -> Global<Counter> {
+            Self {
+                count: 0,
+                history: Vec::new(),
+            }
+            .instantiate_with_features(features.iter().map(|feature| feature.as_str()).collect())
+            .prepare_to_globalize(OwnerRole::None)
+            .globalize()
+        }
+
+        pub fn increment(&mut self) {
+            self.count += 1;
+            if Runtime::is_feature_enabled("full") {
+                self.history.push(self.count);
+            }
+        }
+
+        pub fn is_full(&self) -> bool {
+            Runtime::is_feature_enabled("full")
+        }
+
+        pub fn history(&self) -> Vec<u32> {
+            self.history.clone()
+        }
+    }
+}
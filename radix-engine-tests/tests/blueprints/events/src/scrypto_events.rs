@@ -10,8 +10,33 @@ struct UnregisteredEvent {
     number: u64,
 }
 
+#[derive(ScryptoSbor, ScryptoEvent, Debug)]
+struct EmittedEvent {
+    number: u64,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug)]
+struct IndexedEvent {
+    key: Indexed<String>,
+    number: u64,
+}
+
+/// An unrelated type which happens to share its name with the indexed field marker.
+mod lookalike {
+    use scrypto::prelude::*;
+
+    #[derive(ScryptoSbor, Debug)]
+    pub struct Indexed(pub String);
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug)]
+struct LookalikeIndexedEvent {
+    key: lookalike::Indexed,
+    number: u64,
+}
+
 #[blueprint]
-#[events(RegisteredEvent)]
+#[events(RegisteredEvent, IndexedEvent, LookalikeIndexedEvent)]
 mod scrypto_events {
     struct ScryptoEvents;
 
@@ -23,5 +48,23 @@ mod scrypto_events {
         pub fn emit_unregistered_event(number: u64) {
             Runtime::emit_event(UnregisteredEvent { number });
         }
+
+        pub fn emit_event_with_macro(number: u64) {
+            emit!(EmittedEvent { number });
+        }
+
+        pub fn emit_indexed_event(key: String, number: u64) {
+            Runtime::emit_event(IndexedEvent {
+                key: Indexed(key),
+                number,
+            });
+        }
+
+        pub fn emit_lookalike_indexed_event(key: String, number: u64) {
+            Runtime::emit_event(LookalikeIndexedEvent {
+                key: lookalike::Indexed(key),
+                number,
+            });
+        }
     }
 }
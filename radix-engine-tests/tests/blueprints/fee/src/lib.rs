@@ -48,6 +48,22 @@ mod fee {
             self.garbage_vaults.push(vault);
         }
 
+        pub fn lock_fee_with_temp_refund_vault_and_fail(&mut self, amount: Decimal) {
+            let refund_vault = Vault::new(XRD);
+            ScryptoVmV1Api::object_call(
+                self.xrd.0.as_node_id(),
+                FUNGIBLE_VAULT_LOCK_FEE_WITH_REFUND_IDENT,
+                scrypto_encode(&FungibleVaultLockFeeWithRefundInput {
+                    amount,
+                    contingent: false,
+                    refund_vault: InternalAddress::new_or_panic(refund_vault.0.as_node_id().0),
+                })
+                .unwrap(),
+            );
+            self.garbage_vaults.push(refund_vault);
+            panic!("Failing after locking fee with a temporary refund vault");
+        }
+
         pub fn update_vault_and_lock_fee(&mut self, amount: Decimal) {
             info!("Balance: {}", self.xrd.amount());
             let bucket = self.xrd.take(Decimal::from(1u32));
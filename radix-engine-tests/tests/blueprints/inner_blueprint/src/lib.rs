@@ -0,0 +1,72 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod safe {
+    use compartment::*;
+
+    enable_method_auth! {
+        roles {
+            keeper => updatable_by: [];
+        },
+        methods {
+            add_compartment => PUBLIC;
+            get_compartment_value => PUBLIC;
+            set_compartment_value => PUBLIC;
+        }
+    }
+
+    struct Safe {
+        compartment: Option<Owned<Compartment>>,
+    }
+
+    impl Safe {
+        pub fn new(keeper: AccessRule) -> Global<Safe> {
+            Self { compartment: None }
+                .instantiate()
+                .prepare_to_globalize(OwnerRole::None)
+                .roles(roles! {
+                    keeper => keeper;
+                })
+                .globalize()
+        }
+
+        pub fn add_compartment(&mut self) {
+            // Inner objects can only be instantiated by their outer object
+            self.compartment = Some(Compartment { value: 0 }.instantiate());
+        }
+
+        pub fn get_compartment_value(&self) -> u32 {
+            self.compartment.as_ref().unwrap().get_value()
+        }
+
+        pub fn set_compartment_value(&self, value: u32) {
+            self.compartment.as_ref().unwrap().set_value(value);
+        }
+    }
+}
+
+#[blueprint]
+#[outer_blueprint(Safe)]
+mod compartment {
+    enable_method_auth! {
+        roles_from_outer;
+        methods {
+            get_value => PUBLIC;
+            set_value => restrict_to: [keeper];
+        }
+    }
+
+    struct Compartment {
+        pub value: u32,
+    }
+
+    impl Compartment {
+        pub fn get_value(&self) -> u32 {
+            self.value
+        }
+
+        pub fn set_value(&mut self, value: u32) {
+            self.value = value;
+        }
+    }
+}
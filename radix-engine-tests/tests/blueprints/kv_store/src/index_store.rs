@@ -0,0 +1,56 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod order_book {
+    struct OrderBook {
+        orders: IndexStore<u64, Decimal>,
+    }
+
+    impl OrderBook {
+        pub fn new() -> Global<OrderBook> {
+            Self {
+                orders: IndexStore::new(),
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .globalize()
+        }
+
+        pub fn new_with_local_scan() -> Global<OrderBook> {
+            let orders = IndexStore::new();
+            orders.insert(30, 3, dec!(3));
+            orders.insert(10, 1, dec!(1));
+            orders.insert(20, 2, dec!(2));
+
+            assert_eq!(
+                orders.range(10..30, 10),
+                vec![(10, 1, dec!(1)), (20, 2, dec!(2))]
+            );
+            assert_eq!(orders.first_n(1), vec![(10, 1, dec!(1))]);
+            assert_eq!(orders.remove(10, &1), Some(dec!(1)));
+            assert_eq!(orders.remove(10, &1), None);
+            assert_eq!(orders.first_n(10), vec![(20, 2, dec!(2)), (30, 3, dec!(3))]);
+
+            Self { orders }
+                .instantiate()
+                .prepare_to_globalize(OwnerRole::None)
+                .globalize()
+        }
+
+        pub fn place_order(&mut self, price: u16, id: u64, amount: Decimal) {
+            self.orders.insert(price, id, amount);
+        }
+
+        pub fn cancel_order(&mut self, price: u16, id: u64) -> Option<Decimal> {
+            self.orders.remove(price, &id)
+        }
+
+        pub fn orders_in_range(&self, from: u16, to: u16, limit: u32) -> Vec<(u16, u64, Decimal)> {
+            self.orders.range(from..to, limit)
+        }
+
+        pub fn best_orders(&self, limit: u32) -> Vec<(u16, u64, Decimal)> {
+            self.orders.first_n(limit)
+        }
+    }
+}
@@ -1,7 +1,9 @@
 pub mod basic;
 pub mod cyclic_map;
+pub mod index_store;
 pub mod kv_store;
 pub mod nested_kv_stores;
 pub mod precommitted;
 pub mod ref_check;
 pub mod super_kv_store;
+pub mod typed;
@@ -0,0 +1,41 @@
+use scrypto::prelude::*;
+
+kv_store! {
+    pub struct Names: KeyValueStore<u32, String>;
+}
+
+#[blueprint]
+mod typed {
+    struct Typed {
+        names: Names,
+    }
+
+    impl Typed {
+        pub fn new() -> Global<Typed> {
+            let names = Names::new();
+            names.insert(1u32, "one".to_owned());
+            assert!(names.contains_key(&1u32));
+            assert!(!names.contains_key(&2u32));
+            Self { names }
+                .instantiate()
+                .prepare_to_globalize(OwnerRole::None)
+                .globalize()
+        }
+
+        pub fn insert(&mut self, key: u32, value: String) {
+            self.names.insert(key, value);
+        }
+
+        pub fn get(&self, key: u32) -> Option<String> {
+            self.names.get(&key).map(|value| value.clone())
+        }
+
+        pub fn contains_key(&self, key: u32) -> bool {
+            self.names.contains_key(&key)
+        }
+
+        pub fn remove(&mut self, key: u32) -> Option<String> {
+            self.names.remove(&key)
+        }
+    }
+}
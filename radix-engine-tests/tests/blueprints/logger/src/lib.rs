@@ -7,10 +7,29 @@ mod logger {
     }
 
     impl Logger {
+        pub fn new() -> Global<Logger> {
+            Self { vaults: Vec::new() }
+                .instantiate()
+                .prepare_to_globalize(OwnerRole::None)
+                .globalize()
+        }
+
         pub fn emit_log(message: String) {
             info!("{}", message)
         }
 
+        pub fn emit_log_with_topic(topic: String, message: String) {
+            info!(topic = &topic, "{}", message)
+        }
+
+        pub fn emit_log_with_payload(topic: String, amount: Decimal) {
+            warn!(topic = &topic, payload = amount, "Amount: {}", amount)
+        }
+
+        pub fn emit_component_log(&self, topic: String, message: String) {
+            debug!(topic = &topic, "{}", message)
+        }
+
         pub fn rust_panic(message: String) {
             panic!("{}", message)
         }
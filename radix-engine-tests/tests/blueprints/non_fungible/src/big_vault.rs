@@ -42,5 +42,15 @@ mod big_vault {
         pub fn non_fungibles(&mut self, count: u32) -> IndexSet<NonFungibleLocalId> {
             self.vault.as_non_fungible().non_fungible_local_ids(count)
         }
+
+        pub fn non_fungibles_page(
+            &mut self,
+            limit: u32,
+            cursor: Option<NonFungibleLocalId>,
+        ) -> NonFungibleLocalIdsPage {
+            self.vault
+                .as_non_fungible()
+                .non_fungible_local_ids_page(limit, cursor)
+        }
     }
 }
@@ -68,3 +68,52 @@ mod recall {
         }
     }
 }
+
+#[blueprint]
+mod resource_controller {
+    struct ResourceController {
+        resource_manager: ResourceManager,
+    }
+
+    impl ResourceController {
+        pub fn new() -> (Global<ResourceController>, Bucket) {
+            let (reservation, address) =
+                Runtime::allocate_component_address(ResourceController::blueprint_id());
+
+            let bucket = ResourceBuilder::new_fungible(OwnerRole::None)
+                .recall_roles(recall_roles! {
+                    recaller => rule!(require(global_caller(address)));
+                    recaller_updater => rule!(deny_all);
+                })
+                .freeze_roles(freeze_roles! {
+                    freezer => rule!(require(global_caller(address)));
+                    freezer_updater => rule!(deny_all);
+                })
+                .mint_initial_supply(500);
+
+            let global = Self {
+                resource_manager: bucket.resource_manager(),
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .with_address(reservation)
+            .globalize();
+
+            (global, bucket.into())
+        }
+
+        pub fn recall(&self, vault: InternalAddress, amount: Decimal) -> Bucket {
+            self.resource_manager.recall_from_vault(vault, amount)
+        }
+
+        pub fn freeze_withdraw(&self, vault: InternalAddress) {
+            self.resource_manager
+                .freeze_vault(vault, VaultFreezeFlags::WITHDRAW);
+        }
+
+        pub fn unfreeze_withdraw(&self, vault: InternalAddress) {
+            self.resource_manager
+                .unfreeze_vault(vault, VaultFreezeFlags::WITHDRAW);
+        }
+    }
+}
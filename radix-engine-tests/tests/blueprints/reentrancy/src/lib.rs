@@ -29,3 +29,29 @@ mod reentrant_component {
         }
     }
 }
+
+#[blueprint]
+#[features(allow_reentrancy)]
+mod opt_in_reentrant_component {
+    struct OptInReentrantComponent {}
+
+    impl OptInReentrantComponent {
+        pub fn new(allow_reentrancy: bool) -> Global<OptInReentrantComponent> {
+            let features = if allow_reentrancy {
+                vec!["allow_reentrancy"]
+            } else {
+                vec![]
+            };
+            Self {}
+                .instantiate_with_features(features)
+                .prepare_to_globalize(OwnerRole::None)
+                .globalize()
+        }
+
+        pub fn func(&self) {}
+
+        pub fn call_self(&self, address: ComponentAddress) {
+            ScryptoVmV1Api::object_call(&address.into(), "func", scrypto_args!());
+        }
+    }
+}
@@ -9,6 +9,7 @@ mod royalty_test {
         free_method => Free;
         create_component_with_royalty_enabled => Free;
         create_component_with_royalty => Free;
+        create_component_with_royalty_limits => Free;
     }
 
     struct RoyaltyTest {}
@@ -75,5 +76,32 @@ mod royalty_test {
                 })
                 .globalize()
         }
+
+        pub fn create_component_with_royalty_limits(
+            max_royalty_per_transaction: Option<RoyaltyAmount>,
+            free_calls_per_transaction: u32,
+        ) -> Global<RoyaltyTest> {
+            Self {}
+                .instantiate()
+                .prepare_to_globalize(OwnerRole::None)
+                .enable_component_royalties(component_royalties! {
+                    roles {
+                        royalty_setter => rule!(allow_all);
+                        royalty_setter_updater => rule!(deny_all);
+                        royalty_locker => rule!(allow_all);
+                        royalty_locker_updater => rule!(deny_all);
+                        royalty_claimer => rule!(allow_all);
+                        royalty_claimer_updater => rule!(deny_all);
+                    },
+                    init {
+                        free_method => Free, updatable;
+                        paid_method => Xrd(1.into()), updatable;
+                        paid_method_usd => Usd(1.into()), updatable;
+                        paid_method_panic => Xrd(1.into()), updatable;
+                    }
+                })
+                .component_royalty_limits(max_royalty_per_transaction, free_calls_per_transaction)
+                .globalize()
+        }
     }
 }
@@ -16,6 +16,10 @@ mod tx_runtime {
             Runtime::generate_ruid()
         }
 
+        pub fn generate_ruid_seeded(domain: String) -> [u8; 32] {
+            Runtime::generate_ruid_seeded(domain)
+        }
+
         pub fn test_instance_of_and_blueprint_id() {
             let x = TransactionRuntimeTest {}
                 .instantiate()
@@ -0,0 +1,47 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod transient_counter {
+    struct TransientCounter {
+        calls: u32,
+        calls_in_transaction: Transient<u32>,
+        entered: Transient<bool>,
+    }
+
+    impl TransientCounter {
+        pub fn new() -> Global<TransientCounter> {
+            Self {
+                calls: 0,
+                calls_in_transaction: Transient::new(),
+                entered: Transient::new(),
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .globalize()
+        }
+
+        pub fn call(&mut self) -> (u32, u32) {
+            self.calls += 1;
+            let calls_in_transaction = self.calls_in_transaction.update(|calls| {
+                *calls += 1;
+                *calls
+            });
+            (self.calls, calls_in_transaction)
+        }
+
+        pub fn guarded_call(&self, reenter: bool) {
+            if self.entered.get() {
+                panic!("Reentrant call");
+            }
+            self.entered.set(true);
+            if reenter {
+                ScryptoVmV1Api::object_call(
+                    Runtime::global_address().as_node_id(),
+                    "guarded_call",
+                    scrypto_args!(false),
+                );
+            }
+            self.entered.set(false);
+        }
+    }
+}
@@ -707,3 +707,54 @@ fn mint_burn_events_should_match_resource_supply_post_genesis_and_notarized_tx()
         total_mint_amount.checked_sub(total_burn_amount).unwrap()
     );
 }
+
+#[test]
+fn test_runner_can_start_from_custom_genesis_allocations() {
+    // Arrange
+    let validator_key = Secp256k1PrivateKey::from_u64(1).unwrap().public_key();
+    let staker = ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(2).unwrap().public_key(),
+    );
+    let holder = ComponentAddress::virtual_account_from_public_key(
+        &Secp256k1PrivateKey::from_u64(3).unwrap().public_key(),
+    );
+    let resource_address = ResourceAddress::new_or_panic(
+        NodeId::new(
+            EntityType::GlobalFungibleResourceManager as u8,
+            &hash(vec![4, 5, 6]).lower_bytes(),
+        )
+        .0,
+    );
+    let genesis = CustomGenesis::empty(
+        Epoch::of(1),
+        CustomGenesis::default_consensus_manager_config(),
+    )
+    .with_validator(validator_key.into(), dec!("100"), staker)
+    .with_xrd_balances(vec![(staker, dec!("10")), (holder, dec!("20"))])
+    .with_resource(
+        GenesisResource {
+            reserved_resource_address: resource_address,
+            metadata: vec![(
+                "symbol".to_string(),
+                MetadataValue::String("TST".to_string()),
+            )],
+            owner: None,
+        },
+        vec![(holder, dec!("30"))],
+    );
+
+    // Act
+    let mut test_runner = TestRunnerBuilder::new()
+        .with_custom_genesis(genesis)
+        .build();
+
+    // Assert
+    let validator = test_runner.get_active_validator_info_by_key(&validator_key);
+    assert!(validator.is_registered);
+    assert_eq!(test_runner.get_component_balance(staker, XRD), dec!("10"));
+    assert_eq!(test_runner.get_component_balance(holder, XRD), dec!("20"));
+    assert_eq!(
+        test_runner.get_component_balance(holder, resource_address),
+        dec!("30")
+    );
+}
@@ -1,7 +1,7 @@
 mod package_loader;
 
 use package_loader::PackageLoader;
-use radix_engine::errors::{CallFrameError, KernelError, RuntimeError};
+use radix_engine::errors::{CallFrameError, KernelError, RuntimeError, SystemError};
 use radix_engine::kernel::call_frame::DropNodeError;
 use radix_engine::types::*;
 use scrypto_unit::*;
@@ -280,3 +280,82 @@ fn pass_vault_and_proof_to_other_component() {
     // verify if manifest executed with success
     receipt.expect_commit_success();
 }
+
+fn create_counter(
+    test_runner: &mut DefaultTestRunner,
+    package_address: PackageAddress,
+    function_name: &str,
+) -> ComponentAddress {
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(package_address, "Counter", function_name, manifest_args!())
+            .build(),
+        vec![],
+    );
+    receipt.expect_commit_success().new_component_addresses()[0]
+}
+
+fn increment_twice_and_get_history(
+    test_runner: &mut DefaultTestRunner,
+    component_address: ComponentAddress,
+) -> (bool, Vec<u32>) {
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(component_address, "increment", manifest_args!())
+            .call_method(component_address, "increment", manifest_args!())
+            .call_method(component_address, "is_full", manifest_args!())
+            .call_method(component_address, "history", manifest_args!())
+            .build(),
+        vec![],
+    );
+    let result = receipt.expect_commit_success();
+    (result.output(3), result.output(4))
+}
+
+#[test]
+fn features_enabled_at_instantiation_are_visible_to_the_component() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("component"));
+    let lite = create_counter(&mut test_runner, package_address, "new_lite");
+    let full = create_counter(&mut test_runner, package_address, "new_full");
+
+    // Act
+    let lite_outputs = increment_twice_and_get_history(&mut test_runner, lite);
+    let full_outputs = increment_twice_and_get_history(&mut test_runner, full);
+
+    // Assert
+    assert_eq!(lite_outputs, (false, vec![]));
+    assert_eq!(full_outputs, (true, vec![1, 2]));
+}
+
+#[test]
+fn instantiating_with_an_undeclared_feature_should_fail() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("component"));
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(
+                package_address,
+                "Counter",
+                "new_with_features",
+                manifest_args!(vec!["turbo".to_string()]),
+            )
+            .build(),
+        vec![],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemError(SystemError::InvalidFeature(feature)) if feature == "turbo"
+        )
+    });
+}
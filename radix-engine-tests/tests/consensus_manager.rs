@@ -3,11 +3,14 @@ mod package_loader;
 use package_loader::PackageLoader;
 use radix_engine::blueprints::consensus_manager::UnstakeData;
 use radix_engine::blueprints::consensus_manager::{
-    Validator, ValidatorEmissionAppliedEvent, ValidatorError,
+    ConfigChangeEvent, ConsensusManagerConfigurationFieldPayload, ConsensusManagerField, Validator,
+    ValidatorEmissionAppliedEvent, ValidatorError,
 };
 use radix_engine::blueprints::resource::BucketError;
 use radix_engine::errors::{ApplicationError, RuntimeError, SystemError, SystemModuleError};
 use radix_engine::system::bootstrap::*;
+use radix_engine::system::system_substates::FieldSubstate;
+use radix_engine::track::{LegacyStateUpdates, StateUpdates};
 use radix_engine::transaction::CostingParameters;
 use radix_engine::types::*;
 use radix_engine_interface::api::node_modules::auth::AuthAddresses;
@@ -16,6 +19,8 @@ use radix_engine_interface::blueprints::resource::FromPublicKey;
 use radix_engine_queries::typed_substate_layout::{
     ConsensusManagerError, ValidatorRewardAppliedEvent,
 };
+use radix_engine_store_interface::db_key_mapper::{MappedSubstateDatabase, SpreadPrefixKeyMapper};
+use radix_engine_store_interface::interface::{CommittableSubstateDatabase, DatabaseUpdate};
 use rand::prelude::SliceRandom;
 use rand::Rng;
 use rand_chacha;
@@ -246,6 +251,222 @@ fn next_round_causes_epoch_change_on_reaching_max_rounds() {
     assert_eq!(next_epoch, initial_epoch.next().unwrap());
 }
 
+fn update_config_manifest(config: ConsensusManagerConfig) -> TransactionManifestV1 {
+    ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            CONSENSUS_MANAGER,
+            CONSENSUS_MANAGER_UPDATE_CONFIG_IDENT,
+            ConsensusManagerUpdateConfigInput { config },
+        )
+        .build()
+}
+
+#[test]
+fn update_config_with_system_role_changes_epoch_change_condition() {
+    // Arrange
+    let genesis_epoch = Epoch::of(5);
+    let initial_epoch = genesis_epoch.next().unwrap();
+    let rounds_per_epoch = 100;
+    let updated_rounds_per_epoch = 5;
+    let genesis = CustomGenesis::default(
+        genesis_epoch,
+        CustomGenesis::default_consensus_manager_config().with_epoch_change_condition(
+            EpochChangeCondition {
+                min_round_count: 0,
+                max_round_count: rounds_per_epoch,
+                target_duration_millis: 1000,
+            },
+        ),
+    );
+    let mut test_runner = TestRunnerBuilder::new()
+        .with_custom_genesis(genesis)
+        .build();
+    let updated_config = CustomGenesis::default_consensus_manager_config()
+        .with_epoch_change_condition(EpochChangeCondition {
+            min_round_count: 0,
+            max_round_count: updated_rounds_per_epoch,
+            target_duration_millis: 1000,
+        });
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        update_config_manifest(updated_config.clone()),
+        vec![AuthAddresses::system_role()],
+    );
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    let events = test_runner.extract_events_of_type::<ConfigChangeEvent>(result);
+    assert_eq!(
+        events,
+        vec![ConfigChangeEvent {
+            config: updated_config
+        }]
+    );
+    let receipt = test_runner.advance_to_round(Round::of(updated_rounds_per_epoch));
+    let result = receipt.expect_commit_success();
+    let next_epoch = result.next_epoch().expect("Should have next epoch").epoch;
+    assert_eq!(next_epoch, initial_epoch.next().unwrap());
+}
+
+#[test]
+fn update_config_without_system_role_fails() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        update_config_manifest(CustomGenesis::default_consensus_manager_config()),
+        vec![],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemError(SystemError::AssertAccessRuleFailed)
+        )
+    });
+}
+
+#[test]
+fn update_config_with_invalid_values_fails() {
+    let default_config = CustomGenesis::default_consensus_manager_config();
+    let invalid_configs = [
+        (
+            default_config
+                .clone()
+                .with_min_validator_reliability(dec!("1.1")),
+            ConsensusManagerError::InvalidMinValidatorReliability(dec!("1.1")),
+        ),
+        (
+            default_config
+                .clone()
+                .with_min_validator_reliability(dec!("-0.1")),
+            ConsensusManagerError::InvalidMinValidatorReliability(dec!("-0.1")),
+        ),
+        (
+            default_config
+                .clone()
+                .with_total_emission_xrd_per_epoch(dec!("-1")),
+            ConsensusManagerError::InvalidTotalEmission(dec!("-1")),
+        ),
+        (
+            default_config.clone().with_num_unstake_epochs(0),
+            ConsensusManagerError::ZeroEpochCount("num_unstake_epochs".to_string()),
+        ),
+        (
+            default_config
+                .clone()
+                .with_num_owner_stake_units_unlock_epochs(0),
+            ConsensusManagerError::ZeroEpochCount(
+                "num_owner_stake_units_unlock_epochs".to_string(),
+            ),
+        ),
+        (
+            default_config.with_num_fee_increase_delay_epochs(0),
+            ConsensusManagerError::ZeroEpochCount("num_fee_increase_delay_epochs".to_string()),
+        ),
+    ];
+
+    for (config, expected_error) in invalid_configs {
+        // Arrange
+        let mut test_runner = TestRunnerBuilder::new().build();
+
+        // Act
+        let receipt = test_runner.execute_manifest(
+            update_config_manifest(config),
+            vec![AuthAddresses::system_role()],
+        );
+
+        // Assert
+        receipt.expect_specific_failure(|e| {
+            e == &RuntimeError::ApplicationError(ApplicationError::ConsensusManagerError(
+                expected_error.clone(),
+            ))
+        });
+    }
+}
+
+#[test]
+fn update_config_on_ledger_with_locked_config_requires_unlock_state_updates() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let substate_key = SubstateKey::Field(ConsensusManagerField::Configuration.field_index());
+    let mut config_substate: FieldSubstate<ConsensusManagerConfigurationFieldPayload> = test_runner
+        .substate_db()
+        .get_mapped::<SpreadPrefixKeyMapper, _>(
+            CONSENSUS_MANAGER.as_node_id(),
+            MAIN_BASE_PARTITION,
+            &substate_key,
+        )
+        .unwrap();
+    // Lock the field, as it was at genesis before `update_config` existed
+    config_substate.lock();
+    let lock_updates = StateUpdates::from(LegacyStateUpdates {
+        partition_deletions: index_set_new(),
+        system_updates: indexmap!(
+            (CONSENSUS_MANAGER.into_node_id(), MAIN_BASE_PARTITION) => indexmap!(
+                substate_key => DatabaseUpdate::Set(scrypto_encode(&config_substate).unwrap())
+            )
+        ),
+    });
+    test_runner
+        .substate_db_mut()
+        .commit(&lock_updates.create_database_updates::<SpreadPrefixKeyMapper>());
+    let receipt = test_runner.execute_manifest(
+        update_config_manifest(CustomGenesis::default_consensus_manager_config()),
+        vec![AuthAddresses::system_role()],
+    );
+    receipt.expect_specific_failure(|e| {
+        matches!(e, RuntimeError::SystemError(SystemError::FieldLocked(..)))
+    });
+
+    // Act
+    let unlock_updates =
+        create_consensus_manager_config_unlock_state_updates(test_runner.substate_db());
+    test_runner
+        .substate_db_mut()
+        .commit(&unlock_updates.create_database_updates::<SpreadPrefixKeyMapper>());
+    let receipt = test_runner.execute_manifest(
+        update_config_manifest(CustomGenesis::default_consensus_manager_config()),
+        vec![AuthAddresses::system_role()],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert!(
+        create_consensus_manager_config_unlock_state_updates(test_runner.substate_db())
+            .by_node
+            .is_empty()
+    );
+}
+
+#[test]
+fn update_config_with_too_many_validators_fails() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let mut config = CustomGenesis::default_consensus_manager_config();
+    config.max_validators = ValidatorIndex::MAX as u32 + 1;
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        update_config_manifest(config),
+        vec![AuthAddresses::system_role()],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::ConsensusManagerError(
+                ConsensusManagerError::ExceededValidatorCount { .. }
+            ))
+        )
+    });
+}
+
 #[test]
 fn next_round_fails_if_time_moves_backward() {
     // Arrange
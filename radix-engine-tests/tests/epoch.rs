@@ -1,5 +1,6 @@
 use radix_engine_common::types::Epoch;
 use scrypto_unit::*;
+use transaction::prelude::*;
 
 #[test]
 fn setting_single_epoch_succeeds() {
@@ -31,3 +32,61 @@ fn setting_multiple_epochs_succeed() {
         assert_eq!(test_runner.get_current_epoch(), epoch);
     }
 }
+
+#[test]
+fn set_epoch_moves_forward_through_epoch_changes() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new()
+        .with_custom_genesis(CustomGenesis::default(
+            Epoch::of(1),
+            CustomGenesis::default_consensus_manager_config(),
+        ))
+        .build();
+    let epoch = test_runner.get_current_epoch().after(5).unwrap();
+
+    // Act
+    test_runner.set_epoch(epoch);
+
+    // Assert
+    assert_eq!(test_runner.get_current_epoch(), epoch);
+}
+
+#[test]
+#[should_panic]
+fn set_epoch_cannot_move_backwards() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    test_runner.set_current_epoch(Epoch::of(10));
+
+    // Act
+    test_runner.set_epoch(Epoch::of(9));
+}
+
+#[test]
+fn auto_advance_moves_to_next_epochs_after_each_manifest() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new()
+        .with_custom_genesis(CustomGenesis::default(
+            Epoch::of(1),
+            CustomGenesis::default_consensus_manager_config(),
+        ))
+        .with_auto_advance(AdvancePolicy::Epochs(2))
+        .build();
+    let start_epoch = test_runner.get_current_epoch();
+
+    // Act
+    for _ in 0..3 {
+        test_runner
+            .execute_manifest(
+                ManifestBuilder::new().lock_fee_from_faucet().build(),
+                vec![],
+            )
+            .expect_commit_success();
+    }
+
+    // Assert
+    assert_eq!(
+        test_runner.get_current_epoch(),
+        start_epoch.after(6).unwrap()
+    );
+}
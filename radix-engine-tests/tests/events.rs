@@ -10,6 +10,7 @@ use radix_engine::blueprints::{account, resource::*};
 use radix_engine::errors::{ApplicationError, RuntimeError, SystemError};
 use radix_engine::system::attached_modules::metadata::SetMetadataEvent;
 use radix_engine::system::system_type_checker::TypeCheckError;
+use radix_engine::transaction::EventIndexEntry;
 use radix_engine::types::blueprints::account::ResourcePreference;
 use radix_engine::types::*;
 use radix_engine_interface::api::node_modules::auth::{RoleDefinition, ToRoleEntry};
@@ -290,6 +291,117 @@ fn scrypto_can_emit_registered_events() {
     });
 }
 
+#[test]
+fn scrypto_event_indexed_fields_are_included_in_event_index() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("events"));
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET, 500)
+        .call_function(
+            package_address,
+            "ScryptoEvents",
+            "emit_indexed_event",
+            manifest_args!("some_key".to_string(), 12u64),
+        )
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let commit = receipt.expect_commit(true);
+    assert_eq!(
+        commit.application_event_index.len(),
+        commit.application_events.len()
+    );
+    let indexed_event = commit
+        .application_event_index
+        .iter()
+        .find(|entry| entry.event_name == "IndexedEvent")
+        .unwrap();
+    assert_eq!(
+        indexed_event.emitter,
+        Emitter::Function(BlueprintId::new(&package_address, "ScryptoEvents"))
+    );
+    assert_eq!(
+        indexed_event.indexed_fields,
+        vec![(
+            "key".to_string(),
+            EventIndexEntry::field_hash(&"some_key".to_string())
+        )]
+    );
+    let lock_fee_event = commit
+        .application_event_index
+        .iter()
+        .find(|entry| entry.event_name == "LockFeeEvent")
+        .unwrap();
+    assert!(lock_fee_event.indexed_fields.is_empty());
+}
+
+#[test]
+fn scrypto_event_fields_of_types_named_indexed_are_not_indexed() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("events"));
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET, 500)
+        .call_function(
+            package_address,
+            "ScryptoEvents",
+            "emit_lookalike_indexed_event",
+            manifest_args!("some_key".to_string(), 12u64),
+        )
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let commit = receipt.expect_commit(true);
+    let lookalike_event = commit
+        .application_event_index
+        .iter()
+        .find(|entry| entry.event_name == "LookalikeIndexedEvent")
+        .unwrap();
+    assert!(lookalike_event.indexed_fields.is_empty());
+}
+
+#[test]
+fn scrypto_can_emit_events_registered_by_emit_macro() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("events"));
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee(FAUCET, 500)
+        .call_function(
+            package_address,
+            "ScryptoEvents",
+            "emit_event_with_macro",
+            manifest_args!(12u64),
+        )
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let events = receipt.expect_commit(true).application_events.clone();
+    let (event_identifier, event_data) = events.get(1).unwrap();
+    assert!(test_runner.is_event_name_equal::<EmittedEvent>(event_identifier));
+    assert_eq!(
+        EmittedEvent::try_decode_event(event_identifier, event_data),
+        Some(EmittedEvent { number: 12 })
+    );
+    assert_eq!(
+        fungible_vault::LockFeeEvent::try_decode_event(event_identifier, event_data),
+        None
+    );
+}
+
 #[test]
 fn cant_publish_a_package_with_non_struct_or_enum_event() {
     // Arrange
@@ -1850,6 +1962,11 @@ struct RegisteredEvent {
     number: u64,
 }
 
+#[derive(ScryptoSbor, ScryptoEvent, PartialEq, Eq, Debug)]
+struct EmittedEvent {
+    number: u64,
+}
+
 fn is_decoded_equal<T: ScryptoDecode + PartialEq>(expected: &T, actual: &[u8]) -> bool {
     scrypto_decode::<T>(&actual).unwrap() == *expected
 }
@@ -2756,3 +2873,70 @@ fn event_replacements_occur_as_expected() {
         }
     );
 }
+
+#[test]
+fn event_stream_records_events_across_transactions() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let position = test_runner.event_stream().position();
+
+    // Act
+    for amount in [dec!("1"), dec!("2")] {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(account, XRD, amount)
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build();
+        test_runner
+            .execute_manifest(
+                manifest,
+                vec![NonFungibleGlobalId::from_public_key(&public_key)],
+            )
+            .expect_commit_success();
+    }
+
+    // Assert
+    let event_stream = test_runner.event_stream().since(position);
+    assert_eq!(
+        event_stream
+            .iter()
+            .map(|event| event.transaction_index)
+            .collect::<IndexSet<_>>()
+            .len(),
+        2
+    );
+    assert_eq!(
+        event_stream
+            .iter_events_of_type::<fungible_vault::LockFeeEvent>()
+            .count(),
+        2
+    );
+
+    let account_events = test_runner
+        .event_stream()
+        .since(position)
+        .emitted_by(account);
+    assert_eq!(
+        account_events
+            .iter_events_of_type::<account::WithdrawEvent>()
+            .collect::<Vec<_>>(),
+        vec![
+            account::WithdrawEvent::Fungible(XRD, dec!("1")),
+            account::WithdrawEvent::Fungible(XRD, dec!("2")),
+        ]
+    );
+    assert_eq!(
+        account_events
+            .iter_events_of_type::<account::DepositEvent>()
+            .collect::<Vec<_>>(),
+        vec![
+            account::DepositEvent::Fungible(XRD, dec!("1")),
+            account::DepositEvent::Fungible(XRD, dec!("2")),
+        ]
+    );
+    assert!(account_events
+        .iter_events_of_type::<fungible_vault::LockFeeEvent>()
+        .next()
+        .is_none());
+}
@@ -2,8 +2,10 @@ mod package_loader;
 
 use package_loader::PackageLoader;
 use radix_engine::system::system_modules::execution_trace::{
-    ApplicationFnIdentifier, ExecutionTrace, ResourceSpecifier, TraceOrigin, WorktopChange,
+    ApplicationFnIdentifier, ExecutionTrace, ResourceSpecifier, TraceDiff, TraceDivergence,
+    TraceOrigin, WorktopChange,
 };
+use radix_engine::transaction::BalanceChange;
 use radix_engine::types::*;
 use scrypto_unit::*;
 use transaction::model::PreviewFlags;
@@ -597,6 +599,274 @@ fn test_worktop_changes() {
     }
 }
 
+#[test]
+fn test_trace_diff() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("execution_trace"));
+    let mut preview_transfer = |transfer_amount: u8| {
+        let manifest = ManifestBuilder::new()
+            .lock_fee(account, 500)
+            .call_function(
+                package_address,
+                "ExecutionTraceTest",
+                "transfer_resource_between_two_components",
+                manifest_args!(transfer_amount),
+            )
+            .build();
+        let receipt = test_runner.preview_manifest(
+            manifest,
+            vec![public_key.clone().into()],
+            0,
+            PreviewFlags::default(),
+        );
+        receipt
+            .expect_commit_success()
+            .execution_trace
+            .clone()
+            .unwrap()
+    };
+
+    // Act
+    let trace = preview_transfer(10);
+    let same_trace = preview_transfer(10);
+    let other_trace = preview_transfer(20);
+
+    // Assert
+    assert!(TraceDiff::diff(&trace, &same_trace).is_empty());
+
+    let diff = TraceDiff::diff(&trace, &other_trace);
+    assert!(diff.divergences.iter().any(|divergence| matches!(
+        divergence,
+        TraceDivergence::ResourceChanges {
+            instruction_index: 1,
+            ..
+        }
+    )));
+    assert!(diff
+        .divergences
+        .iter()
+        .any(|divergence| matches!(divergence, TraceDivergence::Output { .. })));
+    assert!(!diff.divergences.iter().any(|divergence| matches!(
+        divergence,
+        TraceDivergence::Origin { .. } | TraceDivergence::ChildCount { .. }
+    )));
+}
+
+#[test]
+fn test_trace_resource_movements_between_accounts() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let transfer_amount = Decimal::from(10);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 500)
+        .withdraw_from_account(account, XRD, transfer_amount)
+        .try_deposit_entire_worktop_or_abort(other_account, None)
+        .build();
+    let receipt = test_runner.preview_manifest(
+        manifest,
+        vec![public_key.clone().into()],
+        0,
+        PreviewFlags::default(),
+    );
+
+    // Assert
+    let resource_movements = &receipt
+        .expect_commit_success()
+        .execution_trace
+        .as_ref()
+        .unwrap()
+        .resource_movements;
+    let total_withdrawn = receipt
+        .fee_summary
+        .total_cost()
+        .checked_add(transfer_amount)
+        .unwrap();
+    assert_eq!(
+        resource_movements.account_withdrawals,
+        indexmap!(account => vec![ResourceSpecifier::Amount(XRD, total_withdrawn)])
+    );
+    assert_eq!(
+        resource_movements.account_deposits,
+        indexmap!(other_account => vec![ResourceSpecifier::Amount(XRD, transfer_amount)])
+    );
+    assert_eq!(
+        resource_movements
+            .vault_balance_changes
+            .values()
+            .cloned()
+            .collect::<Vec<_>>(),
+        vec![
+            (
+                XRD,
+                BalanceChange::Fungible(total_withdrawn.checked_neg().unwrap())
+            ),
+            (XRD, BalanceChange::Fungible(transfer_amount)),
+        ]
+    );
+}
+
+#[test]
+fn test_trace_non_fungible_movements_between_accounts() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_non_fungible_resource(account);
+    let id = NonFungibleLocalId::integer(1);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 500)
+        .withdraw_non_fungibles_from_account(account, resource_address, [id.clone()])
+        .try_deposit_entire_worktop_or_abort(other_account, None)
+        .build();
+    let receipt = test_runner.preview_manifest(
+        manifest,
+        vec![public_key.clone().into()],
+        0,
+        PreviewFlags::default(),
+    );
+
+    // Assert
+    let resource_movements = &receipt
+        .expect_commit_success()
+        .execution_trace
+        .as_ref()
+        .unwrap()
+        .resource_movements;
+    assert!(resource_movements
+        .account_withdrawals
+        .get(&account)
+        .unwrap()
+        .contains(&ResourceSpecifier::Ids(
+            resource_address,
+            indexset!(id.clone())
+        )));
+    assert_eq!(
+        resource_movements.account_deposits,
+        indexmap!(other_account => vec![ResourceSpecifier::Ids(resource_address, indexset!(id.clone()))])
+    );
+    assert_eq!(
+        resource_movements
+            .vault_balance_changes
+            .values()
+            .filter(|(address, _)| *address == resource_address)
+            .map(|(_, balance_change)| balance_change.clone())
+            .collect::<Vec<_>>(),
+        vec![
+            BalanceChange::NonFungible {
+                added: btreeset!(),
+                removed: btreeset!(id.clone()),
+            },
+            BalanceChange::NonFungible {
+                added: btreeset!(id),
+                removed: btreeset!(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_export_chrome_trace() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let path = std::env::temp_dir().join("test_export_chrome_trace.json");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 500)
+        .withdraw_from_account(account, XRD, 10)
+        .try_deposit_entire_worktop_or_abort(other_account, None)
+        .build();
+    let receipt = test_runner.execute_manifest_with_chrome_trace(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        &path,
+    );
+
+    // Assert
+    let execution_trace = receipt
+        .expect_commit_success()
+        .execution_trace
+        .as_ref()
+        .unwrap();
+    let withdraw_traces: Vec<&ExecutionTrace> = execution_trace.execution_traces[0]
+        .children
+        .iter()
+        .filter(|trace| match &trace.origin {
+            TraceOrigin::ScryptoMethod(fn_identifier) => fn_identifier.ident == "withdraw",
+            _ => false,
+        })
+        .collect();
+    assert_eq!(withdraw_traces.len(), 1);
+    assert!(withdraw_traces[0].substate_io_counts.reads > 0);
+    assert!(withdraw_traces[0].substate_io_counts.writes > 0);
+
+    let chrome_trace = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(chrome_trace, execution_trace.to_chrome_trace_json());
+    assert!(chrome_trace.starts_with("{\"traceEvents\":[{"));
+    assert!(chrome_trace.contains("\"name\":\"Account::withdraw\",\"cat\":\"method\",\"ph\":\"X\""));
+    assert!(chrome_trace.contains("\"substate_reads\":"));
+}
+
+#[test]
+fn test_trace_wasm_memory_peaks() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("execution_trace"));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .get_free_xrd_from_faucet()
+        .call_function(
+            package_address,
+            "ExecutionTraceTest",
+            "create_and_fund_a_component",
+            manifest_args!(ManifestExpression::EntireWorktop),
+        )
+        .build();
+    let receipt = test_runner.preview_manifest(manifest, vec![], 0, PreviewFlags::default());
+
+    // Assert
+    let root_trace = receipt
+        .expect_commit_success()
+        .execution_trace
+        .as_ref()
+        .unwrap()
+        .execution_traces[0]
+        .clone();
+    let traces = traces_for_instruction(&root_trace.children, 2);
+    let scrypto_trace = traces
+        .iter()
+        .find(|trace| {
+            matches!(
+                &trace.origin,
+                TraceOrigin::ScryptoFunction(ApplicationFnIdentifier { ident, .. })
+                    if ident == "create_and_fund_a_component"
+            )
+        })
+        .unwrap();
+    let wasm_memory_peak = scrypto_trace.wasm_memory_peak.unwrap();
+    assert!(wasm_memory_peak > 0);
+    assert!(wasm_memory_peak <= MAX_WASM_MEMORY_PER_CALL_FRAME);
+
+    // The faucet is a native blueprint
+    let faucet_traces = traces_for_instruction(&root_trace.children, 1);
+    assert!(faucet_traces
+        .iter()
+        .all(|trace| trace.wasm_memory_peak.is_none()));
+}
+
 fn traces_for_instruction(
     traces: &Vec<ExecutionTrace>,
     instruction_index: usize,
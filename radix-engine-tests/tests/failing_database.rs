@@ -0,0 +1,180 @@
+use radix_engine::errors::{KernelError, RejectionReason, RuntimeError};
+use radix_engine::types::*;
+use radix_engine::vm::NoExtension;
+use radix_engine_store_interface::db_key_mapper::{DatabaseKeyMapper, SpreadPrefixKeyMapper};
+use radix_engine_store_interface::interface::{DbPartitionKey, DbSortKey, SubstateDatabase};
+use radix_engine_stores::memory_db::InMemorySubstateDatabase;
+use scrypto_unit::*;
+use std::time::Duration;
+use transaction::prelude::*;
+
+fn build_test_runner() -> TestRunner<NoExtension, FailingSubstateDatabase<InMemorySubstateDatabase>>
+{
+    TestRunnerBuilder::new()
+        .with_custom_database(FailingSubstateDatabase::new(
+            InMemorySubstateDatabase::standard(),
+        ))
+        .build()
+}
+
+fn type_info_key(node_id: &NodeId) -> (DbPartitionKey, DbSortKey) {
+    (
+        SpreadPrefixKeyMapper::to_db_partition_key(node_id, TYPE_INFO_FIELD_PARTITION),
+        SpreadPrefixKeyMapper::to_db_sort_key(&TypeInfoField::TypeInfo.into()),
+    )
+}
+
+fn deposit_resource_manifest(
+    resource_address: ResourceAddress,
+    account: ComponentAddress,
+) -> TransactionManifestV1 {
+    ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .take_all_from_worktop(resource_address, "bucket")
+        .try_deposit_or_abort(account, None, "bucket")
+        .build()
+}
+
+#[test]
+fn delayed_reads_do_not_affect_the_outcome() {
+    // Arrange
+    let mut test_runner = build_test_runner();
+    test_runner.substate_db_mut().inject_fault(
+        FaultTrigger::NthRead(0),
+        DatabaseFault::Delay(Duration::from_millis(1)),
+    );
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new().lock_fee_from_faucet().build(),
+        vec![],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(test_runner.substate_db().triggered_faults(), 1);
+}
+
+#[test]
+fn missing_substate_of_referenced_node_rejects_the_transaction() {
+    // Arrange
+    let mut test_runner = build_test_runner();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(dec!(100), 18, account);
+    let (partition_key, sort_key) = type_info_key(resource_address.as_node_id());
+    test_runner.substate_db_mut().inject_fault(
+        FaultTrigger::Key(partition_key, Some(sort_key)),
+        DatabaseFault::Missing,
+    );
+
+    // Act
+    let receipt =
+        test_runner.execute_manifest(deposit_resource_manifest(resource_address, account), vec![]);
+
+    // Assert
+    receipt.expect_specific_rejection(|e| {
+        matches!(
+            e,
+            RejectionReason::ErrorBeforeLoanAndDeferredCostsRepaid(RuntimeError::KernelError(
+                KernelError::InvalidReference(node_id)
+            )) if node_id == resource_address.as_node_id()
+        )
+    });
+    assert_eq!(test_runner.substate_db().triggered_faults(), 1);
+}
+
+#[test]
+fn missing_partition_of_referenced_node_rejects_the_transaction() {
+    // Arrange
+    let mut test_runner = build_test_runner();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(dec!(100), 18, account);
+    let (partition_key, _) = type_info_key(resource_address.as_node_id());
+    test_runner.substate_db_mut().inject_fault(
+        FaultTrigger::Key(partition_key, None),
+        DatabaseFault::Missing,
+    );
+
+    // Act
+    let receipt =
+        test_runner.execute_manifest(deposit_resource_manifest(resource_address, account), vec![]);
+
+    // Assert
+    receipt.expect_specific_rejection(|e| {
+        matches!(
+            e,
+            RejectionReason::ErrorBeforeLoanAndDeferredCostsRepaid(RuntimeError::KernelError(
+                KernelError::InvalidReference(..)
+            ))
+        )
+    });
+}
+
+#[test]
+fn missing_substate_of_other_key_does_not_affect_the_outcome() {
+    // Arrange
+    let mut test_runner = build_test_runner();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(dec!(100), 18, account);
+    let (partition_key, _) = type_info_key(resource_address.as_node_id());
+    test_runner.substate_db_mut().inject_fault(
+        FaultTrigger::Key(
+            partition_key,
+            Some(SpreadPrefixKeyMapper::to_db_sort_key(&SubstateKey::Field(
+                1u8,
+            ))),
+        ),
+        DatabaseFault::Missing,
+    );
+
+    // Act
+    let receipt =
+        test_runner.execute_manifest(deposit_resource_manifest(resource_address, account), vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(test_runner.substate_db().triggered_faults(), 0);
+}
+
+#[test]
+fn nth_read_which_is_missing_reads_nothing() {
+    // Arrange
+    let mut test_runner = build_test_runner();
+    let (partition_key, sort_key) = type_info_key(FAUCET.as_node_id());
+    let substate_db = test_runner.substate_db_mut();
+    substate_db.inject_fault(FaultTrigger::NthRead(1), DatabaseFault::Missing);
+
+    // Act
+    let first_read = substate_db.get_substate(&partition_key, &sort_key);
+    let second_read = substate_db.get_substate(&partition_key, &sort_key);
+    let third_read = substate_db.get_substate(&partition_key, &sort_key);
+
+    // Assert
+    assert!(first_read.is_some());
+    assert_eq!(second_read, None);
+    assert_eq!(third_read, first_read);
+    assert_eq!(substate_db.triggered_faults(), 1);
+}
+
+#[test]
+fn listing_a_partition_leaves_out_only_the_missing_substate() {
+    // Arrange
+    let mut test_runner = build_test_runner();
+    let partition_key =
+        SpreadPrefixKeyMapper::to_db_partition_key(XRD.as_node_id(), MAIN_BASE_PARTITION);
+    let substate_db = test_runner.substate_db_mut();
+    let entries: Vec<_> = substate_db.list_entries(&partition_key).collect();
+    assert!(entries.len() > 1);
+    let (missing_sort_key, _) = entries[0].clone();
+    substate_db.inject_fault(
+        FaultTrigger::Key(partition_key.clone(), Some(missing_sort_key)),
+        DatabaseFault::Missing,
+    );
+
+    // Act
+    let listed_entries: Vec<_> = substate_db.list_entries(&partition_key).collect();
+
+    // Assert
+    assert_eq!(listed_entries, entries[1..].to_vec());
+    assert_eq!(substate_db.triggered_faults(), 1);
+}
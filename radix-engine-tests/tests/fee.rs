@@ -1,11 +1,15 @@
 mod package_loader;
 
 use package_loader::PackageLoader;
-use radix_engine::blueprints::resource::WorktopError;
+use radix_engine::blueprints::resource::{VaultError, WorktopError};
 use radix_engine::errors::RuntimeError;
-use radix_engine::errors::{ApplicationError, CallFrameError, KernelError};
+use radix_engine::errors::{ApplicationError, CallFrameError, KernelError, RejectionReason};
 use radix_engine::kernel::call_frame::OpenSubstateError;
-use radix_engine::transaction::{FeeLocks, TransactionReceipt};
+use radix_engine::system::system_modules::costing::{
+    FeeTable, FeeTableConfig, FeeTableConfigError, FinalizationCostConfig, KernelCostConfig,
+    WasmCostPreset,
+};
+use radix_engine::transaction::{CostingParameters, ExecutionConfig, FeeLocks, TransactionReceipt};
 use radix_engine::types::*;
 use radix_engine_interface::blueprints::resource::FromPublicKey;
 use scrypto_unit::*;
@@ -484,6 +488,163 @@ fn test_contingent_fee_accounting_failure() {
     assert_eq!(account2_new_balance, account2_balance);
 }
 
+#[test]
+fn test_fee_is_split_proportionally_between_locked_fees() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key1, _, account1) = test_runner.new_allocated_account();
+    let (public_key2, _, account2) = test_runner.new_allocated_account();
+    let account1_balance = test_runner
+        .get_component_resources(account1)
+        .get(&XRD)
+        .cloned()
+        .unwrap();
+    let account2_balance = test_runner
+        .get_component_resources(account2)
+        .get(&XRD)
+        .cloned()
+        .unwrap();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account1, 100)
+        .lock_fee(account2, 300)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![
+            NonFungibleGlobalId::from_public_key(&public_key1),
+            NonFungibleGlobalId::from_public_key(&public_key2),
+        ],
+    );
+
+    // Assert
+    receipt.expect_commit(true);
+    let account1_new_balance = test_runner
+        .get_component_resources(account1)
+        .get(&XRD)
+        .cloned()
+        .unwrap();
+    let account2_new_balance = test_runner
+        .get_component_resources(account2)
+        .get(&XRD)
+        .cloned()
+        .unwrap();
+    let total_cost = receipt.fee_summary.total_cost();
+    let account1_payment = total_cost
+        .checked_mul(dec!(100))
+        .unwrap()
+        .checked_div(dec!(400))
+        .unwrap();
+    assert_eq!(
+        account1_new_balance,
+        account1_balance.checked_sub(account1_payment).unwrap()
+    );
+    assert_eq!(
+        account2_new_balance,
+        account2_balance
+            .checked_sub(total_cost.checked_sub(account1_payment).unwrap())
+            .unwrap()
+    );
+}
+
+#[test]
+fn unused_fee_is_refunded_to_refund_vault() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, sponsor) = test_runner.new_allocated_account();
+    let (_, _, treasury) = test_runner.new_allocated_account();
+    let treasury_vault = test_runner.get_component_vaults(treasury, XRD)[0];
+    let sponsor_balance = test_runner.get_component_balance(sponsor, XRD);
+    let treasury_balance = test_runner.get_component_balance(treasury, XRD);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_with_refund(
+            sponsor,
+            100,
+            InternalAddress::new_or_panic(treasury_vault.into()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit(true);
+    let total_cost = receipt.fee_summary.total_cost();
+    assert_eq!(
+        test_runner.get_component_balance(sponsor, XRD),
+        sponsor_balance.checked_sub(dec!(100)).unwrap()
+    );
+    assert_eq!(
+        test_runner.get_component_balance(treasury, XRD),
+        treasury_balance
+            .checked_add(dec!(100))
+            .unwrap()
+            .checked_sub(total_cost)
+            .unwrap()
+    );
+}
+
+#[test]
+fn lock_fee_with_refund_to_non_xrd_vault_is_rejected() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, sponsor) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(dec!(100), 18, sponsor);
+    let non_xrd_vault = test_runner.get_component_vaults(sponsor, resource_address)[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_with_refund(
+            sponsor,
+            100,
+            InternalAddress::new_or_panic(non_xrd_vault.into()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_rejection(|e| {
+        matches!(
+            e,
+            RejectionReason::ErrorBeforeLoanAndDeferredCostsRepaid(RuntimeError::ApplicationError(
+                ApplicationError::VaultError(VaultError::LockFeeInvalidRefundVault(..))
+            ))
+        )
+    });
+}
+
+#[test]
+fn unused_fee_is_refunded_to_fee_vault_when_refund_vault_is_reverted() {
+    // Arrange
+    let (mut test_runner, component_address) = setup_test_runner();
+    let balance = test_runner.get_component_balance(component_address, XRD);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .call_method(
+            component_address,
+            "lock_fee_with_temp_refund_vault_and_fail",
+            manifest_args!(dec!(500)),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_failure();
+    let total_cost = receipt.fee_summary.total_cost();
+    assert_eq!(
+        test_runner.get_component_balance(component_address, XRD),
+        balance.checked_sub(total_cost).unwrap()
+    );
+}
+
 #[test]
 fn locked_fees_are_correct_in_execution_trace() {
     // Arrange
@@ -571,3 +732,272 @@ fn regular_and_contingent_fee_locks_are_correct_in_execution_trace() {
         }
     )
 }
+
+#[test]
+fn execution_cost_breakdown_by_package_is_attributed_to_invoked_packages() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_account(false);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, dec!("10"))
+        .get_free_xrd_from_faucet()
+        .deposit_batch(account)
+        .build();
+    let receipt = test_runner.preview_manifest(
+        manifest,
+        vec![public_key.into()],
+        0,
+        PreviewFlags::default(),
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    let breakdown = &receipt
+        .fee_details
+        .as_ref()
+        .unwrap()
+        .execution_cost_breakdown_by_package;
+    assert!(breakdown.contains_key(&ACCOUNT_PACKAGE));
+    assert!(breakdown.contains_key(&FAUCET_PACKAGE));
+    assert!(breakdown.contains_key(&RESOURCE_PACKAGE));
+    assert!(!breakdown.contains_key(&TRANSACTION_PROCESSOR_PACKAGE));
+    let attributed: u32 = breakdown.values().sum();
+    assert!(attributed > 0);
+    assert!(attributed <= receipt.fee_summary.total_execution_cost_units_consumed);
+}
+
+#[test]
+fn custom_fee_table_is_used_for_execution_costing() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let manifest = ManifestBuilder::new().lock_fee_from_faucet().build();
+    let fee_table = FeeTable::from_config(FeeTableConfig {
+        system_api_call_cost: 100_000,
+        ..FeeTableConfig::default()
+    })
+    .unwrap();
+
+    // Act
+    let default_receipt = test_runner.execute_manifest(manifest.clone(), vec![]);
+    let nonce = test_runner.next_transaction_nonce();
+    let custom_receipt = test_runner.execute_transaction(
+        TestTransaction::new_from_nonce(manifest, nonce)
+            .prepare()
+            .unwrap()
+            .get_executable(btreeset!()),
+        CostingParameters::default(),
+        ExecutionConfig::for_test_transaction().with_fee_table(fee_table),
+    );
+
+    // Assert
+    default_receipt.expect_commit_success();
+    custom_receipt.expect_commit_success();
+    let default_cost = default_receipt
+        .fee_summary
+        .total_execution_cost_units_consumed;
+    let custom_cost = custom_receipt
+        .fee_summary
+        .total_execution_cost_units_consumed;
+    assert!(custom_cost > default_cost + 99_500);
+}
+
+#[test]
+fn custom_fee_table_base_and_finalization_costs_are_applied() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let manifest = ManifestBuilder::new().lock_fee_from_faucet().build();
+    let fee_table = FeeTable::from_config(FeeTableConfig {
+        tx_base_cost: 1_000_000,
+        finalization: FinalizationCostConfig {
+            commit_state_update_cost: 200_000,
+            ..FinalizationCostConfig::default()
+        },
+        ..FeeTableConfig::default()
+    })
+    .unwrap();
+
+    // Act
+    let default_receipt = test_runner.execute_manifest(manifest.clone(), vec![]);
+    let nonce = test_runner.next_transaction_nonce();
+    let custom_receipt = test_runner.execute_transaction(
+        TestTransaction::new_from_nonce(manifest, nonce)
+            .prepare()
+            .unwrap()
+            .get_executable(btreeset!()),
+        CostingParameters::default(),
+        ExecutionConfig::for_test_transaction().with_fee_table(fee_table),
+    );
+
+    // Assert
+    default_receipt.expect_commit_success();
+    custom_receipt.expect_commit_success();
+    assert!(
+        custom_receipt
+            .fee_summary
+            .total_execution_cost_units_consumed
+            >= default_receipt
+                .fee_summary
+                .total_execution_cost_units_consumed
+                + 1_000_000
+    );
+    assert!(
+        custom_receipt
+            .fee_summary
+            .total_finalization_cost_units_consumed
+            > default_receipt
+                .fee_summary
+                .total_finalization_cost_units_consumed
+    );
+}
+
+#[test]
+fn fee_table_config_wasm_presets() {
+    let subsidized = FeeTableConfig::default().with_wasm_preset(WasmCostPreset::Subsidized);
+    assert!(FeeTable::from_config(subsidized).is_ok());
+    assert!(
+        subsidized.wasm_execution_units_per_cost_unit
+            > FeeTableConfig::default().wasm_execution_units_per_cost_unit
+    );
+    assert_eq!(
+        subsidized.with_wasm_preset(WasmCostPreset::Mainnet),
+        FeeTableConfig::default()
+    );
+}
+
+#[test]
+fn fee_table_config_with_zero_or_overflowing_entries_is_rejected() {
+    assert!(FeeTable::from_config(FeeTableConfig::default()).is_ok());
+    assert_eq!(
+        FeeTable::from_config(FeeTableConfig {
+            wasm_execution_units_per_cost_unit: 0,
+            ..FeeTableConfig::default()
+        })
+        .unwrap_err(),
+        FeeTableConfigError::ZeroEntry("wasm_execution_units_per_cost_unit")
+    );
+    assert_eq!(
+        FeeTable::from_config(FeeTableConfig {
+            tx_payload_cost_per_byte: u32::MAX / 1024,
+            ..FeeTableConfig::default()
+        })
+        .unwrap_err(),
+        FeeTableConfigError::OverflowingEntry("tx_payload_cost_per_byte")
+    );
+    assert_eq!(
+        FeeTable::from_config(FeeTableConfig {
+            kernel: KernelCostConfig {
+                open_substate: 0,
+                ..KernelCostConfig::default()
+            },
+            ..FeeTableConfig::default()
+        })
+        .unwrap_err(),
+        FeeTableConfigError::ZeroEntry("kernel.open_substate")
+    );
+    assert_eq!(
+        FeeTable::from_config(FeeTableConfig {
+            finalization: FinalizationCostConfig {
+                commit_bytes_per_cost_unit: 0,
+                ..FinalizationCostConfig::default()
+            },
+            ..FeeTableConfig::default()
+        })
+        .unwrap_err(),
+        FeeTableConfigError::ZeroEntry("finalization.commit_bytes_per_cost_unit")
+    );
+}
+
+#[test]
+fn transaction_with_tip_below_floor_is_rejected() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let manifest = ManifestBuilder::new().lock_fee_from_faucet().build();
+    let tip_bounds = TipBounds::new(TipBasisPoints(100), TipBasisPoints(1000));
+
+    // Act
+    let nonce = test_runner.next_transaction_nonce();
+    let receipt = test_runner.execute_transaction(
+        TestTransaction::new_from_nonce(manifest, nonce)
+            .prepare()
+            .unwrap()
+            .get_executable(btreeset!()),
+        CostingParameters::default().with_tip_bounds(tip_bounds),
+        ExecutionConfig::for_test_transaction(),
+    );
+
+    // Assert
+    assert_eq!(
+        receipt.expect_rejection(),
+        &RejectionReason::TipOutOfBounds(TipError::BelowFloor {
+            tip: TipBasisPoints::ZERO,
+            floor: TipBasisPoints(100),
+        })
+    );
+}
+
+#[test]
+fn transaction_with_tip_in_basis_points_pays_the_exact_tip() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let manifest = ManifestBuilder::new().lock_fee_from_faucet().build();
+    let tip = TipBasisPoints(150);
+    let tip_bounds = TipBounds::new(TipBasisPoints(150), TipBasisPoints(180));
+
+    // Act
+    let nonce = test_runner.next_transaction_nonce();
+    let prepared = TestTransaction::new_from_nonce(manifest, nonce)
+        .prepare()
+        .unwrap();
+    let mut executable = prepared.get_executable(btreeset!());
+    executable.overwrite_tip(tip);
+    let receipt = test_runner.execute_transaction(
+        executable,
+        CostingParameters::default().with_tip_bounds(tip_bounds),
+        ExecutionConfig::for_test_transaction(),
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(receipt.transaction_costing_parameters.tip, tip);
+    assert_eq!(
+        receipt.fee_summary.effective_execution_cost_unit_price,
+        receipt
+            .costing_parameters
+            .execution_cost_unit_price
+            .checked_mul(dec!("1.015"))
+            .unwrap()
+    );
+    assert_eq!(
+        receipt.fee_summary.total_tipping_cost_in_xrd,
+        tip.tip_on(receipt.fee_summary.total_execution_cost_in_xrd)
+            .unwrap()
+            .checked_add(
+                tip.tip_on(receipt.fee_summary.total_finalization_cost_in_xrd)
+                    .unwrap()
+            )
+            .unwrap()
+    );
+}
+
+#[test]
+fn fee_summary_contains_effective_cost_unit_prices() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let manifest = ManifestBuilder::new().lock_fee_from_faucet().build();
+
+    // Act
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        receipt.fee_summary.effective_execution_cost_unit_price,
+        receipt.effective_execution_cost_unit_price()
+    );
+    assert_eq!(
+        receipt.fee_summary.effective_finalization_cost_unit_price,
+        receipt.effective_finalization_cost_unit_price()
+    );
+}
@@ -0,0 +1,220 @@
+use radix_engine::system::system_modules::costing::{FeeTable, FeeTableConfig};
+use radix_engine::transaction::{FeeEstimationConfig, FeeEstimationError, TransactionReceipt};
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn transfer_manifest(from: ComponentAddress, to: ComponentAddress) -> TransactionManifestV1 {
+    ManifestBuilder::new()
+        .lock_fee(from, 500)
+        .withdraw_from_account(from, XRD, 10)
+        .try_deposit_entire_worktop_or_abort(to, None)
+        .build()
+}
+
+fn total_fee(receipt: &TransactionReceipt) -> Decimal {
+    let fee_summary = &receipt.fee_summary;
+    fee_summary.total_execution_cost_in_xrd
+        + fee_summary.total_finalization_cost_in_xrd
+        + fee_summary.total_royalty_cost_in_xrd
+        + fee_summary.total_storage_cost_in_xrd
+}
+
+#[test]
+fn fee_estimate_brackets_the_actual_fee() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let proofs = vec![NonFungibleGlobalId::from_public_key(&public_key)];
+
+    // Act
+    let estimate = test_runner
+        .estimate_fees(
+            transfer_manifest(account, other_account),
+            proofs.clone(),
+            &FeeEstimationConfig::new(NetworkDefinition::simulator())
+                .with_state_dependent_variance_percentage(20),
+        )
+        .unwrap();
+    let receipt = test_runner.execute_manifest(transfer_manifest(account, other_account), proofs);
+
+    // Assert
+    receipt.expect_commit_success();
+    let total = estimate.total();
+    assert!(total.lower < total.estimate);
+    assert!(total.estimate < total.upper);
+    assert!(total.contains(total_fee(&receipt)));
+}
+
+#[test]
+fn fee_estimate_does_not_commit() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let balance = test_runner.get_component_balance(account, XRD);
+
+    // Act
+    test_runner
+        .estimate_fees(
+            transfer_manifest(account, other_account),
+            [NonFungibleGlobalId::from_public_key(&public_key)],
+            &FeeEstimationConfig::new(NetworkDefinition::simulator()),
+        )
+        .unwrap();
+
+    // Assert
+    assert_eq!(test_runner.get_component_balance(account, XRD), balance);
+}
+
+#[test]
+fn fee_estimate_is_exact_by_default() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+
+    // Act
+    let estimate = test_runner
+        .estimate_fees(
+            transfer_manifest(account, other_account),
+            [NonFungibleGlobalId::from_public_key(&public_key)],
+            &FeeEstimationConfig::new(NetworkDefinition::simulator()),
+        )
+        .unwrap();
+
+    // Assert
+    assert_eq!(estimate.execution.lower, estimate.execution.estimate);
+    assert_eq!(estimate.execution.upper, estimate.execution.estimate);
+}
+
+#[test]
+fn fee_estimate_includes_the_cost_of_unverified_signatures() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let proofs = vec![NonFungibleGlobalId::from_public_key(&public_key)];
+    let config = FeeEstimationConfig::new(NetworkDefinition::simulator());
+
+    // Act
+    let unsigned = test_runner
+        .estimate_fees(
+            transfer_manifest(account, other_account),
+            proofs.clone(),
+            &config,
+        )
+        .unwrap();
+    let signed = test_runner
+        .estimate_fees(
+            transfer_manifest(account, other_account),
+            proofs,
+            &config.clone().with_num_of_signatures(2),
+        )
+        .unwrap();
+
+    // Assert
+    let signature_cost = config.costing_parameters.execution_cost_unit_price
+        * FeeTable::new().verify_tx_signatures_cost(2);
+    assert_eq!(
+        signed.execution.estimate,
+        unsigned.execution.estimate + signature_cost
+    );
+}
+
+#[test]
+fn fee_estimate_uses_the_configured_fee_table() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let proofs = vec![NonFungibleGlobalId::from_public_key(&public_key)];
+    let fee_table = FeeTable::from_config(FeeTableConfig {
+        verify_tx_signature_cost: 2 * FeeTableConfig::default().verify_tx_signature_cost,
+        ..Default::default()
+    })
+    .unwrap();
+    let config = FeeEstimationConfig::new(NetworkDefinition::simulator()).with_fee_table(fee_table);
+
+    // Act
+    let unsigned = test_runner
+        .estimate_fees(
+            transfer_manifest(account, other_account),
+            proofs.clone(),
+            &config,
+        )
+        .unwrap();
+    let signed = test_runner
+        .estimate_fees(
+            transfer_manifest(account, other_account),
+            proofs,
+            &config.clone().with_num_of_signatures(2),
+        )
+        .unwrap();
+
+    // Assert
+    let signature_cost = config.costing_parameters.execution_cost_unit_price
+        * (2 * FeeTable::new().verify_tx_signatures_cost(2));
+    assert_eq!(
+        signed.execution.estimate,
+        unsigned.execution.estimate + signature_cost
+    );
+}
+
+#[test]
+fn fee_estimate_can_disable_auth() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+
+    // Act
+    let estimate = test_runner.estimate_fees(
+        transfer_manifest(account, other_account),
+        vec![],
+        &FeeEstimationConfig::new(NetworkDefinition::simulator()).with_auth(false),
+    );
+
+    // Assert
+    estimate.unwrap().receipt.expect_commit_success();
+}
+
+#[test]
+fn fee_estimate_of_failing_transaction_is_an_error() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 500)
+        .assert_worktop_contains(XRD, 1)
+        .build();
+
+    // Act
+    let result = test_runner.estimate_fees(
+        manifest,
+        [NonFungibleGlobalId::from_public_key(&public_key)],
+        &FeeEstimationConfig::new(NetworkDefinition::simulator()),
+    );
+
+    // Assert
+    assert!(matches!(result, Err(FeeEstimationError::Failed(_))));
+}
+
+#[test]
+fn fee_estimate_of_rejected_transaction_is_an_error() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+
+    // Act
+    let result = test_runner.estimate_fees(
+        transfer_manifest(account, other_account),
+        vec![],
+        &FeeEstimationConfig::new(NetworkDefinition::simulator()),
+    );
+
+    // Assert
+    assert!(matches!(result, Err(FeeEstimationError::Rejected(_))));
+}
@@ -0,0 +1,151 @@
+mod package_loader;
+
+use package_loader::PackageLoader;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn create_order_book(test_runner: &mut DefaultTestRunner) -> ComponentAddress {
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("kv_store"));
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(package_address, "OrderBook", "new", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success().new_component_addresses()[0]
+}
+
+fn place_order(
+    test_runner: &mut DefaultTestRunner,
+    component_address: ComponentAddress,
+    price: u16,
+    id: u64,
+    amount: Decimal,
+) {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            component_address,
+            "place_order",
+            manifest_args!(price, id, amount),
+        )
+        .build();
+    test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+}
+
+fn call_method<T: ScryptoDecode>(
+    test_runner: &mut DefaultTestRunner,
+    component_address: ComponentAddress,
+    method_name: &str,
+    args: impl ResolvableArguments,
+) -> T {
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(component_address, method_name, args)
+        .build();
+    test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success()
+        .output(1)
+}
+
+#[test]
+fn can_scan_index_store_entries_in_sort_prefix_order() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let component_address = create_order_book(&mut test_runner);
+    place_order(&mut test_runner, component_address, 500, 1, dec!(10));
+    place_order(&mut test_runner, component_address, 100, 2, dec!(20));
+    place_order(&mut test_runner, component_address, 300, 3, dec!(30));
+    place_order(&mut test_runner, component_address, u16::MAX, 4, dec!(40));
+
+    // Act
+    let best_orders: Vec<(u16, u64, Decimal)> = call_method(
+        &mut test_runner,
+        component_address,
+        "best_orders",
+        manifest_args!(3u32),
+    );
+    let orders_in_range: Vec<(u16, u64, Decimal)> = call_method(
+        &mut test_runner,
+        component_address,
+        "orders_in_range",
+        manifest_args!(100u16, 500u16, 10u32),
+    );
+    let all_orders: Vec<(u16, u64, Decimal)> = call_method(
+        &mut test_runner,
+        component_address,
+        "best_orders",
+        manifest_args!(10u32),
+    );
+
+    // Assert
+    assert_eq!(
+        best_orders,
+        vec![(100, 2, dec!(20)), (300, 3, dec!(30)), (500, 1, dec!(10))]
+    );
+    assert_eq!(
+        orders_in_range,
+        vec![(100, 2, dec!(20)), (300, 3, dec!(30))]
+    );
+    assert_eq!(
+        all_orders,
+        vec![
+            (100, 2, dec!(20)),
+            (300, 3, dec!(30)),
+            (500, 1, dec!(10)),
+            (u16::MAX, 4, dec!(40))
+        ]
+    );
+}
+
+#[test]
+fn removed_index_store_entries_are_not_scanned() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let component_address = create_order_book(&mut test_runner);
+    place_order(&mut test_runner, component_address, 100, 1, dec!(10));
+    place_order(&mut test_runner, component_address, 200, 2, dec!(20));
+
+    // Act
+    let removed: Option<Decimal> = call_method(
+        &mut test_runner,
+        component_address,
+        "cancel_order",
+        manifest_args!(100u16, 1u64),
+    );
+    let orders: Vec<(u16, u64, Decimal)> = call_method(
+        &mut test_runner,
+        component_address,
+        "best_orders",
+        manifest_args!(10u32),
+    );
+
+    // Assert
+    assert_eq!(removed, Some(dec!(10)));
+    assert_eq!(orders, vec![(200, 2, dec!(20))]);
+}
+
+#[test]
+fn can_scan_index_store_before_it_is_persisted() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("kv_store"));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "OrderBook",
+            "new_with_local_scan",
+            manifest_args!(),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+}
@@ -0,0 +1,157 @@
+mod package_loader;
+
+use package_loader::PackageLoader;
+use radix_engine::blueprints::package::*;
+use radix_engine::errors::{ApplicationError, RuntimeError, SystemModuleError};
+use radix_engine::system::system_modules::auth::AuthError;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn create_safe_with_compartment(
+    test_runner: &mut DefaultTestRunner,
+    keeper: AccessRule,
+) -> ComponentAddress {
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("inner_blueprint"));
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(package_address, "Safe", "new", manifest_args!(keeper))
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let component_address = receipt.expect_commit_success().new_component_addresses()[0];
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(component_address, "add_compartment", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success();
+
+    component_address
+}
+
+#[test]
+fn inner_blueprint_method_can_be_called_with_role_of_outer_blueprint() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let component_address = create_safe_with_compartment(&mut test_runner, rule!(allow_all));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            component_address,
+            "set_compartment_value",
+            manifest_args!(5u32),
+        )
+        .call_method(component_address, "get_compartment_value", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let value: u32 = receipt.expect_commit_success().output(2);
+    assert_eq!(value, 5);
+}
+
+#[test]
+fn inner_blueprint_method_is_protected_by_role_of_outer_blueprint() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let component_address = create_safe_with_compartment(&mut test_runner, rule!(deny_all));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            component_address,
+            "set_compartment_value",
+            manifest_args!(5u32),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::AuthError(
+                AuthError::Unauthorized { .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn publishing_outer_blueprint_with_roles_from_outer_fails() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (code, mut definition) = PackageLoader::get("inner_blueprint");
+    definition
+        .blueprints
+        .get_mut("Compartment")
+        .unwrap()
+        .blueprint_type = BlueprintType::Outer;
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .publish_package_advanced(
+            None,
+            code,
+            definition,
+            MetadataInit::default(),
+            OwnerRole::None,
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::PackageError(
+                PackageError::InvalidAuthSetup
+            ))
+        )
+    });
+}
+
+#[test]
+fn publishing_inner_blueprint_restricted_to_role_missing_from_outer_fails() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (code, mut definition) = PackageLoader::get("inner_blueprint");
+    let safe_definition = definition.blueprints.get_mut("Safe").unwrap();
+    match &mut safe_definition.auth_config.method_auth {
+        MethodAuthTemplate::StaticRoleDefinition(StaticRoleDefinition {
+            roles: RoleSpecification::Normal(roles),
+            ..
+        }) => {
+            roles.clear();
+        }
+        _ => panic!("Unexpected method auth of the outer blueprint"),
+    }
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .publish_package_advanced(
+            None,
+            code,
+            definition,
+            MetadataInit::default(),
+            OwnerRole::None,
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::PackageError(
+                PackageError::MissingRole(role_key)
+            )) if role_key.key == "keeper"
+        )
+    });
+}
@@ -5,6 +5,8 @@ use radix_engine::{
     errors::{ApplicationError, RuntimeError},
     types::*,
 };
+use radix_engine_interface::blueprints::account::ACCOUNT_BLUEPRINT;
+use radix_engine_interface::blueprints::identity::IDENTITY_BLUEPRINT;
 use radix_engine_interface::blueprints::resource::FromPublicKey;
 use scrypto::prelude::{require, require_amount};
 use scrypto_unit::*;
@@ -125,3 +127,49 @@ fn drop_auth_zone_signature_proofs_should_not_invalid_physical_proof() {
     // Assert
     receipt.expect_commit_success();
 }
+
+#[test]
+fn assert_component_blueprint_should_succeed_for_matching_blueprint() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .assert_component_blueprint(account, ACCOUNT_PACKAGE, ACCOUNT_BLUEPRINT)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn assert_component_blueprint_should_fail_for_other_blueprint() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .assert_component_blueprint(account, IDENTITY_PACKAGE, IDENTITY_BLUEPRINT)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::TransactionProcessorError(
+                TransactionProcessorError::ComponentBlueprintMismatch {
+                    component_address,
+                    actual,
+                    ..
+                }
+            )) if *component_address == account
+                && *actual == BlueprintId::new(&ACCOUNT_PACKAGE, ACCOUNT_BLUEPRINT)
+        )
+    });
+}
@@ -28,6 +28,11 @@ fn substate_store_matches_hash_tree_after_each_scenario() {
                     test_runner.assert_state_hash_tree_matches_substate_store();
                     previous = Some(receipt);
                 }
+                NextAction::AdvanceToEpoch(epoch) => {
+                    let receipt = test_runner.advance_to_epoch(epoch);
+                    test_runner.assert_state_hash_tree_matches_substate_store();
+                    previous = Some(receipt);
+                }
                 NextAction::Completed(end_state) => {
                     next_nonce = end_state.next_unused_nonce;
                     break;
@@ -15,9 +15,12 @@ use radix_engine::kernel::kernel_callback_api::{
     RemoveSubstateEvent, ScanKeysEvent, ScanSortedSubstatesEvent, SetSubstateEvent,
     WriteSubstateEvent,
 };
-use radix_engine::track::Track;
+use radix_engine::track::{CommitableSubstateStore, Track};
 use radix_engine::types::*;
-use radix_engine_store_interface::db_key_mapper::SpreadPrefixKeyMapper;
+use radix_engine_store_interface::db_key_mapper::{DatabaseKeyMapper, SpreadPrefixKeyMapper};
+use radix_engine_store_interface::interface::{
+    CommittableSubstateDatabase, DatabaseUpdate, DatabaseUpdates,
+};
 use radix_engine_stores::memory_db::InMemorySubstateDatabase;
 use transaction::model::PreAllocatedAddress;
 
@@ -458,3 +461,50 @@ fn kernel_close_substate_should_fail_if_opened_child_exists() {
         ))
     ));
 }
+
+#[test]
+fn prefetching_substates_does_not_change_substate_reads() {
+    // Arrange
+    let node_id = NodeId([7u8; NodeId::LENGTH]);
+    let present_key = SubstateKey::Field(0u8);
+    let missing_key = SubstateKey::Field(1u8);
+    let mut database = InMemorySubstateDatabase::standard();
+    database.commit(&DatabaseUpdates::from_delta_maps(indexmap!(
+        SpreadPrefixKeyMapper::to_db_partition_key(&node_id, PartitionNumber(0u8)) => indexmap!(
+            SpreadPrefixKeyMapper::to_db_sort_key(&present_key) => DatabaseUpdate::Set(
+                scrypto_encode(&"value").unwrap()
+            )
+        )
+    )));
+    let read_all = |prefetch: bool| {
+        let mut track = Track::<InMemorySubstateDatabase, SpreadPrefixKeyMapper>::new(&database);
+        if prefetch {
+            track.prefetch_substates(vec![
+                (node_id, PartitionNumber(0u8), present_key.clone()),
+                (node_id, PartitionNumber(0u8), missing_key.clone()),
+            ]);
+        }
+        let mut io_accesses = Vec::new();
+        let mut values = Vec::new();
+        for key in [&present_key, &missing_key, &present_key] {
+            let value = track
+                .get_substate(&node_id, PartitionNumber(0u8), key, &mut |io_access| {
+                    io_accesses.push(format!("{:?}", io_access));
+                    Result::<(), ()>::Ok(())
+                })
+                .unwrap()
+                .map(|value| value.as_slice().to_vec());
+            values.push(value);
+        }
+        (values, io_accesses)
+    };
+
+    // Act
+    let (values, io_accesses) = read_all(false);
+    let (prefetched_values, prefetched_io_accesses) = read_all(true);
+
+    // Assert
+    assert_eq!(prefetched_values, values);
+    assert_eq!(prefetched_io_accesses, io_accesses);
+    assert_eq!(io_accesses.len(), 2);
+}
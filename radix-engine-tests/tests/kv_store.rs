@@ -5,6 +5,7 @@ use radix_engine::errors::{CallFrameError, KernelError, RuntimeError};
 use radix_engine::kernel::call_frame::{
     OpenSubstateError, ProcessSubstateError, TakeNodeError, WriteSubstateError,
 };
+use radix_engine::system::system_db_reader::{SystemDatabaseReader, SystemReaderError};
 use radix_engine::types::*;
 use scrypto_unit::*;
 use transaction::prelude::*;
@@ -31,6 +32,105 @@ fn can_insert_in_child_nodes() {
     receipt.expect_commit_success();
 }
 
+#[test]
+fn can_list_kv_store_entries_from_database() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("kv_store"));
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(package_address, "Basic", "multiple_reads", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let component_address = receipt.expect_commit_success().new_component_addresses()[0];
+
+    // Act
+    let reader = SystemDatabaseReader::new(test_runner.substate_db());
+    let (map,): (Own,) = reader
+        .read_typed_object_field(component_address.as_node_id(), ModuleId::Main, 0)
+        .unwrap();
+    let mut entries = reader
+        .list_kv_entries::<String, String>(map.as_node_id())
+        .unwrap();
+    entries.sort();
+
+    // Assert
+    assert_eq!(
+        entries,
+        vec![
+            ("hello".to_owned(), "hello".to_owned()),
+            ("hello2".to_owned(), "hello2".to_owned()),
+        ]
+    );
+    assert_eq!(
+        reader.list_kv_entries::<String, String>(component_address.as_node_id()),
+        Err(SystemReaderError::NotAKeyValueStore)
+    );
+}
+
+#[derive(ScryptoSbor)]
+struct BasicState {
+    map: Own,
+}
+
+fn new_basic_with_entry(test_runner: &mut DefaultTestRunner) -> ComponentAddress {
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("kv_store"));
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "Basic",
+            "new_with_entry",
+            manifest_args!("key".to_owned(), "value".to_owned()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success().new_component_addresses()[0]
+}
+
+#[test]
+fn can_read_component_state_and_kv_entries_with_schema() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let component_address = new_basic_with_entry(&mut test_runner);
+
+    // Act
+    let state: BasicState = test_runner.read_component_state(component_address);
+
+    // Assert
+    assert_eq!(
+        test_runner.read_kv_entry::<String, String>(state.map, &"key".to_owned()),
+        Some("value".to_owned())
+    );
+    assert_eq!(
+        test_runner.read_kv_entry::<String, String>(state.map, &"missing".to_owned()),
+        None
+    );
+}
+
+#[test]
+#[should_panic(expected = "can't be decoded into")]
+fn read_component_state_into_wrong_type_should_panic() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let component_address = new_basic_with_entry(&mut test_runner);
+
+    // Act
+    test_runner.read_component_state::<(String,)>(component_address);
+}
+
+#[test]
+#[should_panic(expected = "Key does not match the key schema of the store")]
+fn read_kv_entry_with_wrong_key_type_should_panic() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let component_address = new_basic_with_entry(&mut test_runner);
+    let state: BasicState = test_runner.read_component_state(component_address);
+
+    // Act
+    test_runner.read_kv_entry::<u32, String>(state.map, &1u32);
+}
+
 #[test]
 fn create_mutable_kv_store_into_map_and_referencing_before_storing() {
     // Arrange
@@ -532,3 +632,38 @@ fn nested_kv_stores_works() {
     // Assert
     receipt.expect_commit_success();
 }
+
+#[test]
+fn declared_kv_store_should_support_typed_accessors() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("kv_store"));
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(package_address, "Typed", "new", manifest_args!())
+            .build(),
+        vec![],
+    );
+    let component_address = receipt.expect_commit_success().new_component_addresses()[0];
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(component_address, "insert", manifest_args!(2u32, "two"))
+            .call_method(component_address, "get", manifest_args!(1u32))
+            .call_method(component_address, "contains_key", manifest_args!(2u32))
+            .call_method(component_address, "remove", manifest_args!(1u32))
+            .call_method(component_address, "contains_key", manifest_args!(1u32))
+            .build(),
+        vec![],
+    );
+
+    // Assert
+    let commit = receipt.expect_commit_success();
+    assert_eq!(commit.output::<Option<String>>(2), Some("one".to_owned()));
+    assert_eq!(commit.output::<bool>(3), true);
+    assert_eq!(commit.output::<Option<String>>(4), Some("one".to_owned()));
+    assert_eq!(commit.output::<bool>(5), false);
+}
@@ -3,6 +3,7 @@ mod package_loader;
 use package_loader::PackageLoader;
 use radix_engine::{
     errors::{ApplicationError, RuntimeError},
+    system::system_modules::transaction_runtime::{LogRecord, PanicLocation},
     transaction::TransactionReceipt,
     types::*,
 };
@@ -40,7 +41,12 @@ fn test_emit_log() {
     {
         receipt.expect_commit_success();
 
-        let logs = receipt.expect_commit(true).application_logs.clone();
+        let logs: Vec<(Level, String)> = receipt
+            .expect_commit(true)
+            .application_logs
+            .iter()
+            .map(|log| (log.level, log.message.clone()))
+            .collect();
         let expected_logs = vec![(Level::Info, message.to_owned())];
 
         assert_eq!(expected_logs, logs)
@@ -70,6 +76,50 @@ fn test_rust_panic() {
     }
 }
 
+#[test]
+fn test_rust_panic_details() {
+    // Arrange
+    let function_name = "rust_panic";
+    let message = "Hey";
+
+    // Act
+    let receipt = call(function_name, message);
+
+    // Assert
+    {
+        receipt.expect_commit_failure();
+
+        let panic_details = receipt.panic_details.clone().unwrap();
+        assert_eq!(panic_details.message, message);
+        assert_eq!(
+            panic_details.blueprint_id.map(|id| id.blueprint_name),
+            Some("Logger".to_owned())
+        );
+        assert_eq!(
+            panic_details.export_name,
+            Some("Logger_rust_panic".to_owned())
+        );
+        assert_eq!(
+            panic_details.location,
+            Some(PanicLocation {
+                file: "logger/src/lib.rs".to_owned(),
+                line: 15,
+                column: 13,
+            })
+        );
+    }
+}
+
+#[test]
+fn test_emit_log_has_no_panic_details() {
+    // Act
+    let receipt = call("emit_log", "Hello");
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(receipt.panic_details, None);
+}
+
 #[test]
 fn test_scrypto_panic() {
     // Arrange
@@ -112,3 +162,89 @@ fn test_assert_length_5() {
         })
     }
 }
+
+fn call_with_topic(
+    function_name: &str,
+    args: ManifestArgs,
+) -> (PackageAddress, TransactionReceipt) {
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("logger"));
+
+    let manifest = ManifestBuilder::new()
+        .call_function(package_address, "Logger", function_name, args)
+        .build();
+    let receipt = test_runner.execute_manifest_ignoring_fee(manifest, vec![]);
+
+    (package_address, receipt)
+}
+
+#[test]
+fn test_emit_log_with_topic() {
+    // Act
+    let (package_address, receipt) = call_with_topic(
+        "emit_log_with_topic",
+        manifest_args!("swap".to_owned(), "Hello".to_owned()),
+    );
+
+    // Assert
+    let commit = receipt.expect_commit_success();
+    let logs: Vec<&LogRecord> = commit.logs_with_topic("swap").collect();
+    assert_eq!(
+        logs,
+        vec![&LogRecord {
+            level: Level::Info,
+            topic: Some("swap".to_owned()),
+            message: "Hello".to_owned(),
+            blueprint_id: Some(BlueprintId::new(&package_address, "Logger")),
+            global_address: None,
+            payload: None,
+        }]
+    );
+    assert_eq!(commit.logs_with_topic("other").count(), 0);
+}
+
+#[test]
+fn test_emit_log_with_payload() {
+    // Act
+    let (_, receipt) = call_with_topic(
+        "emit_log_with_payload",
+        manifest_args!("swap".to_owned(), dec!("1.5")),
+    );
+
+    // Assert
+    let commit = receipt.expect_commit_success();
+    let log = commit.logs_with_topic("swap").next().unwrap();
+    assert_eq!(log.level, Level::Warn);
+    assert_eq!(log.message, "Amount: 1.5");
+    assert_eq!(log.payload_as::<Decimal>(), Some(Ok(dec!("1.5"))));
+}
+
+#[test]
+fn test_emit_log_from_component() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("logger"));
+    let manifest = ManifestBuilder::new()
+        .call_function(package_address, "Logger", "new", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest_ignoring_fee(manifest, vec![]);
+    let component_address = receipt.expect_commit_success().new_component_addresses()[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .call_method(
+            component_address,
+            "emit_component_log",
+            manifest_args!("swap".to_owned(), "Hello".to_owned()),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest_ignoring_fee(manifest, vec![]);
+
+    // Assert
+    let commit = receipt.expect_commit_success();
+    let logs: Vec<&LogRecord> = commit.logs_of_component(component_address.into()).collect();
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0].level, Level::Debug);
+    assert_eq!(logs[0].topic, Some("swap".to_owned()));
+    assert_eq!(logs[0].message, "Hello");
+}
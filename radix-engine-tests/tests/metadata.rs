@@ -1,5 +1,5 @@
 use radix_engine::errors::{ApplicationError, RuntimeError, SystemError};
-use radix_engine::system::attached_modules::metadata::MetadataError;
+use radix_engine::system::attached_modules::metadata::{MetadataError, MetadataValidationError};
 use radix_engine::types::*;
 use radix_engine_interface::api::node_modules::metadata::{
     MetadataConversionError::UnexpectedType, MetadataValue,
@@ -224,6 +224,112 @@ fn cannot_set_metadata_if_initialized_empty_locked() {
     });
 }
 
+#[test]
+fn cannot_initialize_standard_metadata_with_wrong_type() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.compile_and_publish("../assets/blueprints/metadata");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "MetadataTest",
+            "new_with_initial_metadata",
+            manifest_args!(
+                "icon_url".to_string(),
+                "https://www.radixdlt.com".to_string()
+            ),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::MetadataError(
+                MetadataError::MetadataValidationError(
+                    MetadataValidationError::InvalidStandardValue {
+                        key,
+                        error: UnexpectedType {
+                            expected_type_id: METADATA_VALUE_URL_DISCRIMINATOR,
+                            actual_type_id: METADATA_VALUE_STRING_DISCRIMINATOR,
+                        },
+                    }
+                )
+            )) if key == "icon_url"
+        )
+    });
+}
+
+#[test]
+fn cannot_set_standard_metadata_with_wrong_type() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.compile_and_publish("../assets/blueprints/metadata");
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(package_address, "MetadataTest", "new", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let component_address = receipt.expect_commit(true).new_component_addresses()[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .set_metadata(component_address, "tags", "defi")
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::MetadataError(
+                MetadataError::MetadataValidationError(
+                    MetadataValidationError::InvalidStandardValue { .. }
+                )
+            ))
+        )
+    });
+}
+
+#[test]
+fn can_get_standard_metadata_from_scrypto() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address = test_runner.compile_and_publish("../assets/blueprints/metadata");
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(package_address, "MetadataTest", "new", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let component_address = receipt.expect_commit(true).new_component_addresses()[0];
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .set_metadata(component_address, "name", "Metadata Test")
+        .set_metadata(component_address, "tags", ["test", "metadata"])
+        .build();
+    test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(component_address, "get_standard", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let (name, tags): (Option<String>, Option<Vec<String>>) =
+        receipt.expect_commit_success().output(1);
+    assert_eq!(name, Some("Metadata Test".to_string()));
+    assert_eq!(tags, Some(vec!["test".to_string(), "metadata".to_string()]));
+}
+
 #[test]
 fn verify_metadata_set_and_get_success() {
     // Arrange
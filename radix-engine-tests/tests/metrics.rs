@@ -0,0 +1,106 @@
+mod package_loader;
+
+use package_loader::PackageLoader;
+use radix_engine::metrics::*;
+use radix_engine::transaction::{CostingParameters, ExecutionConfig, TransactionReceipt};
+use radix_engine::types::*;
+use scrypto_unit::*;
+use std::sync::Arc;
+use transaction::prelude::*;
+
+fn execute_with_metrics(
+    test_runner: &mut DefaultTestRunner,
+    manifest: TransactionManifestV1,
+    metrics: Arc<InMemoryMetrics>,
+) -> TransactionReceipt {
+    let nonce = test_runner.next_transaction_nonce();
+    test_runner.execute_transaction(
+        TestTransaction::new_from_nonce(manifest, nonce)
+            .prepare()
+            .expect("expected transaction to be preparable")
+            .get_executable(btreeset!()),
+        CostingParameters::default(),
+        ExecutionConfig::for_test_transaction().with_metrics(metrics),
+    )
+}
+
+#[test]
+fn executed_transaction_reports_engine_metrics() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let metrics = Arc::new(InMemoryMetrics::default());
+
+    // Act
+    let receipt = execute_with_metrics(
+        &mut test_runner,
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .get_free_xrd_from_faucet()
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build(),
+        metrics.clone(),
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert!(metrics.counter(EngineCounter::SubstatesRead) > 0);
+    assert!(metrics.counter(EngineCounter::SubstatesWritten) > 0);
+    assert!(metrics.counter(EngineCounter::DatabaseReads) > 0);
+    assert!(metrics.counter(EngineCounter::Invocations) > 0);
+    assert!(metrics.counter(EngineCounter::NativeInvocations) > 0);
+    assert_eq!(metrics.counter(EngineCounter::WasmInvocations), 0);
+    assert_eq!(
+        metrics.histogram(EngineHistogram::ExecutionCostUnits),
+        vec![receipt
+            .fee_summary
+            .total_execution_cost_units_consumed
+            .into()]
+    );
+    assert_eq!(
+        metrics.histogram(EngineHistogram::FinalizationCostUnits),
+        vec![receipt
+            .fee_summary
+            .total_finalization_cost_units_consumed
+            .into()]
+    );
+}
+
+#[test]
+fn wasm_invocations_report_module_cache_usage() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().without_global_wasm_cache().build();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("component"));
+    let metrics = Arc::new(InMemoryMetrics::default());
+
+    // Act
+    let receipt = execute_with_metrics(
+        &mut test_runner,
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(
+                package_address,
+                "ComponentTest",
+                "blueprint_name_function",
+                manifest_args!(),
+            )
+            .call_function(
+                package_address,
+                "ComponentTest",
+                "blueprint_name_function",
+                manifest_args!(),
+            )
+            .build(),
+        metrics.clone(),
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(metrics.counter(EngineCounter::WasmInvocations), 2);
+    assert!(metrics.counter(EngineCounter::WasmModuleCacheHits) >= 1);
+    assert_eq!(
+        metrics.counter(EngineCounter::WasmModuleCacheHits)
+            + metrics.counter(EngineCounter::WasmModuleCacheMisses),
+        2
+    );
+}
@@ -1399,3 +1399,98 @@ fn cant_create_non_fungible_with_id_type_does_not_match() {
         )
     });
 }
+
+#[derive(ScryptoSbor, ManifestSbor, NonFungibleData)]
+struct BlobReferencingData {
+    image: Hash,
+}
+
+#[test]
+fn can_mint_non_fungible_with_large_blob() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_freely_mintable_and_burnable_non_fungible_resource(
+        OwnerRole::None,
+        NonFungibleIdType::Integer,
+        None::<Vec<(NonFungibleLocalId, BlobReferencingData)>>,
+        account,
+    );
+    // Spans multiple storage chunks
+    let blob: Vec<u8> = (0..600 * 1024).map(|i| (i % 251) as u8).collect();
+    let blob_hash = hash(&blob);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .mint_non_fungible_with_blobs(
+            resource_address,
+            [(
+                NonFungibleLocalId::integer(1),
+                BlobReferencingData { image: blob_hash },
+            )],
+            vec![blob.clone()],
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .call_method(
+            resource_address,
+            NON_FUNGIBLE_RESOURCE_MANAGER_GET_NON_FUNGIBLE_BLOB_IDENT,
+            NonFungibleResourceManagerGetNonFungibleBlobInput { hash: blob_hash },
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let commit = receipt.expect_commit_success();
+    let stored_blob: Option<Vec<u8>> = commit.output(3);
+    assert_eq!(stored_blob, Some(blob));
+    let data: BlobReferencingData =
+        test_runner.get_non_fungible_data(resource_address, NonFungibleLocalId::integer(1));
+    assert_eq!(data.image, blob_hash);
+}
+
+#[test]
+fn cannot_mint_non_fungible_with_blob_of_mismatching_hash() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_freely_mintable_and_burnable_non_fungible_resource(
+        OwnerRole::None,
+        NonFungibleIdType::Integer,
+        None::<Vec<(NonFungibleLocalId, BlobReferencingData)>>,
+        account,
+    );
+    let wrong_hash = hash(b"some other content");
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .then(|mut builder| {
+            let blob_ref = builder.add_blob(vec![7u8; 1024]);
+            builder.call_method(
+                resource_address,
+                NON_FUNGIBLE_RESOURCE_MANAGER_MINT_WITH_BLOBS_IDENT,
+                NonFungibleResourceManagerMintWithBlobsManifestInput {
+                    entries: indexmap!(
+                        NonFungibleLocalId::integer(1) => (
+                            to_manifest_value_and_unwrap!(&BlobReferencingData { image: wrong_hash }),
+                        )
+                    ),
+                    blobs: indexmap!(wrong_hash => blob_ref),
+                },
+            )
+        })
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::NonFungibleResourceManagerError(
+                NonFungibleResourceManagerError::BlobHashMismatch { .. }
+            ))
+        )
+    });
+}
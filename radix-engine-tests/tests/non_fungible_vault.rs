@@ -52,6 +52,89 @@ fn get_non_fungibles_on_vault_with_size_less_than_vault_size_should_return() {
     get_non_fungibles_on_vault(100, 99, 99);
 }
 
+#[test]
+fn get_non_fungibles_on_vault_page_by_page_should_return_all_ids() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package = test_runner.publish_package_simple(PackageLoader::get("non_fungible"));
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(package, "BigVault", "new", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let result = receipt.expect_commit_success();
+    let component_address = result.new_component_addresses()[0];
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(component_address, "mint", manifest_args!(100usize))
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success();
+
+    // Act
+    let mut ids = BTreeSet::new();
+    let mut cursor: Option<NonFungibleLocalId> = None;
+    let mut page_count = 0;
+    loop {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                component_address,
+                "non_fungibles_page",
+                manifest_args!(30u32, cursor.clone()),
+            )
+            .build();
+        let receipt = test_runner.execute_manifest(manifest, vec![]);
+        let page: NonFungibleLocalIdsPage = receipt.expect_commit_success().output(1);
+        page_count += 1;
+        for id in page.ids {
+            assert!(ids.insert(id), "Each ID should be returned exactly once");
+        }
+        cursor = page.next_cursor;
+        if cursor.is_none() {
+            break;
+        }
+    }
+
+    // Assert
+    assert_eq!(ids.len(), 100);
+    assert_eq!(page_count, 4);
+}
+
+#[test]
+fn get_non_fungibles_on_vault_page_with_zero_limit_should_fail() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package = test_runner.publish_package_simple(PackageLoader::get("non_fungible"));
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(package, "BigVault", "new", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let component_address = receipt.expect_commit_success().new_component_addresses()[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            component_address,
+            "non_fungibles_page",
+            manifest_args!(0u32, Option::<NonFungibleLocalId>::None),
+        )
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::NonFungibleVaultError(
+                NonFungibleVaultError::InvalidPageLimit
+            ))
+        )
+    });
+}
+
 #[test]
 fn withdraw_1_from_empty_non_fungible_vault_should_return_error() {
     // Arrange
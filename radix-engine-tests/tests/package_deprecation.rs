@@ -0,0 +1,149 @@
+mod package_loader;
+
+use package_loader::PackageLoader;
+use radix_engine::blueprints::package::PackageError;
+use radix_engine::errors::{ApplicationError, RuntimeError};
+use radix_engine::system::system_db_reader::SystemDatabaseReader;
+use radix_engine::transaction::{TransactionReceipt, TransactionWarning};
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn emit_log(
+    test_runner: &mut DefaultTestRunner,
+    package_address: PackageAddress,
+) -> TransactionReceipt {
+    test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(
+                package_address,
+                "Logger",
+                "emit_log",
+                manifest_args!("Hello".to_owned()),
+            )
+            .build(),
+        vec![],
+    )
+}
+
+#[test]
+fn package_owner_can_deprecate_package() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, _) = test_runner.new_allocated_account();
+    let owner_badge = NonFungibleGlobalId::from_public_key(&public_key);
+    let package_address =
+        test_runner.publish_package_with_owner(PackageLoader::get("logger"), owner_badge.clone());
+    let successor =
+        test_runner.publish_package_with_owner(PackageLoader::get("logger"), owner_badge.clone());
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .deprecate_package(package_address, successor)
+            .build(),
+        vec![owner_badge],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    let reader = SystemDatabaseReader::new(test_runner.substate_db());
+    assert_eq!(
+        reader
+            .get_package_deprecation(package_address)
+            .map(|deprecation| deprecation.successor),
+        Some(successor)
+    );
+    assert_eq!(reader.get_package_deprecation(successor), None);
+}
+
+#[test]
+fn non_package_owner_cannot_deprecate_package() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, _) = test_runner.new_allocated_account();
+    let owner_badge = NonFungibleGlobalId::from_public_key(&public_key);
+    let package_address =
+        test_runner.publish_package_with_owner(PackageLoader::get("logger"), owner_badge);
+    let successor = test_runner.publish_package_simple(PackageLoader::get("logger"));
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .deprecate_package(package_address, successor)
+            .build(),
+        vec![],
+    );
+
+    // Assert
+    receipt.expect_auth_failure();
+}
+
+#[test]
+fn package_cannot_be_its_own_successor() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, _) = test_runner.new_allocated_account();
+    let owner_badge = NonFungibleGlobalId::from_public_key(&public_key);
+    let package_address =
+        test_runner.publish_package_with_owner(PackageLoader::get("logger"), owner_badge.clone());
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .deprecate_package(package_address, package_address)
+            .build(),
+        vec![owner_badge],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::PackageError(
+                PackageError::DeprecationSuccessorIsSelf
+            ))
+        )
+    });
+}
+
+#[test]
+fn invoking_deprecated_package_surfaces_warning() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, _) = test_runner.new_allocated_account();
+    let owner_badge = NonFungibleGlobalId::from_public_key(&public_key);
+    let package_address =
+        test_runner.publish_package_with_owner(PackageLoader::get("logger"), owner_badge.clone());
+    let successor =
+        test_runner.publish_package_with_owner(PackageLoader::get("logger"), owner_badge.clone());
+    test_runner
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .deprecate_package(package_address, successor)
+                .build(),
+            vec![owner_badge],
+        )
+        .expect_commit_success();
+
+    // Act
+    let deprecated_receipt = emit_log(&mut test_runner, package_address);
+    let successor_receipt = emit_log(&mut test_runner, successor);
+
+    // Assert
+    deprecated_receipt.expect_commit_success();
+    assert_eq!(
+        deprecated_receipt.warnings,
+        vec![TransactionWarning::DeprecatedPackageInvoked {
+            package_address,
+            successor,
+        }]
+    );
+    successor_receipt.expect_commit_success();
+    assert!(successor_receipt.warnings.is_empty());
+}
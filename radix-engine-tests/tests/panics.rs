@@ -1,3 +1,4 @@
+use radix_engine::blueprints::resource::AuthZone;
 use radix_engine::errors::*;
 use radix_engine::kernel::call_frame::*;
 use radix_engine::kernel::kernel_api::*;
@@ -91,6 +92,13 @@ impl KernelSubstateApi<SystemLockData> for MockKernel {
         panic1!()
     }
 
+    fn kernel_prefetch_substates(
+        &mut self,
+        _: Vec<(NodeId, PartitionNumber, SubstateKey)>,
+    ) -> Result<(), RuntimeError> {
+        panic1!()
+    }
+
     fn kernel_get_lock_data(&mut self, _: SubstateHandle) -> Result<SystemLockData, RuntimeError> {
         panic1!()
     }
@@ -137,6 +145,7 @@ impl KernelSubstateApi<SystemLockData> for MockKernel {
         &mut self,
         _: &NodeId,
         _: PartitionNumber,
+        _: Option<[u8; 2]>,
         _: u32,
     ) -> Result<Vec<(SortedKey, IndexedScryptoValue)>, RuntimeError> {
         panic1!()
@@ -151,6 +160,16 @@ impl KernelSubstateApi<SystemLockData> for MockKernel {
         panic1!()
     }
 
+    fn kernel_scan_keys_after<F: SubstateKeyContent + 'static>(
+        &mut self,
+        _: &NodeId,
+        _: PartitionNumber,
+        _: Option<&SubstateKey>,
+        _: u32,
+    ) -> Result<Vec<SubstateKey>, RuntimeError> {
+        panic1!()
+    }
+
     fn kernel_drain_substates<F: SubstateKeyContent + 'static>(
         &mut self,
         _: &NodeId,
@@ -192,4 +211,8 @@ impl<'g> KernelInternalApi<SystemConfig<Vm<'g, DefaultWasmEngine, NoExtension>>>
     fn kernel_read_proof(&mut self, _: &NodeId) -> Option<ProofSnapshot> {
         panic1!()
     }
+
+    fn kernel_read_auth_zone(&mut self, _: &NodeId) -> Option<AuthZone> {
+        panic1!()
+    }
 }
@@ -431,6 +431,86 @@ fn creating_a_pool_with_non_fungible_resources_fails() {
     )
 }
 
+#[test]
+fn creating_a_pool_with_more_than_max_resources_fails() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().without_trace().build();
+    let (_, _, account) = test_runner.new_account(false);
+
+    let resource_addresses: Vec<ResourceAddress> = (0..=MULTI_RESOURCE_POOL_MAX_RESOURCES)
+        .map(|_| {
+            test_runner.create_freely_mintable_and_burnable_fungible_resource(
+                OwnerRole::None,
+                None,
+                18,
+                account,
+            )
+        })
+        .collect();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .create_multi_resource_pool(OwnerRole::None, rule!(allow_all), resource_addresses)
+        .build();
+    let receipt = test_runner.execute_manifest_ignoring_fee(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|error| {
+        matches!(
+            error,
+            RuntimeError::ApplicationError(ApplicationError::MultiResourcePoolError(
+                MultiResourcePoolError::CantCreatePoolWithMoreThanMaxResources { max, actual }
+            )) if *max == MULTI_RESOURCE_POOL_MAX_RESOURCES && *actual == max + 1
+        )
+    })
+}
+
+#[test]
+fn contribution_and_redemption_through_manifest_builder_helpers() {
+    // Arrange
+    let mut test_runner = TestEnvironment::<3>::new([18, 18, 18]);
+    let [resource1, resource2, resource3] = test_runner.pool_resources;
+    let pool_address = test_runner.pool_component_address;
+    let pool_unit_address = test_runner.pool_unit_resource_address;
+    let account = test_runner.account_component_address;
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .mint_fungible(resource1, 10)
+        .mint_fungible(resource2, 20)
+        .mint_fungible(resource3, 30)
+        .take_all_from_worktop(resource1, "bucket1")
+        .take_all_from_worktop(resource2, "bucket2")
+        .take_all_from_worktop(resource3, "bucket3")
+        .contribute_to_multi_resource_pool(pool_address, ["bucket1", "bucket2", "bucket3"])
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    test_runner
+        .execute_manifest(manifest, true)
+        .expect_commit_success();
+    let pool_units = test_runner
+        .test_runner
+        .get_component_balance(account, pool_unit_address);
+    let manifest = ManifestBuilder::new()
+        .withdraw_from_account(account, pool_unit_address, pool_units)
+        .take_all_from_worktop(pool_unit_address, "pool_units")
+        .redeem_from_multi_resource_pool(pool_address, "pool_units")
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, true);
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.get_vault_amounts(true),
+        indexmap!(
+            resource1 => dec!("0"),
+            resource2 => dec!("0"),
+            resource3 => dec!("0")
+        )
+    );
+}
+
 #[test]
 fn redemption_of_pool_units_rounds_down_for_resources_with_divisibility_not_18() {
     // Arrange
@@ -143,6 +143,127 @@ fn test_assume_all_signature_proofs_flag_method_authorization() {
     result.unwrap().expect_commit_success();
 }
 
+// Batch previews need the `moka` cache, which `std` enables
+#[cfg(feature = "std")]
+#[test]
+fn test_preview_batch_matches_sequential_previews() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let network = NetworkDefinition::simulator();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let preview_flags = PreviewFlags {
+        use_free_credit: true,
+        assume_all_signature_proofs: true,
+        skip_epoch_check: false,
+    };
+    let preview_intents: Vec<PreviewIntentV1> = (1..=8)
+        .map(|i| {
+            let manifest = if i % 2 == 0 {
+                ManifestBuilder::new()
+                    .lock_fee(account, 500)
+                    .withdraw_from_account(account, XRD, i)
+                    .try_deposit_entire_worktop_or_abort(account, None)
+                    .build()
+            } else {
+                ManifestBuilder::new()
+                    .lock_fee(account, 500)
+                    .withdraw_from_account(account, XRD, Decimal::MAX)
+                    .try_deposit_entire_worktop_or_abort(account, None)
+                    .build()
+            };
+            prepare_matching_test_tx_and_preview_intent(
+                &mut test_runner,
+                &network,
+                manifest,
+                &preview_flags,
+            )
+            .1
+        })
+        .collect();
+
+    // Act
+    let batch_results = test_runner.preview_batch(preview_intents.clone(), &network);
+
+    // Assert
+    assert_eq!(batch_results.len(), preview_intents.len());
+    for (preview_intent, batch_result) in preview_intents.into_iter().zip(batch_results) {
+        let sequential_receipt = test_runner.preview(preview_intent, &network).unwrap();
+        let batch_receipt = batch_result.unwrap();
+        assert_eq!(
+            batch_receipt.is_commit_success(),
+            sequential_receipt.is_commit_success()
+        );
+        assert_eq!(
+            batch_receipt.fee_summary.total_cost(),
+            sequential_receipt.fee_summary.total_cost()
+        );
+    }
+}
+
+#[test]
+fn test_preview_includes_substate_value_changes_if_requested() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let network = NetworkDefinition::simulator();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let other_vault = test_runner.get_component_vaults(other_account, XRD)[0];
+    let manifest = ManifestBuilder::new()
+        .lock_fee(account, 500)
+        .withdraw_from_account(account, XRD, 1)
+        .try_deposit_entire_worktop_or_abort(other_account, None)
+        .build();
+    let preview_flags = PreviewFlags {
+        use_free_credit: true,
+        assume_all_signature_proofs: true,
+        skip_epoch_check: false,
+    };
+    let (_, preview_intent_without_values) = prepare_matching_test_tx_and_preview_intent(
+        &mut test_runner,
+        &network,
+        manifest,
+        &preview_flags,
+    );
+    let mut preview_intent = PreviewIntentV2::from(preview_intent_without_values.clone());
+    preview_intent
+        .extensions
+        .insert(PreviewExtension::IncludeSubstateValueChanges);
+
+    // Act
+    let receipt = test_runner.preview(preview_intent, &network).unwrap();
+    let receipt_without_values = test_runner
+        .preview(preview_intent_without_values, &network)
+        .unwrap();
+
+    // Assert
+    let balance_change = receipt
+        .expect_commit_success()
+        .substate_value_changes
+        .as_ref()
+        .unwrap()
+        .iter()
+        .find(|change| {
+            change.node_id == other_vault
+                && change.partition_number == MAIN_BASE_PARTITION
+                && change.substate_key == SubstateKey::Field(0u8)
+        })
+        .cloned()
+        .unwrap();
+    let before = balance_change.before.unwrap();
+    let after = balance_change.after.unwrap();
+    assert_ne!(before.value, after.value);
+    // The payload is rendered against the schema of the vault's balance field
+    let annotated_before = before.annotated_payload.unwrap();
+    let annotated_after = after.annotated_payload.unwrap();
+    assert!(annotated_before.contains("amount"));
+    assert!(annotated_after.contains("amount"));
+    assert_ne!(annotated_before, annotated_after);
+    assert!(receipt_without_values
+        .expect_commit_success()
+        .substate_value_changes
+        .is_none());
+}
+
 fn prepare_matching_test_tx_and_preview_intent(
     test_runner: &mut DefaultTestRunner,
     network: &NetworkDefinition,
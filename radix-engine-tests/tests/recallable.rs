@@ -1,8 +1,9 @@
 mod package_loader;
 
 use package_loader::PackageLoader;
+use radix_engine::blueprints::resource::VaultError;
 use radix_engine::errors::{
-    CallFrameError, KernelError, RejectionReason, RuntimeError, SystemModuleError,
+    ApplicationError, CallFrameError, KernelError, RejectionReason, RuntimeError, SystemModuleError,
 };
 use radix_engine::kernel::call_frame::{CreateFrameError, PassMessageError};
 use radix_engine::system::system_modules::auth::AuthError;
@@ -226,3 +227,148 @@ fn test_recall_on_received_direct_access_reference_which_is_same_as_self() {
     // Assert
     receipt.expect_commit_success();
 }
+
+fn create_resource_controller(
+    test_runner: &mut DefaultTestRunner,
+    account: ComponentAddress,
+) -> (ComponentAddress, ResourceAddress, InternalAddress) {
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("recall"));
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(
+                package_address,
+                "ResourceController",
+                "new",
+                manifest_args!(),
+            )
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build(),
+        vec![],
+    );
+    let result = receipt.expect_commit_success();
+    let component_address = result.new_component_addresses()[0];
+    let resource_address = result.new_resource_addresses()[0];
+    let vault_id = test_runner.get_component_vaults(account, resource_address)[0];
+
+    (
+        component_address,
+        resource_address,
+        InternalAddress::new_or_panic(vault_id.into()),
+    )
+}
+
+#[test]
+fn resource_manager_can_recall_from_vault_of_its_resource() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let (controller, resource_address, vault) =
+        create_resource_controller(&mut test_runner, account);
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(controller, "recall", manifest_args!(vault, dec!("20")))
+            .try_deposit_entire_worktop_or_abort(other_account, None)
+            .build(),
+        vec![],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.get_component_balance(account, resource_address),
+        dec!("480")
+    );
+    assert_eq!(
+        test_runner.get_component_balance(other_account, resource_address),
+        dec!("20")
+    );
+}
+
+#[test]
+fn resource_manager_can_freeze_and_unfreeze_vault_of_its_resource() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (controller, resource_address, vault) =
+        create_resource_controller(&mut test_runner, account);
+    let withdraw_manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, resource_address, dec!("1"))
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(controller, "freeze_withdraw", manifest_args!(vault))
+            .build(),
+        vec![],
+    );
+    receipt.expect_commit_success();
+    let frozen_receipt = test_runner.execute_manifest(
+        withdraw_manifest.clone(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(controller, "unfreeze_withdraw", manifest_args!(vault))
+            .build(),
+        vec![],
+    );
+    receipt.expect_commit_success();
+    let unfrozen_receipt = test_runner.execute_manifest(
+        withdraw_manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    frozen_receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::VaultError(VaultError::VaultIsFrozen))
+        )
+    });
+    unfrozen_receipt.expect_commit_success();
+}
+
+#[test]
+fn resource_manager_cannot_recall_from_vault_of_other_resource() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let (controller, _, _) = create_resource_controller(&mut test_runner, account);
+    let other_resource_address = test_runner.create_recallable_token(account);
+    let other_vault_id = test_runner.get_component_vaults(account, other_resource_address)[0];
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                controller,
+                "recall",
+                manifest_args!(
+                    InternalAddress::new_or_panic(other_vault_id.into()),
+                    dec!("1")
+                ),
+            )
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build(),
+        vec![],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| match e {
+        RuntimeError::ApplicationError(ApplicationError::PanicMessage(message)) => {
+            message.contains("The vault does not hold this resource")
+        }
+        _ => false,
+    });
+}
@@ -0,0 +1,84 @@
+use radix_engine::blueprints::resource::MintFungibleResourceEvent;
+use radix_engine::transaction::TransactionReceipt;
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn mint_and_transfer(
+    test_runner: &mut DefaultTestRunner,
+) -> (
+    TransactionReceipt,
+    ComponentAddress,
+    ComponentAddress,
+    ResourceAddress,
+) {
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let token = test_runner.create_freely_mintable_fungible_resource(
+        OwnerRole::None,
+        Some(dec!("100")),
+        18,
+        account,
+    );
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .mint_fungible(token, dec!("10"))
+        .withdraw_from_account(account, token, dec!("5"))
+        .try_deposit_entire_worktop_or_abort(other_account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    (receipt, account, other_account, token)
+}
+
+#[test]
+fn receipt_assertions_can_be_chained() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+
+    // Act
+    let (receipt, account, other_account, token) = mint_and_transfer(&mut test_runner);
+
+    // Assert
+    assert_receipt!(test_runner, receipt)
+        .committed()
+        .new_components(0)
+        .new_resources(0)
+        .event::<MintFungibleResourceEvent>(|e| e.amount == dec!("10"))
+        .balance_change(account, token, dec!("-5"))
+        .balance_change(other_account, token, dec!("15"));
+}
+
+#[test]
+#[should_panic(expected = "to change by 10, but it changed by 15")]
+fn receipt_assertions_panic_on_unexpected_balance_change() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+
+    // Act
+    let (receipt, _, other_account, token) = mint_and_transfer(&mut test_runner);
+
+    // Assert
+    assert_receipt!(test_runner, receipt)
+        .committed()
+        .balance_change(other_account, token, dec!("10"));
+}
+
+#[test]
+#[should_panic(expected = "events of type MintFungibleResourceEvent satisfies the predicate")]
+fn receipt_assertions_panic_on_unmatched_event() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+
+    // Act
+    let (receipt, _, _, _) = mint_and_transfer(&mut test_runner);
+
+    // Assert
+    assert_receipt!(test_runner, receipt)
+        .committed()
+        .event::<MintFungibleResourceEvent>(|e| e.amount == dec!("5"));
+}
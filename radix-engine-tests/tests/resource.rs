@@ -1,7 +1,9 @@
 mod package_loader;
 
 use package_loader::PackageLoader;
-use radix_engine::blueprints::resource::FungibleResourceManagerError;
+use radix_engine::blueprints::resource::{
+    FungibleResourceManagerError, NonFungibleResourceManagerError,
+};
 use radix_engine::errors::{ApplicationError, RuntimeError, SystemModuleError};
 use radix_engine::system::system_modules::auth::AuthError;
 use radix_engine::types::*;
@@ -643,3 +645,210 @@ fn can_use_non_fungible_types_in_interface() {
     // Assert
     receipt.expect_commit_success();
 }
+
+fn create_fungible_with_max_supply(
+    test_runner: &mut DefaultTestRunner,
+    initial_supply: Decimal,
+    max_supply: Decimal,
+) -> TransactionReceipt {
+    let (_, _, account) = test_runner.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .add_instruction_advanced(InstructionV1::CallFunction {
+            package_address: RESOURCE_PACKAGE.into(),
+            blueprint_name: FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT.to_string(),
+            function_name: FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_MAX_SUPPLY_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(
+                &FungibleResourceManagerCreateWithMaxSupplyManifestInput {
+                    owner_role: OwnerRole::None,
+                    divisibility: 18,
+                    max_supply,
+                    initial_supply: Some(initial_supply),
+                    resource_roles: FungibleResourceRoles {
+                        mint_roles: mint_roles! {
+                            minter => rule!(allow_all);
+                            minter_updater => rule!(deny_all);
+                        },
+                        ..Default::default()
+                    },
+                    metadata: ModuleConfig::default(),
+                    address_reservation: None,
+                }
+            ),
+        })
+        .0
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    test_runner.execute_manifest(manifest, vec![])
+}
+
+#[test]
+fn can_mint_fungible_up_to_max_supply() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let resource_address = create_fungible_with_max_supply(&mut test_runner, dec!(90), dec!(100))
+        .expect_commit_success()
+        .new_resource_addresses()[0];
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .mint_fungible(resource_address, dec!(10))
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+    let max_supply: Option<Decimal> = test_runner
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_method(
+                    resource_address,
+                    RESOURCE_MANAGER_GET_MAX_SUPPLY_IDENT,
+                    manifest_args!(),
+                )
+                .build(),
+            vec![],
+        )
+        .expect_commit_success()
+        .output(1);
+    assert_eq!(max_supply, Some(dec!(100)));
+}
+
+#[test]
+fn mint_fungible_beyond_max_supply_should_fail() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let resource_address = create_fungible_with_max_supply(&mut test_runner, dec!(90), dec!(100))
+        .expect_commit_success()
+        .new_resource_addresses()[0];
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .mint_fungible(resource_address, dec!(11))
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::FungibleResourceManagerError(
+                FungibleResourceManagerError::MaxSupplyExceeded { max, actual }
+            )) if *max == dec!(100) && *actual == dec!(101)
+        )
+    });
+}
+
+#[test]
+fn create_fungible_with_initial_supply_above_max_supply_should_fail() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+
+    // Act
+    let receipt = create_fungible_with_max_supply(&mut test_runner, dec!(101), dec!(100));
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::FungibleResourceManagerError(
+                FungibleResourceManagerError::MaxSupplyExceeded { .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn resource_created_without_max_supply_has_no_max_supply() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(dec!(100), 18, account);
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(
+                resource_address,
+                RESOURCE_MANAGER_GET_MAX_SUPPLY_IDENT,
+                manifest_args!(),
+            )
+            .build(),
+        vec![],
+    );
+
+    // Assert
+    let max_supply: Option<Decimal> = receipt.expect_commit_success().output(1);
+    assert_eq!(max_supply, None);
+}
+
+#[test]
+fn mint_non_fungible_beyond_max_supply_should_fail() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .add_instruction_advanced(InstructionV1::CallFunction {
+            package_address: RESOURCE_PACKAGE.into(),
+            blueprint_name: NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT.to_string(),
+            function_name: NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_MAX_SUPPLY_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(
+                &NonFungibleResourceManagerCreateWithMaxSupplyManifestInput {
+                    owner_role: OwnerRole::None,
+                    id_type: NonFungibleIdType::Integer,
+                    non_fungible_schema:
+                        NonFungibleDataSchema::new_local_without_self_package_replacement::<()>(),
+                    max_supply: dec!(1),
+                    entries: None,
+                    resource_roles: NonFungibleResourceRoles {
+                        mint_roles: mint_roles! {
+                            minter => rule!(allow_all);
+                            minter_updater => rule!(deny_all);
+                        },
+                        ..Default::default()
+                    },
+                    metadata: ModuleConfig::default(),
+                    address_reservation: None,
+                }
+            ),
+        })
+        .0
+        .build();
+    let resource_address = test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success()
+        .new_resource_addresses()[0];
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .mint_non_fungible(
+            resource_address,
+            [
+                (NonFungibleLocalId::integer(1), ()),
+                (NonFungibleLocalId::integer(2), ()),
+            ],
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::NonFungibleResourceManagerError(
+                NonFungibleResourceManagerError::MaxSupplyExceeded { .. }
+            ))
+        )
+    });
+}
@@ -3,6 +3,7 @@ mod package_loader;
 use package_loader::PackageLoader;
 use radix_engine::errors::*;
 use radix_engine::system::system_modules::auth::AuthError;
+use radix_engine::system::system_modules::costing::FeeTable;
 use radix_engine::transaction::TransactionReceipt;
 use radix_engine::types::*;
 use radix_engine_interface::api::node_modules::auth::AuthAddresses;
@@ -190,6 +191,110 @@ fn component_role_assignment_can_be_mutated_to_non_fungible_resource_through_man
     )));
 }
 
+#[test]
+fn component_roles_can_be_set_in_batch_through_manifest() {
+    // Arrange
+    let private_key = Secp256k1PrivateKey::from_u64(709).unwrap();
+    let public_key = private_key.public_key();
+    let virtual_badge_non_fungible_global_id = NonFungibleGlobalId::from_public_key(&public_key);
+    let mut test_runner = MutableRolesTestRunner::new_with_owner(rule!(require(
+        virtual_badge_non_fungible_global_id.clone()
+    )));
+    test_runner.add_initial_proof(virtual_badge_non_fungible_global_id);
+
+    // Act
+    let receipt = test_runner.set_role_rules(indexmap! {
+        RoleKey::new("borrow_funds_auth") => rule!(deny_all),
+        RoleKey::new("deposit_funds_auth") => rule!(deny_all),
+    });
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    let set_roles_events = result
+        .application_events
+        .iter()
+        .filter(|(event_type_identifier, _)| {
+            test_runner.test_runner.event_name(event_type_identifier) == "SetRolesEvent"
+        })
+        .count();
+    assert_eq!(set_roles_events, 1);
+    for method_name in ["borrow_funds", "deposit_funds"] {
+        let receipt = test_runner.execute_manifest(
+            MutableRolesTestRunner::manifest_builder()
+                .call_method(test_runner.component_address, method_name, manifest_args!())
+                .build(),
+        );
+        receipt.expect_specific_failure(|e| {
+            matches!(
+                e,
+                RuntimeError::SystemModuleError(SystemModuleError::AuthError(..))
+            )
+        });
+    }
+}
+
+#[test]
+fn setting_roles_in_batch_costs_a_role_update_per_further_role() {
+    // Arrange
+    let private_key = Secp256k1PrivateKey::from_u64(709).unwrap();
+    let public_key = private_key.public_key();
+    let virtual_badge_non_fungible_global_id = NonFungibleGlobalId::from_public_key(&public_key);
+    let mut test_runner = MutableRolesTestRunner::new_with_owner(rule!(require(
+        virtual_badge_non_fungible_global_id.clone()
+    )));
+    test_runner.add_initial_proof(virtual_badge_non_fungible_global_id);
+
+    // Act
+    let receipt = test_runner.set_role_rules(indexmap! {
+        RoleKey::new("borrow_funds_auth") => rule!(deny_all),
+        RoleKey::new("deposit_funds_auth") => rule!(deny_all),
+    });
+
+    // Assert
+    receipt.expect_commit_success();
+    let set_cost = FeeTable::new().run_native_code_cost(
+        &ROLE_ASSIGNMENT_MODULE_PACKAGE,
+        ROLE_ASSIGNMENT_SET_IDENT,
+        &0,
+    );
+    let execution_cost_breakdown = &receipt
+        .fee_details
+        .as_ref()
+        .unwrap()
+        .execution_cost_breakdown;
+    assert_eq!(
+        execution_cost_breakdown.get("RunNativeCode::set").cloned(),
+        Some(set_cost)
+    );
+}
+
+#[test]
+fn component_roles_cannot_be_set_in_batch_when_required_proofs_are_not_present() {
+    // Arrange
+    let private_key = Secp256k1PrivateKey::from_u64(709).unwrap();
+    let public_key = private_key.public_key();
+    let virtual_badge_non_fungible_global_id = NonFungibleGlobalId::from_public_key(&public_key);
+    let mut test_runner = MutableRolesTestRunner::new_with_owner(rule!(require(
+        virtual_badge_non_fungible_global_id.clone()
+    )));
+
+    // Act
+    let receipt = test_runner.set_role_rules(indexmap! {
+        RoleKey::new("borrow_funds_auth") => rule!(allow_all),
+        RoleKey::new("deposit_funds_auth") => rule!(allow_all),
+    });
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::AuthError(AuthError::Unauthorized(
+                ..
+            )))
+        )
+    });
+}
+
 #[test]
 fn assert_access_rule_through_component_when_not_fulfilled_fails() {
     // Arrange
@@ -948,6 +1053,13 @@ impl MutableRolesTestRunner {
         self.execute_manifest(manifest)
     }
 
+    pub fn set_role_rules(&mut self, roles: IndexMap<RoleKey, AccessRule>) -> TransactionReceipt {
+        let manifest = Self::manifest_builder()
+            .set_roles(self.component_address, ModuleId::Main, roles)
+            .build();
+        self.execute_manifest(manifest)
+    }
+
     pub fn get_role(&mut self, role_key: RoleKey) -> TransactionReceipt {
         let manifest = Self::manifest_builder()
             .get_role(self.component_address, ModuleId::Main, role_key)
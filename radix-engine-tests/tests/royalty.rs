@@ -3,7 +3,10 @@ mod package_loader;
 use package_loader::PackageLoader;
 use radix_engine::blueprints::package::PackageError;
 use radix_engine::errors::{ApplicationError, RuntimeError, SystemError};
+use radix_engine::system::attached_modules::role_assignment::RoleAssignmentError;
 use radix_engine::system::attached_modules::royalty::ComponentRoyaltyError;
+use radix_engine::system::system_modules::costing::RoyaltyRecipient;
+use radix_engine::transaction::TransactionReceipt;
 use radix_engine::types::*;
 use radix_engine_interface::blueprints::resource::FromPublicKey;
 use scrypto_unit::*;
@@ -222,6 +225,239 @@ fn test_royalty_accumulation_when_failure() {
     );
 }
 
+#[test]
+fn test_royalty_breakdown_in_receipt() {
+    let (
+        mut test_runner,
+        account,
+        public_key,
+        package_address,
+        component_address,
+        _owner_badge_resource,
+    ) = set_up_package_and_component();
+
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .call_method(component_address, "paid_method", manifest_args!())
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    receipt.expect_commit(true);
+    let breakdown = receipt.royalty_breakdown();
+    assert_eq!(breakdown.len(), 2);
+    for (recipient, vault_id, amount) in breakdown {
+        assert_eq!(*vault_id, recipient.vault_id());
+        match recipient {
+            RoyaltyRecipient::Package(address, _) => {
+                assert_eq!(*address, package_address);
+                assert_eq!(*amount, dec!("2"));
+            }
+            RoyaltyRecipient::Component(address, _) => {
+                assert_eq!(*address, component_address);
+                assert_eq!(*amount, dec!(1));
+            }
+        }
+    }
+    assert_eq!(
+        breakdown
+            .iter()
+            .fold(Decimal::zero(), |sum, (_, _, amount)| sum
+                .checked_add(*amount)
+                .unwrap()),
+        receipt.fee_summary.total_royalty_cost_in_xrd
+    );
+}
+
+#[test]
+fn test_royalty_breakdown_is_empty_when_failure() {
+    let (
+        mut test_runner,
+        account,
+        public_key,
+        _package_address,
+        component_address,
+        _owner_badge_resource,
+    ) = set_up_package_and_component();
+
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .call_method(component_address, "paid_method_panic", manifest_args!())
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    receipt.expect_commit_failure();
+    assert!(receipt.royalty_breakdown().is_empty());
+}
+
+#[test]
+fn test_claim_royalty_for_blueprint_with_dedicated_claimer() {
+    let (
+        mut test_runner,
+        account,
+        public_key,
+        package_address,
+        component_address,
+        owner_badge_resource,
+    ) = set_up_package_and_component();
+    let (maintainer_public_key, _, maintainer_account) = test_runner.new_allocated_account();
+
+    // Hand the blueprint's royalties to its maintainer
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .create_proof_from_account_of_non_fungibles(
+                account,
+                owner_badge_resource,
+                [NonFungibleLocalId::integer(1)],
+            )
+            .set_blueprint_royalty_claimer(
+                package_address,
+                "RoyaltyTest",
+                rule!(require(NonFungibleGlobalId::from_public_key(
+                    &maintainer_public_key
+                ))),
+            )
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    receipt.expect_commit_success();
+
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .call_method(component_address, "paid_method", manifest_args!())
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.inspect_package_royalty(package_address),
+        Some(dec!("0"))
+    );
+
+    // The package owner can't claim the blueprint's royalties
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .create_proof_from_account_of_non_fungibles(
+                account,
+                owner_badge_resource,
+                [NonFungibleLocalId::integer(1)],
+            )
+            .claim_package_royalties_for_blueprint(package_address, "RoyaltyTest")
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemError(SystemError::AssertAccessRuleFailed)
+        )
+    });
+
+    // The maintainer can
+    let maintainer_pre_balance = test_runner.get_component_balance(maintainer_account, XRD);
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .claim_package_royalties_for_blueprint(package_address, "RoyaltyTest")
+            .try_deposit_entire_worktop_or_abort(maintainer_account, None)
+            .build(),
+        vec![
+            NonFungibleGlobalId::from_public_key(&public_key),
+            NonFungibleGlobalId::from_public_key(&maintainer_public_key),
+        ],
+    );
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.get_component_balance(maintainer_account, XRD),
+        maintainer_pre_balance.checked_add(dec!("2")).unwrap()
+    );
+}
+
+#[test]
+fn cannot_set_royalty_claimer_for_unknown_blueprint() {
+    let (
+        mut test_runner,
+        account,
+        public_key,
+        package_address,
+        _component_address,
+        owner_badge_resource,
+    ) = set_up_package_and_component();
+
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .create_proof_from_account_of_non_fungibles(
+                account,
+                owner_badge_resource,
+                [NonFungibleLocalId::integer(1)],
+            )
+            .set_blueprint_royalty_claimer(package_address, "NoSuchBlueprint", rule!(allow_all))
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::PackageError(
+                PackageError::BlueprintNotFound(..)
+            ))
+        )
+    });
+}
+
+#[test]
+fn cannot_set_royalty_claimer_with_invalid_access_rule() {
+    let (
+        mut test_runner,
+        account,
+        public_key,
+        package_address,
+        _component_address,
+        owner_badge_resource,
+    ) = set_up_package_and_component();
+
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .create_proof_from_account_of_non_fungibles(
+                account,
+                owner_badge_resource,
+                [NonFungibleLocalId::integer(1)],
+            )
+            .set_blueprint_royalty_claimer(
+                package_address,
+                "RoyaltyTest",
+                rule!(require_weighted_threshold(
+                    3,
+                    vec![(require(XRD), 1), (require(ACCOUNT_OWNER_BADGE), 1)]
+                )),
+            )
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::PackageError(
+                PackageError::RoleAssignmentError(RoleAssignmentError::InvalidWeightedThreshold {
+                    threshold: 3,
+                    total_weight: 2,
+                })
+            ))
+        )
+    });
+}
+
 #[test]
 fn test_claim_royalty() {
     let (
@@ -475,6 +711,142 @@ fn cannot_set_royalty_after_locking() {
     });
 }
 
+fn create_component_with_royalty_limits(
+    max_royalty_per_transaction: Option<RoyaltyAmount>,
+    free_calls_per_transaction: u32,
+) -> (
+    DefaultTestRunner,
+    ComponentAddress,
+    Secp256k1PublicKey,
+    ComponentAddress,
+) {
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("royalty"));
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .call_function(
+                package_address,
+                "RoyaltyTest",
+                "create_component_with_royalty_limits",
+                manifest_args!(max_royalty_per_transaction, free_calls_per_transaction),
+            )
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    let component_address: ComponentAddress = receipt.expect_commit(true).output(1);
+
+    (test_runner, account, public_key, component_address)
+}
+
+fn call_paid_method_repeatedly(
+    test_runner: &mut DefaultTestRunner,
+    account: ComponentAddress,
+    public_key: &Secp256k1PublicKey,
+    component_address: ComponentAddress,
+    times: usize,
+) -> TransactionReceipt {
+    let mut builder = ManifestBuilder::new().lock_standard_test_fee(account);
+    for _ in 0..times {
+        builder = builder.call_method(component_address, "paid_method", manifest_args!());
+    }
+    test_runner.execute_manifest(
+        builder.build(),
+        vec![NonFungibleGlobalId::from_public_key(public_key)],
+    )
+}
+
+#[test]
+fn component_royalty_free_calls_are_not_charged() {
+    // Arrange
+    let (mut test_runner, account, public_key, component_address) =
+        create_component_with_royalty_limits(None, 2);
+
+    // Act
+    let receipt =
+        call_paid_method_repeatedly(&mut test_runner, account, &public_key, component_address, 3);
+
+    // Assert
+    receipt.expect_commit(true);
+    assert_eq!(
+        test_runner.inspect_component_royalty(component_address),
+        dec!("1")
+    );
+}
+
+#[test]
+fn component_royalty_free_calls_are_reset_in_each_transaction() {
+    // Arrange
+    let (mut test_runner, account, public_key, component_address) =
+        create_component_with_royalty_limits(None, 1);
+    call_paid_method_repeatedly(&mut test_runner, account, &public_key, component_address, 2)
+        .expect_commit(true);
+
+    // Act
+    let receipt =
+        call_paid_method_repeatedly(&mut test_runner, account, &public_key, component_address, 2);
+
+    // Assert
+    receipt.expect_commit(true);
+    assert_eq!(
+        test_runner.inspect_component_royalty(component_address),
+        dec!("2")
+    );
+}
+
+#[test]
+fn component_royalty_is_capped_per_transaction() {
+    // Arrange
+    let (mut test_runner, account, public_key, component_address) =
+        create_component_with_royalty_limits(Some(RoyaltyAmount::Xrd(dec!("2.5"))), 1);
+
+    // Act
+    let receipt =
+        call_paid_method_repeatedly(&mut test_runner, account, &public_key, component_address, 5);
+
+    // Assert
+    receipt.expect_commit(true);
+    assert_eq!(
+        test_runner.inspect_component_royalty(component_address),
+        dec!("2.5")
+    );
+    // The package royalty is not affected by the component's limits
+    assert_eq!(receipt.fee_summary.total_royalty_cost_in_xrd, dec!("12.5"));
+}
+
+#[test]
+fn cannot_initialize_component_royalty_with_negative_cap() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("royalty"));
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_standard_test_fee(account)
+            .call_function(
+                package_address,
+                "RoyaltyTest",
+                "create_component_with_royalty_limits",
+                manifest_args!(Some(RoyaltyAmount::Xrd(dec!("-1"))), 0u32),
+            )
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::ApplicationError(ApplicationError::ComponentRoyaltyError(
+                ComponentRoyaltyError::RoyaltyAmountIsNegative(..)
+            ))
+        )
+    });
+}
+
 fn set_up_package_and_component() -> (
     DefaultTestRunner,
     ComponentAddress,
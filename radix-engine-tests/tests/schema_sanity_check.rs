@@ -332,6 +332,7 @@ fn is_safe_well_known_type(
         OWN_FUNGIBLE_VAULT_TYPE => true,
         OWN_NON_FUNGIBLE_VAULT_TYPE => true,
         OWN_KEY_VALUE_STORE_TYPE => true, // TODO: maybe unsafe?
+        OWN_INDEX_STORE_TYPE => true,
         OWN_GLOBAL_ADDRESS_RESERVATION_TYPE => true,
         DECIMAL_TYPE => true,
         PRECISE_DECIMAL_TYPE => true,
@@ -355,6 +356,8 @@ fn is_safe_well_known_type(
         RESOURCE_OR_NON_FUNGIBLE_LIST_TYPE => true,
         OWNER_ROLE_TYPE => true,
         ROLE_KEY_TYPE => true,
+        WEIGHTED_ACCESS_RULE_NODE_TYPE => true,
+        WEIGHTED_ACCESS_RULE_NODE_LIST_TYPE => true,
         MODULE_ID_TYPE => true,
         ATTACHED_MODULE_ID_TYPE => true,
         ROYALTY_AMOUNT_TYPE => true,
@@ -0,0 +1,111 @@
+use radix_engine::transaction::{BalanceChange, MetadataChange, StateDiff};
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn preview_state_diff(
+    test_runner: &mut DefaultTestRunner,
+    manifest: TransactionManifestV1,
+) -> (TransactionReceipt, StateDiff) {
+    let receipt = test_runner.preview_manifest_with_extensions(
+        manifest,
+        vec![],
+        0,
+        PreviewFlags {
+            use_free_credit: true,
+            assume_all_signature_proofs: false,
+            skip_epoch_check: false,
+        },
+        indexset!(PreviewExtension::DisableAuth),
+    );
+    let state_diff = StateDiff::new(
+        test_runner.substate_db(),
+        &receipt.expect_commit_success().state_updates,
+    );
+    (receipt, state_diff)
+}
+
+#[test]
+fn state_diff_describes_new_resource() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_fungible_resource(
+            OwnerRole::None,
+            true,
+            18,
+            FungibleResourceRoles::default(),
+            metadata! {
+                init {
+                    "name" => "Test Token".to_owned(), locked;
+                }
+            },
+            Some(dec!(100)),
+        )
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+
+    // Act
+    let (receipt, state_diff) = preview_state_diff(&mut test_runner, manifest);
+
+    // Assert
+    let resource_address = receipt.expect_commit_success().new_resource_addresses()[0];
+    assert_eq!(
+        state_diff
+            .new_entities
+            .get(&GlobalAddress::from(resource_address)),
+        Some(&Some(BlueprintId::new(
+            &RESOURCE_PACKAGE,
+            FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT
+        )))
+    );
+    assert!(state_diff
+        .vault_balance_changes
+        .values()
+        .any(|change| change == &(resource_address, BalanceChange::Fungible(dec!(100)))));
+    assert_eq!(
+        state_diff.metadata_changes[resource_address.as_node_id()]["name"],
+        MetadataChange::Added(MetadataValue::String("Test Token".to_owned()))
+    );
+
+    let encoder = AddressBech32Encoder::for_simulator();
+    let rendered = state_diff.display(&encoder).to_string();
+    assert!(rendered.contains(&resource_address.display(&encoder).to_string()));
+    assert!(rendered.contains("+ name: "));
+    assert!(rendered.contains("\"Test Token\""));
+}
+
+#[test]
+fn state_diff_describes_updated_metadata() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    test_runner
+        .execute_manifest(
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .set_metadata(account, "name", "Old")
+                .build(),
+            vec![NonFungibleGlobalId::from_public_key(&public_key)],
+        )
+        .expect_commit_success();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .set_metadata(account, "name", "New")
+        .build();
+
+    // Act
+    let (_, state_diff) = preview_state_diff(&mut test_runner, manifest);
+
+    // Assert
+    assert!(state_diff.new_entities.is_empty());
+    assert_eq!(
+        state_diff.metadata_changes[account.as_node_id()]["name"],
+        MetadataChange::Updated {
+            old: MetadataValue::String("Old".to_owned()),
+            new: MetadataValue::String("New".to_owned()),
+        }
+    );
+}
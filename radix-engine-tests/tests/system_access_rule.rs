@@ -10,6 +10,7 @@ use radix_engine::types::*;
 use radix_engine::vm::{OverridePackageCode, VmInvoke};
 use radix_engine_interface::api::{ClientApi, ModuleId};
 use radix_engine_interface::blueprints::package::PackageDefinition;
+use radix_engine_interface::rule;
 use radix_engine_queries::typed_substate_layout::{FunctionAuth, PackageError};
 use scrypto_unit::*;
 use transaction::builder::ManifestBuilder;
@@ -17,7 +18,7 @@ use transaction::builder::ManifestBuilder;
 #[test]
 fn creating_an_owner_access_rule_which_is_beyond_the_depth_limit_should_error() {
     let access_rule = create_access_rule_of_depth(MAX_ACCESS_RULE_DEPTH + 1);
-    creating_an_access_rule_which_is_beyond_the_depth_limit_should_error(
+    creating_an_invalid_access_rule_should_fail_with_error(
         AccessRuleCreation::OwnerCreation,
         access_rule,
         |e| {
@@ -34,7 +35,7 @@ fn creating_an_owner_access_rule_which_is_beyond_the_depth_limit_should_error()
 #[test]
 fn creating_a_regular_access_rule_which_is_beyond_the_depth_limit_should_error() {
     let access_rule = create_access_rule_of_depth(MAX_ACCESS_RULE_DEPTH + 1);
-    creating_an_access_rule_which_is_beyond_the_depth_limit_should_error(
+    creating_an_invalid_access_rule_should_fail_with_error(
         AccessRuleCreation::RoleCreation,
         access_rule,
         |e| {
@@ -51,7 +52,7 @@ fn creating_a_regular_access_rule_which_is_beyond_the_depth_limit_should_error()
 #[test]
 fn setting_an_owner_access_rule_which_is_beyond_the_depth_limit_should_error() {
     let access_rule = create_access_rule_of_depth(MAX_ACCESS_RULE_DEPTH + 1);
-    creating_an_access_rule_which_is_beyond_the_depth_limit_should_error(
+    creating_an_invalid_access_rule_should_fail_with_error(
         AccessRuleCreation::OwnerSet,
         access_rule,
         |e| {
@@ -68,7 +69,7 @@ fn setting_an_owner_access_rule_which_is_beyond_the_depth_limit_should_error() {
 #[test]
 fn setting_a_role_access_rule_which_is_beyond_the_depth_limit_should_error() {
     let access_rule = create_access_rule_of_depth(MAX_ACCESS_RULE_DEPTH + 1);
-    creating_an_access_rule_which_is_beyond_the_depth_limit_should_error(
+    creating_an_invalid_access_rule_should_fail_with_error(
         AccessRuleCreation::RoleSet,
         access_rule,
         |e| {
@@ -85,7 +86,7 @@ fn setting_a_role_access_rule_which_is_beyond_the_depth_limit_should_error() {
 #[test]
 fn creating_an_owner_access_rule_which_is_beyond_the_length_limit_should_error() {
     let access_rule = create_access_rule_of_length(MAX_ACCESS_RULE_NODES + 1);
-    creating_an_access_rule_which_is_beyond_the_depth_limit_should_error(
+    creating_an_invalid_access_rule_should_fail_with_error(
         AccessRuleCreation::OwnerCreation,
         access_rule,
         |e| {
@@ -102,7 +103,7 @@ fn creating_an_owner_access_rule_which_is_beyond_the_length_limit_should_error()
 #[test]
 fn creating_a_regular_access_rule_which_is_beyond_the_length_limit_should_error() {
     let access_rule = create_access_rule_of_length(MAX_ACCESS_RULE_NODES + 1);
-    creating_an_access_rule_which_is_beyond_the_depth_limit_should_error(
+    creating_an_invalid_access_rule_should_fail_with_error(
         AccessRuleCreation::RoleCreation,
         access_rule,
         |e| {
@@ -119,7 +120,7 @@ fn creating_a_regular_access_rule_which_is_beyond_the_length_limit_should_error(
 #[test]
 fn setting_an_owner_access_rule_which_is_beyond_the_length_limit_should_error() {
     let access_rule = create_access_rule_of_length(MAX_ACCESS_RULE_NODES + 1);
-    creating_an_access_rule_which_is_beyond_the_depth_limit_should_error(
+    creating_an_invalid_access_rule_should_fail_with_error(
         AccessRuleCreation::OwnerSet,
         access_rule,
         |e| {
@@ -136,7 +137,7 @@ fn setting_an_owner_access_rule_which_is_beyond_the_length_limit_should_error()
 #[test]
 fn setting_a_role_access_rule_which_is_beyond_the_length_limit_should_error() {
     let access_rule = create_access_rule_of_length(MAX_ACCESS_RULE_NODES + 1);
-    creating_an_access_rule_which_is_beyond_the_depth_limit_should_error(
+    creating_an_invalid_access_rule_should_fail_with_error(
         AccessRuleCreation::RoleSet,
         access_rule,
         |e| {
@@ -150,6 +151,29 @@ fn setting_a_role_access_rule_which_is_beyond_the_length_limit_should_error() {
     );
 }
 
+#[test]
+fn creating_a_weighted_threshold_access_rule_which_cannot_be_met_should_error() {
+    let access_rule = rule!(require_weighted_threshold(
+        3,
+        vec![(require(XRD), 1), (require(ACCOUNT_OWNER_BADGE), 1)]
+    ));
+    creating_an_invalid_access_rule_should_fail_with_error(
+        AccessRuleCreation::RoleCreation,
+        access_rule,
+        |e| {
+            matches!(
+                e,
+                RuntimeError::ApplicationError(ApplicationError::RoleAssignmentError(
+                    RoleAssignmentError::InvalidWeightedThreshold {
+                        threshold: 3,
+                        total_weight: 2,
+                    }
+                ))
+            )
+        },
+    );
+}
+
 #[test]
 fn package_function_access_rules_are_checked_for_depth_and_width() {
     // Arrange
@@ -223,7 +247,7 @@ enum AccessRuleCreation {
     RoleSet,
 }
 
-fn creating_an_access_rule_which_is_beyond_the_depth_limit_should_error<F>(
+fn creating_an_invalid_access_rule_should_fail_with_error<F>(
     access_rule_creation: AccessRuleCreation,
     access_rule: AccessRule,
     check_result: F,
@@ -0,0 +1,137 @@
+use radix_engine::errors::RuntimeError;
+use radix_engine::kernel::kernel_api::{KernelNodeApi, KernelSubstateApi};
+use radix_engine::system::system_callback::SystemLockData;
+use radix_engine::types::*;
+use radix_engine::utils::keccak256_hash;
+use radix_engine::vm::{OverridePackageCode, VmInvoke};
+use radix_engine_interface::api::ClientApi;
+use radix_engine_interface::blueprints::package::PackageDefinition;
+use scrypto_unit::*;
+use transaction::builder::ManifestBuilder;
+use transaction::signing::bls12381::Bls12381PrivateKey;
+use transaction::signing::ed25519::Ed25519PrivateKey;
+use transaction::signing::secp256k1::Secp256k1PrivateKey;
+
+const BLUEPRINT_NAME: &str = "MyBlueprint";
+const CUSTOM_PACKAGE_CODE_ID: u64 = 1024;
+
+#[derive(Clone)]
+struct TestInvoke;
+impl VmInvoke for TestInvoke {
+    fn invoke<Y>(
+        &mut self,
+        export_name: &str,
+        _input: &IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError> + KernelNodeApi + KernelSubstateApi<SystemLockData>,
+    {
+        let message = b"Hello Radix";
+        let other_message = b"Goodbye Radix";
+        match export_name {
+            "ed25519_verify" => {
+                let private_key = Ed25519PrivateKey::from_u64(1).unwrap();
+                let signature = private_key.sign(&hash(message));
+                let valid = api.ed25519_verify(message, &private_key.public_key(), &signature)?;
+                let invalid =
+                    api.ed25519_verify(other_message, &private_key.public_key(), &signature)?;
+                Ok(IndexedScryptoValue::from_typed(&(valid, invalid)))
+            }
+            "secp256k1_verify" => {
+                let private_key = Secp256k1PrivateKey::from_u64(1).unwrap();
+                let signature = private_key.sign(&hash(message));
+                let valid = api.secp256k1_verify(message, &private_key.public_key(), &signature)?;
+                let invalid =
+                    api.secp256k1_verify(other_message, &private_key.public_key(), &signature)?;
+                Ok(IndexedScryptoValue::from_typed(&(valid, invalid)))
+            }
+            "bls12381_v1_verify" => {
+                let private_key = Bls12381PrivateKey::from_u64(1).unwrap();
+                let signature = private_key.sign_v1(message);
+                let valid =
+                    api.bls12381_v1_verify(message, &private_key.public_key(), &signature)?;
+                let invalid =
+                    api.bls12381_v1_verify(other_message, &private_key.public_key(), &signature)?;
+                Ok(IndexedScryptoValue::from_typed(&(valid, invalid)))
+            }
+            "hash" => {
+                let keccak256 = api.keccak256_hash(b"Hello Radix")?;
+                let blake2b_256 = api.blake2b_256_hash(b"Hello Radix")?;
+                Ok(IndexedScryptoValue::from_typed(&(keccak256, blake2b_256)))
+            }
+            _ => Ok(IndexedScryptoValue::from_typed(&())),
+        }
+    }
+}
+
+fn call_test_function<T: ScryptoDecode>(function_name: &str) -> T {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new()
+        .with_custom_extension(OverridePackageCode::new(CUSTOM_PACKAGE_CODE_ID, TestInvoke))
+        .build();
+    let package_address = test_runner.publish_native_package(
+        CUSTOM_PACKAGE_CODE_ID,
+        PackageDefinition::new_functions_only_test_definition(
+            BLUEPRINT_NAME,
+            vec![
+                ("ed25519_verify", "ed25519_verify", false),
+                ("secp256k1_verify", "secp256k1_verify", false),
+                ("bls12381_v1_verify", "bls12381_v1_verify", false),
+                ("hash", "hash", false),
+            ],
+        ),
+    );
+
+    // Act
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee(test_runner.faucet_component(), 500u32)
+            .call_function(
+                package_address,
+                BLUEPRINT_NAME,
+                function_name,
+                manifest_args!(),
+            )
+            .build(),
+        vec![],
+    );
+
+    receipt.expect_commit_success().output(1)
+}
+
+#[test]
+fn ed25519_verify_should_only_accept_signature_of_message_hash() {
+    let (valid, invalid): (bool, bool) = call_test_function("ed25519_verify");
+
+    // Assert
+    assert!(valid);
+    assert!(!invalid);
+}
+
+#[test]
+fn secp256k1_verify_should_only_accept_signature_of_message_hash() {
+    let (valid, invalid): (bool, bool) = call_test_function("secp256k1_verify");
+
+    // Assert
+    assert!(valid);
+    assert!(!invalid);
+}
+
+#[test]
+fn bls12381_v1_verify_should_only_accept_signature_of_message() {
+    let (valid, invalid): (bool, bool) = call_test_function("bls12381_v1_verify");
+
+    // Assert
+    assert!(valid);
+    assert!(!invalid);
+}
+
+#[test]
+fn hash_functions_should_return_native_hashes() {
+    let (keccak256, blake2b_256): (Hash, Hash) = call_test_function("hash");
+
+    // Assert
+    assert_eq!(keccak256, keccak256_hash(b"Hello Radix"));
+    assert_eq!(blake2b_256, blake2b_256_hash(b"Hello Radix"));
+}
@@ -2,6 +2,8 @@ use radix_engine_common::types::{Epoch, Round};
 use radix_engine_interface::blueprints::consensus_manager::TimePrecision;
 use radix_engine_interface::time::UtcDateTime;
 use scrypto_unit::*;
+use std::time::Duration;
+use transaction::prelude::*;
 
 #[test]
 fn advancing_round_changes_app_facing_minute_resolution_clock() {
@@ -52,3 +54,62 @@ fn advancing_round_changes_internal_milli_timestamp() {
     // Assert
     assert_eq!(test_runner.get_current_proposer_timestamp_ms(), epoch_milli);
 }
+
+#[test]
+fn advancing_time_changes_app_facing_clock() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new()
+        .with_custom_genesis(CustomGenesis::default(
+            Epoch::of(1),
+            CustomGenesis::default_consensus_manager_config(),
+        ))
+        .build();
+    let start_milli = test_runner.get_current_proposer_timestamp_ms();
+
+    // Act
+    test_runner
+        .advance_time(Duration::from_secs(3600))
+        .expect_commit_success();
+
+    // Assert
+    let expected_milli = start_milli + 3_600_000;
+    assert_eq!(
+        test_runner.get_current_proposer_timestamp_ms(),
+        expected_milli
+    );
+    assert_eq!(
+        test_runner
+            .get_current_time(TimePrecision::Minute)
+            .seconds_since_unix_epoch,
+        expected_milli / 60_000 * 60
+    );
+}
+
+#[test]
+fn auto_advance_moves_clock_forward_after_each_manifest() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new()
+        .with_custom_genesis(CustomGenesis::default(
+            Epoch::of(1),
+            CustomGenesis::default_consensus_manager_config(),
+        ))
+        .with_auto_advance(AdvancePolicy::Time(Duration::from_secs(60)))
+        .build();
+    let start_milli = test_runner.get_current_proposer_timestamp_ms();
+
+    // Act
+    for _ in 0..3 {
+        test_runner
+            .execute_manifest(
+                ManifestBuilder::new().lock_fee_from_faucet().build(),
+                vec![],
+            )
+            .expect_commit_success();
+    }
+
+    // Assert
+    assert_eq!(
+        test_runner.get_current_proposer_timestamp_ms(),
+        start_milli + 180_000
+    );
+}
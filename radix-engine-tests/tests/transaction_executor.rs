@@ -150,6 +150,62 @@ fn test_normal_transaction_flow() {
     receipt.expect_commit_success();
 }
 
+#[test]
+fn failed_transaction_receipt_contains_partial_execution_snapshot_when_enabled() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .get_free_xrd_from_faucet()
+        .assert_worktop_contains(XRD, dec!("1000000000"))
+        .build();
+    let nonce = test_runner.next_transaction_nonce();
+
+    // Act
+    let receipt = test_runner.execute_transaction(
+        TestTransaction::new_from_nonce(manifest, nonce)
+            .prepare()
+            .expect("expected transaction to be preparable")
+            .get_executable(btreeset!()),
+        CostingParameters::default(),
+        ExecutionConfig::for_test_transaction().with_partial_execution_snapshot(true),
+    );
+
+    // Assert
+    receipt.expect_commit_failure();
+    let partial_execution = receipt
+        .partial_execution
+        .expect("Partial execution snapshot should be captured");
+    assert_eq!(partial_execution.instructions_completed, 2);
+    assert!(partial_execution.application_events.len() > 1);
+    assert!(partial_execution
+        .execution_cost_breakdown
+        .values()
+        .any(|cost_units| *cost_units > 0));
+}
+
+#[test]
+fn successful_transaction_receipt_does_not_contain_partial_execution_snapshot() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let manifest = ManifestBuilder::new().lock_fee_from_faucet().build();
+    let nonce = test_runner.next_transaction_nonce();
+
+    // Act
+    let receipt = test_runner.execute_transaction(
+        TestTransaction::new_from_nonce(manifest, nonce)
+            .prepare()
+            .expect("expected transaction to be preparable")
+            .get_executable(btreeset!()),
+        CostingParameters::default(),
+        ExecutionConfig::for_test_transaction().with_partial_execution_snapshot(true),
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert!(receipt.partial_execution.is_none());
+}
+
 fn get_validated(
     transaction: &NotarizedTransactionV1,
 ) -> Result<ValidatedNotarizedTransactionV1, TransactionValidationError> {
@@ -3,8 +3,9 @@ mod package_loader;
 use package_loader::PackageLoader;
 use radix_engine::{
     errors::{RuntimeError, SystemModuleError, VmError},
-    system::system_modules::limits::TransactionLimitsError,
-    transaction::{CostingParameters, ExecutionConfig},
+    system::system_modules::costing::CostingError,
+    system::system_modules::limits::{TransactionLimitsConfigError, TransactionLimitsError},
+    transaction::{CostingParameters, ExecutionConfig, TransactionReceipt},
     types::*,
     vm::wasm::WasmRuntimeError,
 };
@@ -385,3 +386,300 @@ fn test_allocating_buffers_exceeding_limit() {
         )
     });
 }
+
+#[test]
+fn test_instruction_exceeding_instruction_cost_limit_fails() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .set_instruction_cost_limit(1)
+        .get_free_xrd_from_faucet()
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::CostingError(
+                CostingError::InstructionCostUnitLimitExceeded { limit: 1, .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn test_instructions_within_instruction_cost_limit_succeed() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .set_instruction_cost_limit(10_000_000)
+        .get_free_xrd_from_faucet()
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+fn execute_with_execution_config(
+    test_runner: &mut DefaultTestRunner,
+    manifest: TransactionManifestV1,
+    execution_config: ExecutionConfig,
+) -> TransactionReceipt {
+    let nonce = test_runner.next_transaction_nonce();
+    let prepared = TestTransaction::new_from_nonce(manifest, nonce)
+        .prepare()
+        .unwrap();
+    test_runner.execute_transaction(
+        prepared.get_executable(btreeset!()),
+        CostingParameters::default(),
+        execution_config,
+    )
+}
+
+#[test]
+fn test_lowered_event_size_limit_is_enforced() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address =
+        test_runner.publish_package_simple(PackageLoader::get("transaction_limits"));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "TransactionLimitTest",
+            "emit_event_of_size",
+            manifest_args!(1024usize),
+        )
+        .build();
+    let receipt = execute_with_execution_config(
+        &mut test_runner,
+        manifest,
+        ExecutionConfig::for_test_transaction().with_limits(|limits| limits.max_event_size = 512),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::TransactionLimitsError(
+                TransactionLimitsError::EventSizeTooLarge { max: 512, .. }
+            ))
+        )
+    });
+}
+
+#[test]
+fn test_lowered_log_count_limit_is_enforced() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address =
+        test_runner.publish_package_simple(PackageLoader::get("transaction_limits"));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "TransactionLimitTest",
+            "emit_log_of_size",
+            manifest_args!(10usize),
+        )
+        .build();
+    let receipt = execute_with_execution_config(
+        &mut test_runner,
+        manifest,
+        ExecutionConfig::for_test_transaction().with_limits(|limits| limits.max_number_of_logs = 0),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::TransactionLimitsError(
+                TransactionLimitsError::TooManyLogs
+            ))
+        )
+    });
+}
+
+#[test]
+fn test_raised_event_size_limit_allows_larger_events() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address =
+        test_runner.publish_package_simple(PackageLoader::get("transaction_limits"));
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "TransactionLimitTest",
+            "emit_event_of_size",
+            manifest_args!(MAX_EVENT_SIZE + 1),
+        )
+        .build();
+    let receipt = execute_with_execution_config(
+        &mut test_runner,
+        manifest,
+        ExecutionConfig::for_test_transaction()
+            .with_limits(|limits| limits.max_event_size = MAX_EVENT_SIZE * 2),
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn test_lowered_wasm_memory_limit_is_enforced() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let code = wat2wasm(&include_str!("wasm/memory.wat").replace("${n}", "3"));
+    let package_address = test_runner.publish_package(
+        (code, single_function_package_definition("Test", "f")),
+        BTreeMap::new(),
+        OwnerRole::None,
+    );
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(package_address, "Test", "f", manifest_args!())
+        .build();
+    let receipt = execute_with_execution_config(
+        &mut test_runner,
+        manifest,
+        ExecutionConfig::for_test_transaction()
+            .with_limits(|limits| limits.max_wasm_memory_per_call_frame = 2 * 64 * 1024),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::TransactionLimitsError(
+                TransactionLimitsError::MaxWasmMemoryExceeded {
+                    actual: 262144,
+                    max: 131072,
+                }
+            ))
+        )
+    });
+}
+
+#[test]
+fn test_wasm_memory_within_limit_is_allowed() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let code = wat2wasm(&include_str!("wasm/memory.wat").replace("${n}", "3"));
+    let package_address = test_runner.publish_package(
+        (code, single_function_package_definition("Test", "f")),
+        BTreeMap::new(),
+        OwnerRole::None,
+    );
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(package_address, "Test", "f", manifest_args!())
+        .build();
+    let receipt = execute_with_execution_config(
+        &mut test_runner,
+        manifest,
+        ExecutionConfig::for_test_transaction()
+            .with_limits(|limits| limits.max_wasm_memory_per_call_frame = 4 * 64 * 1024),
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn test_wasm_memory_limit_does_not_replace_the_error_of_a_failed_invocation() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let code = wat2wasm(&include_str!("wasm/memory_grow_and_trap.wat").replace("${n}", "3"));
+    let package_address = test_runner.publish_package(
+        (code, single_function_package_definition("Test", "f")),
+        BTreeMap::new(),
+        OwnerRole::None,
+    );
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(package_address, "Test", "f", manifest_args!())
+        .build();
+    let receipt = execute_with_execution_config(
+        &mut test_runner,
+        manifest,
+        ExecutionConfig::for_test_transaction()
+            .with_limits(|limits| limits.max_wasm_memory_per_call_frame = 2 * 64 * 1024),
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::VmError(VmError::Wasm(WasmRuntimeError::ExecutionError(_)))
+        )
+    });
+}
+
+#[test]
+fn test_transaction_limits_validation() {
+    let limits = ExecutionConfig::for_test_transaction().limits();
+    assert_eq!(limits.validate(), Ok(()));
+
+    let mut zero_payload_size = limits.clone();
+    zero_payload_size.max_invoke_payload_size = 0;
+    assert_eq!(
+        zero_payload_size.validate(),
+        Err(TransactionLimitsConfigError::ZeroLimit(
+            "max_invoke_payload_size"
+        ))
+    );
+
+    let mut oversized_substate = limits.clone();
+    oversized_substate.max_substate_value_size = limits.max_track_substate_total_bytes + 1;
+    assert!(matches!(
+        oversized_substate.validate(),
+        Err(TransactionLimitsConfigError::SubstateValueSizeExceedsTotal { .. })
+    ));
+}
+
+#[test]
+fn test_invalid_limits_are_returned_as_errors() {
+    let result = ExecutionConfig::for_test_transaction()
+        .try_with_limits(|limits| limits.max_substate_key_size = 0);
+    assert!(matches!(
+        result,
+        Err(TransactionLimitsConfigError::ZeroLimit(
+            "max_substate_key_size"
+        ))
+    ));
+
+    let result = ExecutionConfig::for_test_transaction()
+        .try_with_limits(|limits| limits.max_event_size = 512);
+    assert_eq!(result.map(|config| config.limits().max_event_size), Ok(512));
+}
+
+#[test]
+#[should_panic(expected = "Invalid transaction limits")]
+fn test_invalid_limits_are_rejected() {
+    ExecutionConfig::for_test_transaction().with_limits(|limits| limits.max_substate_key_size = 0);
+}
@@ -0,0 +1,194 @@
+use radix_engine::system::system_modules::transaction_runtime::LogRecord;
+use radix_engine::transaction::{
+    TransactionOutcome, TransactionReceipt, TransactionResult, VersionedTransactionReceipt,
+};
+use radix_engine::types::*;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+/// A committed receipt, as encoded by the engine before the receipt was versioned to V2.
+const V1_RECEIPT_HEX: &str = concat!(
+    "5c22000121062108a000743ba40b000000000000000000000000000000000000000900e1f5050900093d00a0",
+    "00743ba40b000000000000000000000000000000000000000980f0fa02a0aaaa829007e54be7000000000000",
+    "00000000000000000000a080cdc975bc56000000000000000000000000000000000000a080cdc975bc560000",
+    "000000000000000000000000000000002102080500a000000000000000000000000000000000000000000000",
+    "0000210709e803000009c8000000a000203d88792d000000000000000000000000000000000000a000a0724e",
+    "1809000000000000000000000000000000000000a00030ef7dba020000000000000000000000000000000000",
+    "00a000407a10f35a000000000000000000000000000000000000a00000000000000000000000000000000000",
+    "000000000000002201012102230c09020c4f70656e53756273746174652c0100000d52756e4e617469766543",
+    "6f6465bc020000230c09010a436f6d6d69744c6f6773c8000000220001210921012320220021052080002080",
+    "002080002080002320210021012320a0002104a00098f73e5d01000000000000000000000000000000000000",
+    "a00098f73e5d01000000000000000000000000000000000000a00090c058d733000000000000000000000000",
+    "0000000000002322a0002200012022010100202100202101022202000c0d48656c6c6f2c20776f726c642121",
+    "022320230023212100220000220000",
+);
+
+fn roundtrip(receipt: TransactionReceipt) -> (Vec<u8>, TransactionReceipt) {
+    let bytes = VersionedTransactionReceipt::from(receipt)
+        .to_bytes()
+        .unwrap();
+    let decoded = VersionedTransactionReceipt::from_bytes(&bytes)
+        .unwrap()
+        .into_latest();
+    (bytes, decoded)
+}
+
+#[test]
+fn committed_receipt_survives_encoding_roundtrip() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(account, XRD, dec!(10))
+            .try_deposit_entire_worktop_or_abort(FAUCET, None)
+            .build(),
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+    let fee_summary = receipt.fee_summary.clone();
+    let balance_changes = receipt
+        .expect_commit_success()
+        .vault_balance_changes()
+        .clone();
+
+    // Act
+    let (bytes, decoded) = roundtrip(receipt);
+
+    // Assert
+    assert_eq!(
+        decoded.fee_summary.total_execution_cost_units_consumed,
+        fee_summary.total_execution_cost_units_consumed
+    );
+    assert_eq!(
+        decoded.expect_commit_success().vault_balance_changes(),
+        &balance_changes
+    );
+    assert_eq!(
+        VersionedTransactionReceipt::from(decoded)
+            .to_bytes()
+            .unwrap(),
+        bytes
+    );
+}
+
+#[test]
+fn failed_receipt_survives_encoding_roundtrip() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(account, XRD, dec!(10))
+            .try_deposit_entire_worktop_or_abort(FAUCET, None)
+            .build(),
+        vec![],
+    );
+    receipt.expect_commit_failure();
+
+    // Act
+    let (bytes, decoded) = roundtrip(receipt);
+
+    // Assert
+    decoded.expect_commit_failure();
+    assert_eq!(
+        VersionedTransactionReceipt::from(decoded)
+            .to_bytes()
+            .unwrap(),
+        bytes
+    );
+}
+
+#[test]
+fn invalid_receipt_bytes_are_rejected() {
+    assert!(VersionedTransactionReceipt::from_bytes(&[0u8, 1, 2]).is_err());
+}
+
+#[test]
+fn receipt_encoded_as_v1_decodes_into_latest_version() {
+    // Arrange
+    let bytes = hex::decode(V1_RECEIPT_HEX).unwrap();
+
+    // Act
+    let versioned = VersionedTransactionReceipt::from_bytes(&bytes).unwrap();
+
+    // Assert
+    assert!(matches!(versioned, VersionedTransactionReceipt::V1(_)));
+    assert_eq!(versioned.to_bytes().unwrap(), bytes);
+
+    let receipt = versioned.into_latest();
+    assert_eq!(
+        receipt.costing_parameters.execution_cost_unit_limit,
+        100_000_000
+    );
+    assert_eq!(
+        receipt.transaction_costing_parameters.tip,
+        TipBasisPoints::from_percentage(5)
+    );
+    assert_eq!(
+        receipt.fee_summary.total_execution_cost_units_consumed,
+        1000
+    );
+    assert_eq!(
+        receipt.fee_summary.effective_execution_cost_unit_price,
+        dec!("0.0000000525")
+    );
+    assert_eq!(
+        receipt
+            .fee_details
+            .as_ref()
+            .unwrap()
+            .execution_cost_breakdown
+            .get("RunNativeCode"),
+        Some(&700)
+    );
+    let TransactionResult::Commit(commit) = &receipt.result else {
+        panic!("Expected a commit result");
+    };
+    assert!(matches!(commit.outcome, TransactionOutcome::Success(_)));
+    assert!(receipt.warnings.is_empty());
+}
+
+#[test]
+fn latest_receipt_is_encoded_as_v2() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new().lock_fee_from_faucet().build(),
+        vec![],
+    );
+
+    // Act
+    let (bytes, _) = roundtrip(receipt);
+
+    // Assert
+    assert_eq!(bytes[0], SCRYPTO_SBOR_V1_PAYLOAD_PREFIX);
+    assert_eq!(bytes[1], VALUE_KIND_ENUM);
+    assert_eq!(bytes[2], 1);
+}
+
+#[test]
+fn logs_of_receipt_encoded_as_v1_are_converted_to_log_records() {
+    // Arrange
+    let bytes = hex::decode(V1_RECEIPT_HEX).unwrap();
+
+    // Act
+    let receipt = VersionedTransactionReceipt::from_bytes(&bytes)
+        .unwrap()
+        .into_latest();
+
+    // Assert
+    let commit = receipt.expect_commit_success();
+    assert_eq!(
+        commit.application_logs,
+        vec![LogRecord {
+            level: Level::Info,
+            topic: None,
+            message: "Hello, world!".to_string(),
+            blueprint_id: None,
+            global_address: None,
+            payload: None,
+        }]
+    );
+}
@@ -68,6 +68,47 @@ fn test_generate_ruid() {
     assert_ne!(ruid1, ruid2);
 }
 
+#[test]
+fn test_generate_ruid_seeded() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let package_address =
+        test_runner.publish_package_simple(PackageLoader::get("transaction_runtime"));
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(
+            package_address,
+            "TransactionRuntimeTest",
+            "generate_ruid_seeded",
+            manifest_args!("lottery"),
+        )
+        .call_function(
+            package_address,
+            "TransactionRuntimeTest",
+            "generate_ruid_seeded",
+            manifest_args!("lottery"),
+        )
+        .call_function(
+            package_address,
+            "TransactionRuntimeTest",
+            "generate_ruid",
+            manifest_args!(),
+        )
+        .build();
+
+    // Act
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    let commit = receipt.expect_commit_success();
+    let seeded1: [u8; 32] = commit.output(1);
+    let seeded2: [u8; 32] = commit.output(2);
+    let ruid: [u8; 32] = commit.output(3);
+    assert_ne!(seeded1, seeded2);
+    assert_ne!(seeded1, ruid);
+    assert_ne!(seeded2, ruid);
+}
+
 #[test]
 fn test_instance_of_and_blueprint_id() {
     // Arrange
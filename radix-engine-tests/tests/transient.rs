@@ -0,0 +1,108 @@
+mod package_loader;
+
+use package_loader::PackageLoader;
+use radix_engine::errors::{ApplicationError, RuntimeError};
+use radix_engine::system::system_substates::FieldSubstate;
+use radix_engine::types::*;
+use radix_engine_store_interface::db_key_mapper::{MappedSubstateDatabase, SpreadPrefixKeyMapper};
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn create_transient_counter(test_runner: &mut DefaultTestRunner) -> ComponentAddress {
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("transient"));
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_function(package_address, "TransientCounter", "new", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success().new_component_addresses()[0]
+}
+
+#[test]
+fn transient_field_is_reset_in_every_transaction() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let component_address = create_transient_counter(&mut test_runner);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(component_address, "call", manifest_args!())
+        .call_method(component_address, "call", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let result = receipt.expect_commit_success();
+    let first: (u32, u32) = result.output(1);
+    let second: (u32, u32) = result.output(2);
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(component_address, "call", manifest_args!())
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    let third: (u32, u32) = receipt.expect_commit_success().output(1);
+
+    // Assert
+    assert_eq!(first, (1, 1));
+    assert_eq!(second, (2, 2));
+    assert_eq!(third, (3, 1));
+}
+
+#[test]
+fn transient_field_is_not_persisted() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let component_address = create_transient_counter(&mut test_runner);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(component_address, "call", manifest_args!())
+        .build();
+    test_runner
+        .execute_manifest(manifest, vec![])
+        .expect_commit_success();
+
+    // Assert
+    let substate_db = test_runner.substate_db();
+    let get_field = |field_index: u8| {
+        substate_db.get_mapped::<SpreadPrefixKeyMapper, FieldSubstate<ScryptoValue>>(
+            component_address.as_node_id(),
+            MAIN_BASE_PARTITION,
+            &SubstateKey::Field(field_index),
+        )
+    };
+    assert!(get_field(0).is_some());
+    assert!(get_field(1).is_none());
+    assert!(get_field(2).is_none());
+}
+
+#[test]
+fn transient_field_can_guard_against_reentrancy() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let component_address = create_transient_counter(&mut test_runner);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(component_address, "guarded_call", manifest_args!(false))
+        .call_method(component_address, "guarded_call", manifest_args!(false))
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+    receipt.expect_commit_success();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(component_address, "guarded_call", manifest_args!(true))
+        .build();
+    let receipt = test_runner.execute_manifest(manifest, vec![]);
+
+    // Assert
+    receipt.expect_specific_failure(|e| match e {
+        RuntimeError::ApplicationError(ApplicationError::PanicMessage(message)) => {
+            message.contains("Reentrant call")
+        }
+        _ => false,
+    });
+}
@@ -184,6 +184,11 @@ fn test_all_scenario_commit_receipts_should_have_substate_changes_which_can_be_t
                     }
                     previous = Some(receipt);
                 }
+                NextAction::AdvanceToEpoch(epoch) => {
+                    let receipt = test_runner.advance_to_epoch(epoch);
+                    assert_receipt_substate_changes_can_be_typed(receipt.expect_commit_success());
+                    previous = Some(receipt);
+                }
                 NextAction::Completed(end_state) => {
                     next_nonce = end_state.next_unused_nonce;
                     break;
@@ -221,6 +226,11 @@ fn test_all_scenario_commit_receipts_should_have_events_that_can_be_typed() {
                     }
                     previous = Some(receipt);
                 }
+                NextAction::AdvanceToEpoch(epoch) => {
+                    let receipt = test_runner.advance_to_epoch(epoch);
+                    assert_receipt_events_can_be_typed(receipt.expect_commit_success());
+                    previous = Some(receipt);
+                }
                 NextAction::Completed(end_state) => {
                     next_nonce = end_state.next_unused_nonce;
                     break;
@@ -242,6 +252,7 @@ fn typed_native_event_type_contains_all_native_events() {
         "Identity" => IDENTITY_PACKAGE_DEFINITION.deref(),
         "AccessController" => ACCESS_CONTROLLER_PACKAGE_DEFINITION.deref(),
         "Pool" => POOL_PACKAGE_DEFINITION.deref(),
+        "Locker" => LOCKER_PACKAGE_DEFINITION.deref(),
         "TransactionTracker" => TRANSACTION_TRACKER_PACKAGE_DEFINITION.deref(),
         "Resource" => RESOURCE_PACKAGE_DEFINITION.deref(),
         "Package" => PACKAGE_PACKAGE_DEFINITION.deref(),
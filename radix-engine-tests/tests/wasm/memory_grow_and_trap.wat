@@ -0,0 +1,14 @@
+(module
+  ;; Function that grows the memory and then traps
+  (func $Test_f (param $0 i64) (result i64)
+    ;; Grow memory
+    (memory.grow (i32.const ${n}))
+    drop
+
+    unreachable
+  )
+
+  (memory $0 1)
+  (export "memory" (memory $0))
+  (export "Test_f" (func $Test_f))
+)
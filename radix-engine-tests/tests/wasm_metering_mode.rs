@@ -0,0 +1,126 @@
+mod package_loader;
+
+use package_loader::PackageLoader;
+use radix_engine::errors::{RuntimeError, SystemModuleError};
+use radix_engine::system::system_modules::costing::{CostingError, FeeReserveError};
+use radix_engine::transaction::{CostingParameters, ExecutionConfig, TransactionReceipt};
+use radix_engine::types::*;
+use radix_engine::vm::wasm::WasmMeteringMode;
+use scrypto_unit::*;
+use transaction::prelude::*;
+
+fn setup_test_runner() -> (DefaultTestRunner, ComponentAddress, ComponentAddress) {
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (_, _, account) = test_runner.new_allocated_account();
+    let package_address = test_runner.publish_package_simple(PackageLoader::get("fee"));
+    let receipt = test_runner.execute_manifest(
+        ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .get_free_xrd_from_faucet()
+            .take_from_worktop(XRD, 1000, "bucket")
+            .with_name_lookup(|builder, lookup| {
+                builder.call_function(
+                    package_address,
+                    "Fee",
+                    "new",
+                    manifest_args!(lookup.bucket("bucket")),
+                )
+            })
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build(),
+        vec![],
+    );
+    let component_address = receipt.expect_commit_success().new_component_addresses()[0];
+
+    (test_runner, account, component_address)
+}
+
+fn execute_with_metering_mode(
+    test_runner: &mut DefaultTestRunner,
+    manifest: TransactionManifestV1,
+    wasm_metering_mode: WasmMeteringMode,
+) -> TransactionReceipt {
+    let nonce = test_runner.next_transaction_nonce();
+    test_runner.execute_transaction(
+        TestTransaction::new_from_nonce(manifest, nonce)
+            .prepare()
+            .expect("expected transaction to be preparable")
+            .get_executable(btreeset!()),
+        CostingParameters::default(),
+        ExecutionConfig::for_test_transaction().with_wasm_metering_mode(wasm_metering_mode),
+    )
+}
+
+/// The execution cost units charged for running WASM code, which is all the metering mode
+/// changes - the other costs, such as preparing the code, depend on what's being run.
+fn wasm_execution_cost_units_with_metering_mode(wasm_metering_mode: WasmMeteringMode) -> u32 {
+    let (mut test_runner, account, component_address) = setup_test_runner();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(
+            component_address,
+            "update_vault_and_lock_fee",
+            manifest_args!(dec!(10)),
+        )
+        .get_free_xrd_from_faucet()
+        .try_deposit_entire_worktop_or_abort(account, None)
+        .build();
+
+    let receipt = execute_with_metering_mode(&mut test_runner, manifest, wasm_metering_mode);
+
+    receipt.expect_commit_success();
+    receipt
+        .fee_details
+        .as_ref()
+        .expect("Cost breakdown should be enabled")
+        .execution_cost_breakdown
+        .iter()
+        .filter(|(entry, _)| entry.starts_with("RunWasmCode::"))
+        .map(|(_, cost_units)| *cost_units)
+        .sum()
+}
+
+#[test]
+fn engine_fuel_metering_charges_as_instruction_injection_within_tolerance() {
+    // Arrange
+    let tolerance_percent = 25;
+
+    // Act
+    let injected =
+        wasm_execution_cost_units_with_metering_mode(WasmMeteringMode::InstructionInjection);
+    let fuel = wasm_execution_cost_units_with_metering_mode(WasmMeteringMode::EngineFuel);
+
+    // Assert
+    assert!(injected > 0);
+    let difference = injected.abs_diff(fuel);
+    assert!(
+        difference * 100 <= injected * tolerance_percent,
+        "Instruction injection charged {} WASM execution cost units, engine fuel {}",
+        injected,
+        fuel
+    );
+}
+
+#[test]
+fn engine_fuel_metering_stops_infinite_loop() {
+    // Arrange
+    let (mut test_runner, _, component_address) = setup_test_runner();
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(component_address, "spin_loop", manifest_args!())
+        .build();
+
+    // Act
+    let receipt =
+        execute_with_metering_mode(&mut test_runner, manifest, WasmMeteringMode::EngineFuel);
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        matches!(
+            e,
+            RuntimeError::SystemModuleError(SystemModuleError::CostingError(
+                CostingError::FeeReserveError(FeeReserveError::LimitExceeded { .. })
+            ))
+        )
+    });
+}
@@ -1,4 +1,4 @@
-use radix_engine::blueprints::resource::FungibleResourceManagerError;
+use radix_engine::blueprints::resource::{FungibleResourceManagerError, WorktopError};
 use radix_engine::errors::ApplicationError;
 use radix_engine::errors::RuntimeError;
 use radix_engine::types::*;
@@ -90,3 +90,176 @@ fn test_many_worktop_call() {
         format!("{e:?}").contains("FeeReserveError(LimitExceeded")
     });
 }
+
+#[test]
+fn test_assert_worktop_resources_only_succeeds_with_expected_resources() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(dec!(100), 18, account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, 1)
+        .withdraw_from_account(account, resource_address, 1)
+        .assert_worktop_resources_only([XRD, resource_address])
+        .deposit_batch(account)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn test_assert_worktop_resources_only_fails_with_unexpected_resource() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(dec!(100), 18, account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, 1)
+        .withdraw_from_account(account, resource_address, 1)
+        .assert_worktop_resources_only([XRD])
+        .deposit_batch(account)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(|e| {
+        e == &RuntimeError::ApplicationError(ApplicationError::WorktopError(
+            WorktopError::UnexpectedResource(resource_address),
+        ))
+    });
+}
+
+#[test]
+fn test_yield_worktop_contents_outputs_resources_on_worktop() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(dec!(100), 18, account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .yield_worktop_contents()
+        .withdraw_from_account(account, XRD, 1)
+        .withdraw_from_account(account, resource_address, 5)
+        .yield_worktop_contents()
+        .take_from_worktop(XRD, 1, "xrd")
+        .yield_worktop_contents()
+        .return_to_worktop("xrd")
+        .deposit_batch(account)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    let result = receipt.expect_commit_success();
+    assert_eq!(
+        result.output::<IndexMap<ResourceAddress, Decimal>>(1),
+        index_map_new()
+    );
+    assert_eq!(
+        result.output::<IndexMap<ResourceAddress, Decimal>>(4),
+        indexmap!(XRD => dec!(1), resource_address => dec!(5))
+    );
+    assert_eq!(
+        result.output::<IndexMap<ResourceAddress, Decimal>>(6),
+        indexmap!(resource_address => dec!(5))
+    );
+}
+
+#[test]
+fn test_burn_resource_from_worktop_with_burner_role() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (auth_resource_address, resource_address) =
+        test_runner.create_restricted_burn_token(account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(account, auth_resource_address, 1)
+        .withdraw_from_account(account, resource_address, 5)
+        .burn_resource_from_worktop(resource_address, 3)
+        .deposit_batch(account)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+    assert_eq!(
+        test_runner.get_component_balance(account, resource_address),
+        dec!(2)
+    );
+}
+
+#[test]
+fn test_burn_resource_from_worktop_without_burner_role_fails() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, resource_address) = test_runner.create_restricted_burn_token(account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, resource_address, 5)
+        .burn_resource_from_worktop(resource_address, 3)
+        .deposit_batch(account)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_specific_failure(is_auth_error);
+}
+
+#[test]
+fn test_strict_worktop_manifest_succeeds() {
+    // Arrange
+    let mut test_runner = TestRunnerBuilder::new().build();
+    let (public_key, _, account) = test_runner.new_allocated_account();
+    let (_, _, other_account) = test_runner.new_allocated_account();
+    let resource_address = test_runner.create_fungible_resource(dec!(100), 18, account);
+
+    // Act
+    let manifest = ManifestBuilder::new()
+        .with_strict_worktop()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(account, XRD, 10)
+        .withdraw_from_account(account, resource_address, 5)
+        .take_from_worktop(XRD, 4, "xrd")
+        .try_deposit_or_abort(other_account, None, "xrd")
+        .take_all_from_worktop(resource_address, "resource")
+        .return_to_worktop("resource")
+        .deposit_batch(account)
+        .build();
+    let receipt = test_runner.execute_manifest(
+        manifest,
+        vec![NonFungibleGlobalId::from_public_key(&public_key)],
+    );
+
+    // Assert
+    receipt.expect_commit_success();
+}
@@ -198,6 +198,20 @@ impl AccountBlueprint {
             },
         );
 
+        functions.insert(
+            ACCOUNT_LOCK_FEE_WITH_REFUND_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountLockFeeWithRefundInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountLockFeeWithRefundOutput>(),
+                ),
+                export: ACCOUNT_LOCK_FEE_WITH_REFUND_IDENT.to_string(),
+            },
+        );
+
         functions.insert(
             ACCOUNT_DEPOSIT_IDENT.to_string(),
             FunctionSchemaInit {
@@ -536,6 +550,7 @@ impl AccountBlueprint {
                         ACCOUNT_WITHDRAW_NON_FUNGIBLES_IDENT => [OWNER_ROLE];
                         ACCOUNT_LOCK_FEE_IDENT => [OWNER_ROLE];
                         ACCOUNT_LOCK_CONTINGENT_FEE_IDENT => [OWNER_ROLE];
+                        ACCOUNT_LOCK_FEE_WITH_REFUND_IDENT => [OWNER_ROLE];
                         ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT => [OWNER_ROLE];
                         ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT => [OWNER_ROLE];
                         ACCOUNT_CREATE_PROOF_OF_AMOUNT_IDENT => [OWNER_ROLE];
@@ -779,6 +794,25 @@ impl AccountBlueprint {
         Ok(())
     }
 
+    /// Locks a fee whose unused part is refunded to the given XRD vault, e.g. of a treasury
+    /// component, rather than to the account.
+    pub fn lock_fee_with_refund<Y>(
+        amount: Decimal,
+        refund_vault: InternalAddress,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::get_vault(
+            XRD,
+            |vault, api| vault.lock_fee_with_refund(api, amount, refund_vault),
+            false,
+            api,
+        )?;
+        Ok(())
+    }
+
     /// Method requires auth - if call goes through it performs the deposit with no questions asked
     pub fn deposit<Y>(bucket: Bucket, api: &mut Y) -> Result<(), RuntimeError>
     where
@@ -83,6 +83,14 @@ impl AccountNativePackage {
                 let rtn = AccountBlueprint::lock_contingent_fee(input.amount, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            ACCOUNT_LOCK_FEE_WITH_REFUND_IDENT => {
+                let input: AccountLockFeeWithRefundInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn =
+                    AccountBlueprint::lock_fee_with_refund(input.amount, input.refund_vault, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             ACCOUNT_DEPOSIT_IDENT => {
                 let input: AccountDepositInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1,4 +1,7 @@
-use super::{EpochChangeEvent, RoundChangeEvent, ValidatorCreator, ValidatorOwnerBadgeData};
+use super::{
+    ConfigChangeEvent, EpochChangeEvent, RoundChangeEvent, ValidatorCreator,
+    ValidatorOwnerBadgeData,
+};
 use crate::blueprints::consensus_manager::VALIDATOR_ROLE;
 use crate::errors::ApplicationError;
 use crate::errors::RuntimeError;
@@ -228,6 +231,9 @@ pub enum ConsensusManagerError {
         current: u32,
         max: u32,
     },
+    InvalidMinValidatorReliability(Decimal),
+    InvalidTotalEmission(Decimal),
+    ZeroEpochCount(String),
 }
 
 declare_native_blueprint_state! {
@@ -448,6 +454,21 @@ impl ConsensusManagerBlueprint {
                 export: CONSENSUS_MANAGER_NEXT_ROUND_IDENT.to_string(),
             },
         );
+        functions.insert(
+            CONSENSUS_MANAGER_UPDATE_CONFIG_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<ConsensusManagerUpdateConfigInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<ConsensusManagerUpdateConfigOutput>(),
+                ),
+                export: CONSENSUS_MANAGER_UPDATE_CONFIG_IDENT.to_string(),
+            },
+        );
         functions.insert(
             CONSENSUS_MANAGER_CREATE_VALIDATOR_IDENT.to_string(),
             FunctionSchemaInit {
@@ -468,7 +489,8 @@ impl ConsensusManagerBlueprint {
             aggregator,
             [
                 RoundChangeEvent,
-                EpochChangeEvent
+                EpochChangeEvent,
+                ConfigChangeEvent
             ]
         };
 
@@ -506,6 +528,9 @@ impl ConsensusManagerBlueprint {
                     methods {
                         CONSENSUS_MANAGER_START_IDENT => []; // Genesis is able to call this by skipping auth
                         CONSENSUS_MANAGER_NEXT_ROUND_IDENT => [VALIDATOR_ROLE];
+                        // Checked against the system role by the method itself, since ledgers created
+                        // before the method existed have no role to map it to
+                        CONSENSUS_MANAGER_UPDATE_CONFIG_IDENT => MethodAccessibility::Public;
 
                         CONSENSUS_MANAGER_GET_CURRENT_EPOCH_IDENT => MethodAccessibility::Public;
                         CONSENSUS_MANAGER_GET_CURRENT_TIME_IDENT => MethodAccessibility::Public;
@@ -529,16 +554,7 @@ impl ConsensusManagerBlueprint {
     where
         Y: ClientApi<RuntimeError>,
     {
-        if initial_config.max_validators > ValidatorIndex::MAX as u32 {
-            return Err(RuntimeError::ApplicationError(
-                ApplicationError::ConsensusManagerError(
-                    ConsensusManagerError::ExceededValidatorCount {
-                        current: initial_config.max_validators,
-                        max: ValidatorIndex::MAX as u32,
-                    },
-                ),
-            ));
-        }
+        Self::validate_config(&initial_config)?;
 
         {
             // TODO: remove mint and premint all tokens
@@ -607,7 +623,7 @@ impl ConsensusManagerBlueprint {
             api.new_simple_object(
                 CONSENSUS_MANAGER_BLUEPRINT,
                 indexmap! {
-                    ConsensusManagerField::Configuration.field_index() => FieldValue::immutable(&ConsensusManagerConfigurationFieldPayload::from_content_source(config)),
+                    ConsensusManagerField::Configuration.field_index() => FieldValue::new(&ConsensusManagerConfigurationFieldPayload::from_content_source(config)),
                     ConsensusManagerField::State.field_index() => FieldValue::new(&ConsensusManagerStateFieldPayload::from_content_source(consensus_manager)),
                     ConsensusManagerField::ValidatorRewards.field_index() => FieldValue::new(&ConsensusManagerValidatorRewardsFieldPayload::from_content_source(validator_rewards)),
                     ConsensusManagerField::CurrentValidatorSet.field_index() => FieldValue::new(&ConsensusManagerCurrentValidatorSetFieldPayload::from_content_source(current_validator_set)),
@@ -644,6 +660,98 @@ impl ConsensusManagerBlueprint {
         Ok(())
     }
 
+    fn validate_config(config: &ConsensusManagerConfig) -> Result<(), RuntimeError> {
+        if config.max_validators > ValidatorIndex::MAX as u32 {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::ConsensusManagerError(
+                    ConsensusManagerError::ExceededValidatorCount {
+                        current: config.max_validators,
+                        max: ValidatorIndex::MAX as u32,
+                    },
+                ),
+            ));
+        }
+
+        if config.min_validator_reliability.is_negative()
+            || config.min_validator_reliability > Decimal::ONE
+        {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::ConsensusManagerError(
+                    ConsensusManagerError::InvalidMinValidatorReliability(
+                        config.min_validator_reliability,
+                    ),
+                ),
+            ));
+        }
+
+        if config.total_emission_xrd_per_epoch.is_negative() {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::ConsensusManagerError(
+                    ConsensusManagerError::InvalidTotalEmission(
+                        config.total_emission_xrd_per_epoch,
+                    ),
+                ),
+            ));
+        }
+
+        for (name, epoch_count) in [
+            ("num_unstake_epochs", config.num_unstake_epochs),
+            (
+                "num_owner_stake_units_unlock_epochs",
+                config.num_owner_stake_units_unlock_epochs,
+            ),
+            (
+                "num_fee_increase_delay_epochs",
+                config.num_fee_increase_delay_epochs,
+            ),
+        ] {
+            if epoch_count == 0 {
+                return Err(RuntimeError::ApplicationError(
+                    ApplicationError::ConsensusManagerError(ConsensusManagerError::ZeroEpochCount(
+                        name.to_string(),
+                    )),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Replaces the configuration, e.g. as part of a protocol update.
+    ///
+    /// On ledgers bootstrapped before this method existed, the configuration field was locked at
+    /// genesis, and has to be unlocked first with the state updates from
+    /// [`create_consensus_manager_config_unlock_state_updates`](crate::system::bootstrap::create_consensus_manager_config_unlock_state_updates).
+    pub(crate) fn update_config<Y>(
+        config: ConsensusManagerConfig,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Runtime::assert_access_rule(rule!(require(AuthAddresses::system_role())), api)?;
+        Self::validate_config(&config)?;
+
+        let config_handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            ConsensusManagerField::Configuration.into(),
+            LockFlags::MUTABLE,
+        )?;
+        api.field_write_typed(
+            config_handle,
+            &ConsensusManagerConfigurationFieldPayload::from_content_source(
+                ConsensusManagerConfigSubstate {
+                    config: config.clone(),
+                },
+            ),
+        )?;
+        api.field_close(config_handle)?;
+
+        Runtime::emit_event(api, ConfigChangeEvent { config })?;
+
+        Ok(())
+    }
+
     pub(crate) fn get_current_epoch<Y>(api: &mut Y) -> Result<Epoch, RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
@@ -1,11 +1,18 @@
 use crate::blueprints::consensus_manager::ActiveValidatorSet;
 use crate::types::*;
+use radix_engine_interface::blueprints::consensus_manager::ConsensusManagerConfig;
 
 #[derive(Debug, Clone, ScryptoSbor, ScryptoEvent, PartialEq, Eq)]
 pub struct RoundChangeEvent {
     pub round: Round,
 }
 
+#[derive(Debug, Clone, ScryptoSbor, ScryptoEvent, PartialEq, Eq)]
+pub struct ConfigChangeEvent {
+    /// The *new* configuration.
+    pub config: ConsensusManagerConfig,
+}
+
 #[derive(Debug, Clone, ScryptoSbor, ScryptoEvent, PartialEq, Eq)]
 pub struct EpochChangeEvent {
     /// The *new* epoch's number.
@@ -96,6 +96,14 @@ impl ConsensusManagerNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            CONSENSUS_MANAGER_UPDATE_CONFIG_IDENT => {
+                let input: ConsensusManagerUpdateConfigInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = ConsensusManagerBlueprint::update_config(input.config, api)?;
+
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             CONSENSUS_MANAGER_CREATE_VALIDATOR_IDENT => {
                 let input: ConsensusManagerCreateValidatorInput =
                     input.as_typed().map_err(|e| {
@@ -0,0 +1,561 @@
+use super::*;
+use crate::errors::ApplicationError;
+use crate::errors::RuntimeError;
+use crate::internal_prelude::*;
+use crate::types::*;
+use crate::{event_schema, roles_template};
+use native_sdk::modules::metadata::Metadata;
+use native_sdk::modules::role_assignment::*;
+use native_sdk::modules::royalty::ComponentRoyalty;
+use native_sdk::resource::NativeBucket;
+use native_sdk::resource::NativeVault;
+use native_sdk::runtime::Runtime;
+use radix_engine_interface::api::field_api::LockFlags;
+use radix_engine_interface::api::node_modules::auth::RoleDefinition;
+use radix_engine_interface::api::node_modules::auth::ToRoleEntry;
+use radix_engine_interface::api::*;
+use radix_engine_interface::blueprints::account::*;
+use radix_engine_interface::blueprints::component::Global;
+use radix_engine_interface::blueprints::consensus_manager::TimePrecision;
+use radix_engine_interface::blueprints::locker::*;
+use radix_engine_interface::blueprints::package::{
+    AuthConfig, BlueprintDefinitionInit, BlueprintType, FunctionAuth, MethodAuthTemplate,
+};
+use radix_engine_interface::blueprints::resource::*;
+use radix_engine_interface::time::TimeComparisonOperator;
+use radix_engine_interface::*;
+
+// =================================================================================================
+// Notes:
+// 1. The resources stored for a claimant are kept in one vault per (claimant, resource) pair, the
+//    vaults are never removed from the locker once created.
+// 2. The claimant proves that it is the recipient of the stored resources by meeting the owner role
+//    of its account. Thus, only accounts can be claimants.
+// 3. Every store resets the time after which the stored resources of the claimant can be recovered.
+// =================================================================================================
+
+pub const STORER_ROLE: &'static str = "storer";
+pub const STORER_UPDATER_ROLE: &'static str = "storer_updater";
+pub const RECOVERER_ROLE: &'static str = "recoverer";
+pub const RECOVERER_UPDATER_ROLE: &'static str = "recoverer_updater";
+
+#[derive(Debug, PartialEq, Eq, ScryptoSbor, Clone)]
+pub struct AccountLockerSubstate {
+    /// The number of minutes after the last store for a claimant and resource, after which the
+    /// stored resources can be recovered
+    pub claim_period_in_minutes: u32,
+}
+
+#[derive(Debug, PartialEq, Eq, ScryptoSbor, Clone, Copy, Hash, PartialOrd, Ord)]
+pub struct AccountLockerClaimKey {
+    pub claimant: ComponentAddress,
+    pub resource_address: ResourceAddress,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum AccountLockerError {
+    NothingStored {
+        claimant: ComponentAddress,
+        resource_address: ResourceAddress,
+    },
+    RecoveryNotYetAllowed {
+        recoverable_at: Instant,
+    },
+    TimeOverflow,
+}
+
+impl From<AccountLockerError> for RuntimeError {
+    fn from(value: AccountLockerError) -> Self {
+        Self::ApplicationError(ApplicationError::AccountLockerError(value))
+    }
+}
+
+declare_native_blueprint_state! {
+    blueprint_ident: AccountLocker,
+    blueprint_snake_case: account_locker,
+    features: {
+    },
+    fields: {
+        config:  {
+            ident: Config,
+            field_type: {
+                kind: StaticSingleVersioned,
+            },
+            condition: Condition::Always,
+        }
+    },
+    collections: {
+        vaults: KeyValue {
+            entry_ident: Vault,
+            key_type: {
+                kind: Static,
+                content_type: AccountLockerClaimKey,
+            },
+            value_type: {
+                kind: StaticSingleVersioned,
+            },
+            allow_ownership: true,
+        },
+        recovery_times: KeyValue {
+            entry_ident: RecoveryTime,
+            key_type: {
+                kind: Static,
+                content_type: AccountLockerClaimKey,
+            },
+            value_type: {
+                kind: StaticSingleVersioned,
+            },
+            allow_ownership: false,
+        },
+    }
+}
+
+pub type AccountLockerConfigV1 = AccountLockerSubstate;
+pub type AccountLockerVaultV1 = Vault;
+pub type AccountLockerRecoveryTimeV1 = Instant;
+
+pub struct AccountLockerBlueprint;
+
+impl AccountLockerBlueprint {
+    pub fn get_definition() -> BlueprintDefinitionInit {
+        let mut aggregator = TypeAggregator::<ScryptoCustomTypeKind>::new();
+
+        let feature_set = AccountLockerFeatureSet::all_features();
+        let state = AccountLockerStateSchemaInit::create_schema_init(&mut aggregator);
+
+        let mut functions = index_map_new();
+
+        functions.insert(
+            ACCOUNT_LOCKER_INSTANTIATE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountLockerInstantiateInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountLockerInstantiateOutput>(),
+                ),
+                export: ACCOUNT_LOCKER_INSTANTIATE_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            ACCOUNT_LOCKER_STORE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountLockerStoreInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountLockerStoreOutput>(),
+                ),
+                export: ACCOUNT_LOCKER_STORE_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            ACCOUNT_LOCKER_CLAIM_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountLockerClaimInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountLockerClaimOutput>(),
+                ),
+                export: ACCOUNT_LOCKER_CLAIM_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            ACCOUNT_LOCKER_RECOVER_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountLockerRecoverInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountLockerRecoverOutput>(),
+                ),
+                export: ACCOUNT_LOCKER_RECOVER_EXPORT_NAME.to_string(),
+            },
+        );
+
+        functions.insert(
+            ACCOUNT_LOCKER_GET_AMOUNT_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountLockerGetAmountInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<AccountLockerGetAmountOutput>(),
+                ),
+                export: ACCOUNT_LOCKER_GET_AMOUNT_EXPORT_NAME.to_string(),
+            },
+        );
+
+        let event_schema = event_schema! {
+            aggregator,
+            [
+                StoreEvent,
+                ClaimEvent,
+                RecoverEvent
+            ]
+        };
+
+        let schema = generate_full_schema(aggregator);
+
+        BlueprintDefinitionInit {
+            blueprint_type: BlueprintType::default(),
+            is_transient: false,
+            dependencies: indexset!(),
+            feature_set,
+
+            schema: BlueprintSchemaInit {
+                generics: vec![],
+                schema,
+                state,
+                events: event_schema,
+                types: BlueprintTypeSchemaInit::default(),
+                functions: BlueprintFunctionsSchemaInit { functions },
+                hooks: BlueprintHooksInit::default(),
+            },
+
+            royalty_config: PackageRoyaltyConfig::default(),
+            auth_config: AuthConfig {
+                function_auth: FunctionAuth::AllowAll,
+                method_auth: MethodAuthTemplate::StaticRoleDefinition(roles_template! {
+                    roles {
+                        STORER_ROLE => updaters: [STORER_UPDATER_ROLE];
+                        STORER_UPDATER_ROLE => updaters: [STORER_UPDATER_ROLE];
+                        RECOVERER_ROLE => updaters: [RECOVERER_UPDATER_ROLE];
+                        RECOVERER_UPDATER_ROLE => updaters: [RECOVERER_UPDATER_ROLE];
+                    },
+                    methods {
+                        ACCOUNT_LOCKER_STORE_IDENT => [STORER_ROLE];
+                        ACCOUNT_LOCKER_RECOVER_IDENT => [RECOVERER_ROLE];
+                        ACCOUNT_LOCKER_CLAIM_IDENT => MethodAccessibility::Public;
+                        ACCOUNT_LOCKER_GET_AMOUNT_IDENT => MethodAccessibility::Public;
+                    }
+                }),
+            },
+        }
+    }
+
+    pub fn instantiate<Y>(
+        owner_role: OwnerRole,
+        storer_role: AccessRule,
+        storer_updater_role: AccessRule,
+        recoverer_role: AccessRule,
+        recoverer_updater_role: AccessRule,
+        claim_period_in_minutes: u32,
+        address_reservation: Option<GlobalAddressReservation>,
+        api: &mut Y,
+    ) -> Result<AccountLockerInstantiateOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let (address_reservation, address) = {
+            if let Some(address_reservation) = address_reservation {
+                let address = api.get_reservation_address(address_reservation.0.as_node_id())?;
+                (address_reservation, address)
+            } else {
+                api.allocate_global_address(BlueprintId {
+                    package_address: LOCKER_PACKAGE,
+                    blueprint_name: ACCOUNT_LOCKER_BLUEPRINT.to_string(),
+                })?
+            }
+        };
+
+        let role_assignment = RoleAssignment::create(
+            owner_role,
+            indexmap! {
+                ModuleId::Main => roles_init! {
+                    RoleKey { key: STORER_ROLE.to_owned() } => storer_role;
+                    RoleKey { key: STORER_UPDATER_ROLE.to_owned() } => storer_updater_role;
+                    RoleKey { key: RECOVERER_ROLE.to_owned() } => recoverer_role;
+                    RoleKey { key: RECOVERER_UPDATER_ROLE.to_owned() } => recoverer_updater_role;
+                }
+            },
+            api,
+        )?
+        .0;
+        let metadata = Metadata::create(api)?;
+        let royalty = ComponentRoyalty::create(ComponentRoyaltyConfig::default(), api)?;
+
+        let object_id = api.new_simple_object(
+            ACCOUNT_LOCKER_BLUEPRINT,
+            indexmap! {
+                AccountLockerField::Config.field_index() => FieldValue::immutable(
+                    &AccountLockerConfigFieldPayload::from_content_source(AccountLockerConfigV1 {
+                        claim_period_in_minutes,
+                    }),
+                ),
+            },
+        )?;
+
+        api.globalize(
+            object_id,
+            indexmap!(
+                AttachedModuleId::RoleAssignment => role_assignment.0,
+                AttachedModuleId::Metadata => metadata.0,
+                AttachedModuleId::Royalty => royalty.0,
+            ),
+            Some(address_reservation),
+        )?;
+
+        Ok(Global::new(ComponentAddress::new_or_panic(
+            address.as_node_id().0,
+        )))
+    }
+
+    pub fn store<Y>(
+        claimant: Global<AccountObjectTypeInfo>,
+        bucket: Bucket,
+        try_direct_send: bool,
+        api: &mut Y,
+    ) -> Result<AccountLockerStoreOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let bucket = if try_direct_send {
+            let rtn = api.call_method(
+                claimant.0.as_node_id(),
+                ACCOUNT_TRY_DEPOSIT_OR_REFUND_IDENT,
+                scrypto_encode(&AccountTryDepositOrRefundInput {
+                    bucket,
+                    authorized_depositor_badge: None,
+                })
+                .unwrap(),
+            )?;
+            let refund: AccountTryDepositOrRefundOutput = scrypto_decode(&rtn).unwrap();
+            match refund {
+                Some(bucket) => bucket,
+                None => return Ok(()),
+            }
+        } else {
+            bucket
+        };
+
+        let key = AccountLockerClaimKey {
+            claimant: claimant.0,
+            resource_address: bucket.resource_address(api)?,
+        };
+        let amount = bucket.amount(api)?;
+
+        Self::get_vault(key, |vault, api| vault.put(bucket, api), true, api)?;
+
+        let claim_period_in_minutes = Self::get_config(api)?.claim_period_in_minutes;
+        let recoverable_at = Runtime::current_time(api, TimePrecision::Minute)?
+            .add_minutes(claim_period_in_minutes as i64)
+            .ok_or(AccountLockerError::TimeOverflow)?;
+        let encoded_key = scrypto_encode(&key).expect("Impossible Case!");
+        let kv_store_entry_lock_handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            AccountLockerCollection::RecoveryTimeKeyValue.collection_index(),
+            &encoded_key,
+            LockFlags::MUTABLE,
+        )?;
+        api.key_value_entry_set_typed(
+            kv_store_entry_lock_handle,
+            &AccountLockerRecoveryTimeEntryPayload::from_content_source(recoverable_at),
+        )?;
+        api.key_value_entry_close(kv_store_entry_lock_handle)?;
+
+        Runtime::emit_event(
+            api,
+            StoreEvent {
+                claimant: key.claimant,
+                resource_address: key.resource_address,
+                amount,
+            },
+        )?;
+
+        Ok(())
+    }
+
+    pub fn claim<Y>(
+        claimant: Global<AccountObjectTypeInfo>,
+        resource_address: ResourceAddress,
+        amount: Decimal,
+        api: &mut Y,
+    ) -> Result<AccountLockerClaimOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        // Only the owner of the claimant account can claim the resources stored for it.
+        let owner_role = AttachedRoleAssignment(*claimant.0.as_node_id()).get_owner_role(api)?;
+        Runtime::assert_access_rule(owner_role.rule, api)?;
+
+        let key = AccountLockerClaimKey {
+            claimant: claimant.0,
+            resource_address,
+        };
+        let bucket = Self::get_vault(key, |vault, api| vault.take(amount, api), false, api)?;
+
+        Runtime::emit_event(
+            api,
+            ClaimEvent {
+                claimant: key.claimant,
+                resource_address,
+                amount,
+            },
+        )?;
+
+        Ok(bucket)
+    }
+
+    pub fn recover<Y>(
+        claimant: Global<AccountObjectTypeInfo>,
+        resource_address: ResourceAddress,
+        amount: Decimal,
+        api: &mut Y,
+    ) -> Result<AccountLockerRecoverOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let key = AccountLockerClaimKey {
+            claimant: claimant.0,
+            resource_address,
+        };
+
+        let encoded_key = scrypto_encode(&key).expect("Impossible Case!");
+        let kv_store_entry_lock_handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            AccountLockerCollection::RecoveryTimeKeyValue.collection_index(),
+            &encoded_key,
+            LockFlags::read_only(),
+        )?;
+        let recoverable_at = api
+            .key_value_entry_get_typed::<AccountLockerRecoveryTimeEntryPayload>(
+                kv_store_entry_lock_handle,
+            )?
+            .map(|v| v.into_latest());
+        api.key_value_entry_close(kv_store_entry_lock_handle)?;
+
+        let recoverable_at = recoverable_at.ok_or(AccountLockerError::NothingStored {
+            claimant: key.claimant,
+            resource_address,
+        })?;
+        if !Runtime::compare_against_current_time(
+            api,
+            recoverable_at,
+            TimePrecision::Minute,
+            TimeComparisonOperator::Gte,
+        )? {
+            return Err(AccountLockerError::RecoveryNotYetAllowed { recoverable_at }.into());
+        }
+
+        let bucket = Self::get_vault(key, |vault, api| vault.take(amount, api), false, api)?;
+
+        Runtime::emit_event(
+            api,
+            RecoverEvent {
+                claimant: key.claimant,
+                resource_address,
+                amount,
+            },
+        )?;
+
+        Ok(bucket)
+    }
+
+    pub fn get_amount<Y>(
+        claimant: Global<AccountObjectTypeInfo>,
+        resource_address: ResourceAddress,
+        api: &mut Y,
+    ) -> Result<AccountLockerGetAmountOutput, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let key = AccountLockerClaimKey {
+            claimant: claimant.0,
+            resource_address,
+        };
+        match Self::get_vault(key, |vault, api| vault.amount(api), false, api) {
+            Ok(amount) => Ok(amount),
+            Err(RuntimeError::ApplicationError(ApplicationError::AccountLockerError(
+                AccountLockerError::NothingStored { .. },
+            ))) => Ok(Decimal::ZERO),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn get_config<Y>(api: &mut Y) -> Result<AccountLockerSubstate, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            AccountLockerField::Config.field_index(),
+            LockFlags::read_only(),
+        )?;
+        let config = api
+            .field_read_typed::<AccountLockerConfigFieldPayload>(handle)?
+            .into_latest();
+        api.field_close(handle)?;
+
+        Ok(config)
+    }
+
+    fn get_vault<F, Y, R>(
+        key: AccountLockerClaimKey,
+        vault_fn: F,
+        create: bool,
+        api: &mut Y,
+    ) -> Result<R, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+        F: FnOnce(&mut Vault, &mut Y) -> Result<R, RuntimeError>,
+    {
+        let encoded_key = scrypto_encode(&key).expect("Impossible Case!");
+
+        let mut kv_store_entry_lock_handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            AccountLockerCollection::VaultKeyValue.collection_index(),
+            &encoded_key,
+            LockFlags::read_only(),
+        )?;
+
+        // Get the vault stored in the KeyValueStore entry - if it doesn't exist, then create it if
+        // instructed to.
+        let entry = api
+            .key_value_entry_get_typed::<AccountLockerVaultEntryPayload>(
+                kv_store_entry_lock_handle,
+            )?
+            .map(|v| v.into_latest());
+        let mut vault = match entry {
+            Some(vault) => vault,
+            None if create => {
+                api.key_value_entry_close(kv_store_entry_lock_handle)?;
+                kv_store_entry_lock_handle = api.actor_open_key_value_entry(
+                    ACTOR_STATE_SELF,
+                    AccountLockerCollection::VaultKeyValue.collection_index(),
+                    &encoded_key,
+                    LockFlags::MUTABLE,
+                )?;
+                let vault = Vault::create(key.resource_address, api)?;
+                let own = vault.0;
+                api.key_value_entry_set_typed(
+                    kv_store_entry_lock_handle,
+                    &AccountLockerVaultEntryPayload::from_content_source(vault),
+                )?;
+                Vault(own)
+            }
+            None => {
+                api.key_value_entry_close(kv_store_entry_lock_handle)?;
+                return Err(AccountLockerError::NothingStored {
+                    claimant: key.claimant,
+                    resource_address: key.resource_address,
+                }
+                .into());
+            }
+        };
+
+        let rtn = vault_fn(&mut vault, api)?;
+        api.key_value_entry_close(kv_store_entry_lock_handle)?;
+
+        Ok(rtn)
+    }
+}
@@ -0,0 +1,24 @@
+use crate::types::*;
+use radix_engine_common::math::Decimal;
+use radix_engine_common::{ScryptoEvent, ScryptoSbor};
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug, Clone, PartialEq, Eq)]
+pub struct StoreEvent {
+    pub claimant: ComponentAddress,
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug, Clone, PartialEq, Eq)]
+pub struct ClaimEvent {
+    pub claimant: ComponentAddress,
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+}
+
+#[derive(ScryptoSbor, ScryptoEvent, Debug, Clone, PartialEq, Eq)]
+pub struct RecoverEvent {
+    pub claimant: ComponentAddress,
+    pub resource_address: ResourceAddress,
+    pub amount: Decimal,
+}
@@ -0,0 +1,106 @@
+use super::*;
+use crate::errors::ApplicationError;
+use crate::errors::RuntimeError;
+use crate::types::*;
+use radix_engine_interface::api::ClientApi;
+use radix_engine_interface::blueprints::locker::*;
+use radix_engine_interface::blueprints::package::PackageDefinition;
+
+pub struct LockerNativePackage;
+
+impl LockerNativePackage {
+    pub fn definition() -> PackageDefinition {
+        let blueprints = indexmap!(
+            ACCOUNT_LOCKER_BLUEPRINT.to_string() => AccountLockerBlueprint::get_definition()
+        );
+
+        PackageDefinition { blueprints }
+    }
+
+    pub fn invoke_export<Y>(
+        export_name: &str,
+        input: &IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        match export_name {
+            ACCOUNT_LOCKER_INSTANTIATE_EXPORT_NAME => {
+                let AccountLockerInstantiateInput {
+                    owner_role,
+                    storer_role,
+                    storer_updater_role,
+                    recoverer_role,
+                    recoverer_updater_role,
+                    claim_period_in_minutes,
+                    address_reservation,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+
+                let rtn = AccountLockerBlueprint::instantiate(
+                    owner_role,
+                    storer_role,
+                    storer_updater_role,
+                    recoverer_role,
+                    recoverer_updater_role,
+                    claim_period_in_minutes,
+                    address_reservation,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ACCOUNT_LOCKER_STORE_EXPORT_NAME => {
+                let AccountLockerStoreInput {
+                    claimant,
+                    bucket,
+                    try_direct_send,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+
+                let rtn = AccountLockerBlueprint::store(claimant, bucket, try_direct_send, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ACCOUNT_LOCKER_CLAIM_EXPORT_NAME => {
+                let AccountLockerClaimInput {
+                    claimant,
+                    resource_address,
+                    amount,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+
+                let rtn = AccountLockerBlueprint::claim(claimant, resource_address, amount, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ACCOUNT_LOCKER_RECOVER_EXPORT_NAME => {
+                let AccountLockerRecoverInput {
+                    claimant,
+                    resource_address,
+                    amount,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+
+                let rtn = AccountLockerBlueprint::recover(claimant, resource_address, amount, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            ACCOUNT_LOCKER_GET_AMOUNT_EXPORT_NAME => {
+                let AccountLockerGetAmountInput {
+                    claimant,
+                    resource_address,
+                } = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+
+                let rtn = AccountLockerBlueprint::get_amount(claimant, resource_address, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            _ => Err(RuntimeError::ApplicationError(
+                ApplicationError::ExportDoesNotExist(export_name.to_string()),
+            )),
+        }
+    }
+}
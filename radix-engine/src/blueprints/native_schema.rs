@@ -5,6 +5,7 @@ use crate::blueprints::access_controller::*;
 use crate::blueprints::account::*;
 use crate::blueprints::consensus_manager::*;
 use crate::blueprints::identity::*;
+use crate::blueprints::locker::*;
 use crate::blueprints::package::*;
 use crate::blueprints::pool::*;
 use crate::blueprints::resource::*;
@@ -24,6 +25,7 @@ lazy_static! {
     pub static ref ACCESS_CONTROLLER_PACKAGE_DEFINITION: PackageDefinition =
         AccessControllerNativePackage::definition();
     pub static ref POOL_PACKAGE_DEFINITION: PackageDefinition = PoolNativePackage::definition();
+    pub static ref LOCKER_PACKAGE_DEFINITION: PackageDefinition = LockerNativePackage::definition();
     pub static ref TRANSACTION_TRACKER_PACKAGE_DEFINITION: PackageDefinition =
         TransactionTrackerNativePackage::definition();
     pub static ref RESOURCE_PACKAGE_DEFINITION: PackageDefinition =
@@ -13,6 +13,7 @@ use native_sdk::modules::metadata::Metadata;
 use native_sdk::modules::role_assignment::RoleAssignment;
 use native_sdk::resource::NativeVault;
 use native_sdk::resource::ResourceManager;
+use native_sdk::runtime::Runtime;
 use radix_engine_interface::api::node_modules::auth::{AuthAddresses, ROLE_ASSIGNMENT_BLUEPRINT};
 use radix_engine_interface::api::node_modules::metadata::MetadataInit;
 use radix_engine_interface::api::*;
@@ -137,6 +138,11 @@ pub enum PackageError {
 
     RoyaltiesNotEnabled,
     RoyaltyAmountIsNegative(RoyaltyAmount),
+
+    DeprecationSuccessorIsSelf,
+
+    BlueprintNotFound(String),
+    BlueprintRoyaltyVaultNotFound(String),
 }
 
 impl From<InvalidNameError> for PackageError {
@@ -878,6 +884,51 @@ impl PackageNativePackage {
                 export: PACKAGE_CLAIM_ROYALTIES_IDENT.to_string(),
             },
         );
+        functions.insert(
+            PACKAGE_DEPRECATE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<PackageDeprecateInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<PackageDeprecateOutput>(),
+                ),
+                export: PACKAGE_DEPRECATE_IDENT.to_string(),
+            },
+        );
+        functions.insert(
+            PACKAGE_SET_BLUEPRINT_ROYALTY_CLAIMER_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<PackageSetBlueprintRoyaltyClaimerInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<PackageSetBlueprintRoyaltyClaimerOutput>(
+                        ),
+                ),
+                export: PACKAGE_SET_BLUEPRINT_ROYALTY_CLAIMER_IDENT.to_string(),
+            },
+        );
+        functions.insert(
+            PACKAGE_CLAIM_ROYALTIES_FOR_BLUEPRINT_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<PackageClaimRoyaltiesForBlueprintInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<PackageClaimRoyaltiesForBlueprintOutput>(
+                        ),
+                ),
+                export: PACKAGE_CLAIM_ROYALTIES_FOR_BLUEPRINT_IDENT.to_string(),
+            },
+        );
 
         let schema = generate_full_schema(aggregator);
         let blueprints = indexmap!(
@@ -916,6 +967,10 @@ impl PackageNativePackage {
                             },
                             methods {
                                 PACKAGE_CLAIM_ROYALTIES_IDENT => [SECURIFY_OWNER_ROLE];
+                                PACKAGE_DEPRECATE_IDENT => [SECURIFY_OWNER_ROLE];
+                                PACKAGE_SET_BLUEPRINT_ROYALTY_CLAIMER_IDENT => [SECURIFY_OWNER_ROLE];
+                                // Auth is checked against the blueprint's claimer rule
+                                PACKAGE_CLAIM_ROYALTIES_FOR_BLUEPRINT_IDENT => MethodAccessibility::Public;
                             }
                         },
                     ),
@@ -982,12 +1037,70 @@ impl PackageNativePackage {
                 let rtn = PackageRoyaltyNativeBlueprint::claim_royalties(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            PACKAGE_DEPRECATE_IDENT => {
+                let input: PackageDeprecateInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = Self::deprecate(input.successor, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            PACKAGE_SET_BLUEPRINT_ROYALTY_CLAIMER_IDENT => {
+                let input: PackageSetBlueprintRoyaltyClaimerInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = PackageRoyaltyNativeBlueprint::set_blueprint_royalty_claimer(
+                    input.blueprint_name,
+                    input.claimer,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            PACKAGE_CLAIM_ROYALTIES_FOR_BLUEPRINT_IDENT => {
+                let input: PackageClaimRoyaltiesForBlueprintInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = PackageRoyaltyNativeBlueprint::claim_royalties_for_blueprint(
+                    input.blueprint_name,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             _ => Err(RuntimeError::ApplicationError(
                 ApplicationError::ExportDoesNotExist(export_name.to_string()),
             )),
         }
     }
 
+    /// Marks the package as deprecated in favour of the given successor, replacing any
+    /// successor set previously.
+    pub(crate) fn deprecate<Y>(successor: PackageAddress, api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let package_address = api.actor_get_node_id(ACTOR_REF_SELF)?;
+        if successor.as_node_id().eq(&package_address) {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::PackageError(PackageError::DeprecationSuccessorIsSelf),
+            ));
+        }
+
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            PackageCollection::DeprecationKeyValue.collection_index(),
+            &scrypto_encode(&PackageDeprecationKey).unwrap(),
+            LockFlags::MUTABLE,
+        )?;
+        api.key_value_entry_set_typed(
+            handle,
+            &PackageDeprecationEntryPayload::from_content_source(PackageDeprecation { successor }),
+        )?;
+        api.key_value_entry_close(handle)?;
+
+        Ok(())
+    }
+
     fn init_system_struct(
         royalty_vault: Option<Vault>,
         package_structure: PackageStructure,
@@ -1480,18 +1593,50 @@ impl PackageRoyaltyNativeBlueprint {
         assert!(!royalty_charge.is_negative());
 
         if royalty_charge.is_non_zero() {
-            let handle = api.kernel_open_substate(
+            // Blueprints with a dedicated royalty vault are paid into it, all others into
+            // the package royalty accumulator.
+            let handle = api.kernel_open_substate_with_default(
                 receiver,
-                MAIN_BASE_PARTITION,
-                &PackageField::RoyaltyAccumulator.into(),
-                LockFlags::MUTABLE,
+                MAIN_BASE_PARTITION
+                    .at_offset(PACKAGE_BLUEPRINT_ROYALTY_VAULT_PARTITION_OFFSET)
+                    .unwrap(),
+                &SubstateKey::Map(scrypto_encode(&bp_version_key.blueprint).unwrap()),
+                LockFlags::read_only(),
+                Some(|| {
+                    let kv_entry = KeyValueEntrySubstate::<()>::default();
+                    IndexedScryptoValue::from_typed(&kv_entry)
+                }),
                 SystemLockData::default(),
             )?;
 
-            let substate: PackageRoyaltyAccumulatorFieldSubstate =
+            let substate: PackageBlueprintRoyaltyVaultEntrySubstate =
                 api.kernel_read_substate(handle)?.as_typed().unwrap();
 
-            let vault_id = substate.into_payload().into_latest().royalty_vault.0;
+            let (handle, vault_id) = match substate.into_value() {
+                Some(blueprint_royalty_vault) => (
+                    handle,
+                    blueprint_royalty_vault.into_latest().royalty_vault.0,
+                ),
+                None => {
+                    api.kernel_close_substate(handle)?;
+
+                    let handle = api.kernel_open_substate(
+                        receiver,
+                        MAIN_BASE_PARTITION,
+                        &PackageField::RoyaltyAccumulator.into(),
+                        LockFlags::MUTABLE,
+                        SystemLockData::default(),
+                    )?;
+
+                    let substate: PackageRoyaltyAccumulatorFieldSubstate =
+                        api.kernel_read_substate(handle)?.as_typed().unwrap();
+
+                    (
+                        handle,
+                        substate.into_payload().into_latest().royalty_vault.0,
+                    )
+                }
+            };
             let package_address = PackageAddress::new_or_panic(receiver.0);
             apply_royalty_cost(
                 api,
@@ -1509,14 +1654,7 @@ impl PackageRoyaltyNativeBlueprint {
     where
         Y: ClientApi<RuntimeError>,
     {
-        if !api.actor_is_feature_enabled(
-            ACTOR_STATE_SELF,
-            PackageFeature::PackageRoyalty.feature_name(),
-        )? {
-            return Err(RuntimeError::ApplicationError(
-                ApplicationError::PackageError(PackageError::RoyaltiesNotEnabled),
-            ));
-        }
+        Self::assert_royalties_enabled(api)?;
 
         let handle = api.actor_open_field(
             ACTOR_STATE_SELF,
@@ -1529,6 +1667,117 @@ impl PackageRoyaltyNativeBlueprint {
 
         Ok(bucket)
     }
+
+    /// Sets the rule for claiming the royalties of a blueprint, creating the blueprint's
+    /// royalty vault on first use. From then on, the blueprint's royalties are no longer
+    /// paid into the package royalty accumulator.
+    pub(crate) fn set_blueprint_royalty_claimer<Y>(
+        blueprint_name: String,
+        claimer: AccessRule,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::assert_royalties_enabled(api)?;
+
+        RoleAssignmentNativePackage::verify_access_rule(&claimer).map_err(|e| {
+            RuntimeError::ApplicationError(ApplicationError::PackageError(
+                PackageError::RoleAssignmentError(e),
+            ))
+        })?;
+
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            PackageCollection::BlueprintVersionDefinitionKeyValue.collection_index(),
+            &scrypto_encode(&BlueprintVersionKey::new_default(blueprint_name.as_str())).unwrap(),
+            LockFlags::read_only(),
+        )?;
+        let definition =
+            api.key_value_entry_get_typed::<PackageBlueprintVersionDefinitionEntryPayload>(handle)?;
+        api.key_value_entry_close(handle)?;
+        if definition.is_none() {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::PackageError(PackageError::BlueprintNotFound(blueprint_name)),
+            ));
+        }
+
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            PackageCollection::BlueprintRoyaltyVaultKeyValue.collection_index(),
+            &scrypto_encode(&blueprint_name).unwrap(),
+            LockFlags::MUTABLE,
+        )?;
+        let royalty_vault = match api
+            .key_value_entry_get_typed::<PackageBlueprintRoyaltyVaultEntryPayload>(handle)?
+        {
+            Some(entry) => entry.into_latest().royalty_vault,
+            None => Vault(ResourceManager(XRD).new_empty_vault(api)?),
+        };
+        api.key_value_entry_set_typed(
+            handle,
+            &PackageBlueprintRoyaltyVaultEntryPayload::from_content_source(
+                PackageBlueprintRoyaltyVault {
+                    royalty_vault,
+                    claimer,
+                },
+            ),
+        )?;
+        api.key_value_entry_close(handle)?;
+
+        Ok(())
+    }
+
+    pub(crate) fn claim_royalties_for_blueprint<Y>(
+        blueprint_name: String,
+        api: &mut Y,
+    ) -> Result<Bucket, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::assert_royalties_enabled(api)?;
+
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            PackageCollection::BlueprintRoyaltyVaultKeyValue.collection_index(),
+            &scrypto_encode(&blueprint_name).unwrap(),
+            LockFlags::read_only(),
+        )?;
+        let blueprint_royalty_vault = match api
+            .key_value_entry_get_typed::<PackageBlueprintRoyaltyVaultEntryPayload>(handle)?
+        {
+            Some(entry) => entry.into_latest(),
+            None => {
+                return Err(RuntimeError::ApplicationError(
+                    ApplicationError::PackageError(PackageError::BlueprintRoyaltyVaultNotFound(
+                        blueprint_name,
+                    )),
+                ));
+            }
+        };
+
+        Runtime::assert_access_rule(blueprint_royalty_vault.claimer, api)?;
+        let bucket = blueprint_royalty_vault.royalty_vault.take_all(api)?;
+        api.key_value_entry_close(handle)?;
+
+        Ok(bucket)
+    }
+
+    fn assert_royalties_enabled<Y>(api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if !api.actor_is_feature_enabled(
+            ACTOR_STATE_SELF,
+            PackageFeature::PackageRoyalty.feature_name(),
+        )? {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::PackageError(PackageError::RoyaltiesNotEnabled),
+            ));
+        }
+
+        Ok(())
+    }
 }
 
 pub struct PackageAuthNativeBlueprint;
@@ -110,6 +110,28 @@ declare_native_blueprint_state! {
             },
             allow_ownership: false,
         },
+        deprecation: KeyValue {
+            entry_ident: Deprecation,
+            key_type: {
+                kind: Static,
+                content_type: PackageDeprecationKey,
+            },
+            value_type: {
+                kind: StaticSingleVersioned,
+            },
+            allow_ownership: false,
+        },
+        blueprint_royalty_vaults: KeyValue {
+            entry_ident: BlueprintRoyaltyVault,
+            key_type: {
+                kind: Static,
+                content_type: String,
+            },
+            value_type: {
+                kind: StaticSingleVersioned,
+            },
+            allow_ownership: true,
+        },
     }
 }
 
@@ -169,3 +191,24 @@ impl Debug for PackageCodeInstrumentedCodeV1 {
             .finish()
     }
 }
+
+/// The (only) key of the package's deprecation collection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, ScryptoSbor)]
+pub struct PackageDeprecationKey;
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct PackageDeprecationV1 {
+    pub successor: PackageAddress,
+}
+
+//---------------------------------------
+// Collection models - By Blueprint
+//---------------------------------------
+
+#[derive(Debug, PartialEq, Eq, ScryptoSbor)]
+pub struct PackageBlueprintRoyaltyVaultV1 {
+    /// The vault collecting the royalties of the blueprint's functions and methods.
+    pub royalty_vault: Vault,
+    /// The rule which must be satisfied to claim from the vault.
+    pub claimer: AccessRule,
+}
@@ -27,7 +27,7 @@ use radix_engine_interface::prelude::BlueprintFunctionsSchemaInit;
 use radix_engine_interface::types::*;
 use radix_engine_interface::*;
 
-pub const MULTI_RESOURCE_POOL_BLUEPRINT_IDENT: &'static str = "MultiResourcePool";
+pub const MULTI_RESOURCE_POOL_BLUEPRINT_IDENT: &'static str = MULTI_RESOURCE_POOL_BLUEPRINT;
 
 declare_native_blueprint_state! {
     blueprint_ident: MultiResourcePool,
@@ -226,6 +226,18 @@ impl MultiResourcePoolBlueprint {
     where
         Y: ClientApi<RuntimeError> + KernelNodeApi,
     {
+        // Contributions and redemptions go through all of the vaults of the pool, so the number
+        // of resources is bounded.
+        if resource_addresses.len() > MULTI_RESOURCE_POOL_MAX_RESOURCES {
+            return Err(
+                MultiResourcePoolError::CantCreatePoolWithMoreThanMaxResources {
+                    max: MULTI_RESOURCE_POOL_MAX_RESOURCES,
+                    actual: resource_addresses.len(),
+                }
+                .into(),
+            );
+        }
+
         // A pool can't be created where one of the resources is non-fungible - error out if any of
         // them are
         for resource_address in resource_addresses.iter() {
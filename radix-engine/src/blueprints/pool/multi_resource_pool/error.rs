@@ -25,6 +25,10 @@ pub enum MultiResourcePoolError {
     CantCreatePoolWithLessThanOneResource,
     DecimalOverflowError,
     InvalidGetRedemptionAmount,
+    CantCreatePoolWithMoreThanMaxResources {
+        max: usize,
+        actual: usize,
+    },
 }
 
 impl From<MultiResourcePoolError> for RuntimeError {
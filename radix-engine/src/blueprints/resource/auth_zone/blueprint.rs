@@ -57,6 +57,29 @@ impl AuthZoneBlueprint {
         Ok(())
     }
 
+    /// Pushes all the given proofs in one go, so that a rule requiring all of them is satisfied
+    /// as soon as the call returns.
+    pub fn push_composite<Y>(proofs: Vec<Proof>, api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let auth_zone_handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            AuthZoneField::AuthZone.into(),
+            LockFlags::MUTABLE,
+        )?;
+
+        let mut auth_zone: AuthZone = api.field_read_typed(auth_zone_handle)?;
+        for proof in proofs {
+            auth_zone.push(proof);
+        }
+
+        api.field_write_typed(auth_zone_handle, &auth_zone)?;
+        api.field_close(auth_zone_handle)?;
+
+        Ok(())
+    }
+
     pub fn create_proof_of_amount<Y>(
         resource_address: ResourceAddress,
         amount: Decimal,
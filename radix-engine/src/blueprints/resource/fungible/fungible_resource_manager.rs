@@ -46,6 +46,10 @@ declare_native_blueprint_state! {
             ident: Burn,
             description: "Enabled if the resource can ever support burning",
         },
+        max_supply: {
+            ident: MaxSupply,
+            description: "Enabled if the total supply of the resource is capped",
+        },
     },
     fields: {
         divisibility: {
@@ -61,12 +65,20 @@ declare_native_blueprint_state! {
             },
             condition: Condition::if_feature(FungibleResourceManagerFeature::TrackTotalSupply),
         },
+        max_supply: {
+            ident: MaxSupply,
+            field_type: {
+                kind: StaticSingleVersioned,
+            },
+            condition: Condition::if_feature(FungibleResourceManagerFeature::MaxSupply),
+        },
     },
     collections: {}
 }
 
 pub type FungibleResourceManagerDivisibilityV1 = u8;
 pub type FungibleResourceManagerTotalSupplyV1 = Decimal;
+pub type FungibleResourceManagerMaxSupplyV1 = Decimal;
 
 /// Represents an error when accessing a bucket.
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -78,6 +90,7 @@ pub enum FungibleResourceManagerError {
     NotMintable,
     NotBurnable,
     UnexpectedDecimalComputationError,
+    MaxSupplyExceeded { max: Decimal, actual: Decimal },
 }
 
 pub fn verify_divisibility(divisibility: u8) -> Result<(), RuntimeError> {
@@ -112,8 +125,24 @@ fn check_mint_amount(divisibility: u8, amount: Decimal) -> Result<(), RuntimeErr
     Ok(())
 }
 
+fn check_max_supply(max_supply: Decimal, total_supply: Decimal) -> Result<(), RuntimeError> {
+    if total_supply > max_supply {
+        return Err(RuntimeError::ApplicationError(
+            ApplicationError::FungibleResourceManagerError(
+                FungibleResourceManagerError::MaxSupplyExceeded {
+                    max: max_supply,
+                    actual: total_supply,
+                },
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
 fn to_features_and_roles(
     track_total_supply: bool,
+    max_supply: bool,
     role_init: FungibleResourceRoles,
 ) -> (FungibleResourceManagerFeatureSet, RoleAssignmentInit) {
     let mut roles = RoleAssignmentInit::new();
@@ -124,6 +153,7 @@ fn to_features_and_roles(
         vault_recall: role_init.recall_roles.is_some(),
         mint: role_init.mint_roles.is_some(),
         burn: role_init.burn_roles.is_some(),
+        max_supply,
     };
 
     roles
@@ -199,6 +229,17 @@ impl FungibleResourceManagerBlueprint {
                 export: FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_INITIAL_SUPPLY_EXPORT_NAME.to_string(),
             },
         );
+        functions.insert(
+            FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_MAX_SUPPLY_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<FungibleResourceManagerCreateWithMaxSupplyInput>()),
+                output: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<FungibleResourceManagerCreateWithMaxSupplyOutput>()),
+                export: FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_MAX_SUPPLY_EXPORT_NAME.to_string(),
+            },
+        );
 
         functions.insert(
             FUNGIBLE_RESOURCE_MANAGER_MINT_IDENT.to_string(),
@@ -302,6 +343,20 @@ impl FungibleResourceManagerBlueprint {
                 export: FUNGIBLE_RESOURCE_MANAGER_GET_TOTAL_SUPPLY_EXPORT_NAME.to_string(),
             },
         );
+        functions.insert(
+            RESOURCE_MANAGER_GET_MAX_SUPPLY_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<ResourceManagerGetMaxSupplyInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<ResourceManagerGetMaxSupplyOutput>(),
+                ),
+                export: FUNGIBLE_RESOURCE_MANAGER_GET_MAX_SUPPLY_EXPORT_NAME.to_string(),
+            },
+        );
         functions.insert(
             RESOURCE_MANAGER_GET_AMOUNT_FOR_WITHDRAWAL_IDENT.to_string(),
             FunctionSchemaInit {
@@ -384,6 +439,7 @@ impl FungibleResourceManagerBlueprint {
                         RESOURCE_MANAGER_CREATE_EMPTY_BUCKET_IDENT => MethodAccessibility::Public;
                         RESOURCE_MANAGER_CREATE_EMPTY_VAULT_IDENT => MethodAccessibility::Public;
                         RESOURCE_MANAGER_GET_TOTAL_SUPPLY_IDENT => MethodAccessibility::Public;
+                        RESOURCE_MANAGER_GET_MAX_SUPPLY_IDENT => MethodAccessibility::Public;
                         RESOURCE_MANAGER_GET_AMOUNT_FOR_WITHDRAWAL_IDENT => MethodAccessibility::Public;
                         RESOURCE_MANAGER_DROP_EMPTY_BUCKET_IDENT => MethodAccessibility::Public;
                         RESOURCE_MANAGER_GET_RESOURCE_TYPE_IDENT => MethodAccessibility::Public;
@@ -396,6 +452,7 @@ impl FungibleResourceManagerBlueprint {
     pub(crate) fn create<Y>(
         owner_role: OwnerRole,
         track_total_supply: bool,
+        max_supply: Option<Decimal>,
         divisibility: u8,
         resource_roles: FungibleResourceRoles,
         metadata: ModuleConfig<MetadataInit>,
@@ -408,6 +465,7 @@ impl FungibleResourceManagerBlueprint {
         let (object_id, roles) = Self::create_object(
             Decimal::ZERO,
             track_total_supply,
+            max_supply,
             divisibility,
             resource_roles,
             api,
@@ -429,6 +487,7 @@ impl FungibleResourceManagerBlueprint {
     pub(crate) fn create_with_initial_supply<Y>(
         owner_role: OwnerRole,
         track_total_supply: bool,
+        max_supply: Option<Decimal>,
         divisibility: u8,
         initial_supply: Decimal,
         resource_roles: FungibleResourceRoles,
@@ -442,6 +501,7 @@ impl FungibleResourceManagerBlueprint {
         let (object_id, roles) = Self::create_object(
             initial_supply,
             track_total_supply,
+            max_supply,
             divisibility,
             resource_roles,
             api,
@@ -478,6 +538,50 @@ impl FungibleResourceManagerBlueprint {
         Ok((resource_address, bucket))
     }
 
+    pub(crate) fn create_with_max_supply<Y>(
+        owner_role: OwnerRole,
+        divisibility: u8,
+        max_supply: Decimal,
+        initial_supply: Option<Decimal>,
+        resource_roles: FungibleResourceRoles,
+        metadata: ModuleConfig<MetadataInit>,
+        address_reservation: Option<GlobalAddressReservation>,
+        api: &mut Y,
+    ) -> Result<(ResourceAddress, Option<Bucket>), RuntimeError>
+    where
+        Y: KernelNodeApi + ClientApi<RuntimeError>,
+    {
+        match initial_supply {
+            Some(initial_supply) => {
+                let (resource_address, bucket) = Self::create_with_initial_supply(
+                    owner_role,
+                    true,
+                    Some(max_supply),
+                    divisibility,
+                    initial_supply,
+                    resource_roles,
+                    metadata,
+                    address_reservation,
+                    api,
+                )?;
+                Ok((resource_address, Some(bucket)))
+            }
+            None => {
+                let resource_address = Self::create(
+                    owner_role,
+                    true,
+                    Some(max_supply),
+                    divisibility,
+                    resource_roles,
+                    metadata,
+                    address_reservation,
+                    api,
+                )?;
+                Ok((resource_address, None))
+            }
+        }
+    }
+
     fn create_address_reservation<Y>(
         address_reservation: Option<GlobalAddressReservation>,
         api: &mut Y,
@@ -502,6 +606,7 @@ impl FungibleResourceManagerBlueprint {
     fn create_object<Y>(
         initial_supply: Decimal,
         track_total_supply: bool,
+        max_supply: Option<Decimal>,
         divisibility: u8,
         resource_roles: FungibleResourceRoles,
         api: &mut Y,
@@ -519,7 +624,8 @@ impl FungibleResourceManagerBlueprint {
                 )
         };
 
-        let (features, roles) = to_features_and_roles(track_total_supply, resource_roles);
+        let (features, roles) =
+            to_features_and_roles(track_total_supply, max_supply.is_some(), resource_roles);
 
         if features.track_total_supply {
             let total_supply_field = if features.mint || features.burn {
@@ -542,6 +648,24 @@ impl FungibleResourceManagerBlueprint {
             );
         }
 
+        if let Some(max_supply) = max_supply {
+            if !check_fungible_amount(&max_supply, divisibility) {
+                return Err(RuntimeError::ApplicationError(
+                    ApplicationError::FungibleResourceManagerError(
+                        FungibleResourceManagerError::InvalidAmount(max_supply, divisibility),
+                    ),
+                ));
+            }
+            check_max_supply(max_supply, initial_supply)?;
+
+            fields.insert(
+                FungibleResourceManagerField::MaxSupply.into(),
+                FieldValue::immutable(
+                    &FungibleResourceManagerMaxSupplyFieldPayload::from_content_source(max_supply),
+                ),
+            );
+        }
+
         let object_id = api.new_object(
             FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
             features.feature_names_str(),
@@ -603,6 +727,9 @@ impl FungibleResourceManagerBlueprint {
                             FungibleResourceManagerError::UnexpectedDecimalComputationError,
                         ),
                     ))?;
+            if let Some(max_supply) = Self::get_max_supply(api)? {
+                check_max_supply(max_supply, total_supply)?;
+            }
             api.field_write_typed(
                 total_supply_handle,
                 &FungibleResourceManagerTotalSupplyFieldPayload::from_content_source(total_supply),
@@ -791,6 +918,31 @@ impl FungibleResourceManagerBlueprint {
         }
     }
 
+    pub(crate) fn get_max_supply<Y>(api: &mut Y) -> Result<Option<Decimal>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if api.actor_is_feature_enabled(
+            ACTOR_STATE_SELF,
+            FungibleResourceManagerFeature::MaxSupply.feature_name(),
+        )? {
+            let max_supply_handle = api.actor_open_field(
+                ACTOR_STATE_SELF,
+                FungibleResourceManagerField::MaxSupply.into(),
+                LockFlags::read_only(),
+            )?;
+            let max_supply = api
+                .field_read_typed::<FungibleResourceManagerMaxSupplyFieldPayload>(
+                    max_supply_handle,
+                )?
+                .into_latest();
+            api.field_close(max_supply_handle)?;
+            Ok(Some(max_supply))
+        } else {
+            Ok(None)
+        }
+    }
+
     pub(crate) fn amount_for_withdrawal<Y>(
         api: &mut Y,
         amount: Decimal,
@@ -119,6 +119,21 @@ impl FungibleVaultBlueprint {
                 export: FUNGIBLE_VAULT_LOCK_FEE_IDENT.to_string(),
             },
         );
+        functions.insert(
+            FUNGIBLE_VAULT_LOCK_FEE_WITH_REFUND_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<FungibleVaultLockFeeWithRefundInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<FungibleVaultLockFeeWithRefundOutput>(),
+                ),
+                export: FUNGIBLE_VAULT_LOCK_FEE_WITH_REFUND_IDENT.to_string(),
+            },
+        );
         functions.insert(
             VAULT_RECALL_IDENT.to_string(),
             FunctionSchemaInit {
@@ -269,6 +284,7 @@ impl FungibleVaultBlueprint {
                         VAULT_TAKE_IDENT => [WITHDRAWER_ROLE];
                         VAULT_TAKE_ADVANCED_IDENT => [WITHDRAWER_ROLE];
                         FUNGIBLE_VAULT_LOCK_FEE_IDENT => [WITHDRAWER_ROLE];
+                        FUNGIBLE_VAULT_LOCK_FEE_WITH_REFUND_IDENT => [WITHDRAWER_ROLE];
                         VAULT_RECALL_IDENT => [RECALLER_ROLE];
                         VAULT_PUT_IDENT => [DEPOSITOR_ROLE];
                         VAULT_BURN_IDENT => [BURNER_ROLE];
@@ -374,6 +390,46 @@ impl FungibleVaultBlueprint {
     }
 
     pub fn lock_fee<Y>(amount: Decimal, contingent: bool, api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::lock_fee_internal(amount, contingent, None, api)
+    }
+
+    /// Locks a fee whose unused part is refunded to another XRD vault, rather than to this one.
+    ///
+    /// The refund goes back to this vault instead if the refund vault can't take it when the fees
+    /// are finalized: if it was created by the transaction and the transaction failed, or if it
+    /// is frozen for deposits by then.
+    pub fn lock_fee_with_refund<Y>(
+        amount: Decimal,
+        contingent: bool,
+        refund_vault: InternalAddress,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let refund_vault_id = refund_vault.into_node_id();
+        let blueprint_id = api.get_blueprint_id(&refund_vault_id)?;
+        if !blueprint_id.package_address.eq(&RESOURCE_PACKAGE)
+            || !blueprint_id.blueprint_name.eq(FUNGIBLE_VAULT_BLUEPRINT)
+            || api.get_outer_object(&refund_vault_id)? != GlobalAddress::from(XRD)
+        {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::VaultError(VaultError::LockFeeInvalidRefundVault(refund_vault)),
+            ));
+        }
+
+        Self::lock_fee_internal(amount, contingent, Some(refund_vault_id), api)
+    }
+
+    fn lock_fee_internal<Y>(
+        amount: Decimal,
+        contingent: bool,
+        refund_vault_id: Option<NodeId>,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
     {
@@ -435,7 +491,7 @@ impl FungibleVaultBlueprint {
         // At this point the vault fee take is guaranteed to be force-written
         // so we must take care not to error out before crediting the cost units
         // and emitting an event
-        api.lock_fee(fee, contingent);
+        api.lock_fee(fee, contingent, refund_vault_id);
 
         Ok(())
     }
@@ -46,6 +46,10 @@ declare_native_blueprint_state! {
             ident: Burn,
             description: "Enabled if the resource can ever support burning",
         },
+        max_supply: {
+            ident: MaxSupply,
+            description: "Enabled if the total supply of the resource is capped",
+        },
     },
     fields: {
         id_type: {
@@ -67,6 +71,13 @@ declare_native_blueprint_state! {
             },
             condition: Condition::if_feature(NonFungibleResourceManagerFeature::TrackTotalSupply),
         },
+        max_supply: {
+            ident: MaxSupply,
+            field_type: {
+                kind: StaticSingleVersioned,
+            },
+            condition: Condition::if_feature(NonFungibleResourceManagerFeature::MaxSupply),
+        },
     },
     collections: {
         data: KeyValue {
@@ -81,11 +92,41 @@ declare_native_blueprint_state! {
             },
             allow_ownership: false,
         },
+        blob: KeyValue {
+            entry_ident: Blob,
+            key_type: {
+                kind: Static,
+                content_type: NonFungibleBlobChunkKey,
+            },
+            value_type: {
+                kind: StaticSingleVersioned,
+            },
+            allow_ownership: false,
+        },
     }
 }
 
 pub type NonFungibleResourceManagerIdTypeV1 = NonFungibleIdType;
 pub type NonFungibleResourceManagerTotalSupplyV1 = Decimal;
+pub type NonFungibleResourceManagerMaxSupplyV1 = Decimal;
+/// One chunk of a blob. Blobs are stored in chunks, so that their size isn't bounded by the max
+/// size of a substate.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct NonFungibleResourceManagerBlobV1 {
+    /// The number of chunks of the whole blob.
+    pub chunk_count: u32,
+    pub chunk: Vec<u8>,
+}
+
+/// The key of a chunk of a blob: the hash of the whole blob, and the index of the chunk.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, ScryptoSbor)]
+pub struct NonFungibleBlobChunkKey {
+    pub blob_hash: Hash,
+    pub index: u32,
+}
+
+/// The max size of each stored chunk of a blob.
+pub const NON_FUNGIBLE_BLOB_CHUNK_SIZE: usize = 256 * 1024;
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub struct NonFungibleResourceManagerMutableFieldsV1 {
     pub mutable_field_index: IndexMap<String, usize>,
@@ -105,6 +146,10 @@ pub enum NonFungibleResourceManagerError {
     NotMintable,
     NotBurnable,
     UnexpectedDecimalComputationError,
+    BlobHashMismatch { expected: Hash, actual: Hash },
+    BlobChunkMissing { hash: Hash, index: u32 },
+    InvalidMaxSupply(Decimal),
+    MaxSupplyExceeded { max: Decimal, actual: Decimal },
 }
 
 /// Represents an error when accessing a bucket.
@@ -221,6 +266,17 @@ impl NonFungibleResourceManagerBlueprint {
                 export: NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_RUID_WITH_INITIAL_SUPPLY_IDENT.to_string(),
             },
         );
+        functions.insert(
+            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_MAX_SUPPLY_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerCreateWithMaxSupplyInput>()),
+                output: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerCreateWithMaxSupplyOutput>()),
+                export: NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_MAX_SUPPLY_EXPORT_NAME.to_string(),
+            },
+        );
 
         functions.insert(
             NON_FUNGIBLE_RESOURCE_MANAGER_MINT_IDENT.to_string(),
@@ -277,6 +333,29 @@ impl NonFungibleResourceManagerBlueprint {
             },
         );
 
+        functions.insert(
+            NON_FUNGIBLE_RESOURCE_MANAGER_MINT_WITH_BLOBS_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerMintWithBlobsInput>()),
+                output: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerMintWithBlobsOutput>()),
+                export: NON_FUNGIBLE_RESOURCE_MANAGER_MINT_WITH_BLOBS_EXPORT_NAME.to_string(),
+            },
+        );
+        functions.insert(
+            NON_FUNGIBLE_RESOURCE_MANAGER_GET_NON_FUNGIBLE_BLOB_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerGetNonFungibleBlobInput>()),
+                output: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleResourceManagerGetNonFungibleBlobOutput>()),
+                export: NON_FUNGIBLE_RESOURCE_MANAGER_GET_NON_FUNGIBLE_BLOB_IDENT.to_string(),
+            },
+        );
+
         functions.insert(
             NON_FUNGIBLE_RESOURCE_MANAGER_MINT_RUID_IDENT.to_string(),
             FunctionSchemaInit {
@@ -393,6 +472,20 @@ impl NonFungibleResourceManagerBlueprint {
                 export: NON_FUNGIBLE_RESOURCE_MANAGER_GET_TOTAL_SUPPLY_EXPORT_NAME.to_string(),
             },
         );
+        functions.insert(
+            RESOURCE_MANAGER_GET_MAX_SUPPLY_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<ResourceManagerGetMaxSupplyInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator
+                        .add_child_type_and_descendents::<ResourceManagerGetMaxSupplyOutput>(),
+                ),
+                export: NON_FUNGIBLE_RESOURCE_MANAGER_GET_MAX_SUPPLY_EXPORT_NAME.to_string(),
+            },
+        );
         functions.insert(
             RESOURCE_MANAGER_GET_AMOUNT_FOR_WITHDRAWAL_IDENT.to_string(),
             FunctionSchemaInit {
@@ -475,17 +568,20 @@ impl NonFungibleResourceManagerBlueprint {
                         NON_FUNGIBLE_RESOURCE_MANAGER_MINT_IDENT => [MINTER_ROLE];
                         NON_FUNGIBLE_RESOURCE_MANAGER_MINT_RUID_IDENT => [MINTER_ROLE];
                         NON_FUNGIBLE_RESOURCE_MANAGER_MINT_SINGLE_RUID_IDENT => [MINTER_ROLE];
+                        NON_FUNGIBLE_RESOURCE_MANAGER_MINT_WITH_BLOBS_IDENT => [MINTER_ROLE];
                         RESOURCE_MANAGER_BURN_IDENT => [BURNER_ROLE];
                         RESOURCE_MANAGER_PACKAGE_BURN_IDENT => MethodAccessibility::OwnPackageOnly;
                         NON_FUNGIBLE_RESOURCE_MANAGER_UPDATE_DATA_IDENT => [NON_FUNGIBLE_DATA_UPDATER_ROLE];
                         RESOURCE_MANAGER_CREATE_EMPTY_BUCKET_IDENT => MethodAccessibility::Public;
                         RESOURCE_MANAGER_CREATE_EMPTY_VAULT_IDENT => MethodAccessibility::Public;
                         RESOURCE_MANAGER_GET_TOTAL_SUPPLY_IDENT => MethodAccessibility::Public;
+                        RESOURCE_MANAGER_GET_MAX_SUPPLY_IDENT => MethodAccessibility::Public;
                         RESOURCE_MANAGER_GET_AMOUNT_FOR_WITHDRAWAL_IDENT => MethodAccessibility::Public;
                         RESOURCE_MANAGER_DROP_EMPTY_BUCKET_IDENT => MethodAccessibility::Public;
                         RESOURCE_MANAGER_GET_RESOURCE_TYPE_IDENT => MethodAccessibility::Public;
                         NON_FUNGIBLE_RESOURCE_MANAGER_GET_NON_FUNGIBLE_IDENT => MethodAccessibility::Public;
                         NON_FUNGIBLE_RESOURCE_MANAGER_EXISTS_IDENT => MethodAccessibility::Public;
+                        NON_FUNGIBLE_RESOURCE_MANAGER_GET_NON_FUNGIBLE_BLOB_IDENT => MethodAccessibility::Public;
                     }
                 }),
             },
@@ -634,6 +730,7 @@ impl NonFungibleResourceManagerBlueprint {
         owner_role: OwnerRole,
         id_type: NonFungibleIdType,
         track_total_supply: bool,
+        max_supply: Option<Decimal>,
         non_fungible_schema: NonFungibleDataSchema,
         resource_roles: NonFungibleResourceRoles,
         metadata: ModuleConfig<MetadataInit>,
@@ -647,6 +744,7 @@ impl NonFungibleResourceManagerBlueprint {
             id_type,
             indexmap!(),
             track_total_supply,
+            max_supply,
             non_fungible_schema,
             resource_roles,
             api,
@@ -679,6 +777,7 @@ impl NonFungibleResourceManagerBlueprint {
         owner_role: OwnerRole,
         id_type: NonFungibleIdType,
         track_total_supply: bool,
+        max_supply: Option<Decimal>,
         non_fungible_schema: NonFungibleDataSchema,
         entries: IndexMap<NonFungibleLocalId, (ScryptoValue,)>,
         resource_roles: NonFungibleResourceRoles,
@@ -704,6 +803,7 @@ impl NonFungibleResourceManagerBlueprint {
             id_type,
             entries,
             track_total_supply,
+            max_supply,
             non_fungible_schema,
             resource_roles,
             api,
@@ -771,6 +871,7 @@ impl NonFungibleResourceManagerBlueprint {
             NonFungibleIdType::RUID,
             entries,
             track_total_supply,
+            None,
             non_fungible_schema,
             resource_roles,
             api,
@@ -809,6 +910,53 @@ impl NonFungibleResourceManagerBlueprint {
         ))
     }
 
+    pub(crate) fn create_with_max_supply<Y>(
+        owner_role: OwnerRole,
+        id_type: NonFungibleIdType,
+        non_fungible_schema: NonFungibleDataSchema,
+        max_supply: Decimal,
+        entries: Option<IndexMap<NonFungibleLocalId, (ScryptoValue,)>>,
+        resource_roles: NonFungibleResourceRoles,
+        metadata: ModuleConfig<MetadataInit>,
+        address_reservation: Option<GlobalAddressReservation>,
+        api: &mut Y,
+    ) -> Result<(ResourceAddress, Option<Bucket>), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        match entries {
+            Some(entries) => {
+                let (resource_address, bucket) = Self::create_with_initial_supply(
+                    owner_role,
+                    id_type,
+                    true,
+                    Some(max_supply),
+                    non_fungible_schema,
+                    entries,
+                    resource_roles,
+                    metadata,
+                    address_reservation,
+                    api,
+                )?;
+                Ok((resource_address, Some(bucket)))
+            }
+            None => {
+                let resource_address = Self::create(
+                    owner_role,
+                    id_type,
+                    true,
+                    Some(max_supply),
+                    non_fungible_schema,
+                    resource_roles,
+                    metadata,
+                    address_reservation,
+                    api,
+                )?;
+                Ok((resource_address, None))
+            }
+        }
+    }
+
     pub(crate) fn mint_non_fungible<Y>(
         entries: IndexMap<NonFungibleLocalId, (ScryptoValue,)>,
         api: &mut Y,
@@ -833,6 +981,109 @@ impl NonFungibleResourceManagerBlueprint {
         Ok(bucket)
     }
 
+    pub(crate) fn mint_non_fungible_with_blobs<Y>(
+        entries: IndexMap<NonFungibleLocalId, (ScryptoValue,)>,
+        blobs: IndexMap<Hash, Vec<u8>>,
+        api: &mut Y,
+    ) -> Result<Bucket, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        Self::assert_mintable(api)?;
+
+        for (expected, blob) in blobs {
+            let actual = hash(&blob);
+            if actual != expected {
+                return Err(RuntimeError::ApplicationError(
+                    ApplicationError::NonFungibleResourceManagerError(
+                        NonFungibleResourceManagerError::BlobHashMismatch { expected, actual },
+                    ),
+                ));
+            }
+
+            // Blobs are content-addressed, so an existing blob is never overwritten
+            if Self::get_non_fungible_blob_chunk(expected, 0, api)?.is_some() {
+                continue;
+            }
+            let chunks: Vec<&[u8]> = if blob.is_empty() {
+                vec![&[]]
+            } else {
+                blob.chunks(NON_FUNGIBLE_BLOB_CHUNK_SIZE).collect()
+            };
+            let chunk_count: u32 = chunks.len().try_into().unwrap();
+            for (index, chunk) in chunks.into_iter().enumerate() {
+                let key = NonFungibleBlobChunkKey {
+                    blob_hash: expected,
+                    index: index.try_into().unwrap(),
+                };
+                let handle = api.actor_open_key_value_entry(
+                    ACTOR_STATE_SELF,
+                    NonFungibleResourceManagerCollection::BlobKeyValue.collection_index(),
+                    &scrypto_encode(&key).unwrap(),
+                    LockFlags::MUTABLE,
+                )?;
+                api.key_value_entry_set_typed(
+                    handle,
+                    NonFungibleResourceManagerBlobEntryPayload::from_content_source(
+                        NonFungibleResourceManagerBlobV1 {
+                            chunk_count,
+                            chunk: chunk.to_vec(),
+                        },
+                    ),
+                )?;
+                api.key_value_entry_close(handle)?;
+            }
+        }
+
+        Self::mint_non_fungible(entries, api)
+    }
+
+    pub(crate) fn get_non_fungible_blob<Y>(
+        hash: Hash,
+        api: &mut Y,
+    ) -> Result<Option<Vec<u8>>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let Some(first_chunk) = Self::get_non_fungible_blob_chunk(hash, 0, api)? else {
+            return Ok(None);
+        };
+
+        let mut blob = first_chunk.chunk;
+        for index in 1..first_chunk.chunk_count {
+            let chunk = Self::get_non_fungible_blob_chunk(hash, index, api)?.ok_or(
+                RuntimeError::ApplicationError(ApplicationError::NonFungibleResourceManagerError(
+                    NonFungibleResourceManagerError::BlobChunkMissing { hash, index },
+                )),
+            )?;
+            blob.extend(chunk.chunk);
+        }
+
+        Ok(Some(blob))
+    }
+
+    fn get_non_fungible_blob_chunk<Y>(
+        blob_hash: Hash,
+        index: u32,
+        api: &mut Y,
+    ) -> Result<Option<NonFungibleResourceManagerBlobV1>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_key_value_entry(
+            ACTOR_STATE_SELF,
+            NonFungibleResourceManagerCollection::BlobKeyValue.collection_index(),
+            &scrypto_encode(&NonFungibleBlobChunkKey { blob_hash, index }).unwrap(),
+            LockFlags::read_only(),
+        )?;
+        let chunk = api
+            .key_value_entry_get_typed::<NonFungibleResourceManagerBlobEntryPayload>(handle)?
+            .map(|payload| payload.into_latest());
+        api.key_value_entry_close(handle)?;
+
+        Ok(chunk)
+    }
+
     pub(crate) fn mint_ruid_non_fungible<Y>(
         entries: Vec<(ScryptoValue,)>,
         api: &mut Y,
@@ -1199,10 +1450,36 @@ impl NonFungibleResourceManagerBlueprint {
         }
     }
 
+    pub(crate) fn get_max_supply<Y>(api: &mut Y) -> Result<Option<Decimal>, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if api.actor_is_feature_enabled(
+            ACTOR_STATE_SELF,
+            NonFungibleResourceManagerFeature::MaxSupply.feature_name(),
+        )? {
+            let max_supply_handle = api.actor_open_field(
+                ACTOR_STATE_SELF,
+                NonFungibleResourceManagerField::MaxSupply.into(),
+                LockFlags::read_only(),
+            )?;
+            let max_supply = api
+                .field_read_typed::<NonFungibleResourceManagerMaxSupplyFieldPayload>(
+                    max_supply_handle,
+                )?
+                .into_latest();
+            api.field_close(max_supply_handle)?;
+            Ok(Some(max_supply))
+        } else {
+            Ok(None)
+        }
+    }
+
     fn create_object<Y>(
         id_type: NonFungibleIdType,
         entries: IndexMap<NonFungibleLocalId, (ScryptoValue,)>,
         track_total_supply: bool,
+        max_supply: Option<Decimal>,
         non_fungible_schema: NonFungibleDataSchema,
         resource_roles: NonFungibleResourceRoles,
         api: &mut Y,
@@ -1242,6 +1519,7 @@ impl NonFungibleResourceManagerBlueprint {
 
         let (mut features, roles) = to_features_and_roles(resource_roles);
         features.track_total_supply = track_total_supply;
+        features.max_supply = max_supply.is_some();
 
         let mut fields = indexmap! {
             NonFungibleResourceManagerField::IdType.into() => FieldValue::immutable(
@@ -1271,6 +1549,26 @@ impl NonFungibleResourceManagerBlueprint {
             );
         }
 
+        if let Some(max_supply) = max_supply {
+            if !check_fungible_amount(&max_supply, 0) {
+                return Err(RuntimeError::ApplicationError(
+                    ApplicationError::NonFungibleResourceManagerError(
+                        NonFungibleResourceManagerError::InvalidMaxSupply(max_supply),
+                    ),
+                ));
+            }
+            check_max_supply(max_supply, supply)?;
+
+            fields.insert(
+                NonFungibleResourceManagerField::MaxSupply.into(),
+                FieldValue::immutable(
+                    &NonFungibleResourceManagerMaxSupplyFieldPayload::from_content_source(
+                        max_supply,
+                    ),
+                ),
+            );
+        }
+
         let object_id = api.new_object(
             NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
             features.feature_names_str(),
@@ -1401,6 +1699,11 @@ impl NonFungibleResourceManagerBlueprint {
                             NonFungibleResourceManagerError::UnexpectedDecimalComputationError,
                         ),
                     ))?;
+            if amount.is_positive() {
+                if let Some(max_supply) = Self::get_max_supply(api)? {
+                    check_max_supply(max_supply, total_supply)?;
+                }
+            }
             api.field_write_typed(
                 total_supply_handle,
                 &NonFungibleResourceManagerTotalSupplyFieldPayload::from_content_source(
@@ -1430,6 +1733,21 @@ impl NonFungibleResourceManagerBlueprint {
     }
 }
 
+fn check_max_supply(max_supply: Decimal, total_supply: Decimal) -> Result<(), RuntimeError> {
+    if total_supply > max_supply {
+        return Err(RuntimeError::ApplicationError(
+            ApplicationError::NonFungibleResourceManagerError(
+                NonFungibleResourceManagerError::MaxSupplyExceeded {
+                    max: max_supply,
+                    actual: total_supply,
+                },
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
 fn to_features_and_roles(
     role_init: NonFungibleResourceRoles,
 ) -> (NonFungibleResourceManagerFeatureSet, RoleAssignmentInit) {
@@ -1441,6 +1759,7 @@ fn to_features_and_roles(
         vault_recall: role_init.recall_roles.is_some(),
         mint: role_init.mint_roles.is_some(),
         burn: role_init.burn_roles.is_some(),
+        max_supply: false, // Will be set later
     };
 
     roles
@@ -16,6 +16,7 @@ pub enum NonFungibleVaultError {
     MissingId(NonFungibleLocalId),
     NotEnoughAmount,
     DecimalOverflow,
+    InvalidPageLimit,
 }
 
 declare_native_blueprint_state! {
@@ -220,6 +221,17 @@ impl NonFungibleVaultBlueprint {
                 export: NON_FUNGIBLE_VAULT_GET_NON_FUNGIBLE_LOCAL_IDS_IDENT.to_string(),
             },
         );
+        functions.insert(
+            NON_FUNGIBLE_VAULT_GET_NON_FUNGIBLE_LOCAL_IDS_PAGE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleVaultGetNonFungibleLocalIdsPageInput>()),
+                output: TypeRef::Static(aggregator
+                    .add_child_type_and_descendents::<NonFungibleVaultGetNonFungibleLocalIdsPageOutput>()),
+                export: NON_FUNGIBLE_VAULT_GET_NON_FUNGIBLE_LOCAL_IDS_PAGE_IDENT.to_string(),
+            },
+        );
         functions.insert(
             NON_FUNGIBLE_VAULT_CONTAINS_NON_FUNGIBLE_IDENT.to_string(),
             FunctionSchemaInit {
@@ -340,6 +352,7 @@ impl NonFungibleVaultBlueprint {
                     methods: method_auth_template! {
                         VAULT_GET_AMOUNT_IDENT => MethodAccessibility::Public;
                         NON_FUNGIBLE_VAULT_GET_NON_FUNGIBLE_LOCAL_IDS_IDENT => MethodAccessibility::Public;
+                        NON_FUNGIBLE_VAULT_GET_NON_FUNGIBLE_LOCAL_IDS_PAGE_IDENT => MethodAccessibility::Public;
                         NON_FUNGIBLE_VAULT_CONTAINS_NON_FUNGIBLE_IDENT => MethodAccessibility::Public;
                         NON_FUNGIBLE_VAULT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT => MethodAccessibility::Public;
 
@@ -505,6 +518,44 @@ impl NonFungibleVaultBlueprint {
         Ok(ids)
     }
 
+    /// Returns the liquid non-fungible local IDs of the vault, one page at a time.
+    ///
+    /// IDs locked by proofs (which only exist within a transaction) are not included.
+    /// The limit must be non-zero, as an empty page would never advance the cursor.
+    pub fn get_non_fungible_local_ids_page<Y>(
+        limit: u32,
+        cursor: Option<NonFungibleLocalId>,
+        api: &mut Y,
+    ) -> Result<NonFungibleLocalIdsPage, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if limit == 0 {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::NonFungibleVaultError(NonFungibleVaultError::InvalidPageLimit),
+            ));
+        }
+
+        let ids: Vec<NonFungibleLocalId> = api.actor_index_scan_keys_after_typed(
+            ACTOR_STATE_SELF,
+            NonFungibleVaultCollection::NonFungibleIndex.collection_index(),
+            cursor.as_ref(),
+            limit,
+        )?;
+        let id_len: u32 = ids.len().try_into().unwrap();
+
+        let next_cursor = if id_len < limit {
+            None
+        } else {
+            ids.last().cloned().or(cursor)
+        };
+
+        Ok(NonFungibleLocalIdsPage {
+            ids: ids.into_iter().collect(),
+            next_cursor,
+        })
+    }
+
     pub fn recall<Y>(amount: Decimal, api: &mut Y) -> Result<Bucket, RuntimeError>
     where
         Y: ClientApi<RuntimeError>,
@@ -33,6 +33,10 @@ pub(crate) const FUNGIBLE_RESOURCE_MANAGER_GET_RESOURCE_TYPE_EXPORT_NAME: &str =
     "get_resource_type_FungibleResourceManager";
 pub(crate) const FUNGIBLE_RESOURCE_MANAGER_GET_TOTAL_SUPPLY_EXPORT_NAME: &str =
     "get_total_supply_FungibleResourceManager";
+pub(crate) const FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_MAX_SUPPLY_EXPORT_NAME: &str =
+    "create_with_max_supply_FungibleResourceManager";
+pub(crate) const FUNGIBLE_RESOURCE_MANAGER_GET_MAX_SUPPLY_EXPORT_NAME: &str =
+    "get_max_supply_FungibleResourceManager";
 pub(crate) const FUNGIBLE_RESOURCE_MANAGER_AMOUNT_FOR_WITHDRAWAL_EXPORT_NAME: &str =
     "amount_for_withdrawal_FungibleResourceManager";
 pub(crate) const FUNGIBLE_RESOURCE_MANAGER_DROP_EMPTY_BUCKET_EXPORT_NAME: &str =
@@ -50,6 +54,8 @@ pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_MINT_EXPORT_NAME: &str =
     "mint_NonFungibleResourceManager";
 pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_MINT_RUID_EXPORT_NAME: &str =
     "mint_ruid_NonFungibleResourceManager";
+pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_MINT_WITH_BLOBS_EXPORT_NAME: &str =
+    "mint_with_blobs_NonFungibleResourceManager";
 pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_EMPTY_VAULT_EXPORT_NAME: &str =
     "create_empty_vault_NonFungibleResourceManager";
 pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_EMPTY_BUCKET_EXPORT_NAME: &str =
@@ -58,6 +64,10 @@ pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_GET_RESOURCE_TYPE_EXPORT_NAME: &s
     "get_resource_type_NonFungibleResourceManager";
 pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_GET_TOTAL_SUPPLY_EXPORT_NAME: &str =
     "get_total_supply_NonFungibleResourceManager";
+pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_MAX_SUPPLY_EXPORT_NAME: &str =
+    "create_with_max_supply_NonFungibleResourceManager";
+pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_GET_MAX_SUPPLY_EXPORT_NAME: &str =
+    "get_max_supply_NonFungibleResourceManager";
 pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_AMOUNT_FOR_WITHDRAWAL_EXPORT_NAME: &str =
     "amount_for_withdrawal_NonFungibleResourceManager";
 pub(crate) const NON_FUNGIBLE_RESOURCE_MANAGER_DROP_EMPTY_BUCKET_EXPORT_NAME: &str =
@@ -143,6 +153,7 @@ pub(crate) const NON_FUNGIBLE_PROOF_ON_MOVE_EXPORT_NAME: &str = "on_move_NonFung
 
 pub(crate) const AUTH_ZONE_POP_EXPORT_NAME: &str = "AuthZone_pop";
 pub(crate) const AUTH_ZONE_PUSH_EXPORT_NAME: &str = "AuthZone_push";
+pub(crate) const AUTH_ZONE_PUSH_COMPOSITE_EXPORT_NAME: &str = "AuthZone_push_composite";
 pub(crate) const AUTH_ZONE_CREATE_PROOF_OF_AMOUNT_EXPORT_NAME: &str =
     "AuthZone_create_proof_of_amount";
 pub(crate) const AUTH_ZONE_CREATE_PROOF_OF_NON_FUNGIBLES_EXPORT_NAME: &str =
@@ -907,6 +918,34 @@ impl ResourceNativePackage {
                     export: WORKTOP_ASSERT_CONTAINS_NON_FUNGIBLES_IDENT.to_string(),
                 },
             );
+            functions.insert(
+                WORKTOP_ASSERT_RESOURCES_ONLY_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref_mut()),
+                    input: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<WorktopAssertResourcesOnlyInput>(),
+                    ),
+                    output: TypeRef::Static(
+                        aggregator
+                            .add_child_type_and_descendents::<WorktopAssertResourcesOnlyOutput>(),
+                    ),
+                    export: WORKTOP_ASSERT_RESOURCES_ONLY_IDENT.to_string(),
+                },
+            );
+            functions.insert(
+                WORKTOP_CONTENTS_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref()),
+                    input: TypeRef::Static(
+                        aggregator.add_child_type_and_descendents::<WorktopContentsInput>(),
+                    ),
+                    output: TypeRef::Static(
+                        aggregator.add_child_type_and_descendents::<WorktopContentsOutput>(),
+                    ),
+                    export: WORKTOP_CONTENTS_IDENT.to_string(),
+                },
+            );
             functions.insert(
                 WORKTOP_DRAIN_IDENT.to_string(),
                 FunctionSchemaInit {
@@ -984,6 +1023,19 @@ impl ResourceNativePackage {
                     export: AUTH_ZONE_PUSH_EXPORT_NAME.to_string(),
                 },
             );
+            functions.insert(
+                AUTH_ZONE_PUSH_COMPOSITE_IDENT.to_string(),
+                FunctionSchemaInit {
+                    receiver: Some(ReceiverInfo::normal_ref_mut()),
+                    input: TypeRef::Static(
+                        aggregator.add_child_type_and_descendents::<AuthZonePushCompositeInput>(),
+                    ),
+                    output: TypeRef::Static(
+                        aggregator.add_child_type_and_descendents::<AuthZonePushCompositeOutput>(),
+                    ),
+                    export: AUTH_ZONE_PUSH_COMPOSITE_EXPORT_NAME.to_string(),
+                },
+            );
             functions.insert(
                 AUTH_ZONE_CREATE_PROOF_OF_AMOUNT_IDENT.to_string(),
                 FunctionSchemaInit {
@@ -1160,6 +1212,7 @@ impl ResourceNativePackage {
                 let rtn = FungibleResourceManagerBlueprint::create(
                     input.owner_role,
                     input.track_total_supply,
+                    None,
                     input.divisibility,
                     input.resource_roles,
                     input.metadata,
@@ -1176,6 +1229,7 @@ impl ResourceNativePackage {
                 let rtn = FungibleResourceManagerBlueprint::create_with_initial_supply(
                     input.owner_role,
                     input.track_total_supply,
+                    None,
                     input.divisibility,
                     input.initial_supply,
                     input.resource_roles,
@@ -1185,6 +1239,23 @@ impl ResourceNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_MAX_SUPPLY_EXPORT_NAME => {
+                let input: FungibleResourceManagerCreateWithMaxSupplyInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = FungibleResourceManagerBlueprint::create_with_max_supply(
+                    input.owner_role,
+                    input.divisibility,
+                    input.max_supply,
+                    input.initial_supply,
+                    input.resource_roles,
+                    input.metadata,
+                    input.address_reservation,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             FUNGIBLE_RESOURCE_MANAGER_MINT_EXPORT_NAME => {
                 let input: FungibleResourceManagerMintInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1245,6 +1316,13 @@ impl ResourceNativePackage {
                 let rtn = FungibleResourceManagerBlueprint::get_total_supply(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            FUNGIBLE_RESOURCE_MANAGER_GET_MAX_SUPPLY_EXPORT_NAME => {
+                let _input: ResourceManagerGetMaxSupplyInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = FungibleResourceManagerBlueprint::get_max_supply(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             FUNGIBLE_RESOURCE_MANAGER_AMOUNT_FOR_WITHDRAWAL_EXPORT_NAME => {
                 let input: ResourceManagerGetAmountForWithdrawalInput =
                     input.as_typed().map_err(|e| {
@@ -1266,6 +1344,7 @@ impl ResourceNativePackage {
                     input.owner_role,
                     input.id_type,
                     input.track_total_supply,
+                    None,
                     input.non_fungible_schema,
                     input.resource_roles,
                     input.metadata,
@@ -1283,6 +1362,7 @@ impl ResourceNativePackage {
                     input.owner_role,
                     input.id_type,
                     input.track_total_supply,
+                    None,
                     input.non_fungible_schema,
                     input.entries,
                     input.resource_roles,
@@ -1292,6 +1372,24 @@ impl ResourceNativePackage {
                 )?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_MAX_SUPPLY_EXPORT_NAME => {
+                let input: NonFungibleResourceManagerCreateWithMaxSupplyInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = NonFungibleResourceManagerBlueprint::create_with_max_supply(
+                    input.owner_role,
+                    input.id_type,
+                    input.non_fungible_schema,
+                    input.max_supply,
+                    input.entries,
+                    input.resource_roles,
+                    input.metadata,
+                    input.address_reservation,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_RUID_WITH_INITIAL_SUPPLY_IDENT => {
                 let input: NonFungibleResourceManagerCreateRuidWithInitialSupplyInput =
                     input.as_typed().map_err(|e| {
@@ -1319,6 +1417,27 @@ impl ResourceNativePackage {
                     NonFungibleResourceManagerBlueprint::mint_non_fungible(input.entries, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            NON_FUNGIBLE_RESOURCE_MANAGER_MINT_WITH_BLOBS_EXPORT_NAME => {
+                let input: NonFungibleResourceManagerMintWithBlobsInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = NonFungibleResourceManagerBlueprint::mint_non_fungible_with_blobs(
+                    input.entries,
+                    input.blobs,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            NON_FUNGIBLE_RESOURCE_MANAGER_GET_NON_FUNGIBLE_BLOB_IDENT => {
+                let input: NonFungibleResourceManagerGetNonFungibleBlobInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn =
+                    NonFungibleResourceManagerBlueprint::get_non_fungible_blob(input.hash, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             NON_FUNGIBLE_RESOURCE_MANAGER_MINT_RUID_EXPORT_NAME => {
                 let input: NonFungibleResourceManagerMintRuidInput =
                     input.as_typed().map_err(|e| {
@@ -1417,6 +1536,13 @@ impl ResourceNativePackage {
                 let rtn = NonFungibleResourceManagerBlueprint::get_total_supply(api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            NON_FUNGIBLE_RESOURCE_MANAGER_GET_MAX_SUPPLY_EXPORT_NAME => {
+                let _input: ResourceManagerGetMaxSupplyInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = NonFungibleResourceManagerBlueprint::get_max_supply(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             NON_FUNGIBLE_RESOURCE_MANAGER_AMOUNT_FOR_WITHDRAWAL_EXPORT_NAME => {
                 let input: ResourceManagerGetAmountForWithdrawalInput =
                     input.as_typed().map_err(|e| {
@@ -1445,6 +1571,18 @@ impl ResourceNativePackage {
                 let rtn = FungibleVaultBlueprint::lock_fee(input.amount, input.contingent, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            FUNGIBLE_VAULT_LOCK_FEE_WITH_REFUND_IDENT => {
+                let input: FungibleVaultLockFeeWithRefundInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+                let rtn = FungibleVaultBlueprint::lock_fee_with_refund(
+                    input.amount,
+                    input.contingent,
+                    input.refund_vault,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             FUNGIBLE_VAULT_TAKE_EXPORT_NAME => {
                 let input: VaultTakeInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -1613,6 +1751,18 @@ impl ResourceNativePackage {
                 let rtn = NonFungibleVaultBlueprint::get_non_fungible_local_ids(input.limit, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            NON_FUNGIBLE_VAULT_GET_NON_FUNGIBLE_LOCAL_IDS_PAGE_IDENT => {
+                let input: NonFungibleVaultGetNonFungibleLocalIdsPageInput =
+                    input.as_typed().map_err(|e| {
+                        RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                    })?;
+                let rtn = NonFungibleVaultBlueprint::get_non_fungible_local_ids_page(
+                    input.limit,
+                    input.cursor,
+                    api,
+                )?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             NON_FUNGIBLE_VAULT_CONTAINS_NON_FUNGIBLE_IDENT => {
                 let input: NonFungibleVaultContainsNonFungibleInput =
                     input.as_typed().map_err(|e| {
@@ -1948,6 +2098,10 @@ impl ResourceNativePackage {
             WORKTOP_ASSERT_CONTAINS_NON_FUNGIBLES_IDENT => {
                 WorktopBlueprint::assert_contains_non_fungibles(input, api)
             }
+            WORKTOP_ASSERT_RESOURCES_ONLY_IDENT => {
+                WorktopBlueprint::assert_resources_only(input, api)
+            }
+            WORKTOP_CONTENTS_IDENT => WorktopBlueprint::contents(input, api),
             WORKTOP_DRAIN_IDENT => WorktopBlueprint::drain(input, api),
             AUTH_ZONE_POP_EXPORT_NAME => {
                 let _input: AuthZonePopInput = input.as_typed().map_err(|e| {
@@ -1967,6 +2121,15 @@ impl ResourceNativePackage {
 
                 Ok(IndexedScryptoValue::from_typed(&()))
             }
+            AUTH_ZONE_PUSH_COMPOSITE_EXPORT_NAME => {
+                let input: AuthZonePushCompositeInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+
+                AuthZoneBlueprint::push_composite(input.proofs, api)?;
+
+                Ok(IndexedScryptoValue::from_typed(&()))
+            }
             AUTH_ZONE_CREATE_PROOF_OF_AMOUNT_EXPORT_NAME => {
                 let input: AuthZoneCreateProofOfAmountInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -15,6 +15,7 @@ pub enum VaultError {
     LockFeeNotRadixToken,
     LockFeeInsufficientBalance { requested: Decimal, actual: Decimal },
     DecimalOverflow,
+    LockFeeInvalidRefundVault(InternalAddress),
 }
 
 impl From<VaultError> for RuntimeError {
@@ -26,6 +26,7 @@ impl WorktopSubstate {
 pub enum WorktopError {
     AssertionFailed,
     InsufficientBalance,
+    UnexpectedResource(ResourceAddress),
 }
 
 pub struct WorktopBlueprint;
@@ -337,6 +338,63 @@ impl WorktopBlueprint {
         Ok(IndexedScryptoValue::from_typed(&()))
     }
 
+    pub(crate) fn assert_resources_only<Y>(
+        input: &IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + ClientApi<RuntimeError>,
+    {
+        let input: WorktopAssertResourcesOnlyInput = input
+            .as_typed()
+            .map_err(|e| RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e)))?;
+
+        let worktop_handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            WorktopField::Worktop.into(),
+            LockFlags::read_only(),
+        )?;
+        let worktop: WorktopSubstate = api.field_read_typed(worktop_handle)?;
+        // Relies on the invariant that there are no empty buckets in the worktop
+        if let Some(resource_address) = worktop
+            .resources
+            .keys()
+            .find(|resource_address| !input.resource_addresses.contains(*resource_address))
+        {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::WorktopError(WorktopError::UnexpectedResource(*resource_address)),
+            ));
+        }
+        api.field_close(worktop_handle)?;
+        Ok(IndexedScryptoValue::from_typed(&()))
+    }
+
+    pub(crate) fn contents<Y>(
+        input: &IndexedScryptoValue,
+        api: &mut Y,
+    ) -> Result<IndexedScryptoValue, RuntimeError>
+    where
+        Y: KernelNodeApi + ClientApi<RuntimeError>,
+    {
+        let _input: WorktopContentsInput = input
+            .as_typed()
+            .map_err(|e| RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e)))?;
+
+        let worktop_handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            WorktopField::Worktop.into(),
+            LockFlags::read_only(),
+        )?;
+        let worktop: WorktopSubstate = api.field_read_typed(worktop_handle)?;
+        let mut contents: WorktopContentsOutput = index_map_new();
+        for (resource_address, bucket) in worktop.resources {
+            let amount = Bucket(bucket).amount(api)?;
+            contents.insert(resource_address, amount);
+        }
+        api.field_close(worktop_handle)?;
+        Ok(IndexedScryptoValue::from_typed(&contents))
+    }
+
     pub(crate) fn drain<Y>(
         input: &IndexedScryptoValue,
         api: &mut Y,
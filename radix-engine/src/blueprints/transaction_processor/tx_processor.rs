@@ -53,6 +53,13 @@ pub enum TransactionProcessorError {
     AuthZoneIsEmpty,
     InvocationOutputDecodeError(DecodeError),
     ArgsEncodeError(EncodeError),
+    /// A method was called on a named address before its address reservation was used.
+    AddressNotYetInstantiated(u32),
+    ComponentBlueprintMismatch {
+        component_address: ComponentAddress,
+        expected: BlueprintId,
+        actual: BlueprintId,
+    },
 }
 
 impl From<TransactionProcessorError> for RuntimeError {
@@ -193,6 +200,25 @@ impl TransactionProcessorBlueprint {
                     )?;
                     InstructionOutput::None
                 }
+                InstructionV1::AssertWorktopResourcesOnly { resource_addresses } => {
+                    worktop.assert_resources_only(resource_addresses.into_iter().collect(), api)?;
+                    InstructionOutput::None
+                }
+                InstructionV1::YieldWorktopContents => {
+                    let contents = worktop.contents(api)?;
+                    InstructionOutput::CallReturn(scrypto_encode(&contents).unwrap())
+                }
+                InstructionV1::BurnResourceFromWorktop {
+                    resource_address,
+                    amount,
+                } => {
+                    let bucket = worktop.take(resource_address, amount, api)?;
+                    let rtn = bucket.burn(api)?;
+
+                    let result = IndexedScryptoValue::from_typed(&rtn);
+                    processor.handle_call_return_data(&result, &worktop, api)?;
+                    InstructionOutput::CallReturn(result.into())
+                }
                 InstructionV1::PopFromAuthZone {} => {
                     let proof = LocalAuthZone::pop(api)?.ok_or(RuntimeError::ApplicationError(
                         ApplicationError::TransactionProcessorError(
@@ -207,6 +233,14 @@ impl TransactionProcessorBlueprint {
                     LocalAuthZone::push(proof, api)?;
                     InstructionOutput::None
                 }
+                InstructionV1::PushCompositeToAuthZone { proof_ids } => {
+                    let mut proofs = Vec::new();
+                    for proof_id in proof_ids {
+                        proofs.push(processor.take_proof(&proof_id)?);
+                    }
+                    LocalAuthZone::push_composite(proofs, api)?;
+                    InstructionOutput::None
+                }
                 InstructionV1::CreateProofFromAuthZoneOfAmount {
                     amount,
                     resource_address,
@@ -397,11 +431,39 @@ impl TransactionProcessorBlueprint {
                     let (address_reservation, address) = api.allocate_global_address(
                         BlueprintId::new(&package_address, blueprint_name),
                     )?;
-                    processor.create_manifest_address_reservation(address_reservation)?;
-                    processor.create_manifest_address(address)?;
+                    let address_reservation_id =
+                        processor.create_manifest_address_reservation(address_reservation);
+                    let address_id = processor.create_manifest_address(address);
+                    processor
+                        .named_address_reservations
+                        .insert(address_id, address_reservation_id);
 
                     InstructionOutput::None
                 }
+                InstructionV1::AssertComponentBlueprint {
+                    component_address,
+                    package_address,
+                    blueprint_name,
+                } => {
+                    let expected = BlueprintId::new(&package_address, blueprint_name);
+                    let actual = api.get_blueprint_id(component_address.as_node_id())?;
+                    if actual != expected {
+                        return Err(RuntimeError::ApplicationError(
+                            ApplicationError::TransactionProcessorError(
+                                TransactionProcessorError::ComponentBlueprintMismatch {
+                                    component_address,
+                                    expected,
+                                    actual,
+                                },
+                            ),
+                        ));
+                    }
+                    InstructionOutput::None
+                }
+                InstructionV1::SetInstructionCostLimit { cost_units } => {
+                    api.set_instruction_cost_unit_limit(cost_units)?;
+                    InstructionOutput::None
+                }
             };
             outputs.push(result);
         }
@@ -417,6 +479,8 @@ struct TransactionProcessor {
     proof_mapping: IndexMap<ManifestProof, NodeId>,
     address_reservation_mapping: NonIterMap<ManifestAddressReservation, NodeId>,
     address_mapping: NonIterMap<u32, NodeId>,
+    /// The address reservation allocated alongside each named address
+    named_address_reservations: NonIterMap<u32, ManifestAddressReservation>,
     id_allocator: ManifestIdAllocator,
     blobs_by_hash: IndexMap<Hash, Vec<u8>>,
 }
@@ -432,13 +496,12 @@ impl TransactionProcessor {
             bucket_mapping: NonIterMap::new(),
             address_reservation_mapping: NonIterMap::new(),
             address_mapping: NonIterMap::new(),
+            named_address_reservations: NonIterMap::new(),
             id_allocator: ManifestIdAllocator::new(),
         };
 
         for address_reservation in global_address_reservations {
-            processor
-                .create_manifest_address_reservation(address_reservation)
-                .unwrap();
+            processor.create_manifest_address_reservation(address_reservation);
         }
         processor
     }
@@ -548,17 +611,35 @@ impl TransactionProcessor {
     fn create_manifest_address_reservation(
         &mut self,
         address_reservation: GlobalAddressReservation,
-    ) -> Result<(), RuntimeError> {
+    ) -> ManifestAddressReservation {
         let new_id = self.id_allocator.new_address_reservation_id();
         self.address_reservation_mapping
             .insert(new_id, address_reservation.0.into());
-        Ok(())
+        new_id
     }
 
-    fn create_manifest_address(&mut self, address: GlobalAddress) -> Result<(), RuntimeError> {
+    fn create_manifest_address(&mut self, address: GlobalAddress) -> u32 {
         let new_id = self.id_allocator.new_address_id();
         self.address_mapping.insert(new_id, address.into());
-        Ok(())
+        new_id
+    }
+
+    /// Gets a named address which is about to be invoked, checking that the object at it can
+    /// already exist - that is, its address reservation has been passed to an earlier instruction.
+    fn get_invocable_address(&mut self, address_id: &u32) -> Result<NodeId, RuntimeError> {
+        if let Some(address_reservation_id) = self.named_address_reservations.get(address_id) {
+            if self
+                .address_reservation_mapping
+                .contains_key(address_reservation_id)
+            {
+                return Err(RuntimeError::ApplicationError(
+                    ApplicationError::TransactionProcessorError(
+                        TransactionProcessorError::AddressNotYetInstantiated(*address_id),
+                    ),
+                ));
+            }
+        }
+        self.get_address(address_id)
     }
 
     fn resolve_package_address(
@@ -568,7 +649,7 @@ impl TransactionProcessor {
         match address {
             DynamicPackageAddress::Static(address) => Ok(address),
             DynamicPackageAddress::Named(name) => {
-                let node_id = self.get_address(&name)?;
+                let node_id = self.get_invocable_address(&name)?;
                 PackageAddress::try_from(node_id.0).map_err(|_| {
                     RuntimeError::ApplicationError(ApplicationError::TransactionProcessorError(
                         TransactionProcessorError::NotPackageAddress(node_id),
@@ -585,7 +666,7 @@ impl TransactionProcessor {
         match address {
             DynamicGlobalAddress::Static(address) => Ok(address),
             DynamicGlobalAddress::Named(name) => {
-                let node_id = self.get_address(&name)?;
+                let node_id = self.get_invocable_address(&name)?;
                 GlobalAddress::try_from(node_id.0).map_err(|_| {
                     RuntimeError::ApplicationError(ApplicationError::TransactionProcessorError(
                         TransactionProcessorError::NotGlobalAddress(node_id),
@@ -627,6 +708,7 @@ impl TransactionProcessor {
                     }
                 },
                 TypeInfoSubstate::KeyValueStore(_)
+                | TypeInfoSubstate::IndexStore(_)
                 | TypeInfoSubstate::GlobalAddressReservation(_)
                 | TypeInfoSubstate::GlobalAddressPhantom(_) => {
                     // No-op, but can be extended
@@ -1,6 +1,7 @@
 use crate::blueprints::access_controller::AccessControllerError;
 use crate::blueprints::account::AccountError;
 use crate::blueprints::consensus_manager::{ConsensusManagerError, ValidatorError};
+use crate::blueprints::locker::AccountLockerError;
 use crate::blueprints::package::PackageError;
 use crate::blueprints::pool::multi_resource_pool::MultiResourcePoolError;
 use crate::blueprints::pool::one_resource_pool::OneResourcePoolError;
@@ -24,6 +25,7 @@ use crate::system::attached_modules::royalty::ComponentRoyaltyError;
 use crate::system::system_modules::auth::AuthError;
 use crate::system::system_modules::costing::CostingError;
 use crate::system::system_modules::limits::TransactionLimitsError;
+use crate::system::system_modules::reentrancy::ReentrancyError;
 use crate::system::system_type_checker::TypeCheckError;
 use crate::transaction::AbortReason;
 use crate::types::*;
@@ -31,6 +33,7 @@ use crate::vm::wasm::WasmRuntimeError;
 use radix_engine_interface::api::object_api::ModuleId;
 use radix_engine_interface::api::{ActorStateHandle, AttachedModuleId};
 use radix_engine_interface::blueprints::package::{BlueprintPartitionType, CanonicalBlueprintId};
+use transaction::model::TipError;
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub enum IdAllocationError {
@@ -56,6 +59,7 @@ pub enum RejectionReason {
     },
     IntentHashPreviouslyCommitted,
     IntentHashPreviouslyCancelled,
+    TipOutOfBounds(TipError),
 }
 
 impl fmt::Display for RejectionReason {
@@ -255,6 +259,10 @@ pub enum SystemError {
     /// we're treating the system as a black-box here.
     #[cfg(feature = "std")]
     SystemPanic(String),
+
+    NotAnIndexStore,
+    IndexStoreOwnershipNotAllowed,
+    InvalidLogPayload(DecodeError),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -317,6 +325,7 @@ pub enum SystemModuleError {
     CostingError(CostingError),
     TransactionLimitsError(TransactionLimitsError),
     EventError(Box<EventError>),
+    ReentrancyError(ReentrancyError),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -464,6 +473,8 @@ pub enum ApplicationError {
     TwoResourcePoolError(TwoResourcePoolError),
 
     MultiResourcePoolError(MultiResourcePoolError),
+
+    AccountLockerError(AccountLockerError),
 }
 
 impl From<TransactionProcessorError> for ApplicationError {
@@ -1231,6 +1231,44 @@ impl<C, L: Clone> CallFrame<C, L> {
         Ok(keys)
     }
 
+    pub fn scan_keys_after<'f, K: SubstateKeyContent + 'static, S: CommitableSubstateStore, E>(
+        &mut self,
+        substate_io: &'f mut SubstateIO<S>,
+        node_id: &NodeId,
+        partition_num: PartitionNumber,
+        after: Option<&SubstateKey>,
+        limit: u32,
+        handler: &mut impl CallFrameIOAccessHandler<C, L, E>,
+    ) -> Result<Vec<SubstateKey>, CallbackError<CallFrameScanKeysError, E>> {
+        // Check node visibility
+        let (_ref_origin, device) = self.get_node_ref(node_id).ok_or_else(|| {
+            CallbackError::Error(CallFrameScanKeysError::NodeNotVisible(node_id.clone()))
+        })?;
+
+        let mut adapter = CallFrameToIOAccessAdapter {
+            call_frame: self,
+            handler,
+            phantom: PhantomData::default(),
+        };
+
+        let keys = substate_io.scan_keys_after::<K, E>(
+            device,
+            node_id,
+            partition_num,
+            after,
+            limit,
+            &mut adapter,
+        )?;
+
+        for key in &keys {
+            self.process_output_substate_key(key).map_err(|e| {
+                CallbackError::Error(CallFrameScanKeysError::ProcessSubstateKeyError(e))
+            })?;
+        }
+
+        Ok(keys)
+    }
+
     pub fn drain_substates<'f, K: SubstateKeyContent + 'static, S: CommitableSubstateStore, E>(
         &mut self,
         substate_io: &'f mut SubstateIO<S>,
@@ -1292,6 +1330,7 @@ impl<C, L: Clone> CallFrame<C, L> {
         substate_io: &'f mut SubstateIO<S>,
         node_id: &NodeId,
         partition_num: PartitionNumber,
+        from_sort_prefix: Option<[u8; 2]>,
         count: u32,
         handler: &mut impl CallFrameIOAccessHandler<C, L, E>,
     ) -> Result<
@@ -1311,8 +1350,14 @@ impl<C, L: Clone> CallFrame<C, L> {
             phantom: PhantomData::default(),
         };
 
-        let substates =
-            substate_io.scan_sorted(device, node_id, partition_num, count, &mut adapter)?;
+        let substates = substate_io.scan_sorted(
+            device,
+            node_id,
+            partition_num,
+            from_sort_prefix,
+            count,
+            &mut adapter,
+        )?;
 
         for (key, substate) in &substates {
             self.process_output_substate_key(&SubstateKey::Sorted(key.clone()))
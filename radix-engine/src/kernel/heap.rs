@@ -28,6 +28,10 @@ impl Heap {
         self.nodes.is_empty()
     }
 
+    pub fn contains_node(&self, node_id: &NodeId) -> bool {
+        self.nodes.contains_key(node_id)
+    }
+
     pub fn remove_partition<E, F: FnMut(&Heap, IOAccess) -> Result<(), E>>(
         &mut self,
         node_id: &NodeId,
@@ -180,6 +184,60 @@ impl Heap {
         }
     }
 
+    /// Scans the keys of a node's partition in their sort order, starting after the given key.
+    /// On an non-existing node/partition, this will return an empty vector
+    pub fn scan_keys_after(
+        &self,
+        node_id: &NodeId,
+        partition_num: PartitionNumber,
+        after: Option<&SubstateKey>,
+        count: u32,
+    ) -> Vec<SubstateKey> {
+        let node_substates = self.nodes.get(node_id).and_then(|n| n.get(&partition_num));
+        if let Some(substates) = node_substates {
+            let mut substate_keys: Vec<SubstateKey> = substates
+                .keys()
+                .filter(|key| after.map(|after| *key > after).unwrap_or(true))
+                .cloned()
+                .collect();
+            substate_keys.sort();
+            substate_keys.truncate(count.try_into().unwrap());
+
+            substate_keys
+        } else {
+            vec![]
+        }
+    }
+
+    /// Scans the substates of a node's sorted partition in their sort order, starting from the
+    /// given sort prefix. On an non-existing node/partition, this will return an empty vector
+    pub fn scan_sorted(
+        &self,
+        node_id: &NodeId,
+        partition_num: PartitionNumber,
+        from_sort_prefix: Option<[u8; 2]>,
+        count: u32,
+    ) -> Vec<(SortedKey, IndexedScryptoValue)> {
+        let node_substates = self.nodes.get(node_id).and_then(|n| n.get(&partition_num));
+        if let Some(substates) = node_substates {
+            substates
+                .iter()
+                .filter_map(|(key, value)| match key {
+                    SubstateKey::Sorted(sorted_key) => Some((sorted_key.clone(), value.clone())),
+                    _ => None,
+                })
+                .filter(|(sorted_key, _)| {
+                    from_sort_prefix
+                        .map(|from_sort_prefix| sorted_key.0 >= from_sort_prefix)
+                        .unwrap_or(true)
+                })
+                .take(count.try_into().unwrap())
+                .collect()
+        } else {
+            vec![]
+        }
+    }
+
     /// Drains the substates from a node's partition. On an non-existing node/partition, this
     /// will return an empty vector
     pub fn drain_substates<E, F: FnMut(&Heap, IOAccess) -> Result<(), E>>(
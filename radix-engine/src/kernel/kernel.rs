@@ -73,6 +73,21 @@ impl<'g, M: KernelCallbackObject, S: CommitableSubstateStore> KernelBoot<'g, M,
 
         M::on_init(&mut kernel)?;
 
+        // The type info of every non-virtual reference gets read below, so load them all at once
+        kernel.substate_io.store.prefetch_substates(
+            references
+                .iter()
+                .filter(|reference| !reference.0.is_global_virtual())
+                .map(|reference| {
+                    (
+                        reference.0,
+                        TYPE_INFO_FIELD_PARTITION,
+                        TypeInfoField::TypeInfo.into(),
+                    )
+                })
+                .collect(),
+        );
+
         // Reference management
         for reference in references.iter() {
             let node_id = &reference.0;
@@ -458,6 +473,11 @@ where
         let mut read_only = as_read_only!(self);
         read_only.kernel_read_proof(proof_id)
     }
+
+    fn kernel_read_auth_zone(&mut self, auth_zone_id: &NodeId) -> Option<AuthZone> {
+        let mut read_only = as_read_only!(self);
+        read_only.kernel_read_auth_zone(auth_zone_id)
+    }
 }
 
 struct KernelReadOnly<'g, M>
@@ -644,6 +664,37 @@ where
             })
         }
     }
+
+    fn kernel_read_auth_zone(&mut self, auth_zone_id: &NodeId) -> Option<AuthZone> {
+        let substate = self.heap.get_substate(
+            auth_zone_id,
+            TYPE_INFO_FIELD_PARTITION,
+            &TypeInfoField::TypeInfo.into(),
+        )?;
+        let type_info: TypeInfoSubstate = substate.as_typed().unwrap();
+        match type_info {
+            TypeInfoSubstate::Object(ObjectInfo {
+                blueprint_info: BlueprintInfo { blueprint_id, .. },
+                ..
+            }) if blueprint_id.package_address == RESOURCE_PACKAGE
+                && blueprint_id.blueprint_name == AUTH_ZONE_BLUEPRINT => {}
+            _ => {
+                return None;
+            }
+        }
+
+        let substate = self
+            .heap
+            .get_substate(
+                auth_zone_id,
+                MAIN_BASE_PARTITION,
+                &AuthZoneField::AuthZone.into(),
+            )
+            .unwrap();
+        let auth_zone: FieldSubstate<AuthZone> = substate.as_typed().unwrap();
+
+        Some(auth_zone.into_payload())
+    }
 }
 
 impl<'g, M, S> KernelSubstateApi<M::LockData> for Kernel<'g, M, S>
@@ -778,6 +829,19 @@ where
         Ok(lock_handle)
     }
 
+    #[trace_resources]
+    fn kernel_prefetch_substates(
+        &mut self,
+        substates: Vec<(NodeId, PartitionNumber, SubstateKey)>,
+    ) -> Result<(), RuntimeError> {
+        let substates = substates
+            .into_iter()
+            .filter(|(node_id, ..)| !self.substate_io.heap.contains_node(node_id))
+            .collect();
+        self.substate_io.store.prefetch_substates(substates);
+        Ok(())
+    }
+
     #[trace_resources]
     fn kernel_get_lock_data(
         &mut self,
@@ -993,6 +1057,45 @@ where
         Ok(keys)
     }
 
+    #[trace_resources]
+    fn kernel_scan_keys_after<K: SubstateKeyContent + 'static>(
+        &mut self,
+        node_id: &NodeId,
+        partition_num: PartitionNumber,
+        after: Option<&SubstateKey>,
+        limit: u32,
+    ) -> Result<Vec<SubstateKey>, RuntimeError> {
+        self.callback.on_scan_keys(ScanKeysEvent::Start)?;
+
+        let mut handler = KernelHandler {
+            callback: self.callback,
+            prev_frame: self.prev_frame_stack.last(),
+            on_io_access: |api, io_access| {
+                api.callback
+                    .on_scan_keys(ScanKeysEvent::IOAccess(&io_access))
+            },
+        };
+
+        let keys = self
+            .current_frame
+            .scan_keys_after::<K, _, _>(
+                &mut self.substate_io,
+                node_id,
+                partition_num,
+                after,
+                limit,
+                &mut handler,
+            )
+            .map_err(|e| match e {
+                CallbackError::Error(e) => RuntimeError::KernelError(KernelError::CallFrameError(
+                    CallFrameError::ScanSubstatesError(e),
+                )),
+                CallbackError::CallbackError(e) => e,
+            })?;
+
+        Ok(keys)
+    }
+
     #[trace_resources(log=limit)]
     fn kernel_drain_substates<K: SubstateKeyContent + 'static>(
         &mut self,
@@ -1036,6 +1139,7 @@ where
         &mut self,
         node_id: &NodeId,
         partition_num: PartitionNumber,
+        from_sort_prefix: Option<[u8; 2]>,
         limit: u32,
     ) -> Result<Vec<(SortedKey, IndexedScryptoValue)>, RuntimeError> {
         self.callback
@@ -1056,6 +1160,7 @@ where
                 &mut self.substate_io,
                 node_id,
                 partition_num,
+                from_sort_prefix,
                 limit,
                 &mut handler,
             )
@@ -1,4 +1,5 @@
 use super::call_frame::*;
+use crate::blueprints::resource::AuthZone;
 use crate::errors::*;
 use crate::kernel::kernel_callback_api::*;
 use crate::system::system_modules::execution_trace::*;
@@ -82,6 +83,16 @@ pub trait KernelSubstateApi<L> {
         )
     }
 
+    /// Declares substates which are about to be read, so that the store can load them from the
+    /// database in a single batch.
+    ///
+    /// This is only a hint, which doesn't change the behavior of later reads (other than
+    /// their database accesses having already happened); heap substates are ignored.
+    fn kernel_prefetch_substates(
+        &mut self,
+        substates: Vec<(NodeId, PartitionNumber, SubstateKey)>,
+    ) -> Result<(), RuntimeError>;
+
     /// Retrieves info related to a lock
     fn kernel_get_lock_data(&mut self, lock_handle: SubstateHandle) -> Result<L, RuntimeError>;
 
@@ -125,7 +136,8 @@ pub trait KernelSubstateApi<L> {
         substate_key: &SubstateKey,
     ) -> Result<Option<IndexedScryptoValue>, RuntimeError>;
 
-    /// Reads substates under a node in sorted lexicographical order
+    /// Reads substates under a node in sorted lexicographical order, optionally starting from
+    /// the first substate with the given (or a greater) sort prefix
     ///
     /// Clients must ensure that this isn't used in conjunction with virtualized
     /// substates; otherwise, the behavior is undefined
@@ -133,6 +145,7 @@ pub trait KernelSubstateApi<L> {
         &mut self,
         node_id: &NodeId,
         partition_num: PartitionNumber,
+        from_sort_prefix: Option<[u8; 2]>,
         count: u32,
     ) -> Result<Vec<(SortedKey, IndexedScryptoValue)>, RuntimeError>;
 
@@ -143,6 +156,15 @@ pub trait KernelSubstateApi<L> {
         count: u32,
     ) -> Result<Vec<SubstateKey>, RuntimeError>;
 
+    /// Scans the keys of a partition in a stable order, starting after the given key
+    fn kernel_scan_keys_after<K: SubstateKeyContent + 'static>(
+        &mut self,
+        node_id: &NodeId,
+        partition_num: PartitionNumber,
+        after: Option<&SubstateKey>,
+        count: u32,
+    ) -> Result<Vec<SubstateKey>, RuntimeError>;
+
     fn kernel_drain_substates<K: SubstateKeyContent + 'static>(
         &mut self,
         node_id: &NodeId,
@@ -197,6 +219,9 @@ pub trait KernelInternalApi<M: KernelCallbackObject> {
     /* Super unstable interface, specifically for `ExecutionTrace` kernel module */
     fn kernel_read_bucket(&mut self, bucket_id: &NodeId) -> Option<BucketSnapshot>;
     fn kernel_read_proof(&mut self, proof_id: &NodeId) -> Option<ProofSnapshot>;
+
+    /* Super unstable interface, specifically for the auth trace of `AuthModule` */
+    fn kernel_read_auth_zone(&mut self, auth_zone_id: &NodeId) -> Option<AuthZone>;
 }
 
 pub trait KernelApi<M: KernelCallbackObject>:
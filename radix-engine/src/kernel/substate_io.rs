@@ -606,6 +606,34 @@ impl<'g, S: CommitableSubstateStore + 'g> SubstateIO<'g, S> {
         Ok(keys)
     }
 
+    pub fn scan_keys_after<K: SubstateKeyContent + 'static, E>(
+        &mut self,
+        device: SubstateDevice,
+        node_id: &NodeId,
+        partition_num: PartitionNumber,
+        after: Option<&SubstateKey>,
+        count: u32,
+        handler: &mut impl IOAccessHandler<E>,
+    ) -> Result<Vec<SubstateKey>, CallbackError<CallFrameScanKeysError, E>> {
+        let keys = match device {
+            SubstateDevice::Heap => self
+                .heap
+                .scan_keys_after(node_id, partition_num, after, count),
+            SubstateDevice::Store => self
+                .store
+                .scan_keys_after::<K, E, _>(
+                    node_id,
+                    partition_num,
+                    after,
+                    count,
+                    &mut |io_access| handler.on_io_access(&self.heap, io_access),
+                )
+                .map_err(|e| CallbackError::CallbackError(e))?,
+        };
+
+        Ok(keys)
+    }
+
     pub fn drain_substates<K: SubstateKeyContent + 'static, E>(
         &mut self,
         device: SubstateDevice,
@@ -645,6 +673,7 @@ impl<'g, S: CommitableSubstateStore + 'g> SubstateIO<'g, S> {
         device: SubstateDevice,
         node_id: &NodeId,
         partition_num: PartitionNumber,
+        from_sort_prefix: Option<[u8; 2]>,
         count: u32,
         handler: &mut impl IOAccessHandler<E>,
     ) -> Result<
@@ -653,15 +682,18 @@ impl<'g, S: CommitableSubstateStore + 'g> SubstateIO<'g, S> {
     > {
         let substates = match device {
             SubstateDevice::Heap => {
-                // This should never be triggered because sorted index store is
-                // used by consensus manager only.
-                panic!("Unexpected code path")
+                self.heap
+                    .scan_sorted(node_id, partition_num, from_sort_prefix, count)
             }
             SubstateDevice::Store => self
                 .store
-                .scan_sorted_substates(node_id, partition_num, count, &mut |io_access| {
-                    handler.on_io_access(&self.heap, io_access)
-                })
+                .scan_sorted_substates(
+                    node_id,
+                    partition_num,
+                    from_sort_prefix,
+                    count,
+                    &mut |io_access| handler.on_io_access(&self.heap, io_access),
+                )
                 .map_err(|e| CallbackError::CallbackError(e))?,
         };
 
@@ -28,6 +28,9 @@ pub mod types;
 
 pub mod errors;
 
+/// Engine metrics, reported to a pluggable sink.
+pub mod metrics;
+
 pub mod utils;
 pub mod vm;
 
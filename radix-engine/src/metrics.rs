@@ -0,0 +1,175 @@
+use crate::types::*;
+use sbor::rust::fmt;
+use sbor::rust::sync::Arc;
+
+/// A count of engine events, accumulated over the execution of a transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum EngineCounter {
+    /// Substates read by the kernel, from the heap or the track
+    SubstatesRead,
+    /// Substates written by the kernel, including the ones set without being opened
+    SubstatesWritten,
+    /// Substates the track had to load from the database, as they weren't tracked yet
+    DatabaseReads,
+    /// Invocations of functions and methods, including the ones of native blueprints
+    Invocations,
+    /// Invocations executed by the Scrypto VM
+    WasmInvocations,
+    /// Invocations executed by the native VM
+    NativeInvocations,
+    /// Scrypto VM invocations whose compiled WASM module was cached by the WASM engine
+    WasmModuleCacheHits,
+    /// Scrypto VM invocations whose WASM module had to be compiled
+    WasmModuleCacheMisses,
+}
+
+/// A per-transaction value, of which engine metrics sinks record the distribution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum EngineHistogram {
+    /// The execution cost units consumed by a transaction
+    ExecutionCostUnits,
+    /// The finalization cost units consumed by a transaction
+    FinalizationCostUnits,
+}
+
+/// A sink of engine metrics, e.g. exporting them in the Prometheus format.
+///
+/// The counters of a transaction are accumulated by the engine and reported once the transaction
+/// is executed, so that the sink isn't called from the hot paths of the kernel.
+pub trait EngineMetrics: Send + Sync {
+    fn increment_counter(&self, counter: EngineCounter, value: u64);
+
+    fn observe_histogram(&self, histogram: EngineHistogram, value: u64);
+}
+
+/// The default sink, which discards all the metrics.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoOpMetrics;
+
+impl EngineMetrics for NoOpMetrics {
+    fn increment_counter(&self, _counter: EngineCounter, _value: u64) {}
+
+    fn observe_histogram(&self, _histogram: EngineHistogram, _value: u64) {}
+}
+
+/// A shared [`EngineMetrics`] sink, as set on an
+/// [`ExecutionConfig`](crate::transaction::ExecutionConfig).
+#[derive(Clone)]
+pub struct MetricsSink(Arc<dyn EngineMetrics>);
+
+impl MetricsSink {
+    pub fn new(metrics: Arc<dyn EngineMetrics>) -> Self {
+        Self(metrics)
+    }
+
+    /// Reports the counters accumulated over a transaction, along with the cost units it
+    /// consumed.
+    pub fn report_transaction(
+        &self,
+        counters: &BTreeMap<EngineCounter, u64>,
+        execution_cost_units: u32,
+        finalization_cost_units: u32,
+    ) {
+        for (counter, value) in counters {
+            self.0.increment_counter(*counter, *value);
+        }
+        self.0.observe_histogram(
+            EngineHistogram::ExecutionCostUnits,
+            execution_cost_units.into(),
+        );
+        self.0.observe_histogram(
+            EngineHistogram::FinalizationCostUnits,
+            finalization_cost_units.into(),
+        );
+    }
+}
+
+impl Default for MetricsSink {
+    fn default() -> Self {
+        Self(Arc::new(NoOpMetrics))
+    }
+}
+
+impl fmt::Debug for MetricsSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MetricsSink").finish()
+    }
+}
+
+/// A sink which keeps the metrics in memory - summing up the counters and collecting the
+/// histogram observations - e.g. to assert on them in tests.
+#[cfg(feature = "std")]
+#[derive(Debug, Default)]
+pub struct InMemoryMetrics {
+    counters: std::sync::Mutex<BTreeMap<EngineCounter, u64>>,
+    histograms: std::sync::Mutex<BTreeMap<EngineHistogram, Vec<u64>>>,
+}
+
+#[cfg(feature = "std")]
+impl InMemoryMetrics {
+    pub fn counter(&self, counter: EngineCounter) -> u64 {
+        self.counters
+            .lock()
+            .unwrap()
+            .get(&counter)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn histogram(&self, histogram: EngineHistogram) -> Vec<u64> {
+        self.histograms
+            .lock()
+            .unwrap()
+            .get(&histogram)
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(feature = "std")]
+impl EngineMetrics for InMemoryMetrics {
+    fn increment_counter(&self, counter: EngineCounter, value: u64) {
+        let mut counters = self.counters.lock().unwrap();
+        let total = counters.entry(counter).or_default();
+        *total = total.saturating_add(value);
+    }
+
+    fn observe_histogram(&self, histogram: EngineHistogram, value: u64) {
+        self.histograms
+            .lock()
+            .unwrap()
+            .entry(histogram)
+            .or_default()
+            .push(value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_in_memory_metrics_accumulate_reported_transactions() {
+        let metrics = Arc::new(InMemoryMetrics::default());
+        let sink = MetricsSink::new(metrics.clone());
+        let counters = btreemap!(
+            EngineCounter::SubstatesRead => 5,
+            EngineCounter::WasmInvocations => 1
+        );
+
+        sink.report_transaction(&counters, 100, 10);
+        sink.report_transaction(&counters, 200, 20);
+
+        assert_eq!(metrics.counter(EngineCounter::SubstatesRead), 10);
+        assert_eq!(metrics.counter(EngineCounter::WasmInvocations), 2);
+        assert_eq!(metrics.counter(EngineCounter::DatabaseReads), 0);
+        assert_eq!(
+            metrics.histogram(EngineHistogram::ExecutionCostUnits),
+            vec![100, 200]
+        );
+        assert_eq!(
+            metrics.histogram(EngineHistogram::FinalizationCostUnits),
+            vec![10, 20]
+        );
+    }
+}
@@ -327,9 +327,9 @@ impl MetadataNativePackage {
     where
         Y: ClientApi<RuntimeError>,
     {
-        for value in metadata_init.data.values() {
+        for (key, value) in metadata_init.data.iter() {
             if let Some(v) = &value.value {
-                validate_metadata_value(&v).map_err(|e| {
+                validate_metadata_value(key, &v).map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::MetadataError(
                         MetadataError::MetadataValidationError(e),
                     ))
@@ -355,7 +355,7 @@ impl MetadataNativePackage {
     where
         Y: ClientApi<RuntimeError>,
     {
-        validate_metadata_value(&value).map_err(|e| {
+        validate_metadata_value(&key, &value).map_err(|e| {
             RuntimeError::ApplicationError(ApplicationError::MetadataError(
                 MetadataError::MetadataValidationError(e),
             ))
@@ -451,9 +451,23 @@ impl MetadataNativePackage {
 pub enum MetadataValidationError {
     InvalidURL(String),
     InvalidOrigin(String),
+    InvalidStandardValue {
+        key: String,
+        error: MetadataConversionError,
+    },
 }
 
-pub fn validate_metadata_value(value: &MetadataValue) -> Result<(), MetadataValidationError> {
+pub fn validate_metadata_value(
+    key: &str,
+    value: &MetadataValue,
+) -> Result<(), MetadataValidationError> {
+    check_metadata_standard(key, value).map_err(|error| {
+        MetadataValidationError::InvalidStandardValue {
+            key: key.to_owned(),
+            error,
+        }
+    })?;
+
     match value {
         MetadataValue::String(_) => {}
         MetadataValue::Bool(_) => {}
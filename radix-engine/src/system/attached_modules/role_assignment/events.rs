@@ -7,6 +7,11 @@ pub struct SetRoleEvent {
     pub rule: AccessRule,
 }
 
+#[derive(ScryptoSbor, ScryptoEvent, Debug)]
+pub struct SetRolesEvent {
+    pub roles: IndexMap<RoleKey, AccessRule>,
+}
+
 #[derive(ScryptoSbor, ScryptoEvent, Debug)]
 pub struct SetOwnerRoleEvent {
     pub rule: AccessRule,
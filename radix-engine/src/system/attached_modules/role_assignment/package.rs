@@ -36,6 +36,7 @@ pub enum RoleAssignmentError {
     ExceededMaxRoleNameLen { limit: usize, actual: usize },
     ExceededMaxAccessRuleDepth,
     ExceededMaxAccessRuleNodes,
+    InvalidWeightedThreshold { threshold: u32, total_weight: u64 },
     InvalidName(InvalidNameError),
     ExceededMaxRoles,
     CannotSetRoleIfNotAttached,
@@ -102,6 +103,19 @@ impl RoleAssignmentNativePackage {
                 export: ROLE_ASSIGNMENT_SET_IDENT.to_string(),
             },
         );
+        functions.insert(
+            ROLE_ASSIGNMENT_SET_BATCH_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref_mut()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<RoleAssignmentSetBatchInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<RoleAssignmentSetBatchOutput>(),
+                ),
+                export: ROLE_ASSIGNMENT_SET_BATCH_IDENT.to_string(),
+            },
+        );
         functions.insert(
             ROLE_ASSIGNMENT_GET_IDENT.to_string(),
             FunctionSchemaInit {
@@ -115,12 +129,26 @@ impl RoleAssignmentNativePackage {
                 export: ROLE_ASSIGNMENT_GET_IDENT.to_string(),
             },
         );
+        functions.insert(
+            ROLE_ASSIGNMENT_GET_OWNER_ROLE_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: Some(ReceiverInfo::normal_ref()),
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<RoleAssignmentGetOwnerRoleInput>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<RoleAssignmentGetOwnerRoleOutput>(),
+                ),
+                export: ROLE_ASSIGNMENT_GET_OWNER_ROLE_IDENT.to_string(),
+            },
+        );
 
         let events = event_schema! {
             aggregator,
             [
                 SetOwnerRoleEvent,
                 SetRoleEvent,
+                SetRolesEvent,
                 LockOwnerRoleEvent
             ]
         };
@@ -179,6 +207,39 @@ impl RoleAssignmentNativePackage {
                     module_id: input.module,
                 }
             }
+            ROLE_ASSIGNMENT_SET_BATCH_IDENT => {
+                let input: RoleAssignmentSetBatchInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+
+                // The batch is authorized by a single check against the updater roles common to
+                // every role being set, as each of those alone is allowed to update them all.
+                let mut role_list: Option<RoleList> = None;
+                for role_key in input.roles.keys() {
+                    let updaters = Self::resolve_update_role_method_permission(
+                        global_address.as_node_id(),
+                        input.module,
+                        role_key,
+                        api,
+                    )?;
+                    role_list = Some(match role_list {
+                        None => updaters,
+                        Some(common) => RoleList {
+                            list: common
+                                .list
+                                .into_iter()
+                                .filter(|updater| updaters.list.contains(updater))
+                                .collect(),
+                        },
+                    });
+                }
+
+                ResolvedPermission::RoleList {
+                    role_assignment_of: global_address.clone(),
+                    role_list: role_list.unwrap_or_else(RoleList::none),
+                    module_id: input.module,
+                }
+            }
             ROLE_ASSIGNMENT_SET_OWNER_IDENT => {
                 Self::resolve_update_owner_role_method_permission(global_address.as_node_id(), api)?
             }
@@ -186,6 +247,7 @@ impl RoleAssignmentNativePackage {
                 Self::resolve_update_owner_role_method_permission(global_address.as_node_id(), api)?
             }
             ROLE_ASSIGNMENT_GET_IDENT => ResolvedPermission::AllowAll,
+            ROLE_ASSIGNMENT_GET_OWNER_ROLE_IDENT => ResolvedPermission::AllowAll,
             _ => {
                 return Err(RuntimeError::SystemModuleError(
                     SystemModuleError::AuthError(AuthError::NoMethodMapping(FnIdentifier {
@@ -243,6 +305,14 @@ impl RoleAssignmentNativePackage {
                 let rtn = Self::set_role(input.module, input.role_key, input.rule, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            ROLE_ASSIGNMENT_SET_BATCH_IDENT => {
+                let input: RoleAssignmentSetBatchInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+
+                let rtn = Self::set_roles(input.module, input.roles, api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             ROLE_ASSIGNMENT_GET_IDENT => {
                 let input: RoleAssignmentGetInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
@@ -251,6 +321,14 @@ impl RoleAssignmentNativePackage {
                 let rtn = Self::get_role(input.module, input.role_key, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
+            ROLE_ASSIGNMENT_GET_OWNER_ROLE_IDENT => {
+                let _input: RoleAssignmentGetOwnerRoleInput = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
+
+                let rtn = Self::get_owner_role(api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
             _ => Err(RuntimeError::ApplicationError(
                 ApplicationError::ExportDoesNotExist(export_name.to_string()),
             )),
@@ -265,7 +343,7 @@ impl RoleAssignmentNativePackage {
         pub struct AccessRuleVerifier(usize);
         impl AccessRuleVisitor for AccessRuleVerifier {
             type Error = RoleAssignmentError;
-            fn visit(&mut self, _node: &AccessRuleNode, depth: usize) -> Result<(), Self::Error> {
+            fn visit(&mut self, node: &AccessRuleNode, depth: usize) -> Result<(), Self::Error> {
                 // This is to protect unbounded native stack useage during authorization
                 if depth > MAX_ACCESS_RULE_DEPTH {
                     return Err(RoleAssignmentError::ExceededMaxAccessRuleDepth);
@@ -277,6 +355,18 @@ impl RoleAssignmentNativePackage {
                     return Err(RoleAssignmentError::ExceededMaxAccessRuleNodes);
                 }
 
+                // A threshold of zero is always met, and one above the total weight never is
+                if let AccessRuleNode::WeightedThreshold { threshold, weights } = node {
+                    let total_weight: u64 =
+                        weights.iter().map(|(_, weight)| u64::from(*weight)).sum();
+                    if *threshold == 0 || u64::from(*threshold) > total_weight {
+                        return Err(RoleAssignmentError::InvalidWeightedThreshold {
+                            threshold: *threshold,
+                            total_weight,
+                        });
+                    }
+                }
+
                 Ok(())
             }
         }
@@ -502,6 +592,23 @@ impl RoleAssignmentNativePackage {
         Ok(())
     }
 
+    fn get_owner_role<Y>(api: &mut Y) -> Result<OwnerRoleEntry, RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let handle = api.actor_open_field(
+            ACTOR_STATE_SELF,
+            RoleAssignmentField::Owner.field_index(),
+            LockFlags::read_only(),
+        )?;
+        let owner_role = api
+            .field_read_typed::<RoleAssignmentOwnerFieldPayload>(handle)?
+            .into_latest();
+        api.field_close(handle)?;
+
+        Ok(owner_role.owner_role_entry)
+    }
+
     fn set_role<Y>(
         module: ModuleId,
         role_key: RoleKey,
@@ -511,12 +618,63 @@ impl RoleAssignmentNativePackage {
     where
         Y: ClientApi<RuntimeError>,
     {
+        Self::verify_role_update(module, &role_key, &rule)?;
+        Self::ensure_attached(api)?;
+        Self::write_role(module, role_key.clone(), rule.clone(), api)?;
+
+        Runtime::emit_event(api, SetRoleEvent { role_key, rule })?;
+
+        Ok(())
+    }
+
+    fn set_roles<Y>(
+        module: ModuleId,
+        roles: IndexMap<RoleKey, AccessRule>,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        if roles.len() > MAX_ROLES {
+            return Err(RuntimeError::ApplicationError(
+                ApplicationError::RoleAssignmentError(RoleAssignmentError::ExceededMaxRoles),
+            ));
+        }
+        for (role_key, rule) in &roles {
+            Self::verify_role_update(module, role_key, rule)?;
+        }
+        Self::ensure_attached(api)?;
+
+        // The base cost of `set_batch` covers a single role, each further role costs as much as
+        // setting it on its own
+        for _ in roles.iter().skip(1) {
+            api.consume_cost_units(ClientCostingEntry::RunNativeCode {
+                package_address: &ROLE_ASSIGNMENT_MODULE_PACKAGE,
+                export_name: ROLE_ASSIGNMENT_SET_IDENT,
+                input_size: 0,
+            })?;
+        }
+
+        for (role_key, rule) in &roles {
+            Self::write_role(module, role_key.clone(), rule.clone(), api)?;
+        }
+
+        Runtime::emit_event(api, SetRolesEvent { roles })?;
+
+        Ok(())
+    }
+
+    fn verify_role_update(
+        module: ModuleId,
+        role_key: &RoleKey,
+        rule: &AccessRule,
+    ) -> Result<(), RuntimeError> {
         if module.eq(&ModuleId::RoleAssignment) {
             return Err(RuntimeError::ApplicationError(
                 ApplicationError::RoleAssignmentError(RoleAssignmentError::UsedReservedSpace),
             ));
         }
-        if Self::is_reserved_role_key(&role_key) {
+        if Self::is_reserved_role_key(role_key) {
             return Err(RuntimeError::ApplicationError(
                 ApplicationError::RoleAssignmentError(RoleAssignmentError::UsedReservedRole(
                     role_key.key.to_string(),
@@ -539,15 +697,17 @@ impl RoleAssignmentNativePackage {
             ))
         })?;
 
-        let module_role_key = ModuleRoleKey::new(module, role_key.clone());
-
-        Self::verify_access_rule(&rule).map_err(|e| {
-            RuntimeError::ApplicationError(ApplicationError::RoleAssignmentError(e))
-        })?;
+        Self::verify_access_rule(rule)
+            .map_err(|e| RuntimeError::ApplicationError(ApplicationError::RoleAssignmentError(e)))
+    }
 
-        // Only allow this method to be called on attached role assignment modules.
-        // This is currently implemented to prevent unbounded number of roles from
-        // being created.
+    /// Only allow roles to be set on attached role assignment modules.
+    /// This is currently implemented to prevent unbounded number of roles from
+    /// being created.
+    fn ensure_attached<Y>(api: &mut Y) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
         api.actor_get_node_id(ACTOR_REF_GLOBAL)
             .map_err(|e| match e {
                 RuntimeError::SystemError(SystemError::GlobalAddressDoesNotExist) => {
@@ -558,6 +718,20 @@ impl RoleAssignmentNativePackage {
                 _ => e,
             })?;
 
+        Ok(())
+    }
+
+    fn write_role<Y>(
+        module: ModuleId,
+        role_key: RoleKey,
+        rule: AccessRule,
+        api: &mut Y,
+    ) -> Result<(), RuntimeError>
+    where
+        Y: ClientApi<RuntimeError>,
+    {
+        let module_role_key = ModuleRoleKey::new(module, role_key);
+
         let handle = api.actor_open_key_value_entry(
             ACTOR_STATE_SELF,
             RoleAssignmentCollection::AccessRuleKeyValue.collection_index(),
@@ -568,12 +742,10 @@ impl RoleAssignmentNativePackage {
         // Overwrite whatever access rule (or empty) is there
         api.key_value_entry_set_typed(
             handle,
-            RoleAssignmentAccessRuleEntryPayload::from_content_source(rule.clone()),
+            RoleAssignmentAccessRuleEntryPayload::from_content_source(rule),
         )?;
         api.key_value_entry_close(handle)?;
 
-        Runtime::emit_event(api, SetRoleEvent { role_key, rule })?;
-
         Ok(())
     }
 
@@ -37,6 +37,13 @@ declare_native_blueprint_state! {
             },
             condition: Condition::Always,
         },
+        transaction_limits: {
+            ident: TransactionLimits,
+            field_type: {
+                kind: StaticSingleVersioned,
+            },
+            condition: Condition::Always,
+        },
     },
     collections: {
         method_royalties: KeyValue {
@@ -54,6 +61,7 @@ declare_native_blueprint_state! {
 }
 
 pub type ComponentRoyaltyAccumulatorV1 = ComponentRoyaltySubstate;
+pub type ComponentRoyaltyTransactionLimitsV1 = ComponentRoyaltyTransactionLimitsSubstate;
 pub type ComponentRoyaltyMethodAmountV1 = RoyaltyAmount;
 
 pub struct RoyaltyNativePackage;
@@ -77,6 +85,19 @@ impl RoyaltyNativePackage {
                 export: COMPONENT_ROYALTY_CREATE_IDENT.to_string(),
             },
         );
+        functions.insert(
+            COMPONENT_ROYALTY_CREATE_V2_IDENT.to_string(),
+            FunctionSchemaInit {
+                receiver: None,
+                input: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<ComponentRoyaltyCreateV2Input>(),
+                ),
+                output: TypeRef::Static(
+                    aggregator.add_child_type_and_descendents::<ComponentRoyaltyCreateV2Output>(),
+                ),
+                export: COMPONENT_ROYALTY_CREATE_V2_IDENT.to_string(),
+            },
+        );
         functions.insert(
             COMPONENT_ROYALTY_SET_ROYALTY_IDENT.to_string(),
             FunctionSchemaInit {
@@ -178,6 +199,13 @@ impl RoyaltyNativePackage {
                 let input: ComponentRoyaltyCreateInput = input.as_typed().map_err(|e| {
                     RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
                 })?;
+                let rtn = ComponentRoyaltyBlueprint::create(input.royalty_config.into(), api)?;
+                Ok(IndexedScryptoValue::from_typed(&rtn))
+            }
+            COMPONENT_ROYALTY_CREATE_V2_IDENT => {
+                let input: ComponentRoyaltyCreateV2Input = input.as_typed().map_err(|e| {
+                    RuntimeError::ApplicationError(ApplicationError::InputDecodeError(e))
+                })?;
                 let rtn = ComponentRoyaltyBlueprint::create(input.royalty_config, api)?;
                 Ok(IndexedScryptoValue::from_typed(&rtn))
             }
@@ -320,7 +348,7 @@ pub struct ComponentRoyaltyBlueprint;
 
 impl ComponentRoyaltyBlueprint {
     pub(crate) fn create<Y>(
-        royalty_config: ComponentRoyaltyConfig,
+        royalty_config: ComponentRoyaltyConfigV2,
         api: &mut Y,
     ) -> Result<Own, RuntimeError>
     where
@@ -361,12 +389,29 @@ impl ComponentRoyaltyBlueprint {
             );
         }
 
+        if let Some(max_royalty_per_transaction) = &royalty_config.max_royalty_per_transaction {
+            if max_royalty_per_transaction.is_negative() {
+                return Err(RuntimeError::ApplicationError(
+                    ApplicationError::ComponentRoyaltyError(
+                        ComponentRoyaltyError::RoyaltyAmountIsNegative(
+                            *max_royalty_per_transaction,
+                        ),
+                    ),
+                ));
+            }
+        }
+        let transaction_limits = ComponentRoyaltyTransactionLimitsSubstate {
+            max_royalty_per_transaction: royalty_config.max_royalty_per_transaction,
+            free_calls_per_transaction: royalty_config.free_calls_per_transaction,
+        };
+
         let component_id = api.new_object(
             COMPONENT_ROYALTY_BLUEPRINT,
             vec![],
             GenericArgs::default(),
             indexmap! {
                 ComponentRoyaltyField::Accumulator.field_index() => FieldValue::immutable(&ComponentRoyaltyAccumulatorFieldPayload::from_content_source(accumulator_substate)),
+                ComponentRoyaltyField::TransactionLimits.field_index() => FieldValue::immutable(&ComponentRoyaltyTransactionLimitsFieldPayload::from_content_source(transaction_limits)),
             },
             kv_entries,
         )?;
@@ -493,16 +538,97 @@ impl ComponentRoyaltyBlueprint {
         if royalty_charge.is_non_zero() {
             let vault_id = component_royalty.royalty_vault.0;
             let component_address = ComponentAddress::new_or_panic(receiver.0);
-
-            apply_royalty_cost(
+            let recipient = RoyaltyRecipient::Component(component_address, vault_id.into());
+
+            let transaction_limits = {
+                // Components created before the limits were introduced don't have them
+                let handle = api.kernel_open_substate_with_default(
+                    receiver,
+                    ROYALTY_BASE_PARTITION
+                        .at_offset(ROYALTY_FIELDS_PARTITION_OFFSET)
+                        .unwrap(),
+                    &RoyaltyField::RoyaltyTransactionLimits.into(),
+                    LockFlags::read_only(),
+                    Some(|| {
+                        IndexedScryptoValue::from_typed(&FieldSubstate::new_locked_field(
+                            ComponentRoyaltyTransactionLimitsFieldPayload::from_content_source(
+                                ComponentRoyaltyTransactionLimitsSubstate::default(),
+                            ),
+                        ))
+                    }),
+                    SystemLockData::default(),
+                )?;
+                let substate: FieldSubstate<ComponentRoyaltyTransactionLimitsFieldPayload> =
+                    api.kernel_read_substate(handle)?.as_typed().unwrap();
+                api.kernel_close_substate(handle)?;
+                substate.into_payload().into_latest()
+            };
+
+            let royalty_charge = Self::apply_transaction_limits(
                 api,
                 royalty_charge,
-                RoyaltyRecipient::Component(component_address, vault_id.into()),
+                &recipient,
+                transaction_limits,
             )?;
+            apply_royalty_cost(api, royalty_charge, recipient)?;
         }
 
         api.kernel_close_substate(accumulator_handle)?;
 
         Ok(())
     }
+
+    /// Reduces the royalty charge of a call according to the component's transaction limits,
+    /// and counts the call towards the free calls of the transaction.
+    fn apply_transaction_limits<Y, V>(
+        api: &mut Y,
+        royalty_charge: RoyaltyAmount,
+        recipient: &RoyaltyRecipient,
+        transaction_limits: ComponentRoyaltyTransactionLimitsSubstate,
+    ) -> Result<RoyaltyAmount, RuntimeError>
+    where
+        V: SystemCallbackObject,
+        Y: KernelApi<SystemConfig<V>>,
+    {
+        let costing = &mut api.kernel_get_system().modules.costing;
+
+        let calls = costing
+            .component_royalty_calls
+            .entry(recipient.clone())
+            .or_default();
+        *calls += 1;
+        if *calls <= transaction_limits.free_calls_per_transaction {
+            return Ok(RoyaltyAmount::Free);
+        }
+
+        let max_royalty_per_transaction = match transaction_limits.max_royalty_per_transaction {
+            Some(max_royalty_per_transaction) => max_royalty_per_transaction,
+            None => return Ok(royalty_charge),
+        };
+        let usd_price = costing.fee_reserve.usd_price();
+        let to_xrd = |amount: RoyaltyAmount| match amount {
+            RoyaltyAmount::Free => Some(Decimal::ZERO),
+            RoyaltyAmount::Xrd(xrd_amount) => Some(xrd_amount),
+            RoyaltyAmount::Usd(usd_amount) => usd_amount.checked_mul(usd_price),
+        };
+        let decimal_error = || {
+            RuntimeError::ApplicationError(ApplicationError::ComponentRoyaltyError(
+                ComponentRoyaltyError::UnexpectedDecimalComputationError,
+            ))
+        };
+        let charge_in_xrd = to_xrd(royalty_charge).ok_or_else(decimal_error)?;
+        let max_in_xrd = to_xrd(max_royalty_per_transaction).ok_or_else(decimal_error)?;
+        let charged_in_xrd = costing
+            .fee_reserve
+            .royalty_cost_breakdown()
+            .get(recipient)
+            .cloned()
+            .unwrap_or_default();
+        let remaining_in_xrd = max_in_xrd
+            .checked_sub(charged_in_xrd)
+            .ok_or_else(decimal_error)?
+            .max(Decimal::ZERO);
+
+        Ok(RoyaltyAmount::Xrd(charge_in_xrd.min(remaining_in_xrd)))
+    }
 }
@@ -1,7 +1,10 @@
 use crate::blueprints::access_controller::*;
 use crate::blueprints::account::{AccountNativePackage, AccountOwnerBadgeData};
-use crate::blueprints::consensus_manager::ConsensusManagerNativePackage;
+use crate::blueprints::consensus_manager::{
+    ConsensusManagerConfigurationFieldPayload, ConsensusManagerField, ConsensusManagerNativePackage,
+};
 use crate::blueprints::identity::{IdentityNativePackage, IdentityOwnerBadgeData};
+use crate::blueprints::locker::LockerNativePackage;
 use crate::blueprints::package::{
     create_bootstrap_package_partitions, PackageCollection, PackageNativePackage,
     PackageOwnerBadgeData, SystemInstruction,
@@ -314,8 +317,16 @@ where
     }
 
     pub fn bootstrap_test_default(&mut self) -> Option<GenesisReceipts> {
+        self.bootstrap_test_default_with_genesis_data(vec![])
+    }
+
+    /// Like [`Self::bootstrap_test_default`], but with the given validators, stakes, resources etc.
+    pub fn bootstrap_test_default_with_genesis_data(
+        &mut self,
+        genesis_data_chunks: Vec<GenesisDataChunk>,
+    ) -> Option<GenesisReceipts> {
         self.bootstrap_with_genesis_data(
-            vec![],
+            genesis_data_chunks,
             Epoch::of(1),
             ConsensusManagerConfig {
                 max_validators: 10,
@@ -654,6 +665,38 @@ pub fn create_substate_flash_for_genesis() -> FlashReceipt {
     }
 }
 
+/// Creates the state updates which unlock the consensus manager's configuration field, so that it
+/// can be changed with `update_config`.
+///
+/// Ledgers bootstrapped before `update_config` existed have this field locked at genesis, so these
+/// updates need to be committed by the protocol update which enables the method. They are empty
+/// if the field is already unlocked.
+pub fn create_consensus_manager_config_unlock_state_updates<S: SubstateDatabase>(
+    substate_db: &S,
+) -> StateUpdates {
+    let substate_key = SubstateKey::Field(ConsensusManagerField::Configuration.field_index());
+    let config_substate: FieldSubstate<ConsensusManagerConfigurationFieldPayload> = substate_db
+        .get_mapped::<SpreadPrefixKeyMapper, _>(
+            CONSENSUS_MANAGER.as_node_id(),
+            MAIN_BASE_PARTITION,
+            &substate_key,
+        )
+        .expect("Consensus manager configuration must exist on a bootstrapped ledger");
+    if let LockStatus::Unlocked = config_substate.lock_status() {
+        return StateUpdates::default();
+    }
+
+    let unlocked_substate = FieldSubstate::new_unlocked_field(config_substate.into_payload());
+    StateUpdates::from(LegacyStateUpdates {
+        partition_deletions: index_set_new(),
+        system_updates: indexmap!(
+            (CONSENSUS_MANAGER.into_node_id(), MAIN_BASE_PARTITION) => indexmap!(
+                substate_key => DatabaseUpdate::Set(scrypto_encode(&unlocked_substate).unwrap())
+            )
+        ),
+    })
+}
+
 /// A [`SubstateDatabase`] implementation holding only the initial [`DatabaseUpdates`] from a system
 /// bootstrap flash.
 struct FlashedSubstateDatabase {
@@ -1069,6 +1112,28 @@ pub fn create_system_bootstrap_transaction(
         });
     }
 
+    // Locker Package
+    {
+        pre_allocated_addresses.push((
+            BlueprintId::new(&PACKAGE_PACKAGE, PACKAGE_BLUEPRINT),
+            GlobalAddress::from(LOCKER_PACKAGE),
+        ));
+        instructions.push(InstructionV1::CallFunction {
+            package_address: PACKAGE_PACKAGE.into(),
+            blueprint_name: PACKAGE_BLUEPRINT.to_string(),
+            function_name: PACKAGE_PUBLISH_NATIVE_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&PackagePublishNativeManifestInput {
+                package_address: Some(id_allocator.new_address_reservation_id()),
+                definition: LockerNativePackage::definition(),
+                metadata: metadata_init! {
+                    "name" => "Locker Package".to_owned(), locked;
+                    "description" => "A native package that defines the logic of account locker components, which hold resources for accounts to claim.".to_owned(), locked;
+                },
+                native_package_code_id: LOCKER_CODE_ID,
+            }),
+        });
+    }
+
     // ECDSA Secp256k1
     {
         pre_allocated_addresses.push((
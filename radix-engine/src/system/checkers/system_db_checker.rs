@@ -83,6 +83,8 @@ pub enum SystemPartitionCheckError {
     MissingKeyValueStoreValueSchema(SystemReaderError),
     InvalidKeyValueStoreKey,
     InvalidKeyValueStoreValue,
+    InvalidIndexStoreKey,
+    InvalidIndexStoreValue,
     InvalidFieldKey,
     ContainsFieldWhichShouldNotExist(BlueprintId, NodeId, u8),
     InvalidFieldValue,
@@ -433,10 +435,12 @@ impl<A: ApplicationChecker> SystemDatabaseChecker<A> {
                     },
                 }
             }
-            TypeInfoSubstate::KeyValueStore(..) => SystemNodeCheckerState {
-                node_id: *node_id,
-                node_type: SystemNodeType::KeyValueStore,
-            },
+            TypeInfoSubstate::KeyValueStore(..) | TypeInfoSubstate::IndexStore(..) => {
+                SystemNodeCheckerState {
+                    node_id: *node_id,
+                    node_type: SystemNodeType::KeyValueStore,
+                }
+            }
             TypeInfoSubstate::GlobalAddressPhantom(..) => {
                 return Err(SystemNodeCheckError::FoundGlobalAddressPhantom);
             }
@@ -569,6 +573,55 @@ impl<A: ApplicationChecker> SystemDatabaseChecker<A> {
                         substate_count += 1;
                     }
                 }
+                SystemPartitionDescriptor::IndexStore => {
+                    let type_target = reader
+                        .get_kv_store_type_target(&node_checker_state.node_id)
+                        .map_err(SystemPartitionCheckError::MissingKeyValueStoreTarget)?;
+                    let key_schema = reader
+                        .get_kv_store_payload_schema(&type_target, KeyOrValue::Key)
+                        .map_err(SystemPartitionCheckError::MissingKeyValueStoreKeySchema)?;
+                    let value_schema = reader
+                        .get_kv_store_payload_schema(&type_target, KeyOrValue::Value)
+                        .map_err(SystemPartitionCheckError::MissingKeyValueStoreValueSchema)?;
+
+                    for (key, value) in reader
+                        .substates_iter::<SortedKey>(&node_checker_state.node_id, partition_number)
+                    {
+                        // Key Check
+                        {
+                            let sorted_key = match key {
+                                SubstateKey::Sorted(sorted_key) => sorted_key,
+                                _ => return Err(SystemPartitionCheckError::InvalidIndexStoreKey),
+                            };
+                            reader
+                                .validate_payload(
+                                    &sorted_key.1,
+                                    &key_schema,
+                                    KEY_VALUE_STORE_PAYLOAD_MAX_DEPTH,
+                                )
+                                .map_err(|_| SystemPartitionCheckError::InvalidIndexStoreKey)?;
+                        }
+
+                        // Value Check
+                        {
+                            let entry: SortedIndexEntrySubstate<ScryptoValue> =
+                                scrypto_decode(&value).map_err(|_| {
+                                    SystemPartitionCheckError::InvalidIndexStoreValue
+                                })?;
+                            let entry_payload = scrypto_encode(entry.value())
+                                .map_err(|_| SystemPartitionCheckError::InvalidIndexStoreValue)?;
+                            reader
+                                .validate_payload(
+                                    &entry_payload,
+                                    &value_schema,
+                                    KEY_VALUE_STORE_PAYLOAD_MAX_DEPTH,
+                                )
+                                .map_err(|_| SystemPartitionCheckError::InvalidIndexStoreValue)?;
+                        }
+
+                        substate_count += 1;
+                    }
+                }
                 SystemPartitionDescriptor::Object(module_id, object_partition_descriptor) => {
                     let type_target = reader
                         .get_blueprint_type_target(&node_checker_state.node_id, module_id)
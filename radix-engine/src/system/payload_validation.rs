@@ -98,6 +98,7 @@ impl<'s, 'a, Y: KernelApi<SystemConfig<V>>, V: SystemCallbackObject> ValidationC
                 blueprint: blueprint_id.blueprint_name,
             },
             TypeInfoSubstate::KeyValueStore(_) => TypeInfoForValidation::KeyValueStore,
+            TypeInfoSubstate::IndexStore(_) => TypeInfoForValidation::IndexStore,
             TypeInfoSubstate::GlobalAddressReservation(_) => {
                 TypeInfoForValidation::GlobalAddressReservation
             }
@@ -129,6 +130,7 @@ pub enum TypeInfoForValidation {
         blueprint: String,
     },
     KeyValueStore,
+    IndexStore,
     GlobalAddressReservation,
 }
 
@@ -281,7 +283,12 @@ fn apply_custom_validation_to_custom_value<E: ToString>(
                         || type_info.matches(&RESOURCE_PACKAGE, NON_FUNGIBLE_PROOF_BLUEPRINT)
                 }
                 OwnValidation::IsVault => node_id.is_internal_vault(),
-                OwnValidation::IsKeyValueStore => node_id.is_internal_kv_store(),
+                OwnValidation::IsKeyValueStore => {
+                    matches!(type_info, TypeInfoForValidation::KeyValueStore)
+                }
+                OwnValidation::IsIndexStore => {
+                    matches!(type_info, TypeInfoForValidation::IndexStore)
+                }
                 OwnValidation::IsGlobalAddressReservation => {
                     matches!(type_info, TypeInfoForValidation::GlobalAddressReservation)
                 }
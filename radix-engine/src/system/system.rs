@@ -2,6 +2,7 @@ use super::id_allocation::IDAllocation;
 use super::system_modules::costing::ExecutionCostingEntry;
 use crate::blueprints::package::PackageBlueprintVersionDefinitionEntrySubstate;
 use crate::blueprints::resource::fungible_vault::LockFeeEvent;
+use crate::blueprints::resource::AuthZone;
 use crate::errors::{
     ApplicationError, CannotGlobalizeError, CreateObjectError, InvalidDropAccess,
     InvalidGlobalizeAccess, InvalidModuleType, RuntimeError, SystemError, SystemModuleError,
@@ -11,14 +12,16 @@ use crate::internal_prelude::*;
 use crate::internal_prelude::{IndexEntrySubstate, SortedIndexEntrySubstate};
 use crate::kernel::call_frame::{NodeVisibility, ReferenceOrigin};
 use crate::kernel::kernel_api::*;
-use crate::system::actor::{Actor, FunctionActor, InstanceContext, MethodActor, MethodType};
+use crate::system::actor::{
+    Actor, BlueprintHookActor, FunctionActor, InstanceContext, MethodActor, MethodType,
+};
 use crate::system::node_init::type_info_partition;
 use crate::system::system_callback::{
     FieldLockData, KeyValueEntryLockData, SystemConfig, SystemLockData,
 };
 use crate::system::system_callback_api::SystemCallbackObject;
 use crate::system::system_modules::execution_trace::{BucketSnapshot, ProofSnapshot};
-use crate::system::system_modules::transaction_runtime::Event;
+use crate::system::system_modules::transaction_runtime::{Event, LogRecord, PanicDetails};
 use crate::system::system_modules::{EnabledModules, SystemModuleMixer};
 use crate::system::system_substates::{KeyValueEntrySubstate, LockStatus};
 use crate::system::system_type_checker::{
@@ -27,6 +30,7 @@ use crate::system::system_type_checker::{
 use crate::system::type_info::{TypeInfoBlueprint, TypeInfoSubstate};
 use crate::track::interface::NodeSubstates;
 use crate::types::*;
+use crate::utils::{keccak256_hash, verify_bls12381_v1};
 use radix_engine_interface::api::actor_api::EventFlags;
 use radix_engine_interface::api::actor_index_api::ClientActorIndexApi;
 use radix_engine_interface::api::field_api::{FieldHandle, LockFlags};
@@ -1073,6 +1077,64 @@ where
         Ok(global_address)
     }
 
+    fn add_log_record(
+        &mut self,
+        level: Level,
+        topic: Option<String>,
+        message: String,
+        payload: Option<Vec<u8>>,
+    ) -> Result<(), RuntimeError> {
+        if let Some(payload) = &payload {
+            scrypto_decode::<ScryptoValue>(payload)
+                .map_err(|e| RuntimeError::SystemError(SystemError::InvalidLogPayload(e)))?;
+        }
+
+        let actor = self.current_actor();
+        let log = LogRecord {
+            level,
+            topic,
+            message,
+            blueprint_id: actor.blueprint_id(),
+            global_address: actor
+                .instance_context()
+                .map(|instance_context| instance_context.outer_object),
+            payload,
+        };
+
+        self.api
+            .kernel_get_system()
+            .modules
+            .apply_execution_cost(ExecutionCostingEntry::EmitLog { size: log.len() })?;
+
+        self.api.kernel_get_system().modules.add_log(log)?;
+
+        Ok(())
+    }
+
+    #[cfg_attr(feature = "std", catch_unwind_ignore)]
+    /// Looks up the name of the package export the actor is executing, if any.
+    ///
+    /// Looking up the blueprint definition is costed, so any costing error is returned rather than
+    /// the export name being dropped.
+    fn get_panic_export_name(
+        &mut self,
+        actor: &Actor,
+        blueprint_id: BlueprintId,
+    ) -> Result<Option<String>, RuntimeError> {
+        let definition = self.get_blueprint_default_definition(blueprint_id)?;
+        let export = match actor {
+            Actor::Method(MethodActor { ident, .. })
+            | Actor::Function(FunctionActor { ident, .. }) => {
+                definition.function_exports.get(ident)
+            }
+            Actor::BlueprintHook(BlueprintHookActor { hook, .. }) => {
+                definition.hook_exports.get(hook)
+            }
+            Actor::Root => None,
+        };
+        Ok(export.map(|export| export.export_name.clone()))
+    }
+
     #[cfg_attr(feature = "std", catch_unwind_ignore)]
     pub fn current_actor(&mut self) -> Actor {
         self.api
@@ -1729,20 +1791,17 @@ where
     }
 }
 
-#[cfg_attr(
-    feature = "std",
-    catch_unwind(crate::utils::catch_unwind_system_panic_transformer)
-)]
-impl<'a, Y, V> ClientKeyValueStoreApi<RuntimeError> for SystemService<'a, Y, V>
+impl<'a, Y, V> SystemService<'a, Y, V>
 where
     Y: KernelApi<SystemConfig<V>>,
     V: SystemCallbackObject,
 {
-    // Costing through kernel
-    #[trace_resources]
-    fn key_value_store_new(
+    /// Creates a node typed by a [`KeyValueStoreDataSchema`], i.e. a key value store or an
+    /// index store.
+    fn new_key_value_store_node(
         &mut self,
         data_schema: KeyValueStoreDataSchema,
+        to_type_info: fn(KeyValueStoreInfo) -> TypeInfoSubstate,
     ) -> Result<NodeId, RuntimeError> {
         let mut additional_schemas = index_map_new();
         let (key_type, value_type, allow_ownership) = match data_schema {
@@ -1801,7 +1860,7 @@ where
             btreemap!(
                 MAIN_BASE_PARTITION => btreemap!(),
                 TYPE_INFO_FIELD_PARTITION => type_info_partition(
-                    TypeInfoSubstate::KeyValueStore(KeyValueStoreInfo {
+                    to_type_info(KeyValueStoreInfo {
                         generic_substitutions,
                     })
                 ),
@@ -1811,6 +1870,25 @@ where
 
         Ok(node_id)
     }
+}
+
+#[cfg_attr(
+    feature = "std",
+    catch_unwind(crate::utils::catch_unwind_system_panic_transformer)
+)]
+impl<'a, Y, V> ClientKeyValueStoreApi<RuntimeError> for SystemService<'a, Y, V>
+where
+    Y: KernelApi<SystemConfig<V>>,
+    V: SystemCallbackObject,
+{
+    // Costing through kernel
+    #[trace_resources]
+    fn key_value_store_new(
+        &mut self,
+        data_schema: KeyValueStoreDataSchema,
+    ) -> Result<NodeId, RuntimeError> {
+        self.new_key_value_store_node(data_schema, TypeInfoSubstate::KeyValueStore)
+    }
 
     // Costing through kernel
     #[trace_resources]
@@ -1883,6 +1961,144 @@ where
     }
 }
 
+impl<'a, Y, V> SystemService<'a, Y, V>
+where
+    Y: KernelApi<SystemConfig<V>>,
+    V: SystemCallbackObject,
+{
+    fn get_index_store_type_target(
+        &mut self,
+        node_id: &NodeId,
+    ) -> Result<KVStoreTypeTarget, RuntimeError> {
+        match TypeInfoBlueprint::get_type(node_id, self.api)? {
+            TypeInfoSubstate::IndexStore(info) => Ok(KVStoreTypeTarget {
+                kv_store_type: info.generic_substitutions,
+                meta: *node_id,
+            }),
+            _ => Err(RuntimeError::SystemError(SystemError::NotAnIndexStore)),
+        }
+    }
+}
+
+#[cfg_attr(
+    feature = "std",
+    catch_unwind(crate::utils::catch_unwind_system_panic_transformer)
+)]
+impl<'a, Y, V> ClientIndexStoreApi<RuntimeError> for SystemService<'a, Y, V>
+where
+    Y: KernelApi<SystemConfig<V>>,
+    V: SystemCallbackObject,
+{
+    // Costing through kernel
+    #[trace_resources]
+    fn index_store_new(
+        &mut self,
+        data_schema: KeyValueStoreDataSchema,
+    ) -> Result<NodeId, RuntimeError> {
+        // Sorted scans can't return owned nodes, see `CallFrame::scan_sorted`
+        let allow_ownership = match &data_schema {
+            KeyValueStoreDataSchema::Local {
+                allow_ownership, ..
+            }
+            | KeyValueStoreDataSchema::Remote {
+                allow_ownership, ..
+            } => *allow_ownership,
+        };
+        if allow_ownership {
+            return Err(RuntimeError::SystemError(
+                SystemError::IndexStoreOwnershipNotAllowed,
+            ));
+        }
+
+        self.new_key_value_store_node(data_schema, TypeInfoSubstate::IndexStore)
+    }
+
+    // Costing through kernel
+    #[trace_resources]
+    fn index_store_insert(
+        &mut self,
+        node_id: &NodeId,
+        sorted_key: SortedKey,
+        buffer: Vec<u8>,
+    ) -> Result<(), RuntimeError> {
+        let target = self.get_index_store_type_target(node_id)?;
+
+        self.validate_kv_store_payload(&target, KeyOrValue::Key, &sorted_key.1)?;
+        self.validate_kv_store_payload(&target, KeyOrValue::Value, &buffer)?;
+
+        let value: ScryptoValue = scrypto_decode(&buffer).unwrap();
+        let sorted_entry = SortedIndexEntrySubstate::entry(value);
+        let value = IndexedScryptoValue::from_typed(&sorted_entry);
+
+        self.api.kernel_set_substate(
+            node_id,
+            MAIN_BASE_PARTITION,
+            SubstateKey::Sorted(sorted_key),
+            value,
+        )
+    }
+
+    // Costing through kernel
+    #[trace_resources]
+    fn index_store_remove(
+        &mut self,
+        node_id: &NodeId,
+        sorted_key: &SortedKey,
+    ) -> Result<Option<Vec<u8>>, RuntimeError> {
+        self.get_index_store_type_target(node_id)?;
+
+        let rtn = self
+            .api
+            .kernel_remove_substate(
+                node_id,
+                MAIN_BASE_PARTITION,
+                &SubstateKey::Sorted(sorted_key.clone()),
+            )?
+            .map(|v| {
+                let value: SortedIndexEntrySubstate<ScryptoValue> = v.as_typed().unwrap();
+                scrypto_encode(value.value()).unwrap()
+            });
+
+        Ok(rtn)
+    }
+
+    // Costing through kernel
+    #[trace_resources]
+    fn index_store_scan(
+        &mut self,
+        node_id: &NodeId,
+        from_sort_prefix: [u8; 2],
+        to_sort_prefix: Option<[u8; 2]>,
+        limit: u32,
+    ) -> Result<Vec<(SortedKey, Vec<u8>)>, RuntimeError> {
+        self.get_index_store_type_target(node_id)?;
+
+        let substates = self
+            .api
+            .kernel_scan_sorted_substates(
+                node_id,
+                MAIN_BASE_PARTITION,
+                Some(from_sort_prefix),
+                limit,
+            )?
+            .into_iter()
+            .take_while(|(key, _)| {
+                to_sort_prefix
+                    .map(|to_sort_prefix| key.0 < to_sort_prefix)
+                    .unwrap_or(true)
+            })
+            .map(|(key, value)| {
+                let value: SortedIndexEntrySubstate<ScryptoValue> = value.as_typed().unwrap();
+                let value = scrypto_encode(value.value()).unwrap();
+
+                (key, value)
+            })
+            .collect();
+
+        Ok(substates)
+    }
+}
+
 #[cfg_attr(
     feature = "std",
     catch_unwind(crate::utils::catch_unwind_system_panic_transformer)
@@ -1986,6 +2202,33 @@ where
         Ok(substates)
     }
 
+    // Costing through kernel
+    fn actor_index_scan_keys_after(
+        &mut self,
+        object_handle: ActorStateHandle,
+        collection_index: CollectionIndex,
+        after: Option<Vec<u8>>,
+        limit: u32,
+    ) -> Result<Vec<Vec<u8>>, RuntimeError> {
+        let actor_object_type: ActorStateRef = object_handle.try_into()?;
+
+        let (node_id, _info, partition_num) = self.get_actor_collection_partition_info(
+            actor_object_type,
+            collection_index,
+            &BlueprintPartitionType::IndexCollection,
+        )?;
+
+        let after = after.map(SubstateKey::Map);
+        let substates = self
+            .api
+            .kernel_scan_keys_after::<MapKey>(&node_id, partition_num, after.as_ref(), limit)?
+            .into_iter()
+            .map(|key| key.into_map())
+            .collect();
+
+        Ok(substates)
+    }
+
     // Costing through kernel
     fn actor_index_drain(
         &mut self,
@@ -2123,7 +2366,7 @@ where
 
         let substates = self
             .api
-            .kernel_scan_sorted_substates(&node_id, partition_num, limit)?
+            .kernel_scan_sorted_substates(&node_id, partition_num, None, limit)?
             .into_iter()
             .map(|(key, value)| {
                 let value: SortedIndexEntrySubstate<ScryptoValue> = value.as_typed().unwrap();
@@ -2278,16 +2521,23 @@ where
 
     #[trace_resources]
     #[cfg_attr(feature = "std", catch_unwind_ignore)]
-    fn lock_fee(&mut self, locked_fee: LiquidFungibleResource, contingent: bool) {
+    fn lock_fee(
+        &mut self,
+        locked_fee: LiquidFungibleResource,
+        contingent: bool,
+        refund_vault_id: Option<NodeId>,
+    ) {
         // Credit cost units
         let vault_id = self
             .current_actor()
             .node_id()
             .expect("Caller should only be fungible vault method");
-        self.api
-            .kernel_get_system()
-            .modules
-            .lock_fee(vault_id, locked_fee.clone(), contingent);
+        self.api.kernel_get_system().modules.lock_fee(
+            vault_id,
+            locked_fee.clone(),
+            contingent,
+            refund_vault_id,
+        );
 
         // Emit Locked Fee event
         {
@@ -2410,6 +2660,21 @@ where
         }
     }
 
+    fn tip_basis_points(&mut self) -> Result<u32, RuntimeError> {
+        self.api
+            .kernel_get_system()
+            .modules
+            .apply_execution_cost(ExecutionCostingEntry::QueryFeeReserve)?;
+
+        if let Some(fee_reserve) = self.api.kernel_get_system().modules.fee_reserve() {
+            Ok(fee_reserve.tip().0)
+        } else {
+            Err(RuntimeError::SystemError(
+                SystemError::CostingModuleNotEnabled,
+            ))
+        }
+    }
+
     fn fee_balance(&mut self) -> Result<Decimal, RuntimeError> {
         self.api
             .kernel_get_system()
@@ -2424,6 +2689,24 @@ where
             ))
         }
     }
+
+    // No costing should be applied
+    #[trace_resources]
+    fn set_instruction_cost_unit_limit(&mut self, limit: u32) -> Result<(), RuntimeError> {
+        // Only the TransactionProcessor may limit the instructions
+        if self.api.kernel_get_current_depth() != 1 {
+            return Err(RuntimeError::SystemError(SystemError::InvalidActor));
+        }
+
+        if let Some(costing) = self.api.kernel_get_system().modules.costing_mut() {
+            costing.set_instruction_cost_unit_limit(limit);
+            Ok(())
+        } else {
+            Err(RuntimeError::SystemError(
+                SystemError::CostingModuleNotEnabled,
+            ))
+        }
+    }
 }
 
 #[cfg_attr(
@@ -2801,6 +3084,26 @@ where
         }
     }
 
+    #[trace_resources]
+    fn generate_ruid_seeded(&mut self, domain: &[u8]) -> Result<[u8; 32], RuntimeError> {
+        self.api.kernel_get_system().modules.apply_execution_cost(
+            ExecutionCostingEntry::GenerateRuidSeeded { size: domain.len() },
+        )?;
+
+        if let Some(ruid) = self
+            .api
+            .kernel_get_system()
+            .modules
+            .generate_ruid_seeded(domain)
+        {
+            Ok(ruid)
+        } else {
+            Err(RuntimeError::SystemError(
+                SystemError::TransactionRuntimeModuleNotEnabled,
+            ))
+        }
+    }
+
     #[trace_resources]
     fn bech32_encode_address(&mut self, address: GlobalAddress) -> Result<String, RuntimeError> {
         let network_definition = &self
@@ -2817,8 +3120,23 @@ where
 
     #[trace_resources]
     fn emit_log(&mut self, level: Level, message: String) -> Result<(), RuntimeError> {
+        self.add_log_record(level, None, message, None)
+    }
+
+    #[trace_resources]
+    fn emit_log_record(
+        &mut self,
+        level: Level,
+        topic: String,
+        message: String,
+        payload: Option<Vec<u8>>,
+    ) -> Result<(), RuntimeError> {
+        self.add_log_record(level, Some(topic), message, payload)
+    }
+
+    fn panic(&mut self, message: String) -> Result<(), RuntimeError> {
         self.api.kernel_get_system().modules.apply_execution_cost(
-            ExecutionCostingEntry::EmitLog {
+            ExecutionCostingEntry::Panic {
                 size: message.len(),
             },
         )?;
@@ -2826,26 +3144,112 @@ where
         self.api
             .kernel_get_system()
             .modules
-            .add_log(level, message)?;
+            .set_panic_message(message.clone())?;
+
+        let actor = self.current_actor();
+        let blueprint_id = actor.blueprint_id();
+        let export_name = match &blueprint_id {
+            Some(blueprint_id) => self.get_panic_export_name(&actor, blueprint_id.clone())?,
+            None => None,
+        };
+        self.api
+            .kernel_get_system()
+            .modules
+            .set_panic_details(PanicDetails::from_message(
+                &message,
+                blueprint_id,
+                export_name,
+            ));
 
-        Ok(())
+        Err(RuntimeError::ApplicationError(
+            ApplicationError::PanicMessage(message),
+        ))
     }
+}
 
-    fn panic(&mut self, message: String) -> Result<(), RuntimeError> {
+#[cfg_attr(
+    feature = "std",
+    catch_unwind(crate::utils::catch_unwind_system_panic_transformer)
+)]
+impl<'a, Y, V> ClientCryptoUtilsApi<RuntimeError> for SystemService<'a, Y, V>
+where
+    Y: KernelApi<SystemConfig<V>>,
+    V: SystemCallbackObject,
+{
+    #[trace_resources]
+    fn ed25519_verify(
+        &mut self,
+        message: &[u8],
+        public_key: &Ed25519PublicKey,
+        signature: &Ed25519Signature,
+    ) -> Result<bool, RuntimeError> {
         self.api.kernel_get_system().modules.apply_execution_cost(
-            ExecutionCostingEntry::Panic {
+            ExecutionCostingEntry::Ed25519Verify {
                 size: message.len(),
             },
         )?;
 
+        Ok(transaction::validation::verify_ed25519(
+            &blake2b_256_hash(message),
+            public_key,
+            signature,
+        ))
+    }
+
+    #[trace_resources]
+    fn secp256k1_verify(
+        &mut self,
+        message: &[u8],
+        public_key: &Secp256k1PublicKey,
+        signature: &Secp256k1Signature,
+    ) -> Result<bool, RuntimeError> {
+        self.api.kernel_get_system().modules.apply_execution_cost(
+            ExecutionCostingEntry::Secp256k1Verify {
+                size: message.len(),
+            },
+        )?;
+
+        Ok(transaction::validation::verify_secp256k1(
+            &blake2b_256_hash(message),
+            public_key,
+            signature,
+        ))
+    }
+
+    #[trace_resources]
+    fn bls12381_v1_verify(
+        &mut self,
+        message: &[u8],
+        public_key: &Bls12381G1PublicKey,
+        signature: &Bls12381G2Signature,
+    ) -> Result<bool, RuntimeError> {
+        self.api.kernel_get_system().modules.apply_execution_cost(
+            ExecutionCostingEntry::Bls12381V1Verify {
+                size: message.len(),
+            },
+        )?;
+
+        Ok(verify_bls12381_v1(message, public_key, signature))
+    }
+
+    #[trace_resources]
+    fn keccak256_hash(&mut self, data: &[u8]) -> Result<Hash, RuntimeError> {
         self.api
             .kernel_get_system()
             .modules
-            .set_panic_message(message.clone())?;
+            .apply_execution_cost(ExecutionCostingEntry::Keccak256Hash { size: data.len() })?;
 
-        Err(RuntimeError::ApplicationError(
-            ApplicationError::PanicMessage(message),
-        ))
+        Ok(keccak256_hash(data))
+    }
+
+    #[trace_resources]
+    fn blake2b_256_hash(&mut self, data: &[u8]) -> Result<Hash, RuntimeError> {
+        self.api
+            .kernel_get_system()
+            .modules
+            .apply_execution_cost(ExecutionCostingEntry::Blake2b256Hash { size: data.len() })?;
+
+        Ok(blake2b_256_hash(data))
     }
 }
 
@@ -2936,6 +3340,13 @@ where
         )
     }
 
+    fn kernel_prefetch_substates(
+        &mut self,
+        substates: Vec<(NodeId, PartitionNumber, SubstateKey)>,
+    ) -> Result<(), RuntimeError> {
+        self.api.kernel_prefetch_substates(substates)
+    }
+
     fn kernel_get_lock_data(
         &mut self,
         lock_handle: SubstateHandle,
@@ -2987,10 +3398,11 @@ where
         &mut self,
         node_id: &NodeId,
         partition_num: PartitionNumber,
+        from_sort_prefix: Option<[u8; 2]>,
         limit: u32,
     ) -> Result<Vec<(SortedKey, IndexedScryptoValue)>, RuntimeError> {
         self.api
-            .kernel_scan_sorted_substates(node_id, partition_num, limit)
+            .kernel_scan_sorted_substates(node_id, partition_num, from_sort_prefix, limit)
     }
 
     fn kernel_scan_keys<K: SubstateKeyContent + 'static>(
@@ -3003,6 +3415,17 @@ where
             .kernel_scan_keys::<K>(node_id, partition_num, limit)
     }
 
+    fn kernel_scan_keys_after<K: SubstateKeyContent + 'static>(
+        &mut self,
+        node_id: &NodeId,
+        partition_num: PartitionNumber,
+        after: Option<&SubstateKey>,
+        limit: u32,
+    ) -> Result<Vec<SubstateKey>, RuntimeError> {
+        self.api
+            .kernel_scan_keys_after::<K>(node_id, partition_num, after, limit)
+    }
+
     fn kernel_drain_substates<K: SubstateKeyContent + 'static>(
         &mut self,
         node_id: &NodeId,
@@ -3038,4 +3461,8 @@ where
     fn kernel_read_proof(&mut self, proof_id: &NodeId) -> Option<ProofSnapshot> {
         self.api.kernel_read_proof(proof_id)
     }
+
+    fn kernel_read_auth_zone(&mut self, auth_zone_id: &NodeId) -> Option<AuthZone> {
+        self.api.kernel_read_auth_zone(auth_zone_id)
+    }
 }
@@ -625,6 +625,7 @@ impl<C: SystemCallbackObject> KernelCallbackObject for SystemConfig<C> {
                 }
             }
             TypeInfoSubstate::KeyValueStore(_)
+            | TypeInfoSubstate::IndexStore(_)
             | TypeInfoSubstate::GlobalAddressReservation(_)
             | TypeInfoSubstate::GlobalAddressPhantom(_) => {
                 // There is no way to drop a non-object through system API, triggering `NotAnObject` error.
@@ -678,6 +679,7 @@ impl<C: SystemCallbackObject> KernelCallbackObject for SystemConfig<C> {
                 }
             }
             TypeInfoSubstate::KeyValueStore(_)
+            | TypeInfoSubstate::IndexStore(_)
             | TypeInfoSubstate::GlobalAddressReservation(_)
             | TypeInfoSubstate::GlobalAddressPhantom(_) => Ok(()),
         }
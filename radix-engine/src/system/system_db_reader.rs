@@ -21,7 +21,10 @@ use sbor::rust::prelude::*;
 use sbor::LocalTypeId;
 use sbor::{validate_payload_against_schema, HasLatestVersion, LocatedValidationError};
 
-use crate::blueprints::package::PackageBlueprintVersionDefinitionEntrySubstate;
+use crate::blueprints::package::{
+    PackageBlueprintVersionDefinitionEntrySubstate, PackageDeprecation,
+    PackageDeprecationEntrySubstate, PackageDeprecationKey,
+};
 use crate::system::payload_validation::{SchemaOrigin, TypeInfoForValidation, ValidationContext};
 use crate::system::system_substates::FieldSubstate;
 use crate::system::system_substates::KeyValueEntrySubstate;
@@ -56,6 +59,7 @@ pub enum SystemPartitionDescriptor {
     TypeInfo,
     Schema,
     KeyValueStore,
+    IndexStore,
     Object(ModuleId, ObjectPartitionDescriptor),
 }
 
@@ -97,6 +101,7 @@ pub enum SystemReaderError {
     SchemaDoesNotExist,
     TargetNotSupported,
     BlueprintTypeNotFound(String),
+    DecodeError(DecodeError),
 }
 
 /// A System Layer (Layer 2) abstraction over an underlying substate database
@@ -167,6 +172,23 @@ impl<'a, S: SubstateDatabase> SystemDatabaseReader<'a, S> {
         blueprints
     }
 
+    /// Returns the package's deprecation, if its owner has deprecated it.
+    pub fn get_package_deprecation(
+        &self,
+        package_address: PackageAddress,
+    ) -> Option<PackageDeprecation> {
+        self.substate_db
+            .get_mapped::<SpreadPrefixKeyMapper, PackageDeprecationEntrySubstate>(
+                package_address.as_node_id(),
+                MAIN_BASE_PARTITION
+                    .at_offset(PACKAGE_DEPRECATION_PARTITION_OFFSET)
+                    .unwrap(),
+                &SubstateKey::Map(scrypto_encode(&PackageDeprecationKey).unwrap()),
+            )
+            .and_then(|substate| substate.into_value())
+            .map(|deprecation| deprecation.into_latest())
+    }
+
     pub fn read_object_field(
         &self,
         node_id: &NodeId,
@@ -367,6 +389,20 @@ impl<'a, S: SubstateDatabase> SystemDatabaseReader<'a, S> {
         Ok(Box::new(iter))
     }
 
+    /// Lists the entries of a key-value store, decoded as the given key and value types.
+    pub fn list_kv_entries<K: ScryptoDecode, V: ScryptoDecode>(
+        &self,
+        node_id: &NodeId,
+    ) -> Result<Vec<(K, V)>, SystemReaderError> {
+        self.key_value_store_iter(node_id)?
+            .map(|(key, value)| {
+                let key = scrypto_decode(&key).map_err(SystemReaderError::DecodeError)?;
+                let value = scrypto_decode(&value).map_err(SystemReaderError::DecodeError)?;
+                Ok((key, value))
+            })
+            .collect()
+    }
+
     pub fn collection_iter(
         &self,
         node_id: &NodeId,
@@ -542,8 +578,10 @@ impl<'a, S: SubstateDatabase> SystemDatabaseReader<'a, S> {
             )
             .ok_or_else(|| SystemReaderError::NodeIdDoesNotExist)?;
 
+        // An index store is typed the same way as a key value store
         let kv_store_info = match type_info {
-            TypeInfoSubstate::KeyValueStore(kv_store_info) => kv_store_info,
+            TypeInfoSubstate::KeyValueStore(kv_store_info)
+            | TypeInfoSubstate::IndexStore(kv_store_info) => kv_store_info,
             _ => return Err(SystemReaderError::NotAKeyValueStore),
         };
 
@@ -1047,6 +1085,11 @@ impl<'a, S: SubstateDatabase> SystemDatabaseReader<'a, S> {
                     descriptors.push(SystemPartitionDescriptor::KeyValueStore);
                 }
             }
+            TypeInfoSubstate::IndexStore(..) => {
+                if partition_num.eq(&MAIN_BASE_PARTITION) {
+                    descriptors.push(SystemPartitionDescriptor::IndexStore);
+                }
+            }
             _ => {}
         }
 
@@ -1093,6 +1136,7 @@ impl<'a, S: SubstateDatabase> ValidationContext for ValidationPayloadCheckerCont
                 blueprint: object_info.blueprint_info.blueprint_id.blueprint_name,
             },
             TypeInfoSubstate::KeyValueStore(..) => TypeInfoForValidation::KeyValueStore,
+            TypeInfoSubstate::IndexStore(..) => TypeInfoForValidation::IndexStore,
             TypeInfoSubstate::GlobalAddressReservation(..) => {
                 TypeInfoForValidation::GlobalAddressReservation
             }
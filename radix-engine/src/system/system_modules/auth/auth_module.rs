@@ -1,4 +1,6 @@
-use super::Authorization;
+use super::{
+    AuthCheckOutcome, AuthCheckTrace, AuthRequirement, AuthTrace, AuthZoneSnapshot, Authorization,
+};
 use crate::blueprints::package::PackageAuthNativeBlueprint;
 use crate::blueprints::resource::AuthZone;
 use crate::errors::*;
@@ -48,6 +50,8 @@ pub struct Unauthorized {
 #[derive(Debug, Clone)]
 pub struct AuthModule {
     pub params: AuthZoneParams,
+    /// The access checks made so far, if tracing is enabled
+    pub trace: Option<AuthTrace>,
 }
 
 pub enum AuthorizationCheckResult {
@@ -400,25 +404,21 @@ impl AuthModule {
         fn_identifier: FnIdentifier,
         api: &mut SystemService<Y, V>,
     ) -> Result<(), RuntimeError> {
-        match resolved_permission {
+        let (requirement, outcome) = match resolved_permission {
             ResolvedPermission::AllowAll => return Ok(()),
             ResolvedPermission::AccessRule(rule) => {
                 let result =
                     Authorization::check_authorization_against_access_rule(api, &auth_zone, &rule)?;
 
-                match result {
-                    AuthorizationCheckResult::Authorized => Ok(()),
-                    AuthorizationCheckResult::Failed(access_rule_stack) => Err(
-                        RuntimeError::SystemModuleError(SystemModuleError::AuthError(
-                            AuthError::Unauthorized(Box::new(Unauthorized {
-                                failed_access_rules: FailedAccessRules::AccessRule(
-                                    access_rule_stack,
-                                ),
-                                fn_identifier,
-                            })),
-                        )),
-                    ),
-                }
+                let outcome = match result {
+                    AuthorizationCheckResult::Authorized => AuthCheckOutcome::Authorized,
+                    AuthorizationCheckResult::Failed(access_rule_stack) => {
+                        AuthCheckOutcome::Unauthorized(FailedAccessRules::AccessRule(
+                            access_rule_stack,
+                        ))
+                    }
+                };
+                (AuthRequirement::AccessRule(rule), outcome)
             }
             ResolvedPermission::RoleList {
                 role_assignment_of,
@@ -433,21 +433,107 @@ impl AuthModule {
                     api,
                 )?;
 
-                match result {
-                    AuthorityListAuthorizationResult::Authorized => Ok(()),
-                    AuthorityListAuthorizationResult::Failed(auth_list_fail) => Err(
-                        RuntimeError::SystemModuleError(SystemModuleError::AuthError(
-                            AuthError::Unauthorized(Box::new(Unauthorized {
-                                failed_access_rules: FailedAccessRules::RoleList(auth_list_fail),
-                                fn_identifier,
-                            })),
-                        )),
-                    ),
-                }
+                let outcome = match result {
+                    AuthorityListAuthorizationResult::Authorized => AuthCheckOutcome::Authorized,
+                    AuthorityListAuthorizationResult::Failed(auth_list_fail) => {
+                        AuthCheckOutcome::Unauthorized(FailedAccessRules::RoleList(auth_list_fail))
+                    }
+                };
+                (
+                    AuthRequirement::RoleList {
+                        role_assignment_of,
+                        module_id,
+                        role_list,
+                    },
+                    outcome,
+                )
+            }
+        };
+
+        if api.kernel_get_system().modules.auth.trace.is_some() {
+            Self::trace_check(
+                auth_zone,
+                fn_identifier.clone(),
+                requirement,
+                outcome.clone(),
+                api,
+            );
+        }
+
+        match outcome {
+            AuthCheckOutcome::Authorized => Ok(()),
+            AuthCheckOutcome::Unauthorized(failed_access_rules) => {
+                Err(RuntimeError::SystemModuleError(
+                    SystemModuleError::AuthError(AuthError::Unauthorized(Box::new(Unauthorized {
+                        failed_access_rules,
+                        fn_identifier,
+                    }))),
+                ))
             }
         }
     }
 
+    /// Records an access check, along with the auth zones it was checked against.
+    ///
+    /// The auth zones are read without going through the system, so that tracing doesn't affect
+    /// the costing of the transaction.
+    fn trace_check<Y: KernelApi<SystemConfig<V>>, V: SystemCallbackObject>(
+        auth_zone: &NodeId,
+        fn_identifier: FnIdentifier,
+        requirement: AuthRequirement,
+        outcome: AuthCheckOutcome,
+        api: &mut SystemService<Y, V>,
+    ) {
+        let (local_virtual_non_fungibles, global_caller_auth_zone, caller_auth_zone) =
+            match api.kernel_read_auth_zone(auth_zone) {
+                Some(auth_zone) => (
+                    auth_zone.local_virtual_non_fungibles(),
+                    auth_zone
+                        .global_caller
+                        .map(|(_global_caller, reference)| reference.0),
+                    auth_zone.parent.map(|reference| reference.0),
+                ),
+                None => (BTreeSet::new(), None, None),
+            };
+
+        let check = AuthCheckTrace {
+            fn_identifier,
+            requirement,
+            local_virtual_non_fungibles,
+            global_caller_auth_zones: Self::snapshot_auth_zones(global_caller_auth_zone, api),
+            caller_auth_zones: Self::snapshot_auth_zones(caller_auth_zone, api),
+            outcome,
+        };
+
+        if let Some(trace) = &mut api.kernel_get_system().modules.auth.trace {
+            trace.checks.push(check);
+        }
+    }
+
+    /// Takes a snapshot of the given auth zone and of its parents, from the innermost one outwards.
+    fn snapshot_auth_zones<Y: KernelApi<SystemConfig<V>>, V: SystemCallbackObject>(
+        mut auth_zone: Option<NodeId>,
+        api: &mut SystemService<Y, V>,
+    ) -> Vec<AuthZoneSnapshot> {
+        let mut snapshots = Vec::new();
+        while let Some(auth_zone_id) = auth_zone {
+            let Some(current) = api.kernel_read_auth_zone(&auth_zone_id) else {
+                break;
+            };
+            snapshots.push(AuthZoneSnapshot {
+                proofs: current
+                    .proofs()
+                    .iter()
+                    .filter_map(|proof| api.kernel_read_proof(proof.0.as_node_id()))
+                    .collect(),
+                virtual_resources: current.virtual_resources().clone(),
+                virtual_non_fungibles: current.virtual_non_fungibles().clone(),
+            });
+            auth_zone = current.parent.map(|reference| reference.0);
+        }
+        snapshots
+    }
+
     fn resolve_method_permission<Y: KernelApi<SystemConfig<V>>, V: SystemCallbackObject>(
         api: &mut SystemService<Y, V>,
         blueprint_id: &BlueprintId,
@@ -0,0 +1,57 @@
+use super::FailedAccessRules;
+use crate::system::system_modules::execution_trace::ProofSnapshot;
+use crate::types::*;
+use radix_engine_interface::api::ModuleId;
+use radix_engine_interface::blueprints::resource::*;
+
+/// The access checks made by the auth module during a transaction, explaining why each call was
+/// (or wasn't) authorized.
+///
+/// Calls which are accessible to everyone aren't checked, and so aren't recorded.
+#[derive(Debug, Clone, Default, PartialEq, Eq, ScryptoSbor)]
+pub struct AuthTrace {
+    pub checks: Vec<AuthCheckTrace>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct AuthCheckTrace {
+    /// The function or method being called
+    pub fn_identifier: FnIdentifier,
+    /// The requirement which the caller had to meet
+    pub requirement: AuthRequirement,
+    /// The virtual proofs of the call itself, i.e. the badges of the caller's package and of the
+    /// global caller
+    pub local_virtual_non_fungibles: BTreeSet<NonFungibleGlobalId>,
+    /// The auth zones of the global caller, from the innermost one outwards
+    pub global_caller_auth_zones: Vec<AuthZoneSnapshot>,
+    /// The auth zones of the direct caller, from the innermost one outwards, if the call isn't
+    /// made across a barrier
+    pub caller_auth_zones: Vec<AuthZoneSnapshot>,
+    pub outcome: AuthCheckOutcome,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum AuthRequirement {
+    AccessRule(AccessRule),
+    /// Any of the roles of the list, as assigned by the role assignment module of the given
+    /// global object
+    RoleList {
+        role_assignment_of: GlobalAddress,
+        module_id: ModuleId,
+        role_list: RoleList,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct AuthZoneSnapshot {
+    pub proofs: Vec<ProofSnapshot>,
+    pub virtual_resources: BTreeSet<ResourceAddress>,
+    pub virtual_non_fungibles: BTreeSet<NonFungibleGlobalId>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum AuthCheckOutcome {
+    Authorized,
+    /// The same failed access rules as reported by [`AuthError::Unauthorized`](super::AuthError)
+    Unauthorized(FailedAccessRules),
+}
@@ -320,6 +320,25 @@ impl Authorization {
 
                 return Ok(AuthorizationCheckResult::Authorized);
             }
+            AccessRuleNode::WeightedThreshold { threshold, weights } => {
+                let threshold = u64::from(*threshold);
+                let mut satisfied_weight = 0u64;
+                for (r, weight) in weights {
+                    if satisfied_weight >= threshold {
+                        break;
+                    }
+                    let rtn = Self::verify_auth_rule(auth_zone, r, api)?;
+                    if matches!(rtn, AuthorizationCheckResult::Authorized) {
+                        satisfied_weight += u64::from(*weight);
+                    }
+                }
+
+                if satisfied_weight >= threshold {
+                    Ok(AuthorizationCheckResult::Authorized)
+                } else {
+                    Ok(AuthorizationCheckResult::Failed(vec![]))
+                }
+            }
         }
     }
 
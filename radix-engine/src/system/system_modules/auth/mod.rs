@@ -1,5 +1,7 @@
 mod auth_module;
+mod auth_trace;
 mod authorization;
 
 pub use auth_module::*;
+pub use auth_trace::*;
 pub use authorization::*;
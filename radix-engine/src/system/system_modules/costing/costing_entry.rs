@@ -5,8 +5,8 @@ use crate::kernel::kernel_callback_api::{
     ScanSortedSubstatesEvent, SetSubstateEvent, WriteSubstateEvent,
 };
 use crate::system::actor::Actor;
-use crate::system::system_modules::transaction_runtime::Event;
-use crate::track::interface::StoreCommit;
+use crate::system::system_modules::transaction_runtime::{Event, LogRecord};
+use crate::track::interface::{IOAccess, StoreCommit};
 use crate::types::*;
 use radix_engine_interface::*;
 
@@ -101,6 +101,9 @@ pub enum ExecutionCostingEntry<'a> {
     QueryActor,
     QueryTransactionHash,
     GenerateRuid,
+    GenerateRuidSeeded {
+        size: usize,
+    },
     EmitEvent {
         size: usize,
     },
@@ -110,13 +113,30 @@ pub enum ExecutionCostingEntry<'a> {
     Panic {
         size: usize,
     },
+
+    /* crypto utils */
+    Ed25519Verify {
+        size: usize,
+    },
+    Secp256k1Verify {
+        size: usize,
+    },
+    Bls12381V1Verify {
+        size: usize,
+    },
+    Keccak256Hash {
+        size: usize,
+    },
+    Blake2b256Hash {
+        size: usize,
+    },
 }
 
 #[derive(Debug, IntoStaticStr)]
 pub enum FinalizationCostingEntry<'a> {
     CommitStateUpdates { store_commit: &'a StoreCommit },
     CommitEvents { events: &'a Vec<Event> },
-    CommitLogs { logs: &'a Vec<(Level, String)> },
+    CommitLogs { logs: &'a Vec<LogRecord> },
 }
 
 impl<'a> ExecutionCostingEntry<'a> {
@@ -169,9 +189,17 @@ impl<'a> ExecutionCostingEntry<'a> {
             ExecutionCostingEntry::QueryActor => ft.query_actor_cost(),
             ExecutionCostingEntry::QueryTransactionHash => ft.query_transaction_hash_cost(),
             ExecutionCostingEntry::GenerateRuid => ft.generate_ruid_cost(),
+            ExecutionCostingEntry::GenerateRuidSeeded { size } => {
+                ft.generate_ruid_seeded_cost(*size)
+            }
             ExecutionCostingEntry::EmitEvent { size } => ft.emit_event_cost(*size),
             ExecutionCostingEntry::EmitLog { size } => ft.emit_log_cost(*size),
             ExecutionCostingEntry::Panic { size } => ft.panic_cost(*size),
+            ExecutionCostingEntry::Ed25519Verify { size } => ft.ed25519_verify_cost(*size),
+            ExecutionCostingEntry::Secp256k1Verify { size } => ft.secp256k1_verify_cost(*size),
+            ExecutionCostingEntry::Bls12381V1Verify { size } => ft.bls12381_v1_verify_cost(*size),
+            ExecutionCostingEntry::Keccak256Hash { size } => ft.keccak256_hash_cost(*size),
+            ExecutionCostingEntry::Blake2b256Hash { size } => ft.blake2b_256_hash_cost(*size),
         }
     }
 }
@@ -189,6 +217,52 @@ impl<'a> FinalizationCostingEntry<'a> {
 }
 
 impl<'a> ExecutionCostingEntry<'a> {
+    /// Whether the cost of this entry depends on the state of the substate database, i.e. it is
+    /// charged for reading substates from the database rather than from the track or heap.
+    pub fn is_state_dependent(&self) -> bool {
+        let io_access = match self {
+            ExecutionCostingEntry::CreateNode {
+                event: CreateNodeEvent::IOAccess(io_access),
+            }
+            | ExecutionCostingEntry::DropNode {
+                event: DropNodeEvent::IOAccess(io_access),
+            }
+            | ExecutionCostingEntry::MoveModule {
+                event: MoveModuleEvent::IOAccess(io_access),
+            }
+            | ExecutionCostingEntry::OpenSubstate {
+                event: OpenSubstateEvent::IOAccess(io_access),
+            }
+            | ExecutionCostingEntry::ReadSubstate {
+                event: ReadSubstateEvent::IOAccess(io_access),
+            }
+            | ExecutionCostingEntry::WriteSubstate {
+                event: WriteSubstateEvent::IOAccess(io_access),
+            }
+            | ExecutionCostingEntry::SetSubstate {
+                event: SetSubstateEvent::IOAccess(io_access),
+            }
+            | ExecutionCostingEntry::RemoveSubstate {
+                event: RemoveSubstateEvent::IOAccess(io_access),
+            }
+            | ExecutionCostingEntry::ScanKeys {
+                event: ScanKeysEvent::IOAccess(io_access),
+            }
+            | ExecutionCostingEntry::ScanSortedSubstates {
+                event: ScanSortedSubstatesEvent::IOAccess(io_access),
+            }
+            | ExecutionCostingEntry::DrainSubstates {
+                event: DrainSubstatesEvent::IOAccess(io_access),
+            } => io_access,
+            _ => return false,
+        };
+
+        matches!(
+            io_access,
+            IOAccess::ReadFromDb(..) | IOAccess::ReadFromDbNotFound(..)
+        )
+    }
+
     pub fn to_trace_key(&self) -> String {
         match self {
             ExecutionCostingEntry::RunNativeCode { export_name, .. } => {
@@ -25,12 +25,14 @@ use radix_engine_interface::{types::NodeId, *};
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub enum CostingError {
     FeeReserveError(FeeReserveError),
+    InstructionCostUnitLimitExceeded { limit: u32, consumed: u32 },
 }
 
 impl CanBeAbortion for CostingError {
     fn abortion(&self) -> Option<&AbortReason> {
         match self {
             Self::FeeReserveError(err) => err.abortion(),
+            Self::InstructionCostUnitLimitExceeded { .. } => None,
         }
     }
 }
@@ -84,6 +86,18 @@ pub struct CostingModule {
     pub execution_cost_breakdown: IndexMap<String, u32>,
     pub finalization_cost_breakdown: IndexMap<String, u32>,
     pub storage_cost_breakdown: IndexMap<StorageType, usize>,
+    /// Execution cost units, keyed by the package of the blueprint being executed when charged
+    pub execution_cost_breakdown_by_package: IndexMap<PackageAddress, u32>,
+    /// Execution cost units charged for reading substates from the database
+    pub state_dependent_execution_cost_units: u32,
+    /// The packages of the blueprints currently being invoked, innermost last
+    pub package_stack: Vec<Option<PackageAddress>>,
+    /// The execution cost units each transaction instruction may consume, if limited
+    pub instruction_cost_unit_limit: Option<u32>,
+    /// The execution cost units committed before the current instruction started
+    pub instruction_start_cost_units: u32,
+    /// The number of royalty-bearing calls made to each component royalty recipient so far
+    pub component_royalty_calls: IndexMap<RoyaltyRecipient, u32>,
 
     pub on_apply_cost: OnApplyCost,
 }
@@ -93,6 +107,42 @@ impl CostingModule {
         self.fee_reserve
     }
 
+    pub fn set_instruction_cost_unit_limit(&mut self, limit: u32) {
+        self.instruction_cost_unit_limit = Some(limit);
+    }
+
+    /// The WASM execution units which may still be consumed before the execution cost unit
+    /// limit is reached, at the rate of the fee table.
+    pub fn remaining_wasm_execution_units(&self) -> u64 {
+        let remaining_cost_units = self
+            .fee_reserve
+            .execution_cost_unit_limit()
+            .saturating_sub(self.fee_reserve.execution_cost_units_committed());
+        u64::from(remaining_cost_units)
+            * u64::from(self.fee_table.config().wasm_execution_units_per_cost_unit)
+    }
+
+    pub fn start_instruction(&mut self) {
+        self.instruction_start_cost_units = self.fee_reserve.execution_cost_units_committed();
+    }
+
+    fn check_instruction_cost_unit_limit(&self) -> Result<(), RuntimeError> {
+        if let Some(limit) = self.instruction_cost_unit_limit {
+            let consumed = self
+                .fee_reserve
+                .execution_cost_units_committed()
+                .saturating_sub(self.instruction_start_cost_units);
+            if consumed > limit {
+                return Err(RuntimeError::SystemModuleError(
+                    SystemModuleError::CostingError(
+                        CostingError::InstructionCostUnitLimitExceeded { limit, consumed },
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+
     pub fn apply_execution_cost(
         &mut self,
         costing_entry: ExecutionCostingEntry,
@@ -110,14 +160,10 @@ impl CostingModule {
             })?;
 
         if self.enable_cost_breakdown {
-            let key = costing_entry.to_trace_key();
-            self.execution_cost_breakdown
-                .entry(key)
-                .or_default()
-                .add_assign(cost_units);
+            self.record_execution_cost_breakdown(&costing_entry, cost_units);
         }
 
-        Ok(())
+        self.check_instruction_cost_unit_limit()
     }
 
     pub fn apply_deferred_execution_cost(
@@ -137,14 +183,35 @@ impl CostingModule {
             })?;
 
         if self.enable_cost_breakdown {
-            let key = costing_entry.to_trace_key();
-            self.execution_cost_breakdown
-                .entry(key)
+            self.record_execution_cost_breakdown(&costing_entry, cost_units);
+        }
+
+        Ok(())
+    }
+
+    fn record_execution_cost_breakdown(
+        &mut self,
+        costing_entry: &ExecutionCostingEntry,
+        cost_units: u32,
+    ) {
+        let key = costing_entry.to_trace_key();
+        self.execution_cost_breakdown
+            .entry(key)
+            .or_default()
+            .add_assign(cost_units);
+
+        if let Some(Some(package_address)) = self.package_stack.last() {
+            self.execution_cost_breakdown_by_package
+                .entry(*package_address)
                 .or_default()
                 .add_assign(cost_units);
         }
 
-        Ok(())
+        if costing_entry.is_state_dependent() {
+            self.state_dependent_execution_cost_units = self
+                .state_dependent_execution_cost_units
+                .saturating_add(cost_units);
+        }
     }
 
     pub fn apply_deferred_storage_cost(
@@ -229,8 +296,17 @@ impl CostingModule {
         vault_id: NodeId,
         locked_fee: LiquidFungibleResource,
         contingent: bool,
+        refund_vault_id: Option<NodeId>,
     ) {
-        self.fee_reserve.lock_fee(vault_id, locked_fee, contingent);
+        match refund_vault_id {
+            Some(refund_vault_id) => self.fee_reserve.lock_fee_with_refund(
+                vault_id,
+                locked_fee,
+                contingent,
+                refund_vault_id,
+            ),
+            None => self.fee_reserve.lock_fee(vault_id, locked_fee, contingent),
+        }
     }
 }
 
@@ -290,6 +366,19 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for CostingModule {
                 input_size: invocation.len(),
             })?;
 
+        // Costs from here until the matching `after_invoke` are attributed to the callee
+        {
+            let costing = &mut api.kernel_get_system().modules.costing;
+            if costing.enable_cost_breakdown {
+                costing.package_stack.push(
+                    invocation
+                        .call_frame_data
+                        .blueprint_id()
+                        .map(|blueprint_id| blueprint_id.package_address),
+                );
+            }
+        }
+
         // Identify the function, and optional component address
         let (optional_blueprint_id, ident, maybe_object_royalties) = {
             let (maybe_component, ident) = match &invocation.call_frame_data {
@@ -369,6 +458,11 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for CostingModule {
                 output_size: output.len(),
             })?;
 
+        let costing = &mut api.kernel_get_system().modules.costing;
+        if costing.enable_cost_breakdown {
+            costing.package_stack.pop();
+        }
+
         Ok(())
     }
 
@@ -6,7 +6,9 @@ use crate::{
 };
 use radix_engine_interface::blueprints::resource::LiquidFungibleResource;
 use sbor::rust::cmp::min;
-use transaction::prelude::TransactionCostingParameters;
+use transaction::prelude::{
+    TipBasisPoints, TransactionCostingParameters, BASIS_POINTS_PER_PERCENT,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
 pub enum FeeReserveError {
@@ -72,6 +74,16 @@ pub trait ExecutionFeeReserve {
     ) -> Result<(), FeeReserveError>;
 
     fn lock_fee(&mut self, vault_id: NodeId, fee: LiquidFungibleResource, contingent: bool);
+
+    /// Locks a fee whose unused part is refunded to `refund_vault_id`, rather than to the vault it
+    /// is locked from.
+    fn lock_fee_with_refund(
+        &mut self,
+        vault_id: NodeId,
+        fee: LiquidFungibleResource,
+        contingent: bool,
+        refund_vault_id: NodeId,
+    );
 }
 
 pub trait FinalizingFeeReserve {
@@ -107,7 +119,7 @@ pub struct SystemLoanFeeReserve {
     state_storage_price: Decimal,
     archive_storage_price: Decimal,
 
-    tip_percentage: u16,
+    tip: TipBasisPoints,
 
     /// Whether to abort the transaction run when the loan is repaid.
     /// This is used when test-executing pending transactions.
@@ -140,6 +152,8 @@ pub struct SystemLoanFeeReserve {
 
     /// Payments made during the execution of a transaction.
     locked_fees: Vec<(NodeId, LiquidFungibleResource, bool)>,
+    /// The vaults to refund locked fees to, by index in `locked_fees`, if not the locking vault.
+    refund_vaults: BTreeMap<usize, NodeId>,
 }
 
 impl Default for SystemLoanFeeReserve {
@@ -181,22 +195,14 @@ impl SystemLoanFeeReserve {
             .free_credit_in_xrd
             .is_negative());
 
-        let tip_percentage = Decimal::ONE
-            .checked_add(
-                Decimal::ONE_HUNDREDTH
-                    .checked_mul(transaction_costing_parameters.tip_percentage)
-                    .unwrap(),
-            )
-            .unwrap();
+        let tip = transaction_costing_parameters.tip;
 
-        let effective_execution_cost_unit_price = costing_parameters
-            .execution_cost_unit_price
-            .checked_mul(tip_percentage)
+        let effective_execution_cost_unit_price = tip
+            .apply_to_cost_unit_price(costing_parameters.execution_cost_unit_price)
             .unwrap();
 
-        let effective_finalization_cost_unit_price = costing_parameters
-            .finalization_cost_unit_price
-            .checked_mul(tip_percentage)
+        let effective_finalization_cost_unit_price = tip
+            .apply_to_cost_unit_price(costing_parameters.finalization_cost_unit_price)
             .unwrap();
 
         let system_loan_in_xrd = effective_execution_cost_unit_price
@@ -218,8 +224,8 @@ impl SystemLoanFeeReserve {
             state_storage_price: costing_parameters.state_storage_price,
             archive_storage_price: costing_parameters.archive_storage_price,
 
-            // Tipping percentage
-            tip_percentage: transaction_costing_parameters.tip_percentage,
+            // Tip
+            tip,
 
             // Aborting support
             abort_when_loan_repaid,
@@ -247,6 +253,7 @@ impl SystemLoanFeeReserve {
             storage_cost_deferred: index_map_new(),
 
             locked_fees: Vec::new(),
+            refund_vaults: BTreeMap::new(),
         }
     }
 
@@ -270,14 +277,23 @@ impl SystemLoanFeeReserve {
         self.usd_price
     }
 
+    /// Returns the tip as a whole percentage, rounded down. See [`Self::tip`] for the exact tip.
     pub fn tip_percentage(&self) -> u32 {
-        self.tip_percentage.into()
+        self.tip.0 / BASIS_POINTS_PER_PERCENT
+    }
+
+    pub fn tip(&self) -> TipBasisPoints {
+        self.tip
     }
 
     pub fn fee_balance(&self) -> Decimal {
         self.xrd_balance
     }
 
+    pub fn execution_cost_units_committed(&self) -> u32 {
+        self.execution_cost_units_committed
+    }
+
     pub fn royalty_cost_breakdown(&self) -> &IndexMap<RoyaltyRecipient, Decimal> {
         &self.royalty_cost_breakdown
     }
@@ -524,6 +540,18 @@ impl ExecutionFeeReserve for SystemLoanFeeReserve {
         self.locked_fees
             .push((vault_id, fee.take_all(), contingent));
     }
+
+    fn lock_fee_with_refund(
+        &mut self,
+        vault_id: NodeId,
+        fee: LiquidFungibleResource,
+        contingent: bool,
+        refund_vault_id: NodeId,
+    ) {
+        self.lock_fee(vault_id, fee, contingent);
+        self.refund_vaults
+            .insert(self.locked_fees.len() - 1, refund_vault_id);
+    }
 }
 
 impl FinalizingFeeReserve for SystemLoanFeeReserve {
@@ -538,17 +566,11 @@ impl FinalizingFeeReserve for SystemLoanFeeReserve {
             .checked_mul(Decimal::from(self.finalization_cost_units_committed))
             .unwrap();
 
-        let tip_percentage = Decimal::from(self.tip_percentage)
-            .checked_div(dec!(100))
-            .unwrap();
-        let total_tipping_cost_in_xrd = total_execution_cost_in_xrd
-            .checked_mul(tip_percentage)
+        let total_tipping_cost_in_xrd = self
+            .tip
+            .tip_on(total_execution_cost_in_xrd)
             .unwrap()
-            .checked_add(
-                total_finalization_cost_in_xrd
-                    .checked_mul(tip_percentage)
-                    .unwrap(),
-            )
+            .checked_add(self.tip.tip_on(total_finalization_cost_in_xrd).unwrap())
             .unwrap();
 
         FeeReserveFinalizationSummary {
@@ -558,10 +580,13 @@ impl FinalizingFeeReserve for SystemLoanFeeReserve {
             total_execution_cost_in_xrd,
             total_finalization_cost_in_xrd,
             total_tipping_cost_in_xrd,
+            effective_execution_cost_unit_price: self.effective_execution_cost_unit_price,
+            effective_finalization_cost_unit_price: self.effective_finalization_cost_unit_price,
             total_royalty_cost_in_xrd: self.royalty_cost_committed,
             total_storage_cost_in_xrd: self.storage_cost_committed,
             total_bad_debt_in_xrd: self.xrd_owed,
             locked_fees: self.locked_fees,
+            refund_vaults: self.refund_vaults,
             royalty_cost_breakdown: self.royalty_cost_breakdown,
         }
     }
@@ -598,7 +623,7 @@ mod tests {
         costing_parameters.usd_price = usd_price;
         costing_parameters.state_storage_price = state_storage_price;
         let mut transaction_costing_parameters = TransactionCostingParameters::default();
-        transaction_costing_parameters.tip_percentage = tip_percentage;
+        transaction_costing_parameters.tip = TipBasisPoints::from_percentage(tip_percentage);
 
         SystemLoanFeeReserve::new(
             &costing_parameters,
@@ -746,4 +771,88 @@ mod tests {
             }),
         );
     }
+
+    #[test]
+    fn test_fee_payments_are_proportional_to_locked_fees() {
+        let mut fee_reserve =
+            create_test_fee_reserve(dec!(1), dec!(1), dec!(0), 0, 1000, 50, false);
+        fee_reserve.consume_execution(40).unwrap();
+        fee_reserve.lock_fee(TEST_VAULT_ID, xrd(100), false);
+        fee_reserve.lock_fee(TEST_VAULT_ID_2, xrd(300), false);
+        fee_reserve.repay_all().unwrap();
+        let summary = fee_reserve.finalize();
+        assert_eq!(summary.fee_payments(true), vec![dec!("10"), dec!("30")]);
+        assert_eq!(summary.fee_payments(false), vec![dec!("10"), dec!("30")]);
+    }
+
+    #[test]
+    fn test_fee_payments_rounding_remainder_goes_to_last_lock() {
+        let mut fee_reserve =
+            create_test_fee_reserve(dec!(1), dec!(1), dec!(0), 0, 1000, 50, false);
+        fee_reserve.consume_execution(1).unwrap();
+        fee_reserve.lock_fee(TEST_VAULT_ID, xrd(1), false);
+        fee_reserve.lock_fee(TEST_VAULT_ID_2, xrd(1), false);
+        fee_reserve.lock_fee(TEST_VAULT_ID, xrd(1), false);
+        fee_reserve.repay_all().unwrap();
+        let summary = fee_reserve.finalize();
+        assert_eq!(
+            summary.fee_payments(true),
+            vec![
+                dec!("0.333333333333333333"),
+                dec!("0.333333333333333333"),
+                dec!("0.333333333333333334")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_fee_payments_with_contingent_lock() {
+        let mut fee_reserve =
+            create_test_fee_reserve(dec!(1), dec!(1), dec!(0), 0, 1000, 50, false);
+        fee_reserve.consume_execution(45).unwrap();
+        fee_reserve.lock_fee(TEST_VAULT_ID, xrd(100), false);
+        fee_reserve.lock_fee(TEST_VAULT_ID_2, xrd(5), true);
+        fee_reserve.lock_fee(TEST_VAULT_ID_2, xrd(300), false);
+        fee_reserve.repay_all().unwrap();
+        let summary = fee_reserve.finalize();
+        assert_eq!(
+            summary.fee_payments(true),
+            vec![dec!("10"), dec!("5"), dec!("30")]
+        );
+        assert_eq!(
+            summary.fee_payments(false),
+            vec![dec!("11.25"), dec!("0"), dec!("33.75")]
+        );
+    }
+
+    #[test]
+    fn test_lock_fee_with_refund() {
+        let mut fee_reserve =
+            create_test_fee_reserve(dec!(1), dec!(1), dec!(0), 0, 1000, 50, false);
+        fee_reserve.consume_execution(40).unwrap();
+        fee_reserve.lock_fee(TEST_VAULT_ID, xrd(100), false);
+        fee_reserve.lock_fee_with_refund(TEST_VAULT_ID, xrd(300), false, TEST_VAULT_ID_2);
+        fee_reserve.repay_all().unwrap();
+        let summary = fee_reserve.finalize();
+        assert_eq!(summary.fee_payments(true), vec![dec!("10"), dec!("30")]);
+        assert_eq!(summary.refund_vault(0), TEST_VAULT_ID);
+        assert_eq!(summary.refund_vault(1), TEST_VAULT_ID_2);
+    }
+
+    #[test]
+    fn test_tip_percentage_rounds_down_basis_points() {
+        let mut transaction_costing_parameters = TransactionCostingParameters::default();
+        transaction_costing_parameters.tip = TipBasisPoints(250);
+        let fee_reserve = SystemLoanFeeReserve::new(
+            &CostingParameters::default(),
+            &transaction_costing_parameters,
+            false,
+        );
+        assert_eq!(fee_reserve.tip_percentage(), 2);
+        assert_eq!(fee_reserve.tip(), TipBasisPoints(250));
+
+        let fee_reserve = create_test_fee_reserve(dec!(1), dec!(1), dec!(0), 5, 100, 5, false);
+        assert_eq!(fee_reserve.tip_percentage(), 5);
+        assert_eq!(fee_reserve.tip(), TipBasisPoints(500));
+    }
 }
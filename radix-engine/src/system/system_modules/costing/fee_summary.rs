@@ -16,6 +16,10 @@ pub struct FeeReserveFinalizationSummary {
     pub total_finalization_cost_in_xrd: Decimal,
     /// The total cost for tipping
     pub total_tipping_cost_in_xrd: Decimal,
+    /// The execution cost unit price, with the tip applied
+    pub effective_execution_cost_unit_price: Decimal,
+    /// The finalization cost unit price, with the tip applied
+    pub effective_finalization_cost_unit_price: Decimal,
     /// The total cost for storage
     pub total_storage_cost_in_xrd: Decimal,
     /// The total cost for royalty
@@ -25,6 +29,9 @@ pub struct FeeReserveFinalizationSummary {
     pub total_bad_debt_in_xrd: Decimal,
     /// The vaults locked for XRD payment
     pub locked_fees: Vec<(NodeId, LiquidFungibleResource, bool)>,
+    /// The vaults the unused locked fees are refunded to, by index in `locked_fees`, when not
+    /// the locking vault
+    pub refund_vaults: BTreeMap<usize, NodeId>,
     /// The royalty cost breakdown
     pub royalty_cost_breakdown: IndexMap<RoyaltyRecipient, Decimal>,
 }
@@ -37,6 +44,14 @@ impl FeeReserveFinalizationSummary {
     // NOTE: Decimal arithmetic operation safe unwrap.
     // No chance to overflow considering current costing parameters
 
+    /// The vault the unused part of the `index`-th locked fee is refunded to.
+    pub fn refund_vault(&self, index: usize) -> NodeId {
+        match self.refund_vaults.get(&index) {
+            Some(refund_vault_id) => *refund_vault_id,
+            None => self.locked_fees[index].0,
+        }
+    }
+
     pub fn total_cost(&self) -> Decimal {
         self.total_execution_cost_in_xrd
             .checked_add(self.total_finalization_cost_in_xrd)
@@ -49,6 +64,70 @@ impl FeeReserveFinalizationSummary {
             .unwrap()
     }
 
+    /// Allocates the total cost to the locked fees, returning the amount to take from each lock,
+    /// in the same order as `locked_fees`. Whatever isn't taken is refunded to the
+    /// [refund vault](Self::refund_vault) of the lock.
+    ///
+    /// Contingent locks are only charged if the transaction succeeds, and are charged first
+    /// (most recently locked first). What remains is split between the non-contingent locks,
+    /// proportionally to their locked amounts. The rounding remainder of the split is charged
+    /// to the most recently locked fees, so that the allocation is deterministic.
+    ///
+    /// If the locked fees don't cover the total cost, all locks are charged in full.
+    pub fn fee_payments(&self, is_success: bool) -> Vec<Decimal> {
+        let mut payments = vec![Decimal::ZERO; self.locked_fees.len()];
+        let mut required = self.total_cost();
+
+        // Contingent locks go first
+        if is_success {
+            for (i, (_, locked, contingent)) in self.locked_fees.iter().enumerate().rev() {
+                if *contingent {
+                    let amount = Decimal::min(locked.amount(), required);
+                    payments[i] = amount;
+                    required = required.checked_sub(amount).unwrap();
+                }
+            }
+        }
+
+        // Split the rest proportionally between non-contingent locks
+        let total_locked = self
+            .locked_fees
+            .iter()
+            .filter(|(_, _, contingent)| !contingent)
+            .fold(Decimal::ZERO, |total, (_, locked, _)| {
+                total.checked_add(locked.amount()).unwrap()
+            });
+        if total_locked.is_zero() {
+            return payments;
+        }
+        let to_split = Decimal::min(total_locked, required);
+        let mut remainder = to_split;
+        for (i, (_, locked, contingent)) in self.locked_fees.iter().enumerate() {
+            if !*contingent {
+                let amount = to_split
+                    .checked_mul(locked.amount())
+                    .unwrap()
+                    .checked_div(total_locked)
+                    .unwrap();
+                payments[i] = amount;
+                remainder = remainder.checked_sub(amount).unwrap();
+            }
+        }
+        for (i, (_, locked, contingent)) in self.locked_fees.iter().enumerate().rev() {
+            if remainder.is_zero() {
+                break;
+            }
+            if !*contingent {
+                let amount =
+                    Decimal::min(locked.amount().checked_sub(payments[i]).unwrap(), remainder);
+                payments[i] = payments[i].checked_add(amount).unwrap();
+                remainder = remainder.checked_sub(amount).unwrap();
+            }
+        }
+
+        payments
+    }
+
     pub fn network_fees(&self) -> Decimal {
         self.total_execution_cost_in_xrd
             .checked_add(self.total_finalization_cost_in_xrd)
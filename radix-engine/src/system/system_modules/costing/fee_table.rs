@@ -5,9 +5,10 @@ use crate::kernel::kernel_callback_api::{
 };
 use crate::kernel::substate_io::SubstateDevice;
 use crate::system::actor::Actor;
-use crate::system::system_modules::transaction_runtime::Event;
+use crate::system::system_modules::transaction_runtime::{Event, LogRecord};
 use crate::{
     blueprints::package::*,
+    blueprints::resource::AUTH_ZONE_PUSH_COMPOSITE_EXPORT_NAME,
     track::interface::{IOAccess, StoreCommit},
     types::*,
 };
@@ -50,10 +51,292 @@ lazy_static! {
             .or_default()
             // TODO: publish_wasm_advanced is too expensive, dividing by 6 to let large package (1MiB) to be published, consider using cubic approximation
             .insert(PACKAGE_PUBLISH_WASM_ADVANCED_IDENT, (9063 / 6, 11072798));
+        costs
+            .entry(RESOURCE_PACKAGE)
+            .or_default()
+            // TODO: not benchmarked yet - the cost of `AuthZone_push` for the first proof, plus the
+            // per-byte cost of `publish_native` for decoding and re-encoding every further proof
+            .insert(AUTH_ZONE_PUSH_COMPOSITE_EXPORT_NAME, (875, 810902));
     costs
     };
 }
 
+/// The largest input a per-byte cost is expected to be applied to (the max transaction size).
+const MAX_COSTED_INPUT_SIZE: u32 = 1024 * 1024;
+
+/// The tunable costs of a [`FeeTable`].
+///
+/// The default config holds the costs of the Radix network. Networks with different hardware
+/// assumptions can adjust them, and create a fee table with [`FeeTable::from_config`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoSbor)]
+pub struct FeeTableConfig {
+    /// A flat cost charged once per transaction, on top of its payload cost. Zero by default.
+    pub tx_base_cost: u32,
+    /// The cost of verifying a single transaction signature.
+    pub verify_tx_signature_cost: u32,
+    /// The cost per byte of transaction payload.
+    pub tx_payload_cost_per_byte: u32,
+    /// The cost per byte of data encoded, decoded or validated by the engine.
+    pub data_processing_cost_per_byte: u32,
+    /// The cost per byte of WASM code instantiated.
+    pub instantiate_wasm_code_cost_per_byte: u32,
+    /// The number of WASM execution units which make up one cost unit.
+    pub wasm_execution_units_per_cost_unit: u32,
+    /// The number of CPU instructions which make up one cost unit, used for costing native code
+    /// and kernel API calls.
+    pub cpu_instructions_per_cost_unit: u32,
+    /// The base cost of a system API call, such as locking fee or emitting an event.
+    pub system_api_call_cost: u32,
+    /// The base costs of kernel API calls, in CPU instructions.
+    pub kernel: KernelCostConfig,
+    /// The costs of accessing the substate database.
+    pub io: IoCostConfig,
+    /// The costs of committing the results of a transaction.
+    pub finalization: FinalizationCostConfig,
+}
+
+impl Default for FeeTableConfig {
+    fn default() -> Self {
+        Self {
+            tx_base_cost: 0,
+            verify_tx_signature_cost: 7_000,
+            tx_payload_cost_per_byte: 40,
+            data_processing_cost_per_byte: 2,
+            instantiate_wasm_code_cost_per_byte: 2,
+            wasm_execution_units_per_cost_unit: 3_000,
+            cpu_instructions_per_cost_unit: CPU_INSTRUCTIONS_TO_COST_UNIT,
+            system_api_call_cost: 500,
+            kernel: KernelCostConfig::default(),
+            io: IoCostConfig::default(),
+            finalization: FinalizationCostConfig::default(),
+        }
+    }
+}
+
+/// The base costs of kernel API calls, in CPU instructions.
+///
+/// Data processing and database access are costed on top of these.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoSbor)]
+pub struct KernelCostConfig {
+    pub allocate_node_id: u32,
+    pub create_node: u32,
+    pub pin_node: u32,
+    pub drop_node: u32,
+    pub move_module: u32,
+    pub open_substate: u32,
+    pub read_substate_from_heap: u32,
+    pub read_substate_from_store: u32,
+    pub write_substate: u32,
+    pub close_substate: u32,
+    pub set_substate: u32,
+    pub remove_substate: u32,
+    pub mark_substate_as_transient: u32,
+    pub scan_keys: u32,
+    pub drain_substates_base: u32,
+    pub drain_substates_per_substate: u32,
+    pub scan_sorted_substates: u32,
+}
+
+impl Default for KernelCostConfig {
+    fn default() -> Self {
+        Self {
+            allocate_node_id: 3312,
+            create_node: 15510,
+            pin_node: 424,
+            drop_node: 38883,
+            move_module: 4791,
+            open_substate: 10318,
+            read_substate_from_heap: 2234,
+            read_substate_from_store: 3868,
+            write_substate: 7441,
+            close_substate: 4390,
+            set_substate: 4530,
+            remove_substate: 24389,
+            mark_substate_as_transient: 1896,
+            scan_keys: 16938,
+            drain_substates_base: 9262,
+            drain_substates_per_substate: 9286,
+            scan_sorted_substates: 6369,
+        }
+    }
+}
+
+/// The costs of accessing the substate database, in cost units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoSbor)]
+pub struct IoCostConfig {
+    /// The base cost of reading a substate which exists.
+    pub read_base_cost: u32,
+    /// The number of bytes read which make up one cost unit.
+    pub read_bytes_per_cost_unit: u32,
+    /// The cost of reading a substate which doesn't exist.
+    pub read_not_found_cost: u32,
+}
+
+impl Default for IoCostConfig {
+    fn default() -> Self {
+        // See: https://radixdlt.atlassian.net/wiki/spaces/S/pages/3091562563/RocksDB+metrics
+        Self {
+            read_base_cost: 40_000,
+            read_bytes_per_cost_unit: 10,
+            read_not_found_cost: 160_000,
+        }
+    }
+}
+
+/// The costs of committing the results of a transaction, in finalization cost units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoSbor)]
+pub struct FinalizationCostConfig {
+    /// The base cost of committing a single substate update.
+    pub commit_state_update_cost: u32,
+    /// The base cost of committing a single event.
+    pub commit_event_cost: u32,
+    /// The base cost of committing a single log.
+    pub commit_log_cost: u32,
+    /// The number of bytes committed which make up one cost unit.
+    pub commit_bytes_per_cost_unit: u32,
+}
+
+impl Default for FinalizationCostConfig {
+    fn default() -> Self {
+        // See: https://radixdlt.atlassian.net/wiki/spaces/S/pages/3091562563/RocksDB+metrics
+        Self {
+            commit_state_update_cost: 100_000,
+            commit_event_cost: 5_000,
+            commit_log_cost: 1_000,
+            commit_bytes_per_cost_unit: 4,
+        }
+    }
+}
+
+/// Presets for the WASM costs of a [`FeeTableConfig`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WasmCostPreset {
+    /// The WASM costs of the Radix network, measured with the default WASM engine.
+    Mainnet,
+    /// WASM execution and instantiation are (almost) free, e.g. for private test networks
+    /// where blueprints are trusted. Their native and kernel costs still apply.
+    Subsidized,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeeTableConfigError {
+    ZeroEntry(&'static str),
+    OverflowingEntry(&'static str),
+}
+
+impl FeeTableConfig {
+    /// Replaces the WASM costs of this config with those of the given preset.
+    pub fn with_wasm_preset(mut self, preset: WasmCostPreset) -> Self {
+        let (wasm_execution_units_per_cost_unit, instantiate_wasm_code_cost_per_byte) = match preset
+        {
+            WasmCostPreset::Mainnet => {
+                let default = Self::default();
+                (
+                    default.wasm_execution_units_per_cost_unit,
+                    default.instantiate_wasm_code_cost_per_byte,
+                )
+            }
+            WasmCostPreset::Subsidized => (u32::MAX, 1),
+        };
+        self.wasm_execution_units_per_cost_unit = wasm_execution_units_per_cost_unit;
+        self.instantiate_wasm_code_cost_per_byte = instantiate_wasm_code_cost_per_byte;
+        self
+    }
+
+    /// Checks that all entries (other than `tx_base_cost`) are non-zero, and that no per-byte
+    /// cost overflows when applied to a max size input.
+    pub fn validate(&self) -> Result<(), FeeTableConfigError> {
+        let per_byte_costs = [
+            ("tx_payload_cost_per_byte", self.tx_payload_cost_per_byte),
+            (
+                "data_processing_cost_per_byte",
+                self.data_processing_cost_per_byte,
+            ),
+            (
+                "instantiate_wasm_code_cost_per_byte",
+                self.instantiate_wasm_code_cost_per_byte,
+            ),
+        ];
+        let kernel = &self.kernel;
+        let other_entries = [
+            ("verify_tx_signature_cost", self.verify_tx_signature_cost),
+            (
+                "wasm_execution_units_per_cost_unit",
+                self.wasm_execution_units_per_cost_unit,
+            ),
+            (
+                "cpu_instructions_per_cost_unit",
+                self.cpu_instructions_per_cost_unit,
+            ),
+            ("system_api_call_cost", self.system_api_call_cost),
+            ("kernel.allocate_node_id", kernel.allocate_node_id),
+            ("kernel.create_node", kernel.create_node),
+            ("kernel.pin_node", kernel.pin_node),
+            ("kernel.drop_node", kernel.drop_node),
+            ("kernel.move_module", kernel.move_module),
+            ("kernel.open_substate", kernel.open_substate),
+            (
+                "kernel.read_substate_from_heap",
+                kernel.read_substate_from_heap,
+            ),
+            (
+                "kernel.read_substate_from_store",
+                kernel.read_substate_from_store,
+            ),
+            ("kernel.write_substate", kernel.write_substate),
+            ("kernel.close_substate", kernel.close_substate),
+            ("kernel.set_substate", kernel.set_substate),
+            ("kernel.remove_substate", kernel.remove_substate),
+            (
+                "kernel.mark_substate_as_transient",
+                kernel.mark_substate_as_transient,
+            ),
+            ("kernel.scan_keys", kernel.scan_keys),
+            ("kernel.drain_substates_base", kernel.drain_substates_base),
+            (
+                "kernel.drain_substates_per_substate",
+                kernel.drain_substates_per_substate,
+            ),
+            ("kernel.scan_sorted_substates", kernel.scan_sorted_substates),
+            ("io.read_base_cost", self.io.read_base_cost),
+            (
+                "io.read_bytes_per_cost_unit",
+                self.io.read_bytes_per_cost_unit,
+            ),
+            ("io.read_not_found_cost", self.io.read_not_found_cost),
+            (
+                "finalization.commit_state_update_cost",
+                self.finalization.commit_state_update_cost,
+            ),
+            (
+                "finalization.commit_event_cost",
+                self.finalization.commit_event_cost,
+            ),
+            (
+                "finalization.commit_log_cost",
+                self.finalization.commit_log_cost,
+            ),
+            (
+                "finalization.commit_bytes_per_cost_unit",
+                self.finalization.commit_bytes_per_cost_unit,
+            ),
+        ];
+
+        for (name, value) in per_byte_costs.iter().chain(other_entries.iter()) {
+            if *value == 0 {
+                return Err(FeeTableConfigError::ZeroEntry(*name));
+            }
+        }
+        for (name, value) in per_byte_costs {
+            if value.checked_mul(MAX_COSTED_INPUT_SIZE).is_none() {
+                return Err(FeeTableConfigError::OverflowingEntry(name));
+            }
+        }
+
+        Ok(())
+    }
+}
+
 /// Fee table specifies how each costing entry should be costed.
 ///
 /// ## High Level Guideline
@@ -64,25 +347,39 @@ lazy_static! {
 /// - Baseline: 1 microsecond = 100 cost units
 ///
 #[derive(Debug, Clone, ScryptoSbor)]
-pub struct FeeTable;
+pub struct FeeTable {
+    config: FeeTableConfig,
+}
 
 impl FeeTable {
     pub fn new() -> Self {
-        Self
+        Self {
+            config: FeeTableConfig::default(),
+        }
+    }
+
+    /// Creates a fee table with custom costs, e.g. for a private network.
+    pub fn from_config(config: FeeTableConfig) -> Result<Self, FeeTableConfigError> {
+        config.validate()?;
+        Ok(Self { config })
+    }
+
+    pub fn config(&self) -> &FeeTableConfig {
+        &self.config
     }
 
     //======================
     // Execution costs
     //======================
 
-    fn data_processing_cost(size: usize) -> u32 {
+    fn data_processing_cost(&self, size: usize) -> u32 {
         // Based on benchmark `bench_decode_sbor`
         // Time for processing a byte: 10.244 µs / 1068 = 0.00959176029
 
         // Based on benchmark `bench_validate_sbor_payload`
         // Time for processing a byte: 10.075 µs / 1169 = 0.00861847733
 
-        mul(cast(size), 2)
+        mul(cast(size), self.config.data_processing_cost_per_byte)
     }
 
     fn io_access_cost(&self, io_access: &IOAccess) -> u32 {
@@ -91,13 +388,16 @@ impl FeeTable {
                 // Execution time (µs): 0.0009622109 * size + 389.5155
                 // Execution cost: (0.0009622109 * size + 389.5155) * 100 = 0.1 * size + 40,000
                 // See: https://radixdlt.atlassian.net/wiki/spaces/S/pages/3091562563/RocksDB+metrics
-                add(cast(*size) / 10, 40_000)
+                add(
+                    cast(*size) / self.config.io.read_bytes_per_cost_unit,
+                    self.config.io.read_base_cost,
+                )
             }
             IOAccess::ReadFromDbNotFound(_) => {
                 // Execution time (µs): varies, using max 1,600
                 // Execution cost: 1,600 * 100
                 // See: https://radixdlt.atlassian.net/wiki/spaces/S/pages/3091562563/RocksDB+metrics
-                160_000
+                self.config.io.read_not_found_cost
             }
             IOAccess::HeapSubstateUpdated { .. } | IOAccess::TrackSubstateUpdated { .. } => {
                 // Heap/track substate total size is limited by limits module.
@@ -110,7 +410,7 @@ impl FeeTable {
     pub fn verify_tx_signatures_cost(&self, n: usize) -> u32 {
         // Based on benchmark `bench_validate_secp256k1`
         // The cost for validating a single signature is: 67.522 µs * 100 units/µs = 7,000 cost units
-        mul(cast(n), 7_000)
+        mul(cast(n), self.config.verify_tx_signature_cost)
     }
 
     #[inline]
@@ -121,7 +421,10 @@ impl FeeTable {
         // The size of a typical transfer transaction is 400 bytes, and the cost will be 400 * 40 = 16,000 cost units
         // The max size of a transaction is 1 MiB, and the cost will be 1,048,576 * 40 = 41,943,040 cost units
         // This is roughly 1/24 of storing data in substate store per current setup.
-        mul(cast(size), 40)
+        add(
+            self.config.tx_base_cost,
+            mul(cast(size), self.config.tx_payload_cost_per_byte),
+        )
     }
 
     #[inline]
@@ -145,7 +448,7 @@ impl FeeTable {
                     ))
             });
 
-        native_execution_units / CPU_INSTRUCTIONS_TO_COST_UNIT
+        native_execution_units / self.config.cpu_instructions_per_cost_unit
     }
 
     #[inline]
@@ -158,7 +461,7 @@ impl FeeTable {
         // From `costing::spin_loop`, it takes 5.5391 ms for 1918122691 wasm execution units.
         // Therefore, cost for single unit: 5.5391 *  1000 / 1918122691 * 100 = 0.00028877714
 
-        wasm_execution_units / 3000
+        wasm_execution_units / self.config.wasm_execution_units_per_cost_unit
     }
 
     #[inline]
@@ -166,22 +469,22 @@ impl FeeTable {
         // From `costing::instantiate_radiswap`, it takes 3.3271 ms to instantiate WASM of length 288406.
         // Therefore, cost for byte: 3.3271 *  1000 / 203950 * 100 = 1.63133120863
 
-        mul(cast(size), 2)
+        mul(cast(size), self.config.instantiate_wasm_code_cost_per_byte)
     }
 
     #[inline]
     pub fn before_invoke_cost(&self, _actor: &Actor, input_size: usize) -> u32 {
-        Self::data_processing_cost(input_size)
+        self.data_processing_cost(input_size)
     }
 
     #[inline]
     pub fn after_invoke_cost(&self, input_size: usize) -> u32 {
-        Self::data_processing_cost(input_size)
+        self.data_processing_cost(input_size)
     }
 
     #[inline]
     pub fn allocate_node_id_cost(&self) -> u32 {
-        3312 / CPU_INSTRUCTIONS_TO_COST_UNIT
+        self.config.kernel.allocate_node_id / self.config.cpu_instructions_per_cost_unit
     }
 
     #[inline]
@@ -193,8 +496,8 @@ impl FeeTable {
                     .map(|x| x.values().map(|x| x.len()).sum::<usize>())
                     .sum::<usize>();
                 add(
-                    15510 / CPU_INSTRUCTIONS_TO_COST_UNIT,
-                    Self::data_processing_cost(total_substate_size),
+                    self.config.kernel.create_node / self.config.cpu_instructions_per_cost_unit,
+                    self.data_processing_cost(total_substate_size),
                 )
             }
             CreateNodeEvent::IOAccess(io_access) => self.io_access_cost(io_access),
@@ -204,7 +507,7 @@ impl FeeTable {
 
     #[inline]
     pub fn pin_node_cost(&self, _node_id: &NodeId) -> u32 {
-        424 / CPU_INSTRUCTIONS_TO_COST_UNIT
+        self.config.kernel.pin_node / self.config.cpu_instructions_per_cost_unit
     }
 
     #[inline]
@@ -218,8 +521,8 @@ impl FeeTable {
                     .map(|x| x.values().map(|x| x.len()).sum::<usize>())
                     .sum::<usize>();
                 add(
-                    38883 / CPU_INSTRUCTIONS_TO_COST_UNIT,
-                    Self::data_processing_cost(total_substate_size),
+                    self.config.kernel.drop_node / self.config.cpu_instructions_per_cost_unit,
+                    self.data_processing_cost(total_substate_size),
                 )
             }
         }
@@ -229,7 +532,7 @@ impl FeeTable {
     pub fn move_module_cost(&self, event: &MoveModuleEvent) -> u32 {
         match event {
             MoveModuleEvent::IOAccess(io_access) => add(
-                4791 / CPU_INSTRUCTIONS_TO_COST_UNIT,
+                self.config.kernel.move_module / self.config.cpu_instructions_per_cost_unit,
                 self.io_access_cost(io_access),
             ),
         }
@@ -241,8 +544,8 @@ impl FeeTable {
             OpenSubstateEvent::Start { .. } => 0,
             OpenSubstateEvent::IOAccess(io_access) => self.io_access_cost(io_access),
             OpenSubstateEvent::End { size, .. } => add(
-                10318 / CPU_INSTRUCTIONS_TO_COST_UNIT,
-                Self::data_processing_cost(*size),
+                self.config.kernel.open_substate / self.config.cpu_instructions_per_cost_unit,
+                self.data_processing_cost(*size),
             ),
         }
     }
@@ -252,13 +555,13 @@ impl FeeTable {
         match event {
             ReadSubstateEvent::OnRead { value, device, .. } => {
                 let base_cost: u32 = match device {
-                    SubstateDevice::Heap => 2234,
-                    SubstateDevice::Store => 3868,
+                    SubstateDevice::Heap => self.config.kernel.read_substate_from_heap,
+                    SubstateDevice::Store => self.config.kernel.read_substate_from_store,
                 };
 
                 add(
-                    base_cost / CPU_INSTRUCTIONS_TO_COST_UNIT,
-                    Self::data_processing_cost(value.len()),
+                    base_cost / self.config.cpu_instructions_per_cost_unit,
+                    self.data_processing_cost(value.len()),
                 )
             }
             ReadSubstateEvent::IOAccess(io_access) => self.io_access_cost(io_access),
@@ -270,8 +573,8 @@ impl FeeTable {
         match event {
             WriteSubstateEvent::IOAccess(io_access) => self.io_access_cost(io_access),
             WriteSubstateEvent::Start { value, .. } => add(
-                7441 / CPU_INSTRUCTIONS_TO_COST_UNIT,
-                Self::data_processing_cost(value.len()),
+                self.config.kernel.write_substate / self.config.cpu_instructions_per_cost_unit,
+                self.data_processing_cost(value.len()),
             ),
         }
     }
@@ -279,7 +582,9 @@ impl FeeTable {
     #[inline]
     pub fn close_substate_cost(&self, event: &CloseSubstateEvent) -> u32 {
         match event {
-            CloseSubstateEvent::Start(..) => 4390 / CPU_INSTRUCTIONS_TO_COST_UNIT,
+            CloseSubstateEvent::Start(..) => {
+                self.config.kernel.close_substate / self.config.cpu_instructions_per_cost_unit
+            }
         }
     }
 
@@ -287,8 +592,8 @@ impl FeeTable {
     pub fn set_substate_cost(&self, event: &SetSubstateEvent) -> u32 {
         match event {
             SetSubstateEvent::Start(.., value) => add(
-                4530 / CPU_INSTRUCTIONS_TO_COST_UNIT,
-                Self::data_processing_cost(value.len()),
+                self.config.kernel.set_substate / self.config.cpu_instructions_per_cost_unit,
+                self.data_processing_cost(value.len()),
             ),
             SetSubstateEvent::IOAccess(io_access) => self.io_access_cost(io_access),
         }
@@ -297,7 +602,9 @@ impl FeeTable {
     #[inline]
     pub fn remove_substate_cost(&self, event: &RemoveSubstateEvent) -> u32 {
         match event {
-            RemoveSubstateEvent::Start(..) => 24389 / CPU_INSTRUCTIONS_TO_COST_UNIT,
+            RemoveSubstateEvent::Start(..) => {
+                self.config.kernel.remove_substate / self.config.cpu_instructions_per_cost_unit
+            }
             RemoveSubstateEvent::IOAccess(io_access) => self.io_access_cost(io_access),
         }
     }
@@ -309,13 +616,15 @@ impl FeeTable {
         _partition_number: &PartitionNumber,
         _substate_key: &SubstateKey,
     ) -> u32 {
-        1896 / CPU_INSTRUCTIONS_TO_COST_UNIT
+        self.config.kernel.mark_substate_as_transient / self.config.cpu_instructions_per_cost_unit
     }
 
     #[inline]
     pub fn scan_keys_cost(&self, event: &ScanKeysEvent) -> u32 {
         match event {
-            ScanKeysEvent::Start => 16938 / CPU_INSTRUCTIONS_TO_COST_UNIT,
+            ScanKeysEvent::Start => {
+                self.config.kernel.scan_keys / self.config.cpu_instructions_per_cost_unit
+            }
             ScanKeysEvent::IOAccess(io_access) => self.io_access_cost(io_access),
         }
     }
@@ -324,8 +633,11 @@ impl FeeTable {
     pub fn drain_substates_cost(&self, event: &DrainSubstatesEvent) -> u32 {
         match event {
             DrainSubstatesEvent::Start(count) => {
-                let cpu_instructions = add(9262, mul(9286, *count));
-                cpu_instructions / CPU_INSTRUCTIONS_TO_COST_UNIT
+                let cpu_instructions = add(
+                    self.config.kernel.drain_substates_base,
+                    mul(self.config.kernel.drain_substates_per_substate, *count),
+                );
+                cpu_instructions / self.config.cpu_instructions_per_cost_unit
             }
             DrainSubstatesEvent::IOAccess(io_access) => self.io_access_cost(io_access),
         }
@@ -334,49 +646,109 @@ impl FeeTable {
     #[inline]
     pub fn scan_sorted_substates_cost(&self, event: &ScanSortedSubstatesEvent) -> u32 {
         match event {
-            ScanSortedSubstatesEvent::Start => 6369 / CPU_INSTRUCTIONS_TO_COST_UNIT,
+            ScanSortedSubstatesEvent::Start => {
+                self.config.kernel.scan_sorted_substates
+                    / self.config.cpu_instructions_per_cost_unit
+            }
             ScanSortedSubstatesEvent::IOAccess(io_access) => self.io_access_cost(io_access),
         }
     }
 
     #[inline]
     pub fn lock_fee_cost(&self) -> u32 {
-        500
+        self.config.system_api_call_cost
     }
 
     #[inline]
     pub fn query_fee_reserve_cost(&self) -> u32 {
-        500
+        self.config.system_api_call_cost
     }
 
     #[inline]
     pub fn query_actor_cost(&self) -> u32 {
-        500
+        self.config.system_api_call_cost
     }
 
     #[inline]
     pub fn query_transaction_hash_cost(&self) -> u32 {
-        500
+        self.config.system_api_call_cost
     }
 
     #[inline]
     pub fn generate_ruid_cost(&self) -> u32 {
-        500
+        self.config.system_api_call_cost
+    }
+
+    #[inline]
+    pub fn generate_ruid_seeded_cost(&self, size: usize) -> u32 {
+        add(
+            self.config.system_api_call_cost,
+            self.data_processing_cost(size),
+        )
     }
 
     #[inline]
     pub fn emit_event_cost(&self, size: usize) -> u32 {
-        500 + Self::data_processing_cost(size)
+        add(
+            self.config.system_api_call_cost,
+            self.data_processing_cost(size),
+        )
     }
 
     #[inline]
     pub fn emit_log_cost(&self, size: usize) -> u32 {
-        500 + Self::data_processing_cost(size)
+        add(
+            self.config.system_api_call_cost,
+            self.data_processing_cost(size),
+        )
     }
 
     #[inline]
     pub fn panic_cost(&self, size: usize) -> u32 {
-        500 + Self::data_processing_cost(size)
+        add(
+            self.config.system_api_call_cost,
+            self.data_processing_cost(size),
+        )
+    }
+
+    #[inline]
+    pub fn ed25519_verify_cost(&self, size: usize) -> u32 {
+        // Same order of magnitude as a Secp256k1 verification, plus hashing the message
+        add(
+            self.config.verify_tx_signature_cost,
+            self.data_processing_cost(size),
+        )
+    }
+
+    #[inline]
+    pub fn secp256k1_verify_cost(&self, size: usize) -> u32 {
+        add(
+            self.config.verify_tx_signature_cost,
+            self.data_processing_cost(size),
+        )
+    }
+
+    #[inline]
+    pub fn bls12381_v1_verify_cost(&self, size: usize) -> u32 {
+        // Based on `blst` benchmarks, verifying a single signature (which includes two pairings
+        // and hashing the message to the G2 curve) takes about 1.1 ms = 110,000 cost units
+        add(110_000, self.data_processing_cost(size))
+    }
+
+    #[inline]
+    pub fn keccak256_hash_cost(&self, size: usize) -> u32 {
+        add(
+            self.config.system_api_call_cost,
+            self.data_processing_cost(size),
+        )
+    }
+
+    #[inline]
+    pub fn blake2b_256_hash_cost(&self, size: usize) -> u32 {
+        add(
+            self.config.system_api_call_cost,
+            self.data_processing_cost(size),
+        )
     }
 
     //======================
@@ -389,27 +761,38 @@ impl FeeTable {
         // Committing state time (µs): 0.0025 * size + 1000
         // Finalization cost: (0.0025 * size + 1000) * 100 = 0.25 * size + 100,000
         // See: https://radixdlt.atlassian.net/wiki/spaces/S/pages/3091562563/RocksDB+metrics
+        let config = &self.config.finalization;
         match store_commit {
-            StoreCommit::Insert { size, .. } => add(cast(*size) / 4, 100_000),
-            StoreCommit::Update { size, .. } => add(cast(*size) / 4, 100_000),
-            StoreCommit::Delete { .. } => 100_000,
+            StoreCommit::Insert { size, .. } | StoreCommit::Update { size, .. } => add(
+                cast(*size) / config.commit_bytes_per_cost_unit,
+                config.commit_state_update_cost,
+            ),
+            StoreCommit::Delete { .. } => config.commit_state_update_cost,
         }
     }
 
     #[inline]
     pub fn commit_events_cost(&self, events: &Vec<Event>) -> u32 {
+        let config = &self.config.finalization;
         let mut sum = 0;
         for event in events {
-            sum += add(cast(event.payload.len()) / 4, 5_000)
+            sum += add(
+                cast(event.payload.len()) / config.commit_bytes_per_cost_unit,
+                config.commit_event_cost,
+            )
         }
         sum
     }
 
     #[inline]
-    pub fn commit_logs_cost(&self, logs: &Vec<(Level, String)>) -> u32 {
+    pub fn commit_logs_cost(&self, logs: &Vec<LogRecord>) -> u32 {
+        let config = &self.config.finalization;
         let mut sum = 0;
         for log in logs {
-            sum += add(cast(log.1.len()) / 4, 1_000)
+            sum += add(
+                cast(log.len()) / config.commit_bytes_per_cost_unit,
+                config.commit_log_cost,
+            )
         }
         sum
     }
@@ -0,0 +1,86 @@
+use super::{ExecutionTrace, TraceOrigin};
+use crate::transaction::TransactionExecutionTrace;
+use crate::types::*;
+use sbor::rust::fmt::Write;
+
+impl TransactionExecutionTrace {
+    /// Exports the traced kernel calls in the Chrome trace-event JSON format, which can be loaded
+    /// into `chrome://tracing`, Perfetto or any other tool understanding that format.
+    ///
+    /// Each traced call becomes a complete (`"X"`) event. As the engine is deterministic, time is
+    /// measured in execution cost units rather than wall-clock time: a call lasts as many
+    /// "microseconds" as the cost units it consumed, and its children are laid out one after
+    /// another from the start of their parent. The cost units, substate IO counts and manifest
+    /// instruction index of each call are attached as event args.
+    ///
+    /// Note that only the calls kept by the execution trace module are exported, i.e. the calls
+    /// which move resources, and their ancestors.
+    pub fn to_chrome_trace_json(&self) -> String {
+        let mut events = Vec::new();
+        let mut start = 0u64;
+        for trace in &self.execution_traces {
+            add_chrome_trace_events(trace, start, &mut events);
+            start += trace.execution_cost_units_consumed as u64;
+        }
+
+        format!("{{\"traceEvents\":[{}]}}", events.join(","))
+    }
+}
+
+fn add_chrome_trace_events(trace: &ExecutionTrace, start: u64, events: &mut Vec<String>) {
+    let (name, category) = match &trace.origin {
+        TraceOrigin::ScryptoFunction(fn_identifier) => (
+            format!(
+                "{}::{}",
+                fn_identifier.blueprint_id.blueprint_name, fn_identifier.ident
+            ),
+            "function",
+        ),
+        TraceOrigin::ScryptoMethod(fn_identifier) => (
+            format!(
+                "{}::{}",
+                fn_identifier.blueprint_id.blueprint_name, fn_identifier.ident
+            ),
+            "method",
+        ),
+        TraceOrigin::CreateNode => ("create_node".to_string(), "kernel"),
+        TraceOrigin::DropNode => ("drop_node".to_string(), "kernel"),
+    };
+
+    let mut event = String::new();
+    write!(
+        event,
+        "{{\"name\":\"{}\",\"cat\":\"{}\",\"ph\":\"X\",\"ts\":{},\"dur\":{},\"pid\":1,\"tid\":1,\
+        \"args\":{{\"execution_cost_units\":{},\"substate_reads\":{},\"substate_writes\":{},\
+        \"instruction_index\":{}}}}}",
+        escape_json_string(&name),
+        category,
+        start,
+        trace.execution_cost_units_consumed,
+        trace.execution_cost_units_consumed,
+        trace.substate_io_counts.reads,
+        trace.substate_io_counts.writes,
+        trace.instruction_index,
+    )
+    .unwrap();
+    events.push(event);
+
+    let mut child_start = start;
+    for child in &trace.children {
+        add_chrome_trace_events(child, child_start, events);
+        child_start += child.execution_cost_units_consumed as u64;
+    }
+}
+
+fn escape_json_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if (c as u32) < 0x20 => write!(escaped, "\\u{:04x}", c as u32).unwrap(),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,178 @@
+use super::*;
+use crate::transaction::{FeeLocks, TransactionExecutionTrace};
+use crate::types::*;
+use sbor::rust::collections::*;
+
+/// The divergences between two execution traces (e.g. of the same transaction, executed by two
+/// different engine versions).
+///
+/// Kernel calls are located by their path in the invocation tree, i.e. the indices of the traces
+/// leading to them, starting from the top-level traces.
+#[derive(Debug, Clone, Default, ScryptoSbor)]
+pub struct TraceDiff {
+    pub divergences: Vec<TraceDivergence>,
+}
+
+#[derive(Debug, Clone, ScryptoSbor)]
+pub enum TraceDivergence {
+    /// The resource changes of an instruction differ.
+    ResourceChanges {
+        instruction_index: usize,
+        a: Vec<ResourceChange>,
+        b: Vec<ResourceChange>,
+    },
+    FeeLocks {
+        a: FeeLocks,
+        b: FeeLocks,
+    },
+    /// The kernel calls at the given path have a different number of children.
+    /// Only the children they have in common are compared.
+    ChildCount {
+        path: Vec<usize>,
+        a: usize,
+        b: usize,
+    },
+    /// The kernel calls at the given path have a different origin.
+    /// Their inputs, outputs, costs and children aren't compared.
+    Origin {
+        path: Vec<usize>,
+        a: TraceOrigin,
+        b: TraceOrigin,
+    },
+    /// The buckets or proofs passed into the kernel calls at the given path differ.
+    Input {
+        path: Vec<usize>,
+        a: ResourceSummary,
+        b: ResourceSummary,
+    },
+    /// The buckets or proofs returned from the kernel calls at the given path differ.
+    Output {
+        path: Vec<usize>,
+        a: ResourceSummary,
+        b: ResourceSummary,
+    },
+    /// The execution cost units consumed by the kernel calls at the given path differ.
+    ExecutionCost {
+        path: Vec<usize>,
+        a: u32,
+        b: u32,
+    },
+}
+
+impl TraceDiff {
+    pub fn diff(trace_a: &TransactionExecutionTrace, trace_b: &TransactionExecutionTrace) -> Self {
+        let mut diff = Self::default();
+
+        let mut instruction_indices: IndexSet<usize> =
+            trace_a.resource_changes.keys().cloned().collect();
+        instruction_indices.extend(trace_b.resource_changes.keys().cloned());
+        for instruction_index in instruction_indices {
+            let a = trace_a
+                .resource_changes
+                .get(&instruction_index)
+                .cloned()
+                .unwrap_or_default();
+            let b = trace_b
+                .resource_changes
+                .get(&instruction_index)
+                .cloned()
+                .unwrap_or_default();
+            if a != b {
+                diff.divergences.push(TraceDivergence::ResourceChanges {
+                    instruction_index,
+                    a,
+                    b,
+                });
+            }
+        }
+
+        if trace_a.fee_locks != trace_b.fee_locks {
+            diff.divergences.push(TraceDivergence::FeeLocks {
+                a: trace_a.fee_locks.clone(),
+                b: trace_b.fee_locks.clone(),
+            });
+        }
+
+        diff.diff_execution_traces(
+            &mut Vec::new(),
+            &trace_a.execution_traces,
+            &trace_b.execution_traces,
+        );
+
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.divergences.is_empty()
+    }
+
+    fn diff_execution_traces(
+        &mut self,
+        path: &mut Vec<usize>,
+        traces_a: &[ExecutionTrace],
+        traces_b: &[ExecutionTrace],
+    ) {
+        if traces_a.len() != traces_b.len() {
+            self.divergences.push(TraceDivergence::ChildCount {
+                path: path.clone(),
+                a: traces_a.len(),
+                b: traces_b.len(),
+            });
+        }
+
+        for (index, (a, b)) in traces_a.iter().zip(traces_b.iter()).enumerate() {
+            path.push(index);
+            self.diff_execution_trace(path, a, b);
+            path.pop();
+        }
+    }
+
+    fn diff_execution_trace(
+        &mut self,
+        path: &mut Vec<usize>,
+        a: &ExecutionTrace,
+        b: &ExecutionTrace,
+    ) {
+        if a.origin != b.origin {
+            self.divergences.push(TraceDivergence::Origin {
+                path: path.clone(),
+                a: a.origin.clone(),
+                b: b.origin.clone(),
+            });
+            return;
+        }
+
+        if !a.input.has_same_resources(&b.input) {
+            self.divergences.push(TraceDivergence::Input {
+                path: path.clone(),
+                a: a.input.clone(),
+                b: b.input.clone(),
+            });
+        }
+        if !a.output.has_same_resources(&b.output) {
+            self.divergences.push(TraceDivergence::Output {
+                path: path.clone(),
+                a: a.output.clone(),
+                b: b.output.clone(),
+            });
+        }
+        if a.execution_cost_units_consumed != b.execution_cost_units_consumed {
+            self.divergences.push(TraceDivergence::ExecutionCost {
+                path: path.clone(),
+                a: a.execution_cost_units_consumed,
+                b: b.execution_cost_units_consumed,
+            });
+        }
+
+        self.diff_execution_traces(path, &a.children, &b.children);
+    }
+}
+
+impl ResourceSummary {
+    /// Whether both summaries hold the same buckets and proofs contents, in the same order.
+    /// The IDs of the bucket and proof nodes themselves are ignored.
+    pub fn has_same_resources(&self, other: &ResourceSummary) -> bool {
+        self.buckets.values().eq(other.buckets.values())
+            && self.proofs.values().eq(other.proofs.values())
+    }
+}
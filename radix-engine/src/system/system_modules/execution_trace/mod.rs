@@ -1,3 +1,6 @@
+mod chrome_trace;
+mod diff;
 mod module;
 
+pub use diff::*;
 pub use module::*;
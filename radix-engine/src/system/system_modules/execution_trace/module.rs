@@ -2,12 +2,15 @@ use crate::blueprints::resource::VaultUtil;
 use crate::errors::*;
 use crate::kernel::call_frame::CallFrameMessage;
 use crate::kernel::kernel_api::{KernelApi, KernelInternalApi, KernelInvocation};
-use crate::kernel::kernel_callback_api::{CreateNodeEvent, DropNodeEvent, KernelCallbackObject};
+use crate::kernel::kernel_callback_api::{
+    CreateNodeEvent, DropNodeEvent, KernelCallbackObject, ReadSubstateEvent, RemoveSubstateEvent,
+    SetSubstateEvent, WriteSubstateEvent,
+};
 use crate::system::actor::{Actor, FunctionActor, MethodActor};
 use crate::system::module::SystemModule;
 use crate::system::system_callback::SystemConfig;
 use crate::system::system_callback_api::SystemCallbackObject;
-use crate::transaction::{FeeLocks, TransactionExecutionTrace};
+use crate::transaction::{BalanceChange, FeeLocks, TransactionExecutionTrace};
 use crate::types::*;
 use radix_engine_interface::blueprints::resource::*;
 use radix_engine_interface::math::Decimal;
@@ -35,20 +38,36 @@ pub struct ExecutionTraceModule {
     /// (e.g. open_substate call inside drop_node).
     current_kernel_call_depth: usize,
 
-    /// A stack of traced kernel call inputs, their origin, and the instruction index.
-    traced_kernel_call_inputs_stack: Vec<(ResourceSummary, TraceOrigin, usize)>,
+    /// A stack of traced kernel call inputs, their origin, the instruction index, and
+    /// the execution cost units committed and substate IO counts when the call started.
+    traced_kernel_call_inputs_stack:
+        Vec<(ResourceSummary, TraceOrigin, usize, u32, SubstateIoCounts)>,
+
+    /// The substate reads and writes made so far in the transaction.
+    substate_io_counts: SubstateIoCounts,
 
     /// A mapping of complete KernelCallTrace stacks (\w both inputs and outputs), indexed by depth.
     kernel_call_traces_stacks: IndexMap<usize, Vec<ExecutionTrace>>,
 
     /// Vault operations: (Caller, Vault ID, operation, instruction index)
     vault_ops: Vec<(TraceActor, NodeId, VaultOp, usize)>,
+
+    /// The peak WASM memory sizes of the Scrypto invocations which are yet to finish, indexed by
+    /// kernel call depth.
+    wasm_memory_peaks: IndexMap<usize, usize>,
 }
 
 impl ExecutionTraceModule {
     pub fn update_instruction_index(&mut self, new_index: usize) {
         self.current_instruction_index = new_index;
     }
+
+    /// Records the peak WASM memory size of the Scrypto invocation currently being executed, to
+    /// be attached to its trace once it finishes.
+    pub fn record_wasm_memory_peak(&mut self, memory_size: usize) {
+        self.wasm_memory_peaks
+            .insert(self.current_kernel_call_depth, memory_size);
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
@@ -90,12 +109,53 @@ impl From<&BucketSnapshot> for ResourceSpecifier {
 
 #[derive(Debug, Clone)]
 pub enum VaultOp {
-    Create(Decimal),               // TODO: add trace of vault creation
-    Put(ResourceAddress, Decimal), // TODO: add non-fungible support
-    Take(ResourceAddress, Decimal),
+    Create(Decimal), // TODO: add trace of vault creation
+    Put(BucketSnapshot),
+    Take(BucketSnapshot),
     LockFee(Decimal, bool),
 }
 
+/// A summary of the resources moved by a transaction, built from its vault operations.
+#[derive(Debug, Clone, PartialEq, Eq, Default, ScryptoSbor)]
+pub struct ResourceMovements {
+    /// The resources withdrawn from each account, including fee payments.
+    pub account_withdrawals: IndexMap<ComponentAddress, Vec<ResourceSpecifier>>,
+    /// The resources deposited into each account.
+    pub account_deposits: IndexMap<ComponentAddress, Vec<ResourceSpecifier>>,
+    /// The net balance change of each vault, including the non-fungibles moved in and out.
+    pub vault_balance_changes: IndexMap<NodeId, (ResourceAddress, BalanceChange)>,
+}
+
+impl ResourceMovements {
+    fn add_account_movement(
+        movements: &mut IndexMap<ComponentAddress, Vec<ResourceSpecifier>>,
+        account: ComponentAddress,
+        movement: ResourceSpecifier,
+    ) {
+        let account_movements = movements.entry(account).or_default();
+        for existing in account_movements.iter_mut() {
+            match (existing, &movement) {
+                (
+                    ResourceSpecifier::Amount(resource_address, amount),
+                    ResourceSpecifier::Amount(other_resource_address, other_amount),
+                ) if resource_address == other_resource_address => {
+                    *amount = amount.checked_add(*other_amount).unwrap();
+                    return;
+                }
+                (
+                    ResourceSpecifier::Ids(resource_address, ids),
+                    ResourceSpecifier::Ids(other_resource_address, other_ids),
+                ) if resource_address == other_resource_address => {
+                    ids.extend(other_ids.iter().cloned());
+                    return;
+                }
+                _ => {}
+            }
+        }
+        account_movements.push(movement);
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, ScryptoSbor)]
 pub enum BucketSnapshot {
     Fungible {
@@ -109,6 +169,30 @@ pub enum BucketSnapshot {
 }
 
 impl BucketSnapshot {
+    /// The balance change of a vault this bucket is put into.
+    pub fn deposit_balance_change(&self) -> BalanceChange {
+        match self {
+            BucketSnapshot::Fungible { liquid, .. } => BalanceChange::Fungible(*liquid),
+            BucketSnapshot::NonFungible { liquid, .. } => BalanceChange::NonFungible {
+                added: liquid.iter().cloned().collect(),
+                removed: BTreeSet::new(),
+            },
+        }
+    }
+
+    /// The balance change of a vault this bucket is taken from.
+    pub fn withdrawal_balance_change(&self) -> BalanceChange {
+        match self {
+            BucketSnapshot::Fungible { liquid, .. } => {
+                BalanceChange::Fungible(liquid.checked_neg().unwrap())
+            }
+            BucketSnapshot::NonFungible { liquid, .. } => BalanceChange::NonFungible {
+                added: BTreeSet::new(),
+                removed: liquid.iter().cloned().collect(),
+            },
+        }
+    }
+
     pub fn resource_address(&self) -> ResourceAddress {
         match self {
             BucketSnapshot::Fungible {
@@ -190,6 +274,31 @@ pub struct ExecutionTrace {
     pub input: ResourceSummary,
     pub output: ResourceSummary,
     pub children: Vec<ExecutionTrace>,
+    /// Execution cost units consumed by this kernel call, including its children.
+    /// This is always zero if the costing module is disabled.
+    pub execution_cost_units_consumed: u32,
+    /// Substate reads and writes made by this kernel call, including its children.
+    pub substate_io_counts: SubstateIoCounts,
+    /// The peak size of the WASM linear memory of this invocation, in bytes.
+    /// This is `None` if the invocation didn't execute Scrypto code.
+    pub wasm_memory_peak: Option<usize>,
+}
+
+/// The number of substates read and written, whether on heap or in track.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ScryptoSbor)]
+pub struct SubstateIoCounts {
+    pub reads: u32,
+    /// Writes through an open substate handle, as well as substates set or removed directly.
+    pub writes: u32,
+}
+
+impl SubstateIoCounts {
+    fn since(&self, start: &SubstateIoCounts) -> SubstateIoCounts {
+        SubstateIoCounts {
+            reads: self.reads.saturating_sub(start.reads),
+            writes: self.writes.saturating_sub(start.writes),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, ScryptoSbor)]
@@ -295,17 +404,20 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for ExecutionTraceMo
     ) -> Result<(), RuntimeError> {
         match event {
             CreateNodeEvent::Start(..) => {
-                api.kernel_get_system_state()
+                let system_state = api.kernel_get_system_state();
+                let execution_cost_units = execution_cost_units_committed(system_state.system);
+                system_state
                     .system
                     .modules
                     .execution_trace
-                    .handle_before_create_node();
+                    .handle_before_create_node(execution_cost_units);
             }
             CreateNodeEvent::IOAccess(..) => {}
             CreateNodeEvent::End(node_id) => {
                 let current_depth = api.kernel_get_current_depth();
                 let resource_summary = ResourceSummary::from_node_id(api, node_id);
                 let system_state = api.kernel_get_system_state();
+                let execution_cost_units = execution_cost_units_committed(system_state.system);
                 system_state
                     .system
                     .modules
@@ -314,6 +426,7 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for ExecutionTraceMo
                         system_state.current_call_frame,
                         current_depth,
                         resource_summary,
+                        execution_cost_units,
                     );
             }
         }
@@ -328,20 +441,27 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for ExecutionTraceMo
         match event {
             DropNodeEvent::Start(node_id) => {
                 let resource_summary = ResourceSummary::from_node_id(api, node_id);
-                api.kernel_get_system_state()
+                let system_state = api.kernel_get_system_state();
+                let execution_cost_units = execution_cost_units_committed(system_state.system);
+                system_state
                     .system
                     .modules
                     .execution_trace
-                    .handle_before_drop_node(resource_summary);
+                    .handle_before_drop_node(resource_summary, execution_cost_units);
             }
             DropNodeEvent::End(..) => {
                 let current_depth = api.kernel_get_current_depth();
                 let system_state = api.kernel_get_system_state();
+                let execution_cost_units = execution_cost_units_committed(system_state.system);
                 system_state
                     .system
                     .modules
                     .execution_trace
-                    .handle_after_drop_node(system_state.current_call_frame, current_depth);
+                    .handle_after_drop_node(
+                        system_state.current_call_frame,
+                        current_depth,
+                        execution_cost_units,
+                    );
             }
             DropNodeEvent::IOAccess(_) => {}
         }
@@ -358,6 +478,7 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for ExecutionTraceMo
         let callee = &invocation.call_frame_data;
         let args = &invocation.args;
         let system_state = api.kernel_get_system_state();
+        let execution_cost_units = execution_cost_units_committed(system_state.system);
         system_state
             .system
             .modules
@@ -367,10 +488,63 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for ExecutionTraceMo
                 callee,
                 resource_summary,
                 args,
+                execution_cost_units,
             );
         Ok(())
     }
 
+    fn on_read_substate<Y: KernelInternalApi<SystemConfig<V>>>(
+        api: &mut Y,
+        event: &ReadSubstateEvent,
+    ) -> Result<(), RuntimeError> {
+        if let ReadSubstateEvent::OnRead { .. } = event {
+            let counts = &mut api
+                .kernel_get_system()
+                .modules
+                .execution_trace
+                .substate_io_counts;
+            counts.reads = counts.reads.saturating_add(1);
+        }
+        Ok(())
+    }
+
+    fn on_write_substate<Y: KernelInternalApi<SystemConfig<V>>>(
+        api: &mut Y,
+        event: &WriteSubstateEvent,
+    ) -> Result<(), RuntimeError> {
+        if let WriteSubstateEvent::Start { .. } = event {
+            let counts = &mut api
+                .kernel_get_system()
+                .modules
+                .execution_trace
+                .substate_io_counts;
+            counts.writes = counts.writes.saturating_add(1);
+        }
+        Ok(())
+    }
+
+    fn on_set_substate(
+        system: &mut SystemConfig<V>,
+        event: &SetSubstateEvent,
+    ) -> Result<(), RuntimeError> {
+        if let SetSubstateEvent::Start(..) = event {
+            let counts = &mut system.modules.execution_trace.substate_io_counts;
+            counts.writes = counts.writes.saturating_add(1);
+        }
+        Ok(())
+    }
+
+    fn on_remove_substate(
+        system: &mut SystemConfig<V>,
+        event: &RemoveSubstateEvent,
+    ) -> Result<(), RuntimeError> {
+        if let RemoveSubstateEvent::Start(..) = event {
+            let counts = &mut system.modules.execution_trace.substate_io_counts;
+            counts.writes = counts.writes.saturating_add(1);
+        }
+        Ok(())
+    }
+
     fn on_execution_finish<Y: KernelApi<SystemConfig<V>>>(
         api: &mut Y,
         message: &CallFrameMessage,
@@ -381,6 +555,7 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for ExecutionTraceMo
         let system_state = api.kernel_get_system_state();
 
         let caller = TraceActor::from_actor(system_state.caller_call_frame);
+        let execution_cost_units = execution_cost_units_committed(system_state.system);
 
         system_state
             .system
@@ -391,12 +566,21 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for ExecutionTraceMo
                 current_depth,
                 &caller,
                 resource_summary,
+                execution_cost_units,
             );
 
         Ok(())
     }
 }
 
+fn execution_cost_units_committed<V: SystemCallbackObject>(system: &mut SystemConfig<V>) -> u32 {
+    system
+        .modules
+        .costing()
+        .map(|costing| costing.fee_reserve.execution_cost_units_committed())
+        .unwrap_or(0)
+}
+
 impl ExecutionTraceModule {
     pub fn new(max_kernel_call_depth_traced: usize) -> ExecutionTraceModule {
         Self {
@@ -404,12 +588,14 @@ impl ExecutionTraceModule {
             current_instruction_index: 0,
             current_kernel_call_depth: 0,
             traced_kernel_call_inputs_stack: vec![],
+            substate_io_counts: SubstateIoCounts::default(),
             kernel_call_traces_stacks: index_map_new(),
             vault_ops: Vec::new(),
+            wasm_memory_peaks: index_map_new(),
         }
     }
 
-    fn handle_before_create_node(&mut self) {
+    fn handle_before_create_node(&mut self, execution_cost_units: u32) {
         // Important to always update the counter (even if we're over the depth limit).
         self.current_kernel_call_depth += 1;
         if self.current_kernel_call_depth - 1 > self.max_kernel_call_depth_traced {
@@ -421,6 +607,8 @@ impl ExecutionTraceModule {
             ResourceSummary::default(),
             TraceOrigin::CreateNode,
             instruction_index,
+            execution_cost_units,
+            self.substate_io_counts,
         );
         self.traced_kernel_call_inputs_stack.push(traced_input);
     }
@@ -430,6 +618,7 @@ impl ExecutionTraceModule {
         current_actor: &Actor,
         current_depth: usize,
         resource_summary: ResourceSummary,
+        execution_cost_units: u32,
     ) {
         // Important to always update the counter (even if we're over the depth limit).
         self.current_kernel_call_depth -= 1;
@@ -438,10 +627,20 @@ impl ExecutionTraceModule {
         }
 
         let current_actor = TraceActor::from_actor(current_actor);
-        self.finalize_kernel_call_trace(resource_summary, current_actor, current_depth)
+        self.finalize_kernel_call_trace(
+            resource_summary,
+            current_actor,
+            current_depth,
+            execution_cost_units,
+            None,
+        )
     }
 
-    fn handle_before_drop_node(&mut self, resource_summary: ResourceSummary) {
+    fn handle_before_drop_node(
+        &mut self,
+        resource_summary: ResourceSummary,
+        execution_cost_units: u32,
+    ) {
         // Important to always update the counter (even if we're over the depth limit).
         self.current_kernel_call_depth += 1;
         if self.current_kernel_call_depth - 1 > self.max_kernel_call_depth_traced {
@@ -449,11 +648,22 @@ impl ExecutionTraceModule {
         }
 
         let instruction_index = self.instruction_index();
-        let traced_input = (resource_summary, TraceOrigin::DropNode, instruction_index);
+        let traced_input = (
+            resource_summary,
+            TraceOrigin::DropNode,
+            instruction_index,
+            execution_cost_units,
+            self.substate_io_counts,
+        );
         self.traced_kernel_call_inputs_stack.push(traced_input);
     }
 
-    fn handle_after_drop_node(&mut self, current_actor: &Actor, current_depth: usize) {
+    fn handle_after_drop_node(
+        &mut self,
+        current_actor: &Actor,
+        current_depth: usize,
+        execution_cost_units: u32,
+    ) {
         // Important to always update the counter (even if we're over the depth limit).
         self.current_kernel_call_depth -= 1;
         if self.current_kernel_call_depth > self.max_kernel_call_depth_traced {
@@ -462,7 +672,13 @@ impl ExecutionTraceModule {
 
         let traced_output = ResourceSummary::default();
         let current_actor = TraceActor::from_actor(current_actor);
-        self.finalize_kernel_call_trace(traced_output, current_actor, current_depth)
+        self.finalize_kernel_call_trace(
+            traced_output,
+            current_actor,
+            current_depth,
+            execution_cost_units,
+            None,
+        )
     }
 
     fn handle_before_invoke(
@@ -471,6 +687,7 @@ impl ExecutionTraceModule {
         callee: &Actor,
         resource_summary: ResourceSummary,
         args: &IndexedScryptoValue,
+        execution_cost_units: u32,
     ) {
         // Important to always update the counter (even if we're over the depth limit).
         self.current_kernel_call_depth += 1;
@@ -502,6 +719,8 @@ impl ExecutionTraceModule {
             resource_summary.clone(),
             origin,
             instruction_index,
+            execution_cost_units,
+            self.substate_io_counts,
         ));
 
         match &callee {
@@ -515,7 +734,17 @@ impl ExecutionTraceModule {
                 if VaultUtil::is_vault_blueprint(&actor.get_blueprint_id())
                     && ident.eq(FUNGIBLE_VAULT_LOCK_FEE_IDENT) =>
             {
-                self.handle_vault_lock_fee_input(current_actor, node_id, args)
+                let FungibleVaultLockFeeInput { amount, contingent } = args.as_typed().unwrap();
+                self.handle_vault_lock_fee_input(current_actor, node_id, amount, contingent)
+            }
+            Actor::Method(actor @ MethodActor { node_id, ident, .. })
+                if VaultUtil::is_vault_blueprint(&actor.get_blueprint_id())
+                    && ident.eq(FUNGIBLE_VAULT_LOCK_FEE_WITH_REFUND_IDENT) =>
+            {
+                let FungibleVaultLockFeeWithRefundInput {
+                    amount, contingent, ..
+                } = args.as_typed().unwrap();
+                self.handle_vault_lock_fee_input(current_actor, node_id, amount, contingent)
             }
             _ => {}
         }
@@ -527,7 +756,12 @@ impl ExecutionTraceModule {
         current_depth: usize,
         caller: &TraceActor,
         resource_summary: ResourceSummary,
+        execution_cost_units: u32,
     ) {
+        let wasm_memory_peak = self
+            .wasm_memory_peaks
+            .remove(&self.current_kernel_call_depth);
+
         // Important to always update the counter (even if we're over the depth limit).
         self.current_kernel_call_depth -= 1;
         if self.current_kernel_call_depth > self.max_kernel_call_depth_traced {
@@ -537,7 +771,9 @@ impl ExecutionTraceModule {
         match current_actor {
             Actor::Method(actor @ MethodActor { node_id, ident, .. }) => {
                 if VaultUtil::is_vault_blueprint(&actor.get_blueprint_id())
-                    && ident.eq(VAULT_TAKE_IDENT)
+                    && (ident.eq(VAULT_TAKE_IDENT)
+                        || ident.eq(VAULT_TAKE_ADVANCED_IDENT)
+                        || ident.eq(NON_FUNGIBLE_VAULT_TAKE_NON_FUNGIBLES_IDENT))
                 {
                     self.handle_vault_take_output(&resource_summary, &caller, node_id)
                 }
@@ -547,7 +783,13 @@ impl ExecutionTraceModule {
         }
 
         let current_actor = TraceActor::from_actor(current_actor);
-        self.finalize_kernel_call_trace(resource_summary, current_actor, current_depth)
+        self.finalize_kernel_call_trace(
+            resource_summary,
+            current_actor,
+            current_depth,
+            execution_cost_units,
+            wasm_memory_peak,
+        )
     }
 
     fn finalize_kernel_call_trace(
@@ -555,13 +797,21 @@ impl ExecutionTraceModule {
         traced_output: ResourceSummary,
         current_actor: TraceActor,
         current_depth: usize,
+        execution_cost_units: u32,
+        wasm_memory_peak: Option<usize>,
     ) {
         let child_traces = self
             .kernel_call_traces_stacks
             .remove(&(self.current_kernel_call_depth + 1))
             .unwrap_or(vec![]);
 
-        let (traced_input, origin, instruction_index) = self
+        let (
+            traced_input,
+            origin,
+            instruction_index,
+            execution_cost_units_at_start,
+            substate_io_counts_at_start,
+        ) = self
             .traced_kernel_call_inputs_stack
             .pop()
             .expect("kernel call input stack underflow");
@@ -581,6 +831,10 @@ impl ExecutionTraceModule {
                 input: traced_input,
                 output: traced_output,
                 children: child_traces,
+                execution_cost_units_consumed: execution_cost_units
+                    .saturating_sub(execution_cost_units_at_start),
+                substate_io_counts: self.substate_io_counts.since(&substate_io_counts_at_start),
+                wasm_memory_peak,
             };
 
             let siblings = self
@@ -602,11 +856,14 @@ impl ExecutionTraceModule {
         }
 
         let fee_locks = calculate_fee_locks(&self.vault_ops);
+        let resource_movements =
+            calculate_resource_movements(&self.vault_ops, fee_payments, is_success);
         let resource_changes = calculate_resource_changes(self.vault_ops, fee_payments, is_success);
 
         TransactionExecutionTrace {
             execution_traces,
             resource_changes,
+            resource_movements,
             fee_locks,
         }
     }
@@ -626,7 +883,7 @@ impl ExecutionTraceModule {
             self.vault_ops.push((
                 actor.clone(),
                 vault_id.clone(),
-                VaultOp::Put(resource.resource_address(), resource.amount()),
+                VaultOp::Put(resource.clone()),
                 self.instruction_index(),
             ));
         }
@@ -636,10 +893,10 @@ impl ExecutionTraceModule {
         &mut self,
         caller: &Actor,
         vault_id: &NodeId,
-        args: &IndexedScryptoValue,
+        amount: Decimal,
+        contingent: bool,
     ) {
         let actor = TraceActor::from_actor(caller);
-        let FungibleVaultLockFeeInput { amount, contingent } = args.as_typed().unwrap();
         self.vault_ops.push((
             actor,
             vault_id.clone(),
@@ -658,7 +915,7 @@ impl ExecutionTraceModule {
             self.vault_ops.push((
                 actor.clone(),
                 vault_id.clone(),
-                VaultOp::Take(resource.resource_address(), resource.amount()),
+                VaultOp::Take(resource.clone()),
                 self.instruction_index(),
             ));
         }
@@ -682,27 +939,27 @@ pub fn calculate_resource_changes(
         if let TraceActor::Method(node_id) = actor {
             match vault_op {
                 VaultOp::Create(_) => todo!("Not supported yet!"),
-                VaultOp::Put(resource_address, amount) => {
+                VaultOp::Put(resource) => {
                     let entry = &mut vault_changes
                         .entry(instruction_index)
                         .or_default()
                         .entry(node_id)
                         .or_default()
                         .entry(vault_id)
-                        .or_insert((resource_address, Decimal::zero()))
+                        .or_insert((resource.resource_address(), Decimal::zero()))
                         .1;
-                    *entry = entry.checked_add(amount).unwrap();
+                    *entry = entry.checked_add(resource.amount()).unwrap();
                 }
-                VaultOp::Take(resource_address, amount) => {
+                VaultOp::Take(resource) => {
                     let entry = &mut vault_changes
                         .entry(instruction_index)
                         .or_default()
                         .entry(node_id)
                         .or_default()
                         .entry(vault_id)
-                        .or_insert((resource_address, Decimal::zero()))
+                        .or_insert((resource.resource_address(), Decimal::zero()))
                         .1;
-                    *entry = entry.checked_sub(amount).unwrap();
+                    *entry = entry.checked_sub(resource.amount()).unwrap();
                 }
                 VaultOp::LockFee(..) => {
                     let entry = &mut vault_changes
@@ -745,6 +1002,73 @@ pub fn calculate_resource_changes(
     resource_changes
 }
 
+pub fn calculate_resource_movements(
+    vault_ops: &Vec<(TraceActor, NodeId, VaultOp, usize)>,
+    fee_payments: &IndexMap<NodeId, Decimal>,
+    is_commit_success: bool,
+) -> ResourceMovements {
+    let mut movements = ResourceMovements::default();
+    for (actor, vault_id, vault_op, _) in vault_ops {
+        // As with resource changes, only fee payments stick if the transaction fails.
+        let (resource_address, balance_change, is_withdrawal, movement) = match vault_op {
+            VaultOp::Put(resource) if is_commit_success => (
+                resource.resource_address(),
+                resource.deposit_balance_change(),
+                false,
+                ResourceSpecifier::from(resource),
+            ),
+            VaultOp::Take(resource) if is_commit_success => (
+                resource.resource_address(),
+                resource.withdrawal_balance_change(),
+                true,
+                ResourceSpecifier::from(resource),
+            ),
+            VaultOp::LockFee(..) => {
+                let fee_payment = fee_payments.get(vault_id).cloned().unwrap_or_default();
+                if fee_payment.is_zero() {
+                    continue;
+                }
+                (
+                    XRD,
+                    BalanceChange::Fungible(fee_payment.checked_neg().unwrap()),
+                    true,
+                    ResourceSpecifier::Amount(XRD, fee_payment),
+                )
+            }
+            _ => continue,
+        };
+
+        if let Some((_, existing)) = movements.vault_balance_changes.get_mut(vault_id) {
+            *existing += balance_change;
+        } else {
+            movements
+                .vault_balance_changes
+                .insert(*vault_id, (resource_address, balance_change));
+        }
+
+        if let TraceActor::Method(node_id) = actor {
+            if node_id.is_global_account() {
+                let account = ComponentAddress::new_or_panic(node_id.0);
+                ResourceMovements::add_account_movement(
+                    if is_withdrawal {
+                        &mut movements.account_withdrawals
+                    } else {
+                        &mut movements.account_deposits
+                    },
+                    account,
+                    movement,
+                );
+            }
+        }
+    }
+
+    movements
+        .vault_balance_changes
+        .retain(|_, (_, balance_change)| !balance_change.prune_and_check_if_zero());
+
+    movements
+}
+
 pub fn calculate_fee_locks(vault_ops: &Vec<(TraceActor, NodeId, VaultOp, usize)>) -> FeeLocks {
     let mut fee_locks = FeeLocks {
         lock: Decimal::ZERO,
@@ -25,8 +25,10 @@ pub enum TransactionLimitsError {
     PanicMessageSizeTooLarge { actual: usize, max: usize },
     TooManyLogs,
     TooManyEvents,
+    MaxWasmMemoryExceeded { actual: usize, max: usize },
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct TransactionLimitsConfig {
     pub max_heap_substate_total_bytes: usize,
     pub max_track_substate_total_bytes: usize,
@@ -38,6 +40,58 @@ pub struct TransactionLimitsConfig {
     pub max_panic_message_size: usize,
     pub max_number_of_logs: usize,
     pub max_number_of_events: usize,
+    pub max_wasm_memory_per_call_frame: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionLimitsConfigError {
+    /// The limit would fail every transaction, as every transaction creates substates and makes
+    /// invocations.
+    ZeroLimit(&'static str),
+    /// A single substate could never be within the total substate size limits.
+    SubstateValueSizeExceedsTotal {
+        max_substate_value_size: usize,
+        max_total_bytes: usize,
+    },
+}
+
+impl TransactionLimitsConfig {
+    /// Checks that the limits are consistent with each other.
+    ///
+    /// The event and log limits may be zero, e.g. to exercise the failure paths in tests.
+    pub fn validate(&self) -> Result<(), TransactionLimitsConfigError> {
+        for (name, limit) in [
+            (
+                "max_heap_substate_total_bytes",
+                self.max_heap_substate_total_bytes,
+            ),
+            (
+                "max_track_substate_total_bytes",
+                self.max_track_substate_total_bytes,
+            ),
+            ("max_substate_key_size", self.max_substate_key_size),
+            ("max_substate_value_size", self.max_substate_value_size),
+            ("max_invoke_payload_size", self.max_invoke_payload_size),
+        ] {
+            if limit == 0 {
+                return Err(TransactionLimitsConfigError::ZeroLimit(name));
+            }
+        }
+
+        let max_total_bytes = self
+            .max_heap_substate_total_bytes
+            .min(self.max_track_substate_total_bytes);
+        if self.max_substate_value_size > max_total_bytes {
+            return Err(
+                TransactionLimitsConfigError::SubstateValueSizeExceedsTotal {
+                    max_substate_value_size: self.max_substate_value_size,
+                    max_total_bytes,
+                },
+            );
+        }
+
+        Ok(())
+    }
 }
 
 /// Tracks and verifies transaction limits during transactino execution,
@@ -0,0 +1,211 @@
+use crate::errors::RuntimeError;
+use crate::kernel::kernel_api::{KernelApi, KernelInternalApi, KernelInvocation};
+use crate::kernel::kernel_callback_api::{
+    CreateNodeEvent, DrainSubstatesEvent, DropNodeEvent, MoveModuleEvent, OpenSubstateEvent,
+    ReadSubstateEvent, RemoveSubstateEvent, ScanKeysEvent, ScanSortedSubstatesEvent,
+    SetSubstateEvent, WriteSubstateEvent,
+};
+use crate::metrics::EngineCounter;
+use crate::system::actor::Actor;
+use crate::system::module::SystemModule;
+use crate::system::system_callback::SystemConfig;
+use crate::system::system_callback_api::SystemCallbackObject;
+use crate::track::interface::IOAccess;
+use crate::types::*;
+
+/// Counts the substate accesses and invocations of a transaction, to be reported to the metrics
+/// sink of the execution config once the transaction is executed.
+#[derive(Debug, Clone, Default)]
+pub struct MetricsModule {
+    counters: BTreeMap<EngineCounter, u64>,
+}
+
+impl MetricsModule {
+    pub fn increment(&mut self, counter: EngineCounter) {
+        *self.counters.entry(counter).or_default() += 1;
+    }
+
+    pub fn counters(&self) -> &BTreeMap<EngineCounter, u64> {
+        &self.counters
+    }
+
+    fn process_io_access(&mut self, io_access: &IOAccess) {
+        match io_access {
+            IOAccess::ReadFromDb(..) | IOAccess::ReadFromDbNotFound(..) => {
+                self.increment(EngineCounter::DatabaseReads);
+            }
+            IOAccess::TrackSubstateUpdated { .. } | IOAccess::HeapSubstateUpdated { .. } => {}
+        }
+    }
+}
+
+impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for MetricsModule {
+    fn before_invoke<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        _invocation: &KernelInvocation<Actor>,
+    ) -> Result<(), RuntimeError> {
+        api.kernel_get_system()
+            .modules
+            .metrics
+            .increment(EngineCounter::Invocations);
+
+        Ok(())
+    }
+
+    fn on_create_node<Y: KernelInternalApi<SystemConfig<V>>>(
+        api: &mut Y,
+        event: &CreateNodeEvent,
+    ) -> Result<(), RuntimeError> {
+        if let CreateNodeEvent::IOAccess(io_access) = event {
+            api.kernel_get_system()
+                .modules
+                .metrics
+                .process_io_access(io_access);
+        }
+
+        Ok(())
+    }
+
+    fn on_drop_node<Y: KernelInternalApi<SystemConfig<V>>>(
+        api: &mut Y,
+        event: &DropNodeEvent,
+    ) -> Result<(), RuntimeError> {
+        if let DropNodeEvent::IOAccess(io_access) = event {
+            api.kernel_get_system()
+                .modules
+                .metrics
+                .process_io_access(io_access);
+        }
+
+        Ok(())
+    }
+
+    fn on_move_module<Y: KernelInternalApi<SystemConfig<V>>>(
+        api: &mut Y,
+        event: &MoveModuleEvent,
+    ) -> Result<(), RuntimeError> {
+        match event {
+            MoveModuleEvent::IOAccess(io_access) => {
+                api.kernel_get_system()
+                    .modules
+                    .metrics
+                    .process_io_access(io_access);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn on_open_substate<Y: KernelInternalApi<SystemConfig<V>>>(
+        api: &mut Y,
+        event: &OpenSubstateEvent,
+    ) -> Result<(), RuntimeError> {
+        if let OpenSubstateEvent::IOAccess(io_access) = event {
+            api.kernel_get_system()
+                .modules
+                .metrics
+                .process_io_access(io_access);
+        }
+
+        Ok(())
+    }
+
+    fn on_read_substate<Y: KernelInternalApi<SystemConfig<V>>>(
+        api: &mut Y,
+        event: &ReadSubstateEvent,
+    ) -> Result<(), RuntimeError> {
+        let metrics = &mut api.kernel_get_system().modules.metrics;
+
+        match event {
+            ReadSubstateEvent::OnRead { .. } => {
+                metrics.increment(EngineCounter::SubstatesRead);
+            }
+            ReadSubstateEvent::IOAccess(io_access) => {
+                metrics.process_io_access(io_access);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn on_write_substate<Y: KernelInternalApi<SystemConfig<V>>>(
+        api: &mut Y,
+        event: &WriteSubstateEvent,
+    ) -> Result<(), RuntimeError> {
+        let metrics = &mut api.kernel_get_system().modules.metrics;
+
+        match event {
+            WriteSubstateEvent::Start { .. } => {
+                metrics.increment(EngineCounter::SubstatesWritten);
+            }
+            WriteSubstateEvent::IOAccess(io_access) => {
+                metrics.process_io_access(io_access);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn on_set_substate(
+        system: &mut SystemConfig<V>,
+        event: &SetSubstateEvent,
+    ) -> Result<(), RuntimeError> {
+        match event {
+            SetSubstateEvent::Start(..) => {
+                system
+                    .modules
+                    .metrics
+                    .increment(EngineCounter::SubstatesWritten);
+            }
+            SetSubstateEvent::IOAccess(io_access) => {
+                system.modules.metrics.process_io_access(io_access);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn on_remove_substate(
+        system: &mut SystemConfig<V>,
+        event: &RemoveSubstateEvent,
+    ) -> Result<(), RuntimeError> {
+        if let RemoveSubstateEvent::IOAccess(io_access) = event {
+            system.modules.metrics.process_io_access(io_access);
+        }
+
+        Ok(())
+    }
+
+    fn on_scan_keys(
+        system: &mut SystemConfig<V>,
+        event: &ScanKeysEvent,
+    ) -> Result<(), RuntimeError> {
+        if let ScanKeysEvent::IOAccess(io_access) = event {
+            system.modules.metrics.process_io_access(io_access);
+        }
+
+        Ok(())
+    }
+
+    fn on_drain_substates(
+        system: &mut SystemConfig<V>,
+        event: &DrainSubstatesEvent,
+    ) -> Result<(), RuntimeError> {
+        if let DrainSubstatesEvent::IOAccess(io_access) = event {
+            system.modules.metrics.process_io_access(io_access);
+        }
+
+        Ok(())
+    }
+
+    fn on_scan_sorted_substates(
+        system: &mut SystemConfig<V>,
+        event: &ScanSortedSubstatesEvent,
+    ) -> Result<(), RuntimeError> {
+        if let ScanSortedSubstatesEvent::IOAccess(io_access) = event {
+            system.modules.metrics.process_io_access(io_access);
+        }
+
+        Ok(())
+    }
+}
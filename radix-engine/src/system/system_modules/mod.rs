@@ -3,6 +3,8 @@ pub mod costing;
 pub mod execution_trace;
 pub mod kernel_trace;
 pub mod limits;
+pub mod metrics;
+pub mod reentrancy;
 pub mod transaction_runtime;
 
 mod module_mixer;
@@ -11,21 +11,27 @@ use crate::kernel::kernel_callback_api::{
 };
 #[cfg(feature = "resource_tracker")]
 use crate::kernel::substate_io::SubstateDevice;
+use crate::metrics::EngineCounter;
 use crate::system::actor::Actor;
 use crate::system::module::SystemModule;
 use crate::system::system::SystemService;
 use crate::system::system_callback::SystemConfig;
 use crate::system::system_callback_api::SystemCallbackObject;
-use crate::system::system_modules::auth::AuthModule;
+use crate::system::system_modules::auth::{AuthModule, AuthTrace};
 use crate::system::system_modules::costing::CostingModule;
 use crate::system::system_modules::costing::FeeTable;
 use crate::system::system_modules::costing::SystemLoanFeeReserve;
 use crate::system::system_modules::execution_trace::ExecutionTraceModule;
 use crate::system::system_modules::kernel_trace::KernelTraceModule;
-use crate::system::system_modules::limits::{LimitsModule, TransactionLimitsConfig};
-use crate::system::system_modules::transaction_runtime::{Event, TransactionRuntimeModule};
+use crate::system::system_modules::limits::LimitsModule;
+use crate::system::system_modules::metrics::MetricsModule;
+use crate::system::system_modules::reentrancy::ReentrancyModule;
+use crate::system::system_modules::transaction_runtime::{
+    Event, LogRecord, PanicDetails, TransactionRuntimeModule,
+};
 use crate::transaction::ExecutionConfig;
 use crate::types::*;
+use crate::vm::wasm::WasmMeteringMode;
 use bitflags::bitflags;
 use paste::paste;
 use radix_engine_interface::api::ModuleId;
@@ -48,6 +54,12 @@ bitflags! {
 
         // Execution trace, for preview only
         const EXECUTION_TRACE = 0x01 << 6;
+
+        // Reentrancy policy, opt-in
+        const REENTRANCY = 0x01 << 7;
+
+        // Engine metrics, enabled along with a metrics sink
+        const METRICS = 0x01 << 8;
     }
 }
 
@@ -82,6 +94,7 @@ pub struct SystemModuleMixer {
 
     /* flags */
     pub enabled_modules: EnabledModules,
+    pub wasm_metering_mode: WasmMeteringMode,
 
     /* states */
     pub(super) kernel_trace: KernelTraceModule,
@@ -90,6 +103,8 @@ pub struct SystemModuleMixer {
     pub(crate) auth: AuthModule,
     pub(crate) transaction_runtime: TransactionRuntimeModule,
     pub(super) execution_trace: ExecutionTraceModule,
+    pub(super) reentrancy: ReentrancyModule,
+    pub(super) metrics: MetricsModule,
 }
 
 // Macro generates default modules dispatches call based on passed function name and arguments.
@@ -116,6 +131,12 @@ macro_rules! internal_call_dispatch {
             if modules.contains(EnabledModules::EXECUTION_TRACE) {
                 ExecutionTraceModule::[< $fn >]($($param, )*)?;
             }
+            if modules.contains(EnabledModules::REENTRANCY) {
+                ReentrancyModule::[< $fn >]($($param, )*)?;
+            }
+            if modules.contains(EnabledModules::METRICS) {
+                MetricsModule::[< $fn >]($($param, )*)?;
+            }
             Ok(())
         }
     }};
@@ -135,6 +156,7 @@ impl SystemModuleMixer {
     ) -> Self {
         Self {
             enabled_modules,
+            wasm_metering_mode: execution_config.wasm_metering_mode,
             kernel_trace: KernelTraceModule {},
             costing: CostingModule {
                 fee_reserve,
@@ -143,35 +165,42 @@ impl SystemModuleMixer {
                 tx_payload_len: payload_len,
                 tx_num_of_signature_validations: num_of_signature_validations,
                 max_per_function_royalty_in_xrd: execution_config.max_per_function_royalty_in_xrd,
-                enable_cost_breakdown: execution_config.enable_cost_breakdown,
+                enable_cost_breakdown: execution_config.enable_cost_breakdown
+                    || execution_config.enable_partial_execution_snapshot,
                 execution_cost_breakdown: index_map_new(),
                 finalization_cost_breakdown: index_map_new(),
                 storage_cost_breakdown: index_map_new(),
+                execution_cost_breakdown_by_package: index_map_new(),
+                state_dependent_execution_cost_units: 0,
+                package_stack: Vec::new(),
+                instruction_cost_unit_limit: None,
+                instruction_start_cost_units: 0,
+                component_royalty_calls: index_map_new(),
                 on_apply_cost: Default::default(),
             },
             auth: AuthModule {
                 params: auth_zone_params.clone(),
+                trace: if execution_config.enable_auth_trace {
+                    Some(AuthTrace::default())
+                } else {
+                    None
+                },
             },
-            limits: LimitsModule::new(TransactionLimitsConfig {
-                max_heap_substate_total_bytes: execution_config.max_heap_substate_total_bytes,
-                max_track_substate_total_bytes: execution_config.max_track_substate_total_bytes,
-                max_substate_key_size: execution_config.max_substate_key_size,
-                max_substate_value_size: execution_config.max_substate_value_size,
-                max_invoke_payload_size: execution_config.max_invoke_input_size,
-                max_number_of_logs: execution_config.max_number_of_logs,
-                max_number_of_events: execution_config.max_number_of_events,
-                max_event_size: execution_config.max_event_size,
-                max_log_size: execution_config.max_log_size,
-                max_panic_message_size: execution_config.max_panic_message_size,
-            }),
+            limits: LimitsModule::new(execution_config.limits()),
             execution_trace: ExecutionTraceModule::new(execution_config.max_execution_trace_depth),
+            reentrancy: ReentrancyModule::default(),
+            metrics: MetricsModule::default(),
             transaction_runtime: TransactionRuntimeModule {
                 network_definition,
                 tx_hash,
                 next_id: 0,
+                next_seeded_ids: index_map_new(),
                 logs: Vec::new(),
                 events: Vec::new(),
                 replacements: index_map_new(),
+                instructions_completed: 0,
+                invoked_packages: index_set_new(),
+                panic_details: None,
             },
         }
     }
@@ -182,8 +211,16 @@ impl SystemModuleMixer {
         CostingModule,
         TransactionRuntimeModule,
         ExecutionTraceModule,
+        AuthModule,
+        MetricsModule,
     ) {
-        (self.costing, self.transaction_runtime, self.execution_trace)
+        (
+            self.costing,
+            self.transaction_runtime,
+            self.execution_trace,
+            self.auth,
+            self.metrics,
+        )
     }
 }
 
@@ -197,6 +234,16 @@ impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for SystemModuleMixe
     fn on_init<Y: KernelApi<SystemConfig<V>>>(api: &mut Y) -> Result<(), RuntimeError> {
         let modules: EnabledModules = api.kernel_get_system().modules.enabled_modules;
 
+        // Enable engine metrics
+        if modules.contains(EnabledModules::METRICS) {
+            MetricsModule::on_init(api)?;
+        }
+
+        // Enable reentrancy policy
+        if modules.contains(EnabledModules::REENTRANCY) {
+            ReentrancyModule::on_init(api)?;
+        }
+
         // Enable execution trace
         if modules.contains(EnabledModules::EXECUTION_TRACE) {
             ExecutionTraceModule::on_init(api)?;
@@ -473,18 +520,61 @@ impl SystemModuleMixer {
         AuthModule::on_call_function_finish(api, auth_zone)
     }
 
-    pub fn add_log(&mut self, level: Level, message: String) -> Result<(), RuntimeError> {
+    /// Counts an engine event reported from outside of the kernel callbacks, e.g. by the VM.
+    pub fn increment_metric(&mut self, counter: EngineCounter) {
+        if self.enabled_modules.contains(EnabledModules::METRICS) {
+            self.metrics.increment(counter);
+        }
+    }
+
+    /// The max size of the linear memory of a Scrypto call frame, if limited.
+    pub fn max_wasm_memory_per_call_frame(&self) -> Option<usize> {
+        if self.enabled_modules.contains(EnabledModules::LIMITS) {
+            Some(self.limits.config().max_wasm_memory_per_call_frame)
+        } else {
+            None
+        }
+    }
+
+    /// Records the peak memory size of a Scrypto call frame in the execution trace, and checks it
+    /// against the limit (which is also checked whenever the frame calls into the host, but the
+    /// memory may still have grown since the last such call).
+    pub fn on_wasm_invocation_finish(&mut self, memory_size: usize) -> Result<(), RuntimeError> {
+        if self
+            .enabled_modules
+            .contains(EnabledModules::EXECUTION_TRACE)
+        {
+            self.execution_trace.record_wasm_memory_peak(memory_size);
+        }
+
+        if self.enabled_modules.contains(EnabledModules::LIMITS) {
+            if memory_size > self.limits.config().max_wasm_memory_per_call_frame {
+                return Err(RuntimeError::SystemModuleError(
+                    SystemModuleError::TransactionLimitsError(
+                        TransactionLimitsError::MaxWasmMemoryExceeded {
+                            actual: memory_size,
+                            max: self.limits.config().max_wasm_memory_per_call_frame,
+                        },
+                    ),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn add_log(&mut self, log: LogRecord) -> Result<(), RuntimeError> {
         if self.enabled_modules.contains(EnabledModules::LIMITS) {
             if self.transaction_runtime.logs.len() >= self.limits.config().max_number_of_logs {
                 return Err(RuntimeError::SystemModuleError(
                     SystemModuleError::TransactionLimitsError(TransactionLimitsError::TooManyLogs),
                 ));
             }
-            if message.len() > self.limits.config().max_log_size {
+            if log.len() > self.limits.config().max_log_size {
                 return Err(RuntimeError::SystemModuleError(
                     SystemModuleError::TransactionLimitsError(
                         TransactionLimitsError::LogSizeTooLarge {
-                            actual: message.len(),
+                            actual: log.len(),
                             max: self.limits.config().max_log_size,
                         },
                     ),
@@ -496,7 +586,7 @@ impl SystemModuleMixer {
             .enabled_modules
             .contains(EnabledModules::TRANSACTION_RUNTIME)
         {
-            self.transaction_runtime.add_log(level, message);
+            self.transaction_runtime.add_log(log);
         }
 
         Ok(())
@@ -546,6 +636,15 @@ impl SystemModuleMixer {
         Ok(())
     }
 
+    pub fn set_panic_details(&mut self, panic_details: PanicDetails) {
+        if self
+            .enabled_modules
+            .contains(EnabledModules::TRANSACTION_RUNTIME)
+        {
+            self.transaction_runtime.set_panic_details(panic_details);
+        }
+    }
+
     pub fn set_panic_message(&mut self, message: String) -> Result<(), RuntimeError> {
         if self.enabled_modules.contains(EnabledModules::LIMITS) {
             if message.len() > self.limits.config().max_panic_message_size {
@@ -626,7 +725,25 @@ impl SystemModuleMixer {
         }
     }
 
+    pub fn generate_ruid_seeded(&mut self, domain: &[u8]) -> Option<[u8; 32]> {
+        if self
+            .enabled_modules
+            .contains(EnabledModules::TRANSACTION_RUNTIME)
+        {
+            Some(self.transaction_runtime.generate_ruid_seeded(domain))
+        } else {
+            None
+        }
+    }
+
     pub fn update_instruction_index(&mut self, new_index: usize) {
+        // All instructions before the one about to be executed have completed
+        self.transaction_runtime.instructions_completed = new_index;
+
+        if self.enabled_modules.contains(EnabledModules::COSTING) {
+            self.costing.start_instruction();
+        }
+
         if self
             .enabled_modules
             .contains(EnabledModules::EXECUTION_TRACE)
@@ -674,9 +791,11 @@ impl SystemModuleMixer {
         vault_id: NodeId,
         locked_fee: LiquidFungibleResource,
         contingent: bool,
+        refund_vault_id: Option<NodeId>,
     ) {
         if self.enabled_modules.contains(EnabledModules::COSTING) {
-            self.costing.lock_fee(vault_id, locked_fee, contingent);
+            self.costing
+                .lock_fee(vault_id, locked_fee, contingent, refund_vault_id);
         } else {
             panic!("Fungible Vault Application layer should prevent call to credit if costing not enabled");
         }
@@ -686,7 +805,7 @@ impl SystemModuleMixer {
         &self.transaction_runtime.events
     }
 
-    pub fn logs(&self) -> &Vec<(Level, String)> {
+    pub fn logs(&self) -> &Vec<LogRecord> {
         &self.transaction_runtime.logs
     }
 }
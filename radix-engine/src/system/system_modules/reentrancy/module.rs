@@ -0,0 +1,93 @@
+use crate::kernel::kernel_api::{KernelApi, KernelInternalApi, KernelInvocation};
+use crate::system::actor::{Actor, MethodActor, MethodType};
+use crate::system::module::SystemModule;
+use crate::system::system_callback::SystemConfig;
+use crate::system::system_callback_api::SystemCallbackObject;
+use crate::types::*;
+use crate::{errors::RuntimeError, errors::SystemModuleError};
+
+/// The blueprint feature which allows the components of a blueprint to be re-entered.
+pub const ALLOW_REENTRANCY_FEATURE: &str = "allow_reentrancy";
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum ReentrancyError {
+    ReentrantCall { node_id: NodeId, ident: String },
+}
+
+/// Denies calling a method of a global component while another method of the same component is
+/// on the call stack, unless the component has the [`ALLOW_REENTRANCY_FEATURE`] enabled.
+///
+/// Native blueprints are exempt, as they can't opt in through a feature.
+#[derive(Debug, Clone, Default)]
+pub struct ReentrancyModule {
+    /// The global components entered by each invocation on the call stack, if any.
+    component_stack: Vec<Option<NodeId>>,
+}
+
+impl ReentrancyModule {
+    /// The global component entered by the invocation, if it is subject to the policy.
+    fn entered_component(actor: &Actor) -> Option<&MethodActor> {
+        match actor {
+            Actor::Method(method_actor)
+                if method_actor.method_type == MethodType::Main
+                    && method_actor.node_id.is_global()
+                    && !method_actor
+                        .object_info
+                        .blueprint_info
+                        .blueprint_id
+                        .package_address
+                        .is_native_package() =>
+            {
+                Some(method_actor)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for ReentrancyModule {
+    fn before_invoke<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        invocation: &KernelInvocation<Actor>,
+    ) -> Result<(), RuntimeError> {
+        let entered_component = Self::entered_component(&invocation.call_frame_data);
+
+        let reentrancy = &mut api.kernel_get_system().modules.reentrancy;
+        if let Some(method_actor) = entered_component {
+            if reentrancy
+                .component_stack
+                .contains(&Some(method_actor.node_id))
+                && !method_actor
+                    .object_info
+                    .blueprint_info
+                    .features
+                    .contains(ALLOW_REENTRANCY_FEATURE)
+            {
+                return Err(RuntimeError::SystemModuleError(
+                    SystemModuleError::ReentrancyError(ReentrancyError::ReentrantCall {
+                        node_id: method_actor.node_id,
+                        ident: method_actor.ident.clone(),
+                    }),
+                ));
+            }
+        }
+        reentrancy
+            .component_stack
+            .push(entered_component.map(|method_actor| method_actor.node_id));
+
+        Ok(())
+    }
+
+    fn after_invoke<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        _output: &IndexedScryptoValue,
+    ) -> Result<(), RuntimeError> {
+        api.kernel_get_system()
+            .modules
+            .reentrancy
+            .component_stack
+            .pop();
+
+        Ok(())
+    }
+}
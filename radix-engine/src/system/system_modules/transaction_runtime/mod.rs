@@ -1,3 +1,5 @@
 mod module;
+mod panic;
 
 pub use module::*;
+pub use panic::*;
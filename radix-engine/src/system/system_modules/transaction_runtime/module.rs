@@ -1,5 +1,10 @@
-use crate::kernel::kernel_callback_api::KernelCallbackObject;
+use super::PanicDetails;
+use crate::errors::RuntimeError;
+use crate::kernel::kernel_api::{KernelApi, KernelInvocation};
+use crate::system::actor::Actor;
 use crate::system::module::SystemModule;
+use crate::system::system_callback::SystemConfig;
+use crate::system::system_callback_api::SystemCallbackObject;
 use crate::types::*;
 use radix_engine_interface::api::actor_api::EventFlags;
 use radix_engine_interface::api::ModuleId;
@@ -12,6 +17,9 @@ pub struct Event {
     pub flags: EventFlags,
 }
 
+/// Prefix of the preimage of seeded RUIDs, separating them from plain RUIDs.
+const RUID_SEEDED_TAG: &[u8] = b"ruid_seeded";
+
 /// Size of event flags when calculating event storage cost.
 pub const EVENT_FLAGS_LEN: usize = 4;
 
@@ -21,14 +29,60 @@ impl Event {
     }
 }
 
+/// A log emitted during the transaction, along with the actor which emitted it.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct LogRecord {
+    pub level: Level,
+    /// The topic the log was emitted under, if any, e.g. `swap`.
+    pub topic: Option<String>,
+    pub message: String,
+    /// The blueprint whose code emitted the log.
+    pub blueprint_id: Option<BlueprintId>,
+    /// The global component whose method, or whose inner object's method, emitted the log.
+    pub global_address: Option<GlobalAddress>,
+    /// An SBOR-encoded value attached to the log.
+    pub payload: Option<Vec<u8>>,
+}
+
+impl LogRecord {
+    /// The size of the record, as checked against the log size limit and charged for.
+    pub fn len(&self) -> usize {
+        self.topic
+            .as_ref()
+            .map(|topic| topic.len())
+            .unwrap_or_default()
+            + self.message.len()
+            + self
+                .payload
+                .as_ref()
+                .map(|payload| payload.len())
+                .unwrap_or_default()
+    }
+
+    /// Decodes the payload of the log, if it has one.
+    pub fn payload_as<T: ScryptoDecode>(&self) -> Option<Result<T, DecodeError>> {
+        self.payload.as_ref().map(|payload| scrypto_decode(payload))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TransactionRuntimeModule {
     pub network_definition: NetworkDefinition,
     pub tx_hash: Hash,
     pub next_id: u32,
-    pub logs: Vec<(Level, String)>,
+    /// The counters of seeded RUIDs, by hash of the domain. They are kept apart from `next_id`
+    /// so that seeded generation doesn't shift the RUIDs allocated to non-fungibles, and apart
+    /// from each other so that generating in one domain doesn't shift the RUIDs of another.
+    pub next_seeded_ids: IndexMap<Hash, u32>,
+    pub logs: Vec<LogRecord>,
     pub events: Vec<Event>,
     pub replacements: IndexMap<(NodeId, ModuleId), (NodeId, ModuleId)>,
+    /// The number of manifest instructions which have completed.
+    pub instructions_completed: usize,
+    /// The packages whose code has been invoked, in order of first invocation.
+    pub invoked_packages: IndexSet<PackageAddress>,
+    /// The details of the panic which failed the transaction, if any.
+    pub panic_details: Option<PanicDetails>,
 }
 
 impl TransactionRuntimeModule {
@@ -42,14 +96,38 @@ impl TransactionRuntimeModule {
         hash(bytes).0
     }
 
-    pub fn add_log(&mut self, level: Level, message: String) {
-        self.logs.push((level, message))
+    /// Derives a RUID from the transaction hash, the caller-provided domain and the number of
+    /// RUIDs generated so far in this transaction for that domain.
+    ///
+    /// The result is fully determined by the transaction, so it can't be relied upon to be secret
+    /// from whoever constructs the transaction intent, but differs per call and per domain.
+    pub fn generate_ruid_seeded(&mut self, domain: &[u8]) -> [u8; 32] {
+        let domain_hash = hash(domain);
+        let next_id = self.next_seeded_ids.entry(domain_hash).or_default();
+
+        let mut bytes = Vec::with_capacity(RUID_SEEDED_TAG.len() + 68);
+        bytes.extend_from_slice(RUID_SEEDED_TAG);
+        bytes.extend_from_slice(self.tx_hash.as_slice());
+        bytes.extend_from_slice(domain_hash.as_slice());
+        bytes.extend_from_slice(&next_id.to_le_bytes());
+
+        *next_id += 1;
+
+        hash(bytes).0
+    }
+
+    pub fn add_log(&mut self, log: LogRecord) {
+        self.logs.push(log)
     }
 
     pub fn add_event(&mut self, event: Event) {
         self.events.push(event)
     }
 
+    pub fn set_panic_details(&mut self, panic_details: PanicDetails) {
+        self.panic_details = Some(panic_details)
+    }
+
     pub fn add_replacement(&mut self, old: (NodeId, ModuleId), new: (NodeId, ModuleId)) {
         self.replacements.insert(old, new);
     }
@@ -57,7 +135,7 @@ impl TransactionRuntimeModule {
     pub fn finalize(
         self,
         is_success: bool,
-    ) -> (Vec<(EventTypeIdentifier, Vec<u8>)>, Vec<(Level, String)>) {
+    ) -> (Vec<(EventTypeIdentifier, Vec<u8>)>, Vec<LogRecord>) {
         let mut results = Vec::new();
 
         for Event {
@@ -92,7 +170,22 @@ impl TransactionRuntimeModule {
     }
 }
 
-impl<K: KernelCallbackObject> SystemModule<K> for TransactionRuntimeModule {}
+impl<V: SystemCallbackObject> SystemModule<SystemConfig<V>> for TransactionRuntimeModule {
+    fn before_invoke<Y: KernelApi<SystemConfig<V>>>(
+        api: &mut Y,
+        invocation: &KernelInvocation<Actor>,
+    ) -> Result<(), RuntimeError> {
+        if let Some(blueprint_id) = invocation.call_frame_data.blueprint_id() {
+            api.kernel_get_system()
+                .modules
+                .transaction_runtime
+                .invoked_packages
+                .insert(blueprint_id.package_address);
+        }
+
+        Ok(())
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -107,9 +200,13 @@ mod tests {
             )
             .unwrap(),
             next_id: 5,
+            next_seeded_ids: index_map_new(),
             logs: Vec::new(),
             events: Vec::new(),
             replacements: index_map_new(),
+            instructions_completed: 0,
+            invoked_packages: index_set_new(),
+            panic_details: None,
         };
         assert_eq!(
             NonFungibleLocalId::ruid(id.generate_ruid()).to_string(),
@@ -120,9 +217,13 @@ mod tests {
             network_definition: NetworkDefinition::simulator(),
             tx_hash: Hash([0u8; 32]),
             next_id: 5,
+            next_seeded_ids: index_map_new(),
             logs: Vec::new(),
             events: Vec::new(),
             replacements: index_map_new(),
+            instructions_completed: 0,
+            invoked_packages: index_set_new(),
+            panic_details: None,
         };
         assert_eq!(
             NonFungibleLocalId::ruid(id.generate_ruid()).to_string(),
@@ -133,13 +234,50 @@ mod tests {
             network_definition: NetworkDefinition::simulator(),
             tx_hash: Hash([255u8; 32]),
             next_id: 5,
+            next_seeded_ids: index_map_new(),
             logs: Vec::new(),
             events: Vec::new(),
             replacements: index_map_new(),
+            instructions_completed: 0,
+            invoked_packages: index_set_new(),
+            panic_details: None,
         };
         assert_eq!(
             NonFungibleLocalId::ruid(id.generate_ruid()).to_string(),
             "{04660ebc8e2a2b36-44a6553bd6a17a3a-ef14ce1fae4cb5bc-000811f979007003}"
         );
     }
+
+    #[test]
+    fn test_seeded_ruid_gen() {
+        let mut id = TransactionRuntimeModule {
+            network_definition: NetworkDefinition::simulator(),
+            tx_hash: Hash([0u8; 32]),
+            next_id: 0,
+            next_seeded_ids: index_map_new(),
+            logs: Vec::new(),
+            events: Vec::new(),
+            replacements: index_map_new(),
+            instructions_completed: 0,
+            invoked_packages: index_set_new(),
+            panic_details: None,
+        };
+
+        let first = id.generate_ruid_seeded(b"lottery");
+        let other_domain = id.generate_ruid_seeded(b"dice");
+        let second = id.generate_ruid_seeded(b"lottery");
+        assert_ne!(first, second);
+        assert_ne!(first, other_domain);
+        assert_ne!(second, other_domain);
+
+        // Seeded generation doesn't advance the plain RUID counter
+        assert_eq!(id.next_id, 0);
+
+        // Generating in one domain doesn't shift the RUIDs of another
+        let mut isolated = id.clone();
+        isolated.next_seeded_ids = index_map_new();
+        assert_eq!(isolated.generate_ruid_seeded(b"lottery"), first);
+        assert_eq!(isolated.generate_ruid_seeded(b"lottery"), second);
+        assert_eq!(isolated.generate_ruid_seeded(b"dice"), other_domain);
+    }
 }
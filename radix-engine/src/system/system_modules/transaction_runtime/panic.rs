@@ -0,0 +1,96 @@
+use crate::types::*;
+
+/// Separates the panic payload from its location, in the messages of the Scrypto panic hook.
+const PANIC_LOCATION_SEPARATOR: &str = " @ ";
+
+/// Where a blueprint panicked, as reported by its panic hook.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct PanicLocation {
+    pub file: String,
+    pub line: u32,
+    pub column: u32,
+}
+
+/// A structured panic of a blueprint, captured alongside the `PanicMessage` error which
+/// failed the transaction.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct PanicDetails {
+    /// The panic payload, without the location.
+    pub message: String,
+    /// The blueprint which panicked.
+    pub blueprint_id: Option<BlueprintId>,
+    /// The name of the package export being executed, e.g. `Hello_free_token`.
+    pub export_name: Option<String>,
+    /// The source location of the panic, on a best-effort basis: only available if the
+    /// blueprint was built with panic location info, which `scrypto build` keeps by default.
+    pub location: Option<PanicLocation>,
+}
+
+impl PanicDetails {
+    /// Splits a panic message in the `<payload> @ <file>:<line>:<column>` format of the
+    /// Scrypto panic hook. Messages in other formats are kept whole, without a location.
+    pub fn from_message(
+        message: &str,
+        blueprint_id: Option<BlueprintId>,
+        export_name: Option<String>,
+    ) -> Self {
+        let (message, location) = match message.rsplit_once(PANIC_LOCATION_SEPARATOR) {
+            Some((payload, location)) => match Self::parse_location(location) {
+                Some(location) => (payload, Some(location)),
+                None if location == "<unknown>" => (payload, None),
+                None => (message, None),
+            },
+            None => (message, None),
+        };
+
+        Self {
+            message: message.to_string(),
+            blueprint_id,
+            export_name,
+            location,
+        }
+    }
+
+    fn parse_location(location: &str) -> Option<PanicLocation> {
+        // File names may contain colons, so the line and column are split from the end
+        let mut parts = location.rsplitn(3, ':');
+        let column = parts.next()?.parse().ok()?;
+        let line = parts.next()?.parse().ok()?;
+        let file = parts.next()?;
+        if file.is_empty() {
+            return None;
+        }
+        Some(PanicLocation {
+            file: file.to_string(),
+            line,
+            column,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_panic_details_from_message() {
+        let details = PanicDetails::from_message("Out of tokens @ src/lib.rs:42:13", None, None);
+        assert_eq!(details.message, "Out of tokens");
+        assert_eq!(
+            details.location,
+            Some(PanicLocation {
+                file: "src/lib.rs".to_string(),
+                line: 42,
+                column: 13,
+            })
+        );
+
+        let details = PanicDetails::from_message("Out of tokens @ <unknown>", None, None);
+        assert_eq!(details.message, "Out of tokens");
+        assert_eq!(details.location, None);
+
+        let details = PanicDetails::from_message("Contact me @ home", None, None);
+        assert_eq!(details.message, "Contact me @ home");
+        assert_eq!(details.location, None);
+    }
+}
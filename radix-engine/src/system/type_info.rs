@@ -11,6 +11,8 @@ pub enum TypeInfoSubstate {
     GlobalAddressReservation(GlobalAddress),
     /// Represents a phantom global object, to make allocated global address usable.
     GlobalAddressPhantom(GlobalAddressPhantom),
+    /// Represents a key value store whose entries are ordered by a sort prefix.
+    IndexStore(KeyValueStoreInfo),
 }
 
 impl TypeInfoSubstate {
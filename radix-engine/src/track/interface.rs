@@ -92,6 +92,13 @@ pub trait CommitableSubstateStore {
         on_io_access: &mut F,
     ) -> Result<Option<&IndexedScryptoValue>, E>;
 
+    /// Loads the given substates from the database in a single batch, ahead of their first read.
+    ///
+    /// This is only a hint: it doesn't change the outcome of any later access (including the
+    /// reported [`IOAccess`]es), only how the underlying database gets queried. Substates which
+    /// are already tracked are skipped.
+    fn prefetch_substates(&mut self, substates: Vec<(NodeId, PartitionNumber, SubstateKey)>);
+
     /// Inserts a substate into the substate store.
     ///
     /// Clients must ensure the `node_id`/`partition_num` is a node which has been created; otherwise, the behavior
@@ -143,6 +150,25 @@ pub trait CommitableSubstateStore {
         on_io_access: &mut F,
     ) -> Result<Vec<SubstateKey>, E>;
 
+    /// Returns Substate Keys of maximum count for a given partition, in the database order,
+    /// starting after the given key (or from the beginning if none is given).
+    ///
+    /// Unlike [`scan_keys`](Self::scan_keys), the order is stable across calls, so the last
+    /// returned key can be used to continue the scan.
+    ///
+    /// Clients must ensure that the SubstateKeyContent which the partition is
+    /// associated with is passed in. The returned SubstateKeys are guaranteed to be of
+    /// this type.
+    /// Otherwise, behavior is undefined.
+    fn scan_keys_after<K: SubstateKeyContent + 'static, E, F: FnMut(IOAccess) -> Result<(), E>>(
+        &mut self,
+        node_id: &NodeId,
+        partition_num: PartitionNumber,
+        after: Option<&SubstateKey>,
+        count: u32,
+        on_io_access: &mut F,
+    ) -> Result<Vec<SubstateKey>, E>;
+
     /// Removes substates of maximum count for a given partition.
     ///
     /// Clients must ensure that the SubstateKeyContent which the partition is
@@ -160,10 +186,14 @@ pub trait CommitableSubstateStore {
     ) -> Result<Vec<(SubstateKey, IndexedScryptoValue)>, E>;
 
     /// Returns tuple of substate vector and boolean which is true for the first database access.
+    ///
+    /// If a sort prefix is given, the scan starts from the first substate with that (or a
+    /// greater) sort prefix.
     fn scan_sorted_substates<E, F: FnMut(IOAccess) -> Result<(), E>>(
         &mut self,
         node_id: &NodeId,
         partition_num: PartitionNumber,
+        from_sort_prefix: Option<[u8; 2]>,
         count: u32,
         on_io_access: &mut F,
     ) -> Result<Vec<(SortedKey, IndexedScryptoValue)>, E>;
@@ -10,7 +10,7 @@ use radix_engine_store_interface::db_key_mapper::SubstateKeyContent;
 use radix_engine_store_interface::interface::DbPartitionKey;
 use radix_engine_store_interface::{
     db_key_mapper::DatabaseKeyMapper,
-    interface::{DbSortKey, PartitionEntry, SubstateDatabase},
+    interface::{DbSortKey, DbSubstateKey, DbSubstateValue, PartitionEntry, SubstateDatabase},
 };
 use sbor::rust::collections::btree_map::Entry;
 use sbor::rust::iter::empty;
@@ -27,6 +27,9 @@ pub enum TrackFinalizeError {
 pub struct Track<'s, S: SubstateDatabase, M: DatabaseKeyMapper + 'static> {
     /// Substate database, use `get_substate_from_db` and `list_entries_from_db` for access
     substate_db: &'s S,
+    /// Substate values loaded from the database ahead of their first read, in batches.
+    /// An entry is consumed (and accounted for as a database read) by `get_substate_from_db`.
+    prefetched_substates: IndexMap<DbSubstateKey, Option<DbSubstateValue>>,
 
     tracked_nodes: IndexMap<NodeId, TrackedNode>,
     force_write_tracked_nodes: IndexMap<NodeId, TrackedNode>,
@@ -42,6 +45,7 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper + 'static> Track<'s, S, M> {
     pub fn new(substate_db: &'s S) -> Self {
         Self {
             substate_db,
+            prefetched_substates: index_map_new(),
             force_write_tracked_nodes: index_map_new(),
             tracked_nodes: index_map_new(),
             deleted_partitions: index_set_new(),
@@ -53,13 +57,15 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper + 'static> Track<'s, S, M> {
     // TODO cleanup interface to avoid redundant information
     fn get_substate_from_db<E, F: FnMut(IOAccess) -> Result<(), E>>(
         substate_db: &'s S,
+        prefetched_substates: &mut IndexMap<DbSubstateKey, Option<DbSubstateValue>>,
         partition_key: &DbPartitionKey,
         sort_key: &DbSortKey,
         on_io_access: &mut F,
         canonical_substate_key: CanonicalSubstateKey,
     ) -> Result<Option<IndexedScryptoValue>, E> {
-        let result = substate_db
-            .get_substate(partition_key, sort_key)
+        let result = prefetched_substates
+            .swap_remove(&(partition_key.clone(), sort_key.clone()))
+            .unwrap_or_else(|| substate_db.get_substate(partition_key, sort_key))
             .map(|e| IndexedScryptoValue::from_vec(e).expect("Failed to decode substate"));
         if let Some(x) = &result {
             on_io_access(IOAccess::ReadFromDb(canonical_substate_key, x.len()))?;
@@ -78,6 +84,7 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper + 'static> Track<'s, S, M> {
     >(
         substate_db: &'x S,
         partition_key: &DbPartitionKey,
+        from_sort_key: Option<&DbSortKey>,
         on_io_access: &'x mut F,
         canonical_partition: CanonicalPartition,
     ) -> Box<dyn Iterator<Item = Result<(DbSortKey, (SubstateKey, IndexedScryptoValue)), E>> + 'x>
@@ -136,7 +143,7 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper + 'static> Track<'s, S, M> {
         }
 
         Box::new(TracedIterator {
-            iterator: substate_db.list_entries(partition_key),
+            iterator: substate_db.list_entries_from(partition_key, from_sort_key),
             on_io_access,
             canonical_partition,
             errored_out: false,
@@ -268,6 +275,7 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper + 'static> Track<'s, S, M> {
                     let db_partition_key = M::to_db_partition_key(node_id, partition_number);
                     let substate_value = Self::get_substate_from_db(
                         self.substate_db,
+                        &mut self.prefetched_substates,
                         &db_partition_key,
                         &M::to_db_sort_key(&substate_key),
                         on_io_access,
@@ -418,6 +426,51 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper + 'static> CommitableSubstate
         Ok(value)
     }
 
+    fn prefetch_substates(&mut self, substates: Vec<(NodeId, PartitionNumber, SubstateKey)>) {
+        let mut db_keys = Vec::new();
+        for (node_id, partition_number, substate_key) in substates {
+            if self
+                .transient_substates
+                .is_transient(&node_id, partition_number, &substate_key)
+            {
+                continue;
+            }
+
+            let db_sort_key = M::to_db_sort_key(&substate_key);
+            let is_tracked = self
+                .tracked_nodes
+                .get(&node_id)
+                .map(|tracked_node| {
+                    tracked_node.is_new
+                        || tracked_node
+                            .tracked_partitions
+                            .get(&partition_number)
+                            .map(|partition| partition.substates.contains_key(&db_sort_key))
+                            .unwrap_or(false)
+                })
+                .unwrap_or(false);
+            let db_key = (
+                M::to_db_partition_key(&node_id, partition_number),
+                db_sort_key,
+            );
+            if is_tracked
+                || self.prefetched_substates.contains_key(&db_key)
+                || db_keys.contains(&db_key)
+            {
+                continue;
+            }
+            db_keys.push(db_key);
+        }
+
+        if db_keys.is_empty() {
+            return;
+        }
+
+        let values = self.substate_db.get_substates(&db_keys);
+        self.prefetched_substates
+            .extend(db_keys.into_iter().zip(values));
+    }
+
     fn set_substate<E, F: FnMut(IOAccess) -> Result<(), E>>(
         &mut self,
         node_id: NodeId,
@@ -588,6 +641,7 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper + 'static> CommitableSubstate
         let mut tracked_iter = IterationCountedIter::new(Self::list_entries_from_db::<E, F, K>(
             self.substate_db,
             &db_partition_key,
+            None,
             on_io_access,
             CanonicalPartition {
                 node_id: *node_id,
@@ -622,6 +676,70 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper + 'static> CommitableSubstate
         Ok(items)
     }
 
+    fn scan_keys_after<K: SubstateKeyContent + 'static, E, F: FnMut(IOAccess) -> Result<(), E>>(
+        &mut self,
+        node_id: &NodeId,
+        partition_number: PartitionNumber,
+        after: Option<&SubstateKey>,
+        limit: u32,
+        on_io_access: &mut F,
+    ) -> Result<Vec<SubstateKey>, E> {
+        let limit: usize = limit.try_into().unwrap();
+        let after_db_sort_key = after.map(|key| M::to_db_sort_key(key));
+
+        let node_updates = self.tracked_nodes.get(node_id);
+        let is_new = node_updates
+            .map(|tracked_node| tracked_node.is_new)
+            .unwrap_or(false);
+        let tracked_partition =
+            node_updates.and_then(|n| n.tracked_partitions.get(&partition_number));
+
+        // initialize the "from db" iterator, starting from the given key (which is skipped below)
+        let raw_db_entries: Box<
+            dyn Iterator<Item = Result<(DbSortKey, (SubstateKey, IndexedScryptoValue)), E>>,
+        > = if is_new {
+            Box::new(empty()) // optimization: avoid touching the database altogether
+        } else {
+            let partition_key = M::to_db_partition_key(node_id, partition_number);
+            Box::new(Self::list_entries_from_db::<E, F, K>(
+                self.substate_db,
+                &partition_key,
+                after_db_sort_key.as_ref(),
+                on_io_access,
+                CanonicalPartition {
+                    node_id: *node_id,
+                    partition_number,
+                },
+            ))
+        };
+
+        // initialize the "from track" iterator
+        let tracked_entry_changes = tracked_partition
+            .into_iter()
+            .flat_map(|tracked_partition| tracked_partition.substates.iter())
+            .map(|(db_sort_key, tracked_substate)| {
+                let change = tracked_substate
+                    .substate_value
+                    .get()
+                    .map(|value| (tracked_substate.substate_key.clone(), value.clone()));
+                (db_sort_key.clone(), change)
+            });
+
+        let mut items = Vec::new();
+        for result in OverlayingResultIterator::new(raw_db_entries, tracked_entry_changes)
+            .filter(|result| match (result, &after_db_sort_key) {
+                (Ok((db_sort_key, _)), Some(after_db_sort_key)) => db_sort_key > after_db_sort_key,
+                _ => true,
+            })
+            .take(limit)
+        {
+            let (_db_sort_key, (substate_key, _substate_value)) = result?;
+            items.push(substate_key);
+        }
+
+        Ok(items)
+    }
+
     fn drain_substates<K: SubstateKeyContent + 'static, E, F: FnMut(IOAccess) -> Result<(), E>>(
         &mut self,
         node_id: &NodeId,
@@ -679,6 +797,7 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper + 'static> CommitableSubstate
                 IterationCountedIter::new(Self::list_entries_from_db::<E, F, K>(
                     self.substate_db,
                     &db_partition_key,
+                    None,
                     on_io_access,
                     CanonicalPartition {
                         node_id: *node_id,
@@ -753,11 +872,14 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper + 'static> CommitableSubstate
         &mut self,
         node_id: &NodeId,
         partition_number: PartitionNumber,
+        from_sort_prefix: Option<[u8; 2]>,
         limit: u32,
         on_io_access: &mut F,
     ) -> Result<Vec<(SortedKey, IndexedScryptoValue)>, E> {
         // TODO: ensure we abort if any substates are write locked.
         let limit: usize = limit.try_into().unwrap();
+        let from_db_sort_key =
+            from_sort_prefix.map(|sort_prefix| M::sort_prefix_to_db_sort_key(&sort_prefix));
 
         // initialize the track partition, since we will definitely need it: either to read values from it OR to update the `range_read` on it
         let tracked_node = self
@@ -780,6 +902,7 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper + 'static> CommitableSubstate
             Box::new(Self::list_entries_from_db::<E, F, SortedKey>(
                 self.substate_db,
                 &partition_key,
+                from_db_sort_key.as_ref(),
                 on_io_access,
                 CanonicalPartition {
                     node_id: *node_id,
@@ -792,21 +915,20 @@ impl<'s, S: SubstateDatabase, M: DatabaseKeyMapper + 'static> CommitableSubstate
         });
 
         // initialize the "from track" iterator
-        let tracked_entry_changes =
-            tracked_partition
-                .substates
-                .iter()
-                .map(|(db_sort_key, tracked_substate)| {
-                    // TODO: ensure we abort if any substates are write locked.
-                    if let Some(value) = tracked_substate.substate_value.get() {
-                        (
-                            db_sort_key.clone(),
-                            Some((tracked_substate.substate_key.clone(), value.clone())),
-                        )
-                    } else {
-                        (db_sort_key.clone(), None)
-                    }
-                });
+        let tracked_entry_changes = tracked_partition
+            .substates
+            .range(from_db_sort_key.clone().unwrap_or(DbSortKey(vec![]))..)
+            .map(|(db_sort_key, tracked_substate)| {
+                // TODO: ensure we abort if any substates are write locked.
+                if let Some(value) = tracked_substate.substate_value.get() {
+                    (
+                        db_sort_key.clone(),
+                        Some((tracked_substate.substate_key.clone(), value.clone())),
+                    )
+                } else {
+                    (db_sort_key.clone(), None)
+                }
+            });
 
         let mut items = Vec::new();
         // construct the composite iterator, which applies changes read from our track on top of db values
@@ -0,0 +1,124 @@
+use crate::system::system_db_reader::SystemDatabaseReader;
+use crate::track::TrackedNode;
+use crate::transaction::SystemStructure;
+use crate::types::*;
+use radix_engine_store_interface::interface::SubstateDatabase;
+
+/// A compact description of an application event, which allows filtering the events of a
+/// transaction without decoding their payloads.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct EventIndexEntry {
+    pub emitter: Emitter,
+    pub event_name: String,
+    /// The hashes of the values of the [`Indexed`] fields of the event, by field name.
+    pub indexed_fields: Vec<(String, Hash)>,
+}
+
+impl EventIndexEntry {
+    /// Returns the hash an indexed field with the given value is indexed by.
+    pub fn field_hash<T: ScryptoEncode + ?Sized>(value: &T) -> Hash {
+        hash(scrypto_encode(value).unwrap())
+    }
+
+    pub fn indexed_field(&self, field_name: &str) -> Option<&Hash> {
+        self.indexed_fields
+            .iter()
+            .find(|(name, _)| name == field_name)
+            .map(|(_, hash)| hash)
+    }
+
+    /// Indexes the given application events, using the event schemas resolved into the given
+    /// system structure.
+    pub fn resolve<S: SubstateDatabase>(
+        substate_db: &S,
+        updates: &IndexMap<NodeId, TrackedNode>,
+        application_events: &Vec<(EventTypeIdentifier, Vec<u8>)>,
+        system_structure: &SystemStructure,
+    ) -> Vec<Self> {
+        let system_reader = SystemDatabaseReader::new_with_overlay(substate_db, updates);
+        application_events
+            .iter()
+            .map(|(event_type_identifier, event_data)| {
+                let indexed_fields = system_structure
+                    .event_system_structures
+                    .get(event_type_identifier)
+                    .map(|structure| {
+                        Self::hash_indexed_fields(
+                            &system_reader,
+                            &structure.package_type_reference.full_type_id,
+                            event_data,
+                        )
+                    })
+                    .unwrap_or_default();
+                Self {
+                    emitter: event_type_identifier.0.clone(),
+                    event_name: event_type_identifier.1.clone(),
+                    indexed_fields,
+                }
+            })
+            .collect()
+    }
+
+    fn hash_indexed_fields<S: SubstateDatabase>(
+        system_reader: &SystemDatabaseReader<S>,
+        full_type_id: &FullyScopedTypeId<PackageAddress>,
+        event_data: &[u8],
+    ) -> Vec<(String, Hash)> {
+        let FullyScopedTypeId(package_address, schema_hash, type_id) = full_type_id;
+        let Ok(schema) = system_reader.get_schema(package_address.as_node_id(), schema_hash) else {
+            return Vec::new();
+        };
+        let schema = schema.v1();
+
+        // Only the top-level fields of event structs can be indexed
+        let Some(TypeKind::Tuple { field_types }) = schema.resolve_type_kind(*type_id) else {
+            return Vec::new();
+        };
+        let indexed_field_indices: Vec<usize> = field_types
+            .iter()
+            .enumerate()
+            .filter(|(_, field_type)| Self::is_indexed_field_type(schema, **field_type))
+            .map(|(index, _)| index)
+            .collect();
+        if indexed_field_indices.is_empty() {
+            return Vec::new();
+        }
+
+        let Ok(ScryptoValue::Tuple { fields }) = scrypto_decode::<ScryptoValue>(event_data) else {
+            return Vec::new();
+        };
+        let field_names = schema
+            .resolve_matching_tuple_metadata(*type_id, field_types.len())
+            .field_names;
+
+        indexed_field_indices
+            .into_iter()
+            .filter_map(|index| {
+                // The `Indexed` wrapper is a single field tuple
+                let ScryptoValue::Tuple { fields: wrapped } = fields.get(index)? else {
+                    return None;
+                };
+                let field_name = field_names
+                    .and_then(|names| names.get(index))
+                    .map(|name| name.to_string())
+                    .unwrap_or_else(|| index.to_string());
+                Some((field_name, Self::field_hash(wrapped.get(0)?)))
+            })
+            .collect()
+    }
+
+    /// Whether the type is an [`Indexed`] wrapper, which is recognized by both its type name and
+    /// the name of its single field.
+    fn is_indexed_field_type(schema: &SchemaV1<ScryptoCustomSchema>, type_id: LocalTypeId) -> bool {
+        if schema.resolve_type_name_from_metadata(type_id) != Some(INDEXED_EVENT_FIELD_TYPE_NAME) {
+            return false;
+        }
+        match schema.resolve_type_kind(type_id) {
+            Some(TypeKind::Tuple { field_types }) if field_types.len() == 1 => schema
+                .resolve_matching_tuple_metadata(type_id, 1)
+                .field_names
+                .map_or(false, |names| names[0] == INDEXED_EVENT_FIELD_VALUE_NAME),
+            _ => false,
+        }
+    }
+}
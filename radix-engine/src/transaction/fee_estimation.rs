@@ -0,0 +1,230 @@
+use crate::errors::RuntimeError;
+use crate::system::system_callback_api::SystemCallbackObject;
+use crate::system::system_modules::costing::FeeTable;
+use crate::transaction::*;
+use crate::types::*;
+use radix_engine_interface::network::NetworkDefinition;
+use radix_engine_store_interface::interface::*;
+use transaction::model::Executable;
+
+#[derive(Debug, Clone)]
+pub struct FeeEstimationConfig {
+    pub network_definition: NetworkDefinition,
+    pub costing_parameters: CostingParameters,
+    /// Whether to skip all auth checks, as with [`PreviewExtension::DisableAuth`](transaction::model::PreviewExtension::DisableAuth)
+    pub disable_auth: bool,
+    /// The number of signatures the submitted transaction will carry on top of those already
+    /// charged for by the executable (none, for a preview executable).
+    ///
+    /// These signatures are never verified, but their verification cost is added to the estimate.
+    pub num_of_signatures: usize,
+    /// How much, in percent, the state-dependent share of the execution cost may vary between
+    /// the estimate and the actual execution.
+    ///
+    /// This share is the cost of accessing the substate database, which depends on the size of
+    /// the substates read and written when the transaction is finally executed. How much these
+    /// may change before then depends on the transaction, so no variance is assumed by default:
+    /// the estimate is exact for the current state.
+    pub state_dependent_variance_percentage: u16,
+    /// The fee table the transaction will be costed with, if not the default one
+    pub fee_table: Option<FeeTable>,
+}
+
+impl FeeEstimationConfig {
+    pub fn new(network_definition: NetworkDefinition) -> Self {
+        Self {
+            network_definition,
+            costing_parameters: CostingParameters::default(),
+            disable_auth: false,
+            num_of_signatures: 0,
+            state_dependent_variance_percentage: 0,
+            fee_table: None,
+        }
+    }
+
+    pub fn with_auth(mut self, enabled: bool) -> Self {
+        self.disable_auth = !enabled;
+        self
+    }
+
+    pub fn with_num_of_signatures(mut self, num_of_signatures: usize) -> Self {
+        self.num_of_signatures = num_of_signatures;
+        self
+    }
+
+    pub fn with_state_dependent_variance_percentage(mut self, percentage: u16) -> Self {
+        self.state_dependent_variance_percentage = percentage;
+        self
+    }
+
+    pub fn with_fee_table(mut self, fee_table: FeeTable) -> Self {
+        self.fee_table = Some(fee_table);
+        self
+    }
+
+    fn execution_config(&self) -> ExecutionConfig {
+        let execution_config = ExecutionConfig::for_preview(self.network_definition.clone())
+            .with_auth(!self.disable_auth)
+            .with_cost_breakdown(true);
+        match &self.fee_table {
+            Some(fee_table) => execution_config.with_fee_table(fee_table.clone()),
+            None => execution_config,
+        }
+    }
+}
+
+/// A fee in XRD, along with the range the actual fee is expected to fall in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeBounds {
+    pub lower: Decimal,
+    pub estimate: Decimal,
+    pub upper: Decimal,
+}
+
+impl FeeBounds {
+    pub fn exact(amount: Decimal) -> Self {
+        Self {
+            lower: amount,
+            estimate: amount,
+            upper: amount,
+        }
+    }
+
+    pub fn contains(&self, amount: Decimal) -> bool {
+        self.lower <= amount && amount <= self.upper
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        Self {
+            lower: self.lower.checked_add(other.lower).unwrap(),
+            estimate: self.estimate.checked_add(other.estimate).unwrap(),
+            upper: self.upper.checked_add(other.upper).unwrap(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum FeeEstimationError {
+    /// The transaction was rejected, so no fee would be charged.
+    Rejected(RejectResult),
+    /// The transaction was aborted, so no fee would be charged.
+    Aborted(AbortResult),
+    /// The transaction failed, so the fee would only cover the execution up to the failure.
+    Failed(RuntimeError),
+}
+
+#[derive(Debug, Clone)]
+pub struct FeeEstimate {
+    /// Execution and finalization cost, including the verification of signatures.
+    /// Bounded by the variance of the costs which depend on the state of the substate database.
+    pub execution: FeeBounds,
+    /// Royalties charged by the packages and components called
+    pub royalties: FeeBounds,
+    /// State and archive storage cost
+    pub storage: FeeBounds,
+    /// The tip, at the executable's tip percentage, for the upper bound of the execution cost
+    pub tip_suggestion: Decimal,
+    /// The receipt of the costing-only execution the estimate was derived from
+    pub receipt: TransactionReceipt,
+}
+
+impl FeeEstimate {
+    /// The overall fee, excluding the tip.
+    pub fn total(&self) -> FeeBounds {
+        self.execution.add(&self.royalties).add(&self.storage)
+    }
+
+    /// The amount of XRD to lock to cover the fee in all but the most unexpected cases.
+    pub fn fee_to_lock(&self) -> Decimal {
+        self.total().upper.checked_add(self.tip_suggestion).unwrap()
+    }
+}
+
+/// Estimates the fees of a transaction, without committing anything.
+///
+/// An estimate is only given for transactions which would be committed successfully, as the fee
+/// of any other transaction says nothing about the fee of the one eventually submitted.
+///
+/// The transaction is executed as a preview, so signatures are never verified and auth is only
+/// enforced unless disabled by the config. The execution cost units spent reading from the
+/// substate database are treated as state dependent, as they vary with what other transactions
+/// commit in the meantime, and bound the execution estimate.
+pub fn estimate_fees<S: SubstateDatabase, V: SystemCallbackObject + Clone>(
+    substate_db: &S,
+    vm: V,
+    config: &FeeEstimationConfig,
+    executable: &Executable,
+) -> Result<FeeEstimate, FeeEstimationError> {
+    let execution_config = config.execution_config();
+    let receipt = execute_transaction(
+        substate_db,
+        vm,
+        &config.costing_parameters,
+        &execution_config,
+        executable,
+    );
+    match &receipt.result {
+        TransactionResult::Commit(commit) => {
+            if let TransactionOutcome::Failure(error) = &commit.outcome {
+                return Err(FeeEstimationError::Failed(error.clone()));
+            }
+        }
+        TransactionResult::Reject(reject) => {
+            return Err(FeeEstimationError::Rejected(reject.clone()))
+        }
+        TransactionResult::Abort(abort) => return Err(FeeEstimationError::Aborted(abort.clone())),
+    }
+
+    let execution_cost_unit_price = config.costing_parameters.execution_cost_unit_price;
+    let fee_summary = &receipt.fee_summary;
+
+    let signature_cost_in_xrd = execution_cost_unit_price
+        .checked_mul(
+            execution_config
+                .fee_table()
+                .verify_tx_signatures_cost(config.num_of_signatures),
+        )
+        .unwrap();
+    let execution_cost_in_xrd = fee_summary
+        .total_execution_cost_in_xrd
+        .checked_add(fee_summary.total_finalization_cost_in_xrd)
+        .unwrap()
+        .checked_add(signature_cost_in_xrd)
+        .unwrap();
+
+    let state_dependent_cost_units = receipt
+        .fee_details
+        .as_ref()
+        .map(|details| details.state_dependent_execution_cost_units)
+        .unwrap_or_default();
+    let variance_in_xrd = execution_cost_unit_price
+        .checked_mul(state_dependent_cost_units)
+        .unwrap()
+        .checked_mul(config.state_dependent_variance_percentage)
+        .unwrap()
+        .checked_div(dec!(100))
+        .unwrap();
+
+    let execution = FeeBounds {
+        lower: execution_cost_in_xrd
+            .checked_sub(variance_in_xrd)
+            .unwrap()
+            .max(Decimal::ZERO),
+        estimate: execution_cost_in_xrd,
+        upper: execution_cost_in_xrd.checked_add(variance_in_xrd).unwrap(),
+    };
+
+    let tip_suggestion = executable
+        .costing_parameters()
+        .tip
+        .tip_on(execution.upper)
+        .unwrap();
+
+    Ok(FeeEstimate {
+        execution,
+        royalties: FeeBounds::exact(fee_summary.total_royalty_cost_in_xrd),
+        storage: FeeBounds::exact(fee_summary.total_storage_cost_in_xrd),
+        tip_suggestion,
+        receipt,
+    })
+}
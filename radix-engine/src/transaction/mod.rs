@@ -1,13 +1,21 @@
+mod event_index;
+mod fee_estimation;
 mod preview_executor;
+mod state_diff;
 mod state_update_summary;
 mod system_structure;
 mod transaction_executor;
 mod transaction_receipt;
+mod transaction_receipt_v1;
 mod transaction_reconciler;
 
+pub use event_index::*;
+pub use fee_estimation::*;
 pub use preview_executor::*;
+pub use state_diff::*;
 pub use state_update_summary::*;
 pub use system_structure::*;
 pub use transaction_executor::*;
 pub use transaction_receipt::*;
+pub use transaction_receipt_v1::*;
 pub use transaction_reconciler::*;
@@ -3,8 +3,13 @@ use crate::transaction::TransactionReceipt;
 use crate::transaction::*;
 use radix_engine_interface::network::NetworkDefinition;
 use radix_engine_store_interface::interface::*;
+#[cfg(feature = "std")]
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Mutex,
+};
 use transaction::errors::TransactionValidationError;
-use transaction::model::PreviewIntentV1;
+use transaction::model::{PreviewExtension, PreviewIntentV2};
 use transaction::validation::NotarizedTransactionValidator;
 use transaction::validation::ValidationConfig;
 
@@ -17,7 +22,7 @@ pub fn execute_preview<S: SubstateDatabase, V: SystemCallbackObject + Clone>(
     substate_db: &S,
     vm: V,
     network: &NetworkDefinition,
-    preview_intent: PreviewIntentV1,
+    preview_intent: impl Into<PreviewIntentV2>,
     with_kernel_trace: bool,
 ) -> Result<TransactionReceipt, PreviewError> {
     let validation_config = ValidationConfig::default(network.id);
@@ -25,14 +30,156 @@ pub fn execute_preview<S: SubstateDatabase, V: SystemCallbackObject + Clone>(
     let validator = NotarizedTransactionValidator::new(validation_config);
 
     let validated = validator
-        .validate_preview_intent_v1(preview_intent)
+        .validate_preview_intent_v2(preview_intent.into())
         .map_err(PreviewError::TransactionValidationError)?;
 
     Ok(execute_transaction(
         substate_db,
         vm,
         &CostingParameters::default(),
-        &ExecutionConfig::for_preview(network.clone()).with_kernel_trace(with_kernel_trace),
+        &ExecutionConfig::for_preview(network.clone())
+            .with_kernel_trace(with_kernel_trace)
+            .with_auth(!validated.has_extension(PreviewExtension::DisableAuth))
+            .with_substate_value_changes(
+                validated.has_extension(PreviewExtension::IncludeSubstateValueChanges),
+            ),
         &validated.get_executable(),
     ))
 }
+
+/// Executes batches of previews concurrently against a shared, read-only substate database.
+///
+/// Each preview runs exactly as it would through [`execute_preview`], on its own `Track` - so
+/// previews in a batch never observe each other's state changes, and nothing is committed.
+#[cfg(feature = "std")]
+pub struct PreviewExecutorPool<'s, S, V>
+where
+    S: SubstateDatabase + Sync,
+    V: SystemCallbackObject + Clone + Send,
+{
+    substate_db: &'s S,
+    vm: V,
+    network: NetworkDefinition,
+    max_threads: usize,
+    with_kernel_trace: bool,
+}
+
+#[cfg(feature = "std")]
+impl<'s, S, V> PreviewExecutorPool<'s, S, V>
+where
+    S: SubstateDatabase + Sync,
+    V: SystemCallbackObject + Clone + Send,
+{
+    /// Creates a pool which uses up to one thread per available CPU.
+    pub fn new(substate_db: &'s S, vm: V, network: &NetworkDefinition) -> Self {
+        let max_threads = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1);
+        Self {
+            substate_db,
+            vm,
+            network: network.clone(),
+            max_threads,
+            with_kernel_trace: false,
+        }
+    }
+
+    pub fn with_max_threads(mut self, max_threads: usize) -> Self {
+        self.max_threads = max_threads.max(1);
+        self
+    }
+
+    pub fn with_kernel_trace(mut self, enabled: bool) -> Self {
+        self.with_kernel_trace = enabled;
+        self
+    }
+
+    /// Executes the previews, returning the results in the same order as the given intents.
+    pub fn execute_previews<P: Into<PreviewIntentV2> + Send>(
+        &self,
+        preview_intents: Vec<P>,
+    ) -> Vec<Result<TransactionReceipt, PreviewError>> {
+        let count = preview_intents.len();
+        let thread_count = self.max_threads.min(count);
+        if thread_count <= 1 {
+            return preview_intents
+                .into_iter()
+                .map(|preview_intent| self.execute_one(self.vm.clone(), preview_intent))
+                .collect();
+        }
+
+        // Work is handed out through a shared index, so that a few slow previews don't hold up
+        // the previews queued behind them on the same thread.
+        let slots: Vec<Mutex<Option<P>>> = preview_intents
+            .into_iter()
+            .map(|preview_intent| Mutex::new(Some(preview_intent)))
+            .collect();
+        let next_index = AtomicUsize::new(0);
+        let mut results: Vec<Option<Result<TransactionReceipt, PreviewError>>> =
+            (0..count).map(|_| None).collect();
+
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..thread_count)
+                .map(|_| {
+                    let vm = self.vm.clone();
+                    let substate_db = self.substate_db;
+                    let network = &self.network;
+                    let with_kernel_trace = self.with_kernel_trace;
+                    let slots = &slots;
+                    let next_index = &next_index;
+                    scope.spawn(move || {
+                        let mut thread_results = Vec::new();
+                        loop {
+                            let index = next_index.fetch_add(1, Ordering::Relaxed);
+                            if index >= count {
+                                break;
+                            }
+                            let preview_intent = slots[index]
+                                .lock()
+                                .expect("Preview slot lock should not be poisoned")
+                                .take()
+                                .expect("Each preview should only be taken once");
+                            let result = execute_preview(
+                                substate_db,
+                                vm.clone(),
+                                network,
+                                preview_intent,
+                                with_kernel_trace,
+                            );
+                            thread_results.push((index, result));
+                        }
+                        thread_results
+                    })
+                })
+                .collect();
+
+            for handle in handles {
+                let thread_results = handle
+                    .join()
+                    .unwrap_or_else(|panic| std::panic::resume_unwind(panic));
+                for (index, result) in thread_results {
+                    results[index] = Some(result);
+                }
+            }
+        });
+
+        results
+            .into_iter()
+            .map(|result| result.expect("Every preview should have been executed"))
+            .collect()
+    }
+
+    fn execute_one(
+        &self,
+        vm: V,
+        preview_intent: impl Into<PreviewIntentV2>,
+    ) -> Result<TransactionReceipt, PreviewError> {
+        execute_preview(
+            self.substate_db,
+            vm,
+            &self.network,
+            preview_intent,
+            self.with_kernel_trace,
+        )
+    }
+}
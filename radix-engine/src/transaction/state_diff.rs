@@ -0,0 +1,582 @@
+use super::{
+    BalanceChange, FieldStructure, IndexPartitionEntryStructure, KeyValuePartitionEntryStructure,
+    ObjectSubstateTypeReference, SortedIndexPartitionEntryStructure, SubstateSystemStructure,
+    SystemStructure,
+};
+use crate::blueprints::resource::{FungibleVaultBalanceFieldPayload, FungibleVaultField};
+use crate::internal_prelude::*;
+use crate::system::attached_modules::metadata::MetadataEntryEntryPayload;
+use crate::system::system_db_reader::SystemDatabaseReader;
+use crate::system::type_info::TypeInfoSubstate;
+use crate::track::{NodeStateUpdates, PartitionStateUpdates, StateUpdates};
+use colored::*;
+use radix_engine_interface::api::node_modules::metadata::MetadataValue;
+use radix_engine_store_interface::db_key_mapper::{DatabaseKeyMapper, SpreadPrefixKeyMapper};
+use radix_engine_store_interface::interface::{
+    DatabaseUpdate, DatabaseUpdates, DbPartitionKey, DbSortKey, DbSubstateValue,
+    PartitionDatabaseUpdates, PartitionEntry, SubstateDatabase,
+};
+use sbor::representations::*;
+
+/// A typed, human-readable view of the changes which a [`StateUpdates`] makes to a store.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StateDiff {
+    /// The newly created global entities, with their blueprints (if they are objects).
+    pub new_entities: IndexMap<GlobalAddress, Option<BlueprintId>>,
+    pub vault_balance_changes: IndexMap<NodeId, (ResourceAddress, BalanceChange)>,
+    pub metadata_changes: IndexMap<NodeId, IndexMap<String, MetadataChange>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MetadataChange {
+    Added(MetadataValue),
+    Updated {
+        old: MetadataValue,
+        new: MetadataValue,
+    },
+    Removed(MetadataValue),
+}
+
+impl StateDiff {
+    /// Describes the given updates, which must not have been committed to the store yet.
+    pub fn new<S: SubstateDatabase>(substate_db: &S, state_updates: &StateUpdates) -> Self {
+        let database_updates = state_updates.create_database_updates::<SpreadPrefixKeyMapper>();
+        let updated_db = SubstateDatabaseOverlay::new(substate_db, &database_updates);
+        let old_reader = SystemDatabaseReader::new(substate_db);
+        let new_reader = SystemDatabaseReader::new(&updated_db);
+
+        let mut diff = StateDiff::default();
+        for node_id in state_updates.by_node.keys() {
+            if node_id.is_global() && old_reader.get_type_info(node_id).is_err() {
+                let blueprint_id = match new_reader.get_type_info(node_id) {
+                    Ok(TypeInfoSubstate::Object(info)) => Some(info.blueprint_info.blueprint_id),
+                    _ => None,
+                };
+                diff.new_entities
+                    .insert(GlobalAddress::new_or_panic(node_id.0), blueprint_id);
+            }
+
+            if node_id.is_internal_vault() {
+                if let Some(change) =
+                    Self::vault_balance_change(&updated_db, &old_reader, &new_reader, node_id)
+                {
+                    diff.vault_balance_changes.insert(*node_id, change);
+                }
+            }
+
+            let metadata_changes = Self::metadata_changes(&updated_db, node_id);
+            if !metadata_changes.is_empty() {
+                diff.metadata_changes.insert(*node_id, metadata_changes);
+            }
+        }
+
+        diff
+    }
+
+    fn vault_balance_change<S: SubstateDatabase>(
+        updated_db: &SubstateDatabaseOverlay<S>,
+        old_reader: &SystemDatabaseReader<S>,
+        new_reader: &SystemDatabaseReader<SubstateDatabaseOverlay<S>>,
+        vault_id: &NodeId,
+    ) -> Option<(ResourceAddress, BalanceChange)> {
+        // The vault may have been created or dropped by the updates
+        let object_info = new_reader
+            .get_object_info(*vault_id)
+            .or_else(|_| old_reader.get_object_info(*vault_id))
+            .ok()?;
+        let resource_address = ResourceAddress::new_or_panic(object_info.get_outer_object().into());
+
+        let mut change = if vault_id.is_internal_fungible_vault() {
+            let old_balance = old_reader
+                .fetch_substate::<SpreadPrefixKeyMapper, FieldSubstate<FungibleVaultBalanceFieldPayload>>(
+                    vault_id,
+                    MAIN_BASE_PARTITION,
+                    &FungibleVaultField::Balance.into(),
+                )
+                .map(|substate| substate.into_payload().into_latest().amount())
+                .unwrap_or(Decimal::ZERO);
+            let new_balance = new_reader
+                .fetch_substate::<SpreadPrefixKeyMapper, FieldSubstate<FungibleVaultBalanceFieldPayload>>(
+                    vault_id,
+                    MAIN_BASE_PARTITION,
+                    &FungibleVaultField::Balance.into(),
+                )
+                .map(|substate| substate.into_payload().into_latest().amount())
+                .unwrap_or(Decimal::ZERO);
+            BalanceChange::Fungible(new_balance.checked_sub(old_balance).unwrap())
+        } else {
+            let mut added = BTreeSet::new();
+            let mut removed = BTreeSet::new();
+            let partition_key = SpreadPrefixKeyMapper::to_db_partition_key(
+                vault_id,
+                MAIN_BASE_PARTITION.at_offset(PartitionOffset(1u8)).unwrap(),
+            );
+            for (sort_key, existed, exists) in updated_db.touched_substates(&partition_key) {
+                let id: NonFungibleLocalId =
+                    scrypto_decode(&SpreadPrefixKeyMapper::map_from_db_sort_key(&sort_key))
+                        .unwrap();
+                match (existed, exists) {
+                    (false, true) => {
+                        added.insert(id);
+                    }
+                    (true, false) => {
+                        removed.insert(id);
+                    }
+                    _ => {}
+                }
+            }
+            BalanceChange::NonFungible { added, removed }
+        };
+
+        if change.prune_and_check_if_zero() {
+            None
+        } else {
+            Some((resource_address, change))
+        }
+    }
+
+    fn metadata_changes<S: SubstateDatabase>(
+        updated_db: &SubstateDatabaseOverlay<S>,
+        node_id: &NodeId,
+    ) -> IndexMap<String, MetadataChange> {
+        let partition_key =
+            SpreadPrefixKeyMapper::to_db_partition_key(node_id, METADATA_BASE_PARTITION);
+
+        let mut changes = index_map_new();
+        for (sort_key, _, _) in updated_db.touched_substates(&partition_key) {
+            let key: String =
+                scrypto_decode(&SpreadPrefixKeyMapper::map_from_db_sort_key(&sort_key)).unwrap();
+            let old_value = read_metadata_value(updated_db.root, &partition_key, &sort_key);
+            let new_value = read_metadata_value(updated_db, &partition_key, &sort_key);
+            let change = match (old_value, new_value) {
+                (None, Some(new)) => MetadataChange::Added(new),
+                (Some(old), Some(new)) if old != new => MetadataChange::Updated { old, new },
+                (Some(old), None) => MetadataChange::Removed(old),
+                _ => continue,
+            };
+            changes.insert(key, change);
+        }
+        changes
+    }
+}
+
+fn read_metadata_value<S: SubstateDatabase>(
+    substate_db: &S,
+    partition_key: &DbPartitionKey,
+    sort_key: &DbSortKey,
+) -> Option<MetadataValue> {
+    substate_db
+        .get_substate(partition_key, sort_key)
+        .and_then(|value| {
+            scrypto_decode::<KeyValueEntrySubstate<MetadataEntryEntryPayload>>(&value)
+                .unwrap()
+                .into_value()
+        })
+        .map(|value| value.into_latest())
+}
+
+/// The decoded values of a substate before and after a transaction.
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct SubstateValueChange {
+    pub node_id: NodeId,
+    pub partition_number: PartitionNumber,
+    pub substate_key: SubstateKey,
+    /// The value before the transaction, or `None` if the substate is created by it
+    pub before: Option<DecodedSubstateValue>,
+    /// The value after the transaction, or `None` if the substate is deleted by it
+    pub after: Option<DecodedSubstateValue>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct DecodedSubstateValue {
+    /// The whole substate value, including its field or entry wrapper
+    pub value: ScryptoValue,
+    /// The payload of the substate, rendered with the names of the types, fields and variants of
+    /// its schema, or `None` if the schema can't be resolved (e.g. for system substates)
+    pub annotated_payload: Option<String>,
+}
+
+impl SubstateValueChange {
+    /// Captures the values of the substates changed by the given updates, which must not have been
+    /// committed to the store yet.
+    ///
+    /// Partitions which are reset in batch (only used for the bookkeeping of the transaction
+    /// tracker) are not included.
+    pub fn compute<S: SubstateDatabase>(
+        substate_db: &S,
+        state_updates: &StateUpdates,
+        system_structure: &SystemStructure,
+        address_bech32_encoder: &AddressBech32Encoder,
+    ) -> Vec<Self> {
+        let database_updates = state_updates.create_database_updates::<SpreadPrefixKeyMapper>();
+        // Schemas are never removed, so the updated store can resolve both old and new values
+        let updated_db = SubstateDatabaseOverlay::new(substate_db, &database_updates);
+        let reader = SystemDatabaseReader::new(&updated_db);
+        let display_context =
+            ScryptoValueDisplayContext::with_optional_bech32(Some(address_bech32_encoder));
+
+        let mut changes = Vec::new();
+        for (node_id, node_updates) in &state_updates.by_node {
+            let by_partition = match node_updates {
+                NodeStateUpdates::Delta { by_partition } => by_partition,
+            };
+            for (partition_number, partition_updates) in by_partition {
+                let by_substate = match partition_updates {
+                    PartitionStateUpdates::Delta { by_substate } => by_substate,
+                    PartitionStateUpdates::Batch(..) => continue,
+                };
+                let partition_key =
+                    SpreadPrefixKeyMapper::to_db_partition_key(node_id, *partition_number);
+                for (substate_key, update) in by_substate {
+                    let structure = system_structure
+                        .substate_system_structures
+                        .get(node_id)
+                        .and_then(|partitions| partitions.get(partition_number))
+                        .and_then(|substates| substates.get(substate_key));
+                    let decode = |value: &DbSubstateValue| {
+                        DecodedSubstateValue::new(value, structure, &reader, display_context)
+                    };
+                    let before = substate_db
+                        .get_substate(
+                            &partition_key,
+                            &SpreadPrefixKeyMapper::to_db_sort_key(substate_key),
+                        )
+                        .map(|value| decode(&value));
+                    let after = match update {
+                        DatabaseUpdate::Set(value) => Some(decode(value)),
+                        DatabaseUpdate::Delete => None,
+                    };
+                    changes.push(Self {
+                        node_id: *node_id,
+                        partition_number: *partition_number,
+                        substate_key: substate_key.clone(),
+                        before,
+                        after,
+                    });
+                }
+            }
+        }
+        changes
+    }
+}
+
+impl DecodedSubstateValue {
+    fn new<S: SubstateDatabase>(
+        value: &DbSubstateValue,
+        structure: Option<&SubstateSystemStructure>,
+        reader: &SystemDatabaseReader<S>,
+        display_context: ScryptoValueDisplayContext,
+    ) -> Self {
+        let annotated_payload = structure.and_then(|structure| {
+            let payload = Self::payload(value, structure)?;
+            let (schema, type_id) = Self::payload_schema(reader, structure)?;
+            Some(ScryptoRawPayload::new_from_valid_slice(&payload).to_string(
+                ValueDisplayParameters::Annotated {
+                    display_mode: DisplayMode::RustLike,
+                    print_mode: PrintMode::SingleLine,
+                    custom_context: display_context,
+                    schema: schema.v1(),
+                    type_id,
+                    depth_limit: SCRYPTO_SBOR_V1_MAX_DEPTH,
+                },
+            ))
+        });
+        Self {
+            value: scrypto_decode(value).expect("Substate values are valid SBOR payloads"),
+            annotated_payload,
+        }
+    }
+
+    /// Unwraps the encoded payload from the field or entry wrapper of the substate, if any.
+    fn payload(value: &DbSubstateValue, structure: &SubstateSystemStructure) -> Option<Vec<u8>> {
+        let payload = match structure {
+            SubstateSystemStructure::ObjectField(..) => {
+                scrypto_decode::<FieldSubstate<ScryptoValue>>(value)
+                    .ok()?
+                    .into_payload()
+            }
+            SubstateSystemStructure::KeyValueStoreEntry(..)
+            | SubstateSystemStructure::ObjectKeyValuePartitionEntry(..) => {
+                scrypto_decode::<KeyValueEntrySubstate<ScryptoValue>>(value)
+                    .ok()?
+                    .into_value()?
+            }
+            SubstateSystemStructure::ObjectIndexPartitionEntry(..)
+            | SubstateSystemStructure::IndexStoreEntry(..) => {
+                scrypto_decode::<IndexEntrySubstate<ScryptoValue>>(value)
+                    .ok()?
+                    .into_value()
+            }
+            SubstateSystemStructure::ObjectSortedIndexPartitionEntry(..) => {
+                scrypto_decode::<SortedIndexEntrySubstate<ScryptoValue>>(value)
+                    .ok()?
+                    .into_value()
+            }
+            SubstateSystemStructure::SystemField(..) | SubstateSystemStructure::SystemSchema => {
+                return None
+            }
+        };
+        scrypto_encode(&payload).ok()
+    }
+
+    fn payload_schema<S: SubstateDatabase>(
+        reader: &SystemDatabaseReader<S>,
+        structure: &SubstateSystemStructure,
+    ) -> Option<(Rc<VersionedScryptoSchema>, LocalTypeId)> {
+        let FullyScopedTypeId(node_id, schema_hash, type_id) =
+            match structure {
+                SubstateSystemStructure::ObjectField(FieldStructure { value_schema })
+                | SubstateSystemStructure::ObjectKeyValuePartitionEntry(
+                    KeyValuePartitionEntryStructure { value_schema, .. },
+                )
+                | SubstateSystemStructure::ObjectIndexPartitionEntry(
+                    IndexPartitionEntryStructure { value_schema, .. },
+                )
+                | SubstateSystemStructure::ObjectSortedIndexPartitionEntry(
+                    SortedIndexPartitionEntryStructure { value_schema, .. },
+                ) => match value_schema {
+                    ObjectSubstateTypeReference::Package(reference) => {
+                        let FullyScopedTypeId(package_address, schema_hash, type_id) =
+                            reference.full_type_id.clone();
+                        FullyScopedTypeId(package_address.into_node_id(), schema_hash, type_id)
+                    }
+                    ObjectSubstateTypeReference::ObjectInstance(reference) => {
+                        reference.resolved_full_type_id.clone()
+                    }
+                },
+                SubstateSystemStructure::KeyValueStoreEntry(structure)
+                | SubstateSystemStructure::IndexStoreEntry(structure) => {
+                    structure.value_full_type_id.clone()
+                }
+                SubstateSystemStructure::SystemField(..)
+                | SubstateSystemStructure::SystemSchema => return None,
+            };
+        let schema = reader.get_schema(&node_id, &schema_hash).ok()?;
+        Some((schema, type_id))
+    }
+}
+
+/// A read-only view of a store with some not yet committed updates applied on top of it.
+struct SubstateDatabaseOverlay<'a, S: SubstateDatabase> {
+    root: &'a S,
+    partition_updates: IndexMap<DbPartitionKey, &'a PartitionDatabaseUpdates>,
+}
+
+impl<'a, S: SubstateDatabase> SubstateDatabaseOverlay<'a, S> {
+    fn new(root: &'a S, database_updates: &'a DatabaseUpdates) -> Self {
+        Self {
+            root,
+            partition_updates: database_updates
+                .node_updates
+                .iter()
+                .flat_map(|(node_key, node_updates)| {
+                    node_updates.partition_updates.iter().map(
+                        |(partition_num, partition_updates)| {
+                            let partition_key = DbPartitionKey {
+                                node_key: node_key.clone(),
+                                partition_num: *partition_num,
+                            };
+                            (partition_key, partition_updates)
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the keys of the substates touched by the updates in the given partition, along with
+    /// whether each substate existed before and after the updates.
+    fn touched_substates(&self, partition_key: &DbPartitionKey) -> Vec<(DbSortKey, bool, bool)> {
+        let sort_keys: IndexSet<DbSortKey> = match self.partition_updates.get(partition_key) {
+            Some(PartitionDatabaseUpdates::Delta { substate_updates }) => {
+                substate_updates.keys().cloned().collect()
+            }
+            Some(PartitionDatabaseUpdates::Reset {
+                new_substate_values,
+            }) => self
+                .root
+                .list_entries(partition_key)
+                .map(|(sort_key, _)| sort_key)
+                .chain(new_substate_values.keys().cloned())
+                .collect(),
+            None => index_set_new(),
+        };
+
+        sort_keys
+            .into_iter()
+            .map(|sort_key| {
+                let existed = self.root.get_substate(partition_key, &sort_key).is_some();
+                let exists = self.get_substate(partition_key, &sort_key).is_some();
+                (sort_key, existed, exists)
+            })
+            .collect()
+    }
+}
+
+impl<'a, S: SubstateDatabase> SubstateDatabase for SubstateDatabaseOverlay<'a, S> {
+    fn get_substate(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Option<DbSubstateValue> {
+        match self.partition_updates.get(partition_key) {
+            Some(PartitionDatabaseUpdates::Delta { substate_updates }) => {
+                match substate_updates.get(sort_key) {
+                    Some(DatabaseUpdate::Set(value)) => Some(value.clone()),
+                    Some(DatabaseUpdate::Delete) => None,
+                    None => self.root.get_substate(partition_key, sort_key),
+                }
+            }
+            Some(PartitionDatabaseUpdates::Reset {
+                new_substate_values,
+            }) => new_substate_values.get(sort_key).cloned(),
+            None => self.root.get_substate(partition_key, sort_key),
+        }
+    }
+
+    fn list_entries(
+        &self,
+        partition_key: &DbPartitionKey,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        match self.partition_updates.get(partition_key) {
+            Some(PartitionDatabaseUpdates::Delta { substate_updates }) => {
+                let mut entries: BTreeMap<DbSortKey, DbSubstateValue> =
+                    self.root.list_entries(partition_key).collect();
+                for (sort_key, update) in substate_updates.iter() {
+                    match update {
+                        DatabaseUpdate::Set(value) => {
+                            entries.insert(sort_key.clone(), value.clone());
+                        }
+                        DatabaseUpdate::Delete => {
+                            entries.remove(sort_key);
+                        }
+                    }
+                }
+                Box::new(entries.into_iter())
+            }
+            Some(PartitionDatabaseUpdates::Reset {
+                new_substate_values,
+            }) => {
+                let entries: BTreeMap<DbSortKey, DbSubstateValue> = new_substate_values
+                    .iter()
+                    .map(|(sort_key, value)| (sort_key.clone(), value.clone()))
+                    .collect();
+                Box::new(entries.into_iter())
+            }
+            None => self.root.list_entries(partition_key),
+        }
+    }
+}
+
+fn prefix<T>(i: usize, list: &[T]) -> &'static str {
+    if i == list.len() - 1 {
+        "└─"
+    } else {
+        "├─"
+    }
+}
+
+impl<'a> ContextualDisplay<AddressDisplayContext<'a>> for StateDiff {
+    type Error = fmt::Error;
+
+    fn contextual_format<F: fmt::Write>(
+        &self,
+        f: &mut F,
+        context: &AddressDisplayContext<'a>,
+    ) -> Result<(), Self::Error> {
+        let (metadata_type_id, metadata_schema) =
+            generate_full_schema_from_single_type::<MetadataValue, ScryptoCustomSchema>();
+        let display_metadata_value = |value: &MetadataValue| {
+            let payload = scrypto_encode(value).unwrap();
+            ScryptoRawPayload::new_from_valid_slice(&payload).to_string(
+                ValueDisplayParameters::Annotated {
+                    display_mode: DisplayMode::RustLike,
+                    print_mode: PrintMode::SingleLine,
+                    custom_context: ScryptoValueDisplayContext::with_optional_bech32(
+                        context.encoder,
+                    ),
+                    schema: metadata_schema.v1(),
+                    type_id: metadata_type_id,
+                    depth_limit: SCRYPTO_SBOR_V1_MAX_DEPTH,
+                },
+            )
+        };
+
+        let new_entities: Vec<_> = self.new_entities.iter().collect();
+        write!(
+            f,
+            "{} {}",
+            "New Entities:".bold().green(),
+            new_entities.len()
+        )?;
+        for (i, (address, blueprint_id)) in new_entities.iter().enumerate() {
+            write!(
+                f,
+                "\n{} {}",
+                prefix(i, &new_entities),
+                address.display(*context)
+            )?;
+            if let Some(blueprint_id) = blueprint_id {
+                write!(f, "\n   Blueprint: {}", blueprint_id.display(*context))?;
+            }
+        }
+
+        let balance_changes: Vec<_> = self.vault_balance_changes.iter().collect();
+        write!(
+            f,
+            "\n{} {}",
+            "Balance Changes:".bold().green(),
+            balance_changes.len()
+        )?;
+        for (i, (vault_id, (resource, delta))) in balance_changes.iter().enumerate() {
+            write!(
+                f,
+                "\n{} Vault: {}\n   ResAddr: {}\n   Change: {}",
+                prefix(i, &balance_changes),
+                vault_id.display(*context),
+                resource.display(*context),
+                match delta {
+                    BalanceChange::Fungible(d) => format!("{}", d),
+                    BalanceChange::NonFungible { added, removed } => {
+                        format!("+{:?}, -{:?}", added, removed)
+                    }
+                }
+            )?;
+        }
+
+        let metadata_changes: Vec<_> = self.metadata_changes.iter().collect();
+        write!(
+            f,
+            "\n{} {}",
+            "Metadata Changes:".bold().green(),
+            metadata_changes.len()
+        )?;
+        for (i, (node_id, changes)) in metadata_changes.iter().enumerate() {
+            write!(
+                f,
+                "\n{} Entity: {}",
+                prefix(i, &metadata_changes),
+                node_id.display(*context)
+            )?;
+            for (key, change) in changes.iter() {
+                match change {
+                    MetadataChange::Added(new) => {
+                        write!(f, "\n   + {}: {}", key, display_metadata_value(new))?
+                    }
+                    MetadataChange::Updated { old, new } => write!(
+                        f,
+                        "\n   ~ {}: {} -> {}",
+                        key,
+                        display_metadata_value(old),
+                        display_metadata_value(new)
+                    )?,
+                    MetadataChange::Removed(old) => {
+                        write!(f, "\n   - {}: {}", key, display_metadata_value(old))?
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -20,6 +20,8 @@ pub enum SubstateSystemStructure {
     ObjectKeyValuePartitionEntry(KeyValuePartitionEntryStructure),
     ObjectIndexPartitionEntry(IndexPartitionEntryStructure),
     ObjectSortedIndexPartitionEntry(SortedIndexPartitionEntryStructure),
+    // IndexStore substates
+    IndexStoreEntry(KeyValueStoreEntryStructure),
 }
 
 #[derive(Debug, Clone, ScryptoSbor, PartialEq, Eq)]
@@ -236,32 +238,13 @@ impl<'a, S: SubstateDatabase> SubstateSchemaMapper<'a, S> {
             }
             SystemPartitionDescriptor::Schema => SubstateSystemStructure::SystemSchema,
             SystemPartitionDescriptor::KeyValueStore => {
-                let info = self
-                    .system_reader
-                    .get_kv_store_type_target(node_id)
-                    .expect(&format!("Could not get type info for node {node_id:?}"));
-
-                let key_full_type_id = match info.kv_store_type.key_generic_substitution {
-                    GenericSubstitution::Local(type_id) => type_id.under_node(*node_id),
-                    GenericSubstitution::Remote(type_id) => self
-                        .system_reader
-                        .get_blueprint_type_schema(&type_id)
-                        .map(|x| x.1.under_node(type_id.package_address.into_node_id()))
-                        .expect(&format!("Could not get type info {type_id:?}")),
-                };
-                let value_full_type_id = match info.kv_store_type.value_generic_substitution {
-                    GenericSubstitution::Local(type_id) => type_id.under_node(*node_id),
-                    GenericSubstitution::Remote(type_id) => self
-                        .system_reader
-                        .get_blueprint_type_schema(&type_id)
-                        .map(|x| x.1.under_node(type_id.package_address.into_node_id()))
-                        .expect(&format!("Could not get type info {type_id:?}")),
-                };
-                SubstateSystemStructure::KeyValueStoreEntry(KeyValueStoreEntryStructure {
-                    key_full_type_id,
-                    value_full_type_id,
-                })
+                SubstateSystemStructure::KeyValueStoreEntry(
+                    self.resolve_key_value_store_entry_structure(node_id),
+                )
             }
+            SystemPartitionDescriptor::IndexStore => SubstateSystemStructure::IndexStoreEntry(
+                self.resolve_key_value_store_entry_structure(node_id),
+            ),
             SystemPartitionDescriptor::Object(module_id, object_partition_descriptor) => {
                 let bp_type_target = self
                     .system_reader
@@ -277,6 +260,37 @@ impl<'a, S: SubstateDatabase> SubstateSchemaMapper<'a, S> {
         }
     }
 
+    fn resolve_key_value_store_entry_structure(
+        &self,
+        node_id: &NodeId,
+    ) -> KeyValueStoreEntryStructure {
+        let info = self
+            .system_reader
+            .get_kv_store_type_target(node_id)
+            .expect(&format!("Could not get type info for node {node_id:?}"));
+
+        let key_full_type_id = match info.kv_store_type.key_generic_substitution {
+            GenericSubstitution::Local(type_id) => type_id.under_node(*node_id),
+            GenericSubstitution::Remote(type_id) => self
+                .system_reader
+                .get_blueprint_type_schema(&type_id)
+                .map(|x| x.1.under_node(type_id.package_address.into_node_id()))
+                .expect(&format!("Could not get type info {type_id:?}")),
+        };
+        let value_full_type_id = match info.kv_store_type.value_generic_substitution {
+            GenericSubstitution::Local(type_id) => type_id.under_node(*node_id),
+            GenericSubstitution::Remote(type_id) => self
+                .system_reader
+                .get_blueprint_type_schema(&type_id)
+                .map(|x| x.1.under_node(type_id.package_address.into_node_id()))
+                .expect(&format!("Could not get type info {type_id:?}")),
+        };
+        KeyValueStoreEntryStructure {
+            key_full_type_id,
+            value_full_type_id,
+        }
+    }
+
     fn resolve_object_substate_structure(
         &self,
         bp_type_target: &BlueprintTypeTarget,
@@ -6,6 +6,7 @@ use crate::blueprints::models::FieldPayload;
 use crate::blueprints::resource::{
     fungible_vault::DepositEvent, fungible_vault::PayFeeEvent, BurnFungibleResourceEvent,
     FungibleVaultBalanceFieldPayload, FungibleVaultBalanceFieldSubstate, FungibleVaultField,
+    FungibleVaultFreezeStatusFieldSubstate,
 };
 use crate::blueprints::transaction_tracker::{
     TransactionStatus, TransactionStatusV1, TransactionTrackerSubstate,
@@ -15,11 +16,17 @@ use crate::internal_prelude::KeyValueEntrySubstateV1;
 use crate::kernel::id_allocator::IdAllocator;
 use crate::kernel::kernel::KernelBoot;
 use crate::kernel::kernel_callback_api::*;
+use crate::metrics::{EngineMetrics, MetricsSink};
 use crate::system::system_callback::SystemConfig;
 use crate::system::system_callback_api::SystemCallbackObject;
 use crate::system::system_db_reader::SystemDatabaseReader;
+use crate::system::system_modules::auth::{AuthModule, AuthTrace};
 use crate::system::system_modules::costing::*;
 use crate::system::system_modules::execution_trace::ExecutionTraceModule;
+use crate::system::system_modules::limits::{
+    TransactionLimitsConfig, TransactionLimitsConfigError,
+};
+use crate::system::system_modules::metrics::MetricsModule;
 use crate::system::system_modules::transaction_runtime::TransactionRuntimeModule;
 use crate::system::system_modules::{EnabledModules, SystemModuleMixer};
 use crate::system::system_substates::KeyValueEntrySubstate;
@@ -28,11 +35,13 @@ use crate::track::interface::CommitableSubstateStore;
 use crate::track::{to_state_updates, Track, TrackFinalizeError};
 use crate::transaction::*;
 use crate::types::*;
+use crate::vm::wasm::WasmMeteringMode;
 use radix_engine_common::constants::*;
 use radix_engine_interface::api::ModuleId;
 use radix_engine_interface::blueprints::resource::LiquidFungibleResource;
 use radix_engine_interface::blueprints::transaction_processor::InstructionOutput;
 use radix_engine_store_interface::{db_key_mapper::SpreadPrefixKeyMapper, interface::*};
+use sbor::rust::sync::Arc;
 use transaction::model::*;
 
 /// Protocol-defined costing parameters
@@ -56,6 +65,9 @@ pub struct CostingParameters {
     pub state_storage_price: Decimal,
     /// The price of archive storage in xrd
     pub archive_storage_price: Decimal,
+
+    /// The range of tips accepted, transactions with a tip outside of it are rejected.
+    pub tip_bounds: TipBounds,
 }
 
 impl Default for CostingParameters {
@@ -69,6 +81,7 @@ impl Default for CostingParameters {
             usd_price: USD_PRICE_IN_XRD.try_into().unwrap(),
             state_storage_price: STATE_STORAGE_PRICE_IN_XRD.try_into().unwrap(),
             archive_storage_price: ARCHIVE_STORAGE_PRICE_IN_XRD.try_into().unwrap(),
+            tip_bounds: TipBounds::default(),
         }
     }
 }
@@ -78,6 +91,11 @@ impl CostingParameters {
         self.execution_cost_unit_limit = execution_cost_unit_limit;
         self
     }
+
+    pub fn with_tip_bounds(mut self, tip_bounds: TipBounds) -> Self {
+        self.tip_bounds = tip_bounds;
+        self
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -86,6 +104,15 @@ pub struct ExecutionConfig {
     pub enabled_modules: EnabledModules,
     pub abort_when_loan_repaid: bool,
     pub enable_cost_breakdown: bool,
+    /// Whether to capture a [`PartialExecutionSnapshot`] into the receipt of transactions which
+    /// don't succeed.
+    pub enable_partial_execution_snapshot: bool,
+    /// Whether to record the access checks made by the auth module into an [`AuthTrace`] in
+    /// the receipt.
+    pub enable_auth_trace: bool,
+    /// Whether to capture the decoded values of the changed substates, before and after the
+    /// transaction, into the commit result.
+    pub enable_substate_value_changes: bool,
     pub max_execution_trace_depth: usize,
     pub max_call_depth: usize,
     pub max_heap_substate_total_bytes: usize,
@@ -98,7 +125,15 @@ pub struct ExecutionConfig {
     pub max_panic_message_size: usize,
     pub max_number_of_logs: usize,
     pub max_number_of_events: usize,
+    /// The max size of the WASM linear memory of a call frame, in bytes
+    pub max_wasm_memory_per_call_frame: usize,
     pub max_per_function_royalty_in_xrd: Decimal,
+    /// A custom fee table, replacing the default one
+    pub fee_table: Option<FeeTable>,
+    /// The sink the engine metrics of executed transactions are reported to
+    pub metrics: MetricsSink,
+    /// How the execution of Scrypto WASM code is metered
+    pub wasm_metering_mode: WasmMeteringMode,
 }
 
 impl ExecutionConfig {
@@ -110,6 +145,9 @@ impl ExecutionConfig {
             enabled_modules: EnabledModules::for_notarized_transaction(),
             abort_when_loan_repaid: false,
             enable_cost_breakdown: false,
+            enable_partial_execution_snapshot: false,
+            enable_auth_trace: false,
+            enable_substate_value_changes: false,
             max_execution_trace_depth: MAX_EXECUTION_TRACE_DEPTH,
             max_call_depth: MAX_CALL_DEPTH,
             max_heap_substate_total_bytes: MAX_HEAP_SUBSTATE_TOTAL_BYTES,
@@ -122,8 +160,12 @@ impl ExecutionConfig {
             max_panic_message_size: MAX_PANIC_MESSAGE_SIZE,
             max_number_of_logs: MAX_NUMBER_OF_LOGS,
             max_number_of_events: MAX_NUMBER_OF_EVENTS,
+            max_wasm_memory_per_call_frame: MAX_WASM_MEMORY_PER_CALL_FRAME,
             max_per_function_royalty_in_xrd: Decimal::try_from(MAX_PER_FUNCTION_ROYALTY_IN_XRD)
                 .unwrap(),
+            fee_table: None,
+            metrics: MetricsSink::default(),
+            wasm_metering_mode: WasmMeteringMode::default(),
         }
     }
 
@@ -155,6 +197,7 @@ impl ExecutionConfig {
         Self {
             enabled_modules: EnabledModules::for_test_transaction(),
             enable_cost_breakdown: true,
+            enable_auth_trace: true,
             ..Self::default(NetworkDefinition::simulator())
         }
     }
@@ -163,6 +206,8 @@ impl ExecutionConfig {
         Self {
             enabled_modules: EnabledModules::for_preview(),
             enable_cost_breakdown: true,
+            enable_partial_execution_snapshot: true,
+            enable_auth_trace: true,
             ..Self::default(network_definition)
         }
     }
@@ -176,15 +221,137 @@ impl ExecutionConfig {
         self
     }
 
+    pub fn with_execution_trace(mut self, enabled: bool) -> Self {
+        if enabled {
+            self.enabled_modules.insert(EnabledModules::EXECUTION_TRACE);
+        } else {
+            self.enabled_modules.remove(EnabledModules::EXECUTION_TRACE);
+        }
+        self
+    }
+
+    pub fn with_auth(mut self, enabled: bool) -> Self {
+        if enabled {
+            self.enabled_modules.insert(EnabledModules::AUTH);
+        } else {
+            self.enabled_modules.remove(EnabledModules::AUTH);
+        }
+        self
+    }
+
+    /// Denies re-entering a global component within one call stack, unless the component enables
+    /// the `allow_reentrancy` feature of its blueprint.
+    pub fn with_reentrancy_policy(mut self, enabled: bool) -> Self {
+        if enabled {
+            self.enabled_modules.insert(EnabledModules::REENTRANCY);
+        } else {
+            self.enabled_modules.remove(EnabledModules::REENTRANCY);
+        }
+        self
+    }
+
     pub fn with_cost_breakdown(mut self, enabled: bool) -> Self {
         self.enable_cost_breakdown = enabled;
         self
     }
 
+    pub fn with_partial_execution_snapshot(mut self, enabled: bool) -> Self {
+        self.enable_partial_execution_snapshot = enabled;
+        self
+    }
+
+    pub fn with_auth_trace(mut self, enabled: bool) -> Self {
+        self.enable_auth_trace = enabled;
+        self
+    }
+
+    pub fn with_substate_value_changes(mut self, enabled: bool) -> Self {
+        self.enable_substate_value_changes = enabled;
+        self
+    }
+
     pub fn up_to_loan_repayment(mut self, enabled: bool) -> Self {
         self.abort_when_loan_repaid = enabled;
         self
     }
+
+    pub fn with_fee_table(mut self, fee_table: FeeTable) -> Self {
+        self.fee_table = Some(fee_table);
+        self
+    }
+
+    /// The fee table transactions are costed with: the custom one, if any, or the default one.
+    pub fn fee_table(&self) -> FeeTable {
+        self.fee_table.clone().unwrap_or_else(FeeTable::new)
+    }
+
+    /// Reports the engine metrics of each executed transaction to the given sink - by default,
+    /// they aren't collected at all.
+    pub fn with_metrics(mut self, metrics: Arc<dyn EngineMetrics>) -> Self {
+        self.enabled_modules.insert(EnabledModules::METRICS);
+        self.metrics = MetricsSink::new(metrics);
+        self
+    }
+
+    /// Meters the execution of Scrypto WASM code with the given mode, see [`WasmMeteringMode`].
+    pub fn with_wasm_metering_mode(mut self, wasm_metering_mode: WasmMeteringMode) -> Self {
+        self.wasm_metering_mode = wasm_metering_mode;
+        self
+    }
+
+    /// Returns the transaction limits enforced by the limits module.
+    pub fn limits(&self) -> TransactionLimitsConfig {
+        TransactionLimitsConfig {
+            max_heap_substate_total_bytes: self.max_heap_substate_total_bytes,
+            max_track_substate_total_bytes: self.max_track_substate_total_bytes,
+            max_substate_key_size: self.max_substate_key_size,
+            max_substate_value_size: self.max_substate_value_size,
+            max_invoke_payload_size: self.max_invoke_input_size,
+            max_event_size: self.max_event_size,
+            max_log_size: self.max_log_size,
+            max_panic_message_size: self.max_panic_message_size,
+            max_number_of_logs: self.max_number_of_logs,
+            max_number_of_events: self.max_number_of_events,
+            max_wasm_memory_per_call_frame: self.max_wasm_memory_per_call_frame,
+        }
+    }
+
+    /// Overrides the transaction limits, e.g. to raise the event limits of a system transaction,
+    /// or to lower the limits in tests.
+    ///
+    /// # Panics
+    /// Panics if the updated limits are invalid, see [`Self::try_with_limits`] for a
+    /// non-panicking variant.
+    pub fn with_limits(self, update: impl FnOnce(&mut TransactionLimitsConfig)) -> Self {
+        match self.try_with_limits(update) {
+            Ok(config) => config,
+            Err(error) => panic!("Invalid transaction limits: {:?}", error),
+        }
+    }
+
+    /// Overrides the transaction limits, or returns why the updated limits are invalid, see
+    /// [`TransactionLimitsConfig::validate`].
+    pub fn try_with_limits(
+        mut self,
+        update: impl FnOnce(&mut TransactionLimitsConfig),
+    ) -> Result<Self, TransactionLimitsConfigError> {
+        let mut limits = self.limits();
+        update(&mut limits);
+        limits.validate()?;
+
+        self.max_heap_substate_total_bytes = limits.max_heap_substate_total_bytes;
+        self.max_track_substate_total_bytes = limits.max_track_substate_total_bytes;
+        self.max_substate_key_size = limits.max_substate_key_size;
+        self.max_substate_value_size = limits.max_substate_value_size;
+        self.max_invoke_input_size = limits.max_invoke_payload_size;
+        self.max_event_size = limits.max_event_size;
+        self.max_log_size = limits.max_log_size;
+        self.max_panic_message_size = limits.max_panic_message_size;
+        self.max_number_of_logs = limits.max_number_of_logs;
+        self.max_number_of_events = limits.max_number_of_events;
+        self.max_wasm_memory_per_call_frame = limits.max_wasm_memory_per_call_frame;
+        Ok(self)
+    }
 }
 
 impl<C: SystemCallbackObject> WrappedSystem<C> for SystemConfig<C> {
@@ -235,7 +402,7 @@ where
             executable.costing_parameters(),
             execution_config.abort_when_loan_repaid,
         );
-        let fee_table = FeeTable::new();
+        let fee_table = execution_config.fee_table();
 
         // Dump executable
         #[cfg(not(feature = "alloc"))]
@@ -258,33 +425,54 @@ where
         // TODO: the following assumptions can be removed with better interface.
         // We are assuming that intent hash store is ready when epoch manager is ready.
         let current_epoch = Self::read_epoch(&mut track);
-        let validation_result = if let Some(current_epoch) = current_epoch {
-            if let Some(range) = executable.epoch_range() {
-                Self::validate_epoch_range(
-                    current_epoch,
-                    range.start_epoch_inclusive,
-                    range.end_epoch_exclusive,
-                )
-                .and_then(|_| {
-                    Self::validate_intent_hash(
-                        &mut track,
-                        executable.intent_hash().to_hash(),
+        let validation_result = Self::validate_tip(
+            &costing_parameters.tip_bounds,
+            executable.costing_parameters().tip,
+        )
+        .and_then(|_| {
+            if let Some(current_epoch) = current_epoch {
+                if let Some(range) = executable.epoch_range() {
+                    Self::validate_epoch_range(
+                        current_epoch,
+                        range.start_epoch_inclusive,
                         range.end_epoch_exclusive,
                     )
-                })
+                    .and_then(|_| {
+                        Self::validate_intent_hash(
+                            &mut track,
+                            executable.intent_hash().to_hash(),
+                            range.end_epoch_exclusive,
+                        )
+                    })
+                } else {
+                    Ok(())
+                }
             } else {
                 Ok(())
             }
-        } else {
-            Ok(())
-        };
+        });
 
         // Run manifest
+        let mut partial_execution = None;
+        let mut warnings = Vec::new();
+        let mut panic_details = None;
+        let mut metrics_counters = None;
+        let mut auth_trace = if execution_config.enable_auth_trace {
+            Some(AuthTrace::default())
+        } else {
+            None
+        };
         let (fee_summary, fee_details, result) = match validation_result {
             Ok(()) => {
                 let (
                     interpretation_result,
-                    (mut costing_module, runtime_module, execution_trace_module),
+                    (
+                        mut costing_module,
+                        runtime_module,
+                        execution_trace_module,
+                        auth_module,
+                        metrics_module,
+                    ),
                 ) = self.interpret_manifest::<T>(
                     &mut track,
                     executable,
@@ -303,6 +491,35 @@ where
                     println!("{:?}", interpretation_result);
                 }
 
+                auth_trace = auth_module.trace;
+                panic_details = runtime_module.panic_details.clone();
+                metrics_counters = Some(metrics_module.counters().clone());
+
+                let system_reader = SystemDatabaseReader::new(self.substate_db);
+                for package_address in runtime_module.invoked_packages.iter() {
+                    if let Some(deprecation) =
+                        system_reader.get_package_deprecation(*package_address)
+                    {
+                        warnings.push(TransactionWarning::DeprecatedPackageInvoked {
+                            package_address: *package_address,
+                            successor: deprecation.successor,
+                        });
+                    }
+                }
+
+                let partial_execution_cost_breakdown =
+                    if execution_config.enable_partial_execution_snapshot {
+                        Some(
+                            costing_module
+                                .execution_cost_breakdown
+                                .iter()
+                                .map(|(k, v)| (k.to_string(), *v))
+                                .collect(),
+                        )
+                    } else {
+                        None
+                    };
+
                 let fee_details = if execution_config.enable_cost_breakdown {
                     let execution_cost_breakdown = costing_module
                         .execution_cost_breakdown
@@ -314,9 +531,16 @@ where
                         .into_iter()
                         .map(|(k, v)| (k.to_string(), v))
                         .collect();
+                    let execution_cost_breakdown_by_package = costing_module
+                        .execution_cost_breakdown_by_package
+                        .into_iter()
+                        .collect();
                     Some(TransactionFeeDetails {
                         execution_cost_breakdown,
                         finalization_cost_breakdown,
+                        execution_cost_breakdown_by_package,
+                        state_dependent_execution_cost_units: costing_module
+                            .state_dependent_execution_cost_units,
                     })
                 } else {
                     None
@@ -336,6 +560,20 @@ where
                     interpretation_result,
                     &mut costing_module.fee_reserve,
                 );
+
+                if let Some(execution_cost_breakdown) = partial_execution_cost_breakdown {
+                    if !matches!(result_type, TransactionResultType::Commit(Ok(..))) {
+                        partial_execution = Some(PartialExecutionSnapshot {
+                            instructions_completed: runtime_module.instructions_completed,
+                            application_events: runtime_module
+                                .events
+                                .iter()
+                                .map(|event| (event.type_identifier.clone(), event.payload.clone()))
+                                .collect(),
+                            execution_cost_breakdown,
+                        });
+                    }
+                }
                 match result_type {
                     TransactionResultType::Commit(outcome) => {
                         let is_success = outcome.is_ok();
@@ -398,6 +636,13 @@ where
                             &application_events,
                         );
 
+                        let application_event_index = EventIndexEntry::resolve(
+                            self.substate_db,
+                            &tracked_nodes,
+                            &application_events,
+                            &system_structure,
+                        );
+
                         let state_update_summary =
                             StateUpdateSummary::new(self.substate_db, &tracked_nodes);
 
@@ -419,6 +664,18 @@ where
                             tracked_nodes,
                             deleted_partitions,
                         );
+                        let substate_value_changes = if execution_config
+                            .enable_substate_value_changes
+                        {
+                            Some(SubstateValueChange::compute(
+                                self.substate_db,
+                                &state_updates,
+                                &system_structure,
+                                &AddressBech32Encoder::new(&execution_config.network_definition),
+                            ))
+                        } else {
+                            None
+                        };
 
                         (
                             fee_reserve_finalization.into(),
@@ -433,6 +690,7 @@ where
                                     Err(e) => TransactionOutcome::Failure(e),
                                 },
                                 application_events,
+                                application_event_index,
                                 application_logs,
                                 system_structure,
                                 execution_trace: if execution_config
@@ -443,6 +701,7 @@ where
                                 } else {
                                     None
                                 },
+                                substate_value_changes,
                             }),
                         )
                     }
@@ -470,6 +729,18 @@ where
             ),
         };
 
+        // Report engine metrics
+        if execution_config
+            .enabled_modules
+            .contains(EnabledModules::METRICS)
+        {
+            execution_config.metrics.report_transaction(
+                &metrics_counters.unwrap_or_default(),
+                fee_summary.total_execution_cost_units_consumed,
+                fee_summary.total_finalization_cost_units_consumed,
+            );
+        }
+
         // Stop hardware resource usage tracker
         let resources_usage = match () {
             #[cfg(not(all(target_os = "linux", feature = "std", feature = "cpu_ram_metrics")))]
@@ -486,6 +757,10 @@ where
             fee_details,
             result,
             resources_usage,
+            partial_execution,
+            auth_trace,
+            panic_details,
+            warnings,
         };
 
         // Dump summary
@@ -517,6 +792,45 @@ where
         }
     }
 
+    /// Returns the balance of the given refund vault, or `None` if it can't take a refund: if it
+    /// was created by a transaction which got reverted, or is frozen for deposits.
+    fn read_refund_vault_balance(
+        track: &mut Track<S, SpreadPrefixKeyMapper>,
+        refund_vault_id: &NodeId,
+    ) -> Option<LiquidFungibleResource> {
+        // The freeze status only exists if the resource supports freezing
+        if let Some(freeze_status) = track.read_substate(
+            refund_vault_id,
+            MAIN_BASE_PARTITION,
+            &FungibleVaultField::FreezeStatus.into(),
+        ) {
+            let freeze_status = freeze_status
+                .as_typed::<FungibleVaultFreezeStatusFieldSubstate>()
+                .ok()?
+                .into_payload()
+                .into_latest();
+            if freeze_status.frozen.intersects(VaultFreezeFlags::DEPOSIT) {
+                return None;
+            }
+        }
+
+        track
+            .read_substate(
+                refund_vault_id,
+                MAIN_BASE_PARTITION,
+                &FungibleVaultField::Balance.into(),
+            )?
+            .as_typed::<FungibleVaultBalanceFieldSubstate>()
+            .ok()
+            .map(|vault_balance| vault_balance.into_payload().into_latest())
+    }
+
+    fn validate_tip(tip_bounds: &TipBounds, tip: TipBasisPoints) -> Result<(), RejectionReason> {
+        tip_bounds
+            .validate(tip)
+            .map_err(RejectionReason::TipOutOfBounds)
+    }
+
     fn validate_epoch_range(
         current_epoch: Epoch,
         start_epoch_inclusive: Epoch,
@@ -601,6 +915,8 @@ where
             CostingModule,
             TransactionRuntimeModule,
             ExecutionTraceModule,
+            AuthModule,
+            MetricsModule,
         ),
     ) {
         let mut id_allocator = IdAllocator::new(executable.intent_hash().to_hash());
@@ -637,8 +953,13 @@ where
                 executable.references(),
                 executable.blobs(),
             )
+            .map(|rtn| {
+                let output: Vec<InstructionOutput> = scrypto_decode(&rtn).unwrap();
+                output
+            })
             .and_then(|x| {
                 let system = wrapped_system.system_mut();
+                system.modules.transaction_runtime.instructions_completed = x.len();
 
                 // Note that if a transactions fails during this phase, the costing is
                 // done as if it would succeed.
@@ -674,7 +995,7 @@ where
                     .modules
                     .apply_storage_cost(StorageType::Archive, total_event_size)?;
 
-                let total_log_size = system.modules.logs().iter().map(|x| x.1.len()).sum();
+                let total_log_size = system.modules.logs().iter().map(|x| x.len()).sum();
                 system
                     .modules
                     .apply_storage_cost(StorageType::Archive, total_log_size)?;
@@ -689,10 +1010,6 @@ where
                 // Logs are NOT reverted (This is not ideal, as it means logs are free if the transaction fails)
 
                 Err(e)
-            })
-            .map(|rtn| {
-                let output: Vec<InstructionOutput> = scrypto_decode(&rtn).unwrap();
-                output
             });
 
         let system = wrapped_system.to_system();
@@ -791,19 +1108,15 @@ where
         let mut fee_payments: IndexMap<NodeId, Decimal> = index_map_new();
         let mut required = fee_reserve_finalization.total_cost();
         let mut collected_fees = LiquidFungibleResource::new(Decimal::ZERO);
-        for (vault_id, mut locked, contingent) in
-            fee_reserve_finalization.locked_fees.iter().cloned().rev()
+        let payments = fee_reserve_finalization.fee_payments(is_success);
+        for (i, ((vault_id, mut locked, _), amount)) in fee_reserve_finalization
+            .locked_fees
+            .iter()
+            .cloned()
+            .zip(payments)
+            .enumerate()
+            .rev()
         {
-            let amount = if contingent {
-                if is_success {
-                    Decimal::min(locked.amount(), required)
-                } else {
-                    Decimal::zero()
-                }
-            } else {
-                Decimal::min(locked.amount(), required)
-            };
-
             // NOTE: Decimal arithmetic operation safe unwrap.
             // No chance to overflow considering current costing parameters
 
@@ -811,31 +1124,50 @@ where
             collected_fees.put(locked.take_by_amount(amount).unwrap());
             required = required.checked_sub(amount).unwrap();
 
-            // Refund overpayment
-            let mut vault_balance = track
-                .read_substate(
-                    &vault_id,
-                    MAIN_BASE_PARTITION,
-                    &FungibleVaultField::Balance.into(),
-                )
-                .unwrap()
-                .as_typed::<FungibleVaultBalanceFieldSubstate>()
-                .unwrap()
-                .into_payload()
-                .into_latest();
+            // Refund overpayment, to the vault which locked the fee if the refund vault can't
+            // take it
+            let refund = locked.amount();
+            let refund_vault_id = fee_reserve_finalization.refund_vault(i);
+            let (refund_vault_id, mut vault_balance) =
+                match Self::read_refund_vault_balance(track, &refund_vault_id) {
+                    Some(vault_balance) => (refund_vault_id, vault_balance),
+                    None => (
+                        vault_id,
+                        track
+                            .read_substate(
+                                &vault_id,
+                                MAIN_BASE_PARTITION,
+                                &FungibleVaultField::Balance.into(),
+                            )
+                            .expect("Fee locking vault should exist")
+                            .as_typed::<FungibleVaultBalanceFieldSubstate>()
+                            .unwrap()
+                            .into_payload()
+                            .into_latest(),
+                    ),
+                };
             vault_balance.put(locked);
             let updated_substate_content =
                 FungibleVaultBalanceFieldPayload::from_content_source(vault_balance)
                     .into_unlocked_substate();
             track
                 .set_substate(
-                    vault_id,
+                    refund_vault_id,
                     MAIN_BASE_PARTITION,
                     FungibleVaultField::Balance.into(),
                     IndexedScryptoValue::from_typed(&updated_substate_content),
                     &mut |_| -> Result<(), ()> { Ok(()) },
                 )
                 .unwrap();
+            if refund_vault_id != vault_id && refund.is_positive() {
+                events.push((
+                    EventTypeIdentifier(
+                        Emitter::Method(refund_vault_id, ModuleId::Main),
+                        DepositEvent::EVENT_NAME.to_string(),
+                    ),
+                    scrypto_encode(&DepositEvent { amount: refund }).unwrap(),
+                ));
+            }
 
             // Record final payments
             let entry = fee_payments.entry(vault_id).or_default();
@@ -1147,8 +1479,11 @@ where
         match &receipt.result {
             TransactionResult::Commit(commit) => {
                 println!("{:-^120}", "Application Logs");
-                for (level, message) in &commit.application_logs {
-                    println!("[{}] {}", level, message);
+                for log in &commit.application_logs {
+                    match &log.topic {
+                        Some(topic) => println!("[{}] [{}] {}", log.level, topic, log.message),
+                        None => println!("[{}] {}", log.level, log.message),
+                    }
                 }
 
                 println!("{:-^120}", "Outcome");
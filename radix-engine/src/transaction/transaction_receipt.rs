@@ -2,30 +2,53 @@ use super::{BalanceChange, CostingParameters, StateUpdateSummary};
 use crate::blueprints::consensus_manager::EpochChangeEvent;
 use crate::errors::*;
 use crate::internal_prelude::*;
+use crate::system::system_modules::auth::AuthTrace;
 use crate::system::system_modules::costing::*;
 use crate::system::system_modules::execution_trace::*;
+use crate::system::system_modules::transaction_runtime::{LogRecord, PanicDetails};
 use crate::track::BatchPartitionStateUpdate;
 use crate::track::NodeStateUpdates;
 use crate::track::PartitionStateUpdates;
 use crate::track::StateUpdates;
-use crate::transaction::SystemStructure;
+use crate::transaction::{EventIndexEntry, SubstateValueChange, SystemStructure};
 use colored::*;
 use radix_engine_interface::blueprints::transaction_processor::InstructionOutput;
 use radix_engine_store_interface::interface::DatabaseUpdate;
 use sbor::representations::*;
 use transaction::prelude::TransactionCostingParameters;
 
-define_single_versioned! {
+define_versioned! {
     /// We define a versioned transaction receipt for encoding in the preview API.
     /// This allows a new toolkit build to be able to handle both current and future
     /// receipt versions, allowing us to release a wallet ahead-of-time which is forward
     /// compatible with a new version of the engine (and so a new transaction receipt).
     #[derive(Clone, ScryptoSbor)]
-    pub enum VersionedTransactionReceipt => TransactionReceipt = TransactionReceiptV1
+    pub enum VersionedTransactionReceipt {
+        previous_versions: [
+            1 => TransactionReceiptV1: { updates_to: 2 },
+        ],
+        latest_version: {
+            2 => TransactionReceipt = TransactionReceiptV2,
+        },
+    }
+}
+
+impl VersionedTransactionReceipt {
+    /// Encodes the receipt, so that it can be persisted and decoded by any later engine version.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, EncodeError> {
+        scrypto_encode(self)
+    }
+
+    /// Decodes a receipt encoded with [`Self::to_bytes`], possibly by an earlier engine version.
+    ///
+    /// Use [`HasLatestVersion::into_latest`] to get the receipt in the current format.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeError> {
+        scrypto_decode(bytes)
+    }
 }
 
 #[derive(Clone, ScryptoSbor)]
-pub struct TransactionReceiptV1 {
+pub struct TransactionReceiptV2 {
     /// Costing parameters
     pub costing_parameters: CostingParameters,
     /// Transaction costing parameters
@@ -40,6 +63,18 @@ pub struct TransactionReceiptV1 {
     /// Hardware resources usage report
     /// Available if `resources_usage` feature flag is enabled
     pub resources_usage: Option<ResourcesUsage>,
+    /// How far the transaction got before it failed, was rejected or was aborted
+    /// Available if `ExecutionConfig::enable_partial_execution_snapshot` is enabled and the
+    /// transaction got to execute but didn't succeed
+    pub partial_execution: Option<PartialExecutionSnapshot>,
+    /// Access checks made by the auth module
+    /// Available if `ExecutionConfig::enable_auth_trace` is enabled
+    pub auth_trace: Option<AuthTrace>,
+    /// The blueprint panic which failed the transaction, with its export name and source location
+    /// Available if the transaction failed due to a panic
+    pub panic_details: Option<PanicDetails>,
+    /// Non-fatal issues noticed while executing the transaction
+    pub warnings: Vec<TransactionWarning>,
 }
 
 #[derive(Default, Debug, Clone, ScryptoSbor)]
@@ -55,10 +90,17 @@ pub struct TransactionFeeSummary {
     pub total_finalization_cost_in_xrd: Decimal,
     /// Total tipping cost in XRD.
     pub total_tipping_cost_in_xrd: Decimal,
+    /// The XRD per execution cost unit paid, including the tip.
+    pub effective_execution_cost_unit_price: Decimal,
+    /// The XRD per finalization cost unit paid, including the tip.
+    pub effective_finalization_cost_unit_price: Decimal,
     /// Total storage cost in XRD.
     pub total_storage_cost_in_xrd: Decimal,
     /// Total royalty cost in XRD.
     pub total_royalty_cost_in_xrd: Decimal,
+    /// The royalties earned by each recipient, along with the vault they were deposited into.
+    /// Empty if the transaction failed, as royalties are then reverted.
+    pub royalty_breakdown: Vec<(RoyaltyRecipient, NodeId, Decimal)>,
 }
 
 #[derive(Default, Debug, Clone, ScryptoSbor)]
@@ -67,6 +109,12 @@ pub struct TransactionFeeDetails {
     pub execution_cost_breakdown: BTreeMap<String, u32>,
     /// Finalization cost breakdown
     pub finalization_cost_breakdown: BTreeMap<String, u32>,
+    /// Execution cost breakdown by the package of the blueprint being executed.
+    /// Costs incurred by the transaction processor itself are not included.
+    pub execution_cost_breakdown_by_package: BTreeMap<PackageAddress, u32>,
+    /// Execution cost units charged for reading substates from the database, which may differ
+    /// when the transaction is executed against a different state.
+    pub state_dependent_execution_cost_units: u32,
 }
 
 /// Captures whether a transaction should be committed, and its other results
@@ -91,13 +139,18 @@ pub struct CommitResult {
     pub outcome: TransactionOutcome,
     /// Events emitted
     pub application_events: Vec<(EventTypeIdentifier, Vec<u8>)>,
+    /// A compact index of the emitted events, in the same order as `application_events`
+    pub application_event_index: Vec<EventIndexEntry>,
     /// Logs emitted
-    pub application_logs: Vec<(Level, String)>,
+    pub application_logs: Vec<LogRecord>,
     /// Additional annotation on substates and events
     pub system_structure: SystemStructure,
     /// Transaction execution traces
     /// Available if `ExecutionTrace` module is enabled
     pub execution_trace: Option<TransactionExecutionTrace>,
+    /// The decoded values of the changed substates, before and after the transaction
+    /// Available if `ExecutionConfig::enable_substate_value_changes` is enabled
+    pub substate_value_changes: Option<Vec<SubstateValueChange>>,
 }
 
 #[derive(Debug, Clone, Default, ScryptoSbor)]
@@ -124,6 +177,8 @@ pub enum TransactionOutcome {
 pub struct TransactionExecutionTrace {
     pub execution_traces: Vec<ExecutionTrace>,
     pub resource_changes: IndexMap<usize, Vec<ResourceChange>>,
+    /// The resources moved by the transaction, summed up per account and per vault.
+    pub resource_movements: ResourceMovements,
     pub fee_locks: FeeLocks,
 }
 
@@ -148,6 +203,26 @@ pub enum AbortReason {
     ConfiguredAbortTriggeredOnFeeLoanRepayment,
 }
 
+#[derive(Debug, Clone, Default, ScryptoSbor)]
+pub struct PartialExecutionSnapshot {
+    /// The number of manifest instructions which completed before execution stopped, which is
+    /// also the index of the instruction which was running at that point (if any).
+    pub instructions_completed: usize,
+    /// All events emitted before execution stopped, including the ones which got reverted.
+    pub application_events: Vec<(EventTypeIdentifier, Vec<u8>)>,
+    /// The execution cost units consumed before execution stopped, by reason.
+    pub execution_cost_breakdown: BTreeMap<String, u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum TransactionWarning {
+    /// Code of a package which its owner has deprecated was invoked.
+    DeprecatedPackageInvoked {
+        package_address: PackageAddress,
+        successor: PackageAddress,
+    },
+}
+
 #[derive(Debug, Clone, Default, ScryptoSbor)]
 pub struct ResourcesUsage {
     pub heap_allocations_sum: usize,
@@ -183,9 +258,11 @@ impl CommitResult {
             fee_destination: Default::default(),
             outcome,
             application_events: Default::default(),
+            application_event_index: Default::default(),
             application_logs: Default::default(),
             system_structure: Default::default(),
             execution_trace: Default::default(),
+            substate_value_changes: Default::default(),
         }
     }
 
@@ -215,6 +292,23 @@ impl CommitResult {
         None
     }
 
+    /// The logs emitted under the given topic.
+    pub fn logs_with_topic<'a>(&'a self, topic: &'a str) -> impl Iterator<Item = &'a LogRecord> {
+        self.application_logs
+            .iter()
+            .filter(move |log| log.topic.as_deref() == Some(topic))
+    }
+
+    /// The logs emitted by the methods of the given global component, or of its inner objects.
+    pub fn logs_of_component(
+        &self,
+        global_address: GlobalAddress,
+    ) -> impl Iterator<Item = &LogRecord> {
+        self.application_logs
+            .iter()
+            .filter(move |log| log.global_address == Some(global_address))
+    }
+
     pub fn new_package_addresses(&self) -> &IndexSet<PackageAddress> {
         &self.state_update_summary.new_packages
     }
@@ -364,6 +458,10 @@ impl TransactionReceipt {
             fee_details: Default::default(),
             result: TransactionResult::Commit(commit_result),
             resources_usage: Default::default(),
+            partial_execution: Default::default(),
+            auth_trace: Default::default(),
+            panic_details: Default::default(),
+            warnings: Default::default(),
         }
     }
 
@@ -519,39 +617,23 @@ impl TransactionReceipt {
         })
     }
 
-    pub fn effective_execution_cost_unit_price(&self) -> Decimal {
-        let one_percent = Decimal::ONE_HUNDREDTH;
+    pub fn royalty_breakdown(&self) -> &[(RoyaltyRecipient, NodeId, Decimal)] {
+        &self.fee_summary.royalty_breakdown
+    }
 
+    pub fn effective_execution_cost_unit_price(&self) -> Decimal {
         // Below unwraps are safe, no chance to overflow considering current costing parameters
-        self.costing_parameters
-            .execution_cost_unit_price
-            .checked_mul(
-                Decimal::ONE
-                    .checked_add(
-                        one_percent
-                            .checked_mul(self.transaction_costing_parameters.tip_percentage)
-                            .unwrap(),
-                    )
-                    .unwrap(),
-            )
+        self.transaction_costing_parameters
+            .tip
+            .apply_to_cost_unit_price(self.costing_parameters.execution_cost_unit_price)
             .unwrap()
     }
 
     pub fn effective_finalization_cost_unit_price(&self) -> Decimal {
-        let one_percent = Decimal::ONE_HUNDREDTH;
-
         // Below unwraps are safe, no chance to overflow considering current costing parameters
-        self.costing_parameters
-            .finalization_cost_unit_price
-            .checked_mul(
-                Decimal::ONE
-                    .checked_add(
-                        one_percent
-                            .checked_mul(self.transaction_costing_parameters.tip_percentage)
-                            .unwrap(),
-                    )
-                    .unwrap(),
-            )
+        self.transaction_costing_parameters
+            .tip
+            .apply_to_cost_unit_price(self.costing_parameters.finalization_cost_unit_price)
             .unwrap()
     }
 }
@@ -681,6 +763,22 @@ impl<'a> ContextualDisplay<TransactionReceiptDisplayContext<'a>> for Transaction
             },
         )?;
 
+        if let Some(panic_details) = &self.panic_details {
+            write!(
+                f,
+                "\n{} {}",
+                "Panicked In:".bold().red(),
+                panic_details.export_name.as_deref().unwrap_or("<unknown>"),
+            )?;
+            if let Some(location) = &panic_details.location {
+                write!(
+                    f,
+                    " @ {}:{}:{}",
+                    location.file, location.line, location.column
+                )?;
+            }
+        }
+
         write!(
             f,
             "\n{} {} XRD",
@@ -727,8 +825,12 @@ impl<'a> ContextualDisplay<TransactionReceiptDisplayContext<'a>> for Transaction
                 "Logs:".bold().green(),
                 c.application_logs.len()
             )?;
-            for (i, (level, msg)) in c.application_logs.iter().enumerate() {
-                let (l, m) = match level {
+            for (i, log) in c.application_logs.iter().enumerate() {
+                let msg = match &log.topic {
+                    Some(topic) => format!("[{}] {}", topic, log.message),
+                    None => log.message.clone(),
+                };
+                let (l, m) = match log.level {
                     Level::Error => ("ERROR".red(), msg.red()),
                     Level::Warn => ("WARN".yellow(), msg.yellow()),
                     Level::Info => ("INFO".green(), msg.green()),
@@ -934,8 +1036,18 @@ impl From<FeeReserveFinalizationSummary> for TransactionFeeSummary {
             total_execution_cost_in_xrd: value.total_execution_cost_in_xrd,
             total_finalization_cost_in_xrd: value.total_finalization_cost_in_xrd,
             total_tipping_cost_in_xrd: value.total_tipping_cost_in_xrd,
+            effective_execution_cost_unit_price: value.effective_execution_cost_unit_price,
+            effective_finalization_cost_unit_price: value.effective_finalization_cost_unit_price,
             total_storage_cost_in_xrd: value.total_storage_cost_in_xrd,
             total_royalty_cost_in_xrd: value.total_royalty_cost_in_xrd,
+            royalty_breakdown: value
+                .royalty_cost_breakdown
+                .into_iter()
+                .map(|(recipient, amount)| {
+                    let vault_id = recipient.vault_id();
+                    (recipient, vault_id, amount)
+                })
+                .collect(),
         }
     }
 }
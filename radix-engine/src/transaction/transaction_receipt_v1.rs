@@ -0,0 +1,256 @@
+//! The first version of the transaction receipt.
+//!
+//! These types are frozen, so that receipts persisted by earlier engines can still be decoded.
+//! They must not be changed - changes go into the latest receipt version instead.
+
+use super::{
+    AbortResult, CommitResult, CostingParameters, EventIndexEntry, FeeDestination, FeeLocks,
+    FeeSource, RejectResult, ResourcesUsage, StateUpdateSummary, SystemStructure,
+    TransactionExecutionTrace, TransactionFeeDetails, TransactionFeeSummary, TransactionOutcome,
+    TransactionReceiptV2, TransactionResult,
+};
+use crate::internal_prelude::*;
+use crate::system::system_modules::execution_trace::*;
+use crate::system::system_modules::transaction_runtime::LogRecord;
+use crate::track::StateUpdates;
+use transaction::prelude::{TipBasisPoints, TipBounds, TransactionCostingParameters};
+
+#[derive(Clone, ScryptoSbor)]
+pub struct TransactionReceiptV1 {
+    pub costing_parameters: CostingParametersV1,
+    pub transaction_costing_parameters: TransactionCostingParametersV1,
+    pub fee_summary: TransactionFeeSummaryV1,
+    pub fee_details: Option<TransactionFeeDetailsV1>,
+    pub result: TransactionResultV1,
+    pub resources_usage: Option<ResourcesUsage>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct CostingParametersV1 {
+    pub execution_cost_unit_price: Decimal,
+    pub execution_cost_unit_limit: u32,
+    pub execution_cost_unit_loan: u32,
+    pub finalization_cost_unit_price: Decimal,
+    pub finalization_cost_unit_limit: u32,
+    pub usd_price: Decimal,
+    pub state_storage_price: Decimal,
+    pub archive_storage_price: Decimal,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub struct TransactionCostingParametersV1 {
+    pub tip_percentage: u16,
+    pub free_credit_in_xrd: Decimal,
+}
+
+#[derive(Default, Debug, Clone, ScryptoSbor)]
+pub struct TransactionFeeSummaryV1 {
+    pub total_execution_cost_units_consumed: u32,
+    pub total_finalization_cost_units_consumed: u32,
+    pub total_execution_cost_in_xrd: Decimal,
+    pub total_finalization_cost_in_xrd: Decimal,
+    pub total_tipping_cost_in_xrd: Decimal,
+    pub total_storage_cost_in_xrd: Decimal,
+    pub total_royalty_cost_in_xrd: Decimal,
+}
+
+#[derive(Default, Debug, Clone, ScryptoSbor)]
+pub struct TransactionFeeDetailsV1 {
+    pub execution_cost_breakdown: BTreeMap<String, u32>,
+    pub finalization_cost_breakdown: BTreeMap<String, u32>,
+}
+
+#[derive(Debug, Clone, ScryptoSbor)]
+pub enum TransactionResultV1 {
+    Commit(CommitResultV1),
+    Reject(RejectResult),
+    Abort(AbortResult),
+}
+
+#[derive(Debug, Clone, ScryptoSbor)]
+pub struct CommitResultV1 {
+    pub state_updates: StateUpdates,
+    pub state_update_summary: StateUpdateSummary,
+    pub fee_source: FeeSource,
+    pub fee_destination: FeeDestination,
+    pub outcome: TransactionOutcome,
+    pub application_events: Vec<(EventTypeIdentifier, Vec<u8>)>,
+    pub application_logs: Vec<(Level, String)>,
+    pub system_structure: SystemStructure,
+    pub execution_trace: Option<TransactionExecutionTraceV1>,
+}
+
+#[derive(Debug, Clone, ScryptoSbor)]
+pub struct TransactionExecutionTraceV1 {
+    pub execution_traces: Vec<ExecutionTraceV1>,
+    pub resource_changes: IndexMap<usize, Vec<ResourceChange>>,
+    pub fee_locks: FeeLocks,
+}
+
+#[derive(Debug, Clone, ScryptoSbor)]
+pub struct ExecutionTraceV1 {
+    pub origin: TraceOrigin,
+    pub kernel_call_depth: usize,
+    pub current_frame_actor: TraceActor,
+    pub current_frame_depth: usize,
+    pub instruction_index: usize,
+    pub input: ResourceSummary,
+    pub output: ResourceSummary,
+    pub children: Vec<ExecutionTraceV1>,
+}
+
+/// Details which a V1 receipt didn't record are left empty, apart from the ones which can be
+/// derived from what it did record.
+impl From<TransactionReceiptV1> for TransactionReceiptV2 {
+    fn from(value: TransactionReceiptV1) -> Self {
+        let transaction_costing_parameters =
+            TransactionCostingParameters::from(value.transaction_costing_parameters);
+        let tip = transaction_costing_parameters.tip;
+        let costing_parameters = CostingParameters::from(value.costing_parameters);
+        // Below unwraps are safe, as the prices were applied with the same tip when executing
+        let fee_summary = TransactionFeeSummary {
+            total_execution_cost_units_consumed: value
+                .fee_summary
+                .total_execution_cost_units_consumed,
+            total_finalization_cost_units_consumed: value
+                .fee_summary
+                .total_finalization_cost_units_consumed,
+            total_execution_cost_in_xrd: value.fee_summary.total_execution_cost_in_xrd,
+            total_finalization_cost_in_xrd: value.fee_summary.total_finalization_cost_in_xrd,
+            total_tipping_cost_in_xrd: value.fee_summary.total_tipping_cost_in_xrd,
+            effective_execution_cost_unit_price: tip
+                .apply_to_cost_unit_price(costing_parameters.execution_cost_unit_price)
+                .unwrap(),
+            effective_finalization_cost_unit_price: tip
+                .apply_to_cost_unit_price(costing_parameters.finalization_cost_unit_price)
+                .unwrap(),
+            total_storage_cost_in_xrd: value.fee_summary.total_storage_cost_in_xrd,
+            total_royalty_cost_in_xrd: value.fee_summary.total_royalty_cost_in_xrd,
+            royalty_breakdown: Vec::new(),
+        };
+
+        Self {
+            costing_parameters,
+            transaction_costing_parameters,
+            fee_summary,
+            fee_details: value.fee_details.map(|fee_details| TransactionFeeDetails {
+                execution_cost_breakdown: fee_details.execution_cost_breakdown,
+                finalization_cost_breakdown: fee_details.finalization_cost_breakdown,
+                execution_cost_breakdown_by_package: BTreeMap::new(),
+                state_dependent_execution_cost_units: 0,
+            }),
+            result: value.result.into(),
+            resources_usage: value.resources_usage,
+            partial_execution: None,
+            auth_trace: None,
+            panic_details: None,
+            warnings: Vec::new(),
+        }
+    }
+}
+
+impl From<CostingParametersV1> for CostingParameters {
+    fn from(value: CostingParametersV1) -> Self {
+        Self {
+            execution_cost_unit_price: value.execution_cost_unit_price,
+            execution_cost_unit_limit: value.execution_cost_unit_limit,
+            execution_cost_unit_loan: value.execution_cost_unit_loan,
+            finalization_cost_unit_price: value.finalization_cost_unit_price,
+            finalization_cost_unit_limit: value.finalization_cost_unit_limit,
+            usd_price: value.usd_price,
+            state_storage_price: value.state_storage_price,
+            archive_storage_price: value.archive_storage_price,
+            // The bounds which were enforced before they became configurable
+            tip_bounds: TipBounds::default(),
+        }
+    }
+}
+
+impl From<TransactionCostingParametersV1> for TransactionCostingParameters {
+    fn from(value: TransactionCostingParametersV1) -> Self {
+        Self {
+            tip: TipBasisPoints::from_percentage(value.tip_percentage),
+            free_credit_in_xrd: value.free_credit_in_xrd,
+        }
+    }
+}
+
+impl From<TransactionResultV1> for TransactionResult {
+    fn from(value: TransactionResultV1) -> Self {
+        match value {
+            TransactionResultV1::Commit(commit) => TransactionResult::Commit(commit.into()),
+            TransactionResultV1::Reject(reject) => TransactionResult::Reject(reject),
+            TransactionResultV1::Abort(abort) => TransactionResult::Abort(abort),
+        }
+    }
+}
+
+impl From<CommitResultV1> for CommitResult {
+    fn from(value: CommitResultV1) -> Self {
+        // No event fields could be indexed before V2
+        let application_event_index = value
+            .application_events
+            .iter()
+            .map(|(event_type_identifier, _)| EventIndexEntry {
+                emitter: event_type_identifier.0.clone(),
+                event_name: event_type_identifier.1.clone(),
+                indexed_fields: Vec::new(),
+            })
+            .collect();
+        let application_logs = value
+            .application_logs
+            .into_iter()
+            .map(|(level, message)| LogRecord {
+                level,
+                topic: None,
+                message,
+                blueprint_id: None,
+                global_address: None,
+                payload: None,
+            })
+            .collect();
+
+        Self {
+            state_updates: value.state_updates,
+            state_update_summary: value.state_update_summary,
+            fee_source: value.fee_source,
+            fee_destination: value.fee_destination,
+            outcome: value.outcome,
+            application_events: value.application_events,
+            application_event_index,
+            application_logs,
+            system_structure: value.system_structure,
+            execution_trace: value.execution_trace.map(Into::into),
+            substate_value_changes: None,
+        }
+    }
+}
+
+impl From<TransactionExecutionTraceV1> for TransactionExecutionTrace {
+    fn from(value: TransactionExecutionTraceV1) -> Self {
+        Self {
+            execution_traces: value.execution_traces.into_iter().map(Into::into).collect(),
+            resource_changes: value.resource_changes,
+            resource_movements: Default::default(),
+            fee_locks: value.fee_locks,
+        }
+    }
+}
+
+impl From<ExecutionTraceV1> for ExecutionTrace {
+    fn from(value: ExecutionTraceV1) -> Self {
+        Self {
+            origin: value.origin,
+            kernel_call_depth: value.kernel_call_depth,
+            current_frame_actor: value.current_frame_actor,
+            current_frame_depth: value.current_frame_depth,
+            instruction_index: value.instruction_index,
+            input: value.input,
+            output: value.output,
+            children: value.children.into_iter().map(Into::into).collect(),
+            execution_cost_units_consumed: 0,
+            substate_io_counts: Default::default(),
+            wasm_memory_peak: None,
+        }
+    }
+}
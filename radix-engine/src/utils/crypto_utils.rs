@@ -0,0 +1,32 @@
+use crate::types::*;
+use sha3::{Digest, Keccak256};
+
+// The BLS signatures verified by the engine use the same ciphersuite as the transaction signing
+pub use transaction::signing::bls12381::BLS12381_CIPHERSUITE_V1;
+pub use transaction::validation::verify_bls12381_v1;
+
+pub fn keccak256_hash<T: AsRef<[u8]>>(data: T) -> Hash {
+    Hash(Keccak256::digest(data).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keccak256_hash() {
+        assert_eq!(
+            keccak256_hash("").to_string(),
+            "c5d2460186f7233c927e7db2dcc703c0e500b653ca82273b7bfad8045d85a470"
+        );
+    }
+
+    #[test]
+    fn test_verify_bls12381_v1_rejects_invalid_points() {
+        assert!(!verify_bls12381_v1(
+            b"message",
+            &Bls12381G1PublicKey([0u8; Bls12381G1PublicKey::LENGTH]),
+            &Bls12381G2Signature([0u8; Bls12381G2Signature::LENGTH]),
+        ));
+    }
+}
@@ -1,8 +1,10 @@
+mod crypto_utils;
 mod macros;
 mod native_blueprint_call_validator;
 mod package_extractor;
 mod panics;
 
+pub use crypto_utils::*;
 pub use macros::*;
 pub use native_blueprint_call_validator::*;
 pub use package_extractor::*;
@@ -13,6 +13,7 @@ use radix_engine_interface::blueprints::access_controller::*;
 use radix_engine_interface::blueprints::account::*;
 use radix_engine_interface::blueprints::consensus_manager::*;
 use radix_engine_interface::blueprints::identity::*;
+use radix_engine_interface::blueprints::locker::*;
 use radix_engine_interface::blueprints::resource::*;
 use radix_engine_interface::schema::*;
 use transaction::prelude::*;
@@ -166,6 +167,10 @@ fn get_arguments_schema<'s>(
         Invocation::Function(package_address @ POOL_PACKAGE, ref blueprint, _) => {
             get_blueprint_schema(&POOL_PACKAGE_DEFINITION, package_address, blueprint).map(Some)?
         }
+        Invocation::Function(package_address @ LOCKER_PACKAGE, ref blueprint, _) => {
+            get_blueprint_schema(&LOCKER_PACKAGE_DEFINITION, package_address, blueprint)
+                .map(Some)?
+        }
         Invocation::Function(package_address @ TRANSACTION_PROCESSOR_PACKAGE, ref blueprint, _) => {
             get_blueprint_schema(
                 &TRANSACTION_PROCESSOR_PACKAGE_DEFINITION,
@@ -232,6 +237,10 @@ fn get_arguments_schema<'s>(
                     .blueprints
                     .get(MULTI_RESOURCE_POOL_BLUEPRINT_IDENT),
 
+                EntityType::GlobalAccountLocker => LOCKER_PACKAGE_DEFINITION
+                    .blueprints
+                    .get(ACCOUNT_LOCKER_BLUEPRINT),
+
                 EntityType::GlobalTransactionTracker => TRANSACTION_TRACKER_PACKAGE_DEFINITION
                     .blueprints
                     .get(TRANSACTION_TRACKER_BLUEPRINT),
@@ -5,7 +5,7 @@ use crate::vm::wasm_runtime::NoOpWasmRuntime;
 use crate::{errors::InvokeError, transaction::CostingParameters};
 use radix_engine_interface::blueprints::package::*;
 use sbor::rust::iter;
-use transaction::prelude::TransactionCostingParameters;
+use transaction::prelude::{TipBasisPoints, TransactionCostingParameters};
 
 #[derive(Debug)]
 pub enum ExtractSchemaError {
@@ -40,7 +40,7 @@ pub fn extract_definition(code: &[u8]) -> Result<PackageDefinition, ExtractSchem
     let fee_reserve = SystemLoanFeeReserve::new(
         &CostingParameters::default(),
         &TransactionCostingParameters {
-            tip_percentage: 0,
+            tip: TipBasisPoints::ZERO,
             free_credit_in_xrd: Decimal::try_from(PREVIEW_CREDIT_IN_XRD).unwrap(),
         },
         false,
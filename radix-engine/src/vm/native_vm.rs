@@ -2,6 +2,7 @@ use crate::blueprints::access_controller::AccessControllerNativePackage;
 use crate::blueprints::account::AccountNativePackage;
 use crate::blueprints::consensus_manager::ConsensusManagerNativePackage;
 use crate::blueprints::identity::IdentityNativePackage;
+use crate::blueprints::locker::LockerNativePackage;
 use crate::blueprints::package::PackageNativePackage;
 use crate::blueprints::pool::PoolNativePackage;
 use crate::blueprints::resource::ResourceNativePackage;
@@ -135,6 +136,7 @@ impl<I: VmInvoke> VmInvoke for NativeVmInstance<I> {
                         RoleAssignmentNativePackage::invoke_export(export_name, input, api)
                     }
                     POOL_CODE_ID => PoolNativePackage::invoke_export(export_name, input, api),
+                    LOCKER_CODE_ID => LockerNativePackage::invoke_export(export_name, input, api),
                     TRANSACTION_TRACKER_CODE_ID => {
                         TransactionTrackerNativePackage::invoke_export(export_name, input, api)
                     }
@@ -1,4 +1,4 @@
-use crate::errors::{RuntimeError, SystemUpstreamError};
+use crate::errors::{RuntimeError, SystemUpstreamError, VmError};
 use crate::types::*;
 use crate::vm::vm::VmInvoke;
 use crate::vm::wasm::*;
@@ -31,13 +31,65 @@ impl<W: WasmEngine> ScryptoVm<W> {
         ScryptoVmInstance {
             instance: self.wasm_engine.instantiate(code_hash, instrumented_code),
             package_address: *package_address,
+            max_memory_size: None,
         }
     }
+
+    /// Creates an instance of the original code which is metered by the fuel the WASM engine
+    /// consumes, see [`WasmMeteringMode::EngineFuel`], and stops once it has consumed more than
+    /// the given execution units. Fails if the engine can't meter by fuel.
+    ///
+    /// The code must have been validated when the package was published!
+    pub fn create_fuel_metered_instance(
+        &self,
+        package_address: &PackageAddress,
+        code_hash: CodeHash,
+        original_code: &[u8],
+        max_execution_units: u64,
+    ) -> Result<ScryptoVmInstance<W::WasmInstance>, RuntimeError> {
+        if !self.wasm_engine.supports_fuel_metering() {
+            return Err(RuntimeError::VmError(VmError::Wasm(
+                WasmRuntimeError::FuelMeteringNotSupported,
+            )));
+        }
+
+        let prepare_code = || {
+            WasmValidator::default()
+                .prepare_for_fuel_metering(original_code)
+                .expect("Published code must be valid")
+        };
+
+        Ok(ScryptoVmInstance {
+            instance: self.wasm_engine.instantiate_fuel_metered(
+                code_hash,
+                &prepare_code,
+                self.wasm_validator_config.execution_units_per_fuel(),
+                max_execution_units,
+            ),
+            package_address: *package_address,
+            max_memory_size: None,
+        })
+    }
 }
 
 pub struct ScryptoVmInstance<I: WasmInstance> {
     instance: I,
     package_address: PackageAddress,
+    max_memory_size: Option<usize>,
+}
+
+impl<I: WasmInstance> ScryptoVmInstance<I> {
+    /// Limits the size of the linear memory, in bytes, which is checked on every call the
+    /// instance makes into the host while being invoked.
+    pub fn with_max_memory_size(mut self, max_memory_size: Option<usize>) -> Self {
+        self.max_memory_size = max_memory_size;
+        self
+    }
+
+    /// Returns the peak size of the linear memory of the instance, in bytes.
+    pub fn memory_size(&mut self) -> Result<usize, RuntimeError> {
+        Ok(self.instance.memory_size()?)
+    }
 }
 
 impl<I: WasmInstance> VmInvoke for ScryptoVmInstance<I> {
@@ -56,6 +108,7 @@ impl<I: WasmInstance> VmInvoke for ScryptoVmInstance<I> {
                 api,
                 self.package_address,
                 export_name.to_string(),
+                self.max_memory_size,
             ));
 
             let mut input = Vec::new();
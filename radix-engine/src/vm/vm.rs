@@ -1,11 +1,13 @@
 use crate::blueprints::package::*;
 use crate::errors::{ApplicationError, RuntimeError};
 use crate::kernel::kernel_api::{KernelInternalApi, KernelNodeApi, KernelSubstateApi};
+use crate::metrics::EngineCounter;
 use crate::system::system_callback::{SystemConfig, SystemLockData};
 use crate::system::system_callback_api::SystemCallbackObject;
+use crate::system::system_modules::EnabledModules;
 use crate::system::system_substates::KeyValueEntrySubstate;
 use crate::types::*;
-use crate::vm::wasm::{WasmEngine, WasmValidator};
+use crate::vm::wasm::{WasmEngine, WasmMeteringMode, WasmValidator};
 use crate::vm::{NativeVm, NativeVmExtension, ScryptoVm};
 use radix_engine_interface::api::field_api::LockFlags;
 use radix_engine_interface::api::ClientApi;
@@ -69,30 +71,16 @@ impl<'g, W: WasmEngine + 'g, E: NativeVmExtension> SystemCallbackObject for Vm<'
                 .expect(&format!("Vm type not found: {:?}", export))
         };
 
+        let fuel_metered =
+            api.kernel_get_system().modules.wasm_metering_mode == WasmMeteringMode::EngineFuel;
+
         let output = match vm_type.into_latest().vm_type {
             VmType::Native => {
-                let original_code = {
-                    let handle = api.kernel_open_substate_with_default(
-                        address.as_node_id(),
-                        MAIN_BASE_PARTITION
-                            .at_offset(PACKAGE_ORIGINAL_CODE_PARTITION_OFFSET)
-                            .unwrap(),
-                        &SubstateKey::Map(scrypto_encode(&export.code_hash).unwrap()),
-                        LockFlags::read_only(),
-                        Some(|| {
-                            let kv_entry = KeyValueEntrySubstate::<()>::default();
-                            IndexedScryptoValue::from_typed(&kv_entry)
-                        }),
-                        SystemLockData::default(),
-                    )?;
-                    let original_code = api.kernel_read_substate(handle)?;
-                    let original_code: PackageCodeOriginalCodeEntrySubstate =
-                        original_code.as_typed().unwrap();
-                    api.kernel_close_substate(handle)?;
-                    original_code
-                        .into_value()
-                        .expect(&format!("Original code not found: {:?}", export))
-                };
+                let original_code = read_original_code(address, &export, api)?;
+
+                api.kernel_get_system()
+                    .modules
+                    .increment_metric(EngineCounter::NativeInvocations);
 
                 let mut vm_instance = api
                     .kernel_get_system()
@@ -103,6 +91,52 @@ impl<'g, W: WasmEngine + 'g, E: NativeVmExtension> SystemCallbackObject for Vm<'
 
                 output
             }
+            VmType::ScryptoV1 if fuel_metered => {
+                let original_code = read_original_code(address, &export, api)?.into_latest();
+
+                api.kernel_get_system()
+                    .modules
+                    .increment_metric(EngineCounter::WasmInvocations);
+
+                let max_memory_size = api
+                    .kernel_get_system()
+                    .modules
+                    .max_wasm_memory_per_call_frame();
+                // Without costing, there's nothing to bound the execution by
+                let max_execution_units = api
+                    .kernel_get_system()
+                    .modules
+                    .costing()
+                    .map(|costing| costing.remaining_wasm_execution_units())
+                    .unwrap_or(u64::MAX);
+                let mut scrypto_vm_instance = api
+                    .kernel_get_system()
+                    .callback_obj
+                    .scrypto_vm
+                    .create_fuel_metered_instance(
+                        address,
+                        export.code_hash,
+                        &original_code.code,
+                        max_execution_units,
+                    )?
+                    .with_max_memory_size(max_memory_size);
+
+                api.consume_cost_units(ClientCostingEntry::PrepareWasmCode {
+                    size: original_code.code.len(),
+                })?;
+
+                let output = scrypto_vm_instance.invoke(export.export_name.as_str(), input, api);
+
+                // A failed invocation keeps its own error, rather than being reported as having
+                // exceeded the memory limit
+                let memory_size = scrypto_vm_instance.memory_size();
+                let output = output?;
+                api.kernel_get_system()
+                    .modules
+                    .on_wasm_invocation_finish(memory_size?)?;
+
+                output
+            }
             VmType::ScryptoV1 => {
                 let instrumented_code = {
                     let handle = api.kernel_open_substate_with_default(
@@ -128,6 +162,32 @@ impl<'g, W: WasmEngine + 'g, E: NativeVmExtension> SystemCallbackObject for Vm<'
                         .into_latest()
                 };
 
+                let system = api.kernel_get_system();
+                if system
+                    .modules
+                    .enabled_modules
+                    .contains(EnabledModules::METRICS)
+                {
+                    let cache_counter = if system
+                        .callback_obj
+                        .scrypto_vm
+                        .wasm_engine
+                        .is_cached(export.code_hash)
+                    {
+                        EngineCounter::WasmModuleCacheHits
+                    } else {
+                        EngineCounter::WasmModuleCacheMisses
+                    };
+                    system.modules.increment_metric(cache_counter);
+                    system
+                        .modules
+                        .increment_metric(EngineCounter::WasmInvocations);
+                }
+
+                let max_memory_size = api
+                    .kernel_get_system()
+                    .modules
+                    .max_wasm_memory_per_call_frame();
                 let mut scrypto_vm_instance = {
                     api.kernel_get_system()
                         .callback_obj
@@ -137,14 +197,22 @@ impl<'g, W: WasmEngine + 'g, E: NativeVmExtension> SystemCallbackObject for Vm<'
                             export.code_hash,
                             &instrumented_code.instrumented_code,
                         )
+                        .with_max_memory_size(max_memory_size)
                 };
 
                 api.consume_cost_units(ClientCostingEntry::PrepareWasmCode {
                     size: instrumented_code.instrumented_code.len(),
                 })?;
 
-                let output =
-                    { scrypto_vm_instance.invoke(export.export_name.as_str(), input, api)? };
+                let output = scrypto_vm_instance.invoke(export.export_name.as_str(), input, api);
+
+                // A failed invocation keeps its own error, rather than being reported as having
+                // exceeded the memory limit
+                let memory_size = scrypto_vm_instance.memory_size();
+                let output = output?;
+                api.kernel_get_system()
+                    .modules
+                    .on_wasm_invocation_finish(memory_size?)?;
 
                 output
             }
@@ -233,15 +301,7 @@ impl VmPackageValidation {
                         ));
                     }
 
-                    if fields.len() > 1 {
-                        return Err(RuntimeError::ApplicationError(
-                            ApplicationError::PackageError(PackageError::WasmUnsupported(
-                                "More than 1 substate field not supported".to_string(),
-                            )),
-                        ));
-                    }
-
-                    for field in fields {
+                    for (index, field) in fields.iter().enumerate() {
                         match &field.condition {
                             Condition::Always => {}
                             _ => {
@@ -253,15 +313,26 @@ impl VmPackageValidation {
                             }
                         }
 
-                        match field.transience {
-                            FieldTransience::NotTransient => {}
-                            _ => {
+                        // The first field holds the component state, and any other field the value
+                        // of a `Transient` field of the component state.
+                        match (index, &field.transience) {
+                            (0, FieldTransience::NotTransient) => {}
+                            (0, FieldTransience::TransientStatic { .. }) => {
                                 return Err(RuntimeError::ApplicationError(
                                     ApplicationError::PackageError(PackageError::WasmUnsupported(
-                                        "Transient fields are not supported".to_string(),
+                                        "Transient component state not supported".to_string(),
                                     )),
                                 ));
                             }
+                            (_, FieldTransience::NotTransient) => {
+                                return Err(RuntimeError::ApplicationError(
+                                    ApplicationError::PackageError(PackageError::WasmUnsupported(
+                                        "More than 1 non-transient substate field not supported"
+                                            .to_string(),
+                                    )),
+                                ));
+                            }
+                            (_, FieldTransience::TransientStatic { .. }) => {}
                         }
                     }
 
@@ -306,3 +377,29 @@ impl VmPackageValidation {
         }
     }
 }
+
+fn read_original_code<Y: KernelSubstateApi<SystemLockData>>(
+    address: &PackageAddress,
+    export: &PackageExport,
+    api: &mut Y,
+) -> Result<PackageCodeOriginalCodeEntryPayload, RuntimeError> {
+    let handle = api.kernel_open_substate_with_default(
+        address.as_node_id(),
+        MAIN_BASE_PARTITION
+            .at_offset(PACKAGE_ORIGINAL_CODE_PARTITION_OFFSET)
+            .unwrap(),
+        &SubstateKey::Map(scrypto_encode(&export.code_hash).unwrap()),
+        LockFlags::read_only(),
+        Some(|| {
+            let kv_entry = KeyValueEntrySubstate::<()>::default();
+            IndexedScryptoValue::from_typed(&kv_entry)
+        }),
+        SystemLockData::default(),
+    )?;
+    let original_code = api.kernel_read_substate(handle)?;
+    let original_code: PackageCodeOriginalCodeEntrySubstate = original_code.as_typed().unwrap();
+    api.kernel_close_substate(handle)?;
+    Ok(original_code
+        .into_value()
+        .expect(&format!("Original code not found: {:?}", export)))
+}
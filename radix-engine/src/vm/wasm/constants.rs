@@ -13,6 +13,7 @@ pub const ADDRESS_GET_RESERVATION_ADDRESS_FUNCTION_NAME: &str = "address_get_res
 // Object
 //=================
 pub const OBJECT_NEW_FUNCTION_NAME: &str = "object_new";
+pub const OBJECT_NEW_WITH_FEATURES_FUNCTION_NAME: &str = "object_new_with_features";
 pub const OBJECT_GLOBALIZE_FUNCTION_NAME: &str = "object_globalize";
 pub const OBJECT_INSTANCE_OF_FUNCTION_NAME: &str = "object_instance_of";
 pub const OBJECT_GET_BLUEPRINT_ID_FUNCTION_NAME: &str = "object_get_blueprint_id";
@@ -28,6 +29,7 @@ pub const ACTOR_GET_PACKAGE_ADDRESS_FUNCTION_NAME: &str = "actor_get_package_add
 pub const ACTOR_GET_BLUEPRINT_NAME_FUNCTION_NAME: &str = "actor_get_blueprint_name";
 pub const ACTOR_OPEN_FIELD_FUNCTION_NAME: &str = "actor_open_field";
 pub const ACTOR_GET_OBJECT_ID_FUNCTION_NAME: &str = "actor_get_object_id";
+pub const ACTOR_IS_FEATURE_ENABLED_FUNCTION_NAME: &str = "actor_is_feature_enabled";
 pub const ACTOR_EMIT_EVENT_FUNCTION_NAME: &str = "actor_emit_event";
 
 //=================
@@ -45,6 +47,14 @@ pub const KEY_VALUE_ENTRY_WRITE_FUNCTION_NAME: &str = "kv_entry_write";
 pub const KEY_VALUE_ENTRY_REMOVE_FUNCTION_NAME: &str = "kv_entry_remove";
 pub const KEY_VALUE_ENTRY_CLOSE_FUNCTION_NAME: &str = "kv_entry_close";
 
+//=================
+// Index Store
+//=================
+pub const INDEX_STORE_NEW_FUNCTION_NAME: &str = "index_store_new";
+pub const INDEX_STORE_INSERT_FUNCTION_NAME: &str = "index_store_insert";
+pub const INDEX_STORE_REMOVE_FUNCTION_NAME: &str = "index_store_remove";
+pub const INDEX_STORE_SCAN_FUNCTION_NAME: &str = "index_store_scan";
+
 //=================
 // Field Entry
 //=================
@@ -72,11 +82,22 @@ pub const COSTING_GET_FEE_BALANCE_FUNCTION_NAME: &str = "costing_get_fee_balance
 // System
 //=================
 pub const SYS_LOG_FUNCTION_NAME: &str = "sys_log";
+pub const SYS_LOG_RECORD_FUNCTION_NAME: &str = "sys_log_record";
 pub const SYS_BECH32_ENCODE_ADDRESS_FUNCTION_NAME: &str = "sys_bech32_encode_address";
 pub const SYS_GET_TRANSACTION_HASH_FUNCTION_NAME: &str = "sys_get_transaction_hash";
 pub const SYS_GENERATE_RUID_FUNCTION_NAME: &str = "sys_generate_ruid";
+pub const SYS_GENERATE_RUID_SEEDED_FUNCTION_NAME: &str = "sys_generate_ruid_seeded";
 pub const SYS_PANIC_FUNCTION_NAME: &str = "sys_panic";
 
+//=================
+// Crypto Utils
+//=================
+pub const CRYPTO_UTILS_ED25519_VERIFY_FUNCTION_NAME: &str = "crypto_utils_ed25519_verify";
+pub const CRYPTO_UTILS_SECP256K1_VERIFY_FUNCTION_NAME: &str = "crypto_utils_secp256k1_verify";
+pub const CRYPTO_UTILS_BLS12381_V1_VERIFY_FUNCTION_NAME: &str = "crypto_utils_bls12381_v1_verify";
+pub const CRYPTO_UTILS_KECCAK256_HASH_FUNCTION_NAME: &str = "crypto_utils_keccak256_hash";
+pub const CRYPTO_UTILS_BLAKE2B_256_HASH_FUNCTION_NAME: &str = "crypto_utils_blake2b_256_hash";
+
 //=================
 // WASM Shim
 //=================
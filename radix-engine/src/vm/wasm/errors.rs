@@ -153,6 +153,19 @@ pub enum WasmRuntimeError {
     InvalidPackageAddress,
 
     TooManyBuffers,
+
+    InvalidPublicKey,
+
+    InvalidSignature,
+
+    /// Invalid object features
+    InvalidFeatures(DecodeError),
+
+    /// Invalid index store sort prefix, i.e. not a `u16` (or `u16::MAX + 1`, for an open end)
+    InvalidSortPrefix(u32),
+
+    /// Metering by engine fuel was configured, but the WASM engine can't meter by fuel
+    FuelMeteringNotSupported,
 }
 
 impl SelfError for WasmRuntimeError {
@@ -0,0 +1,35 @@
+use crate::types::*;
+use radix_engine_interface::blueprints::package::CodeHash;
+
+/// How the execution of Scrypto WASM code is metered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WasmMeteringMode {
+    /// The code is run as instrumented at publish time, charging through the injected calls to
+    /// `consume_wasm_execution_units`. This is the mode used by the network.
+    #[default]
+    InstructionInjection,
+    /// The original code is run, charging by the fuel the WASM engine consumed, converted into
+    /// execution units.
+    ///
+    /// The fuel schedule of the engine only approximates the instruction weights, so this is
+    /// meant for performance experiments and never for consensus.
+    ///
+    /// Only the wasmi engine can meter by fuel: with the wasmer engine, every invocation of
+    /// Scrypto code fails with [`WasmRuntimeError::FuelMeteringNotSupported`].
+    ///
+    /// [`WasmRuntimeError::FuelMeteringNotSupported`]: crate::vm::wasm::WasmRuntimeError::FuelMeteringNotSupported
+    EngineFuel,
+}
+
+/// The cache key of the fuel metered module of some code, which must never clash with the key of
+/// its instrumented module, nor with that of a module charging a different rate of fuel.
+pub fn fuel_metered_code_hash(code_hash: CodeHash, execution_units_per_fuel: u32) -> CodeHash {
+    CodeHash(hash(
+        [
+            code_hash.as_ref(),
+            b"fuel_metered".as_slice(),
+            &execution_units_per_fuel.to_le_bytes(),
+        ]
+        .concat(),
+    ))
+}
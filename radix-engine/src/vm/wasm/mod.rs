@@ -1,5 +1,6 @@
 mod constants;
 mod errors;
+mod metering;
 mod prepare;
 mod traits;
 mod wasm_validator;
@@ -14,6 +15,7 @@ pub use self::wasmer::*;
 pub use self::wasmi::*;
 pub use constants::*;
 pub use errors::*;
+pub use metering::*;
 pub use prepare::*;
 pub use traits::*;
 pub use wasm_validator::*;
@@ -18,11 +18,26 @@ pub struct WasmModule {
 
 impl WasmModule {
     pub fn init(code: &[u8]) -> Result<Self, PrepareError> {
-        // deserialize
+        let module = Self::parse(code)?;
+
+        module
+            .module
+            .validate(Self::supported_features())
+            .map_err(|err| PrepareError::ValidationError(err.to_string()))?;
+
+        Ok(module)
+    }
+
+    /// Deserializes the module without validating it, e.g. to report on an invalid module.
+    pub fn parse(code: &[u8]) -> Result<Self, PrepareError> {
         let module = ModuleInfo::new(code).map_err(|_| PrepareError::DeserializationError)?;
 
-        // Radix Engine supports MVP + proposals: mutable globals and sign-extension-ops
-        let features = WasmFeatures {
+        Ok(Self { module })
+    }
+
+    /// Radix Engine supports MVP + proposals: mutable globals and sign-extension-ops
+    fn supported_features() -> WasmFeatures {
+        WasmFeatures {
             mutable_global: true,
             saturating_float_to_int: false,
             sign_extension: true,
@@ -42,13 +57,70 @@ impl WasmModule {
             function_references: false,
             memory_control: false,
             gc: false,
-        };
+        }
+    }
 
-        module
-            .validate(features)
-            .map_err(|err| PrepareError::ValidationError(err.to_string()))?;
+    /// Returns the names of the WASM features used by the module which Radix Engine doesn't
+    /// support, e.g. `floats`.
+    ///
+    /// A feature is considered used if the module only validates with it enabled, so nothing
+    /// is returned for a module which is invalid regardless of the features.
+    pub fn unsupported_features(&self) -> Vec<&'static str> {
+        let all_features = WasmFeatures {
+            mutable_global: true,
+            saturating_float_to_int: true,
+            sign_extension: true,
+            reference_types: true,
+            multi_value: true,
+            bulk_memory: true,
+            simd: true,
+            relaxed_simd: true,
+            threads: true,
+            tail_call: true,
+            floats: true,
+            multi_memory: true,
+            exceptions: true,
+            memory64: true,
+            extended_const: true,
+            component_model: true,
+            function_references: true,
+            memory_control: true,
+            gc: true,
+        };
+        if self.module.validate(all_features).is_err() {
+            return vec![];
+        }
 
-        Ok(Self { module })
+        let unsupported: [(&'static str, fn(&mut WasmFeatures)); 17] = [
+            ("saturating_float_to_int", |f| {
+                f.saturating_float_to_int = false
+            }),
+            ("reference_types", |f| f.reference_types = false),
+            ("multi_value", |f| f.multi_value = false),
+            ("bulk_memory", |f| f.bulk_memory = false),
+            ("simd", |f| f.simd = false),
+            ("relaxed_simd", |f| f.relaxed_simd = false),
+            ("threads", |f| f.threads = false),
+            ("tail_call", |f| f.tail_call = false),
+            ("floats", |f| f.floats = false),
+            ("multi_memory", |f| f.multi_memory = false),
+            ("exceptions", |f| f.exceptions = false),
+            ("memory64", |f| f.memory64 = false),
+            ("extended_const", |f| f.extended_const = false),
+            ("component_model", |f| f.component_model = false),
+            ("function_references", |f| f.function_references = false),
+            ("memory_control", |f| f.memory_control = false),
+            ("gc", |f| f.gc = false),
+        ];
+        unsupported
+            .into_iter()
+            .filter(|(_, disable)| {
+                let mut features = all_features;
+                disable(&mut features);
+                self.module.validate(features).is_err()
+            })
+            .map(|(name, _)| name)
+            .collect()
     }
 
     pub fn enforce_no_start_function(self) -> Result<Self, PrepareError> {
@@ -282,6 +354,90 @@ impl WasmModule {
                             ));
                         }
                     }
+                    INDEX_STORE_NEW_FUNCTION_NAME => {
+                        if let TypeRef::Func(type_index) = entry.ty {
+                            if Self::function_type_matches(
+                                &self.module,
+                                type_index,
+                                vec![ValType::I32, ValType::I32],
+                                vec![ValType::I64],
+                            ) {
+                                continue;
+                            }
+
+                            return Err(PrepareError::InvalidImport(
+                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
+                            ));
+                        }
+                    }
+                    INDEX_STORE_INSERT_FUNCTION_NAME => {
+                        if let TypeRef::Func(type_index) = entry.ty {
+                            if Self::function_type_matches(
+                                &self.module,
+                                type_index,
+                                vec![
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                ],
+                                vec![],
+                            ) {
+                                continue;
+                            }
+
+                            return Err(PrepareError::InvalidImport(
+                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
+                            ));
+                        }
+                    }
+                    INDEX_STORE_REMOVE_FUNCTION_NAME => {
+                        if let TypeRef::Func(type_index) = entry.ty {
+                            if Self::function_type_matches(
+                                &self.module,
+                                type_index,
+                                vec![
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                ],
+                                vec![ValType::I64],
+                            ) {
+                                continue;
+                            }
+
+                            return Err(PrepareError::InvalidImport(
+                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
+                            ));
+                        }
+                    }
+                    INDEX_STORE_SCAN_FUNCTION_NAME => {
+                        if let TypeRef::Func(type_index) = entry.ty {
+                            if Self::function_type_matches(
+                                &self.module,
+                                type_index,
+                                vec![
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                ],
+                                vec![ValType::I64],
+                            ) {
+                                continue;
+                            }
+
+                            return Err(PrepareError::InvalidImport(
+                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
+                            ));
+                        }
+                    }
                     ACTOR_OPEN_FIELD_FUNCTION_NAME => {
                         if let TypeRef::Func(type_index) = entry.ty {
                             if Self::function_type_matches(
@@ -362,6 +518,22 @@ impl WasmModule {
                             ));
                         }
                     }
+                    ACTOR_IS_FEATURE_ENABLED_FUNCTION_NAME => {
+                        if let TypeRef::Func(type_index) = entry.ty {
+                            if Self::function_type_matches(
+                                &self.module,
+                                type_index,
+                                vec![ValType::I32, ValType::I32, ValType::I32],
+                                vec![ValType::I32],
+                            ) {
+                                continue;
+                            }
+
+                            return Err(PrepareError::InvalidImport(
+                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
+                            ));
+                        }
+                    }
                     ACTOR_GET_PACKAGE_ADDRESS_FUNCTION_NAME => {
                         if let TypeRef::Func(type_index) = entry.ty {
                             if Self::function_type_matches(
@@ -411,6 +583,29 @@ impl WasmModule {
                             ));
                         }
                     }
+                    OBJECT_NEW_WITH_FEATURES_FUNCTION_NAME => {
+                        if let TypeRef::Func(type_index) = entry.ty {
+                            if Self::function_type_matches(
+                                &self.module,
+                                type_index,
+                                vec![
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                ],
+                                vec![ValType::I64],
+                            ) {
+                                continue;
+                            }
+
+                            return Err(PrepareError::InvalidImport(
+                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
+                            ));
+                        }
+                    }
 
                     COSTING_GET_EXECUTION_COST_UNIT_LIMIT_FUNCTION_NAME => {
                         if let TypeRef::Func(type_index) = entry.ty {
@@ -673,6 +868,30 @@ impl WasmModule {
                             ));
                         }
                     }
+                    SYS_LOG_RECORD_FUNCTION_NAME => {
+                        if let TypeRef::Func(type_index) = entry.ty {
+                            if Self::function_type_matches(
+                                &self.module,
+                                type_index,
+                                vec![
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                ],
+                                vec![],
+                            ) {
+                                continue;
+                            }
+                            return Err(PrepareError::InvalidImport(
+                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
+                            ));
+                        }
+                    }
                     SYS_BECH32_ENCODE_ADDRESS_FUNCTION_NAME => {
                         if let TypeRef::Func(type_index) = entry.ty {
                             if Self::function_type_matches(
@@ -733,6 +952,117 @@ impl WasmModule {
                             ));
                         }
                     }
+                    SYS_GENERATE_RUID_SEEDED_FUNCTION_NAME => {
+                        if let TypeRef::Func(type_index) = entry.ty {
+                            if Self::function_type_matches(
+                                &self.module,
+                                type_index,
+                                vec![ValType::I32, ValType::I32],
+                                vec![ValType::I64],
+                            ) {
+                                continue;
+                            }
+                            return Err(PrepareError::InvalidImport(
+                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
+                            ));
+                        }
+                    }
+                    CRYPTO_UTILS_ED25519_VERIFY_FUNCTION_NAME => {
+                        if let TypeRef::Func(type_index) = entry.ty {
+                            if Self::function_type_matches(
+                                &self.module,
+                                type_index,
+                                vec![
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                ],
+                                vec![ValType::I32],
+                            ) {
+                                continue;
+                            }
+                            return Err(PrepareError::InvalidImport(
+                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
+                            ));
+                        }
+                    }
+                    CRYPTO_UTILS_SECP256K1_VERIFY_FUNCTION_NAME => {
+                        if let TypeRef::Func(type_index) = entry.ty {
+                            if Self::function_type_matches(
+                                &self.module,
+                                type_index,
+                                vec![
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                ],
+                                vec![ValType::I32],
+                            ) {
+                                continue;
+                            }
+                            return Err(PrepareError::InvalidImport(
+                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
+                            ));
+                        }
+                    }
+                    CRYPTO_UTILS_BLS12381_V1_VERIFY_FUNCTION_NAME => {
+                        if let TypeRef::Func(type_index) = entry.ty {
+                            if Self::function_type_matches(
+                                &self.module,
+                                type_index,
+                                vec![
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                    ValType::I32,
+                                ],
+                                vec![ValType::I32],
+                            ) {
+                                continue;
+                            }
+                            return Err(PrepareError::InvalidImport(
+                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
+                            ));
+                        }
+                    }
+                    CRYPTO_UTILS_KECCAK256_HASH_FUNCTION_NAME => {
+                        if let TypeRef::Func(type_index) = entry.ty {
+                            if Self::function_type_matches(
+                                &self.module,
+                                type_index,
+                                vec![ValType::I32, ValType::I32],
+                                vec![ValType::I64],
+                            ) {
+                                continue;
+                            }
+                            return Err(PrepareError::InvalidImport(
+                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
+                            ));
+                        }
+                    }
+                    CRYPTO_UTILS_BLAKE2B_256_HASH_FUNCTION_NAME => {
+                        if let TypeRef::Func(type_index) = entry.ty {
+                            if Self::function_type_matches(
+                                &self.module,
+                                type_index,
+                                vec![ValType::I32, ValType::I32],
+                                vec![ValType::I64],
+                            ) {
+                                continue;
+                            }
+                            return Err(PrepareError::InvalidImport(
+                                InvalidImport::InvalidFunctionType(entry.name.to_string()),
+                            ));
+                        }
+                    }
                     _ => {}
                 };
             }
@@ -944,39 +1274,48 @@ impl WasmModule {
         self,
         blueprints: I,
     ) -> Result<Self, PrepareError> {
+        if let Some(export_name) = self.missing_exports(blueprints)?.into_iter().next() {
+            return Err(PrepareError::MissingExport { export_name });
+        }
+
+        Ok(self)
+    }
+
+    /// Returns the names of all the functions required by the blueprint schemas which aren't
+    /// exported with the expected signature.
+    pub fn missing_exports<'a, I: Iterator<Item = &'a BlueprintDefinitionInit>>(
+        &self,
+        blueprints: I,
+    ) -> Result<Vec<String>, PrepareError> {
         let exports = self
             .module
             .export_section()
-            .map_err(|err| PrepareError::ModuleInfoError(err.to_string()))?;
-
-        if let Some(exports) = exports {
-            for blueprint_def_init in blueprints {
-                for export_name in blueprint_def_init.schema.exports() {
-                    if !exports.iter().any(|x| {
-                        x.name.eq(&export_name) && {
-                            if let ExternalKind::Func = x.kind {
-                                Self::function_matches(
-                                    &self.module,
-                                    x.index as usize,
-                                    vec![ValType::I64],
-                                    vec![ValType::I64],
-                                )
-                            } else {
-                                false
-                            }
+            .map_err(|err| PrepareError::ModuleInfoError(err.to_string()))?
+            .ok_or(PrepareError::NoExportSection)?;
+
+        let mut missing_exports = vec![];
+        for blueprint_def_init in blueprints {
+            for export_name in blueprint_def_init.schema.exports() {
+                if !exports.iter().any(|x| {
+                    x.name.eq(&export_name) && {
+                        if let ExternalKind::Func = x.kind {
+                            Self::function_matches(
+                                &self.module,
+                                x.index as usize,
+                                vec![ValType::I64],
+                                vec![ValType::I64],
+                            )
+                        } else {
+                            false
                         }
-                    }) {
-                        return Err(PrepareError::MissingExport {
-                            export_name: export_name.to_string(),
-                        });
                     }
+                }) {
+                    missing_exports.push(export_name);
                 }
             }
-
-            Ok(self)
-        } else {
-            Err(PrepareError::NoExportSection)
         }
+
+        Ok(missing_exports)
     }
 
     pub fn inject_instruction_metering<R: Rules>(
@@ -1252,14 +1591,20 @@ mod tests {
             KEY_VALUE_ENTRY_REMOVE_FUNCTION_NAME,
             KEY_VALUE_ENTRY_CLOSE_FUNCTION_NAME,
             KEY_VALUE_STORE_REMOVE_ENTRY_FUNCTION_NAME,
+            INDEX_STORE_NEW_FUNCTION_NAME,
+            INDEX_STORE_INSERT_FUNCTION_NAME,
+            INDEX_STORE_REMOVE_FUNCTION_NAME,
+            INDEX_STORE_SCAN_FUNCTION_NAME,
             ACTOR_OPEN_FIELD_FUNCTION_NAME,
             FIELD_ENTRY_READ_FUNCTION_NAME,
             FIELD_ENTRY_WRITE_FUNCTION_NAME,
             FIELD_ENTRY_CLOSE_FUNCTION_NAME,
             ACTOR_GET_OBJECT_ID_FUNCTION_NAME,
+            ACTOR_IS_FEATURE_ENABLED_FUNCTION_NAME,
             ACTOR_GET_PACKAGE_ADDRESS_FUNCTION_NAME,
             ACTOR_GET_BLUEPRINT_NAME_FUNCTION_NAME,
             OBJECT_NEW_FUNCTION_NAME,
+            OBJECT_NEW_WITH_FEATURES_FUNCTION_NAME,
             COSTING_GET_EXECUTION_COST_UNIT_LIMIT_FUNCTION_NAME,
             COSTING_GET_EXECUTION_COST_UNIT_PRICE_FUNCTION_NAME,
             COSTING_GET_FINALIZATION_COST_UNIT_LIMIT_FUNCTION_NAME,
@@ -1276,10 +1621,17 @@ mod tests {
             OBJECT_GET_OUTER_OBJECT_FUNCTION_NAME,
             ACTOR_EMIT_EVENT_FUNCTION_NAME,
             SYS_LOG_FUNCTION_NAME,
+            SYS_LOG_RECORD_FUNCTION_NAME,
             SYS_BECH32_ENCODE_ADDRESS_FUNCTION_NAME,
             SYS_PANIC_FUNCTION_NAME,
             SYS_GET_TRANSACTION_HASH_FUNCTION_NAME,
             SYS_GENERATE_RUID_FUNCTION_NAME,
+            SYS_GENERATE_RUID_SEEDED_FUNCTION_NAME,
+            CRYPTO_UTILS_ED25519_VERIFY_FUNCTION_NAME,
+            CRYPTO_UTILS_SECP256K1_VERIFY_FUNCTION_NAME,
+            CRYPTO_UTILS_BLS12381_V1_VERIFY_FUNCTION_NAME,
+            CRYPTO_UTILS_KECCAK256_HASH_FUNCTION_NAME,
+            CRYPTO_UTILS_BLAKE2B_256_HASH_FUNCTION_NAME,
         ] {
             assert_invalid_wasm!(
                 wat.replace("name_to_replace", name),
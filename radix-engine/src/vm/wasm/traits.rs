@@ -53,6 +53,13 @@ pub trait WasmRuntime {
         object_states: Vec<u8>,
     ) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
 
+    fn object_new_with_features(
+        &mut self,
+        blueprint_name: Vec<u8>,
+        features: Vec<u8>,
+        object_states: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
+
     fn address_allocate(
         &mut self,
         package_address: Vec<u8>,
@@ -105,6 +112,32 @@ pub trait WasmRuntime {
         key: Vec<u8>,
     ) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
 
+    fn index_store_new(&mut self, schema: Vec<u8>)
+        -> Result<Buffer, InvokeError<WasmRuntimeError>>;
+
+    fn index_store_insert(
+        &mut self,
+        node_id: Vec<u8>,
+        sort_prefix: u32,
+        key: Vec<u8>,
+        value: Vec<u8>,
+    ) -> Result<(), InvokeError<WasmRuntimeError>>;
+
+    fn index_store_remove(
+        &mut self,
+        node_id: Vec<u8>,
+        sort_prefix: u32,
+        key: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
+
+    fn index_store_scan(
+        &mut self,
+        node_id: Vec<u8>,
+        from_sort_prefix: u32,
+        to_sort_prefix: u32,
+        count: u32,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
+
     fn instance_of(
         &mut self,
         object_id: Vec<u8>,
@@ -148,6 +181,12 @@ pub trait WasmRuntime {
         actor_ref_handle: ActorRefHandle,
     ) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
 
+    fn actor_is_feature_enabled(
+        &mut self,
+        object_handle: u32,
+        feature: Vec<u8>,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>>;
+
     fn actor_get_package_address(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
 
     fn actor_get_blueprint_name(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
@@ -190,6 +229,15 @@ pub trait WasmRuntime {
         message: Vec<u8>,
     ) -> Result<(), InvokeError<WasmRuntimeError>>;
 
+    /// Emits a log under a topic, with an SBOR-encoded payload, which is empty if there is none.
+    fn sys_log_record(
+        &mut self,
+        level: Vec<u8>,
+        topic: Vec<u8>,
+        message: Vec<u8>,
+        payload: Vec<u8>,
+    ) -> Result<(), InvokeError<WasmRuntimeError>>;
+
     fn sys_bech32_encode_address(
         &mut self,
         address: Vec<u8>,
@@ -199,7 +247,52 @@ pub trait WasmRuntime {
 
     fn sys_generate_ruid(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
 
+    fn sys_generate_ruid_seeded(
+        &mut self,
+        domain: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
+
     fn sys_panic(&mut self, message: Vec<u8>) -> Result<(), InvokeError<WasmRuntimeError>>;
+
+    fn crypto_utils_ed25519_verify(
+        &mut self,
+        message: Vec<u8>,
+        public_key: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>>;
+
+    fn crypto_utils_secp256k1_verify(
+        &mut self,
+        message: Vec<u8>,
+        public_key: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>>;
+
+    fn crypto_utils_bls12381_v1_verify(
+        &mut self,
+        message: Vec<u8>,
+        public_key: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>>;
+
+    fn crypto_utils_keccak256_hash(
+        &mut self,
+        data: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
+
+    fn crypto_utils_blake2b_256_hash(
+        &mut self,
+        data: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>>;
+
+    /// Checks the current size of the linear memory, in bytes, against the limit of the call
+    /// frame. The engines call it on every call into the host (which, with the instrumentation
+    /// metering, includes the frequent execution unit charges), so that the limit is enforced
+    /// while the module runs, rather than only once it returns.
+    fn check_memory_size(
+        &mut self,
+        memory_size: usize,
+    ) -> Result<(), InvokeError<WasmRuntimeError>>;
 }
 
 /// Represents an instantiated, invokable Scrypto module.
@@ -217,6 +310,11 @@ pub trait WasmInstance {
         args: Vec<Buffer>,
         runtime: &mut Box<dyn WasmRuntime + 'r>,
     ) -> Result<Vec<u8>, InvokeError<WasmRuntimeError>>;
+
+    /// Returns the current size of the linear memory, in bytes.
+    ///
+    /// As a WASM memory never shrinks, this is also its peak size.
+    fn memory_size(&mut self) -> Result<usize, InvokeError<WasmRuntimeError>>;
 }
 
 /// A Scrypto WASM engine validates, instruments and runs Scrypto modules.
@@ -227,4 +325,30 @@ pub trait WasmEngine {
     ///
     /// The code must have been validated and instrumented!
     fn instantiate(&self, code_hash: CodeHash, instrumented_code: &[u8]) -> Self::WasmInstance;
+
+    /// Whether the engine can meter the execution of modules by its own fuel.
+    fn supports_fuel_metering(&self) -> bool {
+        false
+    }
+
+    /// Instantiate a Scrypto module which is charged by the fuel the engine consumes running it,
+    /// at the given execution units per unit of fuel, rather than by instrumentation. An
+    /// invocation of the instance runs out of fuel once it has consumed more than the given max
+    /// execution units.
+    ///
+    /// The code is only prepared if its module isn't cached. It must have been validated and
+    /// prepared for fuel metering, and the engine must support fuel metering!
+    fn instantiate_fuel_metered(
+        &self,
+        _code_hash: CodeHash,
+        _prepare_code: &dyn Fn() -> Vec<u8>,
+        _execution_units_per_fuel: u32,
+        _max_execution_units: u64,
+    ) -> Self::WasmInstance {
+        panic!("Fuel metering is not supported by this WASM engine")
+    }
+
+    /// Whether the compiled module of the code is cached, so that instantiating it doesn't
+    /// require compiling it.
+    fn is_cached(&self, code_hash: CodeHash) -> bool;
 }
@@ -2,6 +2,20 @@ use crate::types::*;
 use crate::vm::wasm::*;
 use radix_engine_interface::blueprints::package::BlueprintDefinitionInit;
 
+/// All the issues which prevent a WASM module from being published, rather than just the first one.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct WasmValidationReport {
+    /// The WASM features used by the module which Radix Engine doesn't support, e.g. `floats`.
+    pub unsupported_features: Vec<String>,
+    pub issues: Vec<PrepareError>,
+}
+
+impl WasmValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
 pub struct WasmValidator {
     pub max_memory_size_in_pages: u32,
     pub max_initial_table_size: u32,
@@ -54,6 +68,85 @@ impl WasmValidator {
             .ensure_compilable()?
             .to_bytes()
     }
+
+    /// Prepares already validated code to be run with engine fuel metering, applying the same
+    /// memory and stack limits as [`WasmValidator::validate`] without injecting any metering.
+    pub fn prepare_for_fuel_metering(&self, code: &[u8]) -> Result<Vec<u8>, PrepareError> {
+        WasmModule::init(code)?
+            .enforce_memory_limit_and_inject_max(self.max_memory_size_in_pages)?
+            .inject_stack_metering(self.instrumenter_config.max_stack_size())?
+            .to_bytes()
+            .map(|(code, _)| code)
+    }
+
+    /// Runs all the checks of [`WasmValidator::validate`] independently of each other, so that
+    /// every issue with the module is reported.
+    ///
+    /// The instrumentation steps are only attempted once all the other checks pass, as they
+    /// assume a valid module.
+    pub fn validate_with_report<'a, I: Iterator<Item = &'a BlueprintDefinitionInit>>(
+        &self,
+        code: &[u8],
+        blueprints: I,
+    ) -> WasmValidationReport {
+        let blueprints: Vec<&BlueprintDefinitionInit> = blueprints.collect();
+        let mut report = WasmValidationReport::default();
+
+        let module = match WasmModule::parse(code) {
+            Ok(module) => module,
+            Err(err) => {
+                report.issues.push(err);
+                return report;
+            }
+        };
+        report.unsupported_features = module
+            .unsupported_features()
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        if let Err(err) = WasmModule::init(code) {
+            report.issues.push(err);
+        }
+        match module.missing_exports(blueprints.iter().copied()) {
+            Ok(missing_exports) => report.issues.extend(
+                missing_exports
+                    .into_iter()
+                    .map(|export_name| PrepareError::MissingExport { export_name }),
+            ),
+            Err(err) => report.issues.push(err),
+        }
+
+        // Each check consumes the module, so it's parsed afresh for each of them
+        let checks: [&dyn Fn(WasmModule) -> Result<WasmModule, PrepareError>; 8] = [
+            &|module| module.enforce_no_start_function(),
+            &|module| module.enforce_import_limit(),
+            &|module| module.enforce_export_names(),
+            &|module| module.enforce_memory_limit_and_inject_max(self.max_memory_size_in_pages),
+            &|module| module.enforce_table_limit(self.max_initial_table_size),
+            &|module| module.enforce_br_table_limit(self.max_number_of_br_table_targets),
+            &|module| {
+                module.enforce_function_limit(
+                    self.max_number_of_functions,
+                    self.max_number_of_function_params,
+                    self.max_number_of_function_locals,
+                )
+            },
+            &|module| module.enforce_global_limit(self.max_number_of_globals),
+        ];
+        for check in checks {
+            if let Err(err) = WasmModule::parse(code).and_then(check) {
+                report.issues.push(err);
+            }
+        }
+
+        if report.is_valid() {
+            if let Err(err) = self.validate(code, blueprints.into_iter()) {
+                report.issues.push(err);
+            }
+        }
+
+        report
+    }
 }
 
 #[cfg(test)]
@@ -62,6 +155,7 @@ mod tests {
     use wabt::{wasm2wat, wat2wasm};
 
     use super::WasmValidator;
+    use crate::vm::wasm::{InvalidMemory, PrepareError};
 
     #[test]
     fn test_validate() {
@@ -157,4 +251,74 @@ mod tests {
 "#
         )
     }
+
+    #[test]
+    fn test_validate_with_report_lists_all_issues() {
+        let code = wat2wasm(
+            r#"
+        (module
+            (func $start)
+
+            ;; Uses floats, and is exported under the wrong name
+            (func $Test_f (param $0 i64) (result i64)
+              (drop
+                (f64.const 1)
+              )
+              (i64.const 0)
+            )
+
+            (start $start)
+            (memory $0 1)
+            (export "Test_f" (func $Test_f))
+        )"#,
+        )
+        .unwrap();
+
+        let report = WasmValidator::default().validate_with_report(
+            &code,
+            PackageDefinition::new_single_function_test_definition("Test", "g")
+                .blueprints
+                .values(),
+        );
+
+        assert!(!report.is_valid());
+        assert_eq!(report.unsupported_features, vec!["floats".to_string()]);
+        assert!(matches!(report.issues[0], PrepareError::ValidationError(_)));
+        assert_eq!(
+            report.issues[1..],
+            [
+                PrepareError::MissingExport {
+                    export_name: "Test_g".to_string()
+                },
+                PrepareError::StartFunctionNotAllowed,
+                PrepareError::InvalidMemory(InvalidMemory::MemoryNotExported),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_with_report_of_valid_module() {
+        let code = wat2wasm(
+            r#"
+        (module
+            (func $Test_f (param $0 i64) (result i64)
+              (i64.const 0)
+            )
+            (memory $0 1)
+            (export "memory" (memory $0))
+            (export "Test_f" (func $Test_f))
+        )"#,
+        )
+        .unwrap();
+
+        let report = WasmValidator::default().validate_with_report(
+            &code,
+            PackageDefinition::new_single_function_test_definition("Test", "f")
+                .blueprints
+                .values(),
+        );
+
+        assert!(report.is_valid());
+        assert!(report.unsupported_features.is_empty());
+    }
 }
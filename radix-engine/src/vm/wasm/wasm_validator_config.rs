@@ -26,6 +26,14 @@ impl WasmValidatorConfigV1 {
     pub fn max_stack_size(&self) -> u32 {
         self.max_stack_size
     }
+
+    /// The execution units charged per unit of fuel consumed when metering by engine fuel.
+    ///
+    /// The fuel schedule charges most instructions a single unit, so this is the weight of the
+    /// cheapest instruction, which the common ones are weighted close to.
+    pub fn execution_units_per_fuel(&self) -> u32 {
+        self.weights.i64const.max(1)
+    }
 }
 
 impl Rules for WasmValidatorConfigV1 {
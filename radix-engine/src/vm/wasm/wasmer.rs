@@ -3,6 +3,8 @@ use crate::types::*;
 use crate::vm::wasm::constants::*;
 use crate::vm::wasm::errors::*;
 use crate::vm::wasm::traits::*;
+#[cfg(all(not(feature = "radix_engine_fuzzing"), feature = "moka"))]
+use lazy_static::lazy_static;
 use radix_engine_interface::api::actor_api::EventFlags;
 use radix_engine_interface::blueprints::package::CodeHash;
 use sbor::rust::sync::{Arc, Mutex};
@@ -149,6 +151,7 @@ macro_rules! grab_runtime {
         let instance = unsafe { $env.instance.get_unchecked() };
         let ptr = $env.runtime_ptr.lock().expect("Runtime ptr unavailable");
         let runtime: &mut Box<dyn WasmRuntime> = unsafe { &mut *(*ptr as *mut _) };
+        runtime.check_memory_size(get_memory_size(instance)?)?;
         (instance, runtime)
     }};
 }
@@ -309,6 +312,26 @@ impl WasmerModule {
                 .map(|buffer| buffer.0)
         }
 
+        pub fn object_new_with_features(
+            env: &WasmerInstanceEnv,
+            blueprint_name_ptr: u32,
+            blueprint_name_len: u32,
+            features_ptr: u32,
+            features_len: u32,
+            object_states_ptr: u32,
+            object_states_len: u32,
+        ) -> Result<u64, InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            runtime
+                .object_new_with_features(
+                    read_memory(&instance, blueprint_name_ptr, blueprint_name_len)?,
+                    read_memory(&instance, features_ptr, features_len)?,
+                    read_memory(&instance, object_states_ptr, object_states_len)?,
+                )
+                .map(|buffer| buffer.0)
+        }
+
         pub fn object_globalize(
             env: &WasmerInstanceEnv,
             obj_ptr: u32,
@@ -417,6 +440,77 @@ impl WasmerModule {
                 .map(|buffer| buffer.0)
         }
 
+        pub fn index_store_new(
+            env: &WasmerInstanceEnv,
+            schema_ptr: u32,
+            schema_len: u32,
+        ) -> Result<u64, InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            runtime
+                .index_store_new(read_memory(&instance, schema_ptr, schema_len)?)
+                .map(|buffer| buffer.0)
+        }
+
+        pub fn index_store_insert(
+            env: &WasmerInstanceEnv,
+            node_id_ptr: u32,
+            node_id_len: u32,
+            sort_prefix: u32,
+            key_ptr: u32,
+            key_len: u32,
+            value_ptr: u32,
+            value_len: u32,
+        ) -> Result<(), InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            runtime.index_store_insert(
+                read_memory(&instance, node_id_ptr, node_id_len)?,
+                sort_prefix,
+                read_memory(&instance, key_ptr, key_len)?,
+                read_memory(&instance, value_ptr, value_len)?,
+            )
+        }
+
+        pub fn index_store_remove(
+            env: &WasmerInstanceEnv,
+            node_id_ptr: u32,
+            node_id_len: u32,
+            sort_prefix: u32,
+            key_ptr: u32,
+            key_len: u32,
+        ) -> Result<u64, InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            runtime
+                .index_store_remove(
+                    read_memory(&instance, node_id_ptr, node_id_len)?,
+                    sort_prefix,
+                    read_memory(&instance, key_ptr, key_len)?,
+                )
+                .map(|buffer| buffer.0)
+        }
+
+        pub fn index_store_scan(
+            env: &WasmerInstanceEnv,
+            node_id_ptr: u32,
+            node_id_len: u32,
+            from_sort_prefix: u32,
+            to_sort_prefix: u32,
+            count: u32,
+        ) -> Result<u64, InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            runtime
+                .index_store_scan(
+                    read_memory(&instance, node_id_ptr, node_id_len)?,
+                    from_sort_prefix,
+                    to_sort_prefix,
+                    count,
+                )
+                .map(|buffer| buffer.0)
+        }
+
         pub fn key_value_entry_read(
             env: &WasmerInstanceEnv,
             handle: u32,
@@ -512,6 +606,19 @@ impl WasmerModule {
                 .map(|buffer| buffer.0)
         }
 
+        pub fn actor_is_feature_enabled(
+            env: &WasmerInstanceEnv,
+            object_handle: u32,
+            feature_ptr: u32,
+            feature_len: u32,
+        ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let feature = read_memory(&instance, feature_ptr, feature_len)?;
+
+            runtime.actor_is_feature_enabled(object_handle, feature)
+        }
+
         pub fn actor_get_package_address(
             env: &WasmerInstanceEnv,
         ) -> Result<u64, InvokeError<WasmRuntimeError>> {
@@ -632,6 +739,27 @@ impl WasmerModule {
             runtime.sys_log(level, message)
         }
 
+        fn sys_log_record(
+            env: &WasmerInstanceEnv,
+            level_ptr: u32,
+            level_len: u32,
+            topic_ptr: u32,
+            topic_len: u32,
+            message_ptr: u32,
+            message_len: u32,
+            payload_ptr: u32,
+            payload_len: u32,
+        ) -> Result<(), InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let level = read_memory(&instance, level_ptr, level_len)?;
+            let topic = read_memory(&instance, topic_ptr, topic_len)?;
+            let message = read_memory(&instance, message_ptr, message_len)?;
+            let payload = read_memory(&instance, payload_ptr, payload_len)?;
+
+            runtime.sys_log_record(level, topic, message, payload)
+        }
+
         fn sys_bech32_encode_address(
             env: &WasmerInstanceEnv,
             address_ptr: u32,
@@ -674,6 +802,102 @@ impl WasmerModule {
             runtime.sys_generate_ruid().map(|buffer| buffer.0)
         }
 
+        pub fn sys_generate_ruid_seeded(
+            env: &WasmerInstanceEnv,
+            domain_ptr: u32,
+            domain_len: u32,
+        ) -> Result<u64, InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let domain = read_memory(&instance, domain_ptr, domain_len)?;
+
+            runtime
+                .sys_generate_ruid_seeded(domain)
+                .map(|buffer| buffer.0)
+        }
+
+        fn crypto_utils_ed25519_verify(
+            env: &WasmerInstanceEnv,
+            message_ptr: u32,
+            message_len: u32,
+            public_key_ptr: u32,
+            public_key_len: u32,
+            signature_ptr: u32,
+            signature_len: u32,
+        ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let message = read_memory(&instance, message_ptr, message_len)?;
+            let public_key = read_memory(&instance, public_key_ptr, public_key_len)?;
+            let signature = read_memory(&instance, signature_ptr, signature_len)?;
+
+            runtime.crypto_utils_ed25519_verify(message, public_key, signature)
+        }
+
+        fn crypto_utils_secp256k1_verify(
+            env: &WasmerInstanceEnv,
+            message_ptr: u32,
+            message_len: u32,
+            public_key_ptr: u32,
+            public_key_len: u32,
+            signature_ptr: u32,
+            signature_len: u32,
+        ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let message = read_memory(&instance, message_ptr, message_len)?;
+            let public_key = read_memory(&instance, public_key_ptr, public_key_len)?;
+            let signature = read_memory(&instance, signature_ptr, signature_len)?;
+
+            runtime.crypto_utils_secp256k1_verify(message, public_key, signature)
+        }
+
+        fn crypto_utils_bls12381_v1_verify(
+            env: &WasmerInstanceEnv,
+            message_ptr: u32,
+            message_len: u32,
+            public_key_ptr: u32,
+            public_key_len: u32,
+            signature_ptr: u32,
+            signature_len: u32,
+        ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let message = read_memory(&instance, message_ptr, message_len)?;
+            let public_key = read_memory(&instance, public_key_ptr, public_key_len)?;
+            let signature = read_memory(&instance, signature_ptr, signature_len)?;
+
+            runtime.crypto_utils_bls12381_v1_verify(message, public_key, signature)
+        }
+
+        fn crypto_utils_keccak256_hash(
+            env: &WasmerInstanceEnv,
+            data_ptr: u32,
+            data_len: u32,
+        ) -> Result<u64, InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let data = read_memory(&instance, data_ptr, data_len)?;
+
+            runtime
+                .crypto_utils_keccak256_hash(data)
+                .map(|buffer| buffer.0)
+        }
+
+        fn crypto_utils_blake2b_256_hash(
+            env: &WasmerInstanceEnv,
+            data_ptr: u32,
+            data_len: u32,
+        ) -> Result<u64, InvokeError<WasmRuntimeError>> {
+            let (instance, runtime) = grab_runtime!(env);
+
+            let data = read_memory(&instance, data_ptr, data_len)?;
+
+            runtime
+                .crypto_utils_blake2b_256_hash(data)
+                .map(|buffer| buffer.0)
+        }
+
         #[cfg(feature = "radix_engine_tests")]
         pub fn host_read_memory(
             env: &WasmerInstanceEnv,
@@ -741,6 +965,7 @@ impl WasmerModule {
                 ADDRESS_ALLOCATE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), address_allocate),
                 ADDRESS_GET_RESERVATION_ADDRESS_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), address_get_reservation_address),
                 OBJECT_NEW_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), object_new),
+                OBJECT_NEW_WITH_FEATURES_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), object_new_with_features),
                 OBJECT_GLOBALIZE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), object_globalize),
                 OBJECT_INSTANCE_OF_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), object_instance_of),
                 OBJECT_GET_BLUEPRINT_ID_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), object_get_blueprint_id),
@@ -751,6 +976,10 @@ impl WasmerModule {
                 KEY_VALUE_STORE_NEW_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), key_value_store_new),
                 KEY_VALUE_STORE_OPEN_ENTRY_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), key_value_store_open_entry),
                 KEY_VALUE_STORE_REMOVE_ENTRY_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), key_value_store_remove_entry),
+                INDEX_STORE_NEW_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), index_store_new),
+                INDEX_STORE_INSERT_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), index_store_insert),
+                INDEX_STORE_REMOVE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), index_store_remove),
+                INDEX_STORE_SCAN_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), index_store_scan),
                 KEY_VALUE_ENTRY_READ_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), key_value_entry_read),
                 KEY_VALUE_ENTRY_WRITE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), key_value_entry_write),
                 KEY_VALUE_ENTRY_REMOVE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), key_value_entry_remove),
@@ -760,6 +989,7 @@ impl WasmerModule {
                 FIELD_ENTRY_CLOSE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), field_entry_close),
                 ACTOR_OPEN_FIELD_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), actor_open_field),
                 ACTOR_GET_OBJECT_ID_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), actor_get_node_id),
+                ACTOR_IS_FEATURE_ENABLED_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), actor_is_feature_enabled),
                 ACTOR_GET_PACKAGE_ADDRESS_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), actor_get_package_address),
                 ACTOR_GET_BLUEPRINT_NAME_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), actor_get_blueprint_name),
                 ACTOR_EMIT_EVENT_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), actor_emit_event),
@@ -772,10 +1002,17 @@ impl WasmerModule {
                 COSTING_GET_TIP_PERCENTAGE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), costing_get_tip_percentage),
                 COSTING_GET_FEE_BALANCE_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), costing_get_fee_balance),
                 SYS_LOG_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), sys_log),
+                SYS_LOG_RECORD_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), sys_log_record),
                 SYS_BECH32_ENCODE_ADDRESS_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), sys_bech32_encode_address),
                 SYS_PANIC_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), sys_panic),
                 SYS_GET_TRANSACTION_HASH_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), sys_get_transaction_hash),
                 SYS_GENERATE_RUID_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), sys_generate_ruid),
+                SYS_GENERATE_RUID_SEEDED_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), sys_generate_ruid_seeded),
+                CRYPTO_UTILS_ED25519_VERIFY_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), crypto_utils_ed25519_verify),
+                CRYPTO_UTILS_SECP256K1_VERIFY_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), crypto_utils_secp256k1_verify),
+                CRYPTO_UTILS_BLS12381_V1_VERIFY_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), crypto_utils_bls12381_v1_verify),
+                CRYPTO_UTILS_KECCAK256_HASH_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), crypto_utils_keccak256_hash),
+                CRYPTO_UTILS_BLAKE2B_256_HASH_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), crypto_utils_blake2b_256_hash),
                 BUFFER_CONSUME_FUNCTION_NAME => Function::new_native_with_env(self.module.store(), env.clone(), buffer_consume),
                 #[cfg(feature = "radix_engine_tests")]
                 "test_host_read_memory" => Function::new_native_with_env(self.module.store(), env.clone(), host_read_memory),
@@ -843,17 +1080,49 @@ impl WasmInstance for WasmerInstance {
             Err(InvokeError::SelfError(WasmRuntimeError::InvalidWasmPointer))
         }
     }
+
+    fn memory_size(&mut self) -> Result<usize, InvokeError<WasmRuntimeError>> {
+        Ok(get_memory_size(&self.instance)?)
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct WasmerEngineOptions {
     max_cache_size: usize,
+    #[cfg_attr(
+        any(feature = "radix_engine_fuzzing", not(feature = "moka")),
+        allow(dead_code)
+    )]
+    use_global_cache: bool,
+}
+
+#[cfg(all(not(feature = "radix_engine_fuzzing"), feature = "moka"))]
+fn new_modules_cache(max_cache_size: usize) -> moka::sync::Cache<CodeHash, Arc<WasmerModule>> {
+    moka::sync::Cache::builder()
+        .weigher(
+            |_metered_code_key: &CodeHash, _value: &Arc<WasmerModule>| -> u32 {
+                // No sophisticated weighing mechanism, just keep a fixed size cache
+                1u32
+            },
+        )
+        .max_capacity(max_cache_size as u64)
+        .build()
+}
+
+#[cfg(all(not(feature = "radix_engine_fuzzing"), feature = "moka"))]
+lazy_static! {
+    /// The modules compiled by all the engines using the global cache, in this process.
+    ///
+    /// A `moka` cache is thread-safe, and its clones share the same entries.
+    static ref GLOBAL_MODULES_CACHE: moka::sync::Cache<CodeHash, Arc<WasmerModule>> =
+        new_modules_cache(WASM_ENGINE_CACHE_SIZE);
 }
 
 impl Default for WasmerEngine {
     fn default() -> Self {
         Self::new(WasmerEngineOptions {
             max_cache_size: WASM_ENGINE_CACHE_SIZE,
+            use_global_cache: false,
         })
     }
 }
@@ -862,20 +1131,17 @@ impl WasmerEngine {
     pub fn new(options: WasmerEngineOptions) -> Self {
         let compiler = Singlepass::new();
 
+        // A `RefCell` cache can't be shared across threads, so the global cache requires `moka`
         #[cfg(all(not(feature = "radix_engine_fuzzing"), not(feature = "moka")))]
         let modules_cache = RefCell::new(lru::LruCache::new(
             NonZeroUsize::new(options.max_cache_size).unwrap(),
         ));
         #[cfg(all(not(feature = "radix_engine_fuzzing"), feature = "moka"))]
-        let modules_cache = moka::sync::Cache::builder()
-            .weigher(
-                |_metered_code_key: &CodeHash, _value: &Arc<WasmerModule>| -> u32 {
-                    // No sophisticated weighing mechanism, just keep a fixed size cache
-                    1u32
-                },
-            )
-            .max_capacity(options.max_cache_size as u64)
-            .build();
+        let modules_cache = if options.use_global_cache {
+            GLOBAL_MODULES_CACHE.clone()
+        } else {
+            new_modules_cache(options.max_cache_size)
+        };
         #[cfg(feature = "radix_engine_fuzzing")]
         let modules_cache = options.max_cache_size;
 
@@ -884,6 +1150,19 @@ impl WasmerEngine {
             modules_cache,
         }
     }
+
+    /// Creates an engine which shares its compiled modules with all the other engines created
+    /// with this method in the process, so that the same code is only compiled once - e.g. across
+    /// the many test runners of a test suite.
+    ///
+    /// As modules are cached by code hash, the engines must only be used with code instrumented
+    /// with the same configuration. Without the `moka` feature, this is the same as `default()`.
+    pub fn with_global_cache() -> Self {
+        Self::new(WasmerEngineOptions {
+            max_cache_size: WASM_ENGINE_CACHE_SIZE,
+            use_global_cache: true,
+        })
+    }
 }
 
 impl WasmEngine for WasmerEngine {
@@ -922,4 +1201,14 @@ impl WasmEngine for WasmerEngine {
 
         new_module.instantiate()
     }
+
+    #[allow(unused_variables)]
+    fn is_cached(&self, code_hash: CodeHash) -> bool {
+        #[cfg(all(not(feature = "radix_engine_fuzzing"), not(feature = "moka")))]
+        return self.modules_cache.borrow().contains(&code_hash);
+        #[cfg(all(not(feature = "radix_engine_fuzzing"), feature = "moka"))]
+        return self.modules_cache.contains_key(&code_hash);
+        #[cfg(feature = "radix_engine_fuzzing")]
+        return false;
+    }
 }
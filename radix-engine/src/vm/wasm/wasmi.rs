@@ -14,7 +14,9 @@ use crate::types::*;
 use crate::vm::wasm::constants::*;
 use crate::vm::wasm::errors::*;
 use crate::vm::wasm::traits::*;
-use crate::vm::wasm::WasmEngine;
+use crate::vm::wasm::{fuel_metered_code_hash, WasmEngine};
+#[cfg(all(not(feature = "radix_engine_fuzzing"), feature = "moka"))]
+use lazy_static::lazy_static;
 
 type FakeHostState = FakeWasmiInstanceEnv;
 type HostState = WasmiInstanceEnv;
@@ -35,12 +37,17 @@ pub struct WasmiModule {
     template_instance: Instance,
     #[allow(dead_code)]
     code_size_bytes: usize,
+    execution_units_per_fuel: Option<u32>,
 }
 
 pub struct WasmiInstance {
     store: Store<HostState>,
     instance: Instance,
     memory: Memory,
+    /// The execution units charged per unit of fuel, if metered by fuel rather than instrumentation
+    execution_units_per_fuel: Option<u32>,
+    /// The execution units an invocation may consume, if metered by fuel
+    max_execution_units: u64,
 }
 
 /// This is to construct a stub `Store<FakeWasmiInstanceEnv>`, which is a part of
@@ -88,6 +95,7 @@ macro_rules! grab_runtime {
             Some(Extern::Memory(memory)) => memory,
             _ => panic!("Failed to find memory export"),
         };
+        runtime.check_memory_size(memory.data(&$caller).len())?;
         (memory, runtime)
     }};
 }
@@ -231,6 +239,36 @@ fn new_object(
         .map(|buffer| buffer.0)
 }
 
+fn new_object_with_features(
+    mut caller: Caller<'_, HostState>,
+    blueprint_name_ptr: u32,
+    blueprint_name_len: u32,
+    features_ptr: u32,
+    features_len: u32,
+    object_states_ptr: u32,
+    object_states_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    runtime
+        .object_new_with_features(
+            read_memory(
+                caller.as_context_mut(),
+                memory,
+                blueprint_name_ptr,
+                blueprint_name_len,
+            )?,
+            read_memory(caller.as_context_mut(), memory, features_ptr, features_len)?,
+            read_memory(
+                caller.as_context_mut(),
+                memory,
+                object_states_ptr,
+                object_states_len,
+            )?,
+        )
+        .map(|buffer| buffer.0)
+}
+
 fn new_key_value_store(
     mut caller: Caller<'_, HostState>,
     schema_id_ptr: u32,
@@ -502,6 +540,68 @@ fn key_value_store_remove(
         .map(|buffer| buffer.0)
 }
 
+fn index_store_new(
+    mut caller: Caller<'_, HostState>,
+    schema_ptr: u32,
+    schema_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+    let schema = read_memory(caller.as_context_mut(), memory, schema_ptr, schema_len)?;
+
+    runtime.index_store_new(schema).map(|buffer| buffer.0)
+}
+
+fn index_store_insert(
+    mut caller: Caller<'_, HostState>,
+    node_id_ptr: u32,
+    node_id_len: u32,
+    sort_prefix: u32,
+    key_ptr: u32,
+    key_len: u32,
+    value_ptr: u32,
+    value_len: u32,
+) -> Result<(), InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+    let node_id = read_memory(caller.as_context_mut(), memory, node_id_ptr, node_id_len)?;
+    let key = read_memory(caller.as_context_mut(), memory, key_ptr, key_len)?;
+    let value = read_memory(caller.as_context_mut(), memory, value_ptr, value_len)?;
+
+    runtime.index_store_insert(node_id, sort_prefix, key, value)
+}
+
+fn index_store_remove(
+    mut caller: Caller<'_, HostState>,
+    node_id_ptr: u32,
+    node_id_len: u32,
+    sort_prefix: u32,
+    key_ptr: u32,
+    key_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+    let node_id = read_memory(caller.as_context_mut(), memory, node_id_ptr, node_id_len)?;
+    let key = read_memory(caller.as_context_mut(), memory, key_ptr, key_len)?;
+
+    runtime
+        .index_store_remove(node_id, sort_prefix, key)
+        .map(|buffer| buffer.0)
+}
+
+fn index_store_scan(
+    mut caller: Caller<'_, HostState>,
+    node_id_ptr: u32,
+    node_id_len: u32,
+    from_sort_prefix: u32,
+    to_sort_prefix: u32,
+    count: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+    let node_id = read_memory(caller.as_context_mut(), memory, node_id_ptr, node_id_len)?;
+
+    runtime
+        .index_store_scan(node_id, from_sort_prefix, to_sort_prefix, count)
+        .map(|buffer| buffer.0)
+}
+
 fn lock_field(
     caller: Caller<'_, HostState>,
     object_handle: u32,
@@ -552,6 +652,19 @@ fn actor_get_node_id(
     runtime.actor_get_node_id(handle).map(|buffer| buffer.0)
 }
 
+fn actor_is_feature_enabled(
+    mut caller: Caller<'_, HostState>,
+    object_handle: u32,
+    feature_ptr: u32,
+    feature_len: u32,
+) -> Result<u32, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let feature = read_memory(caller.as_context_mut(), memory, feature_ptr, feature_len)?;
+
+    runtime.actor_is_feature_enabled(object_handle, feature)
+}
+
 fn get_package_address(
     caller: Caller<'_, HostState>,
 ) -> Result<u64, InvokeError<WasmRuntimeError>> {
@@ -620,6 +733,20 @@ fn generate_ruid(caller: Caller<'_, HostState>) -> Result<u64, InvokeError<WasmR
     runtime.sys_generate_ruid().map(|buffer| buffer.0)
 }
 
+fn generate_ruid_seeded(
+    mut caller: Caller<'_, HostState>,
+    domain_ptr: u32,
+    domain_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let domain = read_memory(caller.as_context_mut(), memory, domain_ptr, domain_len)?;
+
+    runtime
+        .sys_generate_ruid_seeded(domain)
+        .map(|buffer| buffer.0)
+}
+
 fn emit_log(
     mut caller: Caller<'_, HostState>,
     level_ptr: u32,
@@ -635,6 +762,27 @@ fn emit_log(
     runtime.sys_log(level, message)
 }
 
+fn emit_log_record(
+    mut caller: Caller<'_, HostState>,
+    level_ptr: u32,
+    level_len: u32,
+    topic_ptr: u32,
+    topic_len: u32,
+    message_ptr: u32,
+    message_len: u32,
+    payload_ptr: u32,
+    payload_len: u32,
+) -> Result<(), InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let level = read_memory(caller.as_context_mut(), memory, level_ptr, level_len)?;
+    let topic = read_memory(caller.as_context_mut(), memory, topic_ptr, topic_len)?;
+    let message = read_memory(caller.as_context_mut(), memory, message_ptr, message_len)?;
+    let payload = read_memory(caller.as_context_mut(), memory, payload_ptr, payload_len)?;
+
+    runtime.sys_log_record(level, topic, message, payload)
+}
+
 fn bech32_encode_address(
     mut caller: Caller<'_, HostState>,
     address_ptr: u32,
@@ -661,6 +809,118 @@ fn panic(
     runtime.sys_panic(message)
 }
 
+fn ed25519_verify(
+    mut caller: Caller<'_, HostState>,
+    message_ptr: u32,
+    message_len: u32,
+    public_key_ptr: u32,
+    public_key_len: u32,
+    signature_ptr: u32,
+    signature_len: u32,
+) -> Result<u32, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let message = read_memory(caller.as_context_mut(), memory, message_ptr, message_len)?;
+    let public_key = read_memory(
+        caller.as_context_mut(),
+        memory,
+        public_key_ptr,
+        public_key_len,
+    )?;
+    let signature = read_memory(
+        caller.as_context_mut(),
+        memory,
+        signature_ptr,
+        signature_len,
+    )?;
+
+    runtime.crypto_utils_ed25519_verify(message, public_key, signature)
+}
+
+fn secp256k1_verify(
+    mut caller: Caller<'_, HostState>,
+    message_ptr: u32,
+    message_len: u32,
+    public_key_ptr: u32,
+    public_key_len: u32,
+    signature_ptr: u32,
+    signature_len: u32,
+) -> Result<u32, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let message = read_memory(caller.as_context_mut(), memory, message_ptr, message_len)?;
+    let public_key = read_memory(
+        caller.as_context_mut(),
+        memory,
+        public_key_ptr,
+        public_key_len,
+    )?;
+    let signature = read_memory(
+        caller.as_context_mut(),
+        memory,
+        signature_ptr,
+        signature_len,
+    )?;
+
+    runtime.crypto_utils_secp256k1_verify(message, public_key, signature)
+}
+
+fn bls12381_v1_verify(
+    mut caller: Caller<'_, HostState>,
+    message_ptr: u32,
+    message_len: u32,
+    public_key_ptr: u32,
+    public_key_len: u32,
+    signature_ptr: u32,
+    signature_len: u32,
+) -> Result<u32, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let message = read_memory(caller.as_context_mut(), memory, message_ptr, message_len)?;
+    let public_key = read_memory(
+        caller.as_context_mut(),
+        memory,
+        public_key_ptr,
+        public_key_len,
+    )?;
+    let signature = read_memory(
+        caller.as_context_mut(),
+        memory,
+        signature_ptr,
+        signature_len,
+    )?;
+
+    runtime.crypto_utils_bls12381_v1_verify(message, public_key, signature)
+}
+
+fn keccak256_hash(
+    mut caller: Caller<'_, HostState>,
+    data_ptr: u32,
+    data_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let data = read_memory(caller.as_context_mut(), memory, data_ptr, data_len)?;
+
+    runtime
+        .crypto_utils_keccak256_hash(data)
+        .map(|buffer| buffer.0)
+}
+
+fn blake2b_256_hash(
+    mut caller: Caller<'_, HostState>,
+    data_ptr: u32,
+    data_len: u32,
+) -> Result<u64, InvokeError<WasmRuntimeError>> {
+    let (memory, runtime) = grab_runtime!(caller);
+
+    let data = read_memory(caller.as_context_mut(), memory, data_ptr, data_len)?;
+
+    runtime
+        .crypto_utils_blake2b_256_hash(data)
+        .map(|buffer| buffer.0)
+}
+
 #[cfg(feature = "radix_engine_tests")]
 fn test_host_read_memory(
     mut caller: Caller<'_, HostState>,
@@ -723,7 +983,24 @@ pub enum WasmiInstantiationError {
 
 impl WasmiModule {
     pub fn new(code: &[u8]) -> Result<Self, WasmiInstantiationError> {
-        let engine = Engine::default();
+        Self::new_with_engine(Engine::default(), code, None)
+    }
+
+    /// Creates a module whose instances are charged by the fuel they consume.
+    pub fn new_fuel_metered(
+        code: &[u8],
+        execution_units_per_fuel: u32,
+    ) -> Result<Self, WasmiInstantiationError> {
+        let mut config = Config::default();
+        config.consume_fuel(true);
+        Self::new_with_engine(Engine::new(&config), code, Some(execution_units_per_fuel))
+    }
+
+    fn new_with_engine(
+        engine: Engine,
+        code: &[u8],
+        execution_units_per_fuel: Option<u32>,
+    ) -> Result<Self, WasmiInstantiationError> {
         let mut store = Store::new(&engine, WasmiInstanceEnv::new());
 
         let module =
@@ -738,6 +1015,7 @@ impl WasmiModule {
             template_store: unsafe { transmute(store) },
             template_instance: instance,
             code_size_bytes: code.len(),
+            execution_units_per_fuel,
         })
     }
 
@@ -872,6 +1150,29 @@ impl WasmiModule {
             },
         );
 
+        let host_new_component_with_features = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             blueprint_name_ptr: u32,
+             blueprint_name_len: u32,
+             features_ptr: u32,
+             features_len: u32,
+             object_states_ptr: u32,
+             object_states_len: u32|
+             -> Result<u64, Trap> {
+                new_object_with_features(
+                    caller,
+                    blueprint_name_ptr,
+                    blueprint_name_len,
+                    features_ptr,
+                    features_len,
+                    object_states_ptr,
+                    object_states_len,
+                )
+                .map_err(|e| e.into())
+            },
+        );
+
         let host_new_key_value_store = Func::wrap(
             store.as_context_mut(),
             |caller: Caller<'_, HostState>,
@@ -1092,6 +1393,83 @@ impl WasmiModule {
             },
         );
 
+        let host_index_store_new = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             schema_ptr: u32,
+             schema_len: u32|
+             -> Result<u64, Trap> {
+                index_store_new(caller, schema_ptr, schema_len).map_err(|e| e.into())
+            },
+        );
+
+        let host_index_store_insert = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             node_id_ptr: u32,
+             node_id_len: u32,
+             sort_prefix: u32,
+             key_ptr: u32,
+             key_len: u32,
+             value_ptr: u32,
+             value_len: u32|
+             -> Result<(), Trap> {
+                index_store_insert(
+                    caller,
+                    node_id_ptr,
+                    node_id_len,
+                    sort_prefix,
+                    key_ptr,
+                    key_len,
+                    value_ptr,
+                    value_len,
+                )
+                .map_err(|e| e.into())
+            },
+        );
+
+        let host_index_store_remove = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             node_id_ptr: u32,
+             node_id_len: u32,
+             sort_prefix: u32,
+             key_ptr: u32,
+             key_len: u32|
+             -> Result<u64, Trap> {
+                index_store_remove(
+                    caller,
+                    node_id_ptr,
+                    node_id_len,
+                    sort_prefix,
+                    key_ptr,
+                    key_len,
+                )
+                .map_err(|e| e.into())
+            },
+        );
+
+        let host_index_store_scan = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             node_id_ptr: u32,
+             node_id_len: u32,
+             from_sort_prefix: u32,
+             to_sort_prefix: u32,
+             count: u32|
+             -> Result<u64, Trap> {
+                index_store_scan(
+                    caller,
+                    node_id_ptr,
+                    node_id_len,
+                    from_sort_prefix,
+                    to_sort_prefix,
+                    count,
+                )
+                .map_err(|e| e.into())
+            },
+        );
+
         let host_lock_field = Func::wrap(
             store.as_context_mut(),
             |caller: Caller<'_, HostState>,
@@ -1135,6 +1513,18 @@ impl WasmiModule {
             },
         );
 
+        let host_actor_is_feature_enabled = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             object_handle: u32,
+             feature_ptr: u32,
+             feature_len: u32|
+             -> Result<u32, Trap> {
+                actor_is_feature_enabled(caller, object_handle, feature_ptr, feature_len)
+                    .map_err(|e| e.into())
+            },
+        );
+
         let host_get_package_address = Func::wrap(
             store.as_context_mut(),
             |caller: Caller<'_, HostState>| -> Result<u64, Trap> {
@@ -1190,6 +1580,33 @@ impl WasmiModule {
             },
         );
 
+        let host_emit_log_record = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             level_ptr: u32,
+             level_len: u32,
+             topic_ptr: u32,
+             topic_len: u32,
+             message_ptr: u32,
+             message_len: u32,
+             payload_ptr: u32,
+             payload_len: u32|
+             -> Result<(), Trap> {
+                emit_log_record(
+                    caller,
+                    level_ptr,
+                    level_len,
+                    topic_ptr,
+                    topic_len,
+                    message_ptr,
+                    message_len,
+                    payload_ptr,
+                    payload_len,
+                )
+                .map_err(|e| e.into())
+            },
+        );
+
         let host_panic = Func::wrap(
             store.as_context_mut(),
             |caller: Caller<'_, HostState>,
@@ -1224,6 +1641,99 @@ impl WasmiModule {
             },
         );
 
+        let host_generate_ruid_seeded = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             domain_ptr: u32,
+             domain_len: u32|
+             -> Result<u64, Trap> {
+                generate_ruid_seeded(caller, domain_ptr, domain_len).map_err(|e| e.into())
+            },
+        );
+
+        let host_ed25519_verify = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             message_ptr: u32,
+             message_len: u32,
+             public_key_ptr: u32,
+             public_key_len: u32,
+             signature_ptr: u32,
+             signature_len: u32|
+             -> Result<u32, Trap> {
+                ed25519_verify(
+                    caller,
+                    message_ptr,
+                    message_len,
+                    public_key_ptr,
+                    public_key_len,
+                    signature_ptr,
+                    signature_len,
+                )
+                .map_err(|e| e.into())
+            },
+        );
+
+        let host_secp256k1_verify = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             message_ptr: u32,
+             message_len: u32,
+             public_key_ptr: u32,
+             public_key_len: u32,
+             signature_ptr: u32,
+             signature_len: u32|
+             -> Result<u32, Trap> {
+                secp256k1_verify(
+                    caller,
+                    message_ptr,
+                    message_len,
+                    public_key_ptr,
+                    public_key_len,
+                    signature_ptr,
+                    signature_len,
+                )
+                .map_err(|e| e.into())
+            },
+        );
+
+        let host_bls12381_v1_verify = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>,
+             message_ptr: u32,
+             message_len: u32,
+             public_key_ptr: u32,
+             public_key_len: u32,
+             signature_ptr: u32,
+             signature_len: u32|
+             -> Result<u32, Trap> {
+                bls12381_v1_verify(
+                    caller,
+                    message_ptr,
+                    message_len,
+                    public_key_ptr,
+                    public_key_len,
+                    signature_ptr,
+                    signature_len,
+                )
+                .map_err(|e| e.into())
+            },
+        );
+
+        let host_keccak256_hash = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>, data_ptr: u32, data_len: u32| -> Result<u64, Trap> {
+                keccak256_hash(caller, data_ptr, data_len).map_err(|e| e.into())
+            },
+        );
+
+        let host_blake2b_256_hash = Func::wrap(
+            store.as_context_mut(),
+            |caller: Caller<'_, HostState>, data_ptr: u32, data_len: u32| -> Result<u64, Trap> {
+                blake2b_256_hash(caller, data_ptr, data_len).map_err(|e| e.into())
+            },
+        );
+
         let mut linker = <Linker<HostState>>::new();
 
         linker_define!(linker, BUFFER_CONSUME_FUNCTION_NAME, host_consume_buffer);
@@ -1240,6 +1750,11 @@ impl WasmiModule {
         );
         linker_define!(linker, BLUEPRINT_CALL_FUNCTION_NAME, host_blueprint_call);
         linker_define!(linker, OBJECT_NEW_FUNCTION_NAME, host_new_component);
+        linker_define!(
+            linker,
+            OBJECT_NEW_WITH_FEATURES_FUNCTION_NAME,
+            host_new_component_with_features
+        );
 
         linker_define!(
             linker,
@@ -1336,6 +1851,23 @@ impl WasmiModule {
             host_key_value_store_remove
         );
 
+        linker_define!(linker, INDEX_STORE_NEW_FUNCTION_NAME, host_index_store_new);
+        linker_define!(
+            linker,
+            INDEX_STORE_INSERT_FUNCTION_NAME,
+            host_index_store_insert
+        );
+        linker_define!(
+            linker,
+            INDEX_STORE_REMOVE_FUNCTION_NAME,
+            host_index_store_remove
+        );
+        linker_define!(
+            linker,
+            INDEX_STORE_SCAN_FUNCTION_NAME,
+            host_index_store_scan
+        );
+
         linker_define!(linker, FIELD_ENTRY_READ_FUNCTION_NAME, host_field_lock_read);
         linker_define!(
             linker,
@@ -1352,6 +1884,11 @@ impl WasmiModule {
             ACTOR_GET_OBJECT_ID_FUNCTION_NAME,
             host_actor_get_node_id
         );
+        linker_define!(
+            linker,
+            ACTOR_IS_FEATURE_ENABLED_FUNCTION_NAME,
+            host_actor_is_feature_enabled
+        );
         linker_define!(
             linker,
             ACTOR_GET_PACKAGE_ADDRESS_FUNCTION_NAME,
@@ -1369,6 +1906,7 @@ impl WasmiModule {
         );
         linker_define!(linker, ACTOR_EMIT_EVENT_FUNCTION_NAME, host_emit_event);
         linker_define!(linker, SYS_LOG_FUNCTION_NAME, host_emit_log);
+        linker_define!(linker, SYS_LOG_RECORD_FUNCTION_NAME, host_emit_log_record);
         linker_define!(linker, SYS_PANIC_FUNCTION_NAME, host_panic);
         linker_define!(
             linker,
@@ -1382,6 +1920,37 @@ impl WasmiModule {
         );
 
         linker_define!(linker, SYS_GENERATE_RUID_FUNCTION_NAME, host_generate_ruid);
+        linker_define!(
+            linker,
+            SYS_GENERATE_RUID_SEEDED_FUNCTION_NAME,
+            host_generate_ruid_seeded
+        );
+
+        linker_define!(
+            linker,
+            CRYPTO_UTILS_ED25519_VERIFY_FUNCTION_NAME,
+            host_ed25519_verify
+        );
+        linker_define!(
+            linker,
+            CRYPTO_UTILS_SECP256K1_VERIFY_FUNCTION_NAME,
+            host_secp256k1_verify
+        );
+        linker_define!(
+            linker,
+            CRYPTO_UTILS_BLS12381_V1_VERIFY_FUNCTION_NAME,
+            host_bls12381_v1_verify
+        );
+        linker_define!(
+            linker,
+            CRYPTO_UTILS_KECCAK256_HASH_FUNCTION_NAME,
+            host_keccak256_hash
+        );
+        linker_define!(
+            linker,
+            CRYPTO_UTILS_BLAKE2B_256_HASH_FUNCTION_NAME,
+            host_blake2b_256_hash
+        );
 
         #[cfg(feature = "radix_engine_tests")]
         {
@@ -1433,6 +2002,8 @@ impl WasmiModule {
             instance,
             store: unsafe { transmute(store) },
             memory,
+            execution_units_per_fuel: self.execution_units_per_fuel,
+            max_execution_units: u64::MAX,
         }
     }
 }
@@ -1537,12 +2108,47 @@ impl WasmInstance for WasmiInstance {
             .collect();
         let mut ret = [Value::I64(0)];
 
-        let _result = func
+        let fuel_consumed_before = match self.execution_units_per_fuel {
+            Some(execution_units_per_fuel) => {
+                // Bounds the execution, as otherwise it's only charged once it's finished. This
+                // is one unit more than can be paid for, so that running out of fuel is reported
+                // as exceeding the execution cost unit limit once charged.
+                let max_fuel = (self.max_execution_units / u64::from(execution_units_per_fuel))
+                    .saturating_add(1);
+                self.store
+                    .add_fuel(max_fuel)
+                    .expect("Fuel metering is enabled");
+                self.store.fuel_consumed()
+            }
+            None => None,
+        };
+
+        let result = func
             .call(self.store.as_context_mut(), &input, &mut ret)
             .map_err(|e| {
                 let err: InvokeError<WasmRuntimeError> = e.into();
                 err
-            })?;
+            });
+
+        // Charged even if the call trapped, in which case running out of fuel is the likely cause
+        if let (Some(execution_units_per_fuel), Some(fuel_consumed_before)) =
+            (self.execution_units_per_fuel, fuel_consumed_before)
+        {
+            let fuel_consumed = self
+                .store
+                .fuel_consumed()
+                .unwrap_or(fuel_consumed_before)
+                .saturating_sub(fuel_consumed_before);
+            let mut execution_units =
+                fuel_consumed.saturating_mul(u64::from(execution_units_per_fuel));
+            while execution_units > 0 {
+                let n = execution_units.min(u64::from(u32::MAX)) as u32;
+                runtime.consume_wasm_execution_units(n)?;
+                execution_units -= u64::from(n);
+            }
+        }
+
+        let _result = result?;
 
         match i64::try_from(ret[0]) {
             Ok(ret) => read_slice(
@@ -1553,11 +2159,41 @@ impl WasmInstance for WasmiInstance {
             _ => Err(InvokeError::SelfError(WasmRuntimeError::InvalidWasmPointer)),
         }
     }
+
+    fn memory_size(&mut self) -> Result<usize, InvokeError<WasmRuntimeError>> {
+        let store_ctx = self.store.as_context();
+        Ok(self.memory.data(&store_ctx).len())
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct WasmiEngineOptions {
     max_cache_size: usize,
+    #[cfg_attr(
+        any(feature = "radix_engine_fuzzing", not(feature = "moka")),
+        allow(dead_code)
+    )]
+    use_global_cache: bool,
+}
+
+#[cfg(all(not(feature = "radix_engine_fuzzing"), feature = "moka"))]
+fn new_modules_cache(max_cache_size: usize) -> moka::sync::Cache<CodeHash, Arc<WasmiModule>> {
+    moka::sync::Cache::builder()
+        .weigher(|_key: &CodeHash, _value: &Arc<WasmiModule>| -> u32 {
+            // No sophisticated weighing mechanism, just keep a fixed size cache
+            1u32
+        })
+        .max_capacity(max_cache_size as u64)
+        .build()
+}
+
+#[cfg(all(not(feature = "radix_engine_fuzzing"), feature = "moka"))]
+lazy_static! {
+    /// The modules compiled by all the engines using the global cache, in this process.
+    ///
+    /// A `moka` cache is thread-safe, and its clones share the same entries.
+    static ref GLOBAL_MODULES_CACHE: moka::sync::Cache<CodeHash, Arc<WasmiModule>> =
+        new_modules_cache(WASM_ENGINE_CACHE_SIZE);
 }
 
 pub struct WasmiEngine {
@@ -1575,36 +2211,36 @@ impl Default for WasmiEngine {
     fn default() -> Self {
         Self::new(WasmiEngineOptions {
             max_cache_size: WASM_ENGINE_CACHE_SIZE,
+            use_global_cache: false,
         })
     }
 }
 
 impl WasmiEngine {
     pub fn new(options: WasmiEngineOptions) -> Self {
+        // A `RefCell` cache can't be shared across threads, so the global cache requires `moka`
         #[cfg(all(not(feature = "radix_engine_fuzzing"), not(feature = "moka")))]
         let modules_cache = RefCell::new(lru::LruCache::new(
             NonZeroUsize::new(options.max_cache_size).unwrap(),
         ));
         #[cfg(all(not(feature = "radix_engine_fuzzing"), feature = "moka"))]
-        let modules_cache = moka::sync::Cache::builder()
-            .weigher(|_key: &CodeHash, _value: &Arc<WasmiModule>| -> u32 {
-                // No sophisticated weighing mechanism, just keep a fixed size cache
-                1u32
-            })
-            .max_capacity(options.max_cache_size as u64)
-            .build();
+        let modules_cache = if options.use_global_cache {
+            GLOBAL_MODULES_CACHE.clone()
+        } else {
+            new_modules_cache(options.max_cache_size)
+        };
         #[cfg(feature = "radix_engine_fuzzing")]
         let modules_cache = options.max_cache_size;
 
         Self { modules_cache }
     }
-}
-
-impl WasmEngine for WasmiEngine {
-    type WasmInstance = WasmiInstance;
 
     #[allow(unused_variables)]
-    fn instantiate(&self, code_hash: CodeHash, instrumented_code: &[u8]) -> WasmiInstance {
+    fn instantiate_cached(
+        &self,
+        code_hash: CodeHash,
+        new_module: impl FnOnce() -> WasmiModule,
+    ) -> WasmiInstance {
         #[cfg(not(feature = "radix_engine_fuzzing"))]
         {
             #[cfg(not(feature = "moka"))]
@@ -1619,7 +2255,7 @@ impl WasmEngine for WasmiEngine {
             }
         }
 
-        let module = WasmiModule::new(instrumented_code).expect("Failed to instantiate module");
+        let module = new_module();
         let instance = module.instantiate();
 
         #[cfg(not(feature = "radix_engine_fuzzing"))]
@@ -1634,6 +2270,59 @@ impl WasmEngine for WasmiEngine {
 
         instance
     }
+
+    /// Creates an engine which shares its compiled modules with all the other engines created
+    /// with this method in the process, so that the same code is only compiled once - e.g. across
+    /// the many test runners of a test suite.
+    ///
+    /// As modules are cached by code hash, the engines must only be used with code instrumented
+    /// with the same configuration. Without the `moka` feature, this is the same as `default()`.
+    pub fn with_global_cache() -> Self {
+        Self::new(WasmiEngineOptions {
+            max_cache_size: WASM_ENGINE_CACHE_SIZE,
+            use_global_cache: true,
+        })
+    }
+}
+
+impl WasmEngine for WasmiEngine {
+    type WasmInstance = WasmiInstance;
+
+    fn instantiate(&self, code_hash: CodeHash, instrumented_code: &[u8]) -> WasmiInstance {
+        self.instantiate_cached(code_hash, || {
+            WasmiModule::new(instrumented_code).expect("Failed to instantiate module")
+        })
+    }
+
+    fn supports_fuel_metering(&self) -> bool {
+        true
+    }
+
+    fn instantiate_fuel_metered(
+        &self,
+        code_hash: CodeHash,
+        prepare_code: &dyn Fn() -> Vec<u8>,
+        execution_units_per_fuel: u32,
+        max_execution_units: u64,
+    ) -> WasmiInstance {
+        let cache_key = fuel_metered_code_hash(code_hash, execution_units_per_fuel);
+        let mut instance = self.instantiate_cached(cache_key, || {
+            WasmiModule::new_fuel_metered(&prepare_code(), execution_units_per_fuel)
+                .expect("Failed to instantiate module")
+        });
+        instance.max_execution_units = max_execution_units;
+        instance
+    }
+
+    #[allow(unused_variables)]
+    fn is_cached(&self, code_hash: CodeHash) -> bool {
+        #[cfg(all(not(feature = "radix_engine_fuzzing"), not(feature = "moka")))]
+        return self.modules_cache.borrow().contains(&code_hash);
+        #[cfg(all(not(feature = "radix_engine_fuzzing"), feature = "moka"))]
+        return self.modules_cache.contains_key(&code_hash);
+        #[cfg(feature = "radix_engine_fuzzing")]
+        return false;
+    }
 }
 
 // Below tests verify WASM "mutable-global" feature, which allows importing/exporting mutable globals.
@@ -1762,4 +2451,20 @@ mod tests {
         let val = i32::try_from(updated_value).unwrap();
         assert_eq!(val, 11100);
     }
+
+    #[cfg(all(not(feature = "radix_engine_fuzzing"), feature = "moka"))]
+    #[test]
+    fn test_engines_with_global_cache_share_compiled_modules() {
+        let code = wat2wasm(r#"(module (memory $0 1) (export "memory" (memory $0)))"#).unwrap();
+        let code_hash = CodeHash::from_hash(hash(&code));
+
+        WasmiEngine::with_global_cache().instantiate(code_hash, &code);
+
+        assert!(WasmiEngine::with_global_cache()
+            .modules_cache
+            .contains_key(&code_hash));
+        assert!(!WasmiEngine::default()
+            .modules_cache
+            .contains_key(&code_hash));
+    }
 }
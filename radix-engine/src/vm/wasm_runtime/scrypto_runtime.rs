@@ -1,11 +1,15 @@
 use crate::errors::InvokeError;
 use crate::errors::RuntimeError;
+use crate::errors::SystemModuleError;
+use crate::system::system_modules::limits::TransactionLimitsError;
 use crate::types::*;
 use crate::vm::wasm::*;
 use radix_engine_interface::api::actor_api::EventFlags;
 use radix_engine_interface::api::field_api::LockFlags;
 use radix_engine_interface::api::key_value_store_api::KeyValueStoreDataSchema;
-use radix_engine_interface::api::{ActorRefHandle, AttachedModuleId, ClientApi, FieldValue};
+use radix_engine_interface::api::{
+    ActorRefHandle, AttachedModuleId, ClientApi, ClientCryptoUtilsApi, FieldValue, GenericArgs,
+};
 use radix_engine_interface::types::ClientCostingEntry;
 use radix_engine_interface::types::Level;
 use sbor::rust::vec::Vec;
@@ -22,13 +26,20 @@ where
     export_name: String,
     wasm_execution_units_buffer: u32,
     max_number_of_buffers: usize,
+    /// The max size of the linear memory of the call frame, if limited
+    max_memory_size: Option<usize>,
 }
 
 impl<'y, Y> ScryptoRuntime<'y, Y>
 where
     Y: ClientApi<RuntimeError>,
 {
-    pub fn new(api: &'y mut Y, package_address: PackageAddress, export_name: String) -> Self {
+    pub fn new(
+        api: &'y mut Y,
+        package_address: PackageAddress,
+        export_name: String,
+        max_memory_size: Option<usize>,
+    ) -> Self {
         ScryptoRuntime {
             api,
             buffers: index_map_new(),
@@ -37,6 +48,7 @@ where
             export_name,
             wasm_execution_units_buffer: 0,
             max_number_of_buffers: MAX_NUMBER_OF_BUFFERS,
+            max_memory_size,
         }
     }
 
@@ -183,6 +195,30 @@ where
         self.allocate_buffer(component_id.to_vec())
     }
 
+    fn object_new_with_features(
+        &mut self,
+        blueprint_name: Vec<u8>,
+        features: Vec<u8>,
+        object_states: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let blueprint_name =
+            String::from_utf8(blueprint_name).map_err(|_| WasmRuntimeError::InvalidString)?;
+        let features =
+            scrypto_decode::<Vec<String>>(&features).map_err(WasmRuntimeError::InvalidFeatures)?;
+        let object_states = scrypto_decode::<IndexMap<u8, FieldValue>>(&object_states)
+            .map_err(WasmRuntimeError::InvalidObjectStates)?;
+
+        let component_id = self.api.new_object(
+            blueprint_name.as_ref(),
+            features.iter().map(|feature| feature.as_str()).collect(),
+            GenericArgs::default(),
+            object_states,
+            indexmap![],
+        )?;
+
+        self.allocate_buffer(component_id.to_vec())
+    }
+
     fn address_allocate(
         &mut self,
         package_address: Vec<u8>,
@@ -309,6 +345,77 @@ where
         self.allocate_buffer(rtn)
     }
 
+    fn index_store_new(
+        &mut self,
+        schema: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let schema = scrypto_decode::<KeyValueStoreDataSchema>(&schema)
+            .map_err(WasmRuntimeError::InvalidKeyValueStoreSchema)?;
+
+        let index_store_id = self.api.index_store_new(schema)?;
+
+        self.allocate_buffer(index_store_id.to_vec())
+    }
+
+    fn index_store_insert(
+        &mut self,
+        node_id: Vec<u8>,
+        sort_prefix: u32,
+        key: Vec<u8>,
+        value: Vec<u8>,
+    ) -> Result<(), InvokeError<WasmRuntimeError>> {
+        let node_id = NodeId(
+            TryInto::<[u8; NodeId::LENGTH]>::try_into(node_id.as_ref())
+                .map_err(|_| WasmRuntimeError::InvalidNodeId)?,
+        );
+        let sort_prefix = decode_sort_prefix(sort_prefix)?;
+
+        self.api
+            .index_store_insert(&node_id, (sort_prefix, key), value)?;
+        Ok(())
+    }
+
+    fn index_store_remove(
+        &mut self,
+        node_id: Vec<u8>,
+        sort_prefix: u32,
+        key: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let node_id = NodeId(
+            TryInto::<[u8; NodeId::LENGTH]>::try_into(node_id.as_ref())
+                .map_err(|_| WasmRuntimeError::InvalidNodeId)?,
+        );
+        let sort_prefix = decode_sort_prefix(sort_prefix)?;
+
+        let rtn = self.api.index_store_remove(&node_id, &(sort_prefix, key))?;
+        self.allocate_buffer(scrypto_encode(&rtn).expect("Failed to encode removed entry"))
+    }
+
+    fn index_store_scan(
+        &mut self,
+        node_id: Vec<u8>,
+        from_sort_prefix: u32,
+        to_sort_prefix: u32,
+        count: u32,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let node_id = NodeId(
+            TryInto::<[u8; NodeId::LENGTH]>::try_into(node_id.as_ref())
+                .map_err(|_| WasmRuntimeError::InvalidNodeId)?,
+        );
+        let from_sort_prefix = decode_sort_prefix(from_sort_prefix)?;
+        // `u16::MAX + 1` stands for an open end
+        let to_sort_prefix = if to_sort_prefix == u16::MAX as u32 + 1 {
+            None
+        } else {
+            Some(decode_sort_prefix(to_sort_prefix)?)
+        };
+
+        let entries =
+            self.api
+                .index_store_scan(&node_id, from_sort_prefix, to_sort_prefix, count)?;
+        self.allocate_buffer(scrypto_encode(&entries).expect("Failed to encode scanned entries"))
+    }
+
     fn actor_open_field(
         &mut self,
         object_handle: u32,
@@ -358,6 +465,20 @@ where
         self.allocate_buffer(node_id.0.to_vec())
     }
 
+    fn actor_is_feature_enabled(
+        &mut self,
+        object_handle: u32,
+        feature: Vec<u8>,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+        let feature = String::from_utf8(feature).map_err(|_| WasmRuntimeError::InvalidString)?;
+
+        if self.api.actor_is_feature_enabled(object_handle, &feature)? {
+            Ok(1)
+        } else {
+            Ok(0)
+        }
+    }
+
     fn actor_get_package_address(&mut self) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
         let blueprint_id = self.api.actor_get_blueprint_id()?;
 
@@ -476,6 +597,26 @@ where
         Ok(())
     }
 
+    fn sys_log_record(
+        &mut self,
+        level: Vec<u8>,
+        topic: Vec<u8>,
+        message: Vec<u8>,
+        payload: Vec<u8>,
+    ) -> Result<(), InvokeError<WasmRuntimeError>> {
+        self.api.emit_log_record(
+            scrypto_decode::<Level>(&level).map_err(WasmRuntimeError::InvalidLogLevel)?,
+            String::from_utf8(topic).map_err(|_| WasmRuntimeError::InvalidString)?,
+            String::from_utf8(message).map_err(|_| WasmRuntimeError::InvalidString)?,
+            if payload.is_empty() {
+                None
+            } else {
+                Some(payload)
+            },
+        )?;
+        Ok(())
+    }
+
     fn sys_bech32_encode_address(
         &mut self,
         address: Vec<u8>,
@@ -504,6 +645,15 @@ where
         self.allocate_buffer(ruid.to_vec())
     }
 
+    fn sys_generate_ruid_seeded(
+        &mut self,
+        domain: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let ruid = self.api.generate_ruid_seeded(&domain)?;
+
+        self.allocate_buffer(ruid.to_vec())
+    }
+
     fn costing_get_execution_cost_unit_limit(
         &mut self,
     ) -> Result<u32, InvokeError<WasmRuntimeError>> {
@@ -560,4 +710,94 @@ where
 
         self.allocate_buffer(scrypto_encode(&fee_balance).expect("Failed to encode fee_balance"))
     }
+
+    fn crypto_utils_ed25519_verify(
+        &mut self,
+        message: Vec<u8>,
+        public_key: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+        let public_key = Ed25519PublicKey::try_from(public_key.as_slice())
+            .map_err(|_| WasmRuntimeError::InvalidPublicKey)?;
+        let signature = Ed25519Signature::try_from(signature.as_slice())
+            .map_err(|_| WasmRuntimeError::InvalidSignature)?;
+
+        let verified = self.api.ed25519_verify(&message, &public_key, &signature)?;
+        Ok(verified.into())
+    }
+
+    fn crypto_utils_secp256k1_verify(
+        &mut self,
+        message: Vec<u8>,
+        public_key: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+        let public_key = Secp256k1PublicKey::try_from(public_key.as_slice())
+            .map_err(|_| WasmRuntimeError::InvalidPublicKey)?;
+        let signature = Secp256k1Signature::try_from(signature.as_slice())
+            .map_err(|_| WasmRuntimeError::InvalidSignature)?;
+
+        let verified = self
+            .api
+            .secp256k1_verify(&message, &public_key, &signature)?;
+        Ok(verified.into())
+    }
+
+    fn crypto_utils_bls12381_v1_verify(
+        &mut self,
+        message: Vec<u8>,
+        public_key: Vec<u8>,
+        signature: Vec<u8>,
+    ) -> Result<u32, InvokeError<WasmRuntimeError>> {
+        let public_key = Bls12381G1PublicKey::try_from(public_key.as_slice())
+            .map_err(|_| WasmRuntimeError::InvalidPublicKey)?;
+        let signature = Bls12381G2Signature::try_from(signature.as_slice())
+            .map_err(|_| WasmRuntimeError::InvalidSignature)?;
+
+        let verified = self
+            .api
+            .bls12381_v1_verify(&message, &public_key, &signature)?;
+        Ok(verified.into())
+    }
+
+    fn crypto_utils_keccak256_hash(
+        &mut self,
+        data: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let hash = self.api.keccak256_hash(&data)?;
+
+        self.allocate_buffer(hash.to_vec())
+    }
+
+    fn crypto_utils_blake2b_256_hash(
+        &mut self,
+        data: Vec<u8>,
+    ) -> Result<Buffer, InvokeError<WasmRuntimeError>> {
+        let hash = self.api.blake2b_256_hash(&data)?;
+
+        self.allocate_buffer(hash.to_vec())
+    }
+
+    fn check_memory_size(
+        &mut self,
+        memory_size: usize,
+    ) -> Result<(), InvokeError<WasmRuntimeError>> {
+        match self.max_memory_size {
+            Some(max) if memory_size > max => Err(InvokeError::Downstream(
+                RuntimeError::SystemModuleError(SystemModuleError::TransactionLimitsError(
+                    TransactionLimitsError::MaxWasmMemoryExceeded {
+                        actual: memory_size,
+                        max,
+                    },
+                )),
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn decode_sort_prefix(sort_prefix: u32) -> Result<[u8; 2], InvokeError<WasmRuntimeError>> {
+    u16::try_from(sort_prefix)
+        .map(u16::to_be_bytes)
+        .map_err(|_| InvokeError::SelfError(WasmRuntimeError::InvalidSortPrefix(sort_prefix)))
 }
@@ -236,7 +236,7 @@ fn handle_normal_describe(
                 variants
                     .iter()
                     .map(|v| {
-                        let variant_name = v.ident.to_string();
+                        let variant_name = get_schema_name(&v.attrs, v.ident.to_string())?;
                         let FieldsData {
                             unskipped_field_types,
                             unskipped_field_name_strings,
@@ -330,6 +330,16 @@ pub fn is_encoding_skipped(f: &Field) -> Result<bool> {
     Ok(attributes.get_bool_value("skip")? || attributes.get_bool_value("skip_encode")?)
 }
 
+/// The name of a field or variant in the schema, which is its `#[sbor(rename = "...")]` if any.
+///
+/// As fields and variants are encoded by position and discriminator, this allows renaming them
+/// in Rust without changing the schema.
+pub fn get_schema_name(attributes: &[Attribute], rust_name: String) -> Result<String> {
+    Ok(extract_sbor_typed_attributes(attributes)?
+        .get_string_value("rename")?
+        .unwrap_or(rust_name))
+}
+
 pub fn is_transparent(attributes: &[Attribute]) -> Result<bool> {
     let attributes = extract_sbor_typed_attributes(attributes)?;
     Ok(attributes.get_bool_value("transparent")?)
@@ -455,8 +465,10 @@ fn process_fields(
                 let ident = &f.ident;
                 if !is_skipped(f)? {
                     unskipped_field_names.push(quote! { #ident });
-                    unskipped_field_name_strings
-                        .push(ident.as_ref().map(|i| i.to_string()).unwrap_or_default());
+                    unskipped_field_name_strings.push(get_schema_name(
+                        &f.attrs,
+                        ident.as_ref().map(|i| i.to_string()).unwrap_or_default(),
+                    )?);
                     unskipped_field_types.push(f.ty.clone());
                 } else {
                     skipped_field_names.push(quote! { #ident });
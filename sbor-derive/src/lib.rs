@@ -25,6 +25,9 @@ pub fn decode(input: TokenStream) -> TokenStream {
 }
 
 /// Derive code that describes this type.
+///
+/// A field or variant can be given another name in the schema with `#[sbor(rename = "...")]`,
+/// e.g. to keep its schema name when renaming it in Rust.
 #[proc_macro_derive(Describe, attributes(sbor))]
 pub fn describe(input: TokenStream) -> TokenStream {
     sbor_derive_common::describe::handle_describe(proc_macro2::TokenStream::from(input), None)
@@ -0,0 +1,91 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use sbor::rust::borrow::Cow;
+use sbor::rust::vec;
+use sbor::rust::vec::Vec;
+use sbor::*;
+
+#[derive(Debug, PartialEq, Sbor)]
+pub struct TestStructRenamed {
+    #[sbor(rename = "x")]
+    pub renamed_x: u32,
+    pub y: u32,
+}
+
+#[derive(Debug, PartialEq, Sbor)]
+pub struct TestStructOriginal {
+    pub x: u32,
+    pub y: u32,
+}
+
+#[derive(Debug, PartialEq, Sbor)]
+pub enum TestEnumRenamed {
+    #[sbor(rename = "A")]
+    RenamedA {
+        #[sbor(rename = "x")]
+        renamed_x: u32,
+    },
+    B(u32),
+}
+
+#[test]
+fn test_renamed_fields_are_encoded_as_before() {
+    let a = TestStructRenamed { renamed_x: 1, y: 2 };
+    let b = TestStructOriginal { x: 1, y: 2 };
+
+    let mut bytes_a = Vec::with_capacity(512);
+    BasicEncoder::new(&mut bytes_a, 255).encode(&a).unwrap();
+    let mut bytes_b = Vec::with_capacity(512);
+    BasicEncoder::new(&mut bytes_b, 255).encode(&b).unwrap();
+
+    assert_eq!(bytes_a, bytes_b);
+    assert_eq!(
+        BasicDecoder::new(&bytes_b, 255)
+            .decode::<TestStructRenamed>()
+            .unwrap(),
+        a
+    );
+}
+
+#[test]
+fn test_renamed_fields_keep_their_schema_names() {
+    let (type_id, schema) =
+        generate_full_schema_from_single_type::<TestStructRenamed, NoCustomSchema>();
+
+    let metadata = schema.v1().resolve_type_metadata(type_id).unwrap();
+    assert_eq!(metadata.get_name().unwrap(), "TestStructRenamed");
+    assert_eq!(
+        metadata.get_field_names().unwrap(),
+        &[Cow::Borrowed("x"), Cow::Borrowed("y")]
+    );
+    assert!(schema.v1().validate().is_ok());
+}
+
+#[test]
+fn test_renamed_variants_keep_their_schema_names() {
+    let (type_id, schema) =
+        generate_full_schema_from_single_type::<TestEnumRenamed, NoCustomSchema>();
+
+    let metadata = schema.v1().resolve_type_metadata(type_id).unwrap();
+    let variant_a = metadata.get_matching_enum_variant_data(0, 1);
+    assert_eq!(variant_a.variant_name, Some("A"));
+    assert_eq!(variant_a.field_names.unwrap(), &[Cow::Borrowed("x")]);
+    let variant_b = metadata.get_matching_enum_variant_data(1, 1);
+    assert_eq!(variant_b.variant_name, Some("B"));
+    assert!(schema.v1().validate().is_ok());
+
+    let mut bytes = Vec::with_capacity(512);
+    BasicEncoder::new(&mut bytes, 255)
+        .encode(&TestEnumRenamed::RenamedA { renamed_x: 3 })
+        .unwrap();
+    #[rustfmt::skip]
+    assert_eq!(
+        vec![
+            34, // enum type
+            0, // "A"
+            1, // number of fields
+            9, 3, 0, 0, 0, // field value
+        ],
+        bytes
+    );
+}
@@ -94,6 +94,24 @@ pub fn basic_decode_with_depth_limit<T: BasicDecode>(
     BasicDecoder::new(buf, depth_limit).decode_payload(BASIC_SBOR_V1_PAYLOAD_PREFIX)
 }
 
+/// Re-encodes a payload into its canonical byte form.
+pub fn basic_canonicalize(buf: &[u8]) -> Result<Vec<u8>, CanonicalizationError> {
+    canonicalize::<NoCustomValueKind, NoCustomValue>(
+        buf,
+        BASIC_SBOR_V1_PAYLOAD_PREFIX,
+        BASIC_SBOR_V1_MAX_DEPTH,
+    )
+}
+
+/// Returns whether a payload is valid and already in its canonical byte form.
+pub fn basic_is_canonical(buf: &[u8]) -> bool {
+    is_canonical::<NoCustomValueKind, NoCustomValue>(
+        buf,
+        BASIC_SBOR_V1_PAYLOAD_PREFIX,
+        BASIC_SBOR_V1_MAX_DEPTH,
+    )
+}
+
 impl CustomValueKind for NoCustomValueKind {
     fn as_u8(&self) -> u8 {
         panic!("No custom type")
@@ -0,0 +1,172 @@
+use crate::rust::prelude::*;
+use crate::*;
+
+/// Represents an error occurred during canonicalization of a payload.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CanonicalizationError {
+    DecodeError(DecodeError),
+    EncodeError(EncodeError),
+    /// A map has two entries with the same key, so no order of its entries is canonical.
+    DuplicateMapKey,
+}
+
+/// Re-encodes an SBOR payload into its canonical byte form.
+///
+/// The decoder already rejects non-minimal size encodings, so the only freedom left to an encoder
+/// is the order of map entries. In the canonical form, the entries of every map are sorted by the
+/// bytes of their encoded keys. Payloads which only differ in map ordering therefore canonicalize
+/// to the same bytes, which makes the result safe to hash. Payloads with duplicate map keys are
+/// rejected.
+pub fn canonicalize<X, Y>(
+    payload: &[u8],
+    expected_prefix: u8,
+    depth_limit: usize,
+) -> Result<Vec<u8>, CanonicalizationError>
+where
+    X: CustomValueKind,
+    Y: CustomValue<X> + for<'a> Encode<X, VecEncoder<'a, X>> + for<'a> Decode<X, VecDecoder<'a, X>>,
+{
+    let mut value: Value<X, Y> = VecDecoder::<X>::new(payload, depth_limit)
+        .decode_payload(expected_prefix)
+        .map_err(CanonicalizationError::DecodeError)?;
+    canonicalize_value(&mut value, depth_limit)?;
+
+    let mut buf = Vec::with_capacity(payload.len());
+    VecEncoder::<X>::new(&mut buf, depth_limit)
+        .encode_payload(&value, expected_prefix)
+        .map_err(CanonicalizationError::EncodeError)?;
+    Ok(buf)
+}
+
+/// Returns whether the payload is valid SBOR which is already in its canonical byte form.
+pub fn is_canonical<X, Y>(payload: &[u8], expected_prefix: u8, depth_limit: usize) -> bool
+where
+    X: CustomValueKind,
+    Y: CustomValue<X> + for<'a> Encode<X, VecEncoder<'a, X>> + for<'a> Decode<X, VecDecoder<'a, X>>,
+{
+    match canonicalize::<X, Y>(payload, expected_prefix, depth_limit) {
+        Ok(canonical) => canonical == payload,
+        Err(_) => false,
+    }
+}
+
+fn canonicalize_value<X, Y>(
+    value: &mut Value<X, Y>,
+    depth_limit: usize,
+) -> Result<(), CanonicalizationError>
+where
+    X: CustomValueKind,
+    Y: CustomValue<X> + for<'a> Encode<X, VecEncoder<'a, X>>,
+{
+    match value {
+        Value::Enum { fields, .. } | Value::Tuple { fields } => {
+            for field in fields {
+                canonicalize_value(field, depth_limit)?;
+            }
+        }
+        Value::Array { elements, .. } => {
+            for element in elements {
+                canonicalize_value(element, depth_limit)?;
+            }
+        }
+        Value::Map { entries, .. } => {
+            // Children are canonicalized first, as the encoding of a key depends on the order of
+            // any maps nested inside it.
+            let mut keyed_entries = Vec::with_capacity(entries.len());
+            for (mut key, mut entry_value) in entries.drain(..) {
+                canonicalize_value(&mut key, depth_limit)?;
+                canonicalize_value(&mut entry_value, depth_limit)?;
+                let mut key_bytes = Vec::new();
+                VecEncoder::<X>::new(&mut key_bytes, depth_limit)
+                    .encode(&key)
+                    .map_err(CanonicalizationError::EncodeError)?;
+                keyed_entries.push((key_bytes, key, entry_value));
+            }
+            keyed_entries.sort_by(|a, b| a.0.cmp(&b.0));
+            if keyed_entries.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+                return Err(CanonicalizationError::DuplicateMapKey);
+            }
+            entries.extend(
+                keyed_entries
+                    .into_iter()
+                    .map(|(_, key, entry_value)| (key, entry_value)),
+            );
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn map_of(entries: Vec<(BasicValue, BasicValue)>) -> BasicValue {
+        BasicValue::Map {
+            key_value_kind: ValueKind::U8,
+            value_value_kind: ValueKind::String,
+            entries,
+        }
+    }
+
+    fn entry(key: u8, value: &str) -> (BasicValue, BasicValue) {
+        (
+            BasicValue::U8 { value: key },
+            BasicValue::String {
+                value: value.to_string(),
+            },
+        )
+    }
+
+    #[test]
+    fn test_map_entries_are_sorted_by_encoded_key() {
+        let unordered = basic_encode(&map_of(vec![entry(2, "b"), entry(1, "a")])).unwrap();
+        let ordered = basic_encode(&map_of(vec![entry(1, "a"), entry(2, "b")])).unwrap();
+
+        assert_ne!(unordered, ordered);
+        assert!(!basic_is_canonical(&unordered));
+        assert!(basic_is_canonical(&ordered));
+        assert_eq!(basic_canonicalize(&unordered).unwrap(), ordered);
+        assert_eq!(basic_canonicalize(&ordered).unwrap(), ordered);
+    }
+
+    #[test]
+    fn test_nested_maps_are_canonicalized() {
+        let nested = |entries| BasicValue::Tuple {
+            fields: vec![BasicValue::Array {
+                element_value_kind: ValueKind::Map,
+                elements: vec![map_of(entries)],
+            }],
+        };
+        let unordered =
+            basic_encode(&nested(vec![entry(9, "z"), entry(3, "c"), entry(5, "e")])).unwrap();
+        let ordered =
+            basic_encode(&nested(vec![entry(3, "c"), entry(5, "e"), entry(9, "z")])).unwrap();
+
+        assert!(!basic_is_canonical(&unordered));
+        assert_eq!(basic_canonicalize(&unordered).unwrap(), ordered);
+    }
+
+    #[test]
+    fn test_duplicate_map_keys_are_rejected() {
+        let duplicated =
+            basic_encode(&map_of(vec![entry(1, "a"), entry(2, "b"), entry(1, "c")])).unwrap();
+
+        assert!(!basic_is_canonical(&duplicated));
+        assert_eq!(
+            basic_canonicalize(&duplicated),
+            Err(CanonicalizationError::DuplicateMapKey)
+        );
+    }
+
+    #[test]
+    fn test_invalid_payload_is_not_canonical() {
+        let payload = vec![BASIC_SBOR_V1_PAYLOAD_PREFIX, 0x07];
+
+        assert!(!basic_is_canonical(&payload));
+        assert!(matches!(
+            basic_canonicalize(&payload),
+            Err(CanonicalizationError::DecodeError(_))
+        ));
+    }
+}
@@ -7,6 +7,8 @@ compile_error!("Feature `std` and `alloc` can't be enabled at the same time.");
 
 /// SBOR basic, no custom types
 pub mod basic;
+/// SBOR payload canonicalization.
+pub mod canonicalization;
 /// SBOR Categorize trait
 pub mod categorize;
 /// SBOR codec for core Rust types.
@@ -36,6 +38,8 @@ pub mod representations;
 pub mod rust;
 /// SBOR Schema
 pub mod schema;
+/// SBOR streaming decoding.
+pub mod stream_decoder;
 /// SBOR structured payload traversal.
 pub mod traversal;
 /// SBOR value model and any decoding/encoding.
@@ -46,6 +50,7 @@ pub mod value_kind;
 pub mod versioned;
 
 pub use basic::*;
+pub use canonicalization::{canonicalize, is_canonical, CanonicalizationError};
 pub(crate) use categorize::{categorize_generic, categorize_simple};
 pub use categorize::{Categorize, SborEnum, SborTuple};
 pub use constants::*;
@@ -54,6 +59,9 @@ pub use decoder::{BorrowingDecoder, DecodeError, Decoder, VecDecoder};
 pub use encode::Encode;
 pub use encoder::{EncodeError, Encoder, VecEncoder};
 pub use path::{SborPath, SborPathBuf};
+#[cfg(feature = "std")]
+pub use stream_decoder::IoByteSource;
+pub use stream_decoder::{ByteSource, RecordingByteSource, StreamDecoder};
 
 pub use encoded_wrappers::*;
 pub use enum_variant::*;
@@ -4,6 +4,9 @@ use crate::value::Value;
 use crate::CustomValueKind;
 use crate::*;
 
+/// SBOR path query expressions, such as `.field[2].entries["key"]`.
+pub mod query;
+
 #[derive(Eq, PartialEq, Clone)]
 pub struct SborPathBuf(Vec<usize>);
 
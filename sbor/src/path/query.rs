@@ -0,0 +1,531 @@
+use crate::rust::prelude::*;
+use crate::*;
+
+/// A single step of an [`SborPathQuery`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SborPathSegment {
+    /// `.name` - a named field of a tuple or enum variant, resolved through a schema.
+    Field(String),
+    /// `.2` or `[2]` - the n-th field or element of a tuple, enum variant or array,
+    /// or the map entry with the integer key n.
+    Index(usize),
+    /// `["key"]` - the map entry with the string key.
+    Key(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SborPathQueryParseError {
+    UnexpectedEnd,
+    UnexpectedChar { position: usize, char: char },
+    InvalidIndex { position: usize },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SborPathQueryError {
+    /// The segment doesn't apply to the kind of value it was evaluated against,
+    /// e.g. a string key against an array.
+    InvalidSegment {
+        segment: usize,
+    },
+    /// The named field isn't known, either because it doesn't exist or because no schema
+    /// was available to resolve field names at this point.
+    UnknownField {
+        segment: usize,
+        name: String,
+    },
+    IndexOutOfBounds {
+        segment: usize,
+        index: usize,
+        length: usize,
+    },
+    KeyNotFound {
+        segment: usize,
+    },
+}
+
+/// A path expression, such as `.field[2].entries["key"]`, which picks a nested value out of
+/// an SBOR value.
+///
+/// Field names aren't part of an SBOR value, so `.name` segments can only be evaluated with a
+/// schema, see [`SborPathQuery::evaluate_with_schema`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SborPathQuery {
+    segments: Vec<SborPathSegment>,
+}
+
+impl SborPathQuery {
+    pub fn new(segments: Vec<SborPathSegment>) -> Self {
+        Self { segments }
+    }
+
+    pub fn segments(&self) -> &[SborPathSegment] {
+        &self.segments
+    }
+
+    /// Evaluates the query against the value, without resolving any field names.
+    pub fn evaluate<'v, X: CustomValueKind, Y: CustomValue<X>>(
+        &self,
+        value: &'v Value<X, Y>,
+    ) -> Result<&'v Value<X, Y>, SborPathQueryError> {
+        self.evaluate_internal::<X, Y, NoCustomSchema>(value, None)
+    }
+
+    /// Evaluates the query against the value of the given type, resolving field names from
+    /// the schema.
+    pub fn evaluate_with_schema<'v, X: CustomValueKind, Y: CustomValue<X>, S: CustomSchema>(
+        &self,
+        value: &'v Value<X, Y>,
+        schema: &Schema<S>,
+        type_id: LocalTypeId,
+    ) -> Result<&'v Value<X, Y>, SborPathQueryError> {
+        self.evaluate_internal(value, Some((schema, type_id)))
+    }
+
+    fn evaluate_internal<'v, X: CustomValueKind, Y: CustomValue<X>, S: CustomSchema>(
+        &self,
+        value: &'v Value<X, Y>,
+        schema: Option<(&Schema<S>, LocalTypeId)>,
+    ) -> Result<&'v Value<X, Y>, SborPathQueryError> {
+        let mut current = value;
+        let mut current_type = schema.map(|(_, type_id)| type_id);
+        let schema = schema.map(|(schema, _)| schema);
+        let type_kind = |type_id: Option<LocalTypeId>| match (schema, type_id) {
+            (Some(schema), Some(type_id)) => schema.resolve_type_kind(type_id),
+            _ => None,
+        };
+
+        for (segment, path_segment) in self.segments.iter().enumerate() {
+            let (next, next_type) = match (path_segment, current) {
+                (SborPathSegment::Field(name), Value::Tuple { fields }) => {
+                    let field_names = match (schema, current_type) {
+                        (Some(schema), Some(type_id)) => {
+                            schema
+                                .resolve_matching_tuple_metadata(type_id, fields.len())
+                                .field_names
+                        }
+                        _ => None,
+                    };
+                    let index = find_field(field_names, name).ok_or_else(|| {
+                        SborPathQueryError::UnknownField {
+                            segment,
+                            name: name.clone(),
+                        }
+                    })?;
+                    let field_type = match type_kind(current_type) {
+                        Some(TypeKind::Tuple { field_types }) => field_types.get(index).cloned(),
+                        _ => None,
+                    };
+                    (get_child(fields, segment, index)?, field_type)
+                }
+                (
+                    SborPathSegment::Field(name),
+                    Value::Enum {
+                        discriminator,
+                        fields,
+                    },
+                ) => {
+                    let field_names = match (schema, current_type) {
+                        (Some(schema), Some(type_id)) => {
+                            schema
+                                .resolve_matching_enum_metadata(
+                                    type_id,
+                                    *discriminator,
+                                    fields.len(),
+                                )
+                                .field_names
+                        }
+                        _ => None,
+                    };
+                    let index = find_field(field_names, name).ok_or_else(|| {
+                        SborPathQueryError::UnknownField {
+                            segment,
+                            name: name.clone(),
+                        }
+                    })?;
+                    let field_type = match type_kind(current_type) {
+                        Some(TypeKind::Enum { variants }) => variants
+                            .get(discriminator)
+                            .and_then(|field_types| field_types.get(index).cloned()),
+                        _ => None,
+                    };
+                    (get_child(fields, segment, index)?, field_type)
+                }
+                (SborPathSegment::Index(index), Value::Tuple { fields }) => {
+                    let field_type = match type_kind(current_type) {
+                        Some(TypeKind::Tuple { field_types }) => field_types.get(*index).cloned(),
+                        _ => None,
+                    };
+                    (get_child(fields, segment, *index)?, field_type)
+                }
+                (
+                    SborPathSegment::Index(index),
+                    Value::Enum {
+                        discriminator,
+                        fields,
+                    },
+                ) => {
+                    let field_type = match type_kind(current_type) {
+                        Some(TypeKind::Enum { variants }) => variants
+                            .get(discriminator)
+                            .and_then(|field_types| field_types.get(*index).cloned()),
+                        _ => None,
+                    };
+                    (get_child(fields, segment, *index)?, field_type)
+                }
+                (SborPathSegment::Index(index), Value::Array { elements, .. }) => {
+                    let element_type = match type_kind(current_type) {
+                        Some(TypeKind::Array { element_type }) => Some(*element_type),
+                        _ => None,
+                    };
+                    (get_child(elements, segment, *index)?, element_type)
+                }
+                (SborPathSegment::Index(_), Value::Map { entries, .. })
+                | (SborPathSegment::Key(_), Value::Map { entries, .. }) => {
+                    let value_type = match type_kind(current_type) {
+                        Some(TypeKind::Map { value_type, .. }) => Some(*value_type),
+                        _ => None,
+                    };
+                    let (_, entry_value) = entries
+                        .iter()
+                        .find(|(key, _)| key_matches(path_segment, key))
+                        .ok_or(SborPathQueryError::KeyNotFound { segment })?;
+                    (entry_value, value_type)
+                }
+                _ => return Err(SborPathQueryError::InvalidSegment { segment }),
+            };
+            current = next;
+            current_type = next_type;
+        }
+
+        Ok(current)
+    }
+}
+
+fn find_field(field_names: Option<&[Cow<'static, str>]>, name: &str) -> Option<usize> {
+    field_names?
+        .iter()
+        .position(|field_name| field_name.as_ref() == name)
+}
+
+fn get_child<'v, X: CustomValueKind, Y: CustomValue<X>>(
+    children: &'v [Value<X, Y>],
+    segment: usize,
+    index: usize,
+) -> Result<&'v Value<X, Y>, SborPathQueryError> {
+    children
+        .get(index)
+        .ok_or(SborPathQueryError::IndexOutOfBounds {
+            segment,
+            index,
+            length: children.len(),
+        })
+}
+
+fn key_matches<X: CustomValueKind, Y: CustomValue<X>>(
+    path_segment: &SborPathSegment,
+    key: &Value<X, Y>,
+) -> bool {
+    match path_segment {
+        SborPathSegment::Key(expected) => {
+            matches!(key, Value::String { value } if value == expected)
+        }
+        SborPathSegment::Index(expected) => {
+            let expected = *expected as u128;
+            match key {
+                Value::U8 { value } => u128::from(*value) == expected,
+                Value::U16 { value } => u128::from(*value) == expected,
+                Value::U32 { value } => u128::from(*value) == expected,
+                Value::U64 { value } => u128::from(*value) == expected,
+                Value::U128 { value } => *value == expected,
+                Value::I8 { value } => u128::try_from(*value) == Ok(expected),
+                Value::I16 { value } => u128::try_from(*value) == Ok(expected),
+                Value::I32 { value } => u128::try_from(*value) == Ok(expected),
+                Value::I64 { value } => u128::try_from(*value) == Ok(expected),
+                Value::I128 { value } => u128::try_from(*value) == Ok(expected),
+                _ => false,
+            }
+        }
+        SborPathSegment::Field(_) => false,
+    }
+}
+
+impl FromStr for SborPathQuery {
+    type Err = SborPathQueryParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.char_indices().peekable();
+        let mut segments = Vec::new();
+
+        while let Some((position, char)) = chars.next() {
+            let segment = match char {
+                '.' => {
+                    let mut name = String::new();
+                    while let Some((_, char)) =
+                        chars.next_if(|(_, c)| c.is_ascii_alphanumeric() || *c == '_')
+                    {
+                        name.push(char);
+                    }
+                    if name.is_empty() {
+                        return Err(match chars.peek() {
+                            Some((position, char)) => SborPathQueryParseError::UnexpectedChar {
+                                position: *position,
+                                char: *char,
+                            },
+                            None => SborPathQueryParseError::UnexpectedEnd,
+                        });
+                    }
+                    if name.starts_with(|c: char| c.is_ascii_digit()) {
+                        SborPathSegment::Index(parse_index(&name, position + 1)?)
+                    } else {
+                        SborPathSegment::Field(name)
+                    }
+                }
+                '[' => {
+                    let segment = match chars.peek() {
+                        Some((_, '"')) => {
+                            chars.next();
+                            let mut key = String::new();
+                            loop {
+                                match chars.next() {
+                                    Some((_, '"')) => break,
+                                    Some((_, '\\')) => match chars.next() {
+                                        Some((_, char @ ('"' | '\\'))) => key.push(char),
+                                        Some((position, char)) => {
+                                            return Err(SborPathQueryParseError::UnexpectedChar {
+                                                position,
+                                                char,
+                                            })
+                                        }
+                                        None => return Err(SborPathQueryParseError::UnexpectedEnd),
+                                    },
+                                    Some((_, char)) => key.push(char),
+                                    None => return Err(SborPathQueryParseError::UnexpectedEnd),
+                                }
+                            }
+                            SborPathSegment::Key(key)
+                        }
+                        _ => {
+                            let mut digits = String::new();
+                            while let Some((_, char)) = chars.next_if(|(_, c)| c.is_ascii_digit()) {
+                                digits.push(char);
+                            }
+                            SborPathSegment::Index(parse_index(&digits, position + 1)?)
+                        }
+                    };
+                    match chars.next() {
+                        Some((_, ']')) => {}
+                        Some((position, char)) => {
+                            return Err(SborPathQueryParseError::UnexpectedChar { position, char })
+                        }
+                        None => return Err(SborPathQueryParseError::UnexpectedEnd),
+                    }
+                    segment
+                }
+                char => return Err(SborPathQueryParseError::UnexpectedChar { position, char }),
+            };
+            segments.push(segment);
+        }
+
+        Ok(Self { segments })
+    }
+}
+
+fn parse_index(digits: &str, position: usize) -> Result<usize, SborPathQueryParseError> {
+    if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return Err(SborPathQueryParseError::InvalidIndex { position });
+    }
+    digits
+        .parse()
+        .map_err(|_| SborPathQueryParseError::InvalidIndex { position })
+}
+
+impl fmt::Display for SborPathQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for segment in &self.segments {
+            match segment {
+                SborPathSegment::Field(name) => write!(f, ".{}", name)?,
+                SborPathSegment::Index(index) => write!(f, "[{}]", index)?,
+                SborPathSegment::Key(key) => {
+                    write!(f, "[\"")?;
+                    for char in key.chars() {
+                        if char == '"' || char == '\\' {
+                            write!(f, "\\")?;
+                        }
+                        write!(f, "{}", char)?;
+                    }
+                    write!(f, "\"]")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Sbor)]
+    struct MyStruct {
+        field: Vec<MyEnum>,
+        entries: BTreeMap<String, u32>,
+    }
+
+    #[derive(Sbor)]
+    enum MyEnum {
+        Unit,
+        Named { inner: u8 },
+    }
+
+    fn query(s: &str) -> SborPathQuery {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn parses_and_displays_queries() {
+        let parsed = query(".field[2].entries[\"ke\\\"y\"].0");
+        assert_eq!(
+            parsed.segments(),
+            &[
+                SborPathSegment::Field("field".to_string()),
+                SborPathSegment::Index(2),
+                SborPathSegment::Field("entries".to_string()),
+                SborPathSegment::Key("ke\"y".to_string()),
+                SborPathSegment::Index(0),
+            ]
+        );
+        assert_eq!(parsed.to_string(), ".field[2].entries[\"ke\\\"y\"][0]");
+        assert!(query("").segments().is_empty());
+    }
+
+    #[test]
+    fn rejects_malformed_queries() {
+        assert_eq!(
+            "field".parse::<SborPathQuery>(),
+            Err(SborPathQueryParseError::UnexpectedChar {
+                position: 0,
+                char: 'f'
+            })
+        );
+        assert_eq!(
+            ".".parse::<SborPathQuery>(),
+            Err(SborPathQueryParseError::UnexpectedEnd)
+        );
+        assert_eq!(
+            "[x]".parse::<SborPathQuery>(),
+            Err(SborPathQueryParseError::InvalidIndex { position: 1 })
+        );
+        assert_eq!(
+            ".1a".parse::<SborPathQuery>(),
+            Err(SborPathQueryParseError::InvalidIndex { position: 1 })
+        );
+        assert_eq!(
+            "[\"key\"".parse::<SborPathQuery>(),
+            Err(SborPathQueryParseError::UnexpectedEnd)
+        );
+    }
+
+    #[test]
+    fn evaluates_indices_and_keys_without_schema() {
+        let value = BasicValue::Tuple {
+            fields: vec![
+                BasicValue::Array {
+                    element_value_kind: BasicValueKind::U8,
+                    elements: vec![BasicValue::U8 { value: 5 }, BasicValue::U8 { value: 6 }],
+                },
+                BasicValue::Map {
+                    key_value_kind: BasicValueKind::String,
+                    value_value_kind: BasicValueKind::U32,
+                    entries: vec![(
+                        BasicValue::String {
+                            value: "key".to_string(),
+                        },
+                        BasicValue::U32 { value: 7 },
+                    )],
+                },
+                BasicValue::Map {
+                    key_value_kind: BasicValueKind::I16,
+                    value_value_kind: BasicValueKind::Bool,
+                    entries: vec![(
+                        BasicValue::I16 { value: 3 },
+                        BasicValue::Bool { value: true },
+                    )],
+                },
+            ],
+        };
+
+        assert_eq!(query("").evaluate(&value), Ok(&value));
+        assert_eq!(
+            query(".0[1]").evaluate(&value),
+            Ok(&BasicValue::U8 { value: 6 })
+        );
+        assert_eq!(
+            query("[1][\"key\"]").evaluate(&value),
+            Ok(&BasicValue::U32 { value: 7 })
+        );
+        assert_eq!(
+            query("[2][3]").evaluate(&value),
+            Ok(&BasicValue::Bool { value: true })
+        );
+        assert_eq!(
+            query("[0][2]").evaluate(&value),
+            Err(SborPathQueryError::IndexOutOfBounds {
+                segment: 1,
+                index: 2,
+                length: 2
+            })
+        );
+        assert_eq!(
+            query("[1][\"other\"]").evaluate(&value),
+            Err(SborPathQueryError::KeyNotFound { segment: 1 })
+        );
+        assert_eq!(
+            query("[0][\"key\"]").evaluate(&value),
+            Err(SborPathQueryError::InvalidSegment { segment: 1 })
+        );
+        assert_eq!(
+            query(".field").evaluate(&value),
+            Err(SborPathQueryError::UnknownField {
+                segment: 0,
+                name: "field".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn evaluates_named_fields_with_schema() {
+        let (type_id, schema) = generate_full_schema_from_single_type::<MyStruct, NoCustomSchema>();
+        let mut entries = BTreeMap::new();
+        entries.insert("key".to_string(), 42);
+        let payload = basic_encode(&MyStruct {
+            field: vec![MyEnum::Unit, MyEnum::Unit, MyEnum::Named { inner: 9 }],
+            entries,
+        })
+        .unwrap();
+        let value: BasicValue = basic_decode(&payload).unwrap();
+        let evaluate = |s: &str| query(s).evaluate_with_schema(&value, schema.v1(), type_id);
+
+        assert_eq!(
+            evaluate(".field[2].inner"),
+            Ok(&BasicValue::U8 { value: 9 })
+        );
+        assert_eq!(
+            evaluate(".entries[\"key\"]"),
+            Ok(&BasicValue::U32 { value: 42 })
+        );
+        assert_eq!(
+            evaluate(".field[0].inner"),
+            Err(SborPathQueryError::UnknownField {
+                segment: 2,
+                name: "inner".to_string()
+            })
+        );
+        assert_eq!(
+            evaluate(".missing"),
+            Err(SborPathQueryError::UnknownField {
+                segment: 0,
+                name: "missing".to_string()
+            })
+        );
+    }
+}
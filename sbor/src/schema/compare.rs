@@ -0,0 +1,624 @@
+//! Comparison of the types of two schemas, e.g. the schemas of an old and a new version of a
+//! blueprint, to check whether values encoded against the old types can still be decoded (and
+//! validated) against the new types.
+
+use crate::rust::prelude::*;
+use crate::*;
+
+/// A step in the path from a compared root type down to a nested type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SchemaPathSegment {
+    Field(usize),
+    EnumVariant(u8),
+    ArrayElement,
+    MapKey,
+    MapValue,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaChangeKind {
+    // Compatible changes
+    /// The new type is `Any`, which accepts everything the old type accepted.
+    TypeKindRelaxedToAny,
+    /// The new type has no validation, where the old one had some.
+    ValidationRemoved,
+    EnumVariantAdded {
+        variant_id: u8,
+    },
+    TypeRenamed {
+        old_name: Option<String>,
+        new_name: Option<String>,
+    },
+    FieldRenamed {
+        field_index: usize,
+        old_name: String,
+        new_name: String,
+    },
+    EnumVariantRenamed {
+        variant_id: u8,
+        old_name: Option<String>,
+        new_name: Option<String>,
+    },
+
+    // Breaking changes
+    /// The type (or one of the types) can't be resolved in its schema.
+    TypeNotFound,
+    TypeKindChanged,
+    FieldCountChanged {
+        old_count: usize,
+        new_count: usize,
+    },
+    EnumVariantRemoved {
+        variant_id: u8,
+    },
+    /// The validation differs, and isn't simply removed.
+    /// Comparisons are conservative: e.g. a widened numeric range is still reported as a change.
+    ValidationChanged,
+}
+
+impl SchemaChangeKind {
+    /// Whether a value which is valid against the old type may fail to decode or validate
+    /// against the new type.
+    pub fn is_breaking(&self) -> bool {
+        match self {
+            SchemaChangeKind::TypeKindRelaxedToAny
+            | SchemaChangeKind::ValidationRemoved
+            | SchemaChangeKind::EnumVariantAdded { .. }
+            | SchemaChangeKind::TypeRenamed { .. }
+            | SchemaChangeKind::FieldRenamed { .. }
+            | SchemaChangeKind::EnumVariantRenamed { .. } => false,
+            SchemaChangeKind::TypeNotFound
+            | SchemaChangeKind::TypeKindChanged
+            | SchemaChangeKind::FieldCountChanged { .. }
+            | SchemaChangeKind::EnumVariantRemoved { .. }
+            | SchemaChangeKind::ValidationChanged => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SchemaChange {
+    /// The path from the compared root types to the changed types.
+    pub path: Vec<SchemaPathSegment>,
+    pub old_type_id: LocalTypeId,
+    pub new_type_id: LocalTypeId,
+    pub kind: SchemaChangeKind,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SchemaComparison {
+    pub changes: Vec<SchemaChange>,
+}
+
+impl SchemaComparison {
+    /// Whether all values of the old types are valid values of the new types.
+    pub fn is_compatible(&self) -> bool {
+        self.breaking_changes().next().is_none()
+    }
+
+    pub fn breaking_changes(&self) -> impl Iterator<Item = &SchemaChange> {
+        self.changes
+            .iter()
+            .filter(|change| change.kind.is_breaking())
+    }
+
+    pub fn compatible_changes(&self) -> impl Iterator<Item = &SchemaChange> {
+        self.changes
+            .iter()
+            .filter(|change| !change.kind.is_breaking())
+    }
+}
+
+/// Compares types of an old schema against types of a new schema.
+///
+/// Types are compared structurally, from the given root types downwards: the schemas don't need
+/// to have the same type indices, and their other types are ignored. Each pair of (old, new)
+/// types is only compared once, so recursive types are supported.
+pub struct SchemaComparator<'s, S: CustomSchema> {
+    old_schema: &'s Schema<S>,
+    new_schema: &'s Schema<S>,
+}
+
+impl<'s, S: CustomSchema> SchemaComparator<'s, S> {
+    pub fn new(old_schema: &'s Schema<S>, new_schema: &'s Schema<S>) -> Self {
+        Self {
+            old_schema,
+            new_schema,
+        }
+    }
+
+    pub fn compare_types(
+        &self,
+        old_type_id: LocalTypeId,
+        new_type_id: LocalTypeId,
+    ) -> SchemaComparison {
+        self.compare_type_pairs([(old_type_id, new_type_id)])
+    }
+
+    /// Compares multiple (old, new) pairs of root types, e.g. all the field types of a blueprint.
+    pub fn compare_type_pairs(
+        &self,
+        type_pairs: impl IntoIterator<Item = (LocalTypeId, LocalTypeId)>,
+    ) -> SchemaComparison {
+        let mut state = ComparisonState {
+            visited: index_set_new(),
+            path: vec![],
+            changes: vec![],
+        };
+        for (old_type_id, new_type_id) in type_pairs {
+            self.compare_type(&mut state, old_type_id, new_type_id);
+        }
+        SchemaComparison {
+            changes: state.changes,
+        }
+    }
+
+    fn compare_type(
+        &self,
+        state: &mut ComparisonState,
+        old_type_id: LocalTypeId,
+        new_type_id: LocalTypeId,
+    ) {
+        if !state.visited.insert((old_type_id, new_type_id)) {
+            return;
+        }
+
+        let (Some(old_kind), Some(new_kind)) = (
+            self.old_schema.resolve_type_kind(old_type_id),
+            self.new_schema.resolve_type_kind(new_type_id),
+        ) else {
+            state.record(old_type_id, new_type_id, SchemaChangeKind::TypeNotFound);
+            return;
+        };
+
+        self.compare_metadata(state, old_type_id, new_type_id);
+
+        match (old_kind, new_kind) {
+            (_, TypeKind::Any) => {
+                if !matches!(old_kind, TypeKind::Any) {
+                    state.record(
+                        old_type_id,
+                        new_type_id,
+                        SchemaChangeKind::TypeKindRelaxedToAny,
+                    );
+                }
+                // Any accepts everything, so there's no validation to compare against
+                return;
+            }
+            (
+                TypeKind::Array {
+                    element_type: old_element_type,
+                },
+                TypeKind::Array {
+                    element_type: new_element_type,
+                },
+            ) => {
+                state.path.push(SchemaPathSegment::ArrayElement);
+                self.compare_type(state, *old_element_type, *new_element_type);
+                state.path.pop();
+            }
+            (
+                TypeKind::Tuple {
+                    field_types: old_field_types,
+                },
+                TypeKind::Tuple {
+                    field_types: new_field_types,
+                },
+            ) => {
+                self.compare_fields(
+                    state,
+                    old_type_id,
+                    new_type_id,
+                    old_field_types,
+                    new_field_types,
+                );
+            }
+            (
+                TypeKind::Enum {
+                    variants: old_variants,
+                },
+                TypeKind::Enum {
+                    variants: new_variants,
+                },
+            ) => {
+                for (variant_id, old_field_types) in old_variants {
+                    match new_variants.get(variant_id) {
+                        Some(new_field_types) => {
+                            state.path.push(SchemaPathSegment::EnumVariant(*variant_id));
+                            self.compare_fields(
+                                state,
+                                old_type_id,
+                                new_type_id,
+                                old_field_types,
+                                new_field_types,
+                            );
+                            state.path.pop();
+                        }
+                        None => state.record(
+                            old_type_id,
+                            new_type_id,
+                            SchemaChangeKind::EnumVariantRemoved {
+                                variant_id: *variant_id,
+                            },
+                        ),
+                    }
+                }
+                for variant_id in new_variants.keys() {
+                    if !old_variants.contains_key(variant_id) {
+                        state.record(
+                            old_type_id,
+                            new_type_id,
+                            SchemaChangeKind::EnumVariantAdded {
+                                variant_id: *variant_id,
+                            },
+                        );
+                    }
+                }
+            }
+            (
+                TypeKind::Map {
+                    key_type: old_key_type,
+                    value_type: old_value_type,
+                },
+                TypeKind::Map {
+                    key_type: new_key_type,
+                    value_type: new_value_type,
+                },
+            ) => {
+                state.path.push(SchemaPathSegment::MapKey);
+                self.compare_type(state, *old_key_type, *new_key_type);
+                state.path.pop();
+                state.path.push(SchemaPathSegment::MapValue);
+                self.compare_type(state, *old_value_type, *new_value_type);
+                state.path.pop();
+            }
+            (old_kind, new_kind) => {
+                // The remaining kinds have no child types, so they can be compared directly
+                if old_kind != new_kind {
+                    state.record(old_type_id, new_type_id, SchemaChangeKind::TypeKindChanged);
+                    return;
+                }
+            }
+        }
+
+        self.compare_validation(state, old_type_id, new_type_id);
+    }
+
+    fn compare_fields(
+        &self,
+        state: &mut ComparisonState,
+        old_type_id: LocalTypeId,
+        new_type_id: LocalTypeId,
+        old_field_types: &[LocalTypeId],
+        new_field_types: &[LocalTypeId],
+    ) {
+        if old_field_types.len() != new_field_types.len() {
+            state.record(
+                old_type_id,
+                new_type_id,
+                SchemaChangeKind::FieldCountChanged {
+                    old_count: old_field_types.len(),
+                    new_count: new_field_types.len(),
+                },
+            );
+            return;
+        }
+        for (index, (old_field_type, new_field_type)) in
+            old_field_types.iter().zip(new_field_types).enumerate()
+        {
+            state.path.push(SchemaPathSegment::Field(index));
+            self.compare_type(state, *old_field_type, *new_field_type);
+            state.path.pop();
+        }
+    }
+
+    fn compare_metadata(
+        &self,
+        state: &mut ComparisonState,
+        old_type_id: LocalTypeId,
+        new_type_id: LocalTypeId,
+    ) {
+        let (Some(old_metadata), Some(new_metadata)) = (
+            self.old_schema.resolve_type_metadata(old_type_id),
+            self.new_schema.resolve_type_metadata(new_type_id),
+        ) else {
+            return;
+        };
+
+        if old_metadata.get_name() != new_metadata.get_name() {
+            state.record(
+                old_type_id,
+                new_type_id,
+                SchemaChangeKind::TypeRenamed {
+                    old_name: old_metadata.get_name_string(),
+                    new_name: new_metadata.get_name_string(),
+                },
+            );
+        }
+
+        match (&old_metadata.child_names, &new_metadata.child_names) {
+            (
+                Some(ChildNames::NamedFields(old_field_names)),
+                Some(ChildNames::NamedFields(new_field_names)),
+            ) => {
+                Self::compare_field_names(
+                    state,
+                    old_type_id,
+                    new_type_id,
+                    old_field_names,
+                    new_field_names,
+                );
+            }
+            (
+                Some(ChildNames::EnumVariants(old_variants)),
+                Some(ChildNames::EnumVariants(new_variants)),
+            ) => {
+                for (variant_id, old_variant) in old_variants {
+                    let Some(new_variant) = new_variants.get(variant_id) else {
+                        continue;
+                    };
+                    if old_variant.get_name() != new_variant.get_name() {
+                        state.record(
+                            old_type_id,
+                            new_type_id,
+                            SchemaChangeKind::EnumVariantRenamed {
+                                variant_id: *variant_id,
+                                old_name: old_variant.get_name_string(),
+                                new_name: new_variant.get_name_string(),
+                            },
+                        );
+                    }
+                    if let (Some(old_field_names), Some(new_field_names)) =
+                        (old_variant.get_field_names(), new_variant.get_field_names())
+                    {
+                        state.path.push(SchemaPathSegment::EnumVariant(*variant_id));
+                        Self::compare_field_names(
+                            state,
+                            old_type_id,
+                            new_type_id,
+                            old_field_names,
+                            new_field_names,
+                        );
+                        state.path.pop();
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn compare_field_names(
+        state: &mut ComparisonState,
+        old_type_id: LocalTypeId,
+        new_type_id: LocalTypeId,
+        old_field_names: &[Cow<'static, str>],
+        new_field_names: &[Cow<'static, str>],
+    ) {
+        // A changed field count is reported against the type kind
+        if old_field_names.len() != new_field_names.len() {
+            return;
+        }
+        for (field_index, (old_name, new_name)) in
+            old_field_names.iter().zip(new_field_names).enumerate()
+        {
+            if old_name != new_name {
+                state.record(
+                    old_type_id,
+                    new_type_id,
+                    SchemaChangeKind::FieldRenamed {
+                        field_index,
+                        old_name: old_name.to_string(),
+                        new_name: new_name.to_string(),
+                    },
+                );
+            }
+        }
+    }
+
+    fn compare_validation(
+        &self,
+        state: &mut ComparisonState,
+        old_type_id: LocalTypeId,
+        new_type_id: LocalTypeId,
+    ) {
+        let old_validation = self
+            .old_schema
+            .resolve_type_validation(old_type_id)
+            .filter(|validation| !matches!(validation, TypeValidation::None));
+        let new_validation = self
+            .new_schema
+            .resolve_type_validation(new_type_id)
+            .filter(|validation| !matches!(validation, TypeValidation::None));
+        match (old_validation, new_validation) {
+            (None, None) => {}
+            (Some(_), None) => state.record(
+                old_type_id,
+                new_type_id,
+                SchemaChangeKind::ValidationRemoved,
+            ),
+            (old_validation, new_validation) if old_validation == new_validation => {}
+            _ => state.record(
+                old_type_id,
+                new_type_id,
+                SchemaChangeKind::ValidationChanged,
+            ),
+        }
+    }
+}
+
+struct ComparisonState {
+    visited: IndexSet<(LocalTypeId, LocalTypeId)>,
+    path: Vec<SchemaPathSegment>,
+    changes: Vec<SchemaChange>,
+}
+
+impl ComparisonState {
+    fn record(
+        &mut self,
+        old_type_id: LocalTypeId,
+        new_type_id: LocalTypeId,
+        kind: SchemaChangeKind,
+    ) {
+        self.changes.push(SchemaChange {
+            path: self.path.clone(),
+            old_type_id,
+            new_type_id,
+            kind,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod v1 {
+        use crate::*;
+
+        #[derive(Sbor)]
+        pub struct State {
+            pub owner: String,
+            pub count: u32,
+            pub status: Status,
+        }
+
+        #[derive(Sbor)]
+        pub enum Status {
+            Open,
+            Closed { reason: String },
+        }
+
+        #[derive(Sbor)]
+        pub struct Tree {
+            pub children: Vec<Tree>,
+        }
+    }
+
+    mod v2 {
+        use crate::*;
+
+        #[derive(Sbor)]
+        pub struct State {
+            pub admin: String,
+            pub count: u32,
+            pub status: Status,
+        }
+
+        #[derive(Sbor)]
+        pub enum Status {
+            Open,
+            Closed { reason: String },
+            Paused,
+        }
+
+        #[derive(Sbor)]
+        pub struct Tree {
+            pub children: Vec<Tree>,
+            pub label: String,
+        }
+    }
+
+    mod v3 {
+        use crate::*;
+
+        #[derive(Sbor)]
+        pub struct State {
+            pub owner: String,
+            pub count: String,
+            pub status: Status,
+        }
+
+        #[derive(Sbor)]
+        pub enum Status {
+            Open,
+        }
+    }
+
+    fn compare<Old: Describe<NoCustomTypeKind>, New: Describe<NoCustomTypeKind>>(
+    ) -> SchemaComparison {
+        let (old_type_id, old_schema) =
+            generate_full_schema_from_single_type::<Old, NoCustomSchema>();
+        let (new_type_id, new_schema) =
+            generate_full_schema_from_single_type::<New, NoCustomSchema>();
+        SchemaComparator::new(old_schema.v1(), new_schema.v1())
+            .compare_types(old_type_id, new_type_id)
+    }
+
+    #[test]
+    fn identical_types_have_no_changes() {
+        let comparison = compare::<v1::State, v1::State>();
+        assert_eq!(comparison.changes, vec![]);
+        assert!(comparison.is_compatible());
+    }
+
+    #[test]
+    fn renames_and_added_variants_are_compatible() {
+        let comparison = compare::<v1::State, v2::State>();
+        let changes: Vec<_> = comparison
+            .changes
+            .iter()
+            .map(|change| (change.path.clone(), change.kind.clone()))
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                (
+                    vec![],
+                    SchemaChangeKind::FieldRenamed {
+                        field_index: 0,
+                        old_name: "owner".to_string(),
+                        new_name: "admin".to_string(),
+                    }
+                ),
+                (
+                    vec![SchemaPathSegment::Field(2)],
+                    SchemaChangeKind::EnumVariantAdded { variant_id: 2 }
+                ),
+            ]
+        );
+        assert!(comparison.is_compatible());
+    }
+
+    #[test]
+    fn changed_field_types_and_removed_variants_are_breaking() {
+        let comparison = compare::<v1::State, v3::State>();
+        let breaking_changes: Vec<_> = comparison
+            .breaking_changes()
+            .map(|change| (change.path.clone(), change.kind.clone()))
+            .collect();
+        assert_eq!(
+            breaking_changes,
+            vec![
+                (
+                    vec![SchemaPathSegment::Field(1)],
+                    SchemaChangeKind::TypeKindChanged
+                ),
+                (
+                    vec![SchemaPathSegment::Field(2)],
+                    SchemaChangeKind::EnumVariantRemoved { variant_id: 1 }
+                ),
+            ]
+        );
+        assert!(!comparison.is_compatible());
+    }
+
+    #[test]
+    fn recursive_types_are_supported() {
+        assert!(compare::<v1::Tree, v1::Tree>().changes.is_empty());
+
+        let comparison = compare::<v1::Tree, v2::Tree>();
+        let changes: Vec<_> = comparison
+            .changes
+            .iter()
+            .map(|change| change.kind.clone())
+            .collect();
+        assert_eq!(
+            changes,
+            vec![SchemaChangeKind::FieldCountChanged {
+                old_count: 1,
+                new_count: 2,
+            }]
+        );
+        assert!(!comparison.is_compatible());
+    }
+}
@@ -1,3 +1,4 @@
+pub mod compare;
 mod custom_traits;
 mod describe;
 mod macros;
@@ -8,6 +9,7 @@ mod type_data;
 mod type_link;
 mod well_known_types;
 
+pub use compare::*;
 pub use custom_traits::*;
 pub use describe::*;
 pub(crate) use macros::*;
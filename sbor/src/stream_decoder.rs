@@ -0,0 +1,388 @@
+use crate::rust::mem;
+use crate::rust::prelude::*;
+use crate::rust::slice;
+use crate::value_kind::*;
+use crate::*;
+
+/// The most bytes a [`StreamDecoder`] reads from its source in one go, when filling its buffer.
+const READ_CHUNK_SIZE: usize = 64 * 1024;
+
+/// A source of bytes which a [`StreamDecoder`] can pull from.
+///
+/// This is a minimal `no_std`-friendly equivalent of `std::io::Read`. With the `std` feature,
+/// any `std::io::Read` can be used as a source by wrapping it in an [`IoByteSource`].
+pub trait ByteSource {
+    /// Fills the whole of `buf` from the source.
+    ///
+    /// If the source runs out of bytes before `buf` is filled, this should return a
+    /// [`DecodeError::BufferUnderflow`].
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), DecodeError>;
+
+    /// Reads a single byte from the source, returning `Ok(None)` if the source is exhausted.
+    fn try_read_byte(&mut self) -> Result<Option<u8>, DecodeError>;
+}
+
+impl<'a> ByteSource for &'a [u8] {
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), DecodeError> {
+        if self.len() < buf.len() {
+            return Err(DecodeError::BufferUnderflow {
+                required: buf.len(),
+                remaining: self.len(),
+            });
+        }
+        let (head, tail) = self.split_at(buf.len());
+        buf.copy_from_slice(head);
+        *self = tail;
+        Ok(())
+    }
+
+    fn try_read_byte(&mut self) -> Result<Option<u8>, DecodeError> {
+        match self.split_first() {
+            Some((byte, tail)) => {
+                *self = tail;
+                Ok(Some(*byte))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
+impl<'a, S: ByteSource + ?Sized> ByteSource for &'a mut S {
+    #[inline]
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), DecodeError> {
+        (**self).read_exact(buf)
+    }
+
+    #[inline]
+    fn try_read_byte(&mut self) -> Result<Option<u8>, DecodeError> {
+        (**self).try_read_byte()
+    }
+}
+
+/// Adapts a `std::io::Read` into a [`ByteSource`].
+///
+/// An unexpected EOF is reported as a [`DecodeError::BufferUnderflow`], and any other IO error
+/// is reported as a [`DecodeError::ByteSourceError`].
+#[cfg(feature = "std")]
+pub struct IoByteSource<R: std::io::Read> {
+    reader: R,
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::Read> IoByteSource<R> {
+    pub fn new(reader: R) -> Self {
+        Self { reader }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::Read> ByteSource for IoByteSource<R> {
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), DecodeError> {
+        self.reader.read_exact(buf).map_err(|err| match err.kind() {
+            std::io::ErrorKind::UnexpectedEof => DecodeError::BufferUnderflow {
+                required: buf.len(),
+                remaining: 0,
+            },
+            _ => DecodeError::ByteSourceError,
+        })
+    }
+
+    fn try_read_byte(&mut self) -> Result<Option<u8>, DecodeError> {
+        let mut byte = [0u8; 1];
+        loop {
+            match self.reader.read(&mut byte) {
+                Ok(0) => return Ok(None),
+                Ok(_) => return Ok(Some(byte[0])),
+                Err(err) if err.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(_) => return Err(DecodeError::ByteSourceError),
+            }
+        }
+    }
+}
+
+/// A [`ByteSource`] wrapper which records a copy of every byte read from the inner source.
+///
+/// This is useful when the raw payload is needed after a streamed validation pass.
+pub struct RecordingByteSource<'r, S: ByteSource> {
+    inner: S,
+    recorded: &'r mut Vec<u8>,
+}
+
+impl<'r, S: ByteSource> RecordingByteSource<'r, S> {
+    pub fn new(inner: S, recorded: &'r mut Vec<u8>) -> Self {
+        Self { inner, recorded }
+    }
+}
+
+impl<'r, S: ByteSource> ByteSource for RecordingByteSource<'r, S> {
+    fn read_exact(&mut self, buf: &mut [u8]) -> Result<(), DecodeError> {
+        self.inner.read_exact(buf)?;
+        self.recorded.extend_from_slice(buf);
+        Ok(())
+    }
+
+    fn try_read_byte(&mut self) -> Result<Option<u8>, DecodeError> {
+        let byte = self.inner.try_read_byte()?;
+        if let Some(byte) = byte {
+            self.recorded.push(byte);
+        }
+        Ok(byte)
+    }
+}
+
+/// A `StreamDecoder` decodes SBOR from a [`ByteSource`], without requiring the full payload to be in memory.
+///
+/// The decoder always holds one byte of look-ahead, which it uses to support `peek_byte` and `check_end`.
+/// This means that it reads one byte past the end of the current value (if there is one).
+///
+/// Because it doesn't have the whole payload, `peek_remaining` only exposes the look-ahead byte - so types
+/// which need to see the rest of the payload up-front (such as [`RawValue`]) can't be decoded with it.
+/// For structured traversal of large payloads, see [`StreamTraverser`](crate::traversal::StreamTraverser).
+pub struct StreamDecoder<S: ByteSource, X: CustomValueKind> {
+    source: S,
+    lookahead: Result<Option<u8>, DecodeError>,
+    buffer: Vec<u8>,
+    offset: usize,
+    stack_depth: usize,
+    max_depth: usize,
+    phantom: PhantomData<X>,
+}
+
+impl<S: ByteSource, X: CustomValueKind> StreamDecoder<S, X> {
+    pub fn new(mut source: S, max_depth: usize) -> Self {
+        let lookahead = source.try_read_byte();
+        Self {
+            source,
+            lookahead,
+            buffer: Vec::new(),
+            offset: 0,
+            stack_depth: 0,
+            max_depth,
+            phantom: PhantomData,
+        }
+    }
+
+    pub fn into_source(self) -> S {
+        self.source
+    }
+
+    /// Reads the next `n` bytes into the decoder's internal buffer, replacing its previous contents.
+    /// They can then be accessed with [`buffered_bytes`](Self::buffered_bytes).
+    pub fn read_into_buffer(&mut self, n: usize) -> Result<(), DecodeError> {
+        self.buffer.clear();
+        if n == 0 {
+            return Ok(());
+        }
+        let first = self.take_lookahead()?;
+        self.buffer.push(first);
+        // The length comes from the payload, so the buffer is grown a chunk at a time, as bytes
+        // actually arrive, rather than allocated up-front
+        while self.buffer.len() < n {
+            let start = self.buffer.len();
+            let end = start + (n - start).min(READ_CHUNK_SIZE);
+            self.buffer.resize(end, 0);
+            self.source.read_exact(&mut self.buffer[start..end])?;
+        }
+        self.offset += n;
+        self.lookahead = self.source.try_read_byte();
+        Ok(())
+    }
+
+    /// The bytes read by the last call to [`read_into_buffer`](Self::read_into_buffer) or `read_slice`.
+    #[inline]
+    pub fn buffered_bytes(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    #[inline]
+    fn take_lookahead(&mut self) -> Result<u8, DecodeError> {
+        match mem::replace(&mut self.lookahead, Ok(None)) {
+            Ok(Some(byte)) => Ok(byte),
+            Ok(None) => Err(DecodeError::BufferUnderflow {
+                required: 1,
+                remaining: 0,
+            }),
+            Err(error) => Err(error),
+        }
+    }
+
+    #[inline]
+    pub fn track_stack_depth_increase(&mut self) -> Result<(), DecodeError> {
+        self.stack_depth += 1;
+        if self.stack_depth > self.max_depth {
+            return Err(DecodeError::MaxDepthExceeded(self.max_depth));
+        }
+        Ok(())
+    }
+
+    #[inline]
+    pub fn track_stack_depth_decrease(&mut self) -> Result<(), DecodeError> {
+        self.stack_depth -= 1;
+        Ok(())
+    }
+}
+
+impl<S: ByteSource, X: CustomValueKind> Decoder<X> for StreamDecoder<S, X> {
+    fn decode_deeper_body_with_value_kind<T: Decode<X, Self>>(
+        &mut self,
+        value_kind: ValueKind<X>,
+    ) -> Result<T, DecodeError> {
+        self.track_stack_depth_increase()?;
+        let decoded = T::decode_body_with_value_kind(self, value_kind)?;
+        self.track_stack_depth_decrease()?;
+        Ok(decoded)
+    }
+
+    #[inline]
+    fn read_byte(&mut self) -> Result<u8, DecodeError> {
+        let byte = self.take_lookahead()?;
+        self.offset += 1;
+        self.lookahead = self.source.try_read_byte();
+        Ok(byte)
+    }
+
+    #[inline]
+    fn read_slice(&mut self, n: usize) -> Result<&[u8], DecodeError> {
+        self.read_into_buffer(n)?;
+        Ok(&self.buffer)
+    }
+
+    /// Note - as the total length of the source is unknown, an `ExtraTrailingBytes` error
+    /// only indicates that there is at least one extra byte.
+    #[inline]
+    fn check_end(&self) -> Result<(), DecodeError> {
+        match &self.lookahead {
+            Ok(None) => Ok(()),
+            Ok(Some(_)) => Err(DecodeError::ExtraTrailingBytes(1)),
+            Err(error) => Err(*error),
+        }
+    }
+
+    #[inline]
+    fn peek_remaining(&self) -> &[u8] {
+        match &self.lookahead {
+            Ok(Some(byte)) => slice::from_ref(byte),
+            _ => &[],
+        }
+    }
+
+    #[inline]
+    fn get_depth_limit(&self) -> usize {
+        self.max_depth
+    }
+
+    #[inline]
+    fn get_stack_depth(&self) -> usize {
+        self.stack_depth
+    }
+
+    #[inline]
+    fn get_offset(&self) -> usize {
+        self.offset
+    }
+
+    #[inline]
+    fn peek_byte(&self) -> Result<u8, DecodeError> {
+        match &self.lookahead {
+            Ok(Some(byte)) => Ok(*byte),
+            Ok(None) => Err(DecodeError::BufferUnderflow {
+                required: 1,
+                remaining: 0,
+            }),
+            Err(error) => Err(*error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Eq, BasicSbor)]
+    struct TestStruct {
+        name: String,
+        bytes: Vec<u8>,
+        entries: BTreeMap<u32, Option<i64>>,
+    }
+
+    fn test_struct() -> TestStruct {
+        let mut entries = BTreeMap::new();
+        entries.insert(1, Some(-5));
+        entries.insert(2, None);
+        TestStruct {
+            name: "hello".to_string(),
+            bytes: vec![7u8; 1000],
+            entries,
+        }
+    }
+
+    #[test]
+    pub fn stream_decoder_decodes_same_as_vec_decoder() {
+        let payload = basic_encode(&test_struct()).unwrap();
+
+        let decoded: TestStruct = StreamDecoder::<_, NoCustomValueKind>::new(&payload[..], 64)
+            .decode_payload(BASIC_SBOR_V1_PAYLOAD_PREFIX)
+            .unwrap();
+        assert_eq!(decoded, test_struct());
+
+        let value: BasicValue = StreamDecoder::<_, NoCustomValueKind>::new(&payload[..], 64)
+            .decode_payload(BASIC_SBOR_V1_PAYLOAD_PREFIX)
+            .unwrap();
+        assert_eq!(value, basic_decode::<BasicValue>(&payload).unwrap());
+    }
+
+    #[test]
+    pub fn stream_decoder_detects_truncated_and_trailing_bytes() {
+        let payload = basic_encode(&test_struct()).unwrap();
+
+        let result: Result<TestStruct, _> =
+            StreamDecoder::<_, NoCustomValueKind>::new(&payload[..payload.len() - 1], 64)
+                .decode_payload(BASIC_SBOR_V1_PAYLOAD_PREFIX);
+        assert!(matches!(result, Err(DecodeError::BufferUnderflow { .. })));
+
+        let mut extended = payload.clone();
+        extended.push(0);
+        let result: Result<TestStruct, _> =
+            StreamDecoder::<_, NoCustomValueKind>::new(&extended[..], 64)
+                .decode_payload(BASIC_SBOR_V1_PAYLOAD_PREFIX);
+        assert_eq!(result, Err(DecodeError::ExtraTrailingBytes(1)));
+    }
+
+    #[test]
+    pub fn stream_decoder_does_not_allocate_untrusted_lengths_up_front() {
+        // A string which claims to be ~256MB long, but is followed by only a few bytes
+        let payload = [
+            BASIC_SBOR_V1_PAYLOAD_PREFIX,
+            VALUE_KIND_STRING,
+            0xff,
+            0xff,
+            0xff,
+            0x7f,
+            b'a',
+            b'b',
+        ];
+        let result: Result<String, _> =
+            StreamDecoder::<_, NoCustomValueKind>::new(&payload[..], 64)
+                .decode_payload(BASIC_SBOR_V1_PAYLOAD_PREFIX);
+        assert!(matches!(result, Err(DecodeError::BufferUnderflow { .. })));
+
+        let mut decoder = StreamDecoder::<_, NoCustomValueKind>::new(&payload[..], 64);
+        assert!(decoder.read_into_buffer(0x0fff_ffff).is_err());
+        assert!(decoder.buffer.capacity() <= 2 * READ_CHUNK_SIZE);
+    }
+
+    #[test]
+    pub fn recording_byte_source_records_consumed_bytes() {
+        let payload = basic_encode(&test_struct()).unwrap();
+        let mut recorded = Vec::new();
+        let source = RecordingByteSource::new(&payload[..], &mut recorded);
+        let _: TestStruct = StreamDecoder::<_, NoCustomValueKind>::new(source, 64)
+            .decode_payload(BASIC_SBOR_V1_PAYLOAD_PREFIX)
+            .unwrap();
+        assert_eq!(recorded, payload);
+    }
+}
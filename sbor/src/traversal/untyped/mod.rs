@@ -1,5 +1,7 @@
 mod events;
+mod stream_traverser;
 mod traverser;
 
 pub use events::*;
+pub use stream_traverser::*;
 pub use traverser::*;
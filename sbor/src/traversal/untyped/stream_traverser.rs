@@ -0,0 +1,495 @@
+use super::*;
+use crate::rust::prelude::*;
+use crate::value_kind::*;
+use crate::*;
+
+/// The default maximum size of each [`StreamTraversalEvent::TerminalValueBatch`] chunk.
+pub const DEFAULT_STREAM_BATCH_SIZE: usize = 64 * 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocatedStreamTraversalEvent<'t, C: CustomTraversal, Y: CustomValue<C::CustomValueKind>> {
+    pub location: Location<'t, C>,
+    pub event: StreamTraversalEvent<'t, C, Y>,
+}
+
+/// The streamed equivalent of [`TraversalEvent`].
+///
+/// As the payload is not held in memory, terminal values are returned owned, and byte arrays are
+/// returned in chunks which borrow from the traverser's internal buffer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StreamTraversalEvent<'t, C: CustomTraversal, Y: CustomValue<C::CustomValueKind>> {
+    ContainerStart(ContainerHeader<C>),
+    ContainerEnd(ContainerHeader<C>),
+    /// A non-container value. This is never a `Tuple`, `Enum`, `Array` or `Map`.
+    TerminalValue(Value<C::CustomValueKind, Y>),
+    /// A chunk of a `u8` array. A single array may be split over multiple consecutive chunks.
+    TerminalValueBatch(&'t [u8]),
+    End,
+    DecodeError(DecodeError),
+}
+
+impl<'t, C: CustomTraversal, Y: CustomValue<C::CustomValueKind>> StreamTraversalEvent<'t, C, Y> {
+    pub fn is_error(&self) -> bool {
+        match self {
+            StreamTraversalEvent::DecodeError(_) => true,
+            _ => false,
+        }
+    }
+}
+
+/// The `StreamTraverser` is the [`ByteSource`]-backed equivalent of the [`VecTraverser`].
+///
+/// It turns payload decoding into a pull-based event stream, without needing the full payload
+/// in memory - at most one terminal value, or one chunk of a byte array, is buffered at a time.
+/// This allows validating and traversing very large payloads (eg NFT data or package code).
+///
+/// Custom values are decoded into owned values of type `Y`.
+///
+/// The caller is responsible for stopping calling `next_event` after an Error or End event.
+pub struct StreamTraverser<S: ByteSource, C: CustomTraversal, Y: CustomValue<C::CustomValueKind>> {
+    max_depth: usize,
+    max_batch_size: usize,
+    check_exact_end: bool,
+    decoder: StreamDecoder<S, C::CustomValueKind>,
+    container_stack: Vec<ContainerState<C>>,
+    next_event_override: NextEventOverride<C::CustomValueKind>,
+    phantom: PhantomData<Y>,
+}
+
+impl<S, C, Y> StreamTraverser<S, C, Y>
+where
+    S: ByteSource,
+    C: CustomTraversal,
+    Y: CustomValue<C::CustomValueKind>
+        + Decode<C::CustomValueKind, StreamDecoder<S, C::CustomValueKind>>,
+{
+    pub fn new(
+        source: S,
+        max_depth: usize,
+        expected_start: ExpectedStart<C::CustomValueKind>,
+        check_exact_end: bool,
+    ) -> Self {
+        Self {
+            decoder: StreamDecoder::new(source, max_depth),
+            container_stack: Vec::with_capacity(max_depth),
+            max_depth,
+            max_batch_size: DEFAULT_STREAM_BATCH_SIZE,
+            next_event_override: match expected_start {
+                ExpectedStart::PayloadPrefix(prefix) => NextEventOverride::ReadPrefix(prefix),
+                ExpectedStart::Value => NextEventOverride::ReadRootValue,
+                ExpectedStart::ValueBody(value_kind) => {
+                    NextEventOverride::ReadRootValueWithValueKind(value_kind)
+                }
+            },
+            check_exact_end,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Sets the maximum size of each byte array chunk returned as a `TerminalValueBatch` event.
+    pub fn with_max_batch_size(mut self, max_batch_size: usize) -> Self {
+        self.max_batch_size = max_batch_size.max(1);
+        self
+    }
+
+    pub fn next_event<'t>(&'t mut self) -> LocatedStreamTraversalEvent<'t, C, Y> {
+        match self.next_event_override {
+            NextEventOverride::ReadPrefix(expected_prefix) => {
+                self.next_event_override = NextEventOverride::ReadRootValue;
+                if let Err(error) = self.decoder.read_and_check_payload_prefix(expected_prefix) {
+                    return self.map_error(self.get_offset(), error);
+                }
+                self.next_event()
+            }
+            NextEventOverride::ReadRootValue => {
+                self.next_event_override = NextEventOverride::None;
+                self.read_root_value(None)
+            }
+            NextEventOverride::ReadRootValueWithValueKind(value_kind) => {
+                self.next_event_override = NextEventOverride::None;
+                self.read_root_value(Some(value_kind))
+            }
+            NextEventOverride::ReadBytes(remaining) => self.read_bytes_event_override(remaining),
+            NextEventOverride::None => {
+                let parent = self.container_stack.last();
+                match parent {
+                    Some(parent) => {
+                        if parent.is_complete() {
+                            self.exit_container()
+                        } else {
+                            self.read_child_value()
+                        }
+                    }
+                    None => self.read_end(),
+                }
+            }
+        }
+    }
+
+    fn enter_container<'t>(
+        &'t mut self,
+        start_offset: usize,
+        container_header: ContainerHeader<C>,
+    ) -> LocatedStreamTraversalEvent<'t, C, Y> {
+        let child_count = container_header.get_child_count();
+
+        self.container_stack.push(ContainerState {
+            container_header,
+            container_start_offset: start_offset,
+            container_child_count: child_count,
+            current_child_index: None,
+        });
+
+        // Check depth: either container stack overflows or children of this container will overflow.
+        if self.container_stack.len() > self.max_depth
+            || self.container_stack.len() == self.max_depth && child_count > 0
+        {
+            return self.map_error(start_offset, DecodeError::MaxDepthExceeded(self.max_depth));
+        }
+
+        LocatedStreamTraversalEvent {
+            event: StreamTraversalEvent::ContainerStart(container_header),
+            location: Location {
+                start_offset,
+                end_offset: self.get_offset(),
+                ancestor_path: &self.container_stack[0..self.container_stack.len() - 1],
+            },
+        }
+    }
+
+    fn exit_container<'t>(&'t mut self) -> LocatedStreamTraversalEvent<'t, C, Y> {
+        let container = self.container_stack.pop().unwrap();
+        LocatedStreamTraversalEvent {
+            event: StreamTraversalEvent::ContainerEnd(container.container_header),
+            location: Location {
+                start_offset: container.container_start_offset,
+                end_offset: self.get_offset(),
+                ancestor_path: &self.container_stack,
+            },
+        }
+    }
+
+    fn read_root_value<'t>(
+        &'t mut self,
+        value_kind: Option<ValueKind<C::CustomValueKind>>,
+    ) -> LocatedStreamTraversalEvent<'t, C, Y> {
+        let start_offset = self.get_offset();
+        let value_kind = match value_kind {
+            Some(value_kind) => value_kind,
+            None => match self.decoder.read_value_kind() {
+                Ok(value_kind) => value_kind,
+                Err(error) => return self.map_error(self.get_offset(), error),
+            },
+        };
+        self.next_value(start_offset, value_kind)
+    }
+
+    fn read_child_value<'t>(&'t mut self) -> LocatedStreamTraversalEvent<'t, C, Y> {
+        let start_offset = self.get_offset();
+        let parent = self.container_stack.last_mut().unwrap();
+        parent.advance_current_child_index();
+        let value_kind = parent
+            .container_header
+            .get_implicit_child_value_kind(parent.current_child_index.unwrap());
+        let value_kind = match value_kind {
+            Some(value_kind) => value_kind,
+            None => match self.decoder.read_value_kind() {
+                Ok(value_kind) => value_kind,
+                Err(error) => return self.map_error(self.get_offset(), error),
+            },
+        };
+        self.next_value(start_offset, value_kind)
+    }
+
+    fn next_value<'t>(
+        &'t mut self,
+        start_offset: usize,
+        value_kind: ValueKind<C::CustomValueKind>,
+    ) -> LocatedStreamTraversalEvent<'t, C, Y> {
+        let result = match value_kind {
+            ValueKind::Array => return self.decode_array_header(start_offset),
+            ValueKind::Map => return self.decode_map_header(start_offset),
+            ValueKind::Enum => return self.decode_enum_variant_header(start_offset),
+            ValueKind::Tuple => return self.decode_tuple_header(start_offset),
+            // The value is a terminal value, and it's decoded at the same depth as in the VecTraverser
+            _ => Value::<C::CustomValueKind, Y>::decode_body_with_value_kind(
+                &mut self.decoder,
+                value_kind,
+            ),
+        };
+        match result {
+            Ok(value) => LocatedStreamTraversalEvent {
+                event: StreamTraversalEvent::TerminalValue(value),
+                location: Location {
+                    start_offset,
+                    end_offset: self.get_offset(),
+                    ancestor_path: &self.container_stack,
+                },
+            },
+            Err(error) => self.map_error(start_offset, error),
+        }
+    }
+
+    fn map_error<'t>(
+        &'t self,
+        start_offset: usize,
+        error: DecodeError,
+    ) -> LocatedStreamTraversalEvent<'t, C, Y> {
+        LocatedStreamTraversalEvent {
+            event: StreamTraversalEvent::DecodeError(error),
+            location: Location {
+                start_offset,
+                end_offset: self.get_offset(),
+                ancestor_path: &self.container_stack,
+            },
+        }
+    }
+
+    #[inline]
+    fn get_offset(&self) -> usize {
+        self.decoder.get_offset()
+    }
+
+    fn decode_enum_variant_header<'t>(
+        &'t mut self,
+        start_offset: usize,
+    ) -> LocatedStreamTraversalEvent<'t, C, Y> {
+        let header = self.decoder.read_byte().and_then(|variant| {
+            let length = self.decoder.read_size()?;
+            Ok(EnumVariantHeader { variant, length })
+        });
+        match header {
+            Ok(header) => self.enter_container(start_offset, ContainerHeader::EnumVariant(header)),
+            Err(error) => self.map_error(self.get_offset(), error),
+        }
+    }
+
+    fn decode_tuple_header<'t>(
+        &'t mut self,
+        start_offset: usize,
+    ) -> LocatedStreamTraversalEvent<'t, C, Y> {
+        match self.decoder.read_size() {
+            Ok(length) => {
+                self.enter_container(start_offset, ContainerHeader::Tuple(TupleHeader { length }))
+            }
+            Err(error) => self.map_error(self.get_offset(), error),
+        }
+    }
+
+    fn decode_array_header<'t>(
+        &'t mut self,
+        start_offset: usize,
+    ) -> LocatedStreamTraversalEvent<'t, C, Y> {
+        let header = self
+            .decoder
+            .read_value_kind()
+            .and_then(|element_value_kind| {
+                let length = self.decoder.read_size()?;
+                Ok(ArrayHeader {
+                    element_value_kind,
+                    length,
+                })
+            });
+        match header {
+            Ok(header) => {
+                if header.element_value_kind == ValueKind::U8 && header.length > 0 {
+                    self.next_event_override = NextEventOverride::ReadBytes(header.length);
+                }
+                self.enter_container(start_offset, ContainerHeader::Array(header))
+            }
+            Err(error) => self.map_error(self.get_offset(), error),
+        }
+    }
+
+    fn decode_map_header<'t>(
+        &'t mut self,
+        start_offset: usize,
+    ) -> LocatedStreamTraversalEvent<'t, C, Y> {
+        let header = self.decoder.read_value_kind().and_then(|key_value_kind| {
+            let value_value_kind = self.decoder.read_value_kind()?;
+            let length = self.decoder.read_size()?;
+            Ok(MapHeader {
+                key_value_kind,
+                value_value_kind,
+                length,
+            })
+        });
+        match header {
+            Ok(header) => self.enter_container(start_offset, ContainerHeader::Map(header)),
+            Err(error) => self.map_error(self.get_offset(), error),
+        }
+    }
+
+    fn read_end<'t>(&'t self) -> LocatedStreamTraversalEvent<'t, C, Y> {
+        if self.check_exact_end {
+            if let Err(error) = self.decoder.check_end() {
+                return self.map_error(self.get_offset(), error);
+            }
+        }
+        let offset = self.get_offset();
+
+        LocatedStreamTraversalEvent {
+            event: StreamTraversalEvent::End,
+            location: Location {
+                start_offset: offset,
+                end_offset: offset,
+                ancestor_path: &self.container_stack,
+            },
+        }
+    }
+
+    fn read_bytes_event_override<'t>(
+        &'t mut self,
+        remaining: usize,
+    ) -> LocatedStreamTraversalEvent<'t, C, Y> {
+        let start_offset = self.get_offset();
+        let chunk_size = remaining.min(self.max_batch_size);
+        if let Err(error) = self.decoder.read_into_buffer(chunk_size) {
+            return self.map_error(start_offset, error);
+        }
+        // Set it up so that we either read the next chunk, or jump to the end of the child iteration
+        self.container_stack
+            .last_mut()
+            .unwrap()
+            .advance_current_child_index_by(chunk_size);
+        self.next_event_override = if remaining > chunk_size {
+            NextEventOverride::ReadBytes(remaining - chunk_size)
+        } else {
+            NextEventOverride::None
+        };
+        LocatedStreamTraversalEvent {
+            event: StreamTraversalEvent::TerminalValueBatch(self.decoder.buffered_bytes()),
+            location: Location {
+                start_offset,
+                end_offset: self.get_offset(),
+                ancestor_path: &self.container_stack,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type BasicStreamTraverser<'a> = StreamTraverser<&'a [u8], NoCustomTraversal, NoCustomValue>;
+
+    #[derive(Categorize, Encode)]
+    struct TestStruct {
+        name: String,
+        blob: Vec<u8>,
+        entries: BTreeMap<u16, (bool, i32)>,
+    }
+
+    fn test_payload() -> Vec<u8> {
+        let mut entries = BTreeMap::new();
+        entries.insert(3, (true, -1));
+        basic_encode(&TestStruct {
+            name: "blob".to_string(),
+            blob: (0..=255u8).cycle().take(1000).collect(),
+            entries,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    pub fn stream_traverser_matches_vec_traverser() {
+        let payload = test_payload();
+        let mut vec_traverser = BasicTraverser::new(
+            &payload,
+            64,
+            ExpectedStart::PayloadPrefix(BASIC_SBOR_V1_PAYLOAD_PREFIX),
+            true,
+        );
+        let mut stream_traverser = BasicStreamTraverser::new(
+            &payload[..],
+            64,
+            ExpectedStart::PayloadPrefix(BASIC_SBOR_V1_PAYLOAD_PREFIX),
+            true,
+        );
+        loop {
+            let expected = vec_traverser.next_event();
+            let actual = stream_traverser.next_event();
+            assert_eq!(expected.location.start_offset, actual.location.start_offset);
+            assert_eq!(expected.location.end_offset, actual.location.end_offset);
+            match (expected.event, actual.event) {
+                (TraversalEvent::ContainerStart(a), StreamTraversalEvent::ContainerStart(b))
+                | (TraversalEvent::ContainerEnd(a), StreamTraversalEvent::ContainerEnd(b)) => {
+                    assert_eq!(a, b)
+                }
+                (
+                    TraversalEvent::TerminalValue(TerminalValueRef::String(a)),
+                    StreamTraversalEvent::TerminalValue(Value::String { value: b }),
+                ) => assert_eq!(a, b),
+                (
+                    TraversalEvent::TerminalValue(TerminalValueRef::U16(a)),
+                    StreamTraversalEvent::TerminalValue(Value::U16 { value: b }),
+                ) => assert_eq!(a, b),
+                (
+                    TraversalEvent::TerminalValue(TerminalValueRef::Bool(a)),
+                    StreamTraversalEvent::TerminalValue(Value::Bool { value: b }),
+                ) => assert_eq!(a, b),
+                (
+                    TraversalEvent::TerminalValue(TerminalValueRef::I32(a)),
+                    StreamTraversalEvent::TerminalValue(Value::I32 { value: b }),
+                ) => assert_eq!(a, b),
+                (
+                    TraversalEvent::TerminalValueBatch(TerminalValueBatchRef::U8(a)),
+                    StreamTraversalEvent::TerminalValueBatch(b),
+                ) => assert_eq!(a, b),
+                (TraversalEvent::End, StreamTraversalEvent::End) => break,
+                (a, b) => panic!("Mismatched events: {:?} vs {:?}", a, b),
+            }
+        }
+    }
+
+    #[test]
+    pub fn stream_traverser_chunks_byte_arrays() {
+        let payload = test_payload();
+        let mut traverser = BasicStreamTraverser::new(
+            &payload[..],
+            64,
+            ExpectedStart::PayloadPrefix(BASIC_SBOR_V1_PAYLOAD_PREFIX),
+            true,
+        )
+        .with_max_batch_size(300);
+        let mut chunk_sizes = vec![];
+        let mut blob = vec![];
+        loop {
+            let event = traverser.next_event();
+            match event.event {
+                StreamTraversalEvent::TerminalValueBatch(bytes) => {
+                    chunk_sizes.push(bytes.len());
+                    blob.extend_from_slice(bytes);
+                }
+                StreamTraversalEvent::End => break,
+                StreamTraversalEvent::DecodeError(error) => panic!("{:?}", error),
+                _ => {}
+            }
+        }
+        assert_eq!(chunk_sizes, vec![300, 300, 300, 100]);
+        assert_eq!(blob, (0..=255u8).cycle().take(1000).collect::<Vec<_>>());
+    }
+
+    #[test]
+    pub fn stream_traverser_reports_truncated_payload() {
+        let payload = test_payload();
+        let mut traverser = BasicStreamTraverser::new(
+            &payload[..payload.len() - 2],
+            64,
+            ExpectedStart::PayloadPrefix(BASIC_SBOR_V1_PAYLOAD_PREFIX),
+            true,
+        );
+        loop {
+            let event = traverser.next_event();
+            match event.event {
+                StreamTraversalEvent::DecodeError(error) => {
+                    assert!(matches!(error, DecodeError::BufferUnderflow { .. }));
+                    break;
+                }
+                StreamTraversalEvent::End => panic!("Expected a decode error"),
+                _ => {}
+            }
+        }
+    }
+}
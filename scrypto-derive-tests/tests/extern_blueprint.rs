@@ -0,0 +1,243 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(unused_imports)]
+
+use scrypto::prelude::*;
+use std::env;
+
+#[derive(ScryptoSbor, Clone)]
+pub struct Config {
+    name: String,
+    owner: Option<NonFungibleGlobalId>,
+    rule: AccessRule,
+    limits: Vec<Limit>,
+}
+
+#[derive(ScryptoSbor, Clone)]
+pub struct Limit(Decimal, u32);
+
+#[derive(ScryptoSbor, Clone)]
+pub enum Status {
+    #[sbor(discriminator(0))]
+    Active,
+    #[sbor(discriminator(1))]
+    Paused { until: Instant },
+    #[sbor(discriminator(5))]
+    Closed(String),
+}
+
+#[derive(ScryptoSbor)]
+pub struct Tree {
+    value: u32,
+    children: Vec<Tree>,
+}
+
+#[derive(ScryptoSbor)]
+pub struct Labelled<T> {
+    label: String,
+    value: T,
+}
+
+// The schema in `tests/schemas/extern_target.rpd` is that of this blueprint, and must be
+// regenerated whenever it changes.
+#[blueprint]
+mod extern_target {
+    struct ExternTarget {
+        config: Config,
+        status: Status,
+    }
+
+    impl ExternTarget {
+        pub fn instantiate(config: Config) -> Global<ExternTarget> {
+            Self {
+                config,
+                status: Status::Active,
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .globalize()
+        }
+
+        pub fn status(&self) -> Status {
+            self.status.clone()
+        }
+
+        pub fn set_status(&mut self, status: Status) {
+            self.status = status;
+        }
+
+        pub fn limits(&self) -> Vec<Limit> {
+            self.config.limits.clone()
+        }
+
+        pub fn tree(&self) -> Tree {
+            Tree {
+                value: 0,
+                children: vec![],
+            }
+        }
+
+        pub fn labelled_count(&self) -> Labelled<u32> {
+            Labelled {
+                label: self.config.name.clone(),
+                value: 0,
+            }
+        }
+
+        pub fn labelled_amount(&self) -> Labelled<Decimal> {
+            Labelled {
+                label: self.config.name.clone(),
+                value: Decimal::ZERO,
+            }
+        }
+
+        pub fn check(
+            &self,
+            rule: AccessRule,
+            id: NonFungibleGlobalId,
+            url: UncheckedUrl,
+        ) -> Result<u64, String> {
+            let _ = (rule, id, url);
+            Ok(0)
+        }
+    }
+}
+
+#[blueprint]
+mod extern_caller {
+    extern_blueprint_from_schema!(
+        "package_sim1p4r4955skdjq9swg8s5jguvcjvyj7tsxct87a9z6sw76cdfd2jg3zk",
+        "tests/schemas/extern_target.rpd",
+        ExternTarget
+    );
+
+    struct ExternCaller {}
+
+    impl ExternCaller {
+        pub fn instantiate(config: extern_target_types::Config) -> Global<ExternTarget> {
+            Blueprint::<ExternTarget>::instantiate(config)
+        }
+
+        pub fn pause(mut target: Global<ExternTarget>, until: Instant) {
+            target.set_status(extern_target_types::Status::Paused { until });
+        }
+
+        pub fn is_closed(target: Global<ExternTarget>) -> bool {
+            matches!(target.status(), extern_target_types::Status::Closed(_))
+        }
+
+        pub fn total_limit(target: Global<ExternTarget>) -> Decimal {
+            target
+                .limits()
+                .into_iter()
+                .fold(Decimal::ZERO, |total, limit| total + limit.0)
+        }
+
+        pub fn leaf_count(target: Global<ExternTarget>) -> usize {
+            target.tree().children.len()
+        }
+
+        pub fn labels(target: Global<ExternTarget>) -> (String, ScryptoValue) {
+            (target.labelled_count().label, target.labelled_amount())
+        }
+
+        pub fn check(
+            target: Global<ExternTarget>,
+            rule: AccessRule,
+            id: NonFungibleGlobalId,
+        ) -> Result<u64, String> {
+            target.check(rule, id, UncheckedUrl::of("https://example.com"))
+        }
+    }
+}
+
+fn assert_same_encoding<A: ScryptoEncode, B: ScryptoEncode>(generated: &A, original: &B) {
+    assert_eq!(
+        scrypto_encode(generated).unwrap(),
+        scrypto_encode(original).unwrap()
+    );
+}
+
+#[test]
+fn test_extern_blueprint_from_schema_generates_named_structs() {
+    use extern_caller::extern_target_types;
+
+    let id = NonFungibleGlobalId::new(
+        ResourceAddress::new_or_panic([EntityType::GlobalNonFungibleResourceManager as u8; 30]),
+        NonFungibleLocalId::integer(1),
+    );
+    let generated = extern_target_types::Config {
+        name: "target".to_owned(),
+        owner: Some(id.clone()),
+        rule: rule!(allow_all),
+        limits: vec![extern_target_types::Limit(dec!(1), 2)],
+    };
+    let original = Config {
+        name: "target".to_owned(),
+        owner: Some(id),
+        rule: rule!(allow_all),
+        limits: vec![Limit(dec!(1), 2)],
+    };
+    assert_same_encoding(&generated, &original);
+}
+
+#[test]
+fn test_extern_blueprint_from_schema_generates_enums_with_their_discriminators() {
+    use extern_caller::extern_target_types::Status as GeneratedStatus;
+
+    assert_same_encoding(&GeneratedStatus::Active, &Status::Active);
+    assert_same_encoding(
+        &GeneratedStatus::Paused {
+            until: Instant::new(1),
+        },
+        &Status::Paused {
+            until: Instant::new(1),
+        },
+    );
+    assert_same_encoding(
+        &GeneratedStatus::Closed("closed".to_owned()),
+        &Status::Closed("closed".to_owned()),
+    );
+}
+
+#[test]
+fn test_extern_blueprint_from_schema_generates_recursive_types() {
+    use extern_caller::extern_target_types;
+
+    let generated = extern_target_types::Tree {
+        value: 1,
+        children: vec![Box::new(extern_target_types::Tree {
+            value: 2,
+            children: vec![],
+        })],
+    };
+    let original = Tree {
+        value: 1,
+        children: vec![Tree {
+            value: 2,
+            children: vec![],
+        }],
+    };
+    assert_same_encoding(&generated, &original);
+}
+
+#[test]
+fn test_extern_blueprint_from_schema_try_compile() {
+    // Change CARGO_MANIFEST_DIR to tests/extern_blueprint_from_schema, where the test crate is
+    // located, as 'trybuild' doesn't carry over the features of this crate.
+    let manifest_dir = env::current_dir()
+        .unwrap()
+        .join("tests/extern_blueprint_from_schema");
+    env::set_var("CARGO_MANIFEST_DIR", &manifest_dir);
+
+    // Also change the current dir to the 'extern_blueprint_from_schema' dir.
+    // Otherwise 'trybuild' will not be able to find files to compile.
+    assert!(env::set_current_dir(manifest_dir).is_ok());
+
+    let t = trybuild::TestCases::new();
+
+    // Paths must be relative to the manifest_dir, whereas the schema paths within the test cases
+    // are relative to the 'target/tests/trybuild/extern_blueprint_from_schema' dir of its build.
+    t.compile_fail("src/*_schema_file.rs");
+    t.compile_fail("src/missing_blueprint.rs");
+    t.compile_fail("src/mismatched_argument_type.rs");
+}
@@ -0,0 +1,25 @@
+use scrypto::prelude::*;
+
+#[derive(ScryptoSbor)]
+pub enum Status {
+    Active,
+}
+
+#[blueprint]
+mod caller {
+    extern_blueprint_from_schema!(
+        "package_sim1p4r4955skdjq9swg8s5jguvcjvyj7tsxct87a9z6sw76cdfd2jg3zk",
+        "../../../../../schemas/extern_target.rpd",
+        ExternTarget
+    );
+
+    struct Caller {}
+
+    impl Caller {
+        pub fn activate(mut target: Global<ExternTarget>) {
+            target.set_status(Status::Active);
+        }
+    }
+}
+
+fn main() {}
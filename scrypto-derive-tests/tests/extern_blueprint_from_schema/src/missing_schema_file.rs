@@ -0,0 +1,16 @@
+use scrypto::prelude::*;
+
+#[blueprint]
+mod caller {
+    extern_blueprint_from_schema!(
+        "package_sim1p4r4955skdjq9swg8s5jguvcjvyj7tsxct87a9z6sw76cdfd2jg3zk",
+        "schemas/missing.rpd",
+        ExternTarget
+    );
+
+    struct Caller {}
+
+    impl Caller {}
+}
+
+fn main() {}
@@ -104,6 +104,36 @@ impl Parse for EventsInner {
     }
 }
 
+pub struct FeaturesInner {
+    pub paren_token: Paren,
+    pub features: Punctuated<Ident, Token![,]>,
+}
+
+impl Parse for FeaturesInner {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        Ok(Self {
+            paren_token: parenthesized!(content in input),
+            features: content.parse_terminated(Ident::parse)?,
+        })
+    }
+}
+
+pub struct OuterBlueprintInner {
+    pub paren_token: Paren,
+    pub outer_blueprint: Ident,
+}
+
+impl Parse for OuterBlueprintInner {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        Ok(Self {
+            paren_token: parenthesized!(content in input),
+            outer_blueprint: content.parse()?,
+        })
+    }
+}
+
 pub struct TypesInner {
     pub paren_token: Paren,
     pub aliasable_types: Punctuated<AliasableType, Token![,]>,
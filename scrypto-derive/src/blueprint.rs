@@ -8,6 +8,8 @@ use std::str::FromStr;
 use syn::parse::{Parse, ParseStream, Parser};
 use syn::spanned::Spanned;
 use syn::token::{Brace, Comma};
+#[cfg(not(feature = "no-schema"))]
+use syn::visit::Visit;
 use syn::*;
 
 macro_rules! trace {
@@ -90,6 +92,39 @@ impl Parse for ImportBlueprint {
     }
 }
 
+pub struct ImportBlueprintFromSchema {
+    pub package: Expr,
+    pub comma0: Comma,
+    pub path: LitStr,
+    pub comma1: Comma,
+    pub blueprint: Ident,
+    pub rename: Option<(Token![as], Ident)>,
+}
+
+impl Parse for ImportBlueprintFromSchema {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let package = input.parse()?;
+        let comma0 = input.parse()?;
+        let path = input.parse()?;
+        let comma1 = input.parse()?;
+        let blueprint = input.parse()?;
+        let rename = if input.peek(Token![as]) {
+            Some((input.parse()?, input.parse()?))
+        } else {
+            None
+        };
+
+        Ok(Self {
+            package,
+            comma0,
+            path,
+            comma1,
+            blueprint,
+            rename,
+        })
+    }
+}
+
 pub fn replace_macros_in_body(block: &mut Block, dependency_exprs: &mut Vec<Expr>) -> Result<()> {
     for stmt in &mut block.stmts {
         match stmt {
@@ -223,6 +258,35 @@ pub fn replace_macros(expr: &mut Expr, dependency_exprs: &mut Vec<Expr>) -> Resu
     Ok(())
 }
 
+/// Finds the event types emitted with `emit!(EventType { .. })` within the blueprint impl.
+///
+/// Only struct expressions named by a single identifier are picked up. A qualified path such as
+/// `a::B { .. }` could equally be a struct in a module or a variant of an enum, which can't be
+/// told apart syntactically - so those events still need to be listed in `#[events(...)]`.
+#[cfg(not(feature = "no-schema"))]
+fn find_emitted_event_paths(bp_impl: &ItemImpl) -> Vec<Path> {
+    struct EmittedEventsVisitor {
+        paths: Vec<Path>,
+    }
+
+    impl<'ast> Visit<'ast> for EmittedEventsVisitor {
+        fn visit_macro(&mut self, mac: &'ast Macro) {
+            if mac.path.is_ident("emit") {
+                if let Ok(Expr::Struct(expr_struct)) = mac.parse_body::<Expr>() {
+                    if expr_struct.path.get_ident().is_some() {
+                        self.paths.push(expr_struct.path);
+                    }
+                }
+            }
+            visit::visit_macro(self, mac);
+        }
+    }
+
+    let mut visitor = EmittedEventsVisitor { paths: Vec::new() };
+    visitor.visit_item_impl(bp_impl);
+    visitor.paths
+}
+
 /// Derive a sensible type identifier from a path, so to pass Radix Engine's constraints.
 pub fn derive_sensible_identifier_from_path(path: &Path) -> Result<String> {
     if let Some(segment) = path.segments.last() {
@@ -261,12 +325,16 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
     // parse blueprint struct and impl
     let blueprint = parse2::<ast::Blueprint>(input)?;
     let mut bp = blueprint.module;
+    #[cfg(not(feature = "no-schema"))]
+    let emitted_event_paths = find_emitted_event_paths(&bp.implementation);
     let bp_strut = &bp.structure;
     let bp_fields = &bp_strut.fields;
     let bp_semi_token = &bp_strut.semi_token;
     let bp_impl = &mut bp.implementation;
     let bp_ident = &bp_strut.ident;
     validate_type_ident(&bp_ident)?;
+    let (transient_members, transient_types): (Vec<Member>, Vec<Type>) =
+        find_transient_fields(bp_fields).into_iter().unzip();
     let bp_items = &mut bp_impl.items;
     let bp_name = bp_ident.to_string();
 
@@ -415,42 +483,105 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
         let mut import_statements = Vec::new();
         loop {
             let import_blueprint_index = macro_statements.iter().position(|item| {
-                item.mac
-                    .path
-                    .get_ident()
-                    .unwrap()
-                    .eq(&Ident::new("extern_blueprint", Span::call_site()))
+                let ident = item.mac.path.get_ident().unwrap();
+                ident.eq(&Ident::new("extern_blueprint", Span::call_site()))
+                    || ident.eq(&Ident::new(
+                        "extern_blueprint_from_schema",
+                        Span::call_site(),
+                    ))
             });
             if let Some(import_blueprint_index) = import_blueprint_index {
                 let import_macro = macro_statements.remove(import_blueprint_index);
-                let import_blueprint: ImportBlueprint = import_macro.mac.parse_body()?;
-
-                let package_expr = {
-                    let package = import_blueprint.package;
-                    match package {
-                        Expr::Lit(..) => {
-                            let lit_str: LitStr = parse_quote!( #package );
-                            let (_hrp, _entity_type, address) =
-                                AddressBech32Decoder::validate_and_decode_ignore_hrp(
-                                    lit_str.value().as_str(),
-                                )
-                                .unwrap();
-                            let package_expr: Expr = parse_quote! {
-                                PackageAddress :: new_or_panic([ #(#address),* ])
-                            };
-                            package_expr
-                        }
-                        _ => package,
+                let from_schema = import_macro.mac.path.get_ident().unwrap().eq(&Ident::new(
+                    "extern_blueprint_from_schema",
+                    Span::call_site(),
+                ));
+
+                // The signatures are either declared inline, or generated from the blueprint
+                // schema within a package definition file.
+                let (package, blueprint_ident, rename, signatures, schema_file, schema_types) =
+                    if from_schema {
+                        let import_blueprint: ImportBlueprintFromSchema =
+                            import_macro.mac.parse_body()?;
+                        let schema_file = crate::extern_schema::read_blueprint_schema(
+                            &import_blueprint.path,
+                            import_blueprint.blueprint.to_string().as_str(),
+                        )?;
+                        let blueprint = match &import_blueprint.rename {
+                            Some((_, rename)) => rename,
+                            None => &import_blueprint.blueprint,
+                        };
+                        let types_module = crate::extern_schema::types_module_ident(blueprint);
+                        let stubs = crate::extern_schema::blueprint_stubs(
+                            &schema_file.schema,
+                            import_blueprint.blueprint.to_string().as_str(),
+                            blueprint,
+                            &types_module,
+                            import_blueprint.path.span(),
+                        )?;
+                        let schema_types = (!stubs.type_definitions.is_empty()).then(|| {
+                            let type_definitions = stubs.type_definitions;
+                            let doc = format!(
+                                "The named types of the {} blueprint, generated from its schema.",
+                                import_blueprint.blueprint
+                            );
+                            quote! {
+                                #[doc = #doc]
+                                pub mod #types_module {
+                                    use super::*;
+
+                                    #(#type_definitions)*
+                                }
+                            }
+                        });
+                        (
+                            import_blueprint.package,
+                            import_blueprint.blueprint,
+                            import_blueprint.rename,
+                            stubs.signatures,
+                            Some(schema_file.path),
+                            schema_types,
+                        )
+                    } else {
+                        let import_blueprint: ImportBlueprint = import_macro.mac.parse_body()?;
+                        let signatures = import_blueprint
+                            .functions
+                            .into_iter()
+                            .map(|function| function.sig)
+                            .collect();
+                        (
+                            import_blueprint.package,
+                            import_blueprint.blueprint,
+                            import_blueprint.rename,
+                            signatures,
+                            None,
+                            None,
+                        )
+                    };
+
+                let package_expr = match package {
+                    Expr::Lit(..) => {
+                        let lit_str: LitStr = parse_quote!( #package );
+                        let (_hrp, _entity_type, address) =
+                            AddressBech32Decoder::validate_and_decode_ignore_hrp(
+                                lit_str.value().as_str(),
+                            )
+                            .unwrap();
+                        let package_expr: Expr = parse_quote! {
+                            PackageAddress :: new_or_panic([ #(#address),* ])
+                        };
+                        package_expr
                     }
+                    _ => package,
                 };
 
                 dependency_exprs.push(package_expr.clone());
 
-                let blueprint_name = import_blueprint.blueprint.to_string();
-                let blueprint = if let Some((_, rename)) = import_blueprint.rename {
+                let blueprint_name = blueprint_ident.to_string();
+                let blueprint = if let Some((_, rename)) = rename {
                     rename
                 } else {
-                    import_blueprint.blueprint
+                    blueprint_ident
                 };
 
                 let owned_typed_name = format!("Owned{}", blueprint.to_string());
@@ -459,21 +590,29 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
 
                 let mut methods = Vec::new();
                 let mut functions = Vec::new();
-                for function in import_blueprint.functions {
-                    let is_method = function
-                        .sig
+                for sig in signatures {
+                    let is_method = sig
                         .inputs
                         .iter()
                         .find(|arg| matches!(arg, FnArg::Receiver(..)))
                         .is_some();
                     if is_method {
-                        methods.push(function.sig);
+                        methods.push(sig);
                     } else {
-                        functions.push(function.sig);
+                        functions.push(sig);
                     }
                 }
 
+                // Including the schema file ensures the blueprint is rebuilt when it changes.
+                let schema_file_include = schema_file.map(|path| {
+                    quote! {
+                        const _: &[u8] = include_bytes!(#path);
+                    }
+                });
+
                 let import_statement = quote! {
+                    #schema_file_include
+                    #schema_types
                     extern_blueprint_internal! {
                         #package_expr,
                         #blueprint,
@@ -738,9 +877,18 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
         let fn_names = generated_schema_info.fn_names;
         let fn_schemas = generated_schema_info.fn_schemas;
 
-        // Getting the event types and other named types from attribute
-        let (event_type_names, event_type_paths, registered_type_names, registered_type_paths) = {
+        // Getting the event types, other named types, features and outer blueprint from attribute
+        let (
+            event_type_names,
+            event_type_paths,
+            registered_type_names,
+            registered_type_paths,
+            feature_names,
+            outer_blueprint,
+        ) = {
             let mut event_type_paths = BTreeMap::<String, Path>::new();
+            let mut feature_names = Vec::<String>::new();
+            let mut outer_blueprint = None::<String>;
             for attribute in blueprint.attributes {
                 if attribute.path.is_ident("events") {
                     let events_inner = parse2::<ast::EventsInner>(attribute.tokens.clone())?;
@@ -754,6 +902,28 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
                         }
                     }
                 } else if attribute.path.is_ident("types") {
+                } else if attribute.path.is_ident("features") {
+                    let features_inner = parse2::<ast::FeaturesInner>(attribute.tokens.clone())?;
+                    for feature in features_inner.features.iter() {
+                        let feature_name = feature.to_string();
+                        if feature_names.contains(&feature_name) {
+                            return Err(Error::new(
+                                feature.span(),
+                                "A feature with an identical name has already been named",
+                            ));
+                        }
+                        feature_names.push(feature_name);
+                    }
+                } else if attribute.path.is_ident("outer_blueprint") {
+                    let outer_blueprint_inner =
+                        parse2::<ast::OuterBlueprintInner>(attribute.tokens.clone())?;
+                    if outer_blueprint.is_some() {
+                        return Err(Error::new(
+                            attribute.path.span(),
+                            "The outer blueprint has already been named",
+                        ));
+                    }
+                    outer_blueprint = Some(outer_blueprint_inner.outer_blueprint.to_string());
                 }
                 // None of the attributes to apply at the top-level of blueprint macros matched. So,
                 // we provide an error to the user that they're using an incorrect attribute macro
@@ -765,6 +935,11 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
                     ));
                 }
             }
+            // Events emitted with `emit!(EventType { .. })` don't need to be listed explicitly
+            for path in emitted_event_paths {
+                let type_name = derive_sensible_identifier_from_path(&path)?;
+                event_type_paths.entry(type_name).or_insert(path);
+            }
             (
                 event_type_paths
                     .keys()
@@ -786,9 +961,22 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
                     .into_iter()
                     .cloned()
                     .collect::<Vec<_>>(),
+                feature_names,
+                outer_blueprint,
             )
         };
 
+        let blueprint_type = match outer_blueprint {
+            Some(outer_blueprint) => quote! {
+                scrypto::blueprints::package::BlueprintType::Inner {
+                    outer_blueprint: #outer_blueprint.to_owned(),
+                }
+            },
+            None => quote! {
+                scrypto::blueprints::package::BlueprintType::default()
+            },
+        };
+
         quote! {
             #function_auth_statements
 
@@ -808,6 +996,10 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
                     let mut fields = Vec::new();
                     let type_index = aggregator.add_child_type_and_descendents::<#bp_ident>();
                     fields.push(FieldSchema::static_field(type_index));
+                    #(
+                        let type_index = aggregator.add_child_type_and_descendents::<#transient_types>();
+                        fields.push(FieldSchema::transient_field(type_index, <#transient_types as Default>::default()));
+                    )*
 
                     let state = BlueprintStateSchemaInit {
                         fields,
@@ -868,6 +1060,11 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
                     dependencies.insert(#dependency_exprs.into());
                 })*
 
+                let mut feature_set = index_set_new();
+                #({
+                    feature_set.insert(#feature_names.to_owned());
+                })*
+
                 let auth_config = {
                     scrypto::blueprints::package::AuthConfig {
                         method_auth: method_auth_template(),
@@ -878,9 +1075,9 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
                 let royalty_config = package_royalty_config();
 
                 let return_data = scrypto::blueprints::package::BlueprintDefinitionInit {
-                    blueprint_type: scrypto::blueprints::package::BlueprintType::default(),
+                    blueprint_type: #blueprint_type,
                     is_transient: false,
-                    feature_set: IndexSet::default(),
+                    feature_set,
                     dependencies,
                     schema,
                     auth_config,
@@ -892,6 +1089,22 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
         }
     };
 
+    let init_transient_fields = if transient_members.is_empty() {
+        quote! {}
+    } else {
+        let transient_indices = (1..=transient_members.len()).map(|index| index as u8);
+        quote! {
+            fn init_transient_fields(&mut self) -> ::sbor::rust::vec::Vec<::scrypto::prelude::FieldValue> {
+                let mut values = ::sbor::rust::vec::Vec::new();
+                #(
+                    self.#transient_members.init(#transient_indices);
+                    values.push(::scrypto::prelude::FieldValue::new(<#transient_types as Default>::default()));
+                )*
+                values
+            }
+        }
+    };
+
     let output_original_code = quote! {
         #[derive(::scrypto::prelude::ScryptoSbor)]
         pub struct #bp_ident #bp_fields #bp_semi_token
@@ -902,6 +1115,8 @@ pub fn handle_blueprint(input: TokenStream) -> Result<TokenStream> {
 
         impl ::scrypto::component::ComponentState for #bp_ident {
             const BLUEPRINT_NAME: &'static str = #bp_name;
+
+            #init_transient_fields
         }
 
         impl HasStub for #bp_ident {
@@ -1389,6 +1604,33 @@ fn create_argument_ident(argument: &Pat, index: usize) -> Result<Ident> {
     })
 }
 
+/// Returns the fields of the blueprint struct of type `Transient<T>`, along with their `T`.
+fn find_transient_fields(fields: &Fields) -> Vec<(Member, Type)> {
+    let mut transient_fields = Vec::new();
+    for (index, field) in fields.iter().enumerate() {
+        let segment = match &field.ty {
+            Type::Path(type_path) => match type_path.path.segments.last() {
+                Some(segment) if segment.ident == "Transient" => segment,
+                _ => continue,
+            },
+            _ => continue,
+        };
+        let value_type = match &segment.arguments {
+            PathArguments::AngleBracketed(arguments) => match arguments.args.first() {
+                Some(GenericArgument::Type(value_type)) => value_type.clone(),
+                _ => continue,
+            },
+            _ => continue,
+        };
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(Index::from(index)),
+        };
+        transient_fields.push((member, value_type));
+    }
+    transient_fields
+}
+
 fn validate_type_ident(ident: &Ident) -> Result<()> {
     validate_type_name(&ident.to_string(), ident.span())
 }
@@ -2101,6 +2343,8 @@ mod tests {
 
                         let mut dependencies = index_set_new();
 
+                        let mut feature_set = index_set_new();
+
                         let auth_config = {
                             scrypto::blueprints::package::AuthConfig {
                                 method_auth: method_auth_template(),
@@ -2113,7 +2357,7 @@ mod tests {
                         let return_data = scrypto::blueprints::package::BlueprintDefinitionInit {
                             blueprint_type: scrypto::blueprints::package::BlueprintType::default(),
                             is_transient: false,
-                            feature_set: IndexSet::default(),
+                            feature_set,
                             dependencies,
                             schema,
                             auth_config,
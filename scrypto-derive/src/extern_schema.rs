@@ -0,0 +1,509 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use radix_engine_common::prelude::*;
+use scrypto_schema::*;
+use std::path::PathBuf;
+use syn::{parse_quote, parse_str, Error, Ident, LitStr, Result, Signature};
+
+/// The index of the `schema` field of `BlueprintDefinitionInit`.
+const BLUEPRINT_DEFINITION_SCHEMA_FIELD_INDEX: usize = 4;
+
+/// Types nested deeper than this are mapped to `ScryptoValue`.
+const MAX_TYPE_DEPTH: usize = 16;
+
+pub struct BlueprintSchemaFile {
+    /// The absolute path of the package definition file.
+    pub path: String,
+    pub schema: BlueprintSchemaInit,
+}
+
+/// Reads the schema of a blueprint from a package definition (`.rpd`) file, as output by
+/// `scrypto build`. The path is resolved relative to the manifest directory of the crate
+/// being compiled.
+pub fn read_blueprint_schema(path: &LitStr, blueprint_name: &str) -> Result<BlueprintSchemaFile> {
+    let error = |message: String| Error::new(path.span(), message);
+
+    let mut full_path = std::env::var("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_default();
+    full_path.push(path.value());
+
+    let bytes = std::fs::read(&full_path)
+        .map_err(|err| error(format!("Failed to read {}: {}", path.value(), err)))?;
+
+    // `radix-engine-interface` is not a dependency of this crate, so the `PackageDefinition`
+    // is navigated as a manifest value, down to the `BlueprintSchemaInit` of the blueprint.
+    let definition: ManifestValue = manifest_decode(&bytes)
+        .map_err(|err| error(format!("Failed to decode package definition: {:?}", err)))?;
+    let blueprints = match definition {
+        ManifestValue::Tuple { fields } => fields.into_iter().next(),
+        _ => None,
+    };
+    let entries = match blueprints {
+        Some(ManifestValue::Map { entries, .. }) => entries,
+        _ => return Err(error("Invalid package definition".to_owned())),
+    };
+    let blueprint_definition = entries
+        .into_iter()
+        .find_map(|(key, value)| match key {
+            ManifestValue::String { value: name } if name == blueprint_name => Some(value),
+            _ => None,
+        })
+        .ok_or_else(|| {
+            error(format!(
+                "Blueprint {} not found in package definition",
+                blueprint_name
+            ))
+        })?;
+    let schema = match blueprint_definition {
+        ManifestValue::Tuple { mut fields }
+            if fields.len() > BLUEPRINT_DEFINITION_SCHEMA_FIELD_INDEX =>
+        {
+            fields.swap_remove(BLUEPRINT_DEFINITION_SCHEMA_FIELD_INDEX)
+        }
+        _ => return Err(error("Invalid blueprint definition".to_owned())),
+    };
+    let schema = manifest_encode(&schema)
+        .ok()
+        .and_then(|bytes| manifest_decode::<BlueprintSchemaInit>(&bytes).ok())
+        .ok_or_else(|| error("Invalid blueprint schema".to_owned()))?;
+
+    Ok(BlueprintSchemaFile {
+        path: full_path.to_string_lossy().into_owned(),
+        schema,
+    })
+}
+
+pub struct BlueprintStubs {
+    /// The signatures of the functions and methods, in the form accepted by `extern_blueprint!`.
+    pub signatures: Vec<Signature>,
+    /// The definitions of the named types of the schema which are referenced by the signatures,
+    /// to be output within the types module.
+    pub type_definitions: Vec<TokenStream>,
+}
+
+/// Generates the stubs of the functions and methods of a blueprint, whose stub is `blueprint`.
+///
+/// Named structs and enums of the blueprint schema are generated as types of the given module,
+/// whereas the well-known ones are mapped to their Scrypto types.
+pub fn blueprint_stubs(
+    schema: &BlueprintSchemaInit,
+    blueprint_name: &str,
+    blueprint: &Ident,
+    types_module: &Ident,
+    span: Span,
+) -> Result<BlueprintStubs> {
+    let mut types = TypeGenerator::new(schema.schema.v1(), blueprint_name, blueprint, types_module);
+    let mut signatures = Vec::new();
+
+    for (name, function) in &schema.functions.functions {
+        let ident: Ident = parse_str(name)
+            .map_err(|_| Error::new(span, format!("Invalid function name: {}", name)))?;
+
+        let input = match function.input {
+            TypeRef::Static(type_id) => type_id,
+            TypeRef::Generic(..) => {
+                return Err(Error::new(
+                    span,
+                    format!("Generic input of function {} is not supported", name),
+                ))
+            }
+        };
+        let field_types = match types.schema.resolve_type_kind(input) {
+            Some(TypeKind::Tuple { field_types }) => field_types,
+            _ => {
+                return Err(Error::new(
+                    span,
+                    format!("Input of function {} is not a tuple", name),
+                ))
+            }
+        };
+        let field_names = types
+            .schema
+            .resolve_matching_tuple_metadata(input, field_types.len())
+            .field_names;
+
+        let mut inputs = Vec::new();
+        if let Some(receiver) = &function.receiver {
+            inputs.push(match receiver.receiver {
+                Receiver::SelfRef => quote! { &self },
+                Receiver::SelfRefMut => quote! { &mut self },
+            });
+        }
+        for (i, field_type) in field_types.iter().enumerate() {
+            let arg = field_names
+                .and_then(|names| names.get(i))
+                .and_then(|name| parse_str::<Ident>(name).ok())
+                .unwrap_or_else(|| format_ident!("arg{}", i));
+            let ty = types.rust_type(*field_type, 0);
+            inputs.push(quote! { #arg: #ty });
+        }
+
+        let output = match function.output {
+            TypeRef::Static(type_id) => match types.schema.resolve_type_kind(type_id) {
+                Some(TypeKind::Tuple { field_types }) if field_types.is_empty() => quote! {},
+                _ => {
+                    let ty = types.rust_type(type_id, 0);
+                    quote! { -> #ty }
+                }
+            },
+            TypeRef::Generic(..) => quote! { -> scrypto::prelude::ScryptoValue },
+        };
+
+        signatures.push(parse_quote! {
+            fn #ident(#(#inputs),*) #output
+        });
+    }
+
+    Ok(BlueprintStubs {
+        signatures,
+        type_definitions: types.definitions,
+    })
+}
+
+/// The name of the module holding the types generated for a blueprint, such as `counter_types`
+/// for `Counter`.
+pub fn types_module_ident(blueprint: &Ident) -> Ident {
+    let name = blueprint.to_string();
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake_case = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let previous = chars[i - 1];
+            let next_is_lowercase = chars.get(i + 1).map_or(false, |next| next.is_lowercase());
+            if previous.is_lowercase()
+                || previous.is_numeric()
+                || (previous.is_uppercase() && next_is_lowercase)
+            {
+                snake_case.push('_');
+            }
+        }
+        snake_case.extend(c.to_lowercase());
+    }
+    format_ident!("{}_types", snake_case)
+}
+
+/// Maps the schema types to Rust types, generating the named structs and enums along the way.
+struct TypeGenerator<'s> {
+    schema: &'s SchemaV1<ScryptoCustomSchema>,
+    /// The name of the blueprint within its package, and the ident of its stub.
+    blueprint_name: &'s str,
+    blueprint: &'s Ident,
+    types_module: &'s Ident,
+    /// The idents of the named types by local index, or `None` if the type can't be generated.
+    idents: IndexMap<usize, Option<Ident>>,
+    /// The named types whose definition is being generated, which are boxed when recursed into.
+    in_progress: IndexSet<usize>,
+    definitions: Vec<TokenStream>,
+}
+
+impl<'s> TypeGenerator<'s> {
+    fn new(
+        schema: &'s SchemaV1<ScryptoCustomSchema>,
+        blueprint_name: &'s str,
+        blueprint: &'s Ident,
+        types_module: &'s Ident,
+    ) -> Self {
+        Self {
+            schema,
+            blueprint_name,
+            blueprint,
+            types_module,
+            idents: index_map_new(),
+            in_progress: index_set_new(),
+            definitions: Vec::new(),
+        }
+    }
+
+    fn rust_type(&mut self, type_id: LocalTypeId, depth: usize) -> TokenStream {
+        let any = quote! { scrypto::prelude::ScryptoValue };
+        if depth > MAX_TYPE_DEPTH {
+            return any;
+        }
+
+        let schema = self.schema;
+        let type_kind = match schema.resolve_type_kind(type_id) {
+            Some(type_kind) => type_kind,
+            None => return any,
+        };
+        let name = schema.resolve_type_name_from_metadata(type_id);
+        if let (LocalTypeId::WellKnown(..), Some(name)) = (type_id, name) {
+            if let Some(ty) = well_known_rust_type(name) {
+                return ty;
+            }
+        }
+
+        match type_kind {
+            TypeKind::Any => any,
+            TypeKind::Bool => quote! { bool },
+            TypeKind::I8 => quote! { i8 },
+            TypeKind::I16 => quote! { i16 },
+            TypeKind::I32 => quote! { i32 },
+            TypeKind::I64 => quote! { i64 },
+            TypeKind::I128 => quote! { i128 },
+            TypeKind::U8 => quote! { u8 },
+            TypeKind::U16 => quote! { u16 },
+            TypeKind::U32 => quote! { u32 },
+            TypeKind::U64 => quote! { u64 },
+            TypeKind::U128 => quote! { u128 },
+            TypeKind::String => quote! { String },
+            TypeKind::Array { element_type } => {
+                let element = self.rust_type(*element_type, depth + 1);
+                quote! { Vec<#element> }
+            }
+            TypeKind::Tuple { field_types } => match name {
+                Some(name) => self.named_type(type_id, name, depth),
+                None => {
+                    let fields: Vec<_> = field_types
+                        .iter()
+                        .map(|field_type| self.rust_type(*field_type, depth + 1))
+                        .collect();
+                    quote! { (#(#fields,)*) }
+                }
+            },
+            TypeKind::Enum { variants } => match (name, variants.get(&0), variants.get(&1)) {
+                (Some("Option"), Some(none), Some(some))
+                    if variants.len() == 2 && none.is_empty() && some.len() == 1 =>
+                {
+                    let some = self.rust_type(some[0], depth + 1);
+                    quote! { Option<#some> }
+                }
+                (Some("Result"), Some(ok), Some(err))
+                    if variants.len() == 2 && ok.len() == 1 && err.len() == 1 =>
+                {
+                    let ok = self.rust_type(ok[0], depth + 1);
+                    let err = self.rust_type(err[0], depth + 1);
+                    quote! { Result<#ok, #err> }
+                }
+                (Some(name), ..) => self.named_type(type_id, name, depth),
+                (None, ..) => any,
+            },
+            TypeKind::Map {
+                key_type,
+                value_type,
+            } => {
+                let key = self.rust_type(*key_type, depth + 1);
+                let value = self.rust_type(*value_type, depth + 1);
+                quote! { scrypto::prelude::IndexMap<#key, #value> }
+            }
+            TypeKind::Custom(ScryptoCustomTypeKind::Decimal) => {
+                quote! { scrypto::prelude::Decimal }
+            }
+            TypeKind::Custom(ScryptoCustomTypeKind::PreciseDecimal) => {
+                quote! { scrypto::prelude::PreciseDecimal }
+            }
+            TypeKind::Custom(ScryptoCustomTypeKind::NonFungibleLocalId) => {
+                quote! { scrypto::prelude::NonFungibleLocalId }
+            }
+            TypeKind::Custom(ScryptoCustomTypeKind::Reference) => {
+                match schema.resolve_type_validation(type_id) {
+                    Some(TypeValidation::Custom(ScryptoCustomTypeValidation::Reference(
+                        validation,
+                    ))) => match validation {
+                        ReferenceValidation::IsGlobalPackage => {
+                            quote! { scrypto::prelude::PackageAddress }
+                        }
+                        ReferenceValidation::IsGlobalComponent => {
+                            quote! { scrypto::prelude::ComponentAddress }
+                        }
+                        ReferenceValidation::IsGlobalResourceManager => {
+                            quote! { scrypto::prelude::ResourceAddress }
+                        }
+                        ReferenceValidation::IsGlobalTyped(None, blueprint_name)
+                            if blueprint_name == self.blueprint_name =>
+                        {
+                            let blueprint = self.blueprint;
+                            quote! { scrypto::prelude::Global<#blueprint> }
+                        }
+                        ReferenceValidation::IsGlobalTyped(..) => {
+                            quote! { scrypto::prelude::Global<scrypto::prelude::AnyComponent> }
+                        }
+                        ReferenceValidation::IsGlobal => {
+                            quote! { scrypto::prelude::GlobalAddress }
+                        }
+                        ReferenceValidation::IsInternal
+                        | ReferenceValidation::IsInternalTyped(..) => {
+                            quote! { scrypto::prelude::Reference }
+                        }
+                    },
+                    _ => quote! { scrypto::prelude::Reference },
+                }
+            }
+            TypeKind::Custom(ScryptoCustomTypeKind::Own) => {
+                match schema.resolve_type_validation(type_id) {
+                    Some(TypeValidation::Custom(ScryptoCustomTypeValidation::Own(validation))) => {
+                        match validation {
+                            OwnValidation::IsBucket => quote! { scrypto::prelude::Bucket },
+                            OwnValidation::IsProof => quote! { scrypto::prelude::Proof },
+                            OwnValidation::IsVault => quote! { scrypto::prelude::Vault },
+                            OwnValidation::IsGlobalAddressReservation => {
+                                quote! { scrypto::prelude::GlobalAddressReservation }
+                            }
+                            OwnValidation::IsTypedObject(None, blueprint_name)
+                                if blueprint_name == self.blueprint_name =>
+                            {
+                                let blueprint = self.blueprint;
+                                quote! { scrypto::prelude::Owned<#blueprint> }
+                            }
+                            OwnValidation::IsTypedObject(_, blueprint_name) => {
+                                match blueprint_name.as_str() {
+                                    "FungibleBucket" | "NonFungibleBucket" | "FungibleProof"
+                                    | "NonFungibleProof" | "FungibleVault" | "NonFungibleVault" => {
+                                        let ident = format_ident!("{}", blueprint_name);
+                                        quote! { scrypto::prelude::#ident }
+                                    }
+                                    _ => any,
+                                }
+                            }
+                            OwnValidation::IsKeyValueStore | OwnValidation::IsIndexStore => any,
+                        }
+                    }
+                    _ => any,
+                }
+            }
+        }
+    }
+
+    /// Maps a named struct or enum of the schema to a type of the types module, generating its
+    /// definition on first use. Types whose name isn't a valid ident, or is taken by another type
+    /// (such as another instance of a generic type), are mapped to `ScryptoValue`.
+    fn named_type(&mut self, type_id: LocalTypeId, name: &str, depth: usize) -> TokenStream {
+        let any = quote! { scrypto::prelude::ScryptoValue };
+        let index = match type_id {
+            LocalTypeId::SchemaLocalIndex(index) => index,
+            LocalTypeId::WellKnown(..) => return any,
+        };
+
+        let types_module = self.types_module;
+        if let Some(ident) = self.idents.get(&index) {
+            return match ident {
+                Some(ident) if self.in_progress.contains(&index) => {
+                    quote! { Box<#types_module::#ident> }
+                }
+                Some(ident) => quote! { #types_module::#ident },
+                None => any,
+            };
+        }
+
+        let ident = parse_str::<Ident>(name).ok().filter(|ident| {
+            !self
+                .idents
+                .values()
+                .any(|existing| existing.as_ref() == Some(ident))
+        });
+        self.idents.insert(index, ident.clone());
+        let ident = match ident {
+            Some(ident) => ident,
+            None => return any,
+        };
+
+        self.in_progress.insert(index);
+        let definition = match self.schema.resolve_type_kind(type_id) {
+            Some(TypeKind::Tuple { field_types }) => {
+                let field_names = self
+                    .schema
+                    .resolve_matching_tuple_metadata(type_id, field_types.len())
+                    .field_names;
+                let (fields, braced) = self.fields(field_types, field_names, quote! { pub }, depth);
+                let semi_token = if braced {
+                    quote! {}
+                } else {
+                    quote! { ; }
+                };
+                quote! {
+                    #[derive(::scrypto::prelude::ScryptoSbor)]
+                    pub struct #ident #fields #semi_token
+                }
+            }
+            Some(TypeKind::Enum { variants }) => {
+                let variants: Vec<_> = variants
+                    .iter()
+                    .map(|(discriminator, field_types)| {
+                        let metadata = self.schema.resolve_matching_enum_metadata(
+                            type_id,
+                            *discriminator,
+                            field_types.len(),
+                        );
+                        let variant = metadata
+                            .variant_name
+                            .and_then(|name| parse_str::<Ident>(name).ok())
+                            .unwrap_or_else(|| format_ident!("Variant{}", discriminator));
+                        let (fields, _) =
+                            self.fields(field_types, metadata.field_names, quote! {}, depth);
+                        quote! {
+                            #[sbor(discriminator(#discriminator))]
+                            #variant #fields
+                        }
+                    })
+                    .collect();
+                quote! {
+                    #[derive(::scrypto::prelude::ScryptoSbor)]
+                    pub enum #ident {
+                        #(#variants,)*
+                    }
+                }
+            }
+            _ => unreachable!("Only named tuples and enums are generated"),
+        };
+        self.in_progress.remove(&index);
+        self.definitions.push(definition);
+
+        quote! { #types_module::#ident }
+    }
+
+    /// The fields of a generated struct or enum variant with the given visibility, which are
+    /// named if all the names are valid idents, and whether they are braced.
+    fn fields(
+        &mut self,
+        field_types: &[LocalTypeId],
+        field_names: Option<&[Cow<'static, str>]>,
+        vis: TokenStream,
+        depth: usize,
+    ) -> (TokenStream, bool) {
+        if field_types.is_empty() {
+            return (quote! {}, false);
+        }
+        let types: Vec<_> = field_types
+            .iter()
+            .map(|field_type| self.rust_type(*field_type, depth + 1))
+            .collect();
+        let names: Option<Vec<Ident>> = field_names.and_then(|names| {
+            names
+                .iter()
+                .map(|name| parse_str::<Ident>(name).ok())
+                .collect()
+        });
+        match names {
+            Some(names) => (quote! { { #(#vis #names: #types,)* } }, true),
+            None => (quote! { (#(#vis #types,)*) }, false),
+        }
+    }
+}
+
+/// The Scrypto types of the named well-known types.
+fn well_known_rust_type(name: &str) -> Option<TokenStream> {
+    let ident = match name {
+        "NonFungibleGlobalId"
+        | "Instant"
+        | "UtcDateTime"
+        | "PublicKey"
+        | "Secp256k1PublicKey"
+        | "Ed25519PublicKey"
+        | "PublicKeyHash"
+        | "Secp256k1PublicKeyHash"
+        | "Ed25519PublicKeyHash"
+        | "AccessRule"
+        | "AccessRuleNode"
+        | "ProofRule"
+        | "ResourceOrNonFungible"
+        | "OwnerRole"
+        | "RoleKey"
+        | "ModuleId"
+        | "AttachedModuleId"
+        | "RoyaltyAmount" => format_ident!("{}", name),
+        "Url" => format_ident!("UncheckedUrl"),
+        "Origin" => format_ident!("UncheckedOrigin"),
+        _ => return None,
+    };
+    Some(quote! { scrypto::prelude::#ident })
+}
@@ -1,5 +1,6 @@
 mod ast;
 mod blueprint;
+mod extern_schema;
 mod non_fungible_data;
 mod utils;
 
@@ -40,6 +41,33 @@ use proc_macro::TokenStream;
 ///     }
 /// }
 /// ```
+///
+/// # External Blueprints
+///
+/// Blueprints of other packages can be called through typed stubs, declared with
+/// `extern_blueprint!`, or generated from the package definition file output by
+/// `scrypto build` with `extern_blueprint_from_schema!`. The path of the file is
+/// relative to the crate manifest directory. The named structs and enums of the schema are
+/// generated within a module named after the blueprint, such as `counter_types` for `Counter`.
+///
+/// ```ignore
+/// #[blueprint]
+/// mod caller {
+///     extern_blueprint_from_schema!(
+///         "package_sim1p4r4955skdjq9swg8s5jguvcjvyj7tsxct87a9z6sw76cdfd2jg3zk",
+///         "schemas/counter.rpd",
+///         Counter
+///     );
+///
+///     struct Caller {}
+///
+///     impl Caller {
+///         pub fn call(counter: Global<Counter>) -> u32 {
+///             counter.get_and_incr()
+///         }
+///     }
+/// }
+/// ```
 #[proc_macro_attribute]
 pub fn blueprint(_: TokenStream, input: TokenStream) -> TokenStream {
     blueprint::handle_blueprint(proc_macro2::TokenStream::from(input))
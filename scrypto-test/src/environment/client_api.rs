@@ -103,6 +103,13 @@ implement_client_api! {
             collection_index: CollectionIndex,
             limit: u32,
         ) -> Result<Vec<Vec<u8>>, RuntimeError>,
+        actor_index_scan_keys_after: (
+            &mut self,
+            object_handle: ActorStateHandle,
+            collection_index: CollectionIndex,
+            after: Option<Vec<u8>>,
+            limit: u32,
+        ) -> Result<Vec<Vec<u8>>, RuntimeError>,
         actor_index_drain: (
             &mut self,
             object_handle: ActorStateHandle,
@@ -190,6 +197,27 @@ implement_client_api! {
             key: &Vec<u8>,
         ) -> Result<Vec<u8>, RuntimeError>,
     },
+    ClientIndexStoreApi: {
+        index_store_new: (&mut self, data_schema: KeyValueStoreDataSchema) -> Result<NodeId, RuntimeError>,
+        index_store_insert: (
+            &mut self,
+            node_id: &NodeId,
+            sorted_key: SortedKey,
+            buffer: Vec<u8>,
+        ) -> Result<(), RuntimeError>,
+        index_store_remove: (
+            &mut self,
+            node_id: &NodeId,
+            sorted_key: &SortedKey,
+        ) -> Result<Option<Vec<u8>>, RuntimeError>,
+        index_store_scan: (
+            &mut self,
+            node_id: &NodeId,
+            from_sort_prefix: [u8; 2],
+            to_sort_prefix: Option<[u8; 2]>,
+            count: u32,
+        ) -> Result<Vec<(SortedKey, Vec<u8>)>, RuntimeError>,
+    },
     ClientObjectApi: {
         new_object: (
             &mut self,
@@ -255,7 +283,15 @@ implement_client_api! {
         bech32_encode_address: (&mut self, address: GlobalAddress) -> Result<String, RuntimeError>,
         get_transaction_hash: (&mut self) -> Result<Hash, RuntimeError>,
         generate_ruid: (&mut self) -> Result<[u8; 32], RuntimeError>,
+        generate_ruid_seeded: (&mut self, domain: &[u8]) -> Result<[u8; 32], RuntimeError>,
         emit_log: (&mut self, level: Level, message: String) -> Result<(), RuntimeError>,
+        emit_log_record: (
+            &mut self,
+            level: Level,
+            topic: String,
+            message: String,
+            payload: Option<Vec<u8>>,
+        ) -> Result<(), RuntimeError>,
         panic: (&mut self, message: String) -> Result<(), RuntimeError>,
     },
     ClientCostingApi: {
@@ -264,6 +300,7 @@ implement_client_api! {
             &mut self,
             locked_fee: LiquidFungibleResource,
             contingent: bool,
+            refund_vault_id: Option<NodeId>,
         ) -> (),
         consume_cost_units: (&mut self, costing_entry: ClientCostingEntry) -> Result<(), RuntimeError>,
         execution_cost_unit_limit: (&mut self) -> Result<u32, RuntimeError>,
@@ -273,6 +310,30 @@ implement_client_api! {
         usd_price: (&mut self) -> Result<Decimal, RuntimeError>,
         max_per_function_royalty_in_xrd: (&mut self) -> Result<Decimal, RuntimeError>,
         tip_percentage: (&mut self) -> Result<u32, RuntimeError>,
+        tip_basis_points: (&mut self) -> Result<u32, RuntimeError>,
         fee_balance: (&mut self) -> Result<Decimal, RuntimeError>,
+        set_instruction_cost_unit_limit: (&mut self, limit: u32) -> Result<(), RuntimeError>,
+    },
+    ClientCryptoUtilsApi: {
+        ed25519_verify: (
+            &mut self,
+            message: &[u8],
+            public_key: &Ed25519PublicKey,
+            signature: &Ed25519Signature,
+        ) -> Result<bool, RuntimeError>,
+        secp256k1_verify: (
+            &mut self,
+            message: &[u8],
+            public_key: &Secp256k1PublicKey,
+            signature: &Secp256k1Signature,
+        ) -> Result<bool, RuntimeError>,
+        bls12381_v1_verify: (
+            &mut self,
+            message: &[u8],
+            public_key: &Bls12381G1PublicKey,
+            signature: &Bls12381G2Signature,
+        ) -> Result<bool, RuntimeError>,
+        keccak256_hash: (&mut self, data: &[u8]) -> Result<Hash, RuntimeError>,
+        blake2b_256_hash: (&mut self, data: &[u8]) -> Result<Hash, RuntimeError>,
     }
 }
@@ -0,0 +1,1020 @@
+use crate::PackagePublishingSource;
+use radix_engine::errors::{InvokeError, RuntimeError, VmError};
+use radix_engine::transaction::CostingParameters;
+use radix_engine::types::*;
+use radix_engine::utils::{keccak256_hash, verify_bls12381_v1};
+use radix_engine::vm::wasm::*;
+use radix_engine::vm::wasm_runtime::ScryptoRuntime;
+use radix_engine_interface::api::actor_api::EventFlags;
+use radix_engine_interface::api::field_api::{FieldHandle, LockFlags};
+use radix_engine_interface::api::key_value_entry_api::KeyValueEntryHandle;
+use radix_engine_interface::api::key_value_store_api::KeyValueStoreDataSchema;
+use radix_engine_interface::blueprints::package::*;
+use transaction::validation::{verify_ed25519, verify_secp256k1};
+
+/// A scripted response to a method or function call made by the blueprint under test, mapping
+/// the encoded arguments to the encoded return value.
+pub type CallStub = Box<dyn FnMut(&[u8]) -> Vec<u8>>;
+
+#[derive(Debug)]
+pub enum BlueprintHarnessError {
+    FunctionNotFound {
+        blueprint_name: String,
+        function_name: String,
+    },
+    /// The blueprint panicked, with the given message.
+    Panic(String),
+    /// The blueprint made a call which no stub has been registered for.
+    UnstubbedCall(String),
+    /// The blueprint used a system function the harness doesn't support.
+    Unsupported(&'static str),
+    /// The blueprint read a state field which hasn't been scripted.
+    MissingField(u8),
+    /// The blueprint referred to an object, store or address reservation the harness doesn't
+    /// know of.
+    UnknownNode(NodeId),
+    WasmError(InvokeError<WasmRuntimeError>),
+    OutputDecodeError(DecodeError),
+}
+
+/// Runs the functions and methods of a compiled package directly on the WASM engine, without
+/// bootstrapping a ledger.
+///
+/// The blueprint talks to the harness through the same [`ClientApi`] the system exposes, so it
+/// can instantiate objects, allocate addresses and globalize components, whose state is then
+/// kept in memory. The state of the component methods are called on, and the results of any
+/// calls the blueprint makes to other objects or packages, are scripted up front. Everything
+/// else the blueprint may ask of the system (e.g. auth, fees, index stores) is either
+/// unsupported or answered with a fixed value, so this is only a fit for testing blueprint
+/// logic. Use the [`crate::TestRunner`] for anything else.
+pub struct BlueprintHarness {
+    definition: PackageDefinition,
+    instance: <DefaultWasmEngine as WasmEngine>::WasmInstance,
+    api: HarnessApi,
+}
+
+impl BlueprintHarness {
+    pub fn new<P: Into<PackagePublishingSource>>(source: P) -> Self {
+        let (code, definition) = source.into().code_and_definition();
+        let instrumented_code = WasmValidator::default()
+            .validate(&code, definition.blueprints.values())
+            .expect("Invalid package code")
+            .0;
+        let instance = DefaultWasmEngine::default()
+            .instantiate(CodeHash::from_hash(hash(&code)), &instrumented_code);
+
+        Self {
+            definition,
+            instance,
+            api: HarnessApi {
+                package_address: PackageAddress::new_or_panic(
+                    NodeId::new(
+                        EntityType::GlobalPackage as u8,
+                        &hash("package").lower_bytes(),
+                    )
+                    .0,
+                ),
+                component_address: ComponentAddress::new_or_panic(
+                    NodeId::new(
+                        EntityType::GlobalGenericComponent as u8,
+                        &hash("component").lower_bytes(),
+                    )
+                    .0,
+                ),
+                blueprint_name: String::new(),
+                objects: index_map_new(),
+                reservations: index_map_new(),
+                open_fields: index_map_new(),
+                key_value_stores: index_map_new(),
+                open_entries: index_map_new(),
+                next_handle: 0,
+                next_id: 0,
+                method_stubs: index_map_new(),
+                function_stubs: index_map_new(),
+                logs: Vec::new(),
+                events: Vec::new(),
+                failure: None,
+            },
+        }
+    }
+
+    /// Sets the address the package under test appears to be published at.
+    pub fn with_package_address(mut self, package_address: PackageAddress) -> Self {
+        self.api.package_address = package_address;
+        self
+    }
+
+    /// Sets the address of the component methods are called on, e.g. to one globalized by an
+    /// earlier call. Any state scripted with [`Self::with_state`] moves along with it.
+    pub fn with_component_address(mut self, component_address: ComponentAddress) -> Self {
+        let previous = *self.api.component_address.as_node_id();
+        if let Some(object) = self.api.objects.swap_remove(&previous) {
+            self.api
+                .objects
+                .entry(*component_address.as_node_id())
+                .or_insert(object);
+        }
+        self.api.component_address = component_address;
+        self
+    }
+
+    /// Sets the state of the component methods are called on.
+    pub fn with_state<S: ScryptoEncode>(mut self, state: &S) -> Self {
+        let blueprint_id = BlueprintId::new(&self.api.package_address, "");
+        self.api
+            .objects
+            .entry(*self.api.component_address.as_node_id())
+            .or_insert_with(|| HarnessObject {
+                blueprint_id,
+                fields: index_map_new(),
+            })
+            .fields
+            .insert(0u8, scrypto_encode(state).unwrap());
+        self
+    }
+
+    /// Scripts the result of calling the given method on the given object.
+    pub fn stub_method<F: FnMut(&[u8]) -> Vec<u8> + 'static>(
+        mut self,
+        receiver: NodeId,
+        method_name: &str,
+        stub: F,
+    ) -> Self {
+        self.api
+            .method_stubs
+            .insert((receiver, method_name.to_string()), Box::new(stub));
+        self
+    }
+
+    /// Scripts the result of calling the given blueprint function.
+    pub fn stub_function<F: FnMut(&[u8]) -> Vec<u8> + 'static>(
+        mut self,
+        package_address: PackageAddress,
+        blueprint_name: &str,
+        function_name: &str,
+        stub: F,
+    ) -> Self {
+        self.api.function_stubs.insert(
+            (
+                package_address,
+                blueprint_name.to_string(),
+                function_name.to_string(),
+            ),
+            Box::new(stub),
+        );
+        self
+    }
+
+    /// The current state of the component methods are called on.
+    pub fn state<S: ScryptoDecode>(&self) -> Option<S> {
+        self.object_state(self.api.component_address.as_node_id())
+    }
+
+    /// The current state of an object, or component, the blueprint has instantiated.
+    pub fn object_state<S: ScryptoDecode>(&self, node_id: &NodeId) -> Option<S> {
+        self.api
+            .objects
+            .get(node_id)
+            .and_then(|object| object.fields.get(&0u8))
+            .map(|state| scrypto_decode(state).unwrap())
+    }
+
+    /// The blueprint of an object, or component, the blueprint has instantiated.
+    pub fn object_blueprint_id(&self, node_id: &NodeId) -> Option<&BlueprintId> {
+        self.api
+            .objects
+            .get(node_id)
+            .map(|object| &object.blueprint_id)
+    }
+
+    /// The value stored under the given key of a key value store the blueprint has created.
+    pub fn key_value_store_entry<K: ScryptoEncode, V: ScryptoDecode>(
+        &self,
+        node_id: &NodeId,
+        key: &K,
+    ) -> Option<V> {
+        self.api
+            .key_value_stores
+            .get(node_id)
+            .and_then(|store| store.get(&scrypto_encode(key).unwrap()))
+            .map(|value| scrypto_decode(value).unwrap())
+    }
+
+    pub fn logs(&self) -> &[(Level, String)] {
+        &self.api.logs
+    }
+
+    /// The events emitted so far, by name, with their encoded payloads.
+    pub fn events(&self) -> &[(String, Vec<u8>)] {
+        &self.api.events
+    }
+
+    pub fn call_function(
+        &mut self,
+        blueprint_name: &str,
+        function_name: &str,
+        args: Vec<u8>,
+    ) -> Result<IndexedScryptoValue, BlueprintHarnessError> {
+        self.invoke(blueprint_name, function_name, false, args)
+    }
+
+    pub fn call_method(
+        &mut self,
+        blueprint_name: &str,
+        method_name: &str,
+        args: Vec<u8>,
+    ) -> Result<IndexedScryptoValue, BlueprintHarnessError> {
+        self.invoke(blueprint_name, method_name, true, args)
+    }
+
+    fn invoke(
+        &mut self,
+        blueprint_name: &str,
+        ident: &str,
+        is_method: bool,
+        args: Vec<u8>,
+    ) -> Result<IndexedScryptoValue, BlueprintHarnessError> {
+        let export_name = self
+            .definition
+            .blueprints
+            .get(blueprint_name)
+            .and_then(|blueprint| blueprint.schema.functions.functions.get(ident))
+            .filter(|function| function.receiver.is_some() == is_method)
+            .map(|function| function.export.clone())
+            .ok_or_else(|| BlueprintHarnessError::FunctionNotFound {
+                blueprint_name: blueprint_name.to_string(),
+                function_name: ident.to_string(),
+            })?;
+
+        let package_address = self.api.package_address;
+        if is_method {
+            // The scripted state doesn't know which blueprint it belongs to until it's called
+            if let Some(object) = self
+                .api
+                .objects
+                .get_mut(self.api.component_address.as_node_id())
+            {
+                object.blueprint_id = BlueprintId::new(&package_address, blueprint_name);
+            }
+        }
+        self.api.blueprint_name = blueprint_name.to_string();
+        self.api.failure = None;
+
+        let rtn = {
+            let mut runtime: Box<dyn WasmRuntime> = Box::new(ScryptoRuntime::new(
+                &mut self.api,
+                package_address,
+                export_name.clone(),
+                None,
+            ));
+            let input = runtime.allocate_buffer(args).unwrap();
+            self.instance
+                .invoke_export(&export_name, vec![input], &mut runtime)
+        };
+
+        if let Some(failure) = self.api.failure.take() {
+            return Err(failure);
+        }
+        let rtn = rtn.map_err(BlueprintHarnessError::WasmError)?;
+        IndexedScryptoValue::from_vec(rtn).map_err(BlueprintHarnessError::OutputDecodeError)
+    }
+}
+
+struct HarnessObject {
+    blueprint_id: BlueprintId,
+    fields: IndexMap<FieldIndex, Vec<u8>>,
+}
+
+/// The [`ClientApi`] the blueprint under test runs against.
+struct HarnessApi {
+    package_address: PackageAddress,
+    component_address: ComponentAddress,
+    blueprint_name: String,
+    objects: IndexMap<NodeId, HarnessObject>,
+    reservations: IndexMap<NodeId, GlobalAddress>,
+    open_fields: IndexMap<FieldHandle, (NodeId, FieldIndex)>,
+    key_value_stores: IndexMap<NodeId, IndexMap<Vec<u8>, Vec<u8>>>,
+    open_entries: IndexMap<KeyValueEntryHandle, (NodeId, Vec<u8>)>,
+    next_handle: u32,
+    next_id: u64,
+    method_stubs: IndexMap<(NodeId, String), CallStub>,
+    function_stubs: IndexMap<(PackageAddress, String, String), CallStub>,
+    logs: Vec<(Level, String)>,
+    events: Vec<(String, Vec<u8>)>,
+    failure: Option<BlueprintHarnessError>,
+}
+
+impl HarnessApi {
+    fn fail<T>(&mut self, failure: BlueprintHarnessError) -> Result<T, RuntimeError> {
+        // Only the first failure is reported, as the rest are usually caused by it
+        self.failure.get_or_insert(failure);
+        Err(RuntimeError::VmError(VmError::Wasm(
+            WasmRuntimeError::NotImplemented,
+        )))
+    }
+
+    fn unsupported<T>(&mut self, name: &'static str) -> Result<T, RuntimeError> {
+        self.fail(BlueprintHarnessError::Unsupported(name))
+    }
+
+    fn new_handle(&mut self) -> u32 {
+        let handle = self.next_handle;
+        self.next_handle += 1;
+        handle
+    }
+
+    fn new_node_id(&mut self, entity_type: EntityType) -> NodeId {
+        self.next_id += 1;
+        NodeId::new(
+            entity_type as u8,
+            &hash(self.next_id.to_le_bytes()).lower_bytes(),
+        )
+    }
+
+    fn create_object(&mut self, blueprint_id: BlueprintId) -> NodeId {
+        let node_id = self.new_node_id(EntityType::InternalGenericComponent);
+        self.objects.insert(
+            node_id,
+            HarnessObject {
+                blueprint_id,
+                fields: index_map_new(),
+            },
+        );
+        node_id
+    }
+
+    fn open_entry(
+        &mut self,
+        handle: KeyValueEntryHandle,
+    ) -> Result<(NodeId, Vec<u8>), RuntimeError> {
+        match self.open_entries.get(&handle).cloned() {
+            Some(entry) => Ok(entry),
+            None => self.unsupported("key_value_entry_handle"),
+        }
+    }
+
+    fn remove_entry(&mut self, node_id: &NodeId, key: &Vec<u8>) -> Result<Vec<u8>, RuntimeError> {
+        match self.key_value_stores.get_mut(node_id) {
+            Some(store) => {
+                let value = store.swap_remove(key);
+                Ok(Self::encode_entry(value))
+            }
+            None => self.fail(BlueprintHarnessError::UnknownNode(*node_id)),
+        }
+    }
+
+    fn encode_entry(value: Option<Vec<u8>>) -> Vec<u8> {
+        let value = value.map(|value| scrypto_decode::<ScryptoValue>(&value).unwrap());
+        scrypto_encode(&value).unwrap()
+    }
+}
+
+impl ClientApi<RuntimeError> for HarnessApi {}
+
+impl ClientActorApi<RuntimeError> for HarnessApi {
+    fn actor_get_blueprint_id(&mut self) -> Result<BlueprintId, RuntimeError> {
+        Ok(BlueprintId::new(
+            &self.package_address,
+            self.blueprint_name.clone(),
+        ))
+    }
+
+    fn actor_get_node_id(&mut self, ref_handle: ActorRefHandle) -> Result<NodeId, RuntimeError> {
+        match ref_handle {
+            ACTOR_REF_SELF | ACTOR_REF_GLOBAL => Ok(*self.component_address.as_node_id()),
+            _ => self.unsupported("actor_get_node_id"),
+        }
+    }
+
+    fn actor_is_feature_enabled(
+        &mut self,
+        _object_handle: ActorStateHandle,
+        _feature: &str,
+    ) -> Result<bool, RuntimeError> {
+        Ok(false)
+    }
+
+    fn actor_open_field(
+        &mut self,
+        object_handle: ActorStateHandle,
+        field: FieldIndex,
+        _flags: LockFlags,
+    ) -> Result<FieldHandle, RuntimeError> {
+        if object_handle != ACTOR_STATE_SELF {
+            return self.unsupported("actor_open_field");
+        }
+        let handle = self.new_handle();
+        self.open_fields
+            .insert(handle, (*self.component_address.as_node_id(), field));
+        Ok(handle)
+    }
+
+    fn actor_emit_event(
+        &mut self,
+        event_name: String,
+        event_data: Vec<u8>,
+        _event_flags: EventFlags,
+    ) -> Result<(), RuntimeError> {
+        self.events.push((event_name, event_data));
+        Ok(())
+    }
+}
+
+impl ClientActorKeyValueEntryApi<RuntimeError> for HarnessApi {
+    fn actor_open_key_value_entry(
+        &mut self,
+        _object_handle: ActorStateHandle,
+        _collection_index: CollectionIndex,
+        _key: &Vec<u8>,
+        _flags: LockFlags,
+    ) -> Result<KeyValueEntryHandle, RuntimeError> {
+        self.unsupported("actor_open_key_value_entry")
+    }
+
+    fn actor_remove_key_value_entry(
+        &mut self,
+        _object_handle: ActorStateHandle,
+        _collection_index: CollectionIndex,
+        _key: &Vec<u8>,
+    ) -> Result<Vec<u8>, RuntimeError> {
+        self.unsupported("actor_remove_key_value_entry")
+    }
+}
+
+impl ClientObjectApi<RuntimeError> for HarnessApi {
+    fn new_object(
+        &mut self,
+        blueprint_ident: &str,
+        _features: Vec<&str>,
+        _generic_args: GenericArgs,
+        fields: IndexMap<FieldIndex, FieldValue>,
+        kv_entries: IndexMap<CollectionIndex, IndexMap<Vec<u8>, KVEntry>>,
+    ) -> Result<NodeId, RuntimeError> {
+        if !kv_entries.is_empty() {
+            return self.unsupported("new_object");
+        }
+        let node_id = self.create_object(BlueprintId::new(&self.package_address, blueprint_ident));
+        let object = self.objects.get_mut(&node_id).unwrap();
+        for (index, field) in fields {
+            object.fields.insert(index, field.value);
+        }
+        Ok(node_id)
+    }
+
+    fn drop_object(&mut self, node_id: &NodeId) -> Result<Vec<Vec<u8>>, RuntimeError> {
+        match self.objects.swap_remove(node_id) {
+            Some(object) => Ok(object.fields.into_values().collect()),
+            None => self.fail(BlueprintHarnessError::UnknownNode(*node_id)),
+        }
+    }
+
+    fn get_blueprint_id(&mut self, node_id: &NodeId) -> Result<BlueprintId, RuntimeError> {
+        match self.objects.get(node_id) {
+            Some(object) => Ok(object.blueprint_id.clone()),
+            None => self.fail(BlueprintHarnessError::UnknownNode(*node_id)),
+        }
+    }
+
+    fn get_outer_object(&mut self, _node_id: &NodeId) -> Result<GlobalAddress, RuntimeError> {
+        self.unsupported("get_outer_object")
+    }
+
+    fn allocate_global_address(
+        &mut self,
+        _blueprint_id: BlueprintId,
+    ) -> Result<(GlobalAddressReservation, GlobalAddress), RuntimeError> {
+        let reservation = self.new_node_id(EntityType::InternalGenericComponent);
+        let address =
+            GlobalAddress::new_or_panic(self.new_node_id(EntityType::GlobalGenericComponent).0);
+        self.reservations.insert(reservation, address);
+        Ok((GlobalAddressReservation(Own(reservation)), address))
+    }
+
+    fn allocate_virtual_global_address(
+        &mut self,
+        _blueprint_id: BlueprintId,
+        _global_address: GlobalAddress,
+    ) -> Result<GlobalAddressReservation, RuntimeError> {
+        self.unsupported("allocate_virtual_global_address")
+    }
+
+    fn get_reservation_address(&mut self, node_id: &NodeId) -> Result<GlobalAddress, RuntimeError> {
+        match self.reservations.get(node_id) {
+            Some(address) => Ok(*address),
+            None => self.fail(BlueprintHarnessError::UnknownNode(*node_id)),
+        }
+    }
+
+    fn globalize(
+        &mut self,
+        node_id: NodeId,
+        modules: IndexMap<AttachedModuleId, NodeId>,
+        address_reservation: Option<GlobalAddressReservation>,
+    ) -> Result<GlobalAddress, RuntimeError> {
+        let address = match address_reservation {
+            Some(reservation) => {
+                let reservation = *reservation.0.as_node_id();
+                match self.reservations.swap_remove(&reservation) {
+                    Some(address) => address,
+                    None => return self.fail(BlueprintHarnessError::UnknownNode(reservation)),
+                }
+            }
+            None => {
+                GlobalAddress::new_or_panic(self.new_node_id(EntityType::GlobalGenericComponent).0)
+            }
+        };
+
+        // The modules are consumed by the component, and the harness doesn't model their state
+        for module in modules.values() {
+            if self.objects.swap_remove(module).is_none() {
+                return self.fail(BlueprintHarnessError::UnknownNode(*module));
+            }
+        }
+        let object = match self.objects.swap_remove(&node_id) {
+            Some(object) => object,
+            None => return self.fail(BlueprintHarnessError::UnknownNode(node_id)),
+        };
+        self.objects.insert(address.into_node_id(), object);
+
+        Ok(address)
+    }
+
+    fn globalize_with_address_and_create_inner_object_and_emit_event(
+        &mut self,
+        _node_id: NodeId,
+        _modules: IndexMap<AttachedModuleId, NodeId>,
+        _address_reservation: GlobalAddressReservation,
+        _inner_object_blueprint: &str,
+        _inner_object_fields: IndexMap<u8, FieldValue>,
+        _event_name: &str,
+        _event_data: Vec<u8>,
+    ) -> Result<(GlobalAddress, NodeId), RuntimeError> {
+        self.unsupported("globalize_with_address_and_create_inner_object_and_emit_event")
+    }
+
+    fn call_method(
+        &mut self,
+        receiver: &NodeId,
+        method_name: &str,
+        args: Vec<u8>,
+    ) -> Result<Vec<u8>, RuntimeError> {
+        match self
+            .method_stubs
+            .get_mut(&(*receiver, method_name.to_string()))
+        {
+            Some(stub) => Ok(stub(&args)),
+            None => self.fail(BlueprintHarnessError::UnstubbedCall(format!(
+                "{:?}::{}",
+                receiver, method_name
+            ))),
+        }
+    }
+
+    fn call_direct_access_method(
+        &mut self,
+        _receiver: &NodeId,
+        _method_name: &str,
+        _args: Vec<u8>,
+    ) -> Result<Vec<u8>, RuntimeError> {
+        self.unsupported("call_direct_access_method")
+    }
+
+    fn call_module_method(
+        &mut self,
+        _receiver: &NodeId,
+        _module_id: AttachedModuleId,
+        _method_name: &str,
+        _args: Vec<u8>,
+    ) -> Result<Vec<u8>, RuntimeError> {
+        self.unsupported("call_module_method")
+    }
+}
+
+impl ClientKeyValueStoreApi<RuntimeError> for HarnessApi {
+    fn key_value_store_new(
+        &mut self,
+        _data_schema: KeyValueStoreDataSchema,
+    ) -> Result<NodeId, RuntimeError> {
+        let node_id = self.new_node_id(EntityType::InternalKeyValueStore);
+        self.key_value_stores.insert(node_id, index_map_new());
+        Ok(node_id)
+    }
+
+    fn key_value_store_open_entry(
+        &mut self,
+        node_id: &NodeId,
+        key: &Vec<u8>,
+        _flags: LockFlags,
+    ) -> Result<KeyValueEntryHandle, RuntimeError> {
+        if !self.key_value_stores.contains_key(node_id) {
+            return self.fail(BlueprintHarnessError::UnknownNode(*node_id));
+        }
+        let handle = self.new_handle();
+        self.open_entries.insert(handle, (*node_id, key.clone()));
+        Ok(handle)
+    }
+
+    fn key_value_store_remove_entry(
+        &mut self,
+        node_id: &NodeId,
+        key: &Vec<u8>,
+    ) -> Result<Vec<u8>, RuntimeError> {
+        self.remove_entry(node_id, key)
+    }
+}
+
+impl ClientKeyValueEntryApi<RuntimeError> for HarnessApi {
+    fn key_value_entry_get(
+        &mut self,
+        handle: KeyValueEntryHandle,
+    ) -> Result<Vec<u8>, RuntimeError> {
+        let (node_id, key) = self.open_entry(handle)?;
+        let value = self
+            .key_value_stores
+            .get(&node_id)
+            .and_then(|store| store.get(&key))
+            .cloned();
+        Ok(Self::encode_entry(value))
+    }
+
+    fn key_value_entry_set(
+        &mut self,
+        handle: KeyValueEntryHandle,
+        buffer: Vec<u8>,
+    ) -> Result<(), RuntimeError> {
+        let (node_id, key) = self.open_entry(handle)?;
+        self.key_value_stores
+            .get_mut(&node_id)
+            .unwrap()
+            .insert(key, buffer);
+        Ok(())
+    }
+
+    fn key_value_entry_remove(
+        &mut self,
+        handle: KeyValueEntryHandle,
+    ) -> Result<Vec<u8>, RuntimeError> {
+        let (node_id, key) = self.open_entry(handle)?;
+        self.remove_entry(&node_id, &key)
+    }
+
+    fn key_value_entry_lock(&mut self, _handle: KeyValueEntryHandle) -> Result<(), RuntimeError> {
+        Ok(())
+    }
+
+    fn key_value_entry_close(&mut self, handle: KeyValueEntryHandle) -> Result<(), RuntimeError> {
+        self.open_entries.swap_remove(&handle);
+        Ok(())
+    }
+}
+
+impl ClientIndexStoreApi<RuntimeError> for HarnessApi {
+    fn index_store_new(
+        &mut self,
+        _data_schema: KeyValueStoreDataSchema,
+    ) -> Result<NodeId, RuntimeError> {
+        self.unsupported("index_store_new")
+    }
+
+    fn index_store_insert(
+        &mut self,
+        _node_id: &NodeId,
+        _sorted_key: SortedKey,
+        _buffer: Vec<u8>,
+    ) -> Result<(), RuntimeError> {
+        self.unsupported("index_store_insert")
+    }
+
+    fn index_store_remove(
+        &mut self,
+        _node_id: &NodeId,
+        _sorted_key: &SortedKey,
+    ) -> Result<Option<Vec<u8>>, RuntimeError> {
+        self.unsupported("index_store_remove")
+    }
+
+    fn index_store_scan(
+        &mut self,
+        _node_id: &NodeId,
+        _from_sort_prefix: [u8; 2],
+        _to_sort_prefix: Option<[u8; 2]>,
+        _count: u32,
+    ) -> Result<Vec<(SortedKey, Vec<u8>)>, RuntimeError> {
+        self.unsupported("index_store_scan")
+    }
+}
+
+impl ClientActorSortedIndexApi<RuntimeError> for HarnessApi {
+    fn actor_sorted_index_insert(
+        &mut self,
+        _object_handle: ActorStateHandle,
+        _collection_index: CollectionIndex,
+        _sorted_key: SortedKey,
+        _buffer: Vec<u8>,
+    ) -> Result<(), RuntimeError> {
+        self.unsupported("actor_sorted_index_insert")
+    }
+
+    fn actor_sorted_index_remove(
+        &mut self,
+        _object_handle: ActorStateHandle,
+        _collection_index: CollectionIndex,
+        _sorted_key: &SortedKey,
+    ) -> Result<Option<Vec<u8>>, RuntimeError> {
+        self.unsupported("actor_sorted_index_remove")
+    }
+
+    fn actor_sorted_index_scan(
+        &mut self,
+        _object_handle: ActorStateHandle,
+        _collection_index: CollectionIndex,
+        _count: u32,
+    ) -> Result<Vec<(SortedKey, Vec<u8>)>, RuntimeError> {
+        self.unsupported("actor_sorted_index_scan")
+    }
+}
+
+impl ClientActorIndexApi<RuntimeError> for HarnessApi {
+    fn actor_index_insert(
+        &mut self,
+        _object_handle: ActorStateHandle,
+        _collection_index: CollectionIndex,
+        _key: Vec<u8>,
+        _buffer: Vec<u8>,
+    ) -> Result<(), RuntimeError> {
+        self.unsupported("actor_index_insert")
+    }
+
+    fn actor_index_remove(
+        &mut self,
+        _object_handle: ActorStateHandle,
+        _collection_index: CollectionIndex,
+        _key: Vec<u8>,
+    ) -> Result<Option<Vec<u8>>, RuntimeError> {
+        self.unsupported("actor_index_remove")
+    }
+
+    fn actor_index_scan_keys(
+        &mut self,
+        _object_handle: ActorStateHandle,
+        _collection_index: CollectionIndex,
+        _limit: u32,
+    ) -> Result<Vec<Vec<u8>>, RuntimeError> {
+        self.unsupported("actor_index_scan_keys")
+    }
+
+    fn actor_index_scan_keys_after(
+        &mut self,
+        _object_handle: ActorStateHandle,
+        _collection_index: CollectionIndex,
+        _after: Option<Vec<u8>>,
+        _limit: u32,
+    ) -> Result<Vec<Vec<u8>>, RuntimeError> {
+        self.unsupported("actor_index_scan_keys_after")
+    }
+
+    fn actor_index_drain(
+        &mut self,
+        _object_handle: ActorStateHandle,
+        _collection_index: CollectionIndex,
+        _limit: u32,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>, RuntimeError> {
+        self.unsupported("actor_index_drain")
+    }
+}
+
+impl ClientFieldApi<RuntimeError> for HarnessApi {
+    fn field_read(&mut self, handle: FieldHandle) -> Result<Vec<u8>, RuntimeError> {
+        let (node_id, field) = match self.open_fields.get(&handle) {
+            Some(field) => *field,
+            None => return self.unsupported("field_handle"),
+        };
+        match self
+            .objects
+            .get(&node_id)
+            .and_then(|object| object.fields.get(&field))
+        {
+            Some(value) => Ok(value.clone()),
+            None => self.fail(BlueprintHarnessError::MissingField(field)),
+        }
+    }
+
+    fn field_write(&mut self, handle: FieldHandle, buffer: Vec<u8>) -> Result<(), RuntimeError> {
+        let (node_id, field) = match self.open_fields.get(&handle) {
+            Some(field) => *field,
+            None => return self.unsupported("field_handle"),
+        };
+        let blueprint_id = BlueprintId::new(&self.package_address, self.blueprint_name.clone());
+        self.objects
+            .entry(node_id)
+            .or_insert_with(|| HarnessObject {
+                blueprint_id,
+                fields: index_map_new(),
+            })
+            .fields
+            .insert(field, buffer);
+        Ok(())
+    }
+
+    fn field_lock(&mut self, _handle: FieldHandle) -> Result<(), RuntimeError> {
+        Ok(())
+    }
+
+    fn field_close(&mut self, handle: FieldHandle) -> Result<(), RuntimeError> {
+        self.open_fields.swap_remove(&handle);
+        Ok(())
+    }
+}
+
+impl ClientBlueprintApi<RuntimeError> for HarnessApi {
+    /// Answers calls with the registered stubs, and creates the attached modules of a component
+    /// being globalized as empty objects.
+    fn call_function(
+        &mut self,
+        package_address: PackageAddress,
+        blueprint_name: &str,
+        function_name: &str,
+        args: Vec<u8>,
+    ) -> Result<Vec<u8>, RuntimeError> {
+        let key = (
+            package_address,
+            blueprint_name.to_string(),
+            function_name.to_string(),
+        );
+        if let Some(stub) = self.function_stubs.get_mut(&key) {
+            return Ok(stub(&args));
+        }
+
+        if package_address == ROLE_ASSIGNMENT_MODULE_PACKAGE
+            || package_address == METADATA_MODULE_PACKAGE
+            || package_address == ROYALTY_MODULE_PACKAGE
+        {
+            let node_id = self.create_object(BlueprintId::new(&package_address, blueprint_name));
+            return Ok(scrypto_encode(&Own(node_id)).unwrap());
+        }
+
+        self.fail(BlueprintHarnessError::UnstubbedCall(format!(
+            "{:?}::{}::{}",
+            key.0, key.1, key.2
+        )))
+    }
+
+    fn resolve_blueprint_type(
+        &mut self,
+        _blueprint_type_id: &BlueprintTypeIdentifier,
+    ) -> Result<(Rc<VersionedScryptoSchema>, ScopedTypeId), RuntimeError> {
+        self.unsupported("resolve_blueprint_type")
+    }
+}
+
+impl ClientCostingApi<RuntimeError> for HarnessApi {
+    fn start_lock_fee(&mut self, _amount: Decimal) -> Result<bool, RuntimeError> {
+        self.unsupported("start_lock_fee")
+    }
+
+    fn lock_fee(
+        &mut self,
+        _locked_fee: LiquidFungibleResource,
+        _contingent: bool,
+        _refund_vault_id: Option<NodeId>,
+    ) {
+        self.failure
+            .get_or_insert(BlueprintHarnessError::Unsupported("lock_fee"));
+    }
+
+    fn consume_cost_units(
+        &mut self,
+        _costing_entry: ClientCostingEntry,
+    ) -> Result<(), RuntimeError> {
+        // The harness runs without a fee reserve
+        Ok(())
+    }
+
+    fn execution_cost_unit_limit(&mut self) -> Result<u32, RuntimeError> {
+        Ok(CostingParameters::default().execution_cost_unit_limit)
+    }
+
+    fn execution_cost_unit_price(&mut self) -> Result<Decimal, RuntimeError> {
+        Ok(CostingParameters::default().execution_cost_unit_price)
+    }
+
+    fn finalization_cost_unit_limit(&mut self) -> Result<u32, RuntimeError> {
+        Ok(CostingParameters::default().finalization_cost_unit_limit)
+    }
+
+    fn finalization_cost_unit_price(&mut self) -> Result<Decimal, RuntimeError> {
+        Ok(CostingParameters::default().finalization_cost_unit_price)
+    }
+
+    fn usd_price(&mut self) -> Result<Decimal, RuntimeError> {
+        Ok(CostingParameters::default().usd_price)
+    }
+
+    fn max_per_function_royalty_in_xrd(&mut self) -> Result<Decimal, RuntimeError> {
+        Ok(Decimal::from_str(MAX_PER_FUNCTION_ROYALTY_IN_XRD).unwrap())
+    }
+
+    fn tip_percentage(&mut self) -> Result<u32, RuntimeError> {
+        Ok(0)
+    }
+
+    fn tip_basis_points(&mut self) -> Result<u32, RuntimeError> {
+        Ok(0)
+    }
+
+    fn fee_balance(&mut self) -> Result<Decimal, RuntimeError> {
+        Ok(Decimal::ZERO)
+    }
+
+    fn set_instruction_cost_unit_limit(&mut self, _limit: u32) -> Result<(), RuntimeError> {
+        self.unsupported("set_instruction_cost_unit_limit")
+    }
+}
+
+impl ClientTransactionRuntimeApi<RuntimeError> for HarnessApi {
+    fn bech32_encode_address(&mut self, address: GlobalAddress) -> Result<String, RuntimeError> {
+        Ok(AddressBech32Encoder::for_simulator()
+            .encode(address.as_node_id().as_bytes())
+            .unwrap())
+    }
+
+    fn get_transaction_hash(&mut self) -> Result<Hash, RuntimeError> {
+        Ok(Hash([0u8; 32]))
+    }
+
+    fn generate_ruid(&mut self) -> Result<[u8; 32], RuntimeError> {
+        self.next_id += 1;
+        Ok(hash(self.next_id.to_le_bytes()).0)
+    }
+
+    fn generate_ruid_seeded(&mut self, domain: &[u8]) -> Result<[u8; 32], RuntimeError> {
+        self.next_id += 1;
+        let mut bytes = self.next_id.to_le_bytes().to_vec();
+        bytes.extend(domain);
+        Ok(hash(bytes).0)
+    }
+
+    fn emit_log(&mut self, level: Level, message: String) -> Result<(), RuntimeError> {
+        self.logs.push((level, message));
+        Ok(())
+    }
+
+    /// Records the level and message only, as the harness has no receipt to put the topic and
+    /// payload in.
+    fn emit_log_record(
+        &mut self,
+        level: Level,
+        _topic: String,
+        message: String,
+        _payload: Option<Vec<u8>>,
+    ) -> Result<(), RuntimeError> {
+        self.emit_log(level, message)
+    }
+
+    fn panic(&mut self, message: String) -> Result<(), RuntimeError> {
+        self.fail(BlueprintHarnessError::Panic(message))
+    }
+}
+
+impl ClientExecutionTraceApi<RuntimeError> for HarnessApi {
+    fn update_instruction_index(&mut self, _new_index: usize) -> Result<(), RuntimeError> {
+        Ok(())
+    }
+}
+
+impl ClientCryptoUtilsApi<RuntimeError> for HarnessApi {
+    fn ed25519_verify(
+        &mut self,
+        message: &[u8],
+        public_key: &Ed25519PublicKey,
+        signature: &Ed25519Signature,
+    ) -> Result<bool, RuntimeError> {
+        Ok(verify_ed25519(&hash(message), public_key, signature))
+    }
+
+    fn secp256k1_verify(
+        &mut self,
+        message: &[u8],
+        public_key: &Secp256k1PublicKey,
+        signature: &Secp256k1Signature,
+    ) -> Result<bool, RuntimeError> {
+        Ok(verify_secp256k1(&hash(message), public_key, signature))
+    }
+
+    fn bls12381_v1_verify(
+        &mut self,
+        message: &[u8],
+        public_key: &Bls12381G1PublicKey,
+        signature: &Bls12381G2Signature,
+    ) -> Result<bool, RuntimeError> {
+        Ok(verify_bls12381_v1(message, public_key, signature))
+    }
+
+    fn keccak256_hash(&mut self, data: &[u8]) -> Result<Hash, RuntimeError> {
+        Ok(keccak256_hash(data))
+    }
+
+    fn blake2b_256_hash(&mut self, data: &[u8]) -> Result<Hash, RuntimeError> {
+        Ok(blake2b_256_hash(data))
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{TestDatabase, TestRunner};
+use radix_engine::types::*;
+use radix_engine::vm::NativeVmExtension;
+
+/// A view over the application events emitted by all transactions committed by a [`TestRunner`]
+/// (including the genesis transactions), in the order in which they were emitted.
+///
+/// The stream can be narrowed down to the events emitted by a specific node, or to the events
+/// emitted since a given [`position`](EventStream::position) - which makes it possible to assert
+/// on sequences of events spanning multiple transactions.
+pub struct EventStream<'a, E: NativeVmExtension, D: TestDatabase> {
+    test_runner: &'a TestRunner<E, D>,
+    emitter: Option<NodeId>,
+    since_transaction_index: usize,
+}
+
+/// An event from an [`EventStream`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StreamedEvent<'a> {
+    /// The index of the emitting transaction, amongst all the committed transactions.
+    pub transaction_index: usize,
+    pub event_type_identifier: &'a EventTypeIdentifier,
+    pub event_data: &'a [u8],
+}
+
+impl<'a, E: NativeVmExtension, D: TestDatabase> EventStream<'a, E, D> {
+    pub(crate) fn new(test_runner: &'a TestRunner<E, D>) -> Self {
+        Self {
+            test_runner,
+            emitter: None,
+            since_transaction_index: 0,
+        }
+    }
+
+    /// The current end of the stream, to be used with [`since`](EventStream::since) later on.
+    pub fn position(&self) -> usize {
+        self.test_runner.collected_events().len()
+    }
+
+    /// Only includes events from the transactions committed after the given position was taken.
+    pub fn since(mut self, position: usize) -> Self {
+        self.since_transaction_index = position;
+        self
+    }
+
+    /// Only includes events emitted by the given node.
+    /// For events emitted by blueprint functions, this is the node of the blueprint's package.
+    pub fn emitted_by<T: Into<NodeId>>(mut self, node_id: T) -> Self {
+        self.emitter = Some(node_id.into());
+        self
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = StreamedEvent<'a>> + 'a {
+        let emitter = self.emitter;
+        let test_runner = self.test_runner;
+        test_runner
+            .collected_events()
+            .iter()
+            .enumerate()
+            .skip(self.since_transaction_index)
+            .flat_map(|(transaction_index, events)| {
+                events
+                    .iter()
+                    .map(move |(event_type_identifier, event_data)| StreamedEvent {
+                        transaction_index,
+                        event_type_identifier,
+                        event_data: event_data.as_slice(),
+                    })
+            })
+            .filter(move |event| match &emitter {
+                Some(node_id) => Self::emitter_node_id(event.event_type_identifier) == *node_id,
+                None => true,
+            })
+    }
+
+    /// Decodes all events of the given type.
+    ///
+    /// Events are matched by their type name, so an event of a different type with the same name
+    /// (e.g. from another blueprint) is skipped if it can't be decoded as `T`. Use
+    /// [`emitted_by`](EventStream::emitted_by) to remove any ambiguity.
+    pub fn iter_events_of_type<T: ScryptoEvent>(&self) -> impl Iterator<Item = T> + 'a {
+        let test_runner = self.test_runner;
+        self.iter()
+            .filter(move |event| test_runner.is_event_name_equal::<T>(event.event_type_identifier))
+            .filter_map(|event| scrypto_decode::<T>(event.event_data).ok())
+    }
+
+    fn emitter_node_id(event_type_identifier: &EventTypeIdentifier) -> NodeId {
+        match &event_type_identifier.0 {
+            Emitter::Method(node_id, _) => *node_id,
+            Emitter::Function(blueprint_id) => blueprint_id.package_address.into_node_id(),
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use radix_engine_store_interface::interface::{
+    CommittableSubstateDatabase, DatabaseUpdates, DbPartitionKey, DbSortKey, DbSubstateValue,
+    ListableSubstateDatabase, PartitionEntry, SubstateDatabase,
+};
+use std::cell::Cell;
+use std::time::Duration;
+
+/// What a [`FailingSubstateDatabase`] does on a read which triggers a fault.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DatabaseFault {
+    /// Reads nothing, as if the substate (or partition) was missing from a misconfigured
+    /// database, or couldn't be read due to an IO failure.
+    ///
+    /// As [`SubstateDatabase`] reads can't fail, this is also how IO errors are injected.
+    Missing,
+    /// Waits for the given duration, then reads normally.
+    Delay(Duration),
+}
+
+/// Which reads of a [`FailingSubstateDatabase`] trigger a fault.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FaultTrigger {
+    /// Any read of the given partition, or of a single substate if a sort key is given. Listing
+    /// the partition leaves out a faulty substate, but still lists the others.
+    Key(DbPartitionKey, Option<DbSortKey>),
+    /// The read of the given index (starting at zero), counting from when the fault got
+    /// injected.
+    NthRead(usize),
+}
+
+#[derive(Debug, Clone)]
+struct InjectedFault {
+    trigger: FaultTrigger,
+    fault: DatabaseFault,
+    reads_before_injection: usize,
+}
+
+/// A database which reads from an underlying database, except for the reads which trigger an
+/// injected fault.
+///
+/// Faults are usually injected once the test runner is built, through
+/// [`TestRunner::substate_db_mut`](crate::TestRunner::substate_db_mut), so that they don't
+/// affect the bootstrapping of the ledger. Commits are never faulty.
+pub struct FailingSubstateDatabase<D> {
+    underlying: D,
+    faults: Vec<InjectedFault>,
+    reads: Cell<usize>,
+    triggered_faults: Cell<usize>,
+}
+
+impl<D> FailingSubstateDatabase<D> {
+    pub fn new(underlying: D) -> Self {
+        Self {
+            underlying,
+            faults: Vec::new(),
+            reads: Cell::new(0),
+            triggered_faults: Cell::new(0),
+        }
+    }
+
+    pub fn inject_fault(&mut self, trigger: FaultTrigger, fault: DatabaseFault) {
+        self.faults.push(InjectedFault {
+            trigger,
+            fault,
+            reads_before_injection: self.reads.get(),
+        });
+    }
+
+    pub fn clear_faults(&mut self) {
+        self.faults.clear();
+    }
+
+    /// The number of reads made so far.
+    pub fn reads(&self) -> usize {
+        self.reads.get()
+    }
+
+    /// The number of reads which triggered a fault so far.
+    pub fn triggered_faults(&self) -> usize {
+        self.triggered_faults.get()
+    }
+
+    pub fn underlying(&self) -> &D {
+        &self.underlying
+    }
+
+    pub fn into_underlying(self) -> D {
+        self.underlying
+    }
+
+    /// Counts a read of a substate, or of a whole partition if no sort key is given, and applies
+    /// the fault it triggers, if any. Returns whether the read should find nothing.
+    ///
+    /// Faults on single substates don't apply to partition reads, see
+    /// [`Self::without_faulty_entries`].
+    fn read(&self, partition_key: &DbPartitionKey, sort_key: Option<&DbSortKey>) -> bool {
+        let read_index = self.reads.get();
+        self.reads.set(read_index + 1);
+
+        let fault = self.faults.iter().find(|fault| match &fault.trigger {
+            FaultTrigger::Key(fault_partition_key, fault_sort_key) => {
+                fault_partition_key == partition_key
+                    && match (fault_sort_key, sort_key) {
+                        (None, _) => true,
+                        (Some(fault_sort_key), Some(sort_key)) => fault_sort_key == sort_key,
+                        (Some(_), None) => false,
+                    }
+            }
+            FaultTrigger::NthRead(n) => read_index - fault.reads_before_injection == *n,
+        });
+        match fault {
+            Some(fault) => self.apply(fault),
+            None => false,
+        }
+    }
+
+    /// Applies the faults on single substates of the partition to the listed entries: a missing
+    /// substate is left out, while the others are still listed.
+    fn without_faulty_entries<'a>(
+        &'a self,
+        partition_key: &DbPartitionKey,
+        entries: Box<dyn Iterator<Item = PartitionEntry> + 'a>,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + 'a> {
+        let partition_key = partition_key.clone();
+        Box::new(entries.filter(move |(sort_key, _)| {
+            let fault = self.faults.iter().find(|fault| {
+                matches!(
+                    &fault.trigger,
+                    FaultTrigger::Key(fault_partition_key, Some(fault_sort_key))
+                        if *fault_partition_key == partition_key && fault_sort_key == sort_key
+                )
+            });
+            match fault {
+                Some(fault) => !self.apply(fault),
+                None => true,
+            }
+        }))
+    }
+
+    /// Counts a triggered fault and applies it. Returns whether the read should find nothing.
+    fn apply(&self, fault: &InjectedFault) -> bool {
+        self.triggered_faults.set(self.triggered_faults.get() + 1);
+        match &fault.fault {
+            DatabaseFault::Missing => true,
+            DatabaseFault::Delay(duration) => {
+                std::thread::sleep(*duration);
+                false
+            }
+        }
+    }
+}
+
+impl<D: SubstateDatabase> SubstateDatabase for FailingSubstateDatabase<D> {
+    fn get_substate(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Option<DbSubstateValue> {
+        if self.read(partition_key, Some(sort_key)) {
+            return None;
+        }
+        self.underlying.get_substate(partition_key, sort_key)
+    }
+
+    fn list_entries(
+        &self,
+        partition_key: &DbPartitionKey,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        if self.read(partition_key, None) {
+            return Box::new(std::iter::empty());
+        }
+        self.without_faulty_entries(partition_key, self.underlying.list_entries(partition_key))
+    }
+
+    fn list_entries_from(
+        &self,
+        partition_key: &DbPartitionKey,
+        from_sort_key: Option<&DbSortKey>,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        if self.read(partition_key, None) {
+            return Box::new(std::iter::empty());
+        }
+        self.without_faulty_entries(
+            partition_key,
+            self.underlying
+                .list_entries_from(partition_key, from_sort_key),
+        )
+    }
+}
+
+impl<D: ListableSubstateDatabase> ListableSubstateDatabase for FailingSubstateDatabase<D> {
+    fn list_partition_keys(&self) -> Box<dyn Iterator<Item = DbPartitionKey> + '_> {
+        self.underlying.list_partition_keys()
+    }
+}
+
+impl<D: CommittableSubstateDatabase> CommittableSubstateDatabase for FailingSubstateDatabase<D> {
+    fn commit(&mut self, database_updates: &DatabaseUpdates) {
+        self.underlying.commit(database_updates);
+    }
+}
@@ -1,3 +1,4 @@
+use radix_engine::blueprints::resource::AuthZone;
 use radix_engine::errors::{RuntimeError, SystemModuleError};
 use radix_engine::kernel::call_frame::{CallFrameMessage, NodeVisibility};
 use radix_engine::kernel::kernel_api::{
@@ -476,10 +477,11 @@ impl<'a, M: KernelCallbackObject, Y: KernelApi<InjectCostingError<M>>>
         &mut self,
         node_id: &NodeId,
         partition_num: PartitionNumber,
+        from_sort_prefix: Option<[u8; 2]>,
         count: u32,
     ) -> Result<Vec<(SortedKey, IndexedScryptoValue)>, RuntimeError> {
         self.api
-            .kernel_scan_sorted_substates(node_id, partition_num, count)
+            .kernel_scan_sorted_substates(node_id, partition_num, from_sort_prefix, count)
     }
 
     fn kernel_scan_keys<K: SubstateKeyContent + 'static>(
@@ -492,6 +494,17 @@ impl<'a, M: KernelCallbackObject, Y: KernelApi<InjectCostingError<M>>>
             .kernel_scan_keys::<K>(node_id, partition_num, count)
     }
 
+    fn kernel_scan_keys_after<K: SubstateKeyContent + 'static>(
+        &mut self,
+        node_id: &NodeId,
+        partition_num: PartitionNumber,
+        after: Option<&SubstateKey>,
+        count: u32,
+    ) -> Result<Vec<SubstateKey>, RuntimeError> {
+        self.api
+            .kernel_scan_keys_after::<K>(node_id, partition_num, after, count)
+    }
+
     fn kernel_drain_substates<K: SubstateKeyContent + 'static>(
         &mut self,
         node_id: &NodeId,
@@ -541,6 +554,10 @@ impl<'a, M: KernelCallbackObject, K: KernelApi<InjectCostingError<M>>> KernelInt
     fn kernel_read_proof(&mut self, proof_id: &NodeId) -> Option<ProofSnapshot> {
         self.api.kernel_read_proof(proof_id)
     }
+
+    fn kernel_read_auth_zone(&mut self, auth_zone_id: &NodeId) -> Option<AuthZone> {
+        self.api.kernel_read_auth_zone(auth_zone_id)
+    }
 }
 
 impl<'a, M: KernelCallbackObject, K: KernelApi<InjectCostingError<M>>> KernelApi<M>
@@ -584,4 +601,8 @@ impl<'a, M: KernelCallbackObject, K: KernelInternalApi<InjectCostingError<M>>> K
     fn kernel_read_proof(&mut self, proof_id: &NodeId) -> Option<ProofSnapshot> {
         self.api.kernel_read_proof(proof_id)
     }
+
+    fn kernel_read_auth_zone(&mut self, auth_zone_id: &NodeId) -> Option<AuthZone> {
+        self.api.kernel_read_auth_zone(auth_zone_id)
+    }
 }
@@ -1,7 +1,15 @@
+mod blueprint_harness;
+mod event_stream;
+mod failing_database;
 mod inject_costing_err;
+mod receipt_assertions;
 mod test_runner;
 mod utils;
 
 pub use crate::utils::*;
+pub use blueprint_harness::*;
+pub use event_stream::*;
+pub use failing_database::*;
 pub use inject_costing_err::*;
+pub use receipt_assertions::*;
 pub use test_runner::*;
@@ -0,0 +1,155 @@
+use crate::{SubtreeVaults, TestDatabase, TestRunner};
+use radix_engine::transaction::{BalanceChange, CommitResult, TransactionReceipt};
+use radix_engine::types::*;
+use radix_engine::vm::NativeVmExtension;
+
+/// Starts a chain of assertions on a receipt of a transaction executed by a [`TestRunner`].
+///
+/// ```ignore
+/// assert_receipt!(test_runner, receipt)
+///     .committed()
+///     .new_components(1)
+///     .event::<MintFungibleResourceEvent>(|e| e.amount == dec!("10"))
+///     .balance_change(account, token, dec!("-5"));
+/// ```
+#[macro_export]
+macro_rules! assert_receipt {
+    ($test_runner:expr, $receipt:expr) => {
+        $test_runner.assert_receipt(&$receipt)
+    };
+}
+
+/// Fluent assertions on a [`TransactionReceipt`], each of which panics with a description of the
+/// mismatch, and returns the assertions otherwise so that they can be chained.
+///
+/// The test runner is needed to find the vaults owned by a component, when asserting on its
+/// balance changes.
+pub struct ReceiptAssertions<'a, E: NativeVmExtension, D: TestDatabase> {
+    test_runner: &'a TestRunner<E, D>,
+    receipt: &'a TransactionReceipt,
+}
+
+impl<'a, E: NativeVmExtension, D: TestDatabase> ReceiptAssertions<'a, E, D> {
+    pub(crate) fn new(test_runner: &'a TestRunner<E, D>, receipt: &'a TransactionReceipt) -> Self {
+        Self {
+            test_runner,
+            receipt,
+        }
+    }
+
+    /// Asserts that the transaction was committed successfully.
+    pub fn committed(self) -> Self {
+        self.receipt.expect_commit_success();
+        self
+    }
+
+    /// Asserts that the transaction was committed as a failure.
+    pub fn failed(self) -> Self {
+        self.receipt.expect_commit_failure();
+        self
+    }
+
+    /// Asserts that the transaction was rejected.
+    pub fn rejected(self) -> Self {
+        self.receipt.expect_rejection();
+        self
+    }
+
+    /// Asserts that the transaction created exactly `count` global components.
+    pub fn new_components(self, count: usize) -> Self {
+        let new_components = self.commit().new_component_addresses();
+        if new_components.len() != count {
+            panic!(
+                "Expected {} new components, but got {}: {:?}",
+                count,
+                new_components.len(),
+                new_components
+            );
+        }
+        self
+    }
+
+    /// Asserts that the transaction created exactly `count` resources.
+    pub fn new_resources(self, count: usize) -> Self {
+        let new_resources = self.commit().new_resource_addresses();
+        if new_resources.len() != count {
+            panic!(
+                "Expected {} new resources, but got {}: {:?}",
+                count,
+                new_resources.len(),
+                new_resources
+            );
+        }
+        self
+    }
+
+    /// Asserts that the transaction emitted at least one event of the given type which satisfies
+    /// the predicate.
+    ///
+    /// As in [`EventStream::iter_events_of_type`](crate::EventStream::iter_events_of_type),
+    /// events are matched by their type name.
+    pub fn event<T: ScryptoEvent>(self, predicate: impl Fn(&T) -> bool) -> Self {
+        let events = self.test_runner.extract_events_of_type::<T>(self.commit());
+        if events.is_empty() {
+            panic!(
+                "Expected an event of type {}, but none was emitted",
+                T::EVENT_NAME
+            );
+        }
+        if !events.iter().any(predicate) {
+            panic!(
+                "None of the {} events of type {} satisfies the predicate",
+                events.len(),
+                T::EVENT_NAME
+            );
+        }
+        self
+    }
+
+    /// Asserts that the transaction emitted no event of the given type.
+    pub fn no_event<T: ScryptoEvent>(self) -> Self {
+        let events = self.test_runner.extract_events_of_type::<T>(self.commit());
+        if !events.is_empty() {
+            panic!(
+                "Expected no event of type {}, but {} were emitted",
+                T::EVENT_NAME,
+                events.len()
+            );
+        }
+        self
+    }
+
+    /// Asserts that the fungible balance of the resource, summed over all the vaults owned by the
+    /// given node, changed by exactly `amount` - zero meaning that it didn't change.
+    pub fn balance_change<T: Into<NodeId>>(
+        self,
+        owner: T,
+        resource_address: ResourceAddress,
+        amount: Decimal,
+    ) -> Self {
+        let owner: NodeId = owner.into();
+        let change = SubtreeVaults::new(self.test_runner.substate_db())
+            .sum_balance_changes(&owner, self.commit().vault_balance_changes())
+            .remove(&resource_address);
+        let actual_amount = match change {
+            None => Decimal::ZERO,
+            Some(BalanceChange::Fungible(actual_amount)) => actual_amount,
+            Some(change @ BalanceChange::NonFungible { .. }) => panic!(
+                "Expected a fungible balance change of {:?}, but got {:?}",
+                resource_address, change
+            ),
+        };
+        if actual_amount != amount {
+            panic!(
+                "Expected the balance of {:?} in {:?} to change by {}, but it changed by {}",
+                resource_address, owner, amount, actual_amount
+            );
+        }
+        self
+    }
+
+    /// The commit result, to carry on with custom assertions.
+    pub fn commit(&self) -> &'a CommitResult {
+        self.receipt.expect_commit_ignore_outcome()
+    }
+}
@@ -2,7 +2,9 @@ use std::ffi::OsStr;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::time::Duration;
 
+use crate::{EventStream, ReceiptAssertions};
 use radix_engine::blueprints::consensus_manager::*;
 use radix_engine::blueprints::models::FieldPayload;
 use radix_engine::blueprints::pool::one_resource_pool::ONE_RESOURCE_POOL_BLUEPRINT_IDENT;
@@ -11,14 +13,14 @@ use radix_engine::system::bootstrap::*;
 use radix_engine::system::checkers::*;
 use radix_engine::system::system_callback::SystemConfig;
 use radix_engine::system::system_db_reader::{
-    ObjectCollectionKey, SystemDatabaseReader, SystemDatabaseWriter,
+    ObjectCollectionKey, ResolvedPayloadSchema, SystemDatabaseReader, SystemDatabaseWriter,
 };
 use radix_engine::system::system_substates::FieldSubstate;
 use radix_engine::system::type_info::TypeInfoSubstate;
 use radix_engine::transaction::{
-    execute_preview, execute_transaction_with_system, BalanceChange, CommitResult,
-    CostingParameters, ExecutionConfig, PreviewError, TransactionReceipt, TransactionResult,
-    WrappedSystem,
+    estimate_fees, execute_preview, execute_transaction_with_system, BalanceChange, CommitResult,
+    CostingParameters, ExecutionConfig, FeeEstimate, FeeEstimationConfig, FeeEstimationError,
+    PreviewError, PreviewExecutorPool, TransactionReceipt, TransactionResult, WrappedSystem,
 };
 use radix_engine::types::*;
 use radix_engine::utils::*;
@@ -176,16 +178,86 @@ impl CustomGenesis {
     }
 
     pub fn with_faucet_supply(faucet_supply: Decimal) -> CustomGenesis {
+        CustomGenesis {
+            faucet_supply,
+            ..Self::empty(Epoch::of(1u64), Self::default_consensus_manager_config())
+        }
+    }
+
+    /// A genesis without any validators, stakes or balances, to be built up with
+    /// [`Self::with_validator`], [`Self::with_xrd_balances`] and [`Self::with_resource`].
+    pub fn empty(genesis_epoch: Epoch, initial_config: ConsensusManagerConfig) -> CustomGenesis {
         CustomGenesis {
             genesis_data_chunks: vec![],
-            genesis_epoch: Epoch::of(1u64),
-            initial_config: Self::default_consensus_manager_config(),
+            genesis_epoch,
+            initial_config,
             initial_time_ms: 0,
             initial_current_leader: None,
-            faucet_supply,
+            faucet_supply: *DEFAULT_TESTING_FAUCET_SUPPLY,
         }
     }
 
+    /// Adds a validator, along with the XRD staked to it by the given account.
+    pub fn with_validator(
+        mut self,
+        validator: GenesisValidator,
+        stake_xrd_amount: Decimal,
+        staker_account: ComponentAddress,
+    ) -> Self {
+        let key = validator.key;
+        self.genesis_data_chunks
+            .push(GenesisDataChunk::Validators(vec![validator]));
+        self.genesis_data_chunks.push(GenesisDataChunk::Stakes {
+            accounts: vec![staker_account],
+            allocations: vec![(
+                key,
+                vec![GenesisStakeAllocation {
+                    account_index: 0,
+                    xrd_amount: stake_xrd_amount,
+                }],
+            )],
+        });
+        self.initial_current_leader.get_or_insert(0);
+        self
+    }
+
+    /// Adds XRD, minted at genesis, to the given accounts.
+    pub fn with_xrd_balances(mut self, balances: Vec<(ComponentAddress, Decimal)>) -> Self {
+        self.genesis_data_chunks
+            .push(GenesisDataChunk::XrdBalances(balances));
+        self
+    }
+
+    /// Adds a fungible resource, created at genesis with the given initial balances.
+    pub fn with_resource(
+        mut self,
+        resource: GenesisResource,
+        balances: Vec<(ComponentAddress, Decimal)>,
+    ) -> Self {
+        let resource_address = resource.reserved_resource_address;
+        let (accounts, allocations) = balances
+            .into_iter()
+            .enumerate()
+            .map(|(account_index, (account, amount))| {
+                (
+                    account,
+                    GenesisResourceAllocation {
+                        account_index: account_index as u32,
+                        amount,
+                    },
+                )
+            })
+            .unzip();
+        self.genesis_data_chunks
+            .push(GenesisDataChunk::Resources(vec![resource]));
+        self.genesis_data_chunks
+            .push(GenesisDataChunk::ResourceBalances {
+                accounts,
+                allocations: vec![(resource_address, allocations)],
+            });
+        self
+    }
+
     pub fn default_with_xrd_amount(
         xrd_amount: Decimal,
         genesis_epoch: Epoch,
@@ -299,12 +371,30 @@ impl<T: SubstateDatabase + CommittableSubstateDatabase + ListableSubstateDatabas
 
 pub type DefaultTestRunner = TestRunner<NoExtension, InMemorySubstateDatabase>;
 
+/// How a [`TestRunner`] moves the ledger forward after each manifest it executes, see
+/// [`TestRunnerBuilder::with_auto_advance`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AdvancePolicy {
+    /// The ledger only moves forward through explicit calls, such as
+    /// [`TestRunner::advance_time`]
+    #[default]
+    Manual,
+    /// The given number of rounds pass, without the clock moving forward
+    Rounds(u64),
+    /// A new round starts, with the clock moving forward by the given duration
+    Time(Duration),
+    /// The given number of epochs pass, as with [`TestRunner::set_epoch`]
+    Epochs(u64),
+}
+
 pub struct TestRunnerBuilder<E, D> {
     custom_genesis: Option<CustomGenesis>,
     custom_extension: E,
     custom_database: D,
     trace: bool,
     skip_receipt_check: bool,
+    use_global_wasm_cache: bool,
+    auto_advance: AdvancePolicy,
 }
 
 impl TestRunnerBuilder<NoExtension, InMemorySubstateDatabase> {
@@ -315,6 +405,8 @@ impl TestRunnerBuilder<NoExtension, InMemorySubstateDatabase> {
             custom_database: InMemorySubstateDatabase::standard(),
             trace: true,
             skip_receipt_check: false,
+            use_global_wasm_cache: true,
+            auto_advance: AdvancePolicy::Manual,
         }
     }
 }
@@ -332,6 +424,8 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
             custom_database: HashTreeUpdatingDatabase::new(self.custom_database),
             trace: self.trace,
             skip_receipt_check: false,
+            use_global_wasm_cache: self.use_global_wasm_cache,
+            auto_advance: self.auto_advance,
         }
     }
 
@@ -345,6 +439,35 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
         self
     }
 
+    /// Compiles the WASM code with an engine of its own, rather than sharing the compiled modules
+    /// with the other test runners of the process.
+    pub fn without_global_wasm_cache(mut self) -> Self {
+        self.use_global_wasm_cache = false;
+        self
+    }
+
+    /// Moves the ledger forward according to the policy after each executed manifest, so that
+    /// long test flows don't have to interleave their manifests with round or epoch changes.
+    ///
+    /// The ledger moves forward whatever the outcome of the manifest, but not after the system
+    /// transactions executed by the test runner itself.
+    pub fn with_auto_advance(mut self, policy: AdvancePolicy) -> Self {
+        self.auto_advance = policy;
+        self
+    }
+
+    fn new_scrypto_vm(&self) -> ScryptoVm<DefaultWasmEngine> {
+        let wasm_engine = if self.use_global_wasm_cache {
+            DefaultWasmEngine::with_global_cache()
+        } else {
+            DefaultWasmEngine::default()
+        };
+        ScryptoVm {
+            wasm_engine,
+            wasm_validator_config: WasmValidatorConfigV1::new(),
+        }
+    }
+
     pub fn with_custom_extension<NE: NativeVmExtension>(
         self,
         extension: NE,
@@ -355,6 +478,8 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
             custom_database: self.custom_database,
             trace: self.trace,
             skip_receipt_check: self.skip_receipt_check,
+            use_global_wasm_cache: self.use_global_wasm_cache,
+            auto_advance: self.auto_advance,
         }
     }
 
@@ -365,6 +490,8 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
             custom_database: database,
             trace: self.trace,
             skip_receipt_check: self.skip_receipt_check,
+            use_global_wasm_cache: self.use_global_wasm_cache,
+            auto_advance: self.auto_advance,
         }
     }
 
@@ -380,7 +507,7 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
         //----------------------------------------------------------------
 
         TestRunner {
-            scrypto_vm: ScryptoVm::default(),
+            scrypto_vm: self.new_scrypto_vm(),
             native_vm: NativeVm::new_with_extension(self.custom_extension),
             database: snapshot.database,
             next_private_key: snapshot.next_private_key,
@@ -389,6 +516,7 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
             collected_events: snapshot.collected_events,
             xrd_free_credits_used: snapshot.xrd_free_credits_used,
             skip_receipt_check: snapshot.skip_receipt_check,
+            auto_advance: self.auto_advance,
         }
     }
 
@@ -402,10 +530,7 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
         let trace = false;
         //----------------------------------------------------------------
 
-        let scrypto_vm = ScryptoVm {
-            wasm_engine: DefaultWasmEngine::default(),
-            wasm_validator_config: WasmValidatorConfigV1::new(),
-        };
+        let scrypto_vm = self.new_scrypto_vm();
         let native_vm = NativeVm::new_with_extension(self.custom_extension);
         let vm = Vm::new(&scrypto_vm, native_vm.clone());
         let mut substate_db = self.custom_database;
@@ -467,6 +592,7 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunnerBuilder<E, D> {
             collected_events: events,
             xrd_free_credits_used: false,
             skip_receipt_check: self.skip_receipt_check,
+            auto_advance: self.auto_advance,
         };
 
         let next_epoch = wrap_up_receipt
@@ -491,6 +617,7 @@ pub struct TestRunner<E: NativeVmExtension, D: TestDatabase> {
     collected_events: Vec<Vec<(EventTypeIdentifier, Vec<u8>)>>,
     xrd_free_credits_used: bool,
     skip_receipt_check: bool,
+    auto_advance: AdvancePolicy,
 }
 
 #[cfg(feature = "post_run_db_check")]
@@ -500,6 +627,35 @@ impl<E: NativeVmExtension, D: TestDatabase> Drop for TestRunner<E, D> {
     }
 }
 
+/// The receipts of the transactions executed by [`TestRunner::execute_batch`].
+pub struct BatchReceipt {
+    /// The receipts of the executed transactions, in order
+    pub receipts: Vec<TransactionReceipt>,
+    /// The index of the transaction which wasn't committed successfully, if any, in which case
+    /// it's the last one executed
+    pub failed_at: Option<usize>,
+}
+
+impl BatchReceipt {
+    pub fn is_success(&self) -> bool {
+        self.failed_at.is_none()
+    }
+
+    pub fn failed_receipt(&self) -> Option<&TransactionReceipt> {
+        self.failed_at.map(|index| &self.receipts[index])
+    }
+
+    pub fn expect_success(&self) -> &Vec<TransactionReceipt> {
+        if let Some(index) = self.failed_at {
+            panic!(
+                "Expected batch to succeed but transaction {} failed: {:?}",
+                index, self.receipts[index]
+            );
+        }
+        &self.receipts
+    }
+}
+
 #[derive(Clone)]
 pub struct TestRunnerSnapshot {
     database: InMemorySubstateDatabase,
@@ -549,6 +705,19 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
         self.collected_events.as_ref()
     }
 
+    /// All the events emitted by the committed transactions - see [`EventStream`].
+    pub fn event_stream(&self) -> EventStream<'_, E, D> {
+        EventStream::new(self)
+    }
+
+    /// Fluent assertions on the receipt of a transaction - see [`ReceiptAssertions`].
+    pub fn assert_receipt<'a>(
+        &'a self,
+        receipt: &'a TransactionReceipt,
+    ) -> ReceiptAssertions<'a, E, D> {
+        ReceiptAssertions::new(self, receipt)
+    }
+
     pub fn next_private_key(&mut self) -> u64 {
         self.next_private_key += 1;
         self.next_private_key - 1
@@ -888,6 +1057,73 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
         reader.read_typed_kv_entry(kv_store_id.as_node_id(), key)
     }
 
+    /// Reads the state of a component, i.e. field 0 of its main module, and decodes it into `T`.
+    ///
+    /// Unlike [`Self::component_state`], the stored state is first validated against the state
+    /// schema of the blueprint, so that a mismatch with `T` is reported with the blueprint's
+    /// type name and the location of the offending value.
+    pub fn read_component_state<T: ScryptoDecode>(&self, component_address: ComponentAddress) -> T {
+        let node_id = component_address.as_node_id();
+        let reader = SystemDatabaseReader::new(self.substate_db());
+        let target = reader
+            .get_blueprint_type_target(node_id, ModuleId::Main)
+            .expect("Component does not exist");
+        let state_schema = reader
+            .get_blueprint_payload_schema(&target, &BlueprintPayloadIdentifier::Field(0))
+            .expect("Component has no state schema");
+        let state = reader
+            .read_object_field(node_id, ModuleId::Main, 0)
+            .expect("Component has no state");
+
+        decode_against_schema(
+            &reader,
+            state.as_slice(),
+            &state_schema,
+            BLUEPRINT_PAYLOAD_MAX_DEPTH,
+        )
+    }
+
+    /// Reads the value of a key value store entry, and decodes it into `V`.
+    ///
+    /// The key and the value are validated against the schemas of the store, so that a mismatch
+    /// with `K` or `V` is reported rather than reading as a missing entry.
+    pub fn read_kv_entry<K: ScryptoEncode, V: ScryptoDecode>(
+        &self,
+        kv_store_id: Own,
+        key: &K,
+    ) -> Option<V> {
+        let node_id = kv_store_id.as_node_id();
+        let reader = SystemDatabaseReader::new(self.substate_db());
+        let target = reader
+            .get_kv_store_type_target(node_id)
+            .expect("Key value store does not exist");
+
+        let key_schema = reader
+            .get_kv_store_payload_schema(&target, KeyOrValue::Key)
+            .expect("Key value store has no key schema");
+        let key_payload = scrypto_encode(key).unwrap();
+        if let Err(err) =
+            reader.validate_payload(&key_payload, &key_schema, KEY_VALUE_STORE_PAYLOAD_MAX_DEPTH)
+        {
+            panic!(
+                "Key does not match the key schema of the store: {}",
+                err.error_message(key_schema.schema.v1())
+            );
+        }
+
+        let value_schema = reader
+            .get_kv_store_payload_schema(&target, KeyOrValue::Value)
+            .expect("Key value store has no value schema");
+        let value = reader.read_typed_kv_entry::<K, ScryptoValue>(node_id, key)?;
+
+        Some(decode_against_schema(
+            &reader,
+            &scrypto_encode(&value).unwrap(),
+            &value_schema,
+            KEY_VALUE_STORE_PAYLOAD_MAX_DEPTH,
+        ))
+    }
+
     pub fn load_account_from_faucet(&mut self, account_address: ComponentAddress) {
         let manifest = ManifestBuilder::new()
             .lock_fee_from_faucet()
@@ -1447,6 +1683,68 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
         )
     }
 
+    /// Executes the manifest and writes its execution trace to the given path, in the Chrome
+    /// trace-event JSON format (see
+    /// [`radix_engine::transaction::TransactionExecutionTrace::to_chrome_trace_json`]).
+    pub fn execute_manifest_with_chrome_trace<T, P: AsRef<Path>>(
+        &mut self,
+        manifest: TransactionManifestV1,
+        initial_proofs: T,
+        path: P,
+    ) -> TransactionReceipt
+    where
+        T: IntoIterator<Item = NonFungibleGlobalId>,
+    {
+        let nonce = self.next_transaction_nonce();
+        let receipt = self.execute_transaction(
+            TestTransaction::new_from_nonce(manifest, nonce)
+                .prepare()
+                .expect("expected transaction to be preparable")
+                .get_executable(initial_proofs.into_iter().collect()),
+            CostingParameters::default(),
+            ExecutionConfig::for_test_transaction().with_execution_trace(true),
+        );
+        self.auto_advance();
+        if let TransactionResult::Commit(commit) = &receipt.result {
+            if let Some(execution_trace) = &commit.execution_trace {
+                fs::write(path, execution_trace.to_chrome_trace_json())
+                    .expect("failed to write chrome trace");
+            }
+        }
+        receipt
+    }
+
+    /// Executes the manifests one after the other, each as its own transaction with its own
+    /// nonce, stopping at the first one which isn't committed successfully.
+    pub fn execute_batch<T>(
+        &mut self,
+        manifests: Vec<TransactionManifestV1>,
+        initial_proofs: T,
+    ) -> BatchReceipt
+    where
+        T: IntoIterator<Item = NonFungibleGlobalId>,
+    {
+        let initial_proofs: BTreeSet<NonFungibleGlobalId> = initial_proofs.into_iter().collect();
+        let mut receipts = Vec::new();
+        let mut failed_at = None;
+        for (index, manifest) in manifests.into_iter().enumerate() {
+            let receipt = self.execute_manifest(manifest, initial_proofs.clone());
+            let is_success = receipt.is_commit_success();
+            receipts.push(receipt);
+            if !is_success {
+                failed_at = Some(index);
+                break;
+            }
+        }
+
+        BatchReceipt {
+            receipts,
+            failed_at,
+        }
+    }
+
+    /// Note: as the test runner stays borrowed by the system, the ledger is not moved forward
+    /// afterwards, whatever the [`AdvancePolicy`].
     pub fn execute_manifest_with_system<'a, T, R: WrappedSystem<Vm<'a, DefaultWasmEngine, E>>>(
         &'a mut self,
         manifest: TransactionManifestV1,
@@ -1478,14 +1776,16 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
         T: IntoIterator<Item = NonFungibleGlobalId>,
     {
         let nonce = self.next_transaction_nonce();
-        self.execute_transaction(
+        let receipt = self.execute_transaction(
             TestTransaction::new_from_nonce(manifest, nonce)
                 .prepare()
                 .expect("expected transaction to be preparable")
                 .get_executable(initial_proofs.into_iter().collect()),
             costing_parameters,
             ExecutionConfig::for_test_transaction(),
-        )
+        );
+        self.auto_advance();
+        receipt
     }
 
     pub fn execute_manifest_with_execution_cost_unit_limit<T>(
@@ -1498,14 +1798,16 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
         T: IntoIterator<Item = NonFungibleGlobalId>,
     {
         let nonce = self.next_transaction_nonce();
-        self.execute_transaction(
+        let receipt = self.execute_transaction(
             TestTransaction::new_from_nonce(manifest, nonce)
                 .prepare()
                 .expect("expected transaction to be preparable")
                 .get_executable(initial_proofs.into_iter().collect()),
             CostingParameters::default().with_execution_cost_unit_limit(execution_cost_unit_limit),
             ExecutionConfig::for_test_transaction(),
-        )
+        );
+        self.auto_advance();
+        receipt
     }
 
     pub fn execute_transaction(
@@ -1570,7 +1872,7 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
 
     pub fn preview(
         &mut self,
-        preview_intent: PreviewIntentV1,
+        preview_intent: impl Into<PreviewIntentV2>,
         network: &NetworkDefinition,
     ) -> Result<TransactionReceipt, PreviewError> {
         let vm = Vm {
@@ -1581,12 +1883,77 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
         execute_preview(&self.database, vm, network, preview_intent, self.trace)
     }
 
+    /// Estimates the fees of the manifest, without committing anything.
+    pub fn estimate_fees<T>(
+        &mut self,
+        manifest: TransactionManifestV1,
+        initial_proofs: T,
+        config: &FeeEstimationConfig,
+    ) -> Result<FeeEstimate, FeeEstimationError>
+    where
+        T: IntoIterator<Item = NonFungibleGlobalId>,
+    {
+        let nonce = self.next_transaction_nonce();
+        let prepared = TestTransaction::new_from_nonce(manifest, nonce)
+            .prepare()
+            .expect("expected transaction to be preparable");
+        let vm = Vm {
+            scrypto_vm: &self.scrypto_vm,
+            native_vm: self.native_vm.clone(),
+        };
+
+        estimate_fees(
+            &self.database,
+            vm,
+            config,
+            &prepared.get_executable(initial_proofs.into_iter().collect()),
+        )
+    }
+
+    /// Executes the previews concurrently, returning the results in the same order as the intents.
+    #[cfg(feature = "moka")]
+    pub fn preview_batch(
+        &self,
+        preview_intents: Vec<PreviewIntentV1>,
+        network: &NetworkDefinition,
+    ) -> Vec<Result<TransactionReceipt, PreviewError>>
+    where
+        D: Sync,
+        E: Send,
+    {
+        let vm = Vm {
+            scrypto_vm: &self.scrypto_vm,
+            native_vm: self.native_vm.clone(),
+        };
+
+        PreviewExecutorPool::new(&self.database, vm, network)
+            .with_kernel_trace(self.trace)
+            .execute_previews(preview_intents)
+    }
+
     pub fn preview_manifest(
         &mut self,
         manifest: TransactionManifestV1,
         signer_public_keys: Vec<PublicKey>,
         tip_percentage: u16,
         flags: PreviewFlags,
+    ) -> TransactionReceipt {
+        self.preview_manifest_with_extensions(
+            manifest,
+            signer_public_keys,
+            tip_percentage,
+            flags,
+            index_set_new(),
+        )
+    }
+
+    pub fn preview_manifest_with_extensions(
+        &mut self,
+        manifest: TransactionManifestV1,
+        signer_public_keys: Vec<PublicKey>,
+        tip_percentage: u16,
+        flags: PreviewFlags,
+        extensions: IndexSet<PreviewExtension>,
     ) -> TransactionReceipt {
         let epoch = self.get_current_epoch();
         let vm = Vm {
@@ -1597,7 +1964,7 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
             &mut self.database,
             vm,
             &NetworkDefinition::simulator(),
-            PreviewIntentV1 {
+            PreviewIntentV2 {
                 intent: IntentV1 {
                     header: TransactionHeaderV1 {
                         network_id: NetworkDefinition::simulator().id,
@@ -1616,6 +1983,7 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
                 },
                 signer_public_keys,
                 flags,
+                extensions,
             },
             self.trace,
         )
@@ -1702,6 +2070,34 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
         )
     }
 
+    /// Evaluates a component method against the current state, returning its decoded output.
+    ///
+    /// The call is executed as a preview with auth disabled and the fee paid with free credit,
+    /// so no fee needs to be locked, and any state changes are discarded.
+    ///
+    /// The arguments follow the same rules as for [`call_method`](Self::call_method).
+    pub fn call_method_readonly<T: ScryptoDecode>(
+        &mut self,
+        address: impl ResolvableGlobalAddress,
+        method_name: impl Into<String>,
+        args: impl ResolvableArguments,
+    ) -> T {
+        let receipt = self.preview_manifest_with_extensions(
+            ManifestBuilder::new()
+                .call_method(address, method_name, args)
+                .build(),
+            vec![],
+            0,
+            PreviewFlags {
+                use_free_credit: true,
+                assume_all_signature_proofs: false,
+                skip_epoch_check: true,
+            },
+            indexset!(PreviewExtension::DisableAuth),
+        );
+        receipt.expect_commit_success().output(0)
+    }
+
     fn create_fungible_resource_and_deposit(
         &mut self,
         owner_role: OwnerRole,
@@ -2321,6 +2717,104 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
         self.advance_to_round_at_timestamp(round, current_timestamp_ms)
     }
 
+    /// Moves the clock forward by `duration`, by executing a "start next round" system transaction
+    /// with an accordingly later proposer timestamp (see [`advance_to_round_at_timestamp()`]).
+    ///
+    /// Note: depending on the epoch change condition (e.g. with the default `max_round_count` of
+    /// 1), the round change may also start a new epoch.
+    pub fn advance_time(&mut self, duration: Duration) -> TransactionReceipt {
+        let next_round = Round::of(self.get_consensus_manager_state().round.number() + 1);
+        let proposer_timestamp_ms = self
+            .get_current_proposer_timestamp_ms()
+            .checked_add(duration.as_millis().try_into().unwrap())
+            .unwrap();
+        self.advance_to_round_at_timestamp(next_round, proposer_timestamp_ms)
+    }
+
+    /// Moves the ledger forward to the given epoch, by executing as many epoch-changing "start
+    /// next round" system transactions as needed (see [`advance_to_round_at_timestamp()`]), with
+    /// the clock moving forward by the target epoch duration each time.
+    ///
+    /// Unlike [`set_current_epoch()`](Self::set_current_epoch), which overwrites the substate, this
+    /// goes through the consensus manager, so e.g. validator set updates and emissions happen just
+    /// as they would on a real network.
+    pub fn set_epoch(&mut self, epoch: Epoch) {
+        let current_epoch = self.get_consensus_manager_state().epoch;
+        if epoch < current_epoch {
+            panic!(
+                "Cannot move back from epoch {} to epoch {}",
+                current_epoch.number(),
+                epoch.number()
+            );
+        }
+
+        if epoch > current_epoch {
+            self.advance_to_epoch(epoch);
+        }
+    }
+
+    /// Like [`set_epoch()`](Self::set_epoch), but requires the given epoch to be a future one,
+    /// and returns the receipt of the transaction which started it.
+    pub fn advance_to_epoch(&mut self, epoch: Epoch) -> TransactionReceipt {
+        let current_epoch = self.get_consensus_manager_state().epoch;
+        if epoch <= current_epoch {
+            panic!(
+                "Cannot advance from epoch {} to epoch {}",
+                current_epoch.number(),
+                epoch.number()
+            );
+        }
+
+        let epoch_change_condition = self.get_consensus_manager_config().epoch_change_condition;
+        loop {
+            let current_round = self.get_consensus_manager_state().round.number();
+            let round = Round::of((current_round + 1).max(epoch_change_condition.min_round_count));
+            let proposer_timestamp_ms = self
+                .get_current_proposer_timestamp_ms()
+                .checked_add(
+                    epoch_change_condition
+                        .target_duration_millis
+                        .try_into()
+                        .unwrap(),
+                )
+                .unwrap();
+            let receipt = self.advance_to_round_at_timestamp(round, proposer_timestamp_ms);
+            receipt.expect_commit_success();
+            if self.get_consensus_manager_state().epoch >= epoch {
+                return receipt;
+            }
+        }
+    }
+
+    /// Moves the ledger forward after an executed manifest, according to the [`AdvancePolicy`]
+    /// the test runner was built with.
+    fn auto_advance(&mut self) {
+        match self.auto_advance {
+            AdvancePolicy::Manual => {}
+            AdvancePolicy::Rounds(round_count) => {
+                let round = Round::of(
+                    self.get_consensus_manager_state()
+                        .round
+                        .number()
+                        .checked_add(round_count)
+                        .unwrap(),
+                );
+                self.advance_to_round(round).expect_commit_success();
+            }
+            AdvancePolicy::Time(duration) => {
+                self.advance_time(duration).expect_commit_success();
+            }
+            AdvancePolicy::Epochs(epoch_count) => {
+                let epoch = self
+                    .get_consensus_manager_state()
+                    .epoch
+                    .after(epoch_count)
+                    .unwrap();
+                self.set_epoch(epoch);
+            }
+        }
+    }
+
     /// Reads out the substate holding the "epoch milli" timestamp reported by the proposer on the
     /// most recent round change.
     pub fn get_current_proposer_timestamp_ms(&mut self) -> i64 {
@@ -2348,6 +2842,19 @@ impl<E: NativeVmExtension, D: TestDatabase> TestRunner<E, D> {
             .into_latest()
     }
 
+    pub fn get_consensus_manager_config(&mut self) -> ConsensusManagerConfig {
+        let reader = SystemDatabaseReader::new(self.substate_db());
+        reader
+            .read_typed_object_field::<ConsensusManagerConfigurationFieldPayload>(
+                CONSENSUS_MANAGER.as_node_id(),
+                ModuleId::Main,
+                ConsensusManagerField::Configuration.field_index(),
+            )
+            .unwrap()
+            .into_latest()
+            .config
+    }
+
     pub fn get_current_time(&mut self, precision: TimePrecision) -> Instant {
         let receipt = self.execute_system_transaction(
             vec![InstructionV1::CallMethod {
@@ -2590,6 +3097,34 @@ impl<'d, D: SubstateDatabase> SubtreeVaults<'d, D> {
     }
 }
 
+/// Validates a payload against its on-ledger schema, then decodes it into `T`.
+fn decode_against_schema<S: SubstateDatabase, T: ScryptoDecode>(
+    reader: &SystemDatabaseReader<S>,
+    payload: &[u8],
+    payload_schema: &ResolvedPayloadSchema,
+    depth_limit: usize,
+) -> T {
+    let schema = payload_schema.schema.v1();
+    let type_name = schema
+        .resolve_type_name_from_metadata(payload_schema.type_id)
+        .unwrap_or("<unnamed>");
+    if let Err(err) = reader.validate_payload(payload, payload_schema, depth_limit) {
+        panic!(
+            "Payload does not match the schema of {}: {}",
+            type_name,
+            err.error_message(schema)
+        );
+    }
+    scrypto_decode(payload).unwrap_or_else(|err| {
+        panic!(
+            "Payload of {} can't be decoded into {}: {:?}",
+            type_name,
+            std::any::type_name::<T>(),
+            err
+        )
+    })
+}
+
 pub fn is_auth_error(e: &RuntimeError) -> bool {
     matches!(
         e,
@@ -2760,6 +3295,7 @@ pub fn assert_receipt_events_can_be_typed(commit_result: &CommitResult) {
                 CONSENSUS_MANAGER_PACKAGE,
                 ACCESS_CONTROLLER_PACKAGE,
                 POOL_PACKAGE,
+                LOCKER_PACKAGE,
                 TRANSACTION_PROCESSOR_PACKAGE,
                 METADATA_MODULE_PACKAGE,
                 ROYALTY_MODULE_PACKAGE,
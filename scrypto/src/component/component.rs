@@ -81,15 +81,44 @@ pub trait HasMethods {
 pub trait ComponentState: HasMethods + HasStub + ScryptoEncode + ScryptoDecode {
     const BLUEPRINT_NAME: &'static str;
 
+    /// Assigns the `Transient` fields of the state their field index, which follow the state
+    /// itself at field `0`, and returns the initial values of their substates.
+    fn init_transient_fields(&mut self) -> Vec<FieldValue> {
+        Vec::new()
+    }
+
     fn instantiate(self) -> Owned<Self> {
-        let node_id = ScryptoVmV1Api::object_new(
+        let node_id = ScryptoVmV1Api::object_new(Self::BLUEPRINT_NAME, self.into_fields());
+
+        let stub = Self::Stub::new(ObjectStubHandle::Own(Own(node_id)));
+        Owned(stub)
+    }
+
+    /// Instantiates the component with the given features enabled, which must all be declared
+    /// by the blueprint (see `#[features(..)]`).
+    fn instantiate_with_features(self, features: Vec<&str>) -> Owned<Self> {
+        let node_id = ScryptoVmV1Api::object_new_with_features(
             Self::BLUEPRINT_NAME,
-            indexmap![0u8 => FieldValue::new(&self)],
+            features
+                .into_iter()
+                .map(|feature| feature.to_owned())
+                .collect(),
+            self.into_fields(),
         );
 
         let stub = Self::Stub::new(ObjectStubHandle::Own(Own(node_id)));
         Owned(stub)
     }
+
+    #[doc(hidden)]
+    fn into_fields(mut self) -> IndexMap<u8, FieldValue> {
+        let transient_fields = self.init_transient_fields();
+        let mut fields = indexmap![0u8 => FieldValue::new(&self)];
+        for (index, value) in transient_fields.into_iter().enumerate() {
+            fields.insert(index as u8 + 1, value);
+        }
+        fields
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -239,7 +268,7 @@ pub struct Globalizing<C: HasStub> {
 
     pub owner_role: OwnerRole,
     pub metadata_config: Option<ModuleConfig<MetadataInit>>,
-    pub royalty_config: Option<ModuleConfig<ComponentRoyaltyConfig>>,
+    pub royalty_config: Option<ModuleConfig<ComponentRoyaltyConfigV2>>,
     pub address_reservation: Option<GlobalAddressReservation>,
 
     pub roles: RoleAssignmentInit,
@@ -275,7 +304,10 @@ impl<C: HasStub + HasMethods> Globalizing<C> {
         }
 
         let royalty_config = ModuleConfig {
-            init: ComponentRoyaltyConfig { royalty_amounts },
+            init: ComponentRoyaltyConfigV2 {
+                royalty_amounts,
+                ..Default::default()
+            },
             roles: royalties.1,
         };
 
@@ -284,6 +316,26 @@ impl<C: HasStub + HasMethods> Globalizing<C> {
         self
     }
 
+    /// Limits the royalties charged by the component within a single transaction: the first
+    /// `free_calls_per_transaction` royalty-bearing calls are free, and the total royalty
+    /// charged never exceeds `max_royalty_per_transaction`, if given.
+    ///
+    /// Component royalties must have been enabled first.
+    pub fn component_royalty_limits(
+        mut self,
+        max_royalty_per_transaction: Option<RoyaltyAmount>,
+        free_calls_per_transaction: u32,
+    ) -> Self {
+        let royalty_config = self
+            .royalty_config
+            .as_mut()
+            .expect("Component royalties must be enabled before setting their limits");
+        royalty_config.init.max_royalty_per_transaction = max_royalty_per_transaction;
+        royalty_config.init.free_calls_per_transaction = free_calls_per_transaction;
+
+        self
+    }
+
     pub fn with_address(mut self, address_reservation: GlobalAddressReservation) -> Self {
         self.address_reservation = Some(address_reservation);
         self
@@ -316,7 +368,16 @@ impl<C: HasStub + HasMethods> Globalizing<C> {
         // Royalties
         if let Some(royalty_config) = self.royalty_config {
             roles.insert(ModuleId::Royalty, royalty_config.roles);
-            let royalty = Royalty::new(royalty_config.init);
+            // Components without transaction limits are created as before
+            let royalty = if royalty_config.init.max_royalty_per_transaction.is_none()
+                && royalty_config.init.free_calls_per_transaction == 0
+            {
+                Royalty::new(ComponentRoyaltyConfig {
+                    royalty_amounts: royalty_config.init.royalty_amounts,
+                })
+            } else {
+                Royalty::new_v2(royalty_config.init)
+            };
             modules.insert(
                 AttachedModuleId::Royalty,
                 royalty.handle().as_node_id().clone(),
@@ -0,0 +1,181 @@
+use super::*;
+use crate::engine::scrypto_env::ScryptoVmV1Api;
+use crate::runtime::Runtime;
+use radix_engine_interface::data::scrypto::model::*;
+use radix_engine_interface::data::scrypto::well_known_scrypto_custom_types::{
+    own_index_store_type_data, OWN_INDEX_STORE_TYPE,
+};
+use radix_engine_interface::data::scrypto::*;
+use radix_engine_interface::prelude::{
+    LocalKeyValueStoreDataSchema, KV_STORE_DATA_SCHEMA_VARIANT_LOCAL,
+};
+use sbor::rust::marker::PhantomData;
+use sbor::rust::ops::Range;
+use sbor::rust::prelude::*;
+use sbor::*;
+
+/// A scalable key-value map whose entries are ordered by a `u16` sort prefix, so that they can
+/// be read back in order, e.g. for an order book or a leaderboard.
+///
+/// Entries with the same sort prefix are returned in an arbitrary (but deterministic) order.
+/// Unlike a [`KeyValueStore`], the values can't own other objects.
+pub struct IndexStore<
+    K: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+    V: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+> {
+    pub id: Own,
+    pub key: PhantomData<K>,
+    pub value: PhantomData<V>,
+}
+
+impl<
+        K: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+        V: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+    > IndexStore<K, V>
+{
+    /// Creates a new index store.
+    pub fn new() -> Self {
+        let schema = LocalKeyValueStoreDataSchema::new_with_self_package_replacement::<K, V>(
+            Runtime::package_address(),
+            false,
+        );
+
+        let store_schema = LocalKeyValueStoreDataSchema {
+            additional_schema: schema.additional_schema,
+            key_type: schema.key_type,
+            value_type: schema.value_type,
+            allow_ownership: schema.allow_ownership,
+        };
+        Self {
+            id: Own(ScryptoVmV1Api::index_store_new(FixedEnumVariant::<
+                KV_STORE_DATA_SCHEMA_VARIANT_LOCAL,
+                LocalKeyValueStoreDataSchema,
+            > {
+                fields: store_schema,
+            })),
+            key: PhantomData,
+            value: PhantomData,
+        }
+    }
+
+    /// Inserts a new entry with the given sort prefix, replacing the existing value (if any).
+    pub fn insert(&self, sort_prefix: u16, key: K, value: V) {
+        let key_payload = scrypto_encode(&key).unwrap();
+        let value_payload = scrypto_encode(&value).unwrap();
+
+        ScryptoVmV1Api::index_store_insert(
+            self.id.as_node_id(),
+            sort_prefix,
+            &key_payload,
+            &value_payload,
+        );
+    }
+
+    /// Remove an entry from the store and return the original value if it exists
+    pub fn remove(&self, sort_prefix: u16, key: &K) -> Option<V> {
+        let key_payload = scrypto_encode(key).unwrap();
+        let rtn =
+            ScryptoVmV1Api::index_store_remove(self.id.as_node_id(), sort_prefix, &key_payload);
+
+        let removed: Option<Vec<u8>> = scrypto_decode(&rtn).unwrap();
+        removed.map(|value| scrypto_decode(&value).unwrap())
+    }
+
+    /// Returns up to `limit` entries whose sort prefix is within the given range, in sort prefix
+    /// order.
+    pub fn range(&self, range: Range<u16>, limit: u32) -> Vec<(u16, K, V)> {
+        if range.is_empty() {
+            return Vec::new();
+        }
+        self.scan(u32::from(range.start), u32::from(range.end), limit)
+    }
+
+    /// Returns the first `limit` entries, in sort prefix order.
+    pub fn first_n(&self, limit: u32) -> Vec<(u16, K, V)> {
+        // `u16::MAX + 1` stands for an open end
+        self.scan(0, u16::MAX as u32 + 1, limit)
+    }
+
+    fn scan(&self, from_sort_prefix: u32, to_sort_prefix: u32, limit: u32) -> Vec<(u16, K, V)> {
+        let rtn = ScryptoVmV1Api::index_store_scan(
+            self.id.as_node_id(),
+            from_sort_prefix,
+            to_sort_prefix,
+            limit,
+        );
+
+        let entries: Vec<(([u8; 2], Vec<u8>), Vec<u8>)> = scrypto_decode(&rtn).unwrap();
+        entries
+            .into_iter()
+            .map(|((sort_prefix, key), value)| {
+                (
+                    u16::from_be_bytes(sort_prefix),
+                    scrypto_decode(&key).unwrap(),
+                    scrypto_decode(&value).unwrap(),
+                )
+            })
+            .collect()
+    }
+}
+
+//========
+// binary
+//========
+impl<
+        K: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+        V: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+    > Categorize<ScryptoCustomValueKind> for IndexStore<K, V>
+{
+    #[inline]
+    fn value_kind() -> ValueKind<ScryptoCustomValueKind> {
+        ValueKind::Custom(ScryptoCustomValueKind::Own)
+    }
+}
+
+impl<
+        K: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+        V: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+        E: Encoder<ScryptoCustomValueKind>,
+    > Encode<ScryptoCustomValueKind, E> for IndexStore<K, V>
+{
+    #[inline]
+    fn encode_value_kind(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        encoder.write_value_kind(Self::value_kind())
+    }
+
+    #[inline]
+    fn encode_body(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.id.encode_body(encoder)
+    }
+}
+
+impl<
+        K: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+        V: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+        D: Decoder<ScryptoCustomValueKind>,
+    > Decode<ScryptoCustomValueKind, D> for IndexStore<K, V>
+{
+    fn decode_body_with_value_kind(
+        decoder: &mut D,
+        value_kind: ValueKind<ScryptoCustomValueKind>,
+    ) -> Result<Self, DecodeError> {
+        let own = Own::decode_body_with_value_kind(decoder, value_kind)?;
+        Ok(Self {
+            id: own,
+            key: PhantomData,
+            value: PhantomData,
+        })
+    }
+}
+
+impl<
+        K: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+        V: ScryptoEncode + ScryptoDecode + ScryptoDescribe,
+    > Describe<ScryptoCustomTypeKind> for IndexStore<K, V>
+{
+    const TYPE_ID: RustTypeId = RustTypeId::WellKnown(OWN_INDEX_STORE_TYPE);
+
+    fn type_data() -> sbor::TypeData<ScryptoCustomTypeKind, RustTypeId> {
+        own_index_store_type_data()
+    }
+}
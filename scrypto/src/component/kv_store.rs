@@ -108,6 +108,21 @@ impl<
         ScryptoVmV1Api::kv_entry_close(handle);
     }
 
+    /// Returns true if the map contains an entry for the given key.
+    pub fn contains_key(&self, key: &K) -> bool {
+        let key_payload = scrypto_encode(key).unwrap();
+        let handle = ScryptoVmV1Api::kv_store_open_entry(
+            self.id.as_node_id(),
+            &key_payload,
+            LockFlags::read_only(),
+        );
+        let raw_bytes = ScryptoVmV1Api::kv_entry_read(handle);
+        ScryptoVmV1Api::kv_entry_close(handle);
+
+        let substate: Option<ScryptoValue> = scrypto_decode(&raw_bytes).unwrap();
+        substate.is_some()
+    }
+
     /// Remove an entry from the map and return the original value if it exists
     pub fn remove(&self, key: &K) -> Option<V> {
         let key_payload = scrypto_encode(&key).unwrap();
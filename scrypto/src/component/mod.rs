@@ -1,13 +1,17 @@
 mod component;
+mod index_store;
 mod kv_store;
 mod kv_store_data_ref;
 mod object;
 mod package;
 mod stubs;
+mod transient;
 
 pub use component::*;
+pub use index_store::*;
 pub use kv_store::*;
 pub use kv_store_data_ref::*;
 pub use object::*;
 pub use package::*;
 pub use stubs::*;
+pub use transient::*;
@@ -2,9 +2,12 @@ use super::HasTypeInfo;
 use crate::prelude::{Global, HasStub, ObjectStub, ObjectStubHandle};
 use radix_engine_common::prelude::PACKAGE_PACKAGE;
 use radix_engine_interface::blueprints::package::{
-    PackageClaimRoyaltiesInput, PACKAGE_BLUEPRINT, PACKAGE_CLAIM_ROYALTIES_IDENT,
+    PackageClaimRoyaltiesForBlueprintInput, PackageClaimRoyaltiesInput, PackageDeprecateInput,
+    PackageSetBlueprintRoyaltyClaimerInput, PACKAGE_BLUEPRINT,
+    PACKAGE_CLAIM_ROYALTIES_FOR_BLUEPRINT_IDENT, PACKAGE_CLAIM_ROYALTIES_IDENT,
+    PACKAGE_DEPRECATE_IDENT, PACKAGE_SET_BLUEPRINT_ROYALTY_CLAIMER_IDENT,
 };
-use radix_engine_interface::blueprints::resource::Bucket;
+use radix_engine_interface::blueprints::resource::{AccessRule, Bucket};
 use radix_engine_interface::types::*;
 use radix_engine_interface::*;
 use sbor::rust::prelude::*;
@@ -47,6 +50,36 @@ impl PackageStub {
             &PackageClaimRoyaltiesInput {},
         )
     }
+
+    pub fn set_blueprint_royalty_claimer<S: ToString>(
+        &self,
+        blueprint_name: S,
+        claimer: AccessRule,
+    ) {
+        self.call(
+            PACKAGE_SET_BLUEPRINT_ROYALTY_CLAIMER_IDENT,
+            &PackageSetBlueprintRoyaltyClaimerInput {
+                blueprint_name: blueprint_name.to_string(),
+                claimer,
+            },
+        )
+    }
+
+    pub fn claim_royalties_for_blueprint<S: ToString>(&self, blueprint_name: S) -> Bucket {
+        self.call(
+            PACKAGE_CLAIM_ROYALTIES_FOR_BLUEPRINT_IDENT,
+            &PackageClaimRoyaltiesForBlueprintInput {
+                blueprint_name: blueprint_name.to_string(),
+            },
+        )
+    }
+
+    pub fn deprecate(&self, successor: PackageAddress) {
+        self.call(
+            PACKAGE_DEPRECATE_IDENT,
+            &PackageDeprecateInput { successor },
+        )
+    }
 }
 
 impl From<PackageAddress> for Package {
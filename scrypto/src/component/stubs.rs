@@ -322,6 +322,45 @@ extern_blueprint_internal! {
     }
 }
 
+extern_blueprint_internal! {
+    PackageAddress::new_or_panic([
+        13u8, 144u8, 99u8, 24u8, 198u8, 49u8, 140u8, 111u8, 226u8, 217u8, 25u8, 140u8, 99u8, 24u8,
+        198u8, 49u8, 141u8, 90u8, 190u8, 52u8, 211u8, 223u8, 167u8, 213u8, 230u8, 49u8, 140u8,
+        99u8, 24u8, 198u8,
+    ]),
+    AccountLocker,
+    "AccountLocker",
+    "OwnedAccountLocker",
+    "GlobalAccountLocker",
+    AccountLockerFunctions {
+        fn instantiate(
+            owner_role: OwnerRole,
+            storer_role: AccessRule,
+            storer_updater_role: AccessRule,
+            recoverer_role: AccessRule,
+            recoverer_updater_role: AccessRule,
+            claim_period_in_minutes: u32,
+            address_reservation: Option<GlobalAddressReservation>,
+        ) -> Global<AccountLocker>;
+    },
+    {
+        fn store(&mut self, claimant: Global<Account>, bucket: Bucket, try_direct_send: bool);
+        fn claim(
+            &mut self,
+            claimant: Global<Account>,
+            resource_address: ResourceAddress,
+            amount: Decimal,
+        ) -> Bucket;
+        fn recover(
+            &mut self,
+            claimant: Global<Account>,
+            resource_address: ResourceAddress,
+            amount: Decimal,
+        ) -> Bucket;
+        fn get_amount(&self, claimant: Global<Account>, resource_address: ResourceAddress) -> Decimal;
+    }
+}
+
 extern_blueprint_internal! {
     PackageAddress::new_or_panic([
         13u8, 144u8, 99u8, 24u8, 198u8, 49u8, 140u8, 108u8, 77u8, 99u8, 248u8, 204u8, 99u8, 24u8,
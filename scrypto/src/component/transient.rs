@@ -0,0 +1,131 @@
+use crate::engine::scrypto_env::ScryptoVmV1Api;
+use radix_engine_interface::api::field_api::LockFlags;
+use radix_engine_interface::api::ACTOR_STATE_SELF;
+use radix_engine_interface::data::scrypto::*;
+use radix_engine_interface::types::SubstateHandle;
+use sbor::rust::marker::PhantomData;
+use sbor::*;
+
+/// A component state field whose value is never persisted.
+///
+/// The value lives in a transient field substate of the component rather than in the component
+/// state itself: it starts from `T::default()` in every transaction, and is discarded when the
+/// transaction commits, so it costs no storage fees. This makes it a fit for reentrancy guards
+/// or per-transaction counters.
+///
+/// Only the index of the field is stored in the component state, which is assigned when the
+/// component is instantiated, so a `Transient` must be created with [`Transient::new`] as part
+/// of the blueprint struct.
+pub struct Transient<T: ScryptoEncode + ScryptoDecode + ScryptoDescribe + Default> {
+    field_index: u8,
+    value: PhantomData<T>,
+}
+
+impl<T: ScryptoEncode + ScryptoDecode + ScryptoDescribe + Default> Transient<T> {
+    pub fn new() -> Self {
+        Self {
+            field_index: 0,
+            value: PhantomData,
+        }
+    }
+
+    /// Assigns the field index of the transient field, called by the blueprint on instantiation.
+    #[doc(hidden)]
+    pub fn init(&mut self, field_index: u8) {
+        self.field_index = field_index;
+    }
+
+    /// Returns the value of the field in this transaction.
+    pub fn get(&self) -> T {
+        let handle = self.open(LockFlags::read_only());
+        let value = scrypto_decode(&ScryptoVmV1Api::field_entry_read(handle)).unwrap();
+        ScryptoVmV1Api::field_entry_close(handle);
+        value
+    }
+
+    /// Sets the value of the field for the rest of this transaction.
+    pub fn set(&self, value: T) {
+        let handle = self.open(LockFlags::MUTABLE);
+        ScryptoVmV1Api::field_entry_write(handle, scrypto_encode(&value).unwrap());
+        ScryptoVmV1Api::field_entry_close(handle);
+    }
+
+    /// Updates the value of the field for the rest of this transaction, returning the result of
+    /// the update.
+    pub fn update<F: FnOnce(&mut T) -> O, O>(&self, f: F) -> O {
+        let handle = self.open(LockFlags::MUTABLE);
+        let mut value = scrypto_decode(&ScryptoVmV1Api::field_entry_read(handle)).unwrap();
+        let output = f(&mut value);
+        ScryptoVmV1Api::field_entry_write(handle, scrypto_encode(&value).unwrap());
+        ScryptoVmV1Api::field_entry_close(handle);
+        output
+    }
+
+    fn open(&self, flags: LockFlags) -> SubstateHandle {
+        if self.field_index == 0 {
+            panic!("Transient field used outside of an instantiated component state");
+        }
+        ScryptoVmV1Api::actor_open_field(ACTOR_STATE_SELF, self.field_index, flags)
+    }
+}
+
+impl<T: ScryptoEncode + ScryptoDecode + ScryptoDescribe + Default> Default for Transient<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+//========
+// binary
+//========
+impl<T: ScryptoEncode + ScryptoDecode + ScryptoDescribe + Default>
+    Categorize<ScryptoCustomValueKind> for Transient<T>
+{
+    #[inline]
+    fn value_kind() -> ValueKind<ScryptoCustomValueKind> {
+        ValueKind::U8
+    }
+}
+
+impl<
+        T: ScryptoEncode + ScryptoDecode + ScryptoDescribe + Default,
+        E: Encoder<ScryptoCustomValueKind>,
+    > Encode<ScryptoCustomValueKind, E> for Transient<T>
+{
+    #[inline]
+    fn encode_value_kind(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        encoder.write_value_kind(Self::value_kind())
+    }
+
+    #[inline]
+    fn encode_body(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.field_index.encode_body(encoder)
+    }
+}
+
+impl<
+        T: ScryptoEncode + ScryptoDecode + ScryptoDescribe + Default,
+        D: Decoder<ScryptoCustomValueKind>,
+    > Decode<ScryptoCustomValueKind, D> for Transient<T>
+{
+    fn decode_body_with_value_kind(
+        decoder: &mut D,
+        value_kind: ValueKind<ScryptoCustomValueKind>,
+    ) -> Result<Self, DecodeError> {
+        let field_index = u8::decode_body_with_value_kind(decoder, value_kind)?;
+        Ok(Self {
+            field_index,
+            value: PhantomData,
+        })
+    }
+}
+
+impl<T: ScryptoEncode + ScryptoDecode + ScryptoDescribe + Default> Describe<ScryptoCustomTypeKind>
+    for Transient<T>
+{
+    const TYPE_ID: RustTypeId = <u8 as Describe<ScryptoCustomTypeKind>>::TYPE_ID;
+
+    fn type_data() -> TypeData<ScryptoCustomTypeKind, RustTypeId> {
+        <u8 as Describe<ScryptoCustomTypeKind>>::type_data()
+    }
+}
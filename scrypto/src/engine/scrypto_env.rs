@@ -5,7 +5,10 @@ use radix_engine_interface::api::actor_api::EventFlags;
 use radix_engine_interface::api::key_value_entry_api::KeyValueEntryHandle;
 use radix_engine_interface::api::{ActorRefHandle, FieldValue};
 use radix_engine_interface::api::{AttachedModuleId, FieldIndex, LockFlags};
-use radix_engine_interface::crypto::Hash;
+use radix_engine_interface::crypto::{
+    Bls12381G1PublicKey, Bls12381G2Signature, Ed25519PublicKey, Ed25519Signature, Hash,
+    Secp256k1PublicKey, Secp256k1Signature,
+};
 use radix_engine_interface::data::scrypto::*;
 use radix_engine_interface::types::PackageAddress;
 use radix_engine_interface::types::{BlueprintId, GlobalAddress};
@@ -53,6 +56,27 @@ impl ScryptoVmV1Api {
         NodeId(bytes.try_into().unwrap())
     }
 
+    pub fn object_new_with_features(
+        blueprint_name: &str,
+        features: Vec<String>,
+        object_states: IndexMap<FieldIndex, FieldValue>,
+    ) -> NodeId {
+        let features = scrypto_encode(&features).unwrap();
+        let object_states = scrypto_encode(&object_states).unwrap();
+
+        let bytes = copy_buffer(unsafe {
+            object::object_new_with_features(
+                blueprint_name.as_ptr(),
+                blueprint_name.len(),
+                features.as_ptr(),
+                features.len(),
+                object_states.as_ptr(),
+                object_states.len(),
+            )
+        });
+        NodeId(bytes.try_into().unwrap())
+    }
+
     pub fn object_globalize(
         object_id: NodeId,
         modules: IndexMap<AttachedModuleId, NodeId>,
@@ -194,6 +218,58 @@ impl ScryptoVmV1Api {
         removed
     }
 
+    pub fn index_store_new<S: ScryptoEncode>(schema: S) -> NodeId {
+        let schema = scrypto_encode(&schema).unwrap();
+        let bytes =
+            copy_buffer(unsafe { index_store::index_store_new(schema.as_ptr(), schema.len()) });
+        NodeId(bytes.try_into().unwrap())
+    }
+
+    pub fn index_store_insert(node_id: &NodeId, sort_prefix: u16, key: &Vec<u8>, value: &Vec<u8>) {
+        unsafe {
+            index_store::index_store_insert(
+                node_id.as_ref().as_ptr(),
+                node_id.as_ref().len(),
+                u32::from(sort_prefix),
+                key.as_ptr(),
+                key.len(),
+                value.as_ptr(),
+                value.len(),
+            )
+        };
+    }
+
+    pub fn index_store_remove(node_id: &NodeId, sort_prefix: u16, key: &Vec<u8>) -> Vec<u8> {
+        let removed = copy_buffer(unsafe {
+            index_store::index_store_remove(
+                node_id.as_ref().as_ptr(),
+                node_id.as_ref().len(),
+                u32::from(sort_prefix),
+                key.as_ptr(),
+                key.len(),
+            )
+        });
+        removed
+    }
+
+    pub fn index_store_scan(
+        node_id: &NodeId,
+        from_sort_prefix: u32,
+        to_sort_prefix: u32,
+        count: u32,
+    ) -> Vec<u8> {
+        let entries = copy_buffer(unsafe {
+            index_store::index_store_scan(
+                node_id.as_ref().as_ptr(),
+                node_id.as_ref().len(),
+                from_sort_prefix,
+                to_sort_prefix,
+                count,
+            )
+        });
+        entries
+    }
+
     pub fn actor_open_field(object_handle: u32, field: u8, flags: LockFlags) -> SubstateHandle {
         let handle =
             unsafe { actor::actor_open_field(object_handle, u32::from(field), flags.bits()) };
@@ -206,6 +282,14 @@ impl ScryptoVmV1Api {
         NodeId(node_id.try_into().unwrap())
     }
 
+    pub fn actor_is_feature_enabled(object_handle: u32, feature: &str) -> bool {
+        let rtn = unsafe {
+            actor::actor_is_feature_enabled(object_handle, feature.as_ptr(), feature.len())
+        };
+
+        rtn == 1
+    }
+
     pub fn actor_get_package_address() -> PackageAddress {
         let package_address = copy_buffer(unsafe { actor::actor_get_package_address() });
 
@@ -303,6 +387,23 @@ impl ScryptoVmV1Api {
         unsafe { system::sys_log(level.as_ptr(), level.len(), message.as_ptr(), message.len()) }
     }
 
+    pub fn sys_log_record(level: Level, topic: &str, message: String, payload: Option<Vec<u8>>) {
+        let level = scrypto_encode(&level).unwrap();
+        let payload = payload.unwrap_or_default();
+        unsafe {
+            system::sys_log_record(
+                level.as_ptr(),
+                level.len(),
+                topic.as_ptr(),
+                topic.len(),
+                message.as_ptr(),
+                message.len(),
+                payload.as_ptr(),
+                payload.len(),
+            )
+        }
+    }
+
     pub fn sys_get_transaction_hash() -> Hash {
         let hash = copy_buffer(unsafe { system::sys_get_transaction_hash() });
 
@@ -315,9 +416,86 @@ impl ScryptoVmV1Api {
         ruid.try_into().unwrap()
     }
 
+    pub fn sys_generate_ruid_seeded(domain: &[u8]) -> [u8; 32] {
+        let ruid =
+            copy_buffer(unsafe { system::sys_generate_ruid_seeded(domain.as_ptr(), domain.len()) });
+
+        ruid.try_into().unwrap()
+    }
+
     pub fn sys_panic(message: String) {
         unsafe {
             system::sys_panic(message.as_ptr(), message.len());
         };
     }
+
+    pub fn crypto_utils_ed25519_verify(
+        message: &[u8],
+        public_key: &Ed25519PublicKey,
+        signature: &Ed25519Signature,
+    ) -> bool {
+        let rtn = unsafe {
+            crypto_utils::crypto_utils_ed25519_verify(
+                message.as_ptr(),
+                message.len(),
+                public_key.0.as_ptr(),
+                public_key.0.len(),
+                signature.0.as_ptr(),
+                signature.0.len(),
+            )
+        };
+        rtn == 1
+    }
+
+    pub fn crypto_utils_secp256k1_verify(
+        message: &[u8],
+        public_key: &Secp256k1PublicKey,
+        signature: &Secp256k1Signature,
+    ) -> bool {
+        let rtn = unsafe {
+            crypto_utils::crypto_utils_secp256k1_verify(
+                message.as_ptr(),
+                message.len(),
+                public_key.0.as_ptr(),
+                public_key.0.len(),
+                signature.0.as_ptr(),
+                signature.0.len(),
+            )
+        };
+        rtn == 1
+    }
+
+    pub fn crypto_utils_bls12381_v1_verify(
+        message: &[u8],
+        public_key: &Bls12381G1PublicKey,
+        signature: &Bls12381G2Signature,
+    ) -> bool {
+        let rtn = unsafe {
+            crypto_utils::crypto_utils_bls12381_v1_verify(
+                message.as_ptr(),
+                message.len(),
+                public_key.0.as_ptr(),
+                public_key.0.len(),
+                signature.0.as_ptr(),
+                signature.0.len(),
+            )
+        };
+        rtn == 1
+    }
+
+    pub fn crypto_utils_keccak256_hash(data: &[u8]) -> Hash {
+        let hash = copy_buffer(unsafe {
+            crypto_utils::crypto_utils_keccak256_hash(data.as_ptr(), data.len())
+        });
+
+        Hash(hash.try_into().unwrap())
+    }
+
+    pub fn crypto_utils_blake2b_256_hash(data: &[u8]) -> Hash {
+        let hash = copy_buffer(unsafe {
+            crypto_utils::crypto_utils_blake2b_256_hash(data.as_ptr(), data.len())
+        });
+
+        Hash(hash.try_into().unwrap())
+    }
 }
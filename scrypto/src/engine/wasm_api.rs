@@ -80,6 +80,17 @@ pub mod object {
             obj_fields_len: usize,
         ) -> Buffer;
 
+        /// Creates a new object of a given blueprint defined in the same
+        /// package as the current actor, with the given features enabled
+        pub fn object_new_with_features(
+            blueprint_name_ptr: *const u8,
+            blueprint_name_len: usize,
+            features_ptr: *const u8,
+            features_len: usize,
+            obj_fields_ptr: *const u8,
+            obj_fields_len: usize,
+        ) -> Buffer;
+
         /// Globalizes an object with given modules
         pub fn object_globalize(
             obj_id_ptr: *const u8,
@@ -158,6 +169,13 @@ pub mod actor {
         /// Get the object id of a reference of the current actor
         pub fn actor_get_object_id(actor_ref_handle: ActorRefHandle) -> Buffer;
 
+        /// Check if a feature is enabled for an object of the current actor
+        pub fn actor_is_feature_enabled(
+            actor_state_handle: ActorStateHandle,
+            feature_ptr: *const u8,
+            feature_len: usize,
+        ) -> u32;
+
         /// Open a field of the current actor
         pub fn actor_open_field(
             actor_state_handle: ActorStateHandle,
@@ -202,6 +220,45 @@ pub mod kv_store {
     }
 }
 
+pub mod index_store {
+    pub use radix_engine_interface::types::{Buffer, BufferId, Slice};
+
+    super::wasm_extern_c! {
+        /// Creates a new index store
+        pub fn index_store_new(schema_ptr: *const u8, schema_len: usize) -> Buffer;
+
+        /// Inserts an entry into an index store, replacing the existing entry (if any)
+        pub fn index_store_insert(
+            index_store_id_ptr: *const u8,
+            index_store_id_len: usize,
+            sort_prefix: u32,
+            key_ptr: *const u8,
+            key_len: usize,
+            value_ptr: *const u8,
+            value_len: usize,
+        );
+
+        /// Removes an entry from an index store
+        pub fn index_store_remove(
+            index_store_id_ptr: *const u8,
+            index_store_id_len: usize,
+            sort_prefix: u32,
+            key_ptr: *const u8,
+            key_len: usize,
+        ) -> Buffer;
+
+        /// Scans the entries of an index store in sort prefix order, from `from_sort_prefix`
+        /// (inclusive) to `to_sort_prefix` (exclusive, with `u16::MAX + 1` meaning no end)
+        pub fn index_store_scan(
+            index_store_id_ptr: *const u8,
+            index_store_id_len: usize,
+            from_sort_prefix: u32,
+            to_sort_prefix: u32,
+            count: u32,
+        ) -> Buffer;
+    }
+}
+
 /// API to manipulate or get information about an open Key Value Entry
 pub mod kv_entry {
     pub use radix_engine_interface::types::{Buffer, BufferId, Slice};
@@ -270,6 +327,19 @@ pub mod system {
             message_len: usize,
         );
 
+        /// Logs a string message under a topic, with an SBOR-encoded payload, which is empty if
+        /// there is none
+        pub fn sys_log_record(
+            level_ptr: *const u8,
+            level_len: usize,
+            topic_ptr: *const u8,
+            topic_len: usize,
+            message_ptr: *const u8,
+            message_len: usize,
+            payload_ptr: *const u8,
+            payload_len: usize,
+        );
+
         /// Encode an address to bech32 encoding
         pub fn sys_bech32_encode_address(address_ptr: *const u8, address_len: usize) -> Buffer;
 
@@ -279,11 +349,57 @@ pub mod system {
         /// Generates a unique id
         pub fn sys_generate_ruid() -> Buffer;
 
+        /// Generates a unique id derived from the transaction, a per-transaction counter and the
+        /// given domain
+        pub fn sys_generate_ruid_seeded(domain_ptr: *const u8, domain_len: usize) -> Buffer;
+
         /// Panics and halts transaction execution
         pub fn sys_panic(message_ptr: *const u8, message_len: usize);
     }
 }
 
+pub mod crypto_utils {
+    pub use radix_engine_interface::types::{Buffer, BufferId, Slice};
+
+    super::wasm_extern_c! {
+        /// Verifies an Ed25519 signature of a message hash, returning 1 if valid and 0 otherwise
+        pub fn crypto_utils_ed25519_verify(
+            message_ptr: *const u8,
+            message_len: usize,
+            public_key_ptr: *const u8,
+            public_key_len: usize,
+            signature_ptr: *const u8,
+            signature_len: usize,
+        ) -> u32;
+
+        /// Verifies a Secp256k1 signature of a message hash, returning 1 if valid and 0 otherwise
+        pub fn crypto_utils_secp256k1_verify(
+            message_ptr: *const u8,
+            message_len: usize,
+            public_key_ptr: *const u8,
+            public_key_len: usize,
+            signature_ptr: *const u8,
+            signature_len: usize,
+        ) -> u32;
+
+        /// Verifies a BLS12-381 signature of a message, returning 1 if valid and 0 otherwise
+        pub fn crypto_utils_bls12381_v1_verify(
+            message_ptr: *const u8,
+            message_len: usize,
+            public_key_ptr: *const u8,
+            public_key_len: usize,
+            signature_ptr: *const u8,
+            signature_len: usize,
+        ) -> u32;
+
+        /// Computes the Keccak-256 hash of the given data
+        pub fn crypto_utils_keccak256_hash(data_ptr: *const u8, data_len: usize) -> Buffer;
+
+        /// Computes the Blake2b-256 hash of the given data
+        pub fn crypto_utils_blake2b_256_hash(data_ptr: *const u8, data_len: usize) -> Buffer;
+    }
+}
+
 pub mod buffer {
     pub use radix_engine_interface::types::{Buffer, BufferId, Slice};
 
@@ -9,6 +9,22 @@
 #[cfg(feature = "log-error")]
 #[macro_export]
 macro_rules! error {
+    (topic = $topic: expr, payload = $payload: expr, $($args: expr),+) => {{
+        $crate::runtime::Logger::log(
+            $crate::types::Level::Error,
+            $topic,
+            ::sbor::rust::format!($($args),+),
+            Some($crate::prelude::scrypto_encode(&$payload).unwrap()),
+        );
+    }};
+    (topic = $topic: expr, $($args: expr),+) => {{
+        $crate::runtime::Logger::log(
+            $crate::types::Level::Error,
+            $topic,
+            ::sbor::rust::format!($($args),+),
+            None,
+        );
+    }};
     ($($args: expr),+) => {{
         $crate::runtime::Logger::error(::sbor::rust::format!($($args),+));
     }};
@@ -17,7 +33,7 @@ macro_rules! error {
 #[cfg(not(feature = "log-error"))]
 #[macro_export]
 macro_rules! error {
-    ($($args: expr),+) => {{}};
+    ($($args: tt)+) => {{}};
 }
 
 /// Logs a `WARN` message.
@@ -31,6 +47,22 @@ macro_rules! error {
 #[cfg(feature = "log-warn")]
 #[macro_export]
 macro_rules! warn {
+    (topic = $topic: expr, payload = $payload: expr, $($args: expr),+) => {{
+        $crate::runtime::Logger::log(
+            $crate::types::Level::Warn,
+            $topic,
+            ::sbor::rust::format!($($args),+),
+            Some($crate::prelude::scrypto_encode(&$payload).unwrap()),
+        );
+    }};
+    (topic = $topic: expr, $($args: expr),+) => {{
+        $crate::runtime::Logger::log(
+            $crate::types::Level::Warn,
+            $topic,
+            ::sbor::rust::format!($($args),+),
+            None,
+        );
+    }};
     ($($args: expr),+) => {{
         $crate::runtime::Logger::warn(::sbor::rust::format!($($args),+));
     }};
@@ -39,7 +71,7 @@ macro_rules! warn {
 #[cfg(not(feature = "log-warn"))]
 #[macro_export]
 macro_rules! warn {
-    ($($args: expr),+) => {{}};
+    ($($args: tt)+) => {{}};
 }
 
 /// Logs an `INFO` message.
@@ -49,10 +81,30 @@ macro_rules! warn {
 /// use scrypto::prelude::*;
 ///
 /// info!("Input number: {}", 100);
+/// info!(topic = "swap", "Swapped {} for {}", 100, 200);
+/// info!(topic = "swap", payload = (100u32, 200u32), "Swapped");
 /// ```
+///
+/// A log with a topic is recorded along with its topic, and the SBOR-encoded payload if any.
 #[cfg(feature = "log-info")]
 #[macro_export]
 macro_rules! info {
+    (topic = $topic: expr, payload = $payload: expr, $($args: expr),+) => {{
+        $crate::runtime::Logger::log(
+            $crate::types::Level::Info,
+            $topic,
+            ::sbor::rust::format!($($args),+),
+            Some($crate::prelude::scrypto_encode(&$payload).unwrap()),
+        );
+    }};
+    (topic = $topic: expr, $($args: expr),+) => {{
+        $crate::runtime::Logger::log(
+            $crate::types::Level::Info,
+            $topic,
+            ::sbor::rust::format!($($args),+),
+            None,
+        );
+    }};
     ($($args: expr),+) => {{
         $crate::runtime::Logger::info(::sbor::rust::format!($($args),+));
     }};
@@ -61,7 +113,7 @@ macro_rules! info {
 #[cfg(not(feature = "log-info"))]
 #[macro_export]
 macro_rules! info {
-    ($($args: expr),+) => {{}};
+    ($($args: tt)+) => {{}};
 }
 
 /// Logs a `DEBUG` message.
@@ -75,6 +127,22 @@ macro_rules! info {
 #[cfg(feature = "log-debug")]
 #[macro_export]
 macro_rules! debug {
+    (topic = $topic: expr, payload = $payload: expr, $($args: expr),+) => {{
+        $crate::runtime::Logger::log(
+            $crate::types::Level::Debug,
+            $topic,
+            ::sbor::rust::format!($($args),+),
+            Some($crate::prelude::scrypto_encode(&$payload).unwrap()),
+        );
+    }};
+    (topic = $topic: expr, $($args: expr),+) => {{
+        $crate::runtime::Logger::log(
+            $crate::types::Level::Debug,
+            $topic,
+            ::sbor::rust::format!($($args),+),
+            None,
+        );
+    }};
     ($($args: expr),+) => {{
         $crate::runtime::Logger::debug(::sbor::rust::format!($($args),+));
     }};
@@ -83,7 +151,7 @@ macro_rules! debug {
 #[cfg(not(feature = "log-debug"))]
 #[macro_export]
 macro_rules! debug {
-    ($($args: expr),+) => {{}};
+    ($($args: tt)+) => {{}};
 }
 
 /// Logs a `TRACE` message.
@@ -97,6 +165,22 @@ macro_rules! debug {
 #[cfg(feature = "log-trace")]
 #[macro_export]
 macro_rules! trace {
+    (topic = $topic: expr, payload = $payload: expr, $($args: expr),+) => {{
+        $crate::runtime::Logger::log(
+            $crate::types::Level::Trace,
+            $topic,
+            ::sbor::rust::format!($($args),+),
+            Some($crate::prelude::scrypto_encode(&$payload).unwrap()),
+        );
+    }};
+    (topic = $topic: expr, $($args: expr),+) => {{
+        $crate::runtime::Logger::log(
+            $crate::types::Level::Trace,
+            $topic,
+            ::sbor::rust::format!($($args),+),
+            None,
+        );
+    }};
     ($($args: expr),+) => {{
         $crate::runtime::Logger::trace(::sbor::rust::format!($($args),+));
     }};
@@ -105,7 +189,102 @@ macro_rules! trace {
 #[cfg(not(feature = "log-trace"))]
 #[macro_export]
 macro_rules! trace {
-    ($($args: expr),+) => {{}};
+    ($($args: tt)+) => {{}};
+}
+
+/// Emits an application event, of a type deriving `ScryptoEvent`.
+///
+/// If the event is given as a struct expression named by a single identifier (e.g.
+/// `MyEvent { .. }`), its type is registered in the blueprint schema automatically, so it doesn't
+/// need to be listed in the `#[events(...)]` attribute. Other events (e.g. `events::MyEvent { .. }`,
+/// enum variants, or events held in variables) still need to be listed there.
+///
+/// # Example
+/// ```ignore
+/// use scrypto::prelude::*;
+///
+/// emit!(MyEvent { amount: dec!("1") });
+/// ```
+#[macro_export]
+macro_rules! emit {
+    ($event: expr) => {{
+        $crate::runtime::Runtime::emit_event($event);
+    }};
+}
+
+/// Declares a named key-value store type, with the key and value types fixed.
+///
+/// The generated type is encoded and described exactly like the underlying [`KeyValueStore`],
+/// so it can be used as a field of component state, but each accessor only accepts the declared
+/// key and value types. This avoids relying on type inference when calling `KeyValueStore::new()`.
+///
+/// # Example
+/// ```ignore
+/// use scrypto::prelude::*;
+///
+/// kv_store! {
+///     pub struct Balances: KeyValueStore<ResourceAddress, Vault>;
+/// }
+///
+/// let balances = Balances::new();
+/// assert!(!balances.contains_key(&XRD));
+/// ```
+///
+/// [`KeyValueStore`]: crate::component::KeyValueStore
+#[macro_export]
+macro_rules! kv_store {
+    (
+        $(
+            $(#[$meta:meta])*
+            $vis:vis struct $name:ident: KeyValueStore<$key:ty, $value:ty>;
+        )*
+    ) => {
+        $(
+            $(#[$meta])*
+            #[derive(::scrypto::prelude::ScryptoSbor)]
+            #[sbor(transparent, transparent_name)]
+            $vis struct $name(::scrypto::component::KeyValueStore<$key, $value>);
+
+            #[allow(dead_code)]
+            impl $name {
+                /// Creates a new key value store.
+                pub fn new() -> Self {
+                    Self(::scrypto::component::KeyValueStore::new())
+                }
+
+                /// Returns the value that is associated with the given key.
+                pub fn get(
+                    &self,
+                    key: &$key,
+                ) -> Option<::scrypto::component::KeyValueEntryRef<'_, $value>> {
+                    self.0.get(key)
+                }
+
+                /// Returns a mutable reference to the value that is associated with the given key.
+                pub fn get_mut(
+                    &mut self,
+                    key: &$key,
+                ) -> Option<::scrypto::component::KeyValueEntryRefMut<'_, $value>> {
+                    self.0.get_mut(key)
+                }
+
+                /// Inserts a new key-value pair into this map.
+                pub fn insert(&self, key: $key, value: $value) {
+                    self.0.insert(key, value)
+                }
+
+                /// Remove an entry from the map and return the original value if it exists
+                pub fn remove(&self, key: &$key) -> Option<$value> {
+                    self.0.remove(key)
+                }
+
+                /// Returns true if the map contains an entry for the given key.
+                pub fn contains_key(&self, key: &$key) -> bool {
+                    self.0.contains_key(key)
+                }
+            }
+        )*
+    };
 }
 
 #[macro_export]
@@ -451,6 +630,38 @@ macro_rules! main_accessibility {
     })
 }
 
+/// Like [`to_role_key!`], but for the roles of the outer blueprint, which are defined in another
+/// blueprint module.
+#[macro_export]
+macro_rules! to_outer_role_key {
+    (OWNER) => {{
+        OWNER_ROLE
+    }};
+    (SELF) => {{
+        SELF_ROLE
+    }};
+    ($role:ident) => {{
+        stringify!($role)
+    }};
+}
+
+#[macro_export]
+macro_rules! outer_method_accessibility {
+    (PUBLIC) => ({
+        MethodAccessibility::Public
+    });
+    (NOBODY) => ({
+        [].into()
+    });
+    (restrict_to: [$($roles:ident),+]) => ({
+        let mut list = RoleList::none();
+        $(
+            list.insert(to_outer_role_key!($roles));
+        )+
+        MethodAccessibility::RoleProtected(list)
+    });
+}
+
 #[macro_export]
 macro_rules! internal_add_role {
     ($roles:ident, $role:ident => updatable_by: [$($updaters:ident),*]) => {{
@@ -507,6 +718,32 @@ macro_rules! enable_method_auth {
         }
     );
 
+    (
+        roles_from_outer;
+        methods {
+            $($method:ident => $accessibility:ident $(: [$($allow_role:ident),+])?;)*
+        }
+    ) => (
+        fn method_auth_template() -> scrypto::blueprints::package::MethodAuthTemplate {
+            let mut methods: IndexMap<MethodKey, MethodAccessibility> = index_map_new();
+            let permissions = Methods::<MethodAccessibility> {
+                $(
+                    $method: outer_method_accessibility!($accessibility $(: [$($allow_role),+])?),
+                )*
+            };
+            for (method, permission) in permissions.to_mapping() {
+                methods.insert(MethodKey::new(method), permission);
+            }
+
+            let roles = scrypto::blueprints::package::StaticRoleDefinition {
+                methods,
+                roles: scrypto::blueprints::package::RoleSpecification::UseOuter,
+            };
+
+            scrypto::blueprints::package::MethodAuthTemplate::StaticRoleDefinition(roles)
+        }
+    );
+
     (
         methods {
             $($method:ident => $accessibility:ident $(: [$($allow_role:ident),+])?;)*
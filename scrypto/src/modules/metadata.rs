@@ -3,6 +3,7 @@ use crate::modules::ModuleHandle;
 use crate::runtime::*;
 use crate::*;
 use radix_engine_common::data::scrypto::*;
+use radix_engine_common::types::GlobalAddress;
 use radix_engine_interface::api::node_modules::metadata::*;
 use radix_engine_interface::api::AttachedModuleId;
 use radix_engine_interface::constants::METADATA_MODULE_PACKAGE;
@@ -137,6 +138,28 @@ impl Metadata {
         self.get(name)
     }
 
+    pub fn get_name(&self) -> Result<Option<String>, MetadataConversionError> {
+        self.get(MetadataStandard::Name.key())
+    }
+
+    pub fn get_symbol(&self) -> Result<Option<String>, MetadataConversionError> {
+        self.get(MetadataStandard::Symbol.key())
+    }
+
+    pub fn get_icon_url(&self) -> Result<Option<UncheckedUrl>, MetadataConversionError> {
+        self.get(MetadataStandard::IconUrl.key())
+    }
+
+    pub fn get_tags(&self) -> Result<Option<Vec<String>>, MetadataConversionError> {
+        self.get(MetadataStandard::Tags.key())
+    }
+
+    pub fn get_dapp_definitions(
+        &self,
+    ) -> Result<Option<Vec<GlobalAddress>>, MetadataConversionError> {
+        self.get(MetadataStandard::DappDefinitions.key())
+    }
+
     pub fn remove<K: ToString>(&self, name: K) -> bool {
         let rtn = self.call(
             METADATA_REMOVE_IDENT,
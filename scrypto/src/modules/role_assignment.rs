@@ -5,9 +5,10 @@ use crate::prelude::Attachable;
 use radix_engine_derive::*;
 use radix_engine_interface::api::node_modules::auth::{
     RoleAssignmentCreateInput, RoleAssignmentGetInput, RoleAssignmentLockOwnerInput,
-    RoleAssignmentSetInput, RoleAssignmentSetOwnerInput, ROLE_ASSIGNMENT_BLUEPRINT,
-    ROLE_ASSIGNMENT_CREATE_IDENT, ROLE_ASSIGNMENT_GET_IDENT, ROLE_ASSIGNMENT_LOCK_OWNER_IDENT,
-    ROLE_ASSIGNMENT_SET_IDENT, ROLE_ASSIGNMENT_SET_OWNER_IDENT,
+    RoleAssignmentSetBatchInput, RoleAssignmentSetInput, RoleAssignmentSetOwnerInput,
+    ROLE_ASSIGNMENT_BLUEPRINT, ROLE_ASSIGNMENT_CREATE_IDENT, ROLE_ASSIGNMENT_GET_IDENT,
+    ROLE_ASSIGNMENT_LOCK_OWNER_IDENT, ROLE_ASSIGNMENT_SET_BATCH_IDENT, ROLE_ASSIGNMENT_SET_IDENT,
+    ROLE_ASSIGNMENT_SET_OWNER_IDENT,
 };
 use radix_engine_interface::api::*;
 use radix_engine_interface::blueprints::resource::{
@@ -93,6 +94,17 @@ impl RoleAssignment {
         self.internal_get_role(ModuleId::Main, name)
     }
 
+    /// Sets several main roles at once, with a single auth check and event.
+    pub fn set_roles(&self, roles: IndexMap<RoleKey, AccessRule>) {
+        self.call_ignore_rtn(
+            ROLE_ASSIGNMENT_SET_BATCH_IDENT,
+            &RoleAssignmentSetBatchInput {
+                module: ModuleId::Main,
+                roles,
+            },
+        );
+    }
+
     pub fn set_metadata_role<A: Into<AccessRule>>(&self, name: &str, rule: A) {
         self.internal_set_role(ModuleId::Metadata, name, rule);
     }
@@ -4,10 +4,11 @@ use crate::runtime::*;
 use crate::*;
 use radix_engine_common::types::RoyaltyAmount;
 use radix_engine_interface::api::node_modules::royalty::{
-    ComponentClaimRoyaltiesInput, ComponentRoyaltyCreateInput, ComponentRoyaltyLockInput,
-    ComponentRoyaltySetInput, COMPONENT_ROYALTY_BLUEPRINT, COMPONENT_ROYALTY_CLAIMER_ROLE,
-    COMPONENT_ROYALTY_CLAIMER_UPDATER_ROLE, COMPONENT_ROYALTY_CLAIM_ROYALTIES_IDENT,
-    COMPONENT_ROYALTY_CREATE_IDENT, COMPONENT_ROYALTY_LOCKER_ROLE,
+    ComponentClaimRoyaltiesInput, ComponentRoyaltyCreateInput, ComponentRoyaltyCreateV2Input,
+    ComponentRoyaltyLockInput, ComponentRoyaltySetInput, COMPONENT_ROYALTY_BLUEPRINT,
+    COMPONENT_ROYALTY_CLAIMER_ROLE, COMPONENT_ROYALTY_CLAIMER_UPDATER_ROLE,
+    COMPONENT_ROYALTY_CLAIM_ROYALTIES_IDENT, COMPONENT_ROYALTY_CREATE_IDENT,
+    COMPONENT_ROYALTY_CREATE_V2_IDENT, COMPONENT_ROYALTY_LOCKER_ROLE,
     COMPONENT_ROYALTY_LOCKER_UPDATER_ROLE, COMPONENT_ROYALTY_LOCK_ROYALTY_IDENT,
     COMPONENT_ROYALTY_SETTER_ROLE, COMPONENT_ROYALTY_SETTER_UPDATER_ROLE,
     COMPONENT_ROYALTY_SET_ROYALTY_IDENT,
@@ -16,7 +17,7 @@ use radix_engine_interface::api::AttachedModuleId;
 use radix_engine_interface::blueprints::resource::Bucket;
 use radix_engine_interface::constants::ROYALTY_MODULE_PACKAGE;
 use radix_engine_interface::data::scrypto::{scrypto_decode, scrypto_encode};
-use radix_engine_interface::types::ComponentRoyaltyConfig;
+use radix_engine_interface::types::{ComponentRoyaltyConfig, ComponentRoyaltyConfigV2};
 use sbor::rust::string::ToString;
 use sbor::rust::vec;
 use sbor::rust::vec::Vec;
@@ -62,6 +63,20 @@ impl Royalty {
         Self(ModuleHandle::Own(royalty))
     }
 
+    /// Creates the royalty module with limits on the royalties charged within a single
+    /// transaction.
+    pub fn new_v2(royalty_config: ComponentRoyaltyConfigV2) -> Self {
+        let rtn = ScryptoVmV1Api::blueprint_call(
+            ROYALTY_MODULE_PACKAGE,
+            COMPONENT_ROYALTY_BLUEPRINT,
+            COMPONENT_ROYALTY_CREATE_V2_IDENT,
+            scrypto_encode(&ComponentRoyaltyCreateV2Input { royalty_config }).unwrap(),
+        );
+
+        let royalty: Own = scrypto_decode(&rtn).unwrap();
+        Self(ModuleHandle::Own(royalty))
+    }
+
     pub fn set_royalty<M: ToString>(&self, method: M, amount: RoyaltyAmount) {
         self.call_ignore_rtn(
             COMPONENT_ROYALTY_SET_ROYALTY_IDENT,
@@ -9,7 +9,7 @@ pub use crate::modules::*;
 pub use crate::resource::*;
 pub use crate::runtime::*;
 pub use crate::{
-    blueprint, component_royalties, component_royalty_config, debug, enable_function_auth,
+    blueprint, component_royalties, component_royalty_config, debug, emit, enable_function_auth,
     enable_method_auth, enable_package_royalties, error, extern_blueprint_internal, include_code,
     include_schema, info, internal_add_role, internal_component_royalty_entry, main_accessibility,
     method_accessibilities, method_accessibility, role_list, roles, this_package, to_role_key,
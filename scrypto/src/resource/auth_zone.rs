@@ -10,6 +10,8 @@ use scrypto::engine::scrypto_env::ScryptoVmV1Api;
 pub trait ScryptoAuthZone {
     fn push<P: Into<Proof>>(&self, proof: P);
 
+    fn push_composite<P: Into<Proof>>(&self, proofs: Vec<P>);
+
     fn pop(&self) -> Option<Proof>;
 
     fn create_proof_of_amount<A: Into<Decimal>>(
@@ -43,6 +45,15 @@ impl ScryptoAuthZone for AuthZoneRef {
         );
     }
 
+    fn push_composite<P: Into<Proof>>(&self, proofs: Vec<P>) {
+        let proofs: Vec<Proof> = proofs.into_iter().map(Into::into).collect();
+        ScryptoVmV1Api::object_call(
+            &self.0,
+            AUTH_ZONE_PUSH_COMPOSITE_IDENT,
+            scrypto_encode(&AuthZonePushCompositeInput { proofs }).unwrap(),
+        );
+    }
+
     fn pop(&self) -> Option<Proof> {
         let rtn = ScryptoVmV1Api::object_call(
             &self.0,
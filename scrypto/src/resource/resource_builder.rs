@@ -148,6 +148,7 @@ pub struct InProgressResourceBuilder<T: AnyResourceType> {
     resource_roles: T::ResourceRoles,
     metadata_config: Option<ModuleConfig<MetadataInit>>,
     address_reservation: Option<GlobalAddressReservation>,
+    max_supply: Option<Decimal>,
 }
 
 impl<T: AnyResourceType> InProgressResourceBuilder<T> {
@@ -158,8 +159,27 @@ impl<T: AnyResourceType> InProgressResourceBuilder<T> {
             metadata_config: None,
             address_reservation: None,
             resource_roles: T::ResourceRoles::default(),
+            max_supply: None,
         }
     }
+
+    /// Caps the total supply of the resource - any mint which would take the total supply
+    /// above the cap fails. The total supply of a capped resource is always tracked.
+    ///
+    /// RUID non-fungible resources with a cap must be created with no initial supply.
+    ///
+    /// ### Examples
+    ///
+    /// ```no_run
+    /// use scrypto::prelude::*;
+    ///
+    /// ResourceBuilder::new_fungible(OwnerRole::None)
+    ///    .max_supply(21_000_000);
+    /// ```
+    pub fn max_supply<A: Into<Decimal>>(mut self, max_supply: A) -> Self {
+        self.max_supply = Some(max_supply.into());
+        self
+    }
 }
 
 // Various types for ResourceType
@@ -540,9 +560,31 @@ pub trait CreateWithNoSupplyBuilder: private::CanCreateWithNoSupply {
                 resource_roles,
                 metadata,
                 address_reservation,
+                max_supply,
             } => {
                 let metadata = metadata.unwrap_or_else(|| Default::default());
 
+                if let Some(max_supply) = max_supply {
+                    let bytes = ScryptoVmV1Api::blueprint_call(
+                        RESOURCE_PACKAGE,
+                        FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+                        FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_MAX_SUPPLY_IDENT,
+                        scrypto_encode(&FungibleResourceManagerCreateWithMaxSupplyInput {
+                            owner_role,
+                            divisibility,
+                            max_supply,
+                            initial_supply: None,
+                            metadata,
+                            resource_roles,
+                            address_reservation,
+                        })
+                        .unwrap(),
+                    );
+                    return scrypto_decode::<(ResourceManager, Option<Bucket>)>(&bytes)
+                        .unwrap()
+                        .0;
+                }
+
                 let bytes = ScryptoVmV1Api::blueprint_call(
                     RESOURCE_PACKAGE,
                     FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
@@ -566,9 +608,34 @@ pub trait CreateWithNoSupplyBuilder: private::CanCreateWithNoSupply {
                 resource_roles,
                 metadata,
                 address_reservation,
+                max_supply,
             } => {
                 let metadata = metadata.unwrap_or_else(|| Default::default());
 
+                if let Some(max_supply) = max_supply {
+                    let bytes = ScryptoVmV1Api::blueprint_call(
+                        RESOURCE_PACKAGE,
+                        NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+                        NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_MAX_SUPPLY_IDENT,
+                        scrypto_encode(
+                            &NonFungibleResourceManagerCreateWithMaxSupplyGenericInput::<_, ()> {
+                                owner_role,
+                                id_type,
+                                non_fungible_schema,
+                                max_supply,
+                                entries: None,
+                                resource_roles,
+                                metadata,
+                                address_reservation,
+                            },
+                        )
+                        .unwrap(),
+                    );
+                    return scrypto_decode::<(ResourceManager, Option<Bucket>)>(&bytes)
+                        .unwrap()
+                        .0;
+                }
+
                 let bytes = ScryptoVmV1Api::blueprint_call(
                     RESOURCE_PACKAGE,
                     NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
@@ -633,6 +700,28 @@ impl InProgressResourceBuilder<FungibleResourceType> {
             .take()
             .unwrap_or_else(|| Default::default());
 
+        if let Some(max_supply) = self.max_supply {
+            let bytes = ScryptoVmV1Api::blueprint_call(
+                RESOURCE_PACKAGE,
+                FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+                FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_MAX_SUPPLY_IDENT,
+                scrypto_encode(&FungibleResourceManagerCreateWithMaxSupplyInput {
+                    owner_role: self.owner_role,
+                    divisibility: self.resource_type.divisibility,
+                    max_supply,
+                    initial_supply: Some(amount.into()),
+                    resource_roles: self.resource_roles,
+                    metadata,
+                    address_reservation: self.address_reservation,
+                })
+                .unwrap(),
+            );
+            return scrypto_decode::<(ResourceAddress, Option<FungibleBucket>)>(&bytes)
+                .unwrap()
+                .1
+                .unwrap();
+        }
+
         let bytes = ScryptoVmV1Api::blueprint_call(
             RESOURCE_PACKAGE,
             FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
@@ -681,6 +770,10 @@ impl<D: NonFungibleData, S: ScryptoCategorize + ScryptoEncode + ScryptoDecode>
     where
         T: IntoIterator<Item = (StringNonFungibleLocalId, D)>,
     {
+        if let Some(max_supply) = self.max_supply {
+            return self.mint_initial_supply_with_max_supply(max_supply, map_entries(entries));
+        }
+
         let metadata = self
             .metadata_config
             .take()
@@ -736,6 +829,10 @@ impl<D: NonFungibleData, S: ScryptoCategorize + ScryptoEncode + ScryptoDecode>
     where
         T: IntoIterator<Item = (IntegerNonFungibleLocalId, D)>,
     {
+        if let Some(max_supply) = self.max_supply {
+            return self.mint_initial_supply_with_max_supply(max_supply, map_entries(entries));
+        }
+
         let metadata = self
             .metadata_config
             .take()
@@ -791,6 +888,10 @@ impl<D: NonFungibleData, S: ScryptoCategorize + ScryptoEncode + ScryptoDecode>
     where
         T: IntoIterator<Item = (BytesNonFungibleLocalId, D)>,
     {
+        if let Some(max_supply) = self.max_supply {
+            return self.mint_initial_supply_with_max_supply(max_supply, map_entries(entries));
+        }
+
         let metadata = self
             .metadata_config
             .take()
@@ -850,6 +951,11 @@ impl<D: NonFungibleData, S: ScryptoCategorize + ScryptoEncode + ScryptoDecode>
         T: IntoIterator<Item = D>,
         D: ScryptoEncode,
     {
+        assert!(
+            self.max_supply.is_none(),
+            "RUID non-fungible resources with a max supply must be created with no initial supply"
+        );
+
         let metadata = self
             .metadata_config
             .take()
@@ -892,6 +998,45 @@ fn map_entries<T: IntoIterator<Item = (Y, V)>, V: NonFungibleData, Y: IsNonFungi
         .collect()
 }
 
+impl<
+        Y: IsNonFungibleLocalId,
+        D: NonFungibleData,
+        S: ScryptoCategorize + ScryptoEncode + ScryptoDecode,
+    > InProgressResourceBuilder<NonFungibleResourceType<Y, D, S>>
+{
+    fn mint_initial_supply_with_max_supply(
+        mut self,
+        max_supply: Decimal,
+        entries: IndexMap<NonFungibleLocalId, (D,)>,
+    ) -> NonFungibleBucket {
+        let metadata = self
+            .metadata_config
+            .take()
+            .unwrap_or_else(|| Default::default());
+
+        let bytes = ScryptoVmV1Api::blueprint_call(
+            RESOURCE_PACKAGE,
+            NON_FUNGIBLE_RESOURCE_MANAGER_BLUEPRINT,
+            NON_FUNGIBLE_RESOURCE_MANAGER_CREATE_WITH_MAX_SUPPLY_IDENT,
+            scrypto_encode(&NonFungibleResourceManagerCreateWithMaxSupplyGenericInput {
+                owner_role: self.owner_role,
+                id_type: Y::id_type(),
+                non_fungible_schema: self.resource_type.0,
+                max_supply,
+                entries: Some(entries),
+                resource_roles: self.resource_roles,
+                metadata,
+                address_reservation: self.address_reservation,
+            })
+            .unwrap(),
+        );
+        scrypto_decode::<(ResourceAddress, Option<NonFungibleBucket>)>(&bytes)
+            .unwrap()
+            .1
+            .unwrap()
+    }
+}
+
 impl<T: AnyResourceType> private::CanSetMetadata for InProgressResourceBuilder<T> {
     type OutputBuilder = Self;
 
@@ -922,6 +1067,7 @@ impl private::CanCreateWithNoSupply for InProgressResourceBuilder<FungibleResour
             resource_roles: self.resource_roles,
             metadata: self.metadata_config,
             address_reservation: self.address_reservation,
+            max_supply: self.max_supply,
         }
     }
 }
@@ -943,6 +1089,7 @@ impl<
             resource_roles: self.resource_roles,
             metadata: self.metadata_config,
             address_reservation: self.address_reservation,
+            max_supply: self.max_supply,
         }
     }
 }
@@ -1008,6 +1155,7 @@ mod private {
             resource_roles: FungibleResourceRoles,
             metadata: Option<ModuleConfig<MetadataInit>>,
             address_reservation: Option<GlobalAddressReservation>,
+            max_supply: Option<Decimal>,
         },
         NonFungible {
             owner_role: OwnerRole,
@@ -1016,6 +1164,7 @@ mod private {
             resource_roles: NonFungibleResourceRoles,
             metadata: Option<ModuleConfig<MetadataInit>>,
             address_reservation: Option<GlobalAddressReservation>,
+            max_supply: Option<Decimal>,
         },
     }
 
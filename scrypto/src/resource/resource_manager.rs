@@ -17,6 +17,7 @@ use radix_engine_interface::*;
 use sbor::rust::prelude::*;
 use sbor::*;
 use scrypto::component::HasStub;
+use scrypto::engine::scrypto_env::ScryptoVmV1Api;
 
 #[derive(Debug, Clone, Copy, Eq, PartialEq, ScryptoEncode, ScryptoDecode, ScryptoCategorize)]
 #[sbor(transparent)]
@@ -175,6 +176,14 @@ impl ResourceManagerStub {
         )
     }
 
+    /// Returns the cap on the total supply, if the resource was created with one.
+    pub fn max_supply(&self) -> Option<Decimal> {
+        self.call(
+            RESOURCE_MANAGER_GET_MAX_SUPPLY_IDENT,
+            &ResourceManagerGetMaxSupplyInput {},
+        )
+    }
+
     pub fn non_fungible_exists(&self, id: &NonFungibleLocalId) -> bool {
         self.call(
             NON_FUNGIBLE_RESOURCE_MANAGER_EXISTS_IDENT,
@@ -270,4 +279,85 @@ impl ResourceManagerStub {
             },
         )
     }
+
+    /// Recalls some amount of resource from a vault of this resource, wherever it is stored.
+    ///
+    /// Requires the `recaller` role of this resource.
+    ///
+    /// # Panics
+    /// Panics if the vault does not hold this resource.
+    pub fn recall_from_vault<A: Into<Decimal>>(&self, vault: InternalAddress, amount: A) -> Bucket {
+        self.call_vault(
+            vault,
+            VAULT_RECALL_IDENT,
+            &VaultRecallInput {
+                amount: amount.into(),
+            },
+        )
+    }
+
+    /// Recalls specific non-fungibles from a vault of this resource, wherever it is stored.
+    ///
+    /// Requires the `recaller` role of this resource.
+    ///
+    /// # Panics
+    /// Panics if the vault does not hold this resource, or if this is not a non-fungible resource.
+    pub fn recall_non_fungibles_from_vault(
+        &self,
+        vault: InternalAddress,
+        non_fungible_local_ids: &IndexSet<NonFungibleLocalId>,
+    ) -> Bucket {
+        self.call_vault(
+            vault,
+            NON_FUNGIBLE_VAULT_RECALL_NON_FUNGIBLES_IDENT,
+            &NonFungibleVaultRecallNonFungiblesInput {
+                non_fungible_local_ids: non_fungible_local_ids.clone(),
+            },
+        )
+    }
+
+    /// Freezes the given operations on a vault of this resource, wherever it is stored.
+    ///
+    /// Requires the `freezer` role of this resource.
+    ///
+    /// # Panics
+    /// Panics if the vault does not hold this resource.
+    pub fn freeze_vault(&self, vault: InternalAddress, to_freeze: VaultFreezeFlags) {
+        self.call_vault(vault, VAULT_FREEZE_IDENT, &VaultFreezeInput { to_freeze })
+    }
+
+    /// Unfreezes the given operations on a vault of this resource, wherever it is stored.
+    ///
+    /// Requires the `freezer` role of this resource.
+    ///
+    /// # Panics
+    /// Panics if the vault does not hold this resource.
+    pub fn unfreeze_vault(&self, vault: InternalAddress, to_unfreeze: VaultFreezeFlags) {
+        self.call_vault(
+            vault,
+            VAULT_UNFREEZE_IDENT,
+            &VaultUnfreezeInput { to_unfreeze },
+        )
+    }
+
+    /// Calls a direct access method of a vault, after checking that it holds this resource.
+    fn call_vault<A: ScryptoEncode, T: ScryptoDecode>(
+        &self,
+        vault: InternalAddress,
+        method: &str,
+        args: &A,
+    ) -> T {
+        let resource_address = ScryptoVmV1Api::object_get_outer_object(vault.as_node_id());
+        assert_eq!(
+            resource_address.as_node_id(),
+            self.0.as_node_id(),
+            "The vault does not hold this resource"
+        );
+        let rtn = ScryptoVmV1Api::object_call_direct(
+            vault.as_node_id(),
+            method,
+            scrypto_encode(args).unwrap(),
+        );
+        scrypto_decode(&rtn).unwrap()
+    }
 }
@@ -64,6 +64,16 @@ pub trait ScryptoFungibleVault {
 pub trait ScryptoNonFungibleVault {
     fn non_fungible_local_ids(&self, limit: u32) -> IndexSet<NonFungibleLocalId>;
 
+    /// Returns a page of the non-fungible local IDs in this vault.
+    ///
+    /// Pass `None` as the cursor to get the first page, and the `next_cursor` of the returned
+    /// page to get the following one.
+    fn non_fungible_local_ids_page(
+        &self,
+        limit: u32,
+        cursor: Option<NonFungibleLocalId>,
+    ) -> NonFungibleLocalIdsPage;
+
     fn contains_non_fungible(&self, id: &NonFungibleLocalId) -> bool;
 
     fn non_fungibles<T: NonFungibleData>(&self, limit: u32) -> Vec<NonFungible<T>>;
@@ -407,6 +417,20 @@ impl ScryptoNonFungibleVault for NonFungibleVault {
         scrypto_decode(&rtn).unwrap()
     }
 
+    fn non_fungible_local_ids_page(
+        &self,
+        limit: u32,
+        cursor: Option<NonFungibleLocalId>,
+    ) -> NonFungibleLocalIdsPage {
+        let rtn = ScryptoVmV1Api::object_call(
+            self.0 .0.as_node_id(),
+            NON_FUNGIBLE_VAULT_GET_NON_FUNGIBLE_LOCAL_IDS_PAGE_IDENT,
+            scrypto_encode(&NonFungibleVaultGetNonFungibleLocalIdsPageInput { limit, cursor })
+                .unwrap(),
+        );
+        scrypto_decode(&rtn).unwrap()
+    }
+
     fn contains_non_fungible(&self, id: &NonFungibleLocalId) -> bool {
         let rtn = ScryptoVmV1Api::object_call(
             self.0 .0.as_node_id(),
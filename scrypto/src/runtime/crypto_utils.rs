@@ -0,0 +1,52 @@
+use radix_engine_interface::crypto::{
+    Bls12381G1PublicKey, Bls12381G2Signature, Ed25519PublicKey, Ed25519Signature, Hash,
+    Secp256k1PublicKey, Secp256k1Signature,
+};
+use sbor::rust::fmt::Debug;
+use scrypto::engine::scrypto_env::ScryptoVmV1Api;
+
+/// Cryptographic utilities, computed natively by the engine.
+#[derive(Debug)]
+pub struct CryptoUtils {}
+
+impl CryptoUtils {
+    /// Returns true if the signature is a valid Ed25519 signature of the Blake2b-256 hash of the
+    /// message.
+    pub fn ed25519_verify<T: AsRef<[u8]>>(
+        message: T,
+        public_key: &Ed25519PublicKey,
+        signature: &Ed25519Signature,
+    ) -> bool {
+        ScryptoVmV1Api::crypto_utils_ed25519_verify(message.as_ref(), public_key, signature)
+    }
+
+    /// Returns true if the signature is a valid Secp256k1 signature of the Blake2b-256 hash of the
+    /// message.
+    pub fn secp256k1_verify<T: AsRef<[u8]>>(
+        message: T,
+        public_key: &Secp256k1PublicKey,
+        signature: &Secp256k1Signature,
+    ) -> bool {
+        ScryptoVmV1Api::crypto_utils_secp256k1_verify(message.as_ref(), public_key, signature)
+    }
+
+    /// Returns true if the signature is a valid BLS12-381 signature of the message, using the
+    /// `BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_` ciphersuite.
+    pub fn bls12381_v1_verify<T: AsRef<[u8]>>(
+        message: T,
+        public_key: &Bls12381G1PublicKey,
+        signature: &Bls12381G2Signature,
+    ) -> bool {
+        ScryptoVmV1Api::crypto_utils_bls12381_v1_verify(message.as_ref(), public_key, signature)
+    }
+
+    /// Returns the Keccak-256 hash of the data.
+    pub fn keccak256_hash<T: AsRef<[u8]>>(data: T) -> Hash {
+        ScryptoVmV1Api::crypto_utils_keccak256_hash(data.as_ref())
+    }
+
+    /// Returns the Blake2b-256 hash of the data.
+    pub fn blake2b_256_hash<T: AsRef<[u8]>>(data: T) -> Hash {
+        ScryptoVmV1Api::crypto_utils_blake2b_256_hash(data.as_ref())
+    }
+}
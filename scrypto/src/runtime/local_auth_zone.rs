@@ -23,6 +23,12 @@ impl LocalAuthZone {
         AuthZoneRef(node_id).push(proof)
     }
 
+    /// Pushes all the given proofs in a single call, e.g. to satisfy a rule requiring all of them.
+    pub fn push_composite<P: Into<Proof>>(proofs: Vec<P>) {
+        let node_id = ScryptoVmV1Api::actor_get_object_id(ACTOR_REF_AUTH_ZONE);
+        AuthZoneRef(node_id).push_composite(proofs)
+    }
+
     pub fn pop() -> Option<Proof> {
         let node_id = ScryptoVmV1Api::actor_get_object_id(ACTOR_REF_AUTH_ZONE);
         AuthZoneRef(node_id).pop()
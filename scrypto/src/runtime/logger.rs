@@ -31,4 +31,9 @@ impl Logger {
     pub fn error(message: String) {
         ScryptoVmV1Api::sys_log(Level::Error, message);
     }
+
+    /// Emits a message under a topic, with an optional SBOR-encoded payload.
+    pub fn log(level: Level, topic: &str, message: String, payload: Option<Vec<u8>>) {
+        ScryptoVmV1Api::sys_log_record(level, topic, message, payload);
+    }
 }
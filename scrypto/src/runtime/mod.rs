@@ -1,10 +1,12 @@
 mod clock;
+mod crypto_utils;
 mod data;
 mod local_auth_zone;
 mod logger;
 mod runtime;
 
 pub use clock::*;
+pub use crypto_utils::*;
 pub use data::*;
 pub use local_auth_zone::*;
 pub use logger::Logger;
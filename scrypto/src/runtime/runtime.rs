@@ -86,6 +86,12 @@ impl Runtime {
         ScryptoVmV1Api::actor_get_blueprint_name()
     }
 
+    /// Returns whether a feature is enabled for the current component, i.e. whether it was
+    /// instantiated with it.
+    pub fn is_feature_enabled(feature: &str) -> bool {
+        ScryptoVmV1Api::actor_is_feature_enabled(ACTOR_STATE_SELF, feature)
+    }
+
     pub fn package_token() -> NonFungibleGlobalId {
         NonFungibleGlobalId::package_of_direct_caller_badge(Runtime::package_address())
     }
@@ -118,6 +124,17 @@ impl Runtime {
         ScryptoVmV1Api::sys_generate_ruid()
     }
 
+    /// Returns a value derived from the transaction hash, a counter of the calls made so far in
+    /// the transaction and the given domain.
+    ///
+    /// Every call returns a different value, and callers using distinct domains can't influence
+    /// each other's values. The value is however fully determined by the transaction, so whoever
+    /// builds the transaction can compute it in advance: it is a source of in-transaction entropy,
+    /// not of secret randomness.
+    pub fn generate_ruid_seeded<T: AsRef<[u8]>>(domain: T) -> [u8; 32] {
+        ScryptoVmV1Api::sys_generate_ruid_seeded(domain.as_ref())
+    }
+
     pub fn bech32_encode_address<A: Into<GlobalAddress>>(address: A) -> String {
         ScryptoVmV1Api::sys_bech32_encode_address(address.into())
     }
@@ -4,6 +4,7 @@ use super::Error;
 use clap::Parser;
 use flate2::read::GzDecoder;
 use flume;
+use radix_engine::transaction::StateDiff;
 use radix_engine::types::*;
 use radix_engine::vm::wasm::*;
 use radix_engine::vm::ScryptoVm;
@@ -34,6 +35,10 @@ pub struct TxnExecuteInMemory {
     /// State hash breakpoints, in format of comma separated `<version>:<hash>`
     #[clap(short, long)]
     pub breakpoints: Option<String>,
+
+    /// Print a typed diff of the state changes of each transaction
+    #[clap(long)]
+    pub state_diff: bool,
 }
 
 impl TxnExecuteInMemory {
@@ -81,12 +86,18 @@ impl TxnExecuteInMemory {
         // txn executor
         let substate_database = InMemorySubstateDatabase::standard();
         let mut database = HashTreeUpdatingDatabase::new(substate_database);
+        let print_state_diff = self.state_diff;
         let txn_write_thread_handle = thread::spawn(move || {
             let scrypto_vm = ScryptoVm::<DefaultWasmEngine>::default();
+            let encoder = AddressBech32Encoder::new(&network);
             let iter = rx.iter();
             for tx_payload in iter {
                 let state_updates =
                     execute_ledger_transaction(&database, &scrypto_vm, &network, &tx_payload);
+                if print_state_diff {
+                    let state_diff = StateDiff::new(&database, &state_updates);
+                    println!("{}", state_diff.display(&encoder));
+                }
                 let database_updates =
                     state_updates.create_database_updates::<SpreadPrefixKeyMapper>();
                 database.commit(&database_updates);
@@ -38,6 +38,10 @@ pub struct TxnMeasure {
     /// The max version to execute
     #[clap(short, long)]
     pub max_version: Option<u64>,
+    /// Path to a folder for writing the execution trace of each user transaction to, in the
+    /// Chrome trace-event JSON format
+    #[clap(long)]
+    pub chrome_trace_dir: Option<PathBuf>,
 }
 
 impl TxnMeasure {
@@ -91,6 +95,11 @@ impl TxnMeasure {
             .map_err(Error::IOError)?;
         }
 
+        if let Some(chrome_trace_dir) = &self.chrome_trace_dir {
+            std::fs::create_dir_all(chrome_trace_dir).map_err(Error::IOError)?;
+        }
+        let chrome_trace_dir = self.chrome_trace_dir.clone();
+
         let txn_write_thread_handle = thread::spawn(move || {
             let scrypto_vm = ScryptoVm::<DefaultWasmEngine>::default();
             let iter = rx.iter();
@@ -102,7 +111,11 @@ impl TxnMeasure {
                     &scrypto_vm,
                     &network,
                     &prepared,
+                    chrome_trace_dir.is_some(),
                 );
+                let chrome_trace = receipt
+                    .execution_trace()
+                    .map(|trace| trace.to_chrome_trace_json());
                 let execution_cost_units = receipt
                     .fee_summary()
                     .map(|x| x.total_execution_cost_units_consumed.clone());
@@ -115,12 +128,22 @@ impl TxnMeasure {
                 database.commit(&database_updates);
                 let tx_processing_time = tx_start_time.elapsed();
                 if let PreparedLedgerTransactionInner::UserV1(tx) = prepared.inner {
+                    let intent_hash = TransactionHashBech32Encoder::new(&network)
+                        .encode(&IntentHash(tx.signed_intent.intent.summary.hash))
+                        .unwrap();
+                    if let (Some(chrome_trace_dir), Some(chrome_trace)) =
+                        (&chrome_trace_dir, chrome_trace)
+                    {
+                        std::fs::write(
+                            chrome_trace_dir.join(format!("{}.json", intent_hash)),
+                            chrome_trace,
+                        )
+                        .map_err(Error::IOError)?;
+                    }
                     writeln!(
                         output,
                         "{},{},{},{}",
-                        TransactionHashBech32Encoder::new(&network)
-                            .encode(&IntentHash(tx.signed_intent.intent.summary.hash))
-                            .unwrap(),
+                        intent_hash,
                         tx_processing_time.as_micros(),
                         execution_cost_units.unwrap(),
                         finalization_cost_units.unwrap(),
@@ -1,5 +1,6 @@
 use super::ledger_transaction::*;
 use super::ledger_transaction_execution::execute_ledger_transaction;
+use super::parallel_execution::execute_and_commit_in_parallel;
 use super::Error;
 use clap::Parser;
 use flume;
@@ -9,12 +10,14 @@ use radix_engine::vm::wasm::*;
 use radix_engine::vm::ScryptoVm;
 use radix_engine_interface::prelude::NetworkDefinition;
 use radix_engine_store_interface::db_key_mapper::SpreadPrefixKeyMapper;
-use radix_engine_store_interface::interface::CommittableSubstateDatabase;
+use radix_engine_store_interface::interface::{
+    CommittableSubstateDatabase, PrunableSubstateDatabase,
+};
 use radix_engine_stores::rocks_db_with_merkle_tree::RocksDBWithMerkleTreeSubstateStore;
 use rocksdb::{Direction, IteratorMode, Options, DB};
 use std::path::PathBuf;
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use transaction::prelude::{
     IntentHash, NotarizedTransactionHash, SignedIntentHash, SystemTransactionHash,
 };
@@ -33,8 +36,24 @@ pub struct TxnSync {
     /// The max version to execute
     #[clap(short, long)]
     pub max_version: Option<u64>,
+    /// Executes independent transactions in parallel, in batches of up to the given size.
+    /// Transactions are still committed in order, and re-executed serially on conflicts.
+    #[clap(short, long)]
+    pub parallel_batch_size: Option<usize>,
+    /// Retains the past values of the substates, so that they can be read at older versions.
+    /// Once enabled, the history keeps being recorded on every later sync.
+    #[clap(long)]
+    pub history: bool,
+    /// After syncing, prunes the historical data which only the versions older than the given
+    /// number of latest ones need.
+    #[clap(long)]
+    pub keep_versions: Option<u64>,
 }
 
+/// How long to wait for more transactions before executing a partially filled parallel batch,
+/// e.g. when syncing at the tip of the ledger.
+const BATCH_FILL_TIMEOUT: Duration = Duration::from_millis(100);
+
 impl TxnSync {
     pub fn sync(&self) -> Result<(), Error> {
         let network = match &self.network {
@@ -51,9 +70,10 @@ impl TxnSync {
             cur_version
         };
         let to_version = self.max_version.clone();
+        let parallel_batch_size = self.parallel_batch_size.map(|size| size.max(1));
 
-        let start = std::time::Instant::now();
-        let (tx, rx) = flume::bounded(10);
+        let start = Instant::now();
+        let (tx, rx) = flume::bounded(parallel_batch_size.unwrap_or(0).max(10));
 
         // txn reader
         let mut txn_reader = CommittedTxnReader::StateManagerDatabaseDir(self.source.clone());
@@ -61,30 +81,62 @@ impl TxnSync {
             thread::spawn(move || txn_reader.read(cur_version, to_version, tx));
 
         // txn executor
-        let mut database = RocksDBWithMerkleTreeSubstateStore::standard(self.database_dir.clone());
+        let mut database = if self.history {
+            RocksDBWithMerkleTreeSubstateStore::standard_with_history(self.database_dir.clone())
+        } else {
+            RocksDBWithMerkleTreeSubstateStore::standard(self.database_dir.clone())
+        };
+        let keep_versions = self.keep_versions;
         let txn_write_thread_handle = thread::spawn(move || {
             let scrypto_vm = ScryptoVm::<DefaultWasmEngine>::default();
-            let iter = rx.iter();
-            for (tx_payload, expected_state_root_hash) in iter {
-                let state_updates =
-                    execute_ledger_transaction(&database, &scrypto_vm, &network, &tx_payload);
-                let database_updates =
-                    state_updates.create_database_updates::<SpreadPrefixKeyMapper>();
-                database.commit(&database_updates);
-
-                let new_state_root_hash = database.get_current_root_hash();
-                let new_version = database.get_current_version();
-
-                if new_state_root_hash != expected_state_root_hash {
-                    panic!(
-                        "State hash mismatch at version {}. Expected {} Actual {}",
-                        new_version, expected_state_root_hash, new_state_root_hash
-                    );
+            match parallel_batch_size {
+                None => {
+                    for (tx_payload, expected_state_root_hash) in rx.iter() {
+                        let state_updates = execute_ledger_transaction(
+                            &database,
+                            &scrypto_vm,
+                            &network,
+                            &tx_payload,
+                        );
+                        let database_updates =
+                            state_updates.create_database_updates::<SpreadPrefixKeyMapper>();
+                        database.commit(&database_updates);
+                        check_committed_transaction(&database, expected_state_root_hash, start);
+                    }
                 }
+                Some(batch_size) => {
+                    let thread_count = thread::available_parallelism()
+                        .map(|count| count.get())
+                        .unwrap_or(1);
+                    let mut serial_count = 0;
+                    while let Ok(first) = rx.recv() {
+                        let mut batch = vec![first];
+                        while batch.len() < batch_size {
+                            match rx.recv_timeout(BATCH_FILL_TIMEOUT) {
+                                Ok(next) => batch.push(next),
+                                Err(_) => break,
+                            }
+                        }
+                        let (tx_payloads, expected_state_root_hashes): (Vec<_>, Vec<_>) =
+                            batch.into_iter().unzip();
 
-                // print progress
-                if new_version < 1000 || new_version % 1000 == 0 {
-                    print_progress(start.elapsed(), new_version, new_state_root_hash);
+                        let stats = execute_and_commit_in_parallel(
+                            &mut database,
+                            &scrypto_vm,
+                            &network,
+                            thread_count,
+                            &tx_payloads,
+                            |database, index| {
+                                check_committed_transaction(
+                                    database,
+                                    expected_state_root_hashes[index],
+                                    start,
+                                )
+                            },
+                        );
+                        serial_count += stats.serial;
+                    }
+                    println!("Re-executed serially due to conflicts: {}", serial_count);
                 }
             }
 
@@ -92,6 +144,19 @@ impl TxnSync {
             println!("Time elapsed: {:?}", duration);
             println!("State version: {}", database.get_current_version());
             println!("State root hash: {}", database.get_current_root_hash());
+
+            if let Some(keep_versions) = keep_versions {
+                let prune_before_version =
+                    database.get_current_version().saturating_sub(keep_versions);
+                let metrics = database.prune_before_version(prune_before_version);
+                println!(
+                    "Pruned before version {}: {} tree nodes, {} history entries, {} bytes",
+                    database.get_pruned_before_version(),
+                    metrics.pruned_tree_nodes,
+                    metrics.pruned_history_entries,
+                    metrics.reclaimed_bytes
+                );
+            }
         });
 
         txn_read_thread_handle.join().unwrap()?;
@@ -101,6 +166,27 @@ impl TxnSync {
     }
 }
 
+fn check_committed_transaction(
+    database: &RocksDBWithMerkleTreeSubstateStore,
+    expected_state_root_hash: Hash,
+    start: Instant,
+) {
+    let new_state_root_hash = database.get_current_root_hash();
+    let new_version = database.get_current_version();
+
+    if new_state_root_hash != expected_state_root_hash {
+        panic!(
+            "State hash mismatch at version {}. Expected {} Actual {}",
+            new_version, expected_state_root_hash, new_state_root_hash
+        );
+    }
+
+    // print progress
+    if new_version < 1000 || new_version % 1000 == 0 {
+        print_progress(start.elapsed(), new_version, new_state_root_hash);
+    }
+}
+
 fn print_progress(duration: Duration, new_version: u64, new_root: Hash) {
     let seconds = duration.as_secs() % 60;
     let minutes = (duration.as_secs() / 60) % 60;
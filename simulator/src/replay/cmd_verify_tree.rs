@@ -0,0 +1,52 @@
+use super::Error;
+use clap::Parser;
+use radix_engine::types::*;
+use radix_engine_stores::rocks_db_with_merkle_tree::RocksDBWithMerkleTreeSubstateStore;
+use std::path::PathBuf;
+use std::time::Instant;
+
+/// Verify the state tree of a database against its substates
+#[derive(Parser, Debug)]
+pub struct VerifyTree {
+    /// Path to the folder storing the state
+    pub database_dir: PathBuf,
+
+    /// The max number of issues to print
+    #[clap(short, long, default_value_t = 100)]
+    pub max_printed_issues: usize,
+}
+
+impl VerifyTree {
+    pub fn run(&self) -> Result<(), Error> {
+        if !self.database_dir.exists() {
+            return Err(Error::IOError(std::io::Error::from(
+                std::io::ErrorKind::NotFound,
+            )));
+        }
+
+        let start = Instant::now();
+        let database = RocksDBWithMerkleTreeSubstateStore::standard(self.database_dir.clone());
+        let report = database.verify_state_tree();
+
+        for issue in report.issues.iter().take(self.max_printed_issues) {
+            println!("{:?}", issue);
+        }
+        if report.issues.len() > self.max_printed_issues {
+            println!(
+                "... and {} more issues",
+                report.issues.len() - self.max_printed_issues
+            );
+        }
+        println!("Time elapsed: {:?}", start.elapsed());
+        println!("State version: {}", report.state_version);
+        println!("State root hash: {}", database.get_current_root_hash());
+        println!("Verified tree nodes: {}", report.verified_tree_nodes);
+        println!("Verified substates: {}", report.verified_substates);
+
+        if report.is_consistent() {
+            Ok(())
+        } else {
+            Err(Error::InconsistentStateTree(report.issues.len()))
+        }
+    }
+}
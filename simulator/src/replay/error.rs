@@ -8,4 +8,6 @@ pub enum Error {
     InvalidTransactionArchive,
     InvalidTransactionSource,
     InvalidBreakpoints(String),
+    /// The state tree has the given number of issues
+    InconsistentStateTree(usize),
 }
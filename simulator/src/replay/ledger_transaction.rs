@@ -114,7 +114,7 @@ impl PreparedRoundUpdateTransactionV1 {
                     virtual_resources: BTreeSet::new(),
                 },
                 costing_parameters: TransactionCostingParameters {
-                    tip_percentage: 0,
+                    tip: TipBasisPoints::ZERO,
                     free_credit_in_xrd: Decimal::ZERO,
                 },
                 pre_allocated_addresses: vec![],
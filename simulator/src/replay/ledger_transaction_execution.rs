@@ -2,8 +2,8 @@ use super::ledger_transaction::*;
 use radix_engine::system::bootstrap::*;
 use radix_engine::track::StateUpdates;
 use radix_engine::transaction::{
-    execute_transaction, CostingParameters, ExecutionConfig, TransactionFeeSummary,
-    TransactionReceipt,
+    execute_transaction, CostingParameters, ExecutionConfig, TransactionExecutionTrace,
+    TransactionFeeSummary, TransactionReceipt, TransactionResult,
 };
 use radix_engine::types::*;
 use radix_engine::vm::wasm::*;
@@ -30,6 +30,16 @@ impl LedgerTransactionReceipt {
         }
     }
 
+    pub fn execution_trace(&self) -> Option<&TransactionExecutionTrace> {
+        match self {
+            LedgerTransactionReceipt::Flash(_) => None,
+            LedgerTransactionReceipt::Standard(receipt) => match &receipt.result {
+                TransactionResult::Commit(commit) => commit.execution_trace.as_ref(),
+                _ => None,
+            },
+        }
+    }
+
     pub fn fee_summary(&self) -> Option<&TransactionFeeSummary> {
         match self {
             LedgerTransactionReceipt::Flash(_) => None,
@@ -45,7 +55,7 @@ pub fn execute_ledger_transaction<S: SubstateDatabase>(
     tx_payload: &[u8],
 ) -> StateUpdates {
     let prepared = prepare_ledger_transaction(tx_payload);
-    execute_prepared_ledger_transaction(database, scrypto_vm, network, &prepared)
+    execute_prepared_ledger_transaction(database, scrypto_vm, network, &prepared, false)
         .into_state_updates()
 }
 
@@ -63,6 +73,7 @@ pub fn execute_prepared_ledger_transaction<S: SubstateDatabase>(
     scrypto_vm: &ScryptoVm<DefaultWasmEngine>,
     network: &NetworkDefinition,
     prepared: &PreparedLedgerTransaction,
+    execution_trace: bool,
 ) -> LedgerTransactionReceipt {
     match &prepared.inner {
         PreparedLedgerTransactionInner::Genesis(prepared_genesis_tx) => {
@@ -79,7 +90,8 @@ pub fn execute_prepared_ledger_transaction<S: SubstateDatabase>(
                             native_vm: DefaultNativeVm::new(),
                         },
                         &CostingParameters::default(),
-                        &ExecutionConfig::for_genesis_transaction(network.clone()),
+                        &ExecutionConfig::for_genesis_transaction(network.clone())
+                            .with_execution_trace(execution_trace),
                         &tx.get_executable(btreeset!(AuthAddresses::system_role())),
                     );
                     LedgerTransactionReceipt::Standard(receipt)
@@ -94,7 +106,8 @@ pub fn execute_prepared_ledger_transaction<S: SubstateDatabase>(
                     native_vm: DefaultNativeVm::new(),
                 },
                 &CostingParameters::default(),
-                &ExecutionConfig::for_notarized_transaction(network.clone()),
+                &ExecutionConfig::for_notarized_transaction(network.clone())
+                    .with_execution_trace(execution_trace),
                 &NotarizedTransactionValidator::new(ValidationConfig::default(network.id))
                     .validate(tx.as_ref().clone())
                     .expect("Transaction validation failure")
@@ -110,7 +123,8 @@ pub fn execute_prepared_ledger_transaction<S: SubstateDatabase>(
                     native_vm: DefaultNativeVm::new(),
                 },
                 &CostingParameters::default(),
-                &ExecutionConfig::for_system_transaction(network.clone()),
+                &ExecutionConfig::for_system_transaction(network.clone())
+                    .with_execution_trace(execution_trace),
                 &tx.get_executable(),
             );
             LedgerTransactionReceipt::Standard(receipt)
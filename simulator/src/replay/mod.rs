@@ -1,5 +1,6 @@
 pub mod ledger_transaction;
 pub mod ledger_transaction_execution;
+pub mod parallel_execution;
 pub mod txn_reader;
 
 mod cmd_execute;
@@ -7,6 +8,7 @@ mod cmd_execute_in_memory;
 mod cmd_measure;
 mod cmd_prepare;
 mod cmd_sync;
+mod cmd_verify_tree;
 mod error;
 
 pub use cmd_execute::*;
@@ -14,6 +16,7 @@ pub use cmd_execute_in_memory::*;
 pub use cmd_measure::*;
 pub use cmd_prepare::*;
 pub use cmd_sync::*;
+pub use cmd_verify_tree::*;
 pub use error::*;
 
 use clap::{Parser, Subcommand};
@@ -33,6 +36,7 @@ pub enum Command {
     ExecuteInMemory(TxnExecuteInMemory),
     Sync(TxnSync),
     Measure(TxnMeasure),
+    VerifyTree(VerifyTree),
 }
 
 pub fn run() -> Result<(), Error> {
@@ -44,5 +48,6 @@ pub fn run() -> Result<(), Error> {
         Command::ExecuteInMemory(cmd) => cmd.run(),
         Command::Sync(cmd) => cmd.sync(),
         Command::Measure(cmd) => cmd.run(),
+        Command::VerifyTree(cmd) => cmd.run(),
     }
 }
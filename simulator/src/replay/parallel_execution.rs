@@ -0,0 +1,295 @@
+use super::ledger_transaction_execution::execute_ledger_transaction;
+use radix_engine::track::StateUpdates;
+use radix_engine::types::*;
+use radix_engine::vm::wasm::*;
+use radix_engine::vm::ScryptoVm;
+use radix_engine_interface::prelude::NetworkDefinition;
+use radix_engine_store_interface::db_key_mapper::SpreadPrefixKeyMapper;
+use radix_engine_store_interface::interface::*;
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+
+/// The state read by a transaction, as recorded by a [`ReadRecordingDatabase`].
+#[derive(Debug, Default)]
+pub struct ReadSet {
+    pub substates: IndexSet<DbSubstateKey>,
+    /// The partitions which were iterated over, rather than read substate by substate
+    pub listed_partitions: IndexSet<DbPartitionKey>,
+}
+
+/// A database wrapper which records all the state read through it.
+pub struct ReadRecordingDatabase<'s, S: SubstateDatabase> {
+    database: &'s S,
+    read_set: RefCell<ReadSet>,
+}
+
+impl<'s, S: SubstateDatabase> ReadRecordingDatabase<'s, S> {
+    pub fn new(database: &'s S) -> Self {
+        Self {
+            database,
+            read_set: RefCell::new(ReadSet::default()),
+        }
+    }
+
+    pub fn into_read_set(self) -> ReadSet {
+        self.read_set.into_inner()
+    }
+}
+
+impl<'s, S: SubstateDatabase> SubstateDatabase for ReadRecordingDatabase<'s, S> {
+    fn get_substate(
+        &self,
+        partition_key: &DbPartitionKey,
+        sort_key: &DbSortKey,
+    ) -> Option<DbSubstateValue> {
+        self.read_set
+            .borrow_mut()
+            .substates
+            .insert((partition_key.clone(), sort_key.clone()));
+        self.database.get_substate(partition_key, sort_key)
+    }
+
+    fn get_substates(&self, substate_keys: &[DbSubstateKey]) -> Vec<Option<DbSubstateValue>> {
+        self.read_set
+            .borrow_mut()
+            .substates
+            .extend(substate_keys.iter().cloned());
+        self.database.get_substates(substate_keys)
+    }
+
+    fn list_entries(
+        &self,
+        partition_key: &DbPartitionKey,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        self.read_set
+            .borrow_mut()
+            .listed_partitions
+            .insert(partition_key.clone());
+        self.database.list_entries(partition_key)
+    }
+
+    fn list_entries_from(
+        &self,
+        partition_key: &DbPartitionKey,
+        from_sort_key: Option<&DbSortKey>,
+    ) -> Box<dyn Iterator<Item = PartitionEntry> + '_> {
+        self.read_set
+            .borrow_mut()
+            .listed_partitions
+            .insert(partition_key.clone());
+        self.database
+            .list_entries_from(partition_key, from_sort_key)
+    }
+}
+
+/// The state written by a sequence of transactions.
+#[derive(Debug, Default)]
+pub struct WriteSet {
+    substates: IndexMap<DbPartitionKey, IndexSet<DbSortKey>>,
+    reset_partitions: IndexSet<DbPartitionKey>,
+}
+
+impl WriteSet {
+    pub fn add(&mut self, database_updates: &DatabaseUpdates) {
+        for (node_key, node_updates) in &database_updates.node_updates {
+            for (partition_num, partition_updates) in &node_updates.partition_updates {
+                let partition_key = DbPartitionKey {
+                    node_key: node_key.clone(),
+                    partition_num: *partition_num,
+                };
+                let sort_keys = self.substates.entry(partition_key.clone()).or_default();
+                match partition_updates {
+                    PartitionDatabaseUpdates::Delta { substate_updates } => {
+                        sort_keys.extend(substate_updates.keys().cloned());
+                    }
+                    PartitionDatabaseUpdates::Reset {
+                        new_substate_values,
+                    } => {
+                        sort_keys.extend(new_substate_values.keys().cloned());
+                        self.reset_partitions.insert(partition_key);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns whether any of the given reads may have observed a different value, had the
+    /// writes of this set been committed before them.
+    pub fn conflicts_with(&self, read_set: &ReadSet) -> bool {
+        read_set.listed_partitions.iter().any(|partition_key| {
+            self.substates.contains_key(partition_key)
+                || self.reset_partitions.contains(partition_key)
+        }) || read_set.substates.iter().any(|(partition_key, sort_key)| {
+            self.reset_partitions.contains(partition_key)
+                || self
+                    .substates
+                    .get(partition_key)
+                    .map_or(false, |sort_keys| sort_keys.contains(sort_key))
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BatchExecutionStats {
+    /// The number of transactions whose parallel execution result was committed
+    pub parallel: usize,
+    /// The number of transactions which had to be re-executed serially, due to a conflict with the
+    /// transactions committed before them
+    pub serial: usize,
+}
+
+/// Executes the given ledger transactions and commits them in order, with the same outcome as
+/// executing them one by one.
+///
+/// All the transactions are executed in parallel against the current state, and the results are
+/// committed in order. A transaction which has read state written by the transactions committed
+/// before it is re-executed serially, against the state they left behind, and its serial result
+/// is committed instead. Each transaction is thus executed at most twice.
+///
+/// The `on_commit` callback is called with the index of each transaction, right after it has been
+/// committed.
+pub fn execute_and_commit_in_parallel<S, F>(
+    database: &mut S,
+    scrypto_vm: &ScryptoVm<DefaultWasmEngine>,
+    network: &NetworkDefinition,
+    thread_count: usize,
+    tx_payloads: &[Vec<u8>],
+    mut on_commit: F,
+) -> BatchExecutionStats
+where
+    S: SubstateDatabase + CommittableSubstateDatabase + Sync,
+    F: FnMut(&S, usize),
+{
+    let mut stats = BatchExecutionStats::default();
+    let results = execute_in_parallel(&*database, scrypto_vm, network, thread_count, tx_payloads);
+
+    let mut write_set = WriteSet::default();
+    for (index, (state_updates, read_set)) in results.into_iter().enumerate() {
+        let state_updates = if write_set.conflicts_with(&read_set) {
+            stats.serial += 1;
+            execute_ledger_transaction(&*database, scrypto_vm, network, &tx_payloads[index])
+        } else {
+            stats.parallel += 1;
+            state_updates
+        };
+        let database_updates = state_updates.create_database_updates::<SpreadPrefixKeyMapper>();
+        write_set.add(&database_updates);
+        database.commit(&database_updates);
+        on_commit(database, index);
+    }
+    stats
+}
+
+/// Executes the given ledger transactions independently of each other, against the same state,
+/// returning their state updates and read sets in the same order as the given transactions.
+fn execute_in_parallel<S: SubstateDatabase + Sync>(
+    database: &S,
+    scrypto_vm: &ScryptoVm<DefaultWasmEngine>,
+    network: &NetworkDefinition,
+    thread_count: usize,
+    tx_payloads: &[Vec<u8>],
+) -> Vec<(StateUpdates, ReadSet)> {
+    let count = tx_payloads.len();
+    let thread_count = thread_count.max(1).min(count);
+    let next_index = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<(StateUpdates, ReadSet)>>> =
+        Mutex::new((0..count).map(|_| None).collect());
+
+    std::thread::scope(|scope| {
+        for _ in 0..thread_count {
+            scope.spawn(|| loop {
+                let index = next_index.fetch_add(1, Ordering::Relaxed);
+                if index >= count {
+                    break;
+                }
+                let recording_database = ReadRecordingDatabase::new(database);
+                let state_updates = execute_ledger_transaction(
+                    &recording_database,
+                    scrypto_vm,
+                    network,
+                    &tx_payloads[index],
+                );
+                let read_set = recording_database.into_read_set();
+                results.lock().expect("Results lock should not be poisoned")[index] =
+                    Some((state_updates, read_set));
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .expect("Results lock should not be poisoned")
+        .into_iter()
+        .map(|result| result.expect("Every transaction should have been executed"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn partition_key(partition_num: u8) -> DbPartitionKey {
+        DbPartitionKey {
+            node_key: vec![1; 30],
+            partition_num,
+        }
+    }
+
+    fn sort_key(byte: u8) -> DbSortKey {
+        DbSortKey(vec![byte])
+    }
+
+    fn write_set(partition_updates: PartitionDatabaseUpdates) -> WriteSet {
+        let mut database_updates = DatabaseUpdates::default();
+        database_updates
+            .node_updates
+            .entry(partition_key(0).node_key)
+            .or_default()
+            .partition_updates
+            .insert(0, partition_updates);
+        let mut write_set = WriteSet::default();
+        write_set.add(&database_updates);
+        write_set
+    }
+
+    #[test]
+    fn reads_of_written_substates_conflict() {
+        let write_set = write_set(PartitionDatabaseUpdates::Delta {
+            substate_updates: indexmap!(sort_key(1) => DatabaseUpdate::Delete),
+        });
+
+        let mut read_set = ReadSet::default();
+        read_set.substates.insert((partition_key(0), sort_key(2)));
+        read_set.substates.insert((partition_key(1), sort_key(1)));
+        assert!(!write_set.conflicts_with(&read_set));
+
+        read_set.substates.insert((partition_key(0), sort_key(1)));
+        assert!(write_set.conflicts_with(&read_set));
+    }
+
+    #[test]
+    fn listings_of_written_partitions_conflict() {
+        let write_set = write_set(PartitionDatabaseUpdates::Delta {
+            substate_updates: indexmap!(sort_key(1) => DatabaseUpdate::Set(vec![])),
+        });
+
+        let mut read_set = ReadSet::default();
+        read_set.listed_partitions.insert(partition_key(1));
+        assert!(!write_set.conflicts_with(&read_set));
+
+        read_set.listed_partitions.insert(partition_key(0));
+        assert!(write_set.conflicts_with(&read_set));
+    }
+
+    #[test]
+    fn reads_of_reset_partitions_conflict() {
+        let write_set = write_set(PartitionDatabaseUpdates::Reset {
+            new_substate_values: indexmap!(),
+        });
+
+        let mut read_set = ReadSet::default();
+        read_set.substates.insert((partition_key(0), sort_key(5)));
+        assert!(write_set.conflicts_with(&read_set));
+    }
+}
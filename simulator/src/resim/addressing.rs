@@ -10,9 +10,14 @@ use sbor::rust::fmt;
 use std::str::FromStr;
 use utils::ContextualDisplay;
 
+use crate::resim::resolve_alias;
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AddressError {
     InvalidAddress(String),
+    InvalidNonFungibleGlobalId(ParseNonFungibleGlobalIdError),
+    /// The aliases could not be read, with the reason
+    AliasesNotReadable(String),
 }
 
 #[cfg(not(feature = "alloc"))]
@@ -24,6 +29,10 @@ impl fmt::Display for AddressError {
     }
 }
 
+fn resolve_address_alias(address: &str) -> Result<String, AddressError> {
+    resolve_alias(address).map_err(|error| AddressError::AliasesNotReadable(format!("{:?}", error)))
+}
+
 #[derive(Clone)]
 pub struct SimulatorPackageAddress(pub PackageAddress);
 
@@ -43,6 +52,7 @@ impl FromStr for SimulatorPackageAddress {
     type Err = AddressError;
 
     fn from_str(address: &str) -> Result<Self, Self::Err> {
+        let address = &resolve_address_alias(address)?;
         PackageAddress::try_from_hex(address)
             .or(PackageAddress::try_from_bech32(
                 &AddressBech32Decoder::for_simulator(),
@@ -88,6 +98,7 @@ impl FromStr for SimulatorResourceAddress {
     type Err = AddressError;
 
     fn from_str(address: &str) -> Result<Self, Self::Err> {
+        let address = &resolve_address_alias(address)?;
         ResourceAddress::try_from_hex(address)
             .or(ResourceAddress::try_from_bech32(
                 &AddressBech32Decoder::for_simulator(),
@@ -133,6 +144,7 @@ impl FromStr for SimulatorComponentAddress {
     type Err = AddressError;
 
     fn from_str(address: &str) -> Result<Self, Self::Err> {
+        let address = &resolve_address_alias(address)?;
         ComponentAddress::try_from_hex(address)
             .or(ComponentAddress::try_from_bech32(
                 &AddressBech32Decoder::for_simulator(),
@@ -175,13 +187,14 @@ impl From<NonFungibleGlobalId> for SimulatorNonFungibleGlobalId {
 }
 
 impl FromStr for SimulatorNonFungibleGlobalId {
-    type Err = ParseNonFungibleGlobalIdError;
+    type Err = AddressError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let global_id = NonFungibleGlobalId::try_from_canonical_string(
             &AddressBech32Decoder::for_simulator(),
-            s,
-        )?;
+            &resolve_address_alias(s)?,
+        )
+        .map_err(AddressError::InvalidNonFungibleGlobalId)?;
         Ok(Self(global_id))
     }
 }
@@ -0,0 +1,116 @@
+use clap::{Parser, Subcommand};
+use colored::*;
+use radix_engine::types::*;
+
+use crate::resim::*;
+
+/// Manage named aliases for addresses, usable anywhere an address is expected
+#[derive(Parser, Debug)]
+pub struct Alias {
+    #[clap(subcommand)]
+    pub command: AliasCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum AliasCommand {
+    Add(AliasAdd),
+    Remove(AliasRemove),
+    List(AliasList),
+}
+
+/// Add an alias for an address, replacing any existing alias of the same name
+#[derive(Parser, Debug)]
+pub struct AliasAdd {
+    /// The alias name
+    pub name: String,
+
+    /// The address of a package, component or resource manager
+    pub address: String,
+}
+
+/// Remove an alias
+#[derive(Parser, Debug)]
+pub struct AliasRemove {
+    /// The alias name
+    pub name: String,
+}
+
+/// List all aliases
+#[derive(Parser, Debug)]
+pub struct AliasList {}
+
+impl Alias {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        match &self.command {
+            AliasCommand::Add(cmd) => cmd.run(out),
+            AliasCommand::Remove(cmd) => cmd.run(out),
+            AliasCommand::List(cmd) => cmd.run(out),
+        }
+    }
+}
+
+impl AliasAdd {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        if self.name.is_empty()
+            || self
+                .name
+                .contains(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == '_'))
+        {
+            return Err(Error::InvalidAliasName(self.name.clone()));
+        }
+
+        // Aliases may point at other aliases, but are always stored fully resolved
+        let address = resolve_alias(&self.address)?;
+        let decoder = AddressBech32Decoder::for_simulator();
+        if GlobalAddress::try_from_bech32(&decoder, &address).is_none() {
+            return Err(Error::InvalidId(self.address.clone()));
+        }
+
+        let mut aliases = get_aliases()?;
+        aliases.insert(self.name.clone(), address.clone());
+        set_aliases(&aliases)?;
+
+        writeln!(out, "Alias {} added for {}", self.name, address).map_err(Error::IOError)?;
+        Ok(())
+    }
+}
+
+impl AliasRemove {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let mut aliases = get_aliases()?;
+        if aliases.shift_remove(&self.name).is_none() {
+            return Err(Error::AliasNotFound(self.name.clone()));
+        }
+        set_aliases(&aliases)?;
+
+        writeln!(out, "Alias {} removed", self.name).map_err(Error::IOError)?;
+        Ok(())
+    }
+}
+
+impl AliasList {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        for (name, address) in &get_aliases()? {
+            writeln!(out, "{}: {}", name.green().bold(), address).map_err(Error::IOError)?;
+        }
+        Ok(())
+    }
+}
+
+/// Replaces an alias at the start of the input with the address it stands for.
+///
+/// Only the part before the first `:` is considered, so that resource specifiers such as
+/// `mytoken:10` or `mybadge:#1#` resolve too. Inputs which are not aliases are returned unchanged.
+pub fn resolve_alias(input: &str) -> Result<String, Error> {
+    let aliases = get_aliases()?;
+    let (head, tail) = match input.split_once(':') {
+        Some((head, tail)) => (head, Some(tail)),
+        None => (input, None),
+    };
+    let resolved = match (aliases.get(head.trim()), tail) {
+        (Some(address), Some(tail)) => format!("{}:{}", address, tail),
+        (Some(address), None) => address.clone(),
+        (None, _) => input.to_string(),
+    };
+    Ok(resolved)
+}
@@ -17,7 +17,9 @@ pub struct CallFunction {
     /// The function name
     pub function_name: String,
 
-    /// The call arguments, such as "5", "hello", "<resource_address>:<amount>" and "<resource_address>:<nf_local_id1>,<nf_local_id2>"
+    /// The call arguments, such as "5", "hello", "<resource_address>:<amount>" and "<resource_address>:<nf_local_id1>,<nf_local_id2>".
+    /// Tuples, enums and arrays are parsed against the schema, such as "(5,hello)", "Some(5)" and "[1,2,3]"
+    /// Their elements may be double quoted to contain commas or brackets, such as "[\"hello, world\"]"
     pub arguments: Vec<String>,
 
     /// The proofs to add to the auth zone, in form of "<resource_address>:<amount>" or "<resource_address>:<nf_local_id1>,<nf_local_id2>"
@@ -46,7 +48,12 @@ impl CallFunction {
         let address_bech32_decoder = AddressBech32Decoder::for_simulator();
 
         let default_account = get_default_account()?;
-        let proofs = self.proofs.clone().unwrap_or_default();
+        let proofs = self
+            .proofs
+            .iter()
+            .flatten()
+            .map(|proof| resolve_alias(proof))
+            .collect::<Result<Vec<_>, _>>()?;
 
         let mut builder = ManifestBuilder::new();
         builder = builder.lock_fee_from_faucet();
@@ -66,7 +73,10 @@ impl CallFunction {
                 self.package_address.0,
                 self.blueprint_name.clone(),
                 self.function_name.clone(),
-                self.arguments.clone(),
+                self.arguments
+                    .iter()
+                    .map(|argument| resolve_alias(argument))
+                    .collect::<Result<_, _>>()?,
                 Some(default_account),
             )?
             .try_deposit_entire_worktop_or_refund(default_account, None)
@@ -0,0 +1,110 @@
+use clap::{Parser, Subcommand};
+use radix_engine::types::*;
+use radix_engine_stores::rocks_db::RocksdbSubstateStore;
+use std::fs::remove_dir_all;
+
+use crate::resim::*;
+
+/// Create or restore a named checkpoint of the ledger
+#[derive(Parser, Debug)]
+pub struct Checkpoint {
+    #[clap(subcommand)]
+    pub command: CheckpointCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum CheckpointCommand {
+    Create(CheckpointCreate),
+    Restore(CheckpointRestore),
+}
+
+/// Save the current ledger, simulator configs and aliases as a named checkpoint
+#[derive(Parser, Debug)]
+pub struct CheckpointCreate {
+    /// The checkpoint name
+    pub name: String,
+}
+
+/// Roll the ledger, the simulator configs and the aliases back to a named checkpoint
+#[derive(Parser, Debug)]
+pub struct CheckpointRestore {
+    /// The checkpoint name
+    pub name: String,
+}
+
+impl Checkpoint {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        match &self.command {
+            CheckpointCommand::Create(cmd) => cmd.run(out),
+            CheckpointCommand::Restore(cmd) => cmd.run(out),
+        }
+    }
+}
+
+impl CheckpointCreate {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let checkpoint_dir = get_checkpoint_dir(&self.name)?;
+        if checkpoint_dir.exists() {
+            return Err(Error::CheckpointAlreadyExists(self.name.clone()));
+        }
+        let checkpoints_dir = get_checkpoints_dir()?;
+        fs::create_dir_all(&checkpoints_dir)
+            .map_err(|err| Error::IOErrorAtPath(err, checkpoints_dir))?;
+
+        let scrypto_vm = ScryptoVm::<DefaultWasmEngine>::default();
+        let native_vm = DefaultNativeVm::new();
+        let vm = Vm::new(&scrypto_vm, native_vm);
+        let mut substate_db = RocksdbSubstateStore::standard(get_data_dir()?);
+        Bootstrapper::new(NetworkDefinition::simulator(), &mut substate_db, vm, false)
+            .bootstrap_test_default();
+        substate_db
+            .create_checkpoint(checkpoint_dir.clone())
+            .map_err(Error::DatabaseError)?;
+
+        // The configs (e.g. the nonce) and the aliases are part of the simulator state too
+        for path in [get_configs_path()?, get_aliases_path()?] {
+            if path.exists() {
+                let checkpoint_path = checkpoint_dir.join(path.file_name().unwrap());
+                fs::copy(&path, &checkpoint_path)
+                    .map_err(|err| Error::IOErrorAtPath(err, checkpoint_path))?;
+            }
+        }
+
+        writeln!(out, "Checkpoint {} created", self.name).map_err(Error::IOError)?;
+        Ok(())
+    }
+}
+
+impl CheckpointRestore {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let checkpoint_dir = get_checkpoint_dir(&self.name)?;
+        if !checkpoint_dir.is_dir() {
+            return Err(Error::CheckpointNotFound(self.name.clone()));
+        }
+
+        let data_dir = get_data_dir()?;
+        remove_dir_all(&data_dir).map_err(|err| Error::IOErrorAtPath(err, data_dir.clone()))?;
+        fs::create_dir_all(&data_dir).map_err(|err| Error::IOErrorAtPath(err, data_dir.clone()))?;
+
+        // Copy (rather than move) the checkpoint, so that it can be restored again later on
+        for entry in fs::read_dir(&checkpoint_dir)
+            .map_err(|err| Error::IOErrorAtPath(err, checkpoint_dir.clone()))?
+        {
+            let entry = entry.map_err(Error::IOError)?;
+            fs::copy(entry.path(), data_dir.join(entry.file_name()))
+                .map_err(|err| Error::IOErrorAtPath(err, entry.path()))?;
+        }
+
+        writeln!(out, "Checkpoint {} restored", self.name).map_err(Error::IOError)?;
+        Ok(())
+    }
+}
+
+fn get_checkpoint_dir(name: &str) -> Result<PathBuf, Error> {
+    if name.is_empty()
+        || name.contains(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == '_'))
+    {
+        return Err(Error::InvalidCheckpointName(name.to_string()));
+    }
+    Ok(get_checkpoints_dir()?.join(name))
+}
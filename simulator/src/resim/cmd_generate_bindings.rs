@@ -0,0 +1,57 @@
+use clap::Parser;
+use radix_engine::types::*;
+
+use crate::resim::*;
+use crate::scrypto_bindgen::{generate_manifest_bindings, BindingsLanguage};
+
+/// Generate helpers for building manifests which call the blueprints of a package
+#[derive(Parser, Debug)]
+pub struct GenerateBindings {
+    /// The package ID
+    pub package_address: SimulatorPackageAddress,
+
+    /// The language of the bindings, `rust` or `ts`
+    #[clap(short, long, default_value = "rust")]
+    pub language: BindingsLanguage,
+
+    /// The output file, instead of the standard output
+    #[clap(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+impl GenerateBindings {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let scrypto_vm = ScryptoVm::<DefaultWasmEngine>::default();
+        let native_vm = DefaultNativeVm::new();
+        let vm = Vm::new(&scrypto_vm, native_vm);
+        let mut substate_db = RocksdbSubstateStore::standard(get_data_dir()?);
+        Bootstrapper::new(NetworkDefinition::simulator(), &mut substate_db, vm, false)
+            .bootstrap_test_default();
+
+        let package_address = self.package_address.0;
+        if SystemDatabaseReader::new(&substate_db)
+            .get_package_definition(package_address)
+            .is_empty()
+        {
+            return Err(Error::PackageNotFound(package_address));
+        }
+
+        let bindings = generate_manifest_bindings(
+            &substate_db,
+            package_address,
+            self.language,
+            &AddressBech32Encoder::for_simulator(),
+        )
+        .map_err(Error::BindingsGenerationError)?;
+
+        match &self.output {
+            Some(output) => {
+                fs::write(output, bindings)
+                    .map_err(|err| Error::IOErrorAtPath(err, output.clone()))?;
+                writeln!(out, "Bindings generated to {}", output.to_str().unwrap())
+                    .map_err(Error::IOError)
+            }
+            None => writeln!(out, "{}", bindings).map_err(Error::IOError),
+        }
+    }
+}
@@ -39,7 +39,12 @@ impl Mint {
         let address_bech32_decoder = AddressBech32Decoder::for_simulator();
 
         let default_account = get_default_account()?;
-        let proofs = self.proofs.clone().unwrap_or_default();
+        let proofs = self
+            .proofs
+            .iter()
+            .flatten()
+            .map(|proof| resolve_alias(proof))
+            .collect::<Result<Vec<_>, _>>()?;
 
         let mut builder = ManifestBuilder::new().lock_fee_from_faucet();
         for resource_specifier in proofs {
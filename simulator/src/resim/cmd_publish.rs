@@ -48,16 +48,16 @@ pub struct Publish {
 
     /// When passed, this argument disables wasm-opt from running on the built wasm.
     #[clap(long)]
-    disable_wasm_opt: bool,
+    pub disable_wasm_opt: bool,
 
     /// The max log level, such as ERROR, WARN, INFO, DEBUG and TRACE.
     /// The default is INFO.
     #[clap(long)]
-    log_level: Option<Level>,
+    pub log_level: Option<Level>,
 }
 
 impl Publish {
-    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<Option<PackageAddress>, Error> {
         // Load wasm code
         let (code_path, definition_path) = if self.path.extension() != Some(OsStr::new("wasm")) {
             build_package(
@@ -81,6 +81,21 @@ impl Publish {
         )
         .map_err(Error::SborDecodeError)?;
 
+        // Report every issue with the code up front, rather than just the first one found
+        let report = WasmValidator::default()
+            .validate_with_report(&code, package_definition.blueprints.values());
+        if !report.is_valid() {
+            for feature in &report.unsupported_features {
+                writeln!(out, "{} {}", "Unsupported WASM feature:".red(), feature)
+                    .map_err(Error::IOError)?;
+            }
+            for issue in &report.issues {
+                writeln!(out, "{} {:?}", "Invalid package:".red(), issue)
+                    .map_err(Error::IOError)?;
+            }
+            return Err(Error::InvalidPackage(report.issues[0].clone()));
+        }
+
         if let Some(package_address) = self.package_address.clone() {
             let scrypto_vm = ScryptoVm::<DefaultWasmEngine>::default();
             let native_vm = DefaultNativeVm::new();
@@ -311,6 +326,8 @@ impl Publish {
             substate_db.commit(&DatabaseUpdates::from_delta_maps(database_updates));
 
             writeln!(out, "Package updated!").map_err(Error::IOError)?;
+
+            Ok(Some(package_address.0))
         } else {
             let owner_badge_non_fungible_global_id = self
                 .owner_badge
@@ -337,18 +354,21 @@ impl Publish {
                 out,
             )?;
             if let Some(receipt) = receipt {
+                let package_address = receipt.expect_commit(true).new_package_addresses()[0];
                 writeln!(
                     out,
                     "Success! New Package: {}",
-                    receipt.expect_commit(true).new_package_addresses()[0]
+                    package_address
                         .display(&AddressBech32Encoder::for_simulator())
                         .to_string()
                         .green()
                 )
                 .map_err(Error::IOError)?;
+
+                Ok(Some(package_address))
+            } else {
+                Ok(None)
             }
         }
-
-        Ok(())
     }
 }
@@ -28,6 +28,10 @@ pub struct Run {
     /// Turn on tracing
     #[clap(short, long)]
     pub trace: bool,
+
+    /// Print a typed diff of the state changes, e.g. balance and metadata changes
+    #[clap(long)]
+    pub state_diff: bool,
 }
 
 impl Run {
@@ -62,13 +66,12 @@ impl Run {
         validate_call_arguments_to_native_components(&compiled_manifest.instructions)
             .map_err(Error::InstructionSchemaValidationError)?;
 
-        handle_manifest(
+        execute_manifest(
             compiled_manifest,
             &self.signing_keys,
-            &self.network,
-            &None,
             self.trace,
             true,
+            self.state_diff,
             out,
         )
         .map(|_| ())
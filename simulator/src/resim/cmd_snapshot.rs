@@ -0,0 +1,158 @@
+use clap::{Parser, Subcommand};
+use radix_engine::types::*;
+use radix_engine_store_interface::interface::{
+    CommittableSubstateDatabase, DatabaseUpdates, ListableSubstateDatabase,
+    PartitionDatabaseUpdates,
+};
+use radix_engine_stores::rocks_db::RocksdbSubstateStore;
+use std::fs::remove_dir_all;
+
+use crate::resim::*;
+
+/// Export or import a snapshot of the ledger
+#[derive(Parser, Debug)]
+pub struct Snapshot {
+    #[clap(subcommand)]
+    pub command: SnapshotCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum SnapshotCommand {
+    Export(SnapshotExport),
+    Import(SnapshotImport),
+}
+
+/// Export the ledger, the simulator configs and the aliases into a snapshot file
+#[derive(Parser, Debug)]
+pub struct SnapshotExport {
+    /// The output file
+    pub file: PathBuf,
+}
+
+/// Replace the ledger, the simulator configs and the aliases with the ones from a snapshot file
+#[derive(Parser, Debug)]
+pub struct SnapshotImport {
+    /// The snapshot file
+    pub file: PathBuf,
+}
+
+define_versioned! {
+    #[derive(Debug, Clone, ScryptoSbor)]
+    pub enum VersionedLedgerSnapshot {
+        previous_versions: [
+            1 => LedgerSnapshotV1: { updates_to: 2 },
+        ],
+        latest_version: {
+            2 => LedgerSnapshot = LedgerSnapshotV2,
+        },
+    }
+}
+
+#[derive(Debug, Clone, ScryptoSbor)]
+pub struct LedgerSnapshotV1 {
+    pub configs: Configs,
+    /// The whole substate store, as a reset of each of its partitions.
+    pub database_updates: DatabaseUpdates,
+}
+
+#[derive(Debug, Clone, ScryptoSbor)]
+pub struct LedgerSnapshotV2 {
+    pub configs: Configs,
+    pub aliases: IndexMap<String, String>,
+    /// The whole substate store, as a reset of each of its partitions.
+    pub database_updates: DatabaseUpdates,
+}
+
+impl From<LedgerSnapshotV1> for LedgerSnapshotV2 {
+    fn from(value: LedgerSnapshotV1) -> Self {
+        Self {
+            configs: value.configs,
+            // Snapshots taken before aliases existed have none
+            aliases: index_map_new(),
+            database_updates: value.database_updates,
+        }
+    }
+}
+
+impl Snapshot {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        match &self.command {
+            SnapshotCommand::Export(cmd) => cmd.run(out),
+            SnapshotCommand::Import(cmd) => cmd.run(out),
+        }
+    }
+}
+
+impl SnapshotExport {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let scrypto_vm = ScryptoVm::<DefaultWasmEngine>::default();
+        let native_vm = DefaultNativeVm::new();
+        let vm = Vm::new(&scrypto_vm, native_vm);
+        let mut substate_db = RocksdbSubstateStore::standard(get_data_dir()?);
+        Bootstrapper::new(NetworkDefinition::simulator(), &mut substate_db, vm, false)
+            .bootstrap_test_default();
+
+        let mut database_updates = DatabaseUpdates::default();
+        for partition_key in substate_db.list_partition_keys() {
+            let new_substate_values = substate_db.list_entries(&partition_key).collect();
+            database_updates
+                .node_updates
+                .entry(partition_key.node_key)
+                .or_default()
+                .partition_updates
+                .insert(
+                    partition_key.partition_num,
+                    PartitionDatabaseUpdates::Reset {
+                        new_substate_values,
+                    },
+                );
+        }
+
+        let snapshot = VersionedLedgerSnapshot::new_latest(LedgerSnapshotV2 {
+            configs: get_configs()?,
+            aliases: get_aliases()?,
+            database_updates,
+        });
+        fs::write(
+            &self.file,
+            scrypto_encode(&snapshot).map_err(Error::SborEncodeError)?,
+        )
+        .map_err(|err| Error::IOErrorAtPath(err, self.file.clone()))?;
+
+        writeln!(
+            out,
+            "Ledger snapshot exported to {}",
+            self.file.to_str().unwrap()
+        )
+        .map_err(Error::IOError)?;
+        Ok(())
+    }
+}
+
+impl SnapshotImport {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let bytes =
+            fs::read(&self.file).map_err(|err| Error::IOErrorAtPath(err, self.file.clone()))?;
+        let snapshot: LedgerSnapshot = scrypto_decode::<VersionedLedgerSnapshot>(&bytes)
+            .map_err(Error::SborDecodeError)?
+            .into_latest();
+
+        // Only the data directory is replaced - checkpoints are kept outside of it
+        let dir = get_data_dir()?;
+        remove_dir_all(&dir).map_err(|err| Error::IOErrorAtPath(err, dir.clone()))?;
+
+        let mut substate_db = RocksdbSubstateStore::standard(get_data_dir()?);
+        substate_db.commit(&snapshot.database_updates);
+        drop(substate_db);
+        set_configs(&snapshot.configs)?;
+        set_aliases(&snapshot.aliases)?;
+
+        writeln!(
+            out,
+            "Ledger snapshot imported from {}",
+            self.file.to_str().unwrap()
+        )
+        .map_err(Error::IOError)?;
+        Ok(())
+    }
+}
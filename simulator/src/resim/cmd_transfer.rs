@@ -39,7 +39,12 @@ impl Transfer {
         let address_bech32_decoder = AddressBech32Decoder::for_simulator();
 
         let default_account = get_default_account()?;
-        let proofs = self.proofs.clone().unwrap_or_default();
+        let proofs = self
+            .proofs
+            .iter()
+            .flatten()
+            .map(|proof| resolve_alias(proof))
+            .collect::<Result<Vec<_>, _>>()?;
 
         let mut builder = ManifestBuilder::new().lock_fee_from_faucet();
         for resource_specifier in proofs {
@@ -52,9 +57,11 @@ impl Transfer {
             .map_err(Error::FailedToBuildArguments)?
         }
 
-        let resource_specifier =
-            parse_resource_specifier(&self.resource_specifier, &address_bech32_decoder)
-                .map_err(|_| Error::InvalidResourceSpecifier(self.resource_specifier.clone()))?;
+        let resource_specifier = parse_resource_specifier(
+            &resolve_alias(&self.resource_specifier)?,
+            &address_bech32_decoder,
+        )
+        .map_err(|_| Error::InvalidResourceSpecifier(self.resource_specifier.clone()))?;
 
         builder = match resource_specifier {
             ResourceSpecifier::Amount(amount, resource_address) => {
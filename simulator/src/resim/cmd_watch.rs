@@ -0,0 +1,172 @@
+use clap::Parser;
+use colored::*;
+use radix_engine::types::*;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+use utils::ContextualDisplay;
+
+use crate::resim::*;
+
+/// The environment variable holding the watched package address, for use in the manifest
+/// run after every publish (as `${package}`).
+pub const ENV_WATCHED_PACKAGE: &'static str = "package";
+
+/// Rebuild and republish a package whenever its source changes
+#[derive(Parser, Debug)]
+pub struct Watch {
+    /// The path to a Scrypto package
+    pub path: PathBuf,
+
+    /// The address of an existing package to overwrite; a new package is published if missing
+    #[clap(long)]
+    pub package_address: Option<SimulatorPackageAddress>,
+
+    /// The owner badge (hex value), used if a new package is published
+    #[clap(long)]
+    pub owner_badge: Option<SimulatorNonFungibleGlobalId>,
+
+    /// The path to a transaction manifest to run after every publish, which can refer to the
+    /// package address as `${package}`
+    #[clap(long)]
+    pub run_manifest: Option<PathBuf>,
+
+    /// The private keys used for signing the manifest, separated by comma
+    #[clap(short, long)]
+    pub signing_keys: Option<String>,
+
+    /// How often to check the source for changes, in milliseconds
+    #[clap(long, default_value = "1000")]
+    pub interval: u64,
+
+    /// When passed, this argument disables wasm-opt from running on the built wasm.
+    #[clap(long)]
+    pub disable_wasm_opt: bool,
+
+    /// The max log level, such as ERROR, WARN, INFO, DEBUG and TRACE.
+    /// The default is INFO.
+    #[clap(long)]
+    pub log_level: Option<Level>,
+}
+
+impl Watch {
+    pub fn run<O: std::io::Write>(&self, out: &mut O) -> Result<(), Error> {
+        let mut package_address = self.package_address.clone().map(|address| address.0);
+        let mut last_snapshot = None;
+
+        loop {
+            let snapshot = Self::snapshot_sources(&self.path)?;
+            if last_snapshot.as_ref() != Some(&snapshot) {
+                last_snapshot = Some(snapshot);
+
+                // A broken build or a failing manifest shouldn't stop the watch
+                match self.publish_and_run(package_address, out) {
+                    Ok(published) => package_address = published.or(package_address),
+                    Err(error) => {
+                        writeln!(out, "{} {:?}", "Error:".red(), error).map_err(Error::IOError)?
+                    }
+                }
+                writeln!(out, "Watching {} for changes...", self.path.display())
+                    .map_err(Error::IOError)?;
+            }
+
+            thread::sleep(Duration::from_millis(self.interval));
+        }
+    }
+
+    fn publish_and_run<O: std::io::Write>(
+        &self,
+        package_address: Option<PackageAddress>,
+        out: &mut O,
+    ) -> Result<Option<PackageAddress>, Error> {
+        let package_address = Publish {
+            path: self.path.clone(),
+            owner_badge: self.owner_badge.clone(),
+            package_address: package_address.map(SimulatorPackageAddress),
+            network: None,
+            manifest: None,
+            trace: false,
+            disable_wasm_opt: self.disable_wasm_opt,
+            log_level: self.log_level,
+        }
+        .run(out)?;
+
+        if let (Some(manifest_path), Some(package_address)) = (&self.run_manifest, package_address)
+        {
+            env::set_var(
+                ENV_WATCHED_PACKAGE,
+                package_address
+                    .display(&AddressBech32Encoder::for_simulator())
+                    .to_string(),
+            );
+            Run {
+                path: manifest_path.clone(),
+                network: None,
+                blobs: None,
+                signing_keys: self.signing_keys.clone(),
+                trace: false,
+                state_diff: false,
+            }
+            .run(out)?;
+        }
+
+        Ok(package_address)
+    }
+
+    /// The modification times of all the files of the package, other than build outputs.
+    fn snapshot_sources(path: &Path) -> Result<BTreeMap<PathBuf, SystemTime>, Error> {
+        let mut snapshot = BTreeMap::new();
+        Self::snapshot_dir(path, &mut snapshot)?;
+        Ok(snapshot)
+    }
+
+    fn snapshot_dir(dir: &Path, snapshot: &mut BTreeMap<PathBuf, SystemTime>) -> Result<(), Error> {
+        let entries =
+            fs::read_dir(dir).map_err(|err| Error::IOErrorAtPath(err, dir.to_path_buf()))?;
+        for entry in entries {
+            let entry = entry.map_err(Error::IOError)?;
+            let path = entry.path();
+            let is_hidden = entry.file_name().to_string_lossy().starts_with('.');
+            if is_hidden || entry.file_name() == "target" {
+                continue;
+            }
+
+            let metadata = entry
+                .metadata()
+                .map_err(|err| Error::IOErrorAtPath(err, path.clone()))?;
+            if metadata.is_dir() {
+                Self::snapshot_dir(&path, snapshot)?;
+            } else {
+                let modified = metadata
+                    .modified()
+                    .map_err(|err| Error::IOErrorAtPath(err, path.clone()))?;
+                snapshot.insert(path, modified);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_sources_ignores_build_outputs() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("src")).unwrap();
+        fs::create_dir_all(dir.path().join("target")).unwrap();
+        fs::write(dir.path().join("src").join("lib.rs"), "").unwrap();
+        fs::write(dir.path().join("target").join("out.wasm"), "").unwrap();
+
+        let snapshot = Watch::snapshot_sources(dir.path()).unwrap();
+
+        assert_eq!(
+            snapshot.keys().cloned().collect::<Vec<_>>(),
+            vec![dir.path().join("src").join("lib.rs")]
+        );
+    }
+}
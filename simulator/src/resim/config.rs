@@ -36,6 +36,26 @@ pub fn get_configs_path() -> Result<PathBuf, Error> {
     Ok(path.with_extension("sbor"))
 }
 
+/// The aliases are kept in their own file, rather than in the configs, so that the configs written
+/// by earlier versions of resim still decode.
+pub fn get_aliases_path() -> Result<PathBuf, Error> {
+    let mut path = get_data_dir()?;
+    path.push("aliases");
+    Ok(path.with_extension("sbor"))
+}
+
+/// The checkpoints are kept next to (rather than in) the data directory, so that they survive
+/// `resim reset` and snapshot imports, which clear the data directory.
+pub fn get_checkpoints_dir() -> Result<PathBuf, Error> {
+    let data_dir = get_data_dir()?;
+    let mut dir_name = data_dir
+        .file_name()
+        .map(|name| name.to_os_string())
+        .unwrap_or_default();
+    dir_name.push(".checkpoints");
+    Ok(data_dir.with_file_name(dir_name))
+}
+
 pub fn get_configs() -> Result<Configs, Error> {
     let path = get_configs_path()?;
     if path.exists() {
@@ -50,6 +70,21 @@ pub fn set_configs(configs: &Configs) -> Result<(), Error> {
     fs::write(get_configs_path()?, scrypto_encode(configs).unwrap()).map_err(Error::IOError)
 }
 
+/// Returns the aliases for addresses, keyed by alias name.
+pub fn get_aliases() -> Result<IndexMap<String, String>, Error> {
+    let path = get_aliases_path()?;
+    if path.exists() {
+        scrypto_decode(&fs::read(path).map_err(Error::IOError)?.as_ref())
+            .map_err(Error::SborDecodeError)
+    } else {
+        Ok(index_map_new())
+    }
+}
+
+pub fn set_aliases(aliases: &IndexMap<String, String>) -> Result<(), Error> {
+    fs::write(get_aliases_path()?, scrypto_encode(aliases).unwrap()).map_err(Error::IOError)
+}
+
 pub fn get_default_account() -> Result<ComponentAddress, Error> {
     get_configs()?
         .default_account
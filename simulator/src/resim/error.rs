@@ -2,6 +2,7 @@ use std::io;
 use std::path::PathBuf;
 
 use radix_engine::errors::{RejectionReason, RuntimeError};
+use radix_engine::system::system_db_reader::SystemReaderError;
 use radix_engine::transaction::AbortReason;
 use radix_engine::types::{ComponentAddress, NodeId, PackageAddress};
 use radix_engine::utils::ExtractSchemaError;
@@ -35,6 +36,18 @@ pub enum Error {
 
     IOErrorAtPath(io::Error, PathBuf),
 
+    DatabaseError(rocksdb::Error),
+
+    CheckpointAlreadyExists(String),
+
+    CheckpointNotFound(String),
+
+    InvalidCheckpointName(String),
+
+    InvalidAliasName(String),
+
+    AliasNotFound(String),
+
     SborDecodeError(DecodeError),
 
     SborEncodeError(EncodeError),
@@ -59,6 +72,8 @@ pub enum Error {
 
     LedgerDumpError(EntityDumpError),
 
+    SystemReaderError(SystemReaderError),
+
     CompileError(transaction::manifest::CompileError),
 
     DecompileError(transaction::manifest::DecompileError),
@@ -80,4 +95,6 @@ pub enum Error {
     InvalidResourceSpecifier(String),
 
     RemoteGenericSubstitutionNotSupported,
+
+    BindingsGenerationError(crate::scrypto_bindgen::SchemaError),
 }
@@ -1,7 +1,10 @@
 mod addressing;
+mod cmd_alias;
 mod cmd_call_function;
 mod cmd_call_method;
+mod cmd_checkpoint;
 mod cmd_export_package_definition;
+mod cmd_generate_bindings;
 mod cmd_generate_key_pair;
 mod cmd_mint;
 mod cmd_new_account;
@@ -19,15 +22,20 @@ mod cmd_set_default_account;
 mod cmd_show;
 mod cmd_show_configs;
 mod cmd_show_ledger;
+mod cmd_snapshot;
 mod cmd_transfer;
+mod cmd_watch;
 mod config;
 mod dumper;
 mod error;
 
 pub use addressing::*;
+pub use cmd_alias::*;
 pub use cmd_call_function::*;
 pub use cmd_call_method::*;
+pub use cmd_checkpoint::*;
 pub use cmd_export_package_definition::*;
+pub use cmd_generate_bindings::*;
 pub use cmd_generate_key_pair::*;
 pub use cmd_mint::*;
 pub use cmd_new_account::*;
@@ -45,7 +53,9 @@ pub use cmd_set_default_account::*;
 pub use cmd_show::*;
 pub use cmd_show_configs::*;
 pub use cmd_show_ledger::*;
+pub use cmd_snapshot::*;
 pub use cmd_transfer::*;
+pub use cmd_watch::*;
 pub use config::*;
 pub use dumper::*;
 pub use error::*;
@@ -68,7 +78,9 @@ use radix_engine::transaction::TransactionOutcome;
 use radix_engine::transaction::TransactionReceipt;
 use radix_engine::transaction::TransactionReceiptDisplayContextBuilder;
 use radix_engine::transaction::TransactionResult;
-use radix_engine::transaction::{execute_and_commit_transaction, CostingParameters};
+use radix_engine::transaction::{
+    execute_and_commit_transaction, execute_transaction, CostingParameters, StateDiff,
+};
 use radix_engine::types::*;
 use radix_engine::vm::wasm::*;
 use radix_engine::vm::{DefaultNativeVm, ScryptoVm, Vm};
@@ -80,7 +92,8 @@ use radix_engine_interface::blueprints::resource::FromPublicKey;
 use radix_engine_interface::crypto::hash;
 use radix_engine_interface::network::NetworkDefinition;
 use radix_engine_queries::typed_substate_layout::*;
-use radix_engine_store_interface::interface::SubstateDatabase;
+use radix_engine_store_interface::db_key_mapper::SpreadPrefixKeyMapper;
+use radix_engine_store_interface::interface::{CommittableSubstateDatabase, SubstateDatabase};
 use radix_engine_stores::rocks_db::RocksdbSubstateStore;
 use std::env;
 use std::fs;
@@ -109,9 +122,12 @@ impl ResimCli {
 
 #[derive(Subcommand, Debug)]
 pub enum Command {
+    Alias(Alias),
     CallFunction(CallFunction),
     CallMethod(CallMethod),
+    Checkpoint(Checkpoint),
     ExportPackageDefinition(ExportPackageDefinition),
+    GenerateBindings(GenerateBindings),
     GenerateKeyPair(GenerateKeyPair),
     Mint(crate::resim::cmd_mint::Mint),
     NewAccount(NewAccount),
@@ -129,7 +145,9 @@ pub enum Command {
     ShowConfigs(ShowConfigs),
     ShowLedger(ShowLedger),
     Show(Show),
+    Snapshot(Snapshot),
     Transfer(Transfer),
+    Watch(Watch),
 }
 
 pub fn run() -> Result<(), Error> {
@@ -138,9 +156,12 @@ pub fn run() -> Result<(), Error> {
     let mut out = std::io::stdout();
 
     match cli.command {
+        Command::Alias(cmd) => cmd.run(&mut out),
         Command::CallFunction(cmd) => cmd.run(&mut out),
         Command::CallMethod(cmd) => cmd.run(&mut out),
+        Command::Checkpoint(cmd) => cmd.run(&mut out),
         Command::ExportPackageDefinition(cmd) => cmd.run(&mut out),
+        Command::GenerateBindings(cmd) => cmd.run(&mut out),
         Command::GenerateKeyPair(cmd) => cmd.run(&mut out),
         Command::Mint(cmd) => cmd.run(&mut out),
         Command::NewAccount(cmd) => cmd.run(&mut out),
@@ -149,7 +170,7 @@ pub fn run() -> Result<(), Error> {
         Command::NewBadgeMutable(cmd) => cmd.run(&mut out),
         Command::NewTokenFixed(cmd) => cmd.run(&mut out),
         Command::NewTokenMutable(cmd) => cmd.run(&mut out),
-        Command::Publish(cmd) => cmd.run(&mut out),
+        Command::Publish(cmd) => cmd.run(&mut out).map(|_| ()),
         Command::Reset(cmd) => cmd.run(&mut out),
         Command::Run(cmd) => cmd.run(&mut out),
         Command::SetCurrentEpoch(cmd) => cmd.run(&mut out),
@@ -158,7 +179,9 @@ pub fn run() -> Result<(), Error> {
         Command::ShowConfigs(cmd) => cmd.run(&mut out),
         Command::ShowLedger(cmd) => cmd.run(&mut out),
         Command::Show(cmd) => cmd.run(&mut out),
+        Command::Snapshot(cmd) => cmd.run(&mut out),
         Command::Transfer(cmd) => cmd.run(&mut out),
+        Command::Watch(cmd) => cmd.run(&mut out),
     }
 }
 
@@ -206,13 +229,7 @@ pub fn handle_system_transaction<O: std::io::Write>(
 
     if print_receipt {
         let encoder = AddressBech32Encoder::for_simulator();
-        let display_context = TransactionReceiptDisplayContextBuilder::new()
-            .encoder(&encoder)
-            .schema_lookup_callback(|event_type_identifier: &EventTypeIdentifier| {
-                get_event_schema(&substate_db, event_type_identifier)
-            })
-            .build();
-        writeln!(out, "{}", receipt.display(display_context)).map_err(Error::IOError)?;
+        write_receipt(&substate_db, &receipt, &encoder, out)?;
     }
     drop(substate_db);
 
@@ -250,53 +267,100 @@ pub fn handle_manifest<O: std::io::Write>(
             }
             Ok(None)
         }
-        None => {
-            let scrypto_vm = ScryptoVm::<DefaultWasmEngine>::default();
-            let native_vm = DefaultNativeVm::new();
-            let vm = Vm::new(&scrypto_vm, native_vm);
-            let mut substate_db = RocksdbSubstateStore::standard(get_data_dir()?);
-            Bootstrapper::new(
-                NetworkDefinition::simulator(),
-                &mut substate_db,
-                vm.clone(),
-                false,
-            )
-            .bootstrap_test_default();
-
-            let sks = get_signing_keys(signing_keys)?;
-            let initial_proofs = sks
-                .into_iter()
-                .map(|e| NonFungibleGlobalId::from_public_key(&e.public_key()))
-                .collect::<BTreeSet<NonFungibleGlobalId>>();
-            let nonce = get_nonce()?;
-            let transaction = TestTransaction::new_from_nonce(manifest, nonce);
-
-            let receipt = execute_and_commit_transaction(
-                &mut substate_db,
-                vm,
-                &CostingParameters::default(),
-                &ExecutionConfig::for_test_transaction().with_kernel_trace(trace),
-                &transaction
-                    .prepare()
-                    .map_err(Error::TransactionPrepareError)?
-                    .get_executable(initial_proofs),
-            );
-
-            if print_receipt {
-                let encoder = AddressBech32Encoder::for_simulator();
-                let display_context = TransactionReceiptDisplayContextBuilder::new()
-                    .encoder(&encoder)
-                    .schema_lookup_callback(|event_type_identifier: &EventTypeIdentifier| {
-                        get_event_schema(&substate_db, event_type_identifier)
-                    })
-                    .build();
-                writeln!(out, "{}", receipt.display(display_context)).map_err(Error::IOError)?;
-            }
-            drop(substate_db);
+        None => execute_manifest(manifest, signing_keys, trace, print_receipt, false, out)
+            .map(Option::Some),
+    }
+}
+
+/// Executes the manifest and commits its changes, optionally printing the receipt and a typed diff
+/// of the state changes.
+pub fn execute_manifest<O: std::io::Write>(
+    manifest: TransactionManifestV1,
+    signing_keys: &Option<String>,
+    trace: bool,
+    print_receipt: bool,
+    print_state_diff: bool,
+    out: &mut O,
+) -> Result<TransactionReceipt, Error> {
+    let scrypto_vm = ScryptoVm::<DefaultWasmEngine>::default();
+    let native_vm = DefaultNativeVm::new();
+    let vm = Vm::new(&scrypto_vm, native_vm);
+    let mut substate_db = RocksdbSubstateStore::standard(get_data_dir()?);
+    Bootstrapper::new(
+        NetworkDefinition::simulator(),
+        &mut substate_db,
+        vm.clone(),
+        false,
+    )
+    .bootstrap_test_default();
+
+    let sks = get_signing_keys(signing_keys)?;
+    let initial_proofs = sks
+        .into_iter()
+        .map(|e| NonFungibleGlobalId::from_public_key(&e.public_key()))
+        .collect::<BTreeSet<NonFungibleGlobalId>>();
+    let nonce = get_nonce()?;
+    let transaction = TestTransaction::new_from_nonce(manifest, nonce);
 
-            process_receipt(receipt).map(Option::Some)
+    let receipt = execute_transaction(
+        &substate_db,
+        vm,
+        &CostingParameters::default(),
+        &ExecutionConfig::for_test_transaction().with_kernel_trace(trace),
+        &transaction
+            .prepare()
+            .map_err(Error::TransactionPrepareError)?
+            .get_executable(initial_proofs),
+    );
+
+    let encoder = AddressBech32Encoder::for_simulator();
+    let mut state_diff = None;
+    if let TransactionResult::Commit(commit) = &receipt.result {
+        // The diff is against the state before the changes, so it's computed before committing
+        if print_state_diff {
+            state_diff = Some(StateDiff::new(&substate_db, &commit.state_updates));
         }
+        substate_db.commit(
+            &commit
+                .state_updates
+                .create_database_updates::<SpreadPrefixKeyMapper>(),
+        );
+    }
+    // The receipt is rendered against the committed state, which knows the schemas of the events
+    // of any newly published packages
+    if print_receipt {
+        write_receipt(&substate_db, &receipt, &encoder, out)?;
     }
+    if let Some(state_diff) = state_diff {
+        writeln!(out, "{}", state_diff.display(&encoder)).map_err(Error::IOError)?;
+    }
+    drop(substate_db);
+
+    process_receipt(receipt)
+}
+
+/// Prints the receipt, with its events decoded against their schemas in the given store.
+fn write_receipt<S: SubstateDatabase, O: std::io::Write>(
+    substate_db: &S,
+    receipt: &TransactionReceipt,
+    encoder: &AddressBech32Encoder,
+    out: &mut O,
+) -> Result<(), Error> {
+    let mut event_schemas = index_map_new();
+    if let TransactionResult::Commit(commit) = &receipt.result {
+        for (event_type_identifier, _) in &commit.application_events {
+            if let Some(schema) = get_event_schema(substate_db, event_type_identifier)? {
+                event_schemas.insert(event_type_identifier.clone(), schema);
+            }
+        }
+    }
+    let display_context = TransactionReceiptDisplayContextBuilder::new()
+        .encoder(encoder)
+        .schema_lookup_callback(|event_type_identifier: &EventTypeIdentifier| {
+            event_schemas.get(event_type_identifier).cloned()
+        })
+        .build();
+    writeln!(out, "{}", receipt.display(display_context)).map_err(Error::IOError)
 }
 
 pub fn process_receipt(receipt: TransactionReceipt) -> Result<TransactionReceipt, Error> {
@@ -420,12 +484,14 @@ pub fn get_blueprint_id(component_address: ComponentAddress) -> Result<Blueprint
 pub fn get_event_schema<S: SubstateDatabase>(
     substate_db: &S,
     event_type_identifier: &EventTypeIdentifier,
-) -> Option<(LocalTypeId, VersionedScryptoSchema)> {
+) -> Result<Option<(LocalTypeId, VersionedScryptoSchema)>, Error> {
     let system_reader = SystemDatabaseReader::new(substate_db);
 
     let (blueprint_id, event_name) = match event_type_identifier {
         EventTypeIdentifier(Emitter::Method(node_id, node_module), event_name) => {
-            let blueprint_id = system_reader.get_blueprint_id(node_id, *node_module).ok()?;
+            let blueprint_id = system_reader
+                .get_blueprint_id(node_id, *node_module)
+                .map_err(Error::SystemReaderError)?;
             (blueprint_id, event_name)
         }
         EventTypeIdentifier(Emitter::Function(blueprint_id), event_name) => {
@@ -434,7 +500,7 @@ pub fn get_event_schema<S: SubstateDatabase>(
     };
 
     let version_key = BlueprintVersionKey::new_default(blueprint_id.blueprint_name.as_str());
-    let bp_definition: VersionedPackageBlueprintVersionDefinition = system_reader
+    let bp_definition: Option<VersionedPackageBlueprintVersionDefinition> = system_reader
         .read_object_collection_entry(
             blueprint_id.package_address.as_node_id(),
             ModuleId::Main,
@@ -443,16 +509,21 @@ pub fn get_event_schema<S: SubstateDatabase>(
                 &version_key,
             ),
         )
-        .unwrap()?;
+        .map_err(Error::SystemReaderError)?;
 
+    let Some(bp_definition) = bp_definition else {
+        return Ok(None);
+    };
     let bp_interface = match bp_definition {
         VersionedPackageBlueprintVersionDefinition::V1(blueprint) => blueprint.interface,
     };
 
-    let event_def = bp_interface.events.get(event_name)?;
+    let Some(event_def) = bp_interface.events.get(event_name) else {
+        return Ok(None);
+    };
     match event_def {
         BlueprintPayloadDef::Static(type_id) => {
-            let schema: VersionedScryptoSchema = system_reader
+            let schema: Option<VersionedScryptoSchema> = system_reader
                 .read_object_collection_entry(
                     blueprint_id.package_address.as_node_id(),
                     ModuleId::Main,
@@ -461,9 +532,9 @@ pub fn get_event_schema<S: SubstateDatabase>(
                         &type_id.0,
                     ),
                 )
-                .unwrap()?;
+                .map_err(Error::SystemReaderError)?;
 
-            Some((type_id.1, schema))
+            Ok(schema.map(|schema| (type_id.1, schema)))
         }
         BlueprintPayloadDef::Generic(..) => {
             panic!("Not expecting any events to use generics")
@@ -1,5 +1,4 @@
 use clap::Parser;
-use std::fs;
 use std::path::PathBuf;
 
 use crate::scrypto::*;
@@ -17,6 +16,14 @@ pub struct NewPackage {
     /// Use local Scrypto as dependency
     #[clap(short, long)]
     local: bool,
+
+    /// The template to create the package from: hello (default), token, nft-marketplace or dao
+    #[clap(long, conflicts_with = "template_dir")]
+    template: Option<String>,
+
+    /// A directory of files to create the package from, instead of a built-in template
+    #[clap(long)]
+    template_dir: Option<PathBuf>,
 }
 
 impl NewPackage {
@@ -66,53 +73,63 @@ impl NewPackage {
             ))
         };
 
-        if path.exists() {
-            Err(Error::PackageAlreadyExists)
-        } else {
-            fs::create_dir_all(child_of(&path, "src")).map_err(Error::IOError)?;
-            fs::create_dir_all(child_of(&path, "tests")).map_err(Error::IOError)?;
-
-            fs::write(
-                child_of(&path, "Cargo.toml"),
-                include_str!("../../../assets/template/Cargo.toml_template")
-                    .replace("${package_name}", &self.package_name)
-                    .replace("${sbor}", &sbor)
-                    .replace("${scrypto}", &scrypto)
-                    .replace("${transaction}", &transaction)
-                    .replace("${radix-engine}", &radix_engine)
-                    .replace("${radix-engine-interface}", &radix_engine_interface)
-                    .replace("${scrypto-unit}", &scrypto_unit)
-                    .replace("${scrypto-test}", &scrypto_test)
-                    .replace("${optional-scrypto-test}", &optional_scrypto_test),
-            )
-            .map_err(Error::IOError)?;
-
-            fs::write(
-                child_of(&path, ".gitignore"),
-                include_str!("../../../assets/template/.gitignore"),
-            )
-            .map_err(Error::IOError)?;
+        let substitutions = [
+            ("package_name", self.package_name.clone()),
+            ("wasm_name", wasm_name),
+            ("sbor", sbor),
+            ("scrypto", scrypto),
+            ("transaction", transaction),
+            ("radix-engine", radix_engine),
+            ("radix-engine-interface", radix_engine_interface),
+            ("scrypto-unit", scrypto_unit),
+            ("scrypto-test", scrypto_test),
+            ("optional-scrypto-test", optional_scrypto_test),
+        ];
 
-            fs::write(
-                child_of(&child_of(&path, "src"), "lib.rs"),
-                include_str!("../../../assets/template/src/lib.rs"),
-            )
-            .map_err(Error::IOError)?;
-
-            fs::write(
-                child_of(&child_of(&path, "tests"), "lib.rs"),
-                include_str!("../../../assets/template/tests/lib.rs")
-                    .replace("${wasm_name}", &wasm_name),
-            )
-            .map_err(Error::IOError)?;
+        if path.exists() {
+            return Err(Error::PackageAlreadyExists);
+        }
 
-            Ok(())
+        match &self.template_dir {
+            Some(template_dir) => write_template_dir(template_dir, &path, &substitutions),
+            None => {
+                let name = self.template.as_deref().unwrap_or(DEFAULT_TEMPLATE);
+                let template = find_template(name).ok_or_else(|| {
+                    eprintln!("Available templates:");
+                    for template in PACKAGE_TEMPLATES {
+                        eprintln!("  {:<16} {}", template.name, template.description);
+                    }
+                    Error::TemplateNotFound(name.to_owned())
+                })?;
+                write_template(template, &path, &substitutions)
+            }
         }
     }
 }
 
-fn child_of(path: &PathBuf, name: &str) -> PathBuf {
-    let mut p = path.clone();
-    p.push(name);
-    p
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_template_conflicts_with_template_dir() {
+        let result = NewPackage::try_parse_from([
+            "new-package",
+            "my-package",
+            "--template",
+            "token",
+            "--template-dir",
+            "my-template",
+        ]);
+        assert_eq!(
+            result.unwrap_err().kind(),
+            clap::ErrorKind::ArgumentConflict
+        );
+
+        let new_package =
+            NewPackage::try_parse_from(["new-package", "my-package", "--template", "token"])
+                .unwrap();
+        assert_eq!(new_package.template, Some("token".to_owned()));
+        assert_eq!(new_package.template_dir, None);
+    }
 }
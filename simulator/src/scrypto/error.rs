@@ -1,4 +1,5 @@
 use std::io;
+use std::path::PathBuf;
 
 use crate::utils::*;
 
@@ -13,4 +14,8 @@ pub enum Error {
     FormatError(FormatError),
 
     PackageAlreadyExists,
+
+    TemplateNotFound(String),
+
+    TemplateDirectoryNotFound(PathBuf),
 }
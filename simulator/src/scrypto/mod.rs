@@ -3,12 +3,14 @@ mod cmd_fmt;
 mod cmd_new_package;
 mod cmd_test;
 mod error;
+mod template;
 
 pub use cmd_build::*;
 pub use cmd_fmt::*;
 pub use cmd_new_package::*;
 pub use cmd_test::*;
 pub use error::*;
+pub use template::*;
 
 use clap::{Parser, Subcommand};
 
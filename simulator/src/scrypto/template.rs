@@ -0,0 +1,260 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::scrypto::*;
+
+/// The template used when none is specified.
+pub const DEFAULT_TEMPLATE: &str = "hello";
+
+/// A file of a package template.
+pub struct TemplateFile {
+    /// The path of the file, relative to the package directory
+    pub path: &'static str,
+    pub content: &'static str,
+}
+
+/// A package template shipped with the CLI.
+pub struct PackageTemplate {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub files: &'static [TemplateFile],
+}
+
+macro_rules! template_files {
+    ($template:literal, [$($path:literal),* $(,)?]) => {
+        &[$(
+            TemplateFile {
+                path: $path,
+                content: include_str!(concat!("../../../assets/template/", $template, "/", $path)),
+            }
+        ),*]
+    };
+}
+
+pub const PACKAGE_TEMPLATES: &[PackageTemplate] = &[
+    PackageTemplate {
+        name: "hello",
+        description: "A component giving away tokens",
+        files: template_files!(
+            "hello",
+            [
+                "src/lib.rs",
+                "tests/lib.rs",
+                "manifests/instantiate_hello.rtm",
+                "manifests/free_token.rtm",
+            ]
+        ),
+    },
+    PackageTemplate {
+        name: "token",
+        description: "A fungible token, minted by its owner and burnable by anyone",
+        files: template_files!(
+            "token",
+            [
+                "src/lib.rs",
+                "tests/lib.rs",
+                "manifests/instantiate_token.rtm",
+                "manifests/mint.rtm",
+                "manifests/burn.rtm",
+            ]
+        ),
+    },
+    PackageTemplate {
+        name: "nft-marketplace",
+        description: "A marketplace where its owner sells NFTs at fixed prices",
+        files: template_files!(
+            "nft-marketplace",
+            [
+                "src/lib.rs",
+                "tests/lib.rs",
+                "manifests/instantiate_nft_marketplace.rtm",
+                "manifests/list.rtm",
+                "manifests/buy.rtm",
+            ]
+        ),
+    },
+    PackageTemplate {
+        name: "dao",
+        description: "A DAO whose members vote on proposals",
+        files: template_files!(
+            "dao",
+            [
+                "src/lib.rs",
+                "tests/lib.rs",
+                "manifests/instantiate_dao.rtm",
+                "manifests/add_member.rtm",
+                "manifests/create_proposal.rtm",
+                "manifests/vote.rtm",
+            ]
+        ),
+    },
+];
+
+/// The files written to every package, unless its template directory provides them.
+pub const COMMON_TEMPLATE_FILES: &[TemplateFile] = &[
+    TemplateFile {
+        path: "Cargo.toml",
+        content: include_str!("../../../assets/template/Cargo.toml_template"),
+    },
+    TemplateFile {
+        path: ".gitignore",
+        content: include_str!("../../../assets/template/.gitignore"),
+    },
+];
+
+/// The name a `Cargo.toml` is given in a template directory, so that the directory isn't mistaken
+/// for a Cargo package.
+const CARGO_TOML_TEMPLATE_NAME: &str = "Cargo.toml_template";
+
+pub fn find_template(name: &str) -> Option<&'static PackageTemplate> {
+    PACKAGE_TEMPLATES
+        .iter()
+        .find(|template| template.name == name)
+}
+
+/// Replaces the `${name}` placeholders of a template file.
+pub fn substitute(content: &str, substitutions: &[(&str, String)]) -> String {
+    substitutions
+        .iter()
+        .fold(content.to_owned(), |content, (name, value)| {
+            content.replace(&format!("${{{}}}", name), value)
+        })
+}
+
+/// Writes the files of a built-in template to the package directory.
+pub fn write_template(
+    template: &PackageTemplate,
+    path: &Path,
+    substitutions: &[(&str, String)],
+) -> Result<(), Error> {
+    for file in template.files.iter().chain(COMMON_TEMPLATE_FILES) {
+        write_file(
+            &path.join(file.path),
+            substitute(file.content, substitutions).as_bytes(),
+        )?;
+    }
+    Ok(())
+}
+
+/// Copies a user-provided template directory to the package directory, replacing the
+/// placeholders of its text files.
+///
+/// A `Cargo.toml_template` file is written as `Cargo.toml`. The common files are written when the
+/// directory doesn't provide them.
+pub fn write_template_dir(
+    template_dir: &Path,
+    path: &Path,
+    substitutions: &[(&str, String)],
+) -> Result<(), Error> {
+    if !template_dir.is_dir() {
+        return Err(Error::TemplateDirectoryNotFound(template_dir.to_owned()));
+    }
+
+    for relative_path in list_files(template_dir, Path::new(""))? {
+        let content = fs::read(template_dir.join(&relative_path)).map_err(Error::IOError)?;
+        let content = match String::from_utf8(content) {
+            Ok(text) => substitute(&text, substitutions).into_bytes(),
+            Err(e) => e.into_bytes(),
+        };
+        let relative_path = if relative_path == Path::new(CARGO_TOML_TEMPLATE_NAME) {
+            PathBuf::from("Cargo.toml")
+        } else {
+            relative_path
+        };
+        write_file(&path.join(relative_path), &content)?;
+    }
+
+    for file in COMMON_TEMPLATE_FILES {
+        let file_path = path.join(file.path);
+        if !file_path.exists() {
+            write_file(
+                &file_path,
+                substitute(file.content, substitutions).as_bytes(),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn list_files(root: &Path, relative_dir: &Path) -> Result<Vec<PathBuf>, Error> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(root.join(relative_dir)).map_err(Error::IOError)? {
+        let entry = entry.map_err(Error::IOError)?;
+        let relative_path = relative_dir.join(entry.file_name());
+        if entry.file_type().map_err(Error::IOError)?.is_dir() {
+            files.extend(list_files(root, &relative_path)?);
+        } else {
+            files.push(relative_path);
+        }
+    }
+    Ok(files)
+}
+
+fn write_file(path: &Path, content: &[u8]) -> Result<(), Error> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(Error::IOError)?;
+    }
+    fs::write(path, content).map_err(Error::IOError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_built_in_templates_can_be_found() {
+        for name in ["hello", "token", "nft-marketplace", "dao"] {
+            let template = find_template(name).unwrap();
+            assert!(template.files.iter().any(|file| file.path == "src/lib.rs"));
+        }
+        assert!(find_template("unknown").is_none());
+    }
+
+    #[test]
+    fn test_substitute() {
+        let substitutions = [("package_name", "my-package".to_owned())];
+        assert_eq!(
+            substitute(
+                r#"name = "${package_name}", account = "${account}""#,
+                &substitutions
+            ),
+            r#"name = "my-package", account = "${account}""#
+        );
+    }
+
+    #[test]
+    fn test_write_template_dir() {
+        let template_dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(template_dir.path().join("src")).unwrap();
+        fs::write(
+            template_dir.path().join("src/lib.rs"),
+            "// The ${package_name} package",
+        )
+        .unwrap();
+        fs::write(
+            template_dir.path().join(CARGO_TOML_TEMPLATE_NAME),
+            "name = \"${package_name}\"",
+        )
+        .unwrap();
+        let package_dir = tempfile::tempdir().unwrap();
+        let path = package_dir.path().join("my-package");
+
+        write_template_dir(
+            template_dir.path(),
+            &path,
+            &[("package_name", "my-package".to_owned())],
+        )
+        .unwrap();
+
+        assert_eq!(
+            fs::read_to_string(path.join("src/lib.rs")).unwrap(),
+            "// The my-package package"
+        );
+        assert_eq!(
+            fs::read_to_string(path.join("Cargo.toml")).unwrap(),
+            "name = \"my-package\""
+        );
+        assert!(path.join(".gitignore").exists());
+        assert!(!path.join(CARGO_TOML_TEMPLATE_NAME).exists());
+    }
+}
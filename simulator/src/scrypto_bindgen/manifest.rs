@@ -0,0 +1,696 @@
+//! This module generates helpers for building manifests which call the functions and methods of a
+//! package: `ManifestBuilder` extension traits in Rust, and functions returning the manifest text
+//! of the call instructions in TypeScript.
+
+use heck::{ToLowerCamelCase, ToSnakeCase};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use radix_engine_interface::prelude::*;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::scrypto_bindgen::schema::{self, PackageSchemaResolver, SchemaError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BindingsLanguage {
+    Rust,
+    TypeScript,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseBindingsLanguageError {
+    UnknownLanguage(String),
+}
+
+impl std::error::Error for ParseBindingsLanguageError {}
+
+impl fmt::Display for ParseBindingsLanguageError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl FromStr for BindingsLanguage {
+    type Err = ParseBindingsLanguageError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rust" => Ok(Self::Rust),
+            "ts" | "typescript" => Ok(Self::TypeScript),
+            _ => Err(ParseBindingsLanguageError::UnknownLanguage(s.to_owned())),
+        }
+    }
+}
+
+/// The type of an argument, as far as building a manifest is concerned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum ArgumentType {
+    Bool,
+    /// An integer, with the name of its Rust type (e.g. `u32`)
+    Integer(&'static str),
+    String,
+    Decimal,
+    PreciseDecimal,
+    NonFungibleLocalId,
+    /// An address, with the name of its Rust type (e.g. `ComponentAddress`)
+    Address(&'static str),
+    Bucket,
+    Proof,
+    AddressReservation,
+    Option(Box<ArgumentType>),
+    Array(Box<ArgumentType>),
+    Tuple(Vec<ArgumentType>),
+    Map(Box<ArgumentType>, Box<ArgumentType>),
+    /// Any other type (e.g. a struct of the package), which has to be given as a manifest value.
+    /// Such values are never the elements of an array or the keys/values of a map, as the whole
+    /// array or map is then given as a manifest value.
+    Value,
+}
+
+impl ArgumentType {
+    fn resolve<S>(type_identifier: &ScopedTypeId, schema_resolver: &S) -> Result<Self, SchemaError>
+    where
+        S: PackageSchemaResolver,
+    {
+        Self::resolve_nested(type_identifier, schema_resolver, &mut Vec::new())
+    }
+
+    /// Resolves a type nested in the given types. Named types aren't descended into, so a type
+    /// can only be nested in itself through a cycle of unnamed types, in which case it is given as
+    /// a manifest value.
+    fn resolve_nested<S>(
+        type_identifier: &ScopedTypeId,
+        schema_resolver: &S,
+        ancestors: &mut Vec<ScopedTypeId>,
+    ) -> Result<Self, SchemaError>
+    where
+        S: PackageSchemaResolver,
+    {
+        if ancestors.contains(type_identifier) {
+            return Ok(Self::Value);
+        }
+
+        ancestors.push(*type_identifier);
+        let argument_type = Self::resolve_type_kind(type_identifier, schema_resolver, ancestors);
+        ancestors.pop();
+        argument_type
+    }
+
+    fn resolve_type_kind<S>(
+        type_identifier: &ScopedTypeId,
+        schema_resolver: &S,
+        ancestors: &mut Vec<ScopedTypeId>,
+    ) -> Result<Self, SchemaError>
+    where
+        S: PackageSchemaResolver,
+    {
+        let type_kind = schema_resolver.resolve_type_kind(type_identifier)?;
+        let type_metadata = schema_resolver.resolve_type_metadata(type_identifier)?;
+        let type_validation = schema_resolver.resolve_type_validation(type_identifier)?;
+        let mut resolve_child = |local_type_index| {
+            Self::resolve_nested(
+                &ScopedTypeId(type_identifier.0, local_type_index),
+                schema_resolver,
+                ancestors,
+            )
+        };
+
+        let argument_type = match type_kind {
+            TypeKind::Bool => Self::Bool,
+            TypeKind::I8 => Self::Integer("i8"),
+            TypeKind::I16 => Self::Integer("i16"),
+            TypeKind::I32 => Self::Integer("i32"),
+            TypeKind::I64 => Self::Integer("i64"),
+            TypeKind::I128 => Self::Integer("i128"),
+            TypeKind::U8 => Self::Integer("u8"),
+            TypeKind::U16 => Self::Integer("u16"),
+            TypeKind::U32 => Self::Integer("u32"),
+            TypeKind::U64 => Self::Integer("u64"),
+            TypeKind::U128 => Self::Integer("u128"),
+            TypeKind::String => Self::String,
+            TypeKind::Array { element_type } => match resolve_child(element_type)? {
+                Self::Value => Self::Value,
+                element_type => Self::Array(Box::new(element_type)),
+            },
+            // Named tuples are structs, which are given as manifest values
+            TypeKind::Tuple { field_types } if type_metadata.get_name().is_none() => Self::Tuple(
+                field_types
+                    .iter()
+                    .map(|local_type_index| resolve_child(*local_type_index))
+                    .collect::<Result<_, _>>()?,
+            ),
+            TypeKind::Enum { variants } => match (
+                type_metadata.get_name(),
+                variants.len(),
+                variants.get(&0).as_ref().map(|vec| vec.as_slice()),
+                variants.get(&1).as_ref().map(|vec| vec.as_slice()),
+            ) {
+                (Some("Option"), 2usize, Some([]), Some([some_type_index])) => {
+                    Self::Option(Box::new(resolve_child(*some_type_index)?))
+                }
+                _ => Self::Value,
+            },
+            TypeKind::Map {
+                key_type,
+                value_type,
+            } => match (resolve_child(key_type)?, resolve_child(value_type)?) {
+                (Self::Value, _) | (_, Self::Value) => Self::Value,
+                (key_type, value_type) => Self::Map(Box::new(key_type), Box::new(value_type)),
+            },
+            TypeKind::Custom(ScryptoCustomTypeKind::Decimal) => Self::Decimal,
+            TypeKind::Custom(ScryptoCustomTypeKind::PreciseDecimal) => Self::PreciseDecimal,
+            TypeKind::Custom(ScryptoCustomTypeKind::NonFungibleLocalId) => Self::NonFungibleLocalId,
+            TypeKind::Custom(ScryptoCustomTypeKind::Own) => match type_validation {
+                TypeValidation::Custom(ScryptoCustomTypeValidation::Own(validation)) => {
+                    match validation {
+                        OwnValidation::IsBucket => Self::Bucket,
+                        OwnValidation::IsProof => Self::Proof,
+                        OwnValidation::IsGlobalAddressReservation => Self::AddressReservation,
+                        _ => Self::Value,
+                    }
+                }
+                _ => Self::Value,
+            },
+            TypeKind::Custom(ScryptoCustomTypeKind::Reference) => match type_validation {
+                TypeValidation::Custom(ScryptoCustomTypeValidation::Reference(validation)) => {
+                    match validation {
+                        ReferenceValidation::IsGlobal => Self::Address("GlobalAddress"),
+                        ReferenceValidation::IsGlobalPackage => Self::Address("PackageAddress"),
+                        ReferenceValidation::IsGlobalComponent => Self::Address("ComponentAddress"),
+                        ReferenceValidation::IsGlobalResourceManager => {
+                            Self::Address("ResourceAddress")
+                        }
+                        ReferenceValidation::IsGlobalTyped(Some(package_address), _)
+                            if package_address == RESOURCE_PACKAGE =>
+                        {
+                            Self::Address("ResourceAddress")
+                        }
+                        ReferenceValidation::IsGlobalTyped(Some(package_address), _)
+                            if package_address == PACKAGE_PACKAGE =>
+                        {
+                            Self::Address("PackageAddress")
+                        }
+                        ReferenceValidation::IsGlobalTyped(..) => Self::Address("ComponentAddress"),
+                        ReferenceValidation::IsInternal
+                        | ReferenceValidation::IsInternalTyped(..) => Self::Value,
+                    }
+                }
+                _ => Self::Value,
+            },
+            _ => Self::Value,
+        };
+        Ok(argument_type)
+    }
+
+    fn rust_type(&self) -> String {
+        match self {
+            Self::Bool => "bool".to_owned(),
+            Self::Integer(type_name) | Self::Address(type_name) => type_name.to_string(),
+            Self::String => "String".to_owned(),
+            Self::Decimal => "Decimal".to_owned(),
+            Self::PreciseDecimal => "PreciseDecimal".to_owned(),
+            Self::NonFungibleLocalId => "NonFungibleLocalId".to_owned(),
+            Self::Bucket => "ManifestBucket".to_owned(),
+            Self::Proof => "ManifestProof".to_owned(),
+            Self::AddressReservation => "ManifestAddressReservation".to_owned(),
+            Self::Option(some_type) => format!("Option<{}>", some_type.rust_type()),
+            Self::Array(element_type) => format!("Vec<{}>", element_type.rust_type()),
+            Self::Tuple(field_types) => match field_types.as_slice() {
+                [] => "()".to_owned(),
+                field_types => format!(
+                    "({},)",
+                    field_types
+                        .iter()
+                        .map(|field_type| field_type.rust_type())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            },
+            Self::Map(key_type, value_type) => format!(
+                "IndexMap<{}, {}>",
+                key_type.rust_type(),
+                value_type.rust_type()
+            ),
+            Self::Value => "ManifestValue".to_owned(),
+        }
+    }
+
+    fn ts_type(&self) -> String {
+        match self {
+            Self::Bool => "boolean".to_owned(),
+            Self::Integer("i64" | "i128" | "u64" | "u128") => "bigint".to_owned(),
+            Self::Integer(_) => "number".to_owned(),
+            Self::Option(some_type) => format!("{} | null", some_type.ts_type()),
+            Self::Array(element_type) => format!("Array<{}>", element_type.ts_type()),
+            Self::Tuple(field_types) => format!(
+                "[{}]",
+                field_types
+                    .iter()
+                    .map(|field_type| field_type.ts_type())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Self::Map(key_type, value_type) => {
+                format!("Array<[{}, {}]>", key_type.ts_type(), value_type.ts_type())
+            }
+            // Values are given as their manifest text, and addresses in Bech32m
+            Self::String
+            | Self::Decimal
+            | Self::PreciseDecimal
+            | Self::NonFungibleLocalId
+            | Self::Address(_)
+            | Self::Bucket
+            | Self::Proof
+            | Self::AddressReservation
+            | Self::Value => "string".to_owned(),
+        }
+    }
+
+    /// The value kind of the manifest values of this type, as in `Array<U32>(..)`.
+    fn value_kind(&self) -> String {
+        match self {
+            Self::Bool => "Bool".to_owned(),
+            Self::Integer(type_name) => type_name.to_uppercase(),
+            Self::String => "String".to_owned(),
+            Self::Decimal => "Decimal".to_owned(),
+            Self::PreciseDecimal => "PreciseDecimal".to_owned(),
+            Self::NonFungibleLocalId => "NonFungibleLocalId".to_owned(),
+            Self::Address(_) => "Address".to_owned(),
+            Self::Bucket => "Bucket".to_owned(),
+            Self::Proof => "Proof".to_owned(),
+            Self::AddressReservation => "AddressReservation".to_owned(),
+            Self::Option(_) => "Enum".to_owned(),
+            Self::Array(_) => "Array".to_owned(),
+            Self::Tuple(_) => "Tuple".to_owned(),
+            Self::Map(..) => "Map".to_owned(),
+            Self::Value => unreachable!("Manifest values are never nested in arrays or maps"),
+        }
+    }
+
+    /// Returns a TypeScript expression rendering the value of the given TypeScript expression as
+    /// manifest text.
+    fn ts_render(&self, value: &str, depth: usize) -> String {
+        match self {
+            Self::Bool => format!("String({})", value),
+            Self::Integer(type_name) => format!("`${{{}}}{}`", value, type_name),
+            Self::String => format!("JSON.stringify({})", value),
+            Self::Decimal => format!("`Decimal(\"${{{}}}\")`", value),
+            Self::PreciseDecimal => format!("`PreciseDecimal(\"${{{}}}\")`", value),
+            Self::NonFungibleLocalId => format!("`NonFungibleLocalId(\"${{{}}}\")`", value),
+            Self::Address(_) => format!("`Address(\"${{{}}}\")`", value),
+            Self::Bucket => format!("`Bucket(\"${{{}}}\")`", value),
+            Self::Proof => format!("`Proof(\"${{{}}}\")`", value),
+            Self::AddressReservation => format!("`AddressReservation(\"${{{}}}\")`", value),
+            Self::Option(some_type) => format!(
+                "({} === null ? \"None\" : `Some(${{{}}})`)",
+                value,
+                some_type.ts_render(value, depth)
+            ),
+            Self::Array(element_type) => {
+                let element = format!("v{}", depth);
+                format!(
+                    "`Array<{}>(${{{}.map(({}) => {}).join(\", \")}})`",
+                    element_type.value_kind(),
+                    value,
+                    element,
+                    element_type.ts_render(&element, depth + 1)
+                )
+            }
+            Self::Tuple(field_types) => format!(
+                "`Tuple({})`",
+                field_types
+                    .iter()
+                    .enumerate()
+                    .map(|(i, field_type)| format!(
+                        "${{{}}}",
+                        field_type.ts_render(&format!("{}[{}]", value, i), depth)
+                    ))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Self::Map(key_type, value_type) => {
+                let key = format!("k{}", depth);
+                let entry_value = format!("v{}", depth);
+                format!(
+                    "`Map<{}, {}>(${{{}.map(([{}, {}]) => `${{{}}} => ${{{}}}`).join(\", \")}})`",
+                    key_type.value_kind(),
+                    value_type.value_kind(),
+                    value,
+                    key,
+                    entry_value,
+                    key_type.ts_render(&key, depth + 1),
+                    value_type.ts_render(&entry_value, depth + 1)
+                )
+            }
+            Self::Value => value.to_owned(),
+        }
+    }
+}
+
+struct CallBinding {
+    ident: String,
+    is_method: bool,
+    arguments: Vec<(String, ArgumentType)>,
+}
+
+struct BlueprintBindings {
+    blueprint_name: String,
+    calls: Vec<CallBinding>,
+}
+
+fn resolve_blueprint_bindings<S>(
+    blueprint_interface: schema::BlueprintInterface,
+    schema_resolver: &S,
+) -> Result<BlueprintBindings, SchemaError>
+where
+    S: PackageSchemaResolver,
+{
+    let calls = blueprint_interface
+        .functions
+        .into_iter()
+        .map(|function| {
+            Ok(CallBinding {
+                ident: function.ident,
+                is_method: function.receiver.is_some(),
+                arguments: function
+                    .arguments
+                    .into_iter()
+                    .map(|(arg_name, arg_type_identifier)| {
+                        ArgumentType::resolve(&arg_type_identifier, schema_resolver)
+                            .map(|arg_type| (arg_name, arg_type))
+                    })
+                    .collect::<Result<_, _>>()?,
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(BlueprintBindings {
+        blueprint_name: blueprint_interface.blueprint_name,
+        calls,
+    })
+}
+
+/// Generates the manifest bindings of the given blueprints of a package.
+pub fn generate_manifest_bindings<S>(
+    blueprint_interfaces: Vec<schema::BlueprintInterface>,
+    schema_resolver: &S,
+    language: BindingsLanguage,
+    address_encoder: &AddressBech32Encoder,
+) -> Result<String, SchemaError>
+where
+    S: PackageSchemaResolver,
+{
+    let package_address = schema_resolver.package_address();
+    let blueprints = blueprint_interfaces
+        .into_iter()
+        .map(|blueprint_interface| resolve_blueprint_bindings(blueprint_interface, schema_resolver))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let bindings = match language {
+        BindingsLanguage::Rust => rust_bindings(package_address, &blueprints).to_string(),
+        BindingsLanguage::TypeScript => ts_bindings(
+            &address_encoder
+                .encode(package_address.as_ref())
+                .expect("Failed to encode package address"),
+            &blueprints,
+        ),
+    };
+    Ok(bindings)
+}
+
+fn rust_bindings(package_address: PackageAddress, blueprints: &[BlueprintBindings]) -> TokenStream {
+    let package_address_bytes = package_address.to_vec();
+
+    let blueprint_bindings = blueprints
+        .iter()
+        .map(|blueprint| {
+            let blueprint_name = &blueprint.blueprint_name;
+            let trait_ident = format_ident!("{}ManifestBuilder", blueprint_name);
+
+            let signatures = blueprint
+                .calls
+                .iter()
+                .map(|call| {
+                    let method_ident = format_ident!(
+                        "{}_{}",
+                        blueprint_name.to_snake_case(),
+                        call.ident.to_snake_case()
+                    );
+                    let receiver = if call.is_method {
+                        quote! { receiver: ComponentAddress, }
+                    } else {
+                        quote! {}
+                    };
+                    let arg_idents = call
+                        .arguments
+                        .iter()
+                        .map(|(arg_name, _)| format_ident!("{}", arg_name))
+                        .collect::<Vec<_>>();
+                    let arg_types = call
+                        .arguments
+                        .iter()
+                        .map(|(_, arg_type)| {
+                            syn::parse_str::<syn::Type>(&arg_type.rust_type()).unwrap()
+                        })
+                        .collect::<Vec<_>>();
+                    quote! {
+                        fn #method_ident(self, #receiver #(#arg_idents: #arg_types),*) -> Self
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            let bodies = blueprint
+                .calls
+                .iter()
+                .map(|call| {
+                    let ident = &call.ident;
+                    let arg_idents = call
+                        .arguments
+                        .iter()
+                        .map(|(arg_name, _)| format_ident!("{}", arg_name));
+                    if call.is_method {
+                        quote! {
+                            self.call_method(receiver, #ident, manifest_args!(#(#arg_idents),*))
+                        }
+                    } else {
+                        quote! {
+                            self.call_function(
+                                PACKAGE_ADDRESS,
+                                #blueprint_name,
+                                #ident,
+                                manifest_args!(#(#arg_idents),*)
+                            )
+                        }
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            quote! {
+                pub trait #trait_ident {
+                    #(#signatures;)*
+                }
+
+                impl #trait_ident for ManifestBuilder {
+                    #(#signatures { #bodies })*
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+
+    quote! {
+        use transaction::prelude::*;
+
+        pub const PACKAGE_ADDRESS: PackageAddress =
+            PackageAddress::new_or_panic([ #(#package_address_bytes),* ]);
+
+        #(#blueprint_bindings)*
+    }
+}
+
+fn ts_bindings(package_address: &str, blueprints: &[BlueprintBindings]) -> String {
+    let mut bindings = String::new();
+    bindings.push_str(&format!(
+        "export const PACKAGE_ADDRESS = \"{}\";\n",
+        package_address
+    ));
+
+    for blueprint in blueprints {
+        bindings.push_str(&format!("\nexport class {} {{\n", blueprint.blueprint_name));
+        for call in &blueprint.calls {
+            let mut params = Vec::new();
+            let mut operands = Vec::new();
+            if call.is_method {
+                params.push("receiver: string".to_owned());
+                operands.push("`Address(\"${receiver}\")`".to_owned());
+            } else {
+                operands.push("`Address(\"${PACKAGE_ADDRESS}\")`".to_owned());
+                operands.push(format!("'\"{}\"'", blueprint.blueprint_name));
+            }
+            operands.push(format!("'\"{}\"'", call.ident));
+            for (arg_name, arg_type) in &call.arguments {
+                let param = arg_name.to_lower_camel_case();
+                params.push(format!("{}: {}", param, arg_type.ts_type()));
+                operands.push(arg_type.ts_render(&param, 0));
+            }
+
+            bindings.push_str(&format!(
+                "  static {}({}): string {{\n",
+                call.ident.to_lower_camel_case(),
+                params.join(", ")
+            ));
+            bindings.push_str(&format!(
+                "    return [\n      \"{}\",\n",
+                if call.is_method {
+                    "CALL_METHOD"
+                } else {
+                    "CALL_FUNCTION"
+                }
+            ));
+            for operand in operands {
+                bindings.push_str(&format!("      {},\n", operand));
+            }
+            bindings.push_str("    ].join(\"\\n    \") + \"\\n;\\n\";\n");
+            bindings.push_str("  }\n");
+        }
+        bindings.push_str("}\n");
+    }
+
+    bindings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct SingleSchemaResolver(VersionedScryptoSchema);
+
+    impl SingleSchemaResolver {
+        fn resolve<T: Describe<ScryptoCustomTypeKind>>() -> Result<ArgumentType, SchemaError> {
+            let (type_id, schema) =
+                generate_full_schema_from_single_type::<T, ScryptoCustomSchema>();
+            Self::resolve_in_schema(type_id, schema)
+        }
+
+        fn resolve_in_schema(
+            type_id: LocalTypeId,
+            schema: VersionedScryptoSchema,
+        ) -> Result<ArgumentType, SchemaError> {
+            let resolver = Self(schema);
+            ArgumentType::resolve(
+                &ScopedTypeId(resolver.0.generate_schema_hash(), type_id),
+                &resolver,
+            )
+        }
+    }
+
+    impl PackageSchemaResolver for SingleSchemaResolver {
+        fn lookup_schema(&self, _schema_hash: &SchemaHash) -> Option<VersionedScryptoSchema> {
+            Some(self.0.clone())
+        }
+
+        fn resolve_type_kind(
+            &self,
+            type_identifier: &ScopedTypeId,
+        ) -> Result<SchemaTypeKind<ScryptoCustomSchema>, SchemaError> {
+            self.0
+                .v1()
+                .resolve_type_kind(type_identifier.1)
+                .ok_or(SchemaError::NonExistentLocalTypeIndex(type_identifier.1))
+                .cloned()
+        }
+
+        fn resolve_type_metadata(
+            &self,
+            type_identifier: &ScopedTypeId,
+        ) -> Result<TypeMetadata, SchemaError> {
+            self.0
+                .v1()
+                .resolve_type_metadata(type_identifier.1)
+                .ok_or(SchemaError::NonExistentLocalTypeIndex(type_identifier.1))
+                .cloned()
+        }
+
+        fn resolve_type_validation(
+            &self,
+            type_identifier: &ScopedTypeId,
+        ) -> Result<TypeValidation<ScryptoCustomTypeValidation>, SchemaError> {
+            self.0
+                .v1()
+                .resolve_type_validation(type_identifier.1)
+                .ok_or(SchemaError::NonExistentLocalTypeIndex(type_identifier.1))
+                .cloned()
+        }
+
+        fn package_address(&self) -> PackageAddress {
+            FAUCET_PACKAGE
+        }
+    }
+
+    #[derive(ScryptoSbor)]
+    struct Pair {
+        left: u32,
+        right: u32,
+    }
+
+    #[derive(ScryptoSbor)]
+    struct TreeNode {
+        children: Vec<TreeNode>,
+    }
+
+    #[test]
+    fn structs_are_given_as_manifest_values() {
+        assert_eq!(
+            SingleSchemaResolver::resolve::<Pair>().unwrap(),
+            ArgumentType::Value
+        );
+        assert_eq!(
+            SingleSchemaResolver::resolve::<Vec<Pair>>().unwrap(),
+            ArgumentType::Value
+        );
+        assert_eq!(
+            SingleSchemaResolver::resolve::<(u32, Decimal)>().unwrap(),
+            ArgumentType::Tuple(vec![ArgumentType::Integer("u32"), ArgumentType::Decimal])
+        );
+    }
+
+    #[test]
+    fn self_referential_types_are_resolved() {
+        assert_eq!(
+            SingleSchemaResolver::resolve::<TreeNode>().unwrap(),
+            ArgumentType::Value
+        );
+
+        // An array of itself, which can only be described by a hand-written schema
+        let schema = VersionedScryptoSchema::V1(SchemaV1 {
+            type_kinds: vec![TypeKind::Array {
+                element_type: LocalTypeId::SchemaLocalIndex(0),
+            }],
+            type_metadata: vec![TypeMetadata::unnamed()],
+            type_validations: vec![TypeValidation::None],
+        });
+        assert_eq!(
+            SingleSchemaResolver::resolve_in_schema(LocalTypeId::SchemaLocalIndex(0), schema)
+                .unwrap(),
+            ArgumentType::Value
+        );
+    }
+
+    #[test]
+    fn nested_values_are_rendered_as_manifest_text() {
+        let arg_type = ArgumentType::Map(
+            Box::new(ArgumentType::String),
+            Box::new(ArgumentType::Array(Box::new(ArgumentType::Option(
+                Box::new(ArgumentType::Integer("u32")),
+            )))),
+        );
+
+        assert_eq!(arg_type.rust_type(), "IndexMap<String, Vec<Option<u32>>>");
+        assert_eq!(arg_type.ts_type(), "Array<[string, Array<number | null>]>");
+        assert_eq!(
+            arg_type.ts_render("amounts", 0),
+            "`Map<String, Array>(${amounts.map(([k0, v0]) => `${JSON.stringify(k0)} => \
+             ${`Array<Enum>(${v0.map((v1) => (v1 === null ? \"None\" : `Some(${`${v1}u32`})`))\
+             .join(\", \")})`}`).join(\", \")})`"
+        );
+    }
+}
@@ -1,4 +1,5 @@
 mod ast;
+mod manifest;
 mod schema;
 mod translation;
 
@@ -16,6 +17,9 @@ use crate::resim::*;
 use self::schema::*;
 use self::translation::blueprint_schema_interface_to_ast_interface;
 
+pub use self::manifest::{BindingsLanguage, ParseBindingsLanguageError};
+pub use self::schema::SchemaError;
+
 /// Generates interfaces for Scrypto packages to ease the use of external packages.
 #[derive(Parser, Debug)]
 #[clap(author, version, about, long_about = None, name = "scrypto-bindgen")]
@@ -99,6 +103,30 @@ pub fn run() -> Result<(), Error> {
     Ok(())
 }
 
+/// Generates helpers for building manifests which call the blueprints of a package, in the given
+/// language.
+pub fn generate_manifest_bindings<S>(
+    substate_db: &S,
+    package_address: PackageAddress,
+    language: BindingsLanguage,
+    address_encoder: &AddressBech32Encoder,
+) -> Result<String, SchemaError>
+where
+    S: SubstateDatabase,
+{
+    let reader = SystemDatabaseReader::new(substate_db);
+    let definition = reader.get_package_definition(package_address);
+
+    let schema_resolver = SchemaResolver::new(package_address, reader);
+    let blueprint_interfaces = derive_blueprint_interfaces(definition, &schema_resolver)?;
+    manifest::generate_manifest_bindings(
+        blueprint_interfaces,
+        &schema_resolver,
+        language,
+        address_encoder,
+    )
+}
+
 struct SchemaResolver<'s, S>(PackageAddress, SystemDatabaseReader<'s, S>)
 where
     S: SubstateDatabase;
@@ -117,7 +145,10 @@ where
     S: SubstateDatabase,
 {
     fn lookup_schema(&self, schema_hash: &SchemaHash) -> Option<VersionedScryptoSchema> {
-        self.1.get_schema(self.0.as_node_id(), schema_hash).ok().map(|x| x.as_ref().clone())
+        self.1
+            .get_schema(self.0.as_node_id(), schema_hash)
+            .ok()
+            .map(|x| x.as_ref().clone())
     }
 
     fn resolve_type_kind(
@@ -214,6 +214,14 @@ where
         {
             "Own".to_owned()
         }
+        TypeKind::Custom(ScryptoCustomTypeKind::Own)
+            if type_validation
+                == TypeValidation::Custom(ScryptoCustomTypeValidation::Own(
+                    OwnValidation::IsIndexStore,
+                )) =>
+        {
+            "Own".to_owned()
+        }
         TypeKind::Custom(ScryptoCustomTypeKind::Own)
             if type_validation
                 == TypeValidation::Custom(ScryptoCustomTypeValidation::Own(
@@ -37,6 +37,13 @@ pub enum BuildCallArgumentsError {
     WrongNumberOfArguments(usize, usize),
     BuildCallArgumentError(BuildCallArgumentError),
     RustToManifestValueError(RustToManifestValueError),
+
+    /// The argument at the given index does not match the type it is expected to be.
+    InvalidArgument {
+        index: usize,
+        expected_type: String,
+        error: BuildCallArgumentError,
+    },
 }
 
 /// Represents an error when parsing an argument.
@@ -50,6 +57,12 @@ pub enum BuildCallArgumentError {
 
     /// Failed to interpret this string as a resource specifier
     InvalidResourceSpecifier(String),
+
+    /// The tuple or enum variant was given a different number of fields than expected.
+    WrongNumberOfFields(usize, usize),
+
+    /// The enum has no variant of this name.
+    UnknownEnumVariant(String),
 }
 
 impl From<BuildCallArgumentsError> for BuildCallInstructionError {
@@ -112,20 +125,19 @@ pub fn build_call_arguments<'a>(
             }
 
             for (i, f) in field_types.iter().enumerate() {
-                let (returned_builder, value) = build_call_argument(
+                let (returned_builder, value) = build_typed_call_argument(
                     builder,
                     address_bech32_decoder,
-                    schema
-                        .v1()
-                        .resolve_type_kind(*f)
-                        .expect("Inconsistent schema"),
-                    schema
-                        .v1()
-                        .resolve_type_validation(*f)
-                        .expect("Inconsistent schema"),
+                    schema,
+                    *f,
                     args[i].clone(),
                     account,
-                )?;
+                )
+                .map_err(|error| BuildCallArgumentsError::InvalidArgument {
+                    index: i,
+                    expected_type: type_signature(schema, *f),
+                    error,
+                })?;
                 builder = returned_builder;
                 built_args.push(value);
             }
@@ -149,9 +161,37 @@ macro_rules! parse_basic_type {
     };
 }
 
+fn build_typed_call_argument(
+    builder: ManifestBuilder,
+    address_bech32_decoder: &AddressBech32Decoder,
+    schema: &VersionedScryptoSchema,
+    type_id: LocalTypeId,
+    argument: String,
+    account: Option<ComponentAddress>,
+) -> Result<(ManifestBuilder, ManifestValue), BuildCallArgumentError> {
+    build_call_argument(
+        builder,
+        address_bech32_decoder,
+        schema,
+        type_id,
+        schema
+            .v1()
+            .resolve_type_kind(type_id)
+            .expect("Inconsistent schema"),
+        schema
+            .v1()
+            .resolve_type_validation(type_id)
+            .expect("Inconsistent schema"),
+        argument,
+        account,
+    )
+}
+
 fn build_call_argument<'a>(
     mut builder: ManifestBuilder,
     address_bech32_decoder: &AddressBech32Decoder,
+    schema: &VersionedScryptoSchema,
+    type_id: LocalTypeId,
     type_kind: &ScryptoTypeKind<LocalTypeId>,
     type_validation: &TypeValidation<ScryptoCustomTypeValidation>,
     argument: String,
@@ -362,10 +402,388 @@ fn build_call_argument<'a>(
                 },
             ))
         }
+        ScryptoTypeKind::Tuple { field_types } => {
+            let inner = strip_delimiters(&argument, '(', ')')
+                .ok_or_else(|| BuildCallArgumentError::FailedToParse(argument.clone()))?;
+            let fields = split_composite_argument(inner)?;
+            let (builder, fields) = build_fields(
+                builder,
+                address_bech32_decoder,
+                schema,
+                field_types,
+                fields,
+                account,
+            )?;
+            Ok((builder, ManifestValue::Tuple { fields }))
+        }
+        ScryptoTypeKind::Enum { variants } => {
+            let (variant_name, fields) = match argument.find('(') {
+                Some(index) => {
+                    let inner = strip_delimiters(&argument[index..], '(', ')')
+                        .ok_or_else(|| BuildCallArgumentError::FailedToParse(argument.clone()))?;
+                    (argument[..index].trim(), split_composite_argument(inner)?)
+                }
+                None => (argument.trim(), vec![]),
+            };
+            let variant_names = match schema
+                .v1()
+                .resolve_type_metadata(type_id)
+                .and_then(|metadata| metadata.child_names.as_ref())
+            {
+                Some(ChildNames::EnumVariants(variant_names)) => Some(variant_names),
+                _ => None,
+            };
+            // A variant is given by its name, or by its discriminator - as `type_signature`
+            // renders the variants which have no name in the schema
+            let discriminator = variant_names
+                .and_then(|variant_names| {
+                    variant_names
+                        .iter()
+                        .find(|(_, metadata)| metadata.get_name() == Some(variant_name))
+                        .map(|(discriminator, _)| *discriminator)
+                })
+                .or_else(|| {
+                    variant_name
+                        .parse::<u8>()
+                        .ok()
+                        .filter(|discriminator| variants.contains_key(discriminator))
+                })
+                .ok_or_else(|| {
+                    BuildCallArgumentError::UnknownEnumVariant(variant_name.to_owned())
+                })?;
+            let field_types = variants.get(&discriminator).expect("Inconsistent schema");
+            let (builder, fields) = build_fields(
+                builder,
+                address_bech32_decoder,
+                schema,
+                field_types,
+                fields,
+                account,
+            )?;
+            Ok((
+                builder,
+                ManifestValue::Enum {
+                    discriminator,
+                    fields,
+                },
+            ))
+        }
+        ScryptoTypeKind::Array { element_type } => {
+            let element_value_kind = schema
+                .v1()
+                .resolve_type_kind(*element_type)
+                .and_then(|element_type_kind| {
+                    manifest_value_kind(
+                        element_type_kind,
+                        schema
+                            .v1()
+                            .resolve_type_validation(*element_type)
+                            .expect("Inconsistent schema"),
+                    )
+                })
+                .ok_or_else(|| BuildCallArgumentError::UnsupportedType(type_kind.clone()))?;
+            let inner = strip_delimiters(&argument, '[', ']')
+                .ok_or_else(|| BuildCallArgumentError::FailedToParse(argument.clone()))?;
+            let mut elements = Vec::new();
+            for element in split_composite_argument(inner)? {
+                let (returned_builder, value) = build_typed_call_argument(
+                    builder,
+                    address_bech32_decoder,
+                    schema,
+                    *element_type,
+                    element,
+                    account,
+                )?;
+                builder = returned_builder;
+                elements.push(value);
+            }
+            Ok((
+                builder,
+                ManifestValue::Array {
+                    element_value_kind,
+                    elements,
+                },
+            ))
+        }
         _ => Err(BuildCallArgumentError::UnsupportedType(type_kind.clone())),
     }
 }
 
+fn build_fields(
+    mut builder: ManifestBuilder,
+    address_bech32_decoder: &AddressBech32Decoder,
+    schema: &VersionedScryptoSchema,
+    field_types: &[LocalTypeId],
+    fields: Vec<String>,
+    account: Option<ComponentAddress>,
+) -> Result<(ManifestBuilder, Vec<ManifestValue>), BuildCallArgumentError> {
+    if fields.len() != field_types.len() {
+        return Err(BuildCallArgumentError::WrongNumberOfFields(
+            fields.len(),
+            field_types.len(),
+        ));
+    }
+
+    let mut values = Vec::with_capacity(fields.len());
+    for (field_type, field) in field_types.iter().zip(fields) {
+        let (returned_builder, value) = build_typed_call_argument(
+            builder,
+            address_bech32_decoder,
+            schema,
+            *field_type,
+            field,
+            account,
+        )?;
+        builder = returned_builder;
+        values.push(value);
+    }
+    Ok((builder, values))
+}
+
+/// Returns the value kind which a manifest value of the given type is encoded with, if the type
+/// is one which can be built from a command line argument.
+fn manifest_value_kind(
+    type_kind: &ScryptoTypeKind<LocalTypeId>,
+    type_validation: &TypeValidation<ScryptoCustomTypeValidation>,
+) -> Option<ManifestValueKind> {
+    let value_kind = match type_kind {
+        ScryptoTypeKind::Bool => ManifestValueKind::Bool,
+        ScryptoTypeKind::I8 => ManifestValueKind::I8,
+        ScryptoTypeKind::I16 => ManifestValueKind::I16,
+        ScryptoTypeKind::I32 => ManifestValueKind::I32,
+        ScryptoTypeKind::I64 => ManifestValueKind::I64,
+        ScryptoTypeKind::I128 => ManifestValueKind::I128,
+        ScryptoTypeKind::U8 => ManifestValueKind::U8,
+        ScryptoTypeKind::U16 => ManifestValueKind::U16,
+        ScryptoTypeKind::U32 => ManifestValueKind::U32,
+        ScryptoTypeKind::U64 => ManifestValueKind::U64,
+        ScryptoTypeKind::U128 => ManifestValueKind::U128,
+        ScryptoTypeKind::String => ManifestValueKind::String,
+        ScryptoTypeKind::Array { .. } => ManifestValueKind::Array,
+        ScryptoTypeKind::Tuple { .. } => ManifestValueKind::Tuple,
+        ScryptoTypeKind::Enum { .. } => ManifestValueKind::Enum,
+        ScryptoTypeKind::Custom(ScryptoCustomTypeKind::Decimal) => {
+            ManifestValueKind::Custom(ManifestCustomValueKind::Decimal)
+        }
+        ScryptoTypeKind::Custom(ScryptoCustomTypeKind::PreciseDecimal) => {
+            ManifestValueKind::Custom(ManifestCustomValueKind::PreciseDecimal)
+        }
+        ScryptoTypeKind::Custom(ScryptoCustomTypeKind::NonFungibleLocalId) => {
+            ManifestValueKind::Custom(ManifestCustomValueKind::NonFungibleLocalId)
+        }
+        ScryptoTypeKind::Custom(ScryptoCustomTypeKind::Reference) => {
+            ManifestValueKind::Custom(ManifestCustomValueKind::Address)
+        }
+        ScryptoTypeKind::Custom(ScryptoCustomTypeKind::Own) => match type_validation {
+            TypeValidation::Custom(ScryptoCustomTypeValidation::Own(OwnValidation::IsBucket)) => {
+                ManifestValueKind::Custom(ManifestCustomValueKind::Bucket)
+            }
+            TypeValidation::Custom(ScryptoCustomTypeValidation::Own(OwnValidation::IsProof)) => {
+                ManifestValueKind::Custom(ManifestCustomValueKind::Proof)
+            }
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some(value_kind)
+}
+
+/// Strips the given opening and closing delimiters from the argument, if it is wrapped in them.
+fn strip_delimiters(argument: &str, open: char, close: char) -> Option<&str> {
+    argument
+        .trim()
+        .strip_prefix(open)
+        .and_then(|argument| argument.strip_suffix(close))
+}
+
+/// Splits the contents of a tuple, enum variant or array argument on its top-level commas,
+/// so that `1,(2,3),[4,5]` is split into `1`, `(2,3)` and `[4,5]`.
+///
+/// An element wrapped in double quotes is taken as is, without its quotes, so that it may contain
+/// commas and brackets - e.g. a string such as `"Hello, world"` or a resource specifier such as
+/// `"resource_sim1...:#1#,#2#"`. Within quotes, `\"` and `\\` stand for `"` and `\`.
+fn split_composite_argument(argument: &str) -> Result<Vec<String>, BuildCallArgumentError> {
+    if argument.trim().is_empty() {
+        return Ok(vec![]);
+    }
+
+    let failed_to_parse = || BuildCallArgumentError::FailedToParse(argument.to_owned());
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    let mut in_quotes = false;
+    let mut escaped = false;
+    for (index, c) in argument.char_indices() {
+        if in_quotes {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => in_quotes = false,
+                _ => {}
+            }
+            continue;
+        }
+        match c {
+            '"' => in_quotes = true,
+            '(' | '[' => depth += 1,
+            ')' | ']' => {
+                depth = depth.checked_sub(1).ok_or_else(failed_to_parse)?;
+            }
+            ',' if depth == 0 => {
+                parts.push(unquote_element(&argument[start..index]).ok_or_else(failed_to_parse)?);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 || in_quotes {
+        return Err(failed_to_parse());
+    }
+    parts.push(unquote_element(&argument[start..]).ok_or_else(failed_to_parse)?);
+
+    Ok(parts)
+}
+
+/// Trims the given element of a composite argument and, if it is wrapped in double quotes, strips
+/// them and unescapes its contents. Returns `None` if the element is only partly quoted.
+fn unquote_element(element: &str) -> Option<String> {
+    let element = element.trim();
+    let Some(quoted) = element.strip_prefix('"') else {
+        return Some(element.to_owned());
+    };
+
+    let mut unquoted = String::with_capacity(quoted.len());
+    let mut chars = quoted.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => unquoted.push(chars.next()?),
+            '"' => return chars.as_str().is_empty().then_some(unquoted),
+            c => unquoted.push(c),
+        }
+    }
+    None
+}
+
+/// Renders a human readable signature of the given type, such as `Option { None, Some(Decimal) }`,
+/// used to tell the user what an argument is expected to look like.
+pub fn type_signature(schema: &VersionedScryptoSchema, type_id: LocalTypeId) -> String {
+    type_signature_with_depth(schema, type_id, 0)
+}
+
+fn type_signature_with_depth(
+    schema: &VersionedScryptoSchema,
+    type_id: LocalTypeId,
+    depth: usize,
+) -> String {
+    // Recursive types would otherwise be expanded forever
+    const MAX_DEPTH: usize = 8;
+
+    let schema_v1 = schema.v1();
+    let name = schema_v1.resolve_type_name_from_metadata(type_id);
+    let (Some(type_kind), Some(type_validation)) = (
+        schema_v1.resolve_type_kind(type_id),
+        schema_v1.resolve_type_validation(type_id),
+    ) else {
+        return "?".to_owned();
+    };
+    if depth >= MAX_DEPTH {
+        return name.unwrap_or("..").to_owned();
+    }
+    let signature_of =
+        |type_id: &LocalTypeId| type_signature_with_depth(schema, *type_id, depth + 1);
+
+    match type_kind {
+        ScryptoTypeKind::Any => "Any".to_owned(),
+        ScryptoTypeKind::Bool => "bool".to_owned(),
+        ScryptoTypeKind::I8 => "i8".to_owned(),
+        ScryptoTypeKind::I16 => "i16".to_owned(),
+        ScryptoTypeKind::I32 => "i32".to_owned(),
+        ScryptoTypeKind::I64 => "i64".to_owned(),
+        ScryptoTypeKind::I128 => "i128".to_owned(),
+        ScryptoTypeKind::U8 => "u8".to_owned(),
+        ScryptoTypeKind::U16 => "u16".to_owned(),
+        ScryptoTypeKind::U32 => "u32".to_owned(),
+        ScryptoTypeKind::U64 => "u64".to_owned(),
+        ScryptoTypeKind::U128 => "u128".to_owned(),
+        ScryptoTypeKind::String => "String".to_owned(),
+        ScryptoTypeKind::Array { element_type } => format!("[{}]", signature_of(element_type)),
+        ScryptoTypeKind::Map {
+            key_type,
+            value_type,
+        } => format!(
+            "Map<{}, {}>",
+            signature_of(key_type),
+            signature_of(value_type)
+        ),
+        ScryptoTypeKind::Tuple { field_types } => {
+            let field_names = schema_v1
+                .resolve_matching_tuple_metadata(type_id, field_types.len())
+                .field_names;
+            let fields = field_types
+                .iter()
+                .enumerate()
+                .map(|(i, field_type)| match field_names {
+                    Some(field_names) => {
+                        format!("{}: {}", field_names[i], signature_of(field_type))
+                    }
+                    None => signature_of(field_type),
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("{}({})", name.unwrap_or(""), fields)
+        }
+        ScryptoTypeKind::Enum { variants } => {
+            let variants = variants
+                .iter()
+                .map(|(discriminator, field_types)| {
+                    let variant_name = schema_v1
+                        .resolve_matching_enum_metadata(type_id, *discriminator, field_types.len())
+                        .variant_name
+                        .map(|variant_name| variant_name.to_owned())
+                        .unwrap_or_else(|| discriminator.to_string());
+                    if field_types.is_empty() {
+                        variant_name
+                    } else {
+                        let fields = field_types
+                            .iter()
+                            .map(signature_of)
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        format!("{}({})", variant_name, fields)
+                    }
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("{} {{ {} }}", name.unwrap_or("Enum"), variants)
+        }
+        ScryptoTypeKind::Custom(ScryptoCustomTypeKind::Reference) => match type_validation {
+            TypeValidation::Custom(ScryptoCustomTypeValidation::Reference(
+                ReferenceValidation::IsGlobalPackage,
+            )) => "PackageAddress".to_owned(),
+            TypeValidation::Custom(ScryptoCustomTypeValidation::Reference(
+                ReferenceValidation::IsGlobalComponent,
+            )) => "ComponentAddress".to_owned(),
+            TypeValidation::Custom(ScryptoCustomTypeValidation::Reference(
+                ReferenceValidation::IsGlobalResourceManager,
+            )) => "ResourceAddress".to_owned(),
+            TypeValidation::Custom(ScryptoCustomTypeValidation::Reference(
+                ReferenceValidation::IsGlobal | ReferenceValidation::IsGlobalTyped(..),
+            )) => "GlobalAddress".to_owned(),
+            _ => "Reference".to_owned(),
+        },
+        ScryptoTypeKind::Custom(ScryptoCustomTypeKind::Own) => match type_validation {
+            TypeValidation::Custom(ScryptoCustomTypeValidation::Own(OwnValidation::IsBucket)) => {
+                "Bucket".to_owned()
+            }
+            TypeValidation::Custom(ScryptoCustomTypeValidation::Own(OwnValidation::IsProof)) => {
+                "Proof".to_owned()
+            }
+            _ => name.unwrap_or("Own").to_owned(),
+        },
+        ScryptoTypeKind::Custom(custom_type_kind) => format!("{:?}", custom_type_kind),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -727,6 +1145,201 @@ mod test {
         )
     }
 
+    #[test]
+    pub fn parsing_of_tuple_succeeds() {
+        // Arrange
+        let arg = "(12, hello, [1, 2])";
+
+        // Act
+        let parsed_arg: (u8, String, Vec<u32>) =
+            build_and_decode_typed_arg(arg).expect("Failed to parse arg");
+
+        // Assert
+        assert_eq!(parsed_arg, (12u8, "hello".to_owned(), vec![1u32, 2u32]))
+    }
+
+    #[test]
+    pub fn parsing_of_enum_by_variant_name_succeeds() {
+        // Arrange
+        let some_arg = "Some(1.5)";
+        let none_arg = "None";
+
+        // Act
+        let parsed_some: Option<Decimal> =
+            build_and_decode_typed_arg(some_arg).expect("Failed to parse arg");
+        let parsed_none: Option<Decimal> =
+            build_and_decode_typed_arg(none_arg).expect("Failed to parse arg");
+
+        // Assert
+        assert_eq!(parsed_some, Some(Decimal::from_str("1.5").unwrap()));
+        assert_eq!(parsed_none, None);
+    }
+
+    #[test]
+    pub fn parsing_of_enum_by_discriminator_of_type_signature_succeeds() {
+        // Arrange
+        let type_id = LocalTypeId::SchemaLocalIndex(0);
+        let schema: VersionedScryptoSchema = SchemaV1::<ScryptoCustomSchema> {
+            type_kinds: vec![ScryptoTypeKind::Enum {
+                variants: indexmap!(
+                    0 => vec![],
+                    1 => vec![LocalTypeId::WellKnown(basic_well_known_types::U64_TYPE)],
+                ),
+            }],
+            type_metadata: vec![TypeMetadata::unnamed()],
+            type_validations: vec![TypeValidation::None],
+        }
+        .into();
+
+        // Act
+        let signature = type_signature(&schema, type_id);
+        let (_, built_arg) = build_typed_call_argument(
+            ManifestBuilder::new(),
+            &AddressBech32Decoder::for_simulator(),
+            &schema,
+            type_id,
+            "1(5)".to_owned(),
+            None,
+        )
+        .expect("Failed to parse arg");
+
+        // Assert
+        assert_eq!(signature, "Enum { 0, 1(u64) }");
+        assert_eq!(
+            built_arg,
+            ManifestValue::Enum {
+                discriminator: 1,
+                fields: vec![ManifestValue::U64 { value: 5 }],
+            }
+        );
+    }
+
+    #[test]
+    pub fn parsing_of_nested_arrays_succeeds() {
+        // Arrange
+        let arg = "[[1, 2], [], [3]]";
+
+        // Act
+        let parsed_arg: Vec<Vec<u64>> =
+            build_and_decode_typed_arg(arg).expect("Failed to parse arg");
+
+        // Assert
+        assert_eq!(parsed_arg, vec![vec![1u64, 2u64], vec![], vec![3u64]])
+    }
+
+    #[test]
+    pub fn parsing_of_quoted_elements_with_commas_and_brackets_succeeds() {
+        // Arrange
+        let arg = r#"("Hello, world", "(not a tuple)", ["a,b", "say \"hi\"", c])"#;
+
+        // Act
+        let parsed_arg: (String, String, Vec<String>) =
+            build_and_decode_typed_arg(arg).expect("Failed to parse arg");
+
+        // Assert
+        assert_eq!(
+            parsed_arg,
+            (
+                "Hello, world".to_owned(),
+                "(not a tuple)".to_owned(),
+                vec!["a,b".to_owned(), "say \"hi\"".to_owned(), "c".to_owned()]
+            )
+        )
+    }
+
+    #[test]
+    pub fn parsing_of_unterminated_or_partly_quoted_elements_fails() {
+        for arg in [r#"("Hello, world)"#, r#"("Hello" world)"#] {
+            // Act
+            let result = build_and_decode_typed_arg::<(String,)>(arg);
+
+            // Assert
+            assert!(matches!(
+                result,
+                Err(BuildAndDecodeArgError::BuildCallArgumentError(
+                    BuildCallArgumentError::FailedToParse(..)
+                ))
+            ));
+        }
+    }
+
+    #[test]
+    pub fn parsing_of_unknown_enum_variant_fails() {
+        // Act
+        let result = build_and_decode_typed_arg::<Option<u8>>("Maybe(1)");
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(BuildAndDecodeArgError::BuildCallArgumentError(
+                BuildCallArgumentError::UnknownEnumVariant(variant)
+            )) if variant == "Maybe"
+        ));
+    }
+
+    #[test]
+    pub fn parsing_of_tuple_with_wrong_number_of_fields_fails() {
+        // Act
+        let result = build_and_decode_typed_arg::<(u8, u8)>("(1, 2, 3)");
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(BuildAndDecodeArgError::BuildCallArgumentError(
+                BuildCallArgumentError::WrongNumberOfFields(3, 2)
+            ))
+        ));
+    }
+
+    #[test]
+    pub fn invalid_argument_error_reports_expected_type() {
+        // Arrange
+        let (type_id, schema) =
+            generate_full_schema_from_single_type::<(Option<Decimal>,), ScryptoCustomSchema>();
+
+        // Act
+        let result = build_call_arguments(
+            ManifestBuilder::new(),
+            &AddressBech32Decoder::for_simulator(),
+            &schema,
+            type_id,
+            vec!["Some(abc)".to_owned()],
+            None,
+        );
+
+        // Assert
+        match result {
+            Err(BuildCallArgumentsError::InvalidArgument {
+                index,
+                expected_type,
+                ..
+            }) => {
+                assert_eq!(index, 0);
+                assert_eq!(expected_type, "Option { None, Some(Decimal) }");
+            }
+            _ => panic!("Expected an invalid argument error"),
+        }
+    }
+
+    pub fn build_and_decode_typed_arg<T: ManifestDecode + Describe<ScryptoCustomTypeKind>>(
+        arg: &str,
+    ) -> Result<T, BuildAndDecodeArgError> {
+        let (type_id, schema) = generate_full_schema_from_single_type::<T, ScryptoCustomSchema>();
+        let (_, built_arg) = build_typed_call_argument(
+            ManifestBuilder::new(),
+            &AddressBech32Decoder::for_simulator(),
+            &schema,
+            type_id,
+            arg.to_owned(),
+            None,
+        )
+        .map_err(BuildAndDecodeArgError::BuildCallArgumentError)?;
+
+        let bytes = manifest_encode(&built_arg).map_err(BuildAndDecodeArgError::EncodeError)?;
+
+        manifest_decode(&bytes).map_err(BuildAndDecodeArgError::DecodeError)
+    }
+
     pub fn build_and_decode_arg<S: AsRef<str>, T: ManifestDecode>(
         arg: S,
         type_kind: ScryptoTypeKind<LocalTypeId>,
@@ -736,6 +1349,8 @@ mod test {
         let (_, built_arg) = build_call_argument(
             builder,
             &AddressBech32Decoder::for_simulator(),
+            &VersionedScryptoSchema::empty(),
+            LocalTypeId::SchemaLocalIndex(0),
             &type_kind,
             &type_validation,
             arg.as_ref().to_owned(),
@@ -69,6 +69,10 @@ $resim call-method $component free_token
 # Test - export package definition
 $resim export-package-definition $package target/temp.rpd
 
+# Test - generate manifest bindings
+$resim generate-bindings $package --language rust
+$resim generate-bindings $package --language ts --output target/temp.ts
+
 # Test - dump component state
 $resim show $package
 $resim show $component
@@ -85,7 +89,7 @@ files=`ls target/*.blob`
 blobs=`echo $files | sed 's/ / --blobs /g'`
 $resim run ./target/temp2.rtm --blobs $blobs
 $resim new-account --manifest ./target/temp3.rtm
-$resim run ./target/temp3.rtm
+$resim run ./target/temp3.rtm --state-diff
 
 # Test - run manifest with a given set of signing keys
 $resim generate-key-pair
@@ -125,3 +129,52 @@ token_address=`$resim new-token-mutable $non_fungible_global_id | awk '/Resource
 
 # Test - mint and transfer (Mintable that requires a `NonFungibleGlobalId`)
 $resim mint 777 $token_address --proofs "$non_fungible_global_id"
+
+# Test - address aliases
+$resim alias add mytoken $token_address
+$resim alias add minter $minter_badge
+$resim alias add bob $account2
+$resim alias list
+$resim mint 111 mytoken --proofs minter:1
+$resim transfer mytoken:111 bob
+$resim show mytoken
+$resim alias remove bob
+
+# Test - export and import a ledger snapshot
+$resim snapshot export target/ledger.snapshot
+$resim reset
+$resim snapshot import target/ledger.snapshot
+account_dump=`$resim show $account`
+if [[ ${account_dump} != *"XRD"* ]];then
+    echo "Account not restored from snapshot!"
+    exit 1
+fi
+$resim show mytoken
+if $resim alias list | grep -q bob; then
+    echo "Removed alias restored from snapshot!"
+    exit 1
+fi
+
+# Test - create and restore a ledger checkpoint
+$resim checkpoint create before-account3
+account3=`$resim new-account | awk '/Account component address:/ {print $NF}'`
+$resim show $account3
+$resim checkpoint restore before-account3
+if $resim show $account3; then
+    echo "Account not rolled back by checkpoint restore!"
+    exit 1
+fi
+$resim show $account
+
+# Test - checkpoints survive a reset
+$resim reset
+$resim checkpoint restore before-account3
+$resim show $account
+$resim show mytoken
+
+# Test - checkpoints survive a snapshot import
+$resim snapshot import target/ledger.snapshot
+$resim alias remove mytoken
+$resim checkpoint restore before-account3
+$resim show $account
+$resim show mytoken
@@ -20,6 +20,15 @@ $scrypto test --path $test_pkg
 $scrypto test --path $test_pkg -- test_hello --nocapture
 $scrypto test --path $test_pkg -- --nocapture
 
+# Templates
+for template in token nft-marketplace dao; do
+  template_pkg="./target/temp/$template"
+  rm -fr $template_pkg
+  $scrypto new-package $template --path $template_pkg --local --template $template
+  $scrypto test --path $template_pkg
+  rm -fr $template_pkg
+done
+
 # Logging
 $scrypto build --path ../examples/everything --log-level ERROR
 size1=$(ls -la ../examples/everything/target/wasm32-unknown-unknown/release/everything.wasm | cut -d ' ' -f 5)
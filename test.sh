@@ -25,6 +25,9 @@ test_crates_features \
     transaction-scenarios \
     transaction" \
     --features=compile-blueprints-at-build-time
+test_crates_features \
+    "transaction" \
+    --features=bls,serde
 
 echo "Testing scrypto packages..."
 test_packages \
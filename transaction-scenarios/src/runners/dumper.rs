@@ -1,12 +1,19 @@
+use radix_engine::blueprints::consensus_manager::{
+    ConsensusManagerField, ConsensusManagerProposerMilliTimestampFieldPayload,
+    ConsensusManagerStateFieldPayload, ConsensusManagerSubstate,
+};
 use radix_engine::system::system_callback_api::SystemCallbackObject;
+use radix_engine::system::system_db_reader::SystemDatabaseReader;
 use radix_engine::vm::{DefaultNativeVm, NativeVm, NoExtension, Vm};
 use radix_engine::{
-    system::bootstrap::Bootstrapper,
+    system::bootstrap::{Bootstrapper, GenesisDataChunk, GenesisStakeAllocation},
     vm::{
         wasm::{DefaultWasmEngine, WasmEngine},
         ScryptoVm,
     },
 };
+use radix_engine_interface::api::node_modules::auth::AuthAddresses;
+use radix_engine_interface::blueprints::consensus_manager::*;
 use radix_engine_store_interface::interface::*;
 use radix_engine_stores::memory_db::InMemorySubstateDatabase;
 use transaction::validation::{NotarizedTransactionValidator, ValidationConfig};
@@ -24,6 +31,18 @@ pub fn run_all_in_memory_and_dump_examples(
     network: NetworkDefinition,
     root_path: std::path::PathBuf,
 ) -> Result<(), FullScenarioError> {
+    run_all_in_memory_and_dump_examples_with_config(
+        ScenarioConfig::default().with_network(network),
+        root_path,
+    )
+}
+
+#[cfg(feature = "std")]
+pub fn run_all_in_memory_and_dump_examples_with_config(
+    config: ScenarioConfig,
+    root_path: std::path::PathBuf,
+) -> Result<(), FullScenarioError> {
+    let network = config.network.clone();
     let mut substate_db = InMemorySubstateDatabase::standard();
     let scrypto_vm = ScryptoVm::<DefaultWasmEngine>::default();
     let native_vm = DefaultNativeVm::new();
@@ -32,19 +51,40 @@ pub fn run_all_in_memory_and_dump_examples(
         native_vm,
     };
 
-    let receipts = Bootstrapper::new(NetworkDefinition::simulator(), &mut substate_db, vm, false)
-        .bootstrap_test_default()
-        .unwrap();
-    let epoch = receipts
+    // A validator is needed to lead the rounds of any epoch changes which scenarios require
+    let validator_key = Secp256k1PrivateKey::from_u64(1).unwrap().public_key();
+    let genesis_data_chunks = vec![
+        GenesisDataChunk::Validators(vec![validator_key.into()]),
+        GenesisDataChunk::Stakes {
+            accounts: vec![ComponentAddress::virtual_account_from_public_key(
+                &validator_key,
+            )],
+            allocations: vec![(
+                validator_key,
+                vec![GenesisStakeAllocation {
+                    account_index: 0,
+                    xrd_amount: Decimal::one(),
+                }],
+            )],
+        },
+    ];
+    Bootstrapper::new(NetworkDefinition::simulator(), &mut substate_db, vm, false)
+        .bootstrap_test_default_with_genesis_data(genesis_data_chunks)
+        .unwrap()
         .wrap_up_receipt
         .expect_commit_success()
         .next_epoch()
-        .expect("Wrap up ends in next epoch")
-        .epoch;
+        .expect("Wrap up ends in next epoch");
 
     let mut next_nonce: u32 = 0;
     for scenario_builder in get_builder_for_every_scenario() {
-        let mut scenario = scenario_builder(ScenarioCore::new(network.clone(), epoch, next_nonce));
+        // Scenarios may move the network on to later epochs
+        let epoch = read_consensus_manager_state(&substate_db).epoch;
+        let mut scenario = scenario_builder(ScenarioCore::new_with_config(
+            config.clone(),
+            epoch,
+            next_nonce,
+        ));
         let context = {
             let sub_folder = root_path.join(scenario.metadata().logical_name);
             // Clear directory before generating anew
@@ -126,11 +166,100 @@ where
                     &transaction.get_executable(),
                 ));
             }
+            NextAction::AdvanceToEpoch(epoch) => {
+                previous = Some(advance_to_epoch(
+                    substate_db,
+                    vm.clone(),
+                    costing_parameters,
+                    &context.network,
+                    epoch,
+                ));
+            }
             NextAction::Completed(end_state) => break Ok(end_state),
         }
     }
 }
 
+/// Executes "start next round" validator transactions, with the first validator of the active set
+/// leading each round, until the given epoch starts - and returns the receipt of the transaction which started
+/// it, or of the first one which didn't commit successfully.
+fn advance_to_epoch<S, V>(
+    substate_db: &mut S,
+    vm: V,
+    costing_parameters: &CostingParameters,
+    network: &NetworkDefinition,
+    epoch: Epoch,
+) -> TransactionReceipt
+where
+    S: SubstateDatabase + CommittableSubstateDatabase,
+    V: SystemCallbackObject + Clone,
+{
+    loop {
+        let state = read_consensus_manager_state(&*substate_db);
+        let proposer_timestamp_ms = SystemDatabaseReader::new(&*substate_db)
+            .read_typed_object_field::<ConsensusManagerProposerMilliTimestampFieldPayload>(
+                CONSENSUS_MANAGER.as_node_id(),
+                ModuleId::Main,
+                ConsensusManagerField::ProposerMilliTimestamp.field_index(),
+            )
+            .expect("Consensus manager timestamp should exist")
+            .into_latest()
+            .epoch_milli;
+        let round = Round::of(state.round.number() + 1);
+        let transaction = SystemTransactionV1 {
+            instructions: InstructionsV1(vec![InstructionV1::CallMethod {
+                address: CONSENSUS_MANAGER.into(),
+                method_name: CONSENSUS_MANAGER_NEXT_ROUND_IDENT.to_string(),
+                args: to_manifest_value_and_unwrap!(&ConsensusManagerNextRoundInput {
+                    round,
+                    proposer_timestamp_ms,
+                    leader_proposal_history: LeaderProposalHistory {
+                        gap_round_leaders: vec![],
+                        current_leader: 0,
+                        is_fallback: false,
+                    },
+                }),
+            }]),
+            blobs: BlobsV1 { blobs: vec![] },
+            hash_for_execution: hash(format!(
+                "Scenario round change: epoch {} round {}",
+                state.epoch.number(),
+                round.number()
+            )),
+            pre_allocated_addresses: vec![],
+        }
+        .prepare()
+        .expect("Round change transaction should be preparable");
+        let receipt = execute_and_commit_transaction(
+            substate_db,
+            vm.clone(),
+            costing_parameters,
+            &ExecutionConfig::for_system_transaction(network.clone()),
+            &transaction.get_executable(btreeset![AuthAddresses::validator_role()]),
+        );
+        let started_epoch = match &receipt.result {
+            TransactionResult::Commit(commit) if commit.outcome.is_success() => {
+                commit.next_epoch().map(|next_epoch| next_epoch.epoch)
+            }
+            _ => return receipt,
+        };
+        if started_epoch.map_or(false, |started_epoch| started_epoch >= epoch) {
+            return receipt;
+        }
+    }
+}
+
+fn read_consensus_manager_state<S: SubstateDatabase>(substate_db: &S) -> ConsensusManagerSubstate {
+    SystemDatabaseReader::new(substate_db)
+        .read_typed_object_field::<ConsensusManagerStateFieldPayload>(
+            CONSENSUS_MANAGER.as_node_id(),
+            ModuleId::Main,
+            ConsensusManagerField::State.field_index(),
+        )
+        .expect("Consensus manager state should exist")
+        .into_latest()
+}
+
 #[cfg(test)]
 #[cfg(feature = "std")]
 mod test {
@@ -162,4 +291,43 @@ mod test {
             .unwrap();
         }
     }
+
+    #[test]
+    pub fn scenarios_with_the_same_seed_are_reproducible() {
+        let config = ScenarioConfig::default()
+            .with_seed(42)
+            .with_account_count(25)
+            .with_transfer_amount(dec!(5));
+        let read_manifests = |root_path: &std::path::PathBuf| {
+            let mut manifests = BTreeMap::new();
+            for entry in walkdir::WalkDir::new(root_path) {
+                let path = entry.unwrap().path().to_owned();
+                if path.extension().and_then(|str| str.to_str()) != Some("rtm") {
+                    continue;
+                }
+                let manifest_string = std::fs::read_to_string(&path).unwrap();
+                manifests.insert(
+                    path.strip_prefix(root_path).unwrap().to_owned(),
+                    manifest_string,
+                );
+            }
+            manifests
+        };
+
+        let mut runs = vec![];
+        for run in 0..2 {
+            let root_path = std::env::temp_dir().join(format!(
+                "scenarios-seed-42-run-{}-{}",
+                run,
+                std::process::id()
+            ));
+            run_all_in_memory_and_dump_examples_with_config(config.clone(), root_path.clone())
+                .unwrap();
+            runs.push(read_manifests(&root_path));
+            std::fs::remove_dir_all(&root_path).unwrap();
+        }
+
+        assert!(!runs[0].is_empty());
+        assert_eq!(runs[0], runs[1]);
+    }
 }
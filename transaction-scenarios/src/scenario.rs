@@ -76,9 +76,83 @@ impl Completeable for ManifestBuilder {
     }
 }
 
+/// The parameters shared by every scenario, which allow varied but reproducible ledger fixtures
+/// to be generated from the same scenarios.
+#[derive(Debug, Clone)]
+pub struct ScenarioConfig {
+    pub network: NetworkDefinition,
+    /// The seed from which the accounts used by the scenarios are derived.
+    /// If `None`, the scenarios use their fixed, historical accounts.
+    pub seed: Option<u64>,
+    /// The number of additional accounts which the scenarios spread their activity over.
+    pub account_count: usize,
+    /// The amount of XRD moved by each transfer of the scenarios.
+    pub transfer_amount: Decimal,
+}
+
+impl Default for ScenarioConfig {
+    fn default() -> Self {
+        Self {
+            network: NetworkDefinition::simulator(),
+            seed: None,
+            account_count: 0,
+            transfer_amount: dec!(1),
+        }
+    }
+}
+
+impl ScenarioConfig {
+    pub fn with_network(mut self, network: NetworkDefinition) -> Self {
+        self.network = network;
+        self
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    pub fn with_account_count(mut self, account_count: usize) -> Self {
+        self.account_count = account_count;
+        self
+    }
+
+    pub fn with_transfer_amount(mut self, transfer_amount: Decimal) -> Self {
+        self.transfer_amount = transfer_amount;
+        self
+    }
+
+    /// The account playing the `index`-th role of a scenario.
+    ///
+    /// Without a seed, this is the given fixed account. With a seed, the account is derived
+    /// from the seed and the index, so the same seed always results in the same accounts.
+    pub fn account(&self, index: u64, fixed: impl FnOnce() -> VirtualAccount) -> VirtualAccount {
+        match self.seed {
+            Some(seed) => Self::derive_account(seed, index),
+            None => fixed(),
+        }
+    }
+
+    /// The additional accounts which the scenarios spread their activity over.
+    /// These are always derived from the seed (or from a seed of `0` if there is none).
+    pub fn additional_accounts(&self) -> Vec<VirtualAccount> {
+        let seed = self.seed.unwrap_or_default();
+        (0..self.account_count as u64)
+            .map(|index| Self::derive_account(seed, u64::MAX - index))
+            .collect()
+    }
+
+    fn derive_account(seed: u64, index: u64) -> VirtualAccount {
+        let mut bytes = seed.to_le_bytes().to_vec();
+        bytes.extend(index.to_le_bytes());
+        let key = u64::from_le_bytes(hash(bytes).lower_bytes());
+        ed25519_account_for_private_key(key)
+    }
+}
+
 /// A core set of functionality and utilities common to every scenario
 pub struct ScenarioCore {
-    network: NetworkDefinition,
+    config: ScenarioConfig,
     epoch: Epoch,
     nonce: u32,
     default_notary: PrivateKey,
@@ -88,8 +162,16 @@ pub struct ScenarioCore {
 
 impl ScenarioCore {
     pub fn new(network: NetworkDefinition, epoch: Epoch, starting_nonce: u32) -> Self {
+        Self::new_with_config(
+            ScenarioConfig::default().with_network(network),
+            epoch,
+            starting_nonce,
+        )
+    }
+
+    pub fn new_with_config(config: ScenarioConfig, epoch: Epoch, starting_nonce: u32) -> Self {
         Self {
-            network,
+            config,
             epoch,
             nonce: starting_nonce,
             default_notary: ed25519_account_1().key,
@@ -159,7 +241,7 @@ impl ScenarioCore {
         self.nonce += 1;
         let mut builder = TransactionBuilder::new()
             .header(TransactionHeaderV1 {
-                network_id: self.network.id,
+                network_id: self.config.network.id,
                 start_epoch_inclusive: self.epoch,
                 end_epoch_exclusive: self.epoch.next().unwrap(),
                 nonce,
@@ -189,11 +271,15 @@ impl ScenarioCore {
     }
 
     pub fn network(&self) -> &NetworkDefinition {
-        &self.network
+        &self.config.network
+    }
+
+    pub fn config(&self) -> &ScenarioConfig {
+        &self.config
     }
 
     pub fn encoder(&self) -> AddressBech32Encoder {
-        AddressBech32Encoder::new(&self.network)
+        AddressBech32Encoder::new(&self.config.network)
     }
 
     pub fn check_start(&self, previous: &Option<&TransactionReceipt>) -> Result<(), ScenarioError> {
@@ -291,6 +377,9 @@ impl ScenarioError {
 
 pub enum NextAction {
     Transaction(NextTransaction),
+    /// The runner should progress the network's rounds until the given epoch starts, and then
+    /// provide the receipt of the transaction which started it as the previous result.
+    AdvanceToEpoch(Epoch),
     Completed(EndState),
 }
 
@@ -371,12 +460,23 @@ pub struct ScenarioMetadata {
     pub logical_name: &'static str,
 }
 
+/// A scenario specific config, which can be derived from the [`ScenarioConfig`] shared by every
+/// scenario. By default, the shared config is ignored.
+pub trait FromScenarioConfig: Default {
+    fn from_scenario_config(_config: &ScenarioConfig) -> Self {
+        Self::default()
+    }
+}
+
+impl FromScenarioConfig for () {}
+
 pub trait ScenarioCreator: Sized {
-    type Config: Default;
+    type Config: FromScenarioConfig;
     type State: Default;
 
     fn create(core: ScenarioCore) -> Box<dyn ScenarioInstance> {
-        Self::create_with_config_and_state(core, Default::default(), Default::default())
+        let config = Self::Config::from_scenario_config(core.config());
+        Self::create_with_config_and_state(core, config, Default::default())
     }
 
     fn create_with_config_and_state(
@@ -424,7 +524,9 @@ impl<Config: 'static, State: 'static> ScenarioBuilder<Config, State> {
             + 'static,
     ) -> Self {
         self.transactions.push(ScenarioTransaction {
-            creator: Box::new(creator),
+            creator: Box::new(move |core, config, state| {
+                creator(core, config, state).map(NextAction::Transaction)
+            }),
             handler: Box::new(|core, _, _, receipt| {
                 core.check_commit_success(&receipt)?;
                 Ok(())
@@ -441,7 +543,9 @@ impl<Config: 'static, State: 'static> ScenarioBuilder<Config, State> {
             + 'static,
     ) -> Self {
         self.transactions.push(ScenarioTransaction {
-            creator: Box::new(creator),
+            creator: Box::new(move |core, config, state| {
+                creator(core, config, state).map(NextAction::Transaction)
+            }),
             handler: Box::new(
                 move |core, config, state, receipt| -> Result<(), ScenarioError> {
                     let commit_result = core.check_commit_success(receipt)?;
@@ -460,7 +564,9 @@ impl<Config: 'static, State: 'static> ScenarioBuilder<Config, State> {
             + 'static,
     ) -> Self {
         self.transactions.push(ScenarioTransaction {
-            creator: Box::new(creator),
+            creator: Box::new(move |core, config, state| {
+                creator(core, config, state).map(NextAction::Transaction)
+            }),
             handler: Box::new(
                 move |core, config, state, receipt| -> Result<(), ScenarioError> {
                     let error = core.check_commit_failure(receipt)?;
@@ -484,12 +590,52 @@ impl<Config: 'static, State: 'static> ScenarioBuilder<Config, State> {
             + 'static,
     ) -> Self {
         self.transactions.push(ScenarioTransaction {
-            creator: Box::new(creator),
+            creator: Box::new(move |core, config, state| {
+                creator(core, config, state).map(NextAction::Transaction)
+            }),
             handler: Box::new(handler),
         });
         self
     }
 
+    /// Progresses the network to the next epoch, which the following transactions are then
+    /// valid in. Also checks that the epoch change commits successfully.
+    pub fn next_epoch(self) -> Self {
+        self.next_epoch_with_result_handler(|_, _, _, _| Ok(()))
+    }
+
+    pub fn next_epoch_with_result_handler(
+        mut self,
+        handler: impl Fn(&mut ScenarioCore, &Config, &mut State, &CommitResult) -> Result<(), ScenarioError>
+            + 'static,
+    ) -> Self {
+        self.transactions.push(ScenarioTransaction {
+            creator: Box::new(|core, _, _| {
+                let next_epoch = core
+                    .epoch
+                    .next()
+                    .ok_or_else(|| ScenarioError::Custom("Epoch number overflowed".to_string()))?;
+                core.last_transaction_name =
+                    Some(format!("epoch-change-to-{}", next_epoch.number()));
+                Ok(NextAction::AdvanceToEpoch(next_epoch))
+            }),
+            handler: Box::new(
+                move |core, config, state, receipt| -> Result<(), ScenarioError> {
+                    let commit_result = core.check_commit_success(receipt)?;
+                    let next_epoch = commit_result.next_epoch().ok_or_else(|| {
+                        ScenarioError::Custom(format!(
+                            "{} didn't start a new epoch",
+                            core.last_transaction_description()
+                        ))
+                    })?;
+                    core.epoch = next_epoch.epoch;
+                    handler(core, config, state, commit_result)
+                },
+            ),
+        });
+        self
+    }
+
     pub fn finalize(
         self,
         finalizer: impl Fn(&mut ScenarioCore, &Config, &mut State) -> Result<ScenarioOutput, ScenarioError>
@@ -520,8 +666,8 @@ pub struct ScenarioTransaction<Config, State> {
     handler: Box<TransactionResultHandler<Config, State>>,
 }
 
-type TransactionCreator<Config, State> = dyn Fn(&mut ScenarioCore, &Config, &mut State) -> Result<NextTransaction, ScenarioError>
-    + 'static;
+type TransactionCreator<Config, State> =
+    dyn Fn(&mut ScenarioCore, &Config, &mut State) -> Result<NextAction, ScenarioError> + 'static;
 type TransactionResultHandler<Config, State> = dyn Fn(&mut ScenarioCore, &Config, &mut State, &TransactionReceipt) -> Result<(), ScenarioError>
     + 'static;
 type ScenarioFinalizer<Config, State> = dyn Fn(&mut ScenarioCore, &Config, &mut State) -> Result<ScenarioOutput, ScenarioError>
@@ -551,12 +697,11 @@ impl<Config, State> ScenarioInstance for Scenario<Config, State> {
                 .as_ref()(core, &self.config, &mut self.state, receipt)?;
         }
         let next_action = if next_transaction_index < self.transactions.len() {
-            let next_transaction = self.transactions[next_transaction_index].creator.as_ref()(
+            self.transactions[next_transaction_index].creator.as_ref()(
                 core,
                 &self.config,
                 &mut self.state,
-            )?;
-            NextAction::Transaction(next_transaction)
+            )?
         } else {
             let output = self.finalizer.as_ref()(core, &self.config, &mut self.state)?;
             NextAction::Completed(core.finish_scenario(output))
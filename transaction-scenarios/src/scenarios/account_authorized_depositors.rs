@@ -15,9 +15,15 @@ pub struct AccountAuthorizedDepositorsScenarioState {
 
 impl Default for AccountAuthorizedDepositorsScenarioConfig {
     fn default() -> Self {
+        Self::from_scenario_config(&ScenarioConfig::default())
+    }
+}
+
+impl FromScenarioConfig for AccountAuthorizedDepositorsScenarioConfig {
+    fn from_scenario_config(config: &ScenarioConfig) -> Self {
         Self {
-            source_account: secp256k1_account_1(),
-            destination_account: secp256k1_account_2(),
+            source_account: config.account(0, secp256k1_account_1),
+            destination_account: config.account(1, secp256k1_account_2),
         }
     }
 }
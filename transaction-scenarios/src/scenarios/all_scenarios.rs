@@ -45,6 +45,12 @@ impl Iterator for AllScenariosIterator {
             10 => Some(Box::new(|core| {
                 max_transaction::MaxTransactionScenarioCreator::create(core)
             })),
+            11 => Some(Box::new(|core| {
+                resource_lifecycle::ResourceLifecycleScenarioCreator::create(core)
+            })),
+            12 => Some(Box::new(|core| {
+                validator_staking::ValidatorStakingScenarioCreator::create(core)
+            })),
             _ => None,
         }
     }
@@ -18,9 +18,15 @@ pub struct FungibleResourceScenarioState {
 
 impl Default for FungibleResourceScenarioConfig {
     fn default() -> Self {
+        Self::from_scenario_config(&ScenarioConfig::default())
+    }
+}
+
+impl FromScenarioConfig for FungibleResourceScenarioConfig {
+    fn from_scenario_config(config: &ScenarioConfig) -> Self {
         Self {
-            user_account_1: secp256k1_account_1(),
-            user_account_2: secp256k1_account_2(),
+            user_account_1: config.account(0, secp256k1_account_1),
+            user_account_2: config.account(1, secp256k1_account_2),
         }
     }
 }
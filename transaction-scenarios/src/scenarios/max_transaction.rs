@@ -6,6 +6,8 @@ use radix_engine_interface::*;
 #[derive(Default)]
 pub struct MaxTransactionScenarioState(Option<PackageAddress>, Option<ComponentAddress>);
 
+impl FromScenarioConfig for MaxTransactionScenarioState {}
+
 pub struct MaxTransactionScenarioCreator;
 
 impl ScenarioCreator for MaxTransactionScenarioCreator {
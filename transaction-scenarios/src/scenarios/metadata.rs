@@ -25,10 +25,16 @@ pub struct MetadataScenarioConfig {
 
 impl Default for MetadataScenarioConfig {
     fn default() -> Self {
+        Self::from_scenario_config(&ScenarioConfig::default())
+    }
+}
+
+impl FromScenarioConfig for MetadataScenarioConfig {
+    fn from_scenario_config(config: &ScenarioConfig) -> Self {
         Self {
-            user_account_1: secp256k1_account_1(),
-            user_account_sandbox: secp256k1_account_sandbox(),
-            user_account_dashboard: secp256k1_account_dashboard(),
+            user_account_1: config.account(0, secp256k1_account_1),
+            user_account_sandbox: config.account(1, secp256k1_account_sandbox),
+            user_account_dashboard: config.account(2, secp256k1_account_dashboard),
         }
     }
 }
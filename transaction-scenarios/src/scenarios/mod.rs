@@ -10,6 +10,8 @@ pub mod metadata;
 pub mod non_fungible_resource;
 pub mod non_fungible_resource_with_remote_type;
 pub mod radiswap;
+pub mod resource_lifecycle;
 pub mod transfer_xrd;
+pub mod validator_staking;
 
 pub use all_scenarios::*;
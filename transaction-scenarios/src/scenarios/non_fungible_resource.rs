@@ -23,9 +23,15 @@ pub struct NonFungibleResourceScenarioState {
 
 impl Default for NonFungibleResourceScenarioConfig {
     fn default() -> Self {
+        Self::from_scenario_config(&ScenarioConfig::default())
+    }
+}
+
+impl FromScenarioConfig for NonFungibleResourceScenarioConfig {
+    fn from_scenario_config(config: &ScenarioConfig) -> Self {
         Self {
-            main_account: secp256k1_account_1(),
-            occasional_recipient_account: secp256k1_account_2(),
+            main_account: config.account(0, secp256k1_account_1),
+            occasional_recipient_account: config.account(1, secp256k1_account_2),
         }
     }
 }
@@ -17,12 +17,19 @@ pub struct RadiswapScenarioConfig {
 
 impl Default for RadiswapScenarioConfig {
     fn default() -> Self {
+        Self::from_scenario_config(&ScenarioConfig::default())
+    }
+}
+
+impl FromScenarioConfig for RadiswapScenarioConfig {
+    fn from_scenario_config(config: &ScenarioConfig) -> Self {
         Self {
-            radiswap_dapp_definition_account: ed25519_account_for_private_key(891231),
-            storing_account: secp256k1_account_2(),
-            user_account_1: secp256k1_account_3(),
-            user_account_2: ed25519_account_1(),
-            user_account_3: ed25519_account_2(),
+            radiswap_dapp_definition_account: config
+                .account(0, || ed25519_account_for_private_key(891231)),
+            storing_account: config.account(1, secp256k1_account_2),
+            user_account_1: config.account(2, secp256k1_account_3),
+            user_account_2: config.account(3, ed25519_account_1),
+            user_account_3: config.account(4, ed25519_account_2),
         }
     }
 }
@@ -83,7 +90,7 @@ impl ScenarioCreator for RadiswapScenarioCreator {
                                         "symbol" => "BTC".to_owned(), locked;
                                         "description" => "A peer to peer decentralized proof of work network.".to_owned(), locked;
                                         "tags" => vec!["p2p".to_owned(), "blockchain".to_owned()], locked;
-                                        "icon_url" => "https://www.example.com/".to_owned(), locked;
+                                        "icon_url" => UncheckedUrl::of("https://www.example.com/"), locked;
                                         "info_url" => "https://www.example.com/".to_owned(), locked;
                                     }
                                 },
@@ -106,7 +113,7 @@ impl ScenarioCreator for RadiswapScenarioCreator {
                                         "symbol" => "ETH".to_owned(), locked;
                                         "description" => "The native token of the Ethereum blockchain".to_owned(), locked;
                                         "tags" => vec!["p2p".to_owned(), "blockchain".to_owned(), "gas".to_owned()], locked;
-                                        "icon_url" => "https://www.example.com/".to_owned(), locked;
+                                        "icon_url" => UncheckedUrl::of("https://www.example.com/"), locked;
                                         "info_url" => "https://www.example.com/".to_owned(), locked;
                                     }
                                 },
@@ -129,7 +136,7 @@ impl ScenarioCreator for RadiswapScenarioCreator {
                                         "symbol" => "ETC".to_owned(), locked;
                                         "description" => "The native token of the Ethereum Classic blockchain".to_owned(), locked;
                                         "tags" => vec!["p2p".to_owned(), "blockchain".to_owned(), "gas".to_owned()], locked;
-                                        "icon_url" => "https://www.example.com/".to_owned(), locked;
+                                        "icon_url" => UncheckedUrl::of("https://www.example.com/"), locked;
                                         "info_url" => "https://www.example.com/".to_owned(), locked;
                                     }
                                 },
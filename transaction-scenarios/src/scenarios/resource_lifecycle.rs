@@ -0,0 +1,459 @@
+use crate::internal_prelude::*;
+use radix_engine::types::*;
+use radix_engine_interface::api::node_modules::ModuleConfig;
+use radix_engine_interface::*;
+
+/// The number of resources whose behaviors are exercised by a single transaction.
+const RESOURCES_PER_EXERCISE_TRANSACTION: usize = 8;
+
+pub struct ResourceLifecycleScenarioConfig {
+    pub main_account: VirtualAccount,
+    pub occasional_recipient_account: VirtualAccount,
+}
+
+impl Default for ResourceLifecycleScenarioConfig {
+    fn default() -> Self {
+        Self::from_scenario_config(&ScenarioConfig::default())
+    }
+}
+
+impl FromScenarioConfig for ResourceLifecycleScenarioConfig {
+    fn from_scenario_config(config: &ScenarioConfig) -> Self {
+        Self {
+            main_account: config.account(0, secp256k1_account_1),
+            occasional_recipient_account: config.account(1, secp256k1_account_2),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct ResourceLifecycleScenarioState {
+    /// The badge which every role of the created resources requires.
+    pub badge: Option<ResourceAddress>,
+    /// The created resources, in creation order.
+    pub resources: Vec<LifecycleResource>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LifecycleResourceKind {
+    Fungible,
+    NonFungible,
+}
+
+impl LifecycleResourceKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Fungible => "fungible",
+            Self::NonFungible => "non-fungible",
+        }
+    }
+}
+
+/// A combination of the behaviors a resource can be created with.
+/// Each enabled behavior is restricted to the holders of the scenario's badge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResourceBehaviors {
+    pub mintable: bool,
+    pub burnable: bool,
+    pub recallable: bool,
+    pub freezable: bool,
+    pub restrict_withdraw: bool,
+    pub restrict_deposit: bool,
+}
+
+impl ResourceBehaviors {
+    pub fn all_combinations() -> Vec<Self> {
+        (0..64u8)
+            .map(|bits| Self {
+                mintable: bits & 1 != 0,
+                burnable: bits & 2 != 0,
+                recallable: bits & 4 != 0,
+                freezable: bits & 8 != 0,
+                restrict_withdraw: bits & 16 != 0,
+                restrict_deposit: bits & 32 != 0,
+            })
+            .collect()
+    }
+
+    /// A spaceless name for the combination, e.g. `mint-burn-restrict_deposit`.
+    pub fn name(&self) -> String {
+        let names: Vec<&str> = [
+            (self.mintable, "mint"),
+            (self.burnable, "burn"),
+            (self.recallable, "recall"),
+            (self.freezable, "freeze"),
+            (self.restrict_withdraw, "restrict_withdraw"),
+            (self.restrict_deposit, "restrict_deposit"),
+        ]
+        .into_iter()
+        .filter_map(|(enabled, name)| enabled.then_some(name))
+        .collect();
+        if names.is_empty() {
+            "none".to_string()
+        } else {
+            names.join("-")
+        }
+    }
+
+    pub fn fungible_roles(&self, badge: ResourceAddress) -> FungibleResourceRoles {
+        FungibleResourceRoles {
+            mint_roles: self.mintable.then(|| MintRoles {
+                minter: Some(rule!(require(badge))),
+                minter_updater: Some(rule!(deny_all)),
+            }),
+            burn_roles: self.burnable.then(|| BurnRoles {
+                burner: Some(rule!(require(badge))),
+                burner_updater: Some(rule!(deny_all)),
+            }),
+            freeze_roles: self.freezable.then(|| FreezeRoles {
+                freezer: Some(rule!(require(badge))),
+                freezer_updater: Some(rule!(deny_all)),
+            }),
+            recall_roles: self.recallable.then(|| RecallRoles {
+                recaller: Some(rule!(require(badge))),
+                recaller_updater: Some(rule!(deny_all)),
+            }),
+            withdraw_roles: self.restrict_withdraw.then(|| WithdrawRoles {
+                withdrawer: Some(rule!(require(badge))),
+                withdrawer_updater: Some(rule!(deny_all)),
+            }),
+            deposit_roles: self.restrict_deposit.then(|| DepositRoles {
+                depositor: Some(rule!(require(badge))),
+                depositor_updater: Some(rule!(deny_all)),
+            }),
+        }
+    }
+
+    pub fn non_fungible_roles(&self, badge: ResourceAddress) -> NonFungibleResourceRoles {
+        let FungibleResourceRoles {
+            mint_roles,
+            burn_roles,
+            freeze_roles,
+            recall_roles,
+            withdraw_roles,
+            deposit_roles,
+        } = self.fungible_roles(badge);
+        NonFungibleResourceRoles {
+            mint_roles,
+            burn_roles,
+            freeze_roles,
+            recall_roles,
+            withdraw_roles,
+            deposit_roles,
+            non_fungible_data_update_roles: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LifecycleResource {
+    pub kind: LifecycleResourceKind,
+    pub behaviors: ResourceBehaviors,
+    pub resource_address: ResourceAddress,
+    /// The vault of the main account holding the resource.
+    pub vault: InternalAddress,
+}
+
+impl LifecycleResource {
+    pub fn name(&self) -> String {
+        format!("{}--{}", self.kind.name(), self.behaviors.name())
+    }
+
+    /// The events which exercising the resource is expected to emit, with their emitters.
+    pub fn expected_events(&self) -> Vec<(Emitter, &'static str)> {
+        let resource_manager =
+            Emitter::Method(self.resource_address.into_node_id(), ModuleId::Main);
+        let vault = Emitter::Method(self.vault.into_node_id(), ModuleId::Main);
+        let mut events = vec![
+            (vault.clone(), "WithdrawEvent"),
+            (
+                Emitter::Method(self.resource_address.into_node_id(), ModuleId::Metadata),
+                "SetMetadataEvent",
+            ),
+        ];
+        if self.behaviors.mintable {
+            events.push((
+                resource_manager.clone(),
+                match self.kind {
+                    LifecycleResourceKind::Fungible => "MintFungibleResourceEvent",
+                    LifecycleResourceKind::NonFungible => "MintNonFungibleResourceEvent",
+                },
+            ));
+        }
+        if self.behaviors.burnable {
+            events.push((
+                resource_manager,
+                match self.kind {
+                    LifecycleResourceKind::Fungible => "BurnFungibleResourceEvent",
+                    LifecycleResourceKind::NonFungible => "BurnNonFungibleResourceEvent",
+                },
+            ));
+        }
+        if self.behaviors.recallable {
+            events.push((vault, "RecallEvent"));
+        }
+        events
+    }
+
+    fn exercise(
+        &self,
+        mut builder: ManifestBuilder,
+        config: &ResourceLifecycleScenarioConfig,
+    ) -> ManifestBuilder {
+        let resource_address = self.resource_address;
+        let main_account = config.main_account.address;
+
+        if self.behaviors.mintable {
+            builder = match self.kind {
+                LifecycleResourceKind::Fungible => {
+                    builder.mint_fungible(resource_address, dec!("10"))
+                }
+                LifecycleResourceKind::NonFungible => builder.mint_non_fungible(
+                    resource_address,
+                    btreemap!(NonFungibleLocalId::integer(4) => ()),
+                ),
+            }
+            .try_deposit_entire_worktop_or_abort(main_account, None);
+        }
+
+        if self.behaviors.burnable {
+            builder = match self.kind {
+                LifecycleResourceKind::Fungible => {
+                    builder.withdraw_from_account(main_account, resource_address, dec!("1"))
+                }
+                LifecycleResourceKind::NonFungible => builder.withdraw_non_fungibles_from_account(
+                    main_account,
+                    resource_address,
+                    [NonFungibleLocalId::integer(1)],
+                ),
+            }
+            .burn_all_from_worktop(resource_address);
+        }
+
+        builder = match self.kind {
+            LifecycleResourceKind::Fungible => {
+                builder.withdraw_from_account(main_account, resource_address, dec!("1"))
+            }
+            LifecycleResourceKind::NonFungible => builder.withdraw_non_fungibles_from_account(
+                main_account,
+                resource_address,
+                [NonFungibleLocalId::integer(2)],
+            ),
+        }
+        .try_deposit_entire_worktop_or_abort(config.occasional_recipient_account.address, None);
+
+        if self.behaviors.recallable {
+            builder = builder
+                .recall(self.vault, dec!("1"))
+                .try_deposit_entire_worktop_or_abort(main_account, None);
+        }
+
+        if self.behaviors.freezable {
+            builder = builder
+                .freeze_withdraw(self.vault)
+                .freeze_deposit(self.vault)
+                .freeze_burn(self.vault)
+                .unfreeze_withdraw(self.vault)
+                .unfreeze_deposit(self.vault)
+                .unfreeze_burn(self.vault);
+        }
+
+        builder.set_metadata(resource_address, "lifecycle", "exercised".to_owned())
+    }
+}
+
+pub struct ResourceLifecycleScenarioCreator;
+
+impl ScenarioCreator for ResourceLifecycleScenarioCreator {
+    type Config = ResourceLifecycleScenarioConfig;
+
+    type State = ResourceLifecycleScenarioState;
+
+    fn create_with_config_and_state(
+        core: ScenarioCore,
+        config: Self::Config,
+        start_state: Self::State,
+    ) -> Box<dyn ScenarioInstance> {
+        let metadata = ScenarioMetadata {
+            logical_name: "resource_lifecycle",
+        };
+
+        let to_create: Vec<(LifecycleResourceKind, ResourceBehaviors)> = [
+            LifecycleResourceKind::Fungible,
+            LifecycleResourceKind::NonFungible,
+        ]
+        .into_iter()
+        .flat_map(|kind| {
+            ResourceBehaviors::all_combinations()
+                .into_iter()
+                .map(move |behaviors| (kind, behaviors))
+        })
+        .collect();
+        let exercise_transactions = (to_create.len() + RESOURCES_PER_EXERCISE_TRANSACTION - 1)
+            / RESOURCES_PER_EXERCISE_TRANSACTION;
+
+        #[allow(unused_variables)]
+        let mut builder = ScenarioBuilder::new(core, metadata, config, start_state)
+            .successful_transaction_with_result_handler(
+                |core, config, state| {
+                    core.next_transaction_with_faucet_lock_fee(
+                        "badge-create",
+                        |builder| {
+                            builder
+                                .create_fungible_resource(
+                                    OwnerRole::None,
+                                    false,
+                                    0,
+                                    FungibleResourceRoles::default(),
+                                    metadata!(),
+                                    Some(dec!("1")),
+                                )
+                                .try_deposit_entire_worktop_or_abort(
+                                    config.main_account.address,
+                                    None,
+                                )
+                        },
+                        vec![],
+                    )
+                },
+                |core, config, state, result| {
+                    state.badge = Some(result.new_resource_addresses()[0]);
+                    Ok(())
+                },
+            );
+
+        for (kind, behaviors) in to_create {
+            builder = builder.successful_transaction_with_result_handler(
+                move |core, config, state| {
+                    let badge = state.badge.unwrap();
+                    let resource_metadata = metadata! {
+                        roles {
+                            metadata_setter => rule!(require(badge));
+                            metadata_setter_updater => rule!(deny_all);
+                            metadata_locker => rule!(require(badge));
+                            metadata_locker_updater => rule!(deny_all);
+                        },
+                        init {
+                            "name" => format!("{} {}", kind.name(), behaviors.name()), updatable;
+                        }
+                    };
+                    core.next_transaction_with_faucet_lock_fee(
+                        &format!("{}-create--{}", kind.name(), behaviors.name()),
+                        |builder| {
+                            let builder = builder.create_proof_from_account_of_amount(
+                                config.main_account.address,
+                                badge,
+                                dec!("1"),
+                            );
+                            match kind {
+                                LifecycleResourceKind::Fungible => builder
+                                    .create_fungible_resource(
+                                        OwnerRole::None,
+                                        true,
+                                        18,
+                                        behaviors.fungible_roles(badge),
+                                        resource_metadata,
+                                        Some(dec!("100")),
+                                    ),
+                                LifecycleResourceKind::NonFungible => builder
+                                    .create_non_fungible_resource(
+                                        OwnerRole::None,
+                                        NonFungibleIdType::Integer,
+                                        true,
+                                        behaviors.non_fungible_roles(badge),
+                                        resource_metadata,
+                                        Some(btreemap!(
+                                            NonFungibleLocalId::integer(1) => (),
+                                            NonFungibleLocalId::integer(2) => (),
+                                            NonFungibleLocalId::integer(3) => (),
+                                        )),
+                                    ),
+                            }
+                            .try_deposit_entire_worktop_or_abort(config.main_account.address, None)
+                        },
+                        vec![&config.main_account.key],
+                    )
+                },
+                move |_, _, state, result| {
+                    state.resources.push(LifecycleResource {
+                        kind,
+                        behaviors,
+                        resource_address: result.new_resource_addresses()[0],
+                        vault: result.new_vault_addresses()[0],
+                    });
+                    Ok(())
+                },
+            );
+        }
+
+        for transaction_index in 0..exercise_transactions {
+            let first_resource = transaction_index * RESOURCES_PER_EXERCISE_TRANSACTION;
+            builder = builder.successful_transaction_with_result_handler(
+                move |core, config, state| {
+                    core.next_transaction_with_faucet_lock_fee(
+                        &format!("exercise-behaviors--{}", transaction_index + 1),
+                        |builder| {
+                            let mut builder = builder.create_proof_from_account_of_amount(
+                                config.main_account.address,
+                                state.badge.unwrap(),
+                                dec!("1"),
+                            );
+                            for resource in state
+                                .resources
+                                .iter()
+                                .skip(first_resource)
+                                .take(RESOURCES_PER_EXERCISE_TRANSACTION)
+                            {
+                                builder = resource.exercise(builder, config);
+                            }
+                            builder
+                        },
+                        vec![&config.main_account.key],
+                    )
+                },
+                move |_, _, state, result| {
+                    let emitted_events: IndexSet<(&Emitter, &str)> = result
+                        .application_events
+                        .iter()
+                        .map(|(EventTypeIdentifier(emitter, name), _)| (emitter, name.as_str()))
+                        .collect();
+                    for resource in state
+                        .resources
+                        .iter()
+                        .skip(first_resource)
+                        .take(RESOURCES_PER_EXERCISE_TRANSACTION)
+                    {
+                        for (emitter, name) in resource.expected_events() {
+                            if !emitted_events.contains(&(&emitter, name)) {
+                                return Err(ScenarioError::Custom(format!(
+                                    "{} did not emit the expected {}",
+                                    resource.name(),
+                                    name
+                                )));
+                            }
+                        }
+                    }
+                    Ok(())
+                },
+            );
+        }
+
+        builder.finalize(|_, config, state| {
+            let mut interesting_addresses = DescribedAddresses::new()
+                .add("main_account", &config.main_account)
+                .add(
+                    "occasional_recipient_account",
+                    &config.occasional_recipient_account,
+                )
+                .add("badge", state.badge.unwrap());
+            for resource in &state.resources {
+                interesting_addresses = interesting_addresses
+                    .add(resource.name(), resource.resource_address)
+                    .add(format!("{}--vault", resource.name()), resource.vault);
+            }
+            Ok(ScenarioOutput {
+                interesting_addresses,
+            })
+        })
+    }
+}
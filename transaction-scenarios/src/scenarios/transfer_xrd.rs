@@ -4,18 +4,33 @@ pub struct TransferXrdConfig {
     pub from_account: VirtualAccount,
     pub to_account_1: VirtualAccount,
     pub to_account_2: VirtualAccount,
+    /// Further accounts, each of which receives `amount` from the faucet.
+    pub additional_accounts: Vec<VirtualAccount>,
+    pub amount: Decimal,
 }
 
 impl Default for TransferXrdConfig {
     fn default() -> Self {
+        Self::from_scenario_config(&ScenarioConfig::default())
+    }
+}
+
+impl FromScenarioConfig for TransferXrdConfig {
+    fn from_scenario_config(config: &ScenarioConfig) -> Self {
         Self {
-            from_account: secp256k1_account_1(),
-            to_account_1: secp256k1_account_2(),
-            to_account_2: ed25519_account_3(),
+            from_account: config.account(0, secp256k1_account_1),
+            to_account_1: config.account(1, secp256k1_account_2),
+            to_account_2: config.account(2, ed25519_account_3),
+            additional_accounts: config.additional_accounts(),
+            amount: config.transfer_amount,
         }
     }
 }
 
+/// The number of additional accounts funded by each transaction, which must all be funded by a
+/// single faucet withdrawal.
+const ADDITIONAL_ACCOUNTS_PER_TRANSACTION: usize = 10;
+
 pub enum TransferXrdScenarioCreator {}
 
 impl ScenarioCreator for TransferXrdScenarioCreator {
@@ -31,8 +46,12 @@ impl ScenarioCreator for TransferXrdScenarioCreator {
             logical_name: "transfer_xrd",
         };
 
+        let additional_account_batches =
+            (config.additional_accounts.len() + ADDITIONAL_ACCOUNTS_PER_TRANSACTION - 1)
+                / ADDITIONAL_ACCOUNTS_PER_TRANSACTION;
+
         #[allow(unused_variables)]
-        ScenarioBuilder::new(core, metadata, config, start_state)
+        let mut builder = ScenarioBuilder::new(core, metadata, config, start_state)
             .successful_transaction(|core, config, state| {
                 core.next_transaction_free_xrd_from_faucet(config.from_account.address)
             })
@@ -41,8 +60,8 @@ impl ScenarioCreator for TransferXrdScenarioCreator {
                     "transfer--try_deposit_or_abort",
                     |builder| {
                         builder
-                            .withdraw_from_account(config.from_account.address, XRD, dec!(1))
-                            .take_from_worktop(XRD, dec!(1), "xrd")
+                            .withdraw_from_account(config.from_account.address, XRD, config.amount)
+                            .take_from_worktop(XRD, config.amount, "xrd")
                             .try_deposit_or_abort(config.to_account_1.address, None, "xrd")
                             .done()
                     },
@@ -54,8 +73,8 @@ impl ScenarioCreator for TransferXrdScenarioCreator {
                     "transfer--try_deposit_or_refund",
                     |builder| {
                         builder
-                            .withdraw_from_account(config.from_account.address, XRD, dec!(1))
-                            .take_from_worktop(XRD, dec!(1), "xrd")
+                            .withdraw_from_account(config.from_account.address, XRD, config.amount)
+                            .take_from_worktop(XRD, config.amount, "xrd")
                             .try_deposit_or_refund(config.to_account_1.address, None, "xrd")
                             .done()
                     },
@@ -67,7 +86,7 @@ impl ScenarioCreator for TransferXrdScenarioCreator {
                     "transfer--try_deposit_batch_or_abort",
                     |builder| {
                         builder
-                            .withdraw_from_account(config.from_account.address, XRD, dec!(1))
+                            .withdraw_from_account(config.from_account.address, XRD, config.amount)
                             .try_deposit_entire_worktop_or_abort(config.to_account_1.address, None)
                             .done()
                     },
@@ -79,7 +98,7 @@ impl ScenarioCreator for TransferXrdScenarioCreator {
                     "transfer--try_deposit_batch_or_refund",
                     |builder| {
                         builder
-                            .withdraw_from_account(config.from_account.address, XRD, dec!(1))
+                            .withdraw_from_account(config.from_account.address, XRD, config.amount)
                             .try_deposit_entire_worktop_or_refund(config.to_account_1.address, None)
                             .done()
                     },
@@ -91,7 +110,7 @@ impl ScenarioCreator for TransferXrdScenarioCreator {
                     "self-transfer--deposit_batch",
                     |builder| {
                         builder
-                            .withdraw_from_account(config.from_account.address, XRD, dec!(1))
+                            .withdraw_from_account(config.from_account.address, XRD, config.amount)
                             .deposit_batch(config.from_account.address)
                             .done()
                     },
@@ -103,22 +122,54 @@ impl ScenarioCreator for TransferXrdScenarioCreator {
                     "multi-transfer--deposit_batch",
                     |builder| {
                         builder
-                            .withdraw_from_account(config.from_account.address, XRD, dec!(1))
+                            .withdraw_from_account(config.from_account.address, XRD, config.amount)
                             .try_deposit_entire_worktop_or_abort(config.to_account_1.address, None)
-                            .withdraw_from_account(config.from_account.address, XRD, dec!(1))
+                            .withdraw_from_account(config.from_account.address, XRD, config.amount)
                             .try_deposit_entire_worktop_or_abort(config.to_account_2.address, None)
                             .done()
                     },
                     vec![&config.from_account.key],
                 )
+            });
+
+        for batch_index in 0..additional_account_batches {
+            builder = builder.successful_transaction(move |core, config, _| {
+                core.next_transaction_with_faucet_lock_fee_fallible(
+                    "multi-transfer--additional_accounts",
+                    |mut builder| {
+                        builder = builder.get_free_xrd_from_faucet();
+                        for account in config
+                            .additional_accounts
+                            .chunks(ADDITIONAL_ACCOUNTS_PER_TRANSACTION)
+                            .nth(batch_index)
+                            .unwrap_or_default()
+                        {
+                            let bucket = builder.generate_bucket_name("xrd");
+                            builder = builder
+                                .take_from_worktop(XRD, config.amount, &bucket)
+                                .try_deposit_or_abort(account.address, None, bucket);
+                        }
+                        builder
+                            .try_deposit_entire_worktop_or_abort(config.from_account.address, None)
+                            .done()
+                    },
+                    vec![],
+                )
+            });
+        }
+
+        builder.finalize(|core, config, state| -> Result<_, ScenarioError> {
+            let mut interesting_addresses = DescribedAddresses::new()
+                .add("from_account", config.from_account.address)
+                .add("to_account_1", config.to_account_1.address)
+                .add("to_account_2", config.to_account_2.address);
+            for (index, account) in config.additional_accounts.iter().enumerate() {
+                interesting_addresses =
+                    interesting_addresses.add(format!("additional_account_{}", index), account);
+            }
+            Ok(ScenarioOutput {
+                interesting_addresses,
             })
-            .finalize(|core, config, state| -> Result<_, ScenarioError> {
-                Ok(ScenarioOutput {
-                    interesting_addresses: DescribedAddresses::new()
-                        .add("from_account", config.from_account.address)
-                        .add("to_account_1", config.to_account_1.address)
-                        .add("to_account_2", config.to_account_2.address),
-                })
-            })
+        })
     }
 }
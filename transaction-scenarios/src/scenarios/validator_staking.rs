@@ -0,0 +1,367 @@
+use crate::internal_prelude::*;
+use radix_engine::blueprints::consensus_manager::ValidatorError;
+use radix_engine::errors::{ApplicationError, RuntimeError};
+use radix_engine::types::*;
+use radix_engine_interface::blueprints::consensus_manager::*;
+
+/// The amount of XRD the owner stakes to their own validator.
+const OWNER_STAKE_AMOUNT: Decimal = dec!("500");
+
+pub struct ValidatorStakingScenarioConfig {
+    pub owner_account: VirtualAccount,
+    pub validator_key: Secp256k1PublicKey,
+    /// The accounts delegating stake to the validator, the `n`-th of which stakes `n * 100` XRD.
+    pub staker_accounts: Vec<VirtualAccount>,
+}
+
+impl Default for ValidatorStakingScenarioConfig {
+    fn default() -> Self {
+        Self::from_scenario_config(&ScenarioConfig::default())
+    }
+}
+
+impl FromScenarioConfig for ValidatorStakingScenarioConfig {
+    fn from_scenario_config(config: &ScenarioConfig) -> Self {
+        Self {
+            owner_account: config.account(0, secp256k1_account_1),
+            validator_key: Secp256k1PrivateKey::from_u64(46110)
+                .expect("Should be valid")
+                .public_key(),
+            staker_accounts: vec![
+                config.account(1, secp256k1_account_2),
+                config.account(2, secp256k1_account_3),
+                config.account(3, ed25519_account_3),
+            ],
+        }
+    }
+}
+
+impl ValidatorStakingScenarioConfig {
+    fn stake_amount(staker_index: usize) -> Decimal {
+        dec!("100") * (staker_index + 1)
+    }
+
+    fn unstake_amount(staker_index: usize) -> Decimal {
+        // The unstakes happen before any emissions, so stake units are still worth one XRD each
+        Self::stake_amount(staker_index) / 2
+    }
+}
+
+#[derive(Default)]
+pub struct ValidatorStakingScenarioState {
+    pub validator: Option<ComponentAddress>,
+    pub stake_unit: Option<ResourceAddress>,
+    pub claim_nft: Option<ResourceAddress>,
+}
+
+impl ValidatorStakingScenarioState {
+    fn owner_badge_id(&self) -> NonFungibleLocalId {
+        NonFungibleLocalId::bytes(self.validator.unwrap().as_node_id().0).unwrap()
+    }
+}
+
+/// Checks that the validator emitted all of the given events.
+fn check_validator_events(
+    result: &CommitResult,
+    validator: ComponentAddress,
+    expected_events: &[&str],
+) -> Result<(), ScenarioError> {
+    let emitter = Emitter::Method(validator.into_node_id(), ModuleId::Main);
+    for expected_event in expected_events {
+        if !result
+            .application_events
+            .iter()
+            .any(|(EventTypeIdentifier(event_emitter, name), _)| {
+                event_emitter == &emitter && name == expected_event
+            })
+        {
+            return Err(ScenarioError::Custom(format!(
+                "The validator did not emit the expected {}",
+                expected_event
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Creates and registers a validator, stakes to it as its owner and from several accounts, and
+/// unstakes part of the delegated stake.
+///
+/// An attempt to claim the unstaked XRD before the unlock epoch is checked to fail. The network is
+/// then moved on to the next epoch, in which the validator joins the active set and the stakers
+/// claim their XRD - and on to the one after, when the validator is checked to receive emissions.
+pub struct ValidatorStakingScenarioCreator;
+
+impl ScenarioCreator for ValidatorStakingScenarioCreator {
+    type Config = ValidatorStakingScenarioConfig;
+    type State = ValidatorStakingScenarioState;
+
+    fn create_with_config_and_state(
+        core: ScenarioCore,
+        config: Self::Config,
+        start_state: Self::State,
+    ) -> Box<dyn ScenarioInstance> {
+        let metadata = ScenarioMetadata {
+            logical_name: "validator_staking",
+        };
+
+        let staker_count = config.staker_accounts.len();
+
+        #[allow(unused_variables)]
+        let mut builder = ScenarioBuilder::new(core, metadata, config, start_state)
+            .successful_transaction(|core, config, state| {
+                core.next_transaction_free_xrd_from_faucet(config.owner_account.address)
+            })
+            .successful_transaction_with_result_handler(
+                |core, config, state| {
+                    core.next_transaction_with_faucet_lock_fee(
+                        "validator-create",
+                        |builder| {
+                            builder
+                                .get_free_xrd_from_faucet()
+                                .take_all_from_worktop(XRD, "creation_fee")
+                                .create_validator(config.validator_key, dec!("0.1"), "creation_fee")
+                                .try_deposit_entire_worktop_or_abort(
+                                    config.owner_account.address,
+                                    None,
+                                )
+                        },
+                        vec![],
+                    )
+                },
+                |core, config, state, result| {
+                    state.validator = Some(result.new_component_addresses()[0]);
+                    for resource_address in result.new_resource_addresses() {
+                        if resource_address.is_fungible() {
+                            state.stake_unit = Some(*resource_address);
+                        } else {
+                            state.claim_nft = Some(*resource_address);
+                        }
+                    }
+                    Ok(())
+                },
+            )
+            .successful_transaction_with_result_handler(
+                |core, config, state| {
+                    let validator = state.validator.unwrap();
+                    core.next_transaction_with_faucet_lock_fee(
+                        "validator-register",
+                        |builder| {
+                            builder
+                                .create_proof_from_account_of_non_fungibles(
+                                    config.owner_account.address,
+                                    VALIDATOR_OWNER_BADGE,
+                                    [state.owner_badge_id()],
+                                )
+                                .register_validator(validator)
+                                .call_method(
+                                    validator,
+                                    VALIDATOR_UPDATE_ACCEPT_DELEGATED_STAKE_IDENT,
+                                    (true,),
+                                )
+                        },
+                        vec![&config.owner_account.key],
+                    )
+                },
+                |core, config, state, result| {
+                    check_validator_events(
+                        result,
+                        state.validator.unwrap(),
+                        &[
+                            "RegisterValidatorEvent",
+                            "UpdateAcceptingStakeDelegationStateEvent",
+                        ],
+                    )
+                },
+            )
+            .successful_transaction_with_result_handler(
+                |core, config, state| {
+                    let validator = state.validator.unwrap();
+                    core.next_transaction_with_faucet_lock_fee(
+                        "validator-stake--owner",
+                        |builder| {
+                            builder
+                                .create_proof_from_account_of_non_fungibles(
+                                    config.owner_account.address,
+                                    VALIDATOR_OWNER_BADGE,
+                                    [state.owner_badge_id()],
+                                )
+                                .withdraw_from_account(
+                                    config.owner_account.address,
+                                    XRD,
+                                    OWNER_STAKE_AMOUNT,
+                                )
+                                .take_all_from_worktop(XRD, "stake")
+                                .stake_validator_as_owner(validator, "stake")
+                                .try_deposit_entire_worktop_or_abort(
+                                    config.owner_account.address,
+                                    None,
+                                )
+                        },
+                        vec![&config.owner_account.key],
+                    )
+                },
+                |core, config, state, result| {
+                    check_validator_events(result, state.validator.unwrap(), &["StakeEvent"])
+                },
+            )
+            .successful_transaction(|core, config, state| {
+                core.next_transaction_with_faucet_lock_fee(
+                    "stakers-top-up",
+                    |mut builder| {
+                        builder = builder.get_free_xrd_from_faucet();
+                        for (index, staker) in config.staker_accounts.iter().enumerate() {
+                            let bucket = builder.generate_bucket_name("xrd");
+                            builder = builder
+                                .take_from_worktop(
+                                    XRD,
+                                    ValidatorStakingScenarioConfig::stake_amount(index),
+                                    &bucket,
+                                )
+                                .try_deposit_or_abort(staker.address, None, bucket);
+                        }
+                        builder
+                            .try_deposit_entire_worktop_or_abort(config.owner_account.address, None)
+                    },
+                    vec![],
+                )
+            });
+
+        for staker_index in 0..staker_count {
+            builder = builder.successful_transaction_with_result_handler(
+                move |core, config, state| {
+                    let staker = &config.staker_accounts[staker_index];
+                    core.next_transaction_with_faucet_lock_fee(
+                        &format!("validator-stake--staker-{}", staker_index + 1),
+                        |builder| {
+                            builder
+                                .withdraw_from_account(
+                                    staker.address,
+                                    XRD,
+                                    ValidatorStakingScenarioConfig::stake_amount(staker_index),
+                                )
+                                .take_all_from_worktop(XRD, "stake")
+                                .stake_validator(state.validator.unwrap(), "stake")
+                                .try_deposit_entire_worktop_or_abort(staker.address, None)
+                        },
+                        vec![&staker.key],
+                    )
+                },
+                |_, _, state, result| {
+                    check_validator_events(result, state.validator.unwrap(), &["StakeEvent"])
+                },
+            );
+        }
+
+        for staker_index in 0..staker_count {
+            builder = builder.successful_transaction_with_result_handler(
+                move |core, config, state| {
+                    let staker = &config.staker_accounts[staker_index];
+                    core.next_transaction_with_faucet_lock_fee(
+                        &format!("validator-unstake--staker-{}", staker_index + 1),
+                        |builder| {
+                            builder
+                                .withdraw_from_account(
+                                    staker.address,
+                                    state.stake_unit.unwrap(),
+                                    ValidatorStakingScenarioConfig::unstake_amount(staker_index),
+                                )
+                                .take_all_from_worktop(state.stake_unit.unwrap(), "stake_units")
+                                .unstake_validator(state.validator.unwrap(), "stake_units")
+                                .try_deposit_entire_worktop_or_abort(staker.address, None)
+                        },
+                        vec![&staker.key],
+                    )
+                },
+                |_, _, state, result| {
+                    check_validator_events(result, state.validator.unwrap(), &["UnstakeEvent"])
+                },
+            );
+        }
+
+        builder = builder
+            .failed_transaction_with_error_handler(
+                |core, config, state| {
+                    let staker = &config.staker_accounts[0];
+                    core.next_transaction_with_faucet_lock_fee(
+                        "validator-claim-xrd--before-unlock",
+                        |builder| {
+                            builder
+                                .withdraw_from_account(
+                                    staker.address,
+                                    state.claim_nft.unwrap(),
+                                    dec!("1"),
+                                )
+                                .take_all_from_worktop(state.claim_nft.unwrap(), "claim_nft")
+                                .claim_xrd(state.validator.unwrap(), "claim_nft")
+                                .try_deposit_entire_worktop_or_abort(staker.address, None)
+                        },
+                        vec![&staker.key],
+                    )
+                },
+                |_, _, _, error| match error {
+                    RuntimeError::ApplicationError(ApplicationError::ValidatorError(
+                        ValidatorError::EpochUnlockHasNotOccurredYet,
+                    )) => Ok(()),
+                    _ => Err(ScenarioError::Custom(format!(
+                        "Expected the claim to fail before the unlock epoch, but got {:?}",
+                        error
+                    ))),
+                },
+            )
+            .next_epoch();
+
+        for staker_index in 0..staker_count {
+            builder = builder.successful_transaction_with_result_handler(
+                move |core, config, state| {
+                    let staker = &config.staker_accounts[staker_index];
+                    core.next_transaction_with_faucet_lock_fee(
+                        &format!("validator-claim-xrd--staker-{}", staker_index + 1),
+                        |builder| {
+                            builder
+                                .withdraw_from_account(
+                                    staker.address,
+                                    state.claim_nft.unwrap(),
+                                    dec!("1"),
+                                )
+                                .take_all_from_worktop(state.claim_nft.unwrap(), "claim_nft")
+                                .claim_xrd(state.validator.unwrap(), "claim_nft")
+                                .try_deposit_entire_worktop_or_abort(staker.address, None)
+                        },
+                        vec![&staker.key],
+                    )
+                },
+                |_, _, state, result| {
+                    check_validator_events(result, state.validator.unwrap(), &["ClaimXrdEvent"])
+                },
+            );
+        }
+
+        builder
+            .next_epoch_with_result_handler(|_, _, state, result| {
+                check_validator_events(
+                    result,
+                    state.validator.unwrap(),
+                    &["ValidatorEmissionAppliedEvent"],
+                )
+            })
+            .finalize(|_, config, state| {
+                let mut interesting_addresses = DescribedAddresses::new()
+                    .add("owner_account", &config.owner_account)
+                    .add("validator", state.validator.unwrap())
+                    .add("validator_stake_unit", state.stake_unit.unwrap())
+                    .add("validator_claim_nft", state.claim_nft.unwrap())
+                    .add(
+                        "validator_owner_badge",
+                        NonFungibleGlobalId::new(VALIDATOR_OWNER_BADGE, state.owner_badge_id()),
+                    );
+                for (index, staker) in config.staker_accounts.iter().enumerate() {
+                    interesting_addresses =
+                        interesting_addresses.add(format!("staker_account_{}", index + 1), staker);
+                }
+                Ok(ScenarioOutput {
+                    interesting_addresses,
+                })
+            })
+    }
+}
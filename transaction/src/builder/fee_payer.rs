@@ -0,0 +1,132 @@
+use crate::internal_prelude::*;
+use radix_engine_interface::blueprints::account::*;
+
+/// Returns whether the instruction calls a method on a statically addressed account.
+fn is_account_method_call(instruction: &InstructionV1) -> bool {
+    match instruction {
+        InstructionV1::CallMethod {
+            address: DynamicGlobalAddress::Static(address),
+            ..
+        } => address.as_node_id().is_global_account(),
+        _ => false,
+    }
+}
+
+/// Returns whether the instruction locks a (non-contingent) fee from an account.
+fn is_fee_lock(instruction: &InstructionV1) -> bool {
+    match instruction {
+        InstructionV1::CallMethod { method_name, .. } => {
+            is_account_method_call(instruction)
+                && matches!(
+                    method_name.as_str(),
+                    ACCOUNT_LOCK_FEE_IDENT
+                        | ACCOUNT_LOCK_FEE_WITH_REFUND_IDENT
+                        | ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT
+                        | ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT
+                )
+        }
+        _ => false,
+    }
+}
+
+/// Returns whether the instruction is a plain `lock_fee` call on the given account.
+fn is_plain_fee_lock_from(instruction: &InstructionV1, account_address: ComponentAddress) -> bool {
+    match instruction {
+        InstructionV1::CallMethod {
+            address: DynamicGlobalAddress::Static(address),
+            method_name,
+            ..
+        } => {
+            address.as_node_id() == account_address.as_node_id()
+                && method_name.as_str() == ACCOUNT_LOCK_FEE_IDENT
+        }
+        _ => false,
+    }
+}
+
+/// Returns whether the instruction may come before a fee lock.
+///
+/// Only fee locks and the creation of proofs, which may be needed to authorize a fee lock, are
+/// allowed: anything else could fail or run out of the fee loan before the fee is locked.
+fn can_precede_fee_lock(instruction: &InstructionV1) -> bool {
+    match instruction {
+        InstructionV1::CallMethod { method_name, .. } => {
+            is_fee_lock(instruction)
+                || (is_account_method_call(instruction)
+                    && matches!(
+                        method_name.as_str(),
+                        ACCOUNT_CREATE_PROOF_OF_AMOUNT_IDENT
+                            | ACCOUNT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT
+                    ))
+        }
+        _ => false,
+    }
+}
+
+/// Inserts a fee lock from the given account right after the leading fee locks and proof
+/// creations of the instructions.
+///
+/// As locking a fee creates no bucket, proof or address, the ids allocated by the instructions
+/// which end up after it are unaffected.
+pub(crate) fn insert_fee_lock(
+    instructions: &mut Vec<InstructionV1>,
+    account_address: ComponentAddress,
+    amount: Decimal,
+) {
+    let index = instructions
+        .iter()
+        .position(|instruction| !can_precede_fee_lock(instruction))
+        .unwrap_or(instructions.len());
+    instructions.insert(
+        index,
+        InstructionV1::CallMethod {
+            address: account_address.into(),
+            method_name: ACCOUNT_LOCK_FEE_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&AccountLockFeeInput { amount }),
+        },
+    );
+}
+
+/// Makes the given account pay the fee, replacing any plain `lock_fee` call on it rather than
+/// locking its fee twice.
+///
+/// As locking a fee creates no bucket, proof or address, removing such calls leaves the ids
+/// allocated by the other instructions unaffected.
+pub(crate) fn set_fee_lock(
+    instructions: &mut Vec<InstructionV1>,
+    account_address: ComponentAddress,
+    amount: Decimal,
+) {
+    instructions.retain(|instruction| !is_plain_fee_lock_from(instruction, account_address));
+    insert_fee_lock(instructions, account_address, amount);
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum FeeLockError {
+    NoFeeLock,
+    FeeLockPrecededByOtherInstructions { instruction_index: usize },
+}
+
+/// Checks that the instructions lock a fee, and that every fee lock is only preceded by other
+/// fee locks and proof creations.
+pub(crate) fn check_fee_locks(instructions: &[InstructionV1]) -> Result<(), FeeLockError> {
+    let prefix_len = instructions
+        .iter()
+        .position(|instruction| !can_precede_fee_lock(instruction))
+        .unwrap_or(instructions.len());
+
+    if let Some(index) = instructions[prefix_len..]
+        .iter()
+        .position(is_fee_lock)
+        .map(|index| prefix_len + index)
+    {
+        return Err(FeeLockError::FeeLockPrecededByOtherInstructions {
+            instruction_index: index,
+        });
+    }
+    if !instructions[..prefix_len].iter().any(is_fee_lock) {
+        return Err(FeeLockError::NoFeeLock);
+    }
+
+    Ok(())
+}
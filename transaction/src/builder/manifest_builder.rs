@@ -1,3 +1,5 @@
+use super::fee_payer::insert_fee_lock;
+use super::strict_worktop::WorktopTracker;
 use crate::internal_prelude::*;
 use crate::manifest::decompiler::decompile_with_known_naming;
 use crate::manifest::decompiler::ManifestObjectNames;
@@ -10,7 +12,9 @@ use radix_engine_interface::blueprints::access_controller::*;
 use radix_engine_interface::blueprints::account::*;
 use radix_engine_interface::blueprints::consensus_manager::*;
 use radix_engine_interface::blueprints::identity::*;
+use radix_engine_interface::blueprints::locker::*;
 use radix_engine_interface::blueprints::package::*;
+use radix_engine_interface::blueprints::pool::*;
 use radix_engine_interface::blueprints::resource::*;
 
 /// A manifest builder for use in tests.
@@ -86,6 +90,8 @@ pub struct ManifestBuilder {
     instructions: Vec<InstructionV1>,
     /// Blobs
     blobs: BTreeMap<Hash, Vec<u8>>,
+    /// Set in strict worktop mode.
+    worktop_tracker: Option<WorktopTracker>,
 }
 
 pub struct NewSymbols {
@@ -102,9 +108,28 @@ impl ManifestBuilder {
             registrar: ManifestNameRegistrar::new(),
             instructions: Vec::new(),
             blobs: BTreeMap::default(),
+            worktop_tracker: None,
         }
     }
 
+    /// Enables strict worktop mode, in which the builder tracks the resources withdrawn from
+    /// accounts onto the worktop and automatically inserts:
+    /// * `ASSERT_WORKTOP_CONTAINS` instructions for them before each deposit, of the worktop or
+    ///   of buckets, and before taking all of one of them from the worktop,
+    /// * an assertion that the worktop is empty at the end of the manifest.
+    ///
+    /// # Panics
+    /// Panics if any instruction has already been added, as the resources it put on the worktop
+    /// wouldn't be tracked.
+    pub fn with_strict_worktop(mut self) -> Self {
+        assert!(
+            self.instructions.is_empty(),
+            "Strict worktop mode must be enabled before adding any instruction"
+        );
+        self.worktop_tracker = Some(WorktopTracker::default());
+        self
+    }
+
     pub fn name_lookup(&self) -> ManifestNameLookup {
         self.registrar.name_lookup()
     }
@@ -209,6 +234,11 @@ impl ManifestBuilder {
     /// An internal method which is used by other methods - the callers are expected to handle
     /// registering buckets/proofs/etc and consuming them
     fn add_instruction(mut self, instruction: InstructionV1) -> Self {
+        if let Some(tracker) = &mut self.worktop_tracker {
+            self.instructions
+                .extend(tracker.assertions_before(&instruction));
+            tracker.process_instruction(&instruction);
+        }
         self.instructions.push(instruction);
         self
     }
@@ -354,6 +384,23 @@ impl ManifestBuilder {
         })
     }
 
+    /// Asserts that worktop contains no resources other than the given ones.
+    pub fn assert_worktop_resources_only<R: ResolvableResourceAddress>(
+        self,
+        resource_addresses: impl IntoIterator<Item = R>,
+    ) -> Self {
+        let resource_addresses = resource_addresses
+            .into_iter()
+            .map(|resource_address| resource_address.resolve_static(&self.registrar))
+            .collect();
+        self.add_instruction(InstructionV1::AssertWorktopResourcesOnly { resource_addresses })
+    }
+
+    /// Outputs the amount of each resource on the worktop at this point, in the receipt.
+    pub fn yield_worktop_contents(self) -> Self {
+        self.add_instruction(InstructionV1::YieldWorktopContents)
+    }
+
     /// Pops the most recent proof from auth zone.
     pub fn pop_from_auth_zone(self, new_proof: impl NewManifestProof) -> Self {
         new_proof.register(&self.registrar);
@@ -366,6 +413,18 @@ impl ManifestBuilder {
         self.add_instruction(InstructionV1::PushToAuthZone { proof_id: proof })
     }
 
+    /// Pushes several proofs onto the auth zone at once
+    pub fn push_composite_to_auth_zone(
+        self,
+        proofs: impl IntoIterator<Item = impl ExistingManifestProof>,
+    ) -> Self {
+        let proof_ids = proofs
+            .into_iter()
+            .map(|proof| proof.mark_consumed(&self.registrar))
+            .collect();
+        self.add_instruction(InstructionV1::PushCompositeToAuthZone { proof_ids })
+    }
+
     /// Creates proof from the auth zone by amount.
     pub fn create_proof_from_auth_zone_of_amount(
         self,
@@ -484,6 +543,26 @@ impl ManifestBuilder {
         })
     }
 
+    /// Asserts that the given component is an instance of the given blueprint.
+    pub fn assert_component_blueprint(
+        self,
+        component_address: ComponentAddress,
+        package_address: impl ResolvablePackageAddress,
+        blueprint_name: impl Into<String>,
+    ) -> Self {
+        let package_address = package_address.resolve_static(&self.registrar);
+        self.add_instruction(InstructionV1::AssertComponentBlueprint {
+            component_address,
+            package_address,
+            blueprint_name: blueprint_name.into(),
+        })
+    }
+
+    /// Limits the execution cost units which each of the following instructions may consume.
+    pub fn set_instruction_cost_limit(self, cost_units: u32) -> Self {
+        self.add_instruction(InstructionV1::SetInstructionCostLimit { cost_units })
+    }
+
     /// Drops a proof.
     pub fn drop_proof(self, proof: impl ExistingManifestProof) -> Self {
         let proof = proof.mark_consumed(&self.registrar);
@@ -999,6 +1078,25 @@ impl ManifestBuilder {
         )
     }
 
+    /// Sets several roles of a module in a single call, which is authorized once against the
+    /// updater roles common to all of them.
+    pub fn set_roles(
+        self,
+        address: impl ResolvableGlobalAddress,
+        role_module: ModuleId,
+        roles: IndexMap<RoleKey, AccessRule>,
+    ) -> Self {
+        self.call_module_method(
+            address,
+            ModuleId::RoleAssignment,
+            ROLE_ASSIGNMENT_SET_BATCH_IDENT,
+            RoleAssignmentSetBatchInput {
+                module: role_module,
+                roles,
+            },
+        )
+    }
+
     pub fn get_role(
         self,
         address: impl ResolvableGlobalAddress,
@@ -1136,6 +1234,51 @@ impl ManifestBuilder {
         })
     }
 
+    pub fn set_blueprint_royalty_claimer(
+        self,
+        package_address: impl ResolvablePackageAddress,
+        blueprint_name: impl Into<String>,
+        claimer: AccessRule,
+    ) -> Self {
+        let address = package_address.resolve(&self.registrar);
+        self.add_instruction(InstructionV1::CallMethod {
+            address: address.into(),
+            method_name: PACKAGE_SET_BLUEPRINT_ROYALTY_CLAIMER_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&PackageSetBlueprintRoyaltyClaimerManifestInput {
+                blueprint_name: blueprint_name.into(),
+                claimer,
+            }),
+        })
+    }
+
+    pub fn claim_package_royalties_for_blueprint(
+        self,
+        package_address: impl ResolvablePackageAddress,
+        blueprint_name: impl Into<String>,
+    ) -> Self {
+        let address = package_address.resolve(&self.registrar);
+        self.add_instruction(InstructionV1::CallMethod {
+            address: address.into(),
+            method_name: PACKAGE_CLAIM_ROYALTIES_FOR_BLUEPRINT_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&PackageClaimRoyaltiesForBlueprintManifestInput {
+                blueprint_name: blueprint_name.into(),
+            }),
+        })
+    }
+
+    pub fn deprecate_package(
+        self,
+        package_address: impl ResolvablePackageAddress,
+        successor: PackageAddress,
+    ) -> Self {
+        let address = package_address.resolve(&self.registrar);
+        self.add_instruction(InstructionV1::CallMethod {
+            address: address.into(),
+            method_name: PACKAGE_DEPRECATE_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(&PackageDeprecateManifestInput { successor }),
+        })
+    }
+
     pub fn set_component_royalty(
         self,
         component_address: impl ResolvableComponentAddress,
@@ -1409,6 +1552,20 @@ impl ManifestBuilder {
             .burn_resource(bucket)
     }
 
+    /// Takes resource from worktop, by amount, and burns it in a single instruction.
+    pub fn burn_resource_from_worktop(
+        self,
+        resource_address: impl ResolvableResourceAddress,
+        amount: impl ResolvableDecimal,
+    ) -> Self {
+        let resource_address = resource_address.resolve_static(&self.registrar);
+        let amount = amount.resolve();
+        self.add_instruction(InstructionV1::BurnResourceFromWorktop {
+            resource_address,
+            amount,
+        })
+    }
+
     pub fn burn_all_from_worktop(self, resource_address: impl ResolvableResourceAddress) -> Self {
         let resource_address = resource_address.resolve_static(&self.registrar);
 
@@ -1463,6 +1620,40 @@ impl ManifestBuilder {
         })
     }
 
+    /// Mints non-fungibles, storing the given blobs alongside them under the hash of their content.
+    /// The non-fungible data can then refer to a blob by its hash.
+    pub fn mint_non_fungible_with_blobs<
+        T: IntoIterator<Item = (NonFungibleLocalId, V)>,
+        V: ManifestEncode,
+    >(
+        mut self,
+        resource_address: impl ResolvableResourceAddress,
+        entries: T,
+        blobs: Vec<Vec<u8>>,
+    ) -> Self {
+        let address = resource_address.resolve(&self.registrar);
+
+        let entries = entries
+            .into_iter()
+            .map(|(id, e)| (id, (to_manifest_value_and_unwrap!(&e),)))
+            .collect();
+        let blobs = blobs
+            .into_iter()
+            .map(|blob| {
+                let blob_ref = self.add_blob(blob);
+                (Hash(blob_ref.0), blob_ref)
+            })
+            .collect();
+
+        self.add_instruction(InstructionV1::CallMethod {
+            address: address.into(),
+            method_name: NON_FUNGIBLE_RESOURCE_MANAGER_MINT_WITH_BLOBS_IDENT.to_string(),
+            args: to_manifest_value_and_unwrap!(
+                &NonFungibleResourceManagerMintWithBlobsManifestInput { entries, blobs }
+            ),
+        })
+    }
+
     pub fn mint_ruid_non_fungible<T: IntoIterator<Item = V>, V: ManifestEncode>(
         self,
         resource_address: impl ResolvableResourceAddress,
@@ -1680,6 +1871,44 @@ impl ManifestBuilder {
         })
     }
 
+    /// Locks a fee from the XRD vault of an account, ahead of any instruction which could fail or
+    /// exhaust the fee loan before the fee is locked.
+    ///
+    /// Unlike [`Self::lock_fee`], this can be called at any point while building the manifest: the
+    /// fee lock is inserted right after the leading fee locks and proof creations. As it may end
+    /// up before any address allocation, the account must be given by its static address.
+    pub fn lock_fee_auto(
+        mut self,
+        account_address: ComponentAddress,
+        amount: impl ResolvableDecimal,
+    ) -> Self {
+        insert_fee_lock(&mut self.instructions, account_address, amount.resolve());
+        self
+    }
+
+    /// Locks a fee from the XRD vault of an account, refunding whatever isn't used to the given
+    /// XRD vault rather than to the account - e.g. so that a fee sponsor gets its refunds in a
+    /// treasury component.
+    pub fn lock_fee_with_refund(
+        self,
+        account_address: impl ResolvableComponentAddress,
+        amount: impl ResolvableDecimal,
+        refund_vault: InternalAddress,
+    ) -> Self {
+        let address = account_address.resolve(&self.registrar);
+        let amount = amount.resolve();
+        let args = to_manifest_value_and_unwrap!(&AccountLockFeeWithRefundInput {
+            amount,
+            refund_vault,
+        });
+
+        self.add_instruction(InstructionV1::CallMethod {
+            address: address.into(),
+            method_name: ACCOUNT_LOCK_FEE_WITH_REFUND_IDENT.to_string(),
+            args,
+        })
+    }
+
     pub fn lock_contingent_fee(
         self,
         account_address: impl ResolvableComponentAddress,
@@ -1991,6 +2220,56 @@ impl ManifestBuilder {
         )
     }
 
+    pub fn set_default_deposit_rule(
+        self,
+        account_address: impl ResolvableComponentAddress,
+        default: DefaultDepositRule,
+    ) -> Self {
+        let address = account_address.resolve(&self.registrar);
+
+        self.call_method(
+            address,
+            ACCOUNT_SET_DEFAULT_DEPOSIT_RULE_IDENT,
+            AccountSetDefaultDepositRuleInput { default },
+        )
+    }
+
+    /// Adds the resource to the account's allow list (with [`ResourcePreference::Allowed`]) or
+    /// deny list (with [`ResourcePreference::Disallowed`]).
+    pub fn set_resource_preference(
+        self,
+        account_address: impl ResolvableComponentAddress,
+        resource_address: impl ResolvableResourceAddress,
+        resource_preference: ResourcePreference,
+    ) -> Self {
+        let address = account_address.resolve(&self.registrar);
+        let resource_address = resource_address.resolve_static(&self.registrar);
+
+        self.call_method(
+            address,
+            ACCOUNT_SET_RESOURCE_PREFERENCE_IDENT,
+            AccountSetResourcePreferenceInput {
+                resource_address,
+                resource_preference,
+            },
+        )
+    }
+
+    pub fn remove_resource_preference(
+        self,
+        account_address: impl ResolvableComponentAddress,
+        resource_address: impl ResolvableResourceAddress,
+    ) -> Self {
+        let address = account_address.resolve(&self.registrar);
+        let resource_address = resource_address.resolve_static(&self.registrar);
+
+        self.call_method(
+            address,
+            ACCOUNT_REMOVE_RESOURCE_PREFERENCE_IDENT,
+            AccountRemoveResourcePreferenceInput { resource_address },
+        )
+    }
+
     pub fn create_access_controller(
         self,
         controlled_asset: impl ExistingManifestBucket,
@@ -2017,8 +2296,155 @@ impl ManifestBuilder {
         )
     }
 
+    pub fn create_account_locker(
+        self,
+        owner_role: OwnerRole,
+        storer_role: AccessRule,
+        recoverer_role: AccessRule,
+        claim_period_in_minutes: u32,
+    ) -> Self {
+        self.call_function(
+            LOCKER_PACKAGE,
+            ACCOUNT_LOCKER_BLUEPRINT,
+            ACCOUNT_LOCKER_INSTANTIATE_IDENT,
+            AccountLockerInstantiateManifestInput {
+                owner_role,
+                storer_role,
+                storer_updater_role: rule!(deny_all),
+                recoverer_role,
+                recoverer_updater_role: rule!(deny_all),
+                claim_period_in_minutes,
+                address_reservation: None,
+            },
+        )
+    }
+
+    /// Stores the bucket in the account locker for the claimant to claim. If `try_direct_send` is
+    /// true, the bucket is deposited into the claimant account instead, if the account accepts it.
+    pub fn store_in_account_locker(
+        self,
+        locker_address: impl ResolvableComponentAddress,
+        claimant: ComponentAddress,
+        bucket: impl ExistingManifestBucket,
+        try_direct_send: bool,
+    ) -> Self {
+        let address = locker_address.resolve(&self.registrar);
+        let bucket = bucket.mark_consumed(&self.registrar);
+
+        self.call_method(
+            address,
+            ACCOUNT_LOCKER_STORE_IDENT,
+            AccountLockerStoreManifestInput {
+                claimant,
+                bucket,
+                try_direct_send,
+            },
+        )
+    }
+
+    pub fn claim_from_account_locker(
+        self,
+        locker_address: impl ResolvableComponentAddress,
+        claimant: ComponentAddress,
+        resource_address: impl ResolvableResourceAddress,
+        amount: impl ResolvableDecimal,
+    ) -> Self {
+        let address = locker_address.resolve(&self.registrar);
+        let resource_address = resource_address.resolve_static(&self.registrar);
+        let amount = amount.resolve();
+
+        self.call_method(
+            address,
+            ACCOUNT_LOCKER_CLAIM_IDENT,
+            AccountLockerClaimManifestInput {
+                claimant,
+                resource_address,
+                amount,
+            },
+        )
+    }
+
+    pub fn recover_from_account_locker(
+        self,
+        locker_address: impl ResolvableComponentAddress,
+        claimant: ComponentAddress,
+        resource_address: impl ResolvableResourceAddress,
+        amount: impl ResolvableDecimal,
+    ) -> Self {
+        let address = locker_address.resolve(&self.registrar);
+        let resource_address = resource_address.resolve_static(&self.registrar);
+        let amount = amount.resolve();
+
+        self.call_method(
+            address,
+            ACCOUNT_LOCKER_RECOVER_IDENT,
+            AccountLockerRecoverManifestInput {
+                claimant,
+                resource_address,
+                amount,
+            },
+        )
+    }
+
+    /// Creates a pool of up to [`MULTI_RESOURCE_POOL_MAX_RESOURCES`] fungible resources.
+    pub fn create_multi_resource_pool(
+        self,
+        owner_role: OwnerRole,
+        pool_manager_rule: AccessRule,
+        resource_addresses: impl IntoIterator<Item = ResourceAddress>,
+    ) -> Self {
+        self.call_function(
+            POOL_PACKAGE,
+            MULTI_RESOURCE_POOL_BLUEPRINT,
+            MULTI_RESOURCE_POOL_INSTANTIATE_IDENT,
+            MultiResourcePoolInstantiateManifestInput {
+                owner_role,
+                pool_manager_rule,
+                resource_addresses: resource_addresses.into_iter().collect(),
+                address_reservation: None,
+            },
+        )
+    }
+
+    /// Contributes the buckets to the pool, in exchange for pool units. The change, if any, is
+    /// returned along with the pool units.
+    pub fn contribute_to_multi_resource_pool(
+        self,
+        pool_address: impl ResolvableComponentAddress,
+        buckets: impl IntoIterator<Item = impl ExistingManifestBucket>,
+    ) -> Self {
+        let address = pool_address.resolve(&self.registrar);
+        let buckets = buckets
+            .into_iter()
+            .map(|bucket| bucket.mark_consumed(&self.registrar))
+            .collect();
+
+        self.call_method(
+            address,
+            MULTI_RESOURCE_POOL_CONTRIBUTE_IDENT,
+            MultiResourcePoolContributeManifestInput { buckets },
+        )
+    }
+
+    /// Redeems the bucket of pool units for the proportional share of the resources of the pool.
+    pub fn redeem_from_multi_resource_pool(
+        self,
+        pool_address: impl ResolvableComponentAddress,
+        bucket: impl ExistingManifestBucket,
+    ) -> Self {
+        let address = pool_address.resolve(&self.registrar);
+        let bucket = bucket.mark_consumed(&self.registrar);
+
+        self.call_method(
+            address,
+            MULTI_RESOURCE_POOL_REDEEM_IDENT,
+            MultiResourcePoolRedeemManifestInput { bucket },
+        )
+    }
+
     /// Builds a transaction manifest.
-    pub fn build(self) -> TransactionManifestV1 {
+    pub fn build(mut self) -> TransactionManifestV1 {
+        self.instructions.extend(self.final_assertion());
         let manifest = TransactionManifestV1 {
             instructions: self.instructions,
             blobs: self.blobs,
@@ -2038,7 +2464,20 @@ impl ManifestBuilder {
         &self,
         network_definition: &NetworkDefinition,
     ) -> Result<String, DecompileError> {
-        decompile_with_known_naming(&self.instructions, network_definition, self.object_names())
+        let instructions: Vec<_> = self
+            .instructions
+            .iter()
+            .cloned()
+            .chain(self.final_assertion())
+            .collect();
+        decompile_with_known_naming(&instructions, network_definition, self.object_names())
+    }
+
+    /// The assertion that [`Self::build`] ends the manifest with, in strict worktop mode.
+    fn final_assertion(&self) -> Option<InstructionV1> {
+        self.worktop_tracker
+            .as_ref()
+            .map(|tracker| tracker.final_assertion())
     }
 }
 
@@ -1,5 +1,7 @@
+mod fee_payer;
 mod manifest_builder;
 mod manifest_namer;
+mod strict_worktop;
 mod transaction_builder;
 
 pub use manifest_builder::*;
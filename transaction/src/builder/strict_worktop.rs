@@ -0,0 +1,355 @@
+use crate::internal_prelude::*;
+use radix_engine_interface::blueprints::account::*;
+
+/// Tracks the resources the manifest being built is known to put on the worktop, to insert
+/// worktop assertions in strict worktop mode (see [`ManifestBuilder::with_strict_worktop`]).
+///
+/// The tracked amounts are lower bounds: resources returned by arbitrary calls are not
+/// tracked, so they can only make the assertions easier to satisfy.
+#[derive(Default)]
+pub(crate) struct WorktopTracker {
+    id_allocator: ManifestIdAllocator,
+    worktop: IndexMap<ResourceAddress, Decimal>,
+    buckets: IndexMap<ManifestBucket, Option<(ResourceAddress, Decimal)>>,
+}
+
+impl WorktopTracker {
+    /// Returns the assertions to insert before the given instruction.
+    ///
+    /// The worktop contents are asserted before any call taking the entire worktop (e.g. a
+    /// `deposit_batch`) or buckets (e.g. a `deposit`), and before taking all of a resource from
+    /// the worktop, as the bucket is usually deposited next. Other takes fail by themselves on
+    /// missing resources.
+    pub fn assertions_before(&self, instruction: &InstructionV1) -> Vec<InstructionV1> {
+        match instruction {
+            InstructionV1::TakeAllFromWorktop { resource_address } => self
+                .worktop
+                .get(resource_address)
+                .map(|amount| Self::assert_contains(*resource_address, *amount))
+                .into_iter()
+                .collect(),
+            InstructionV1::CallFunction { args, .. }
+            | InstructionV1::CallMethod { args, .. }
+            | InstructionV1::CallRoyaltyMethod { args, .. }
+            | InstructionV1::CallMetadataMethod { args, .. }
+            | InstructionV1::CallRoleAssignmentMethod { args, .. }
+            | InstructionV1::CallDirectVaultMethod { args, .. }
+                if contains_entire_worktop(args) || contains_bucket(args) =>
+            {
+                self.worktop
+                    .iter()
+                    .map(|(resource_address, amount)| {
+                        Self::assert_contains(*resource_address, *amount)
+                    })
+                    .collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Returns the assertion to end the manifest with, i.e. that the worktop is empty.
+    pub fn final_assertion(&self) -> InstructionV1 {
+        InstructionV1::AssertWorktopResourcesOnly {
+            resource_addresses: Vec::new(),
+        }
+    }
+
+    pub fn process_instruction(&mut self, instruction: &InstructionV1) {
+        match instruction {
+            InstructionV1::TakeFromWorktop {
+                resource_address,
+                amount,
+            } => {
+                self.remove(resource_address, *amount);
+                self.new_bucket(Some((*resource_address, *amount)));
+            }
+            InstructionV1::TakeNonFungiblesFromWorktop {
+                resource_address,
+                ids,
+            } => {
+                let amount = Decimal::from(ids.len());
+                self.remove(resource_address, amount);
+                self.new_bucket(Some((*resource_address, amount)));
+            }
+            InstructionV1::TakeAllFromWorktop { resource_address } => {
+                let taken = self
+                    .worktop
+                    .shift_remove(resource_address)
+                    .map(|amount| (*resource_address, amount));
+                self.new_bucket(taken);
+            }
+            InstructionV1::ReturnToWorktop { bucket_id } => {
+                if let Some(Some((resource_address, amount))) = self.buckets.remove(bucket_id) {
+                    self.add(resource_address, amount);
+                }
+            }
+            InstructionV1::BurnResource { bucket_id } => {
+                self.buckets.remove(bucket_id);
+            }
+            InstructionV1::BurnResourceFromWorktop {
+                resource_address,
+                amount,
+            } => {
+                self.remove(resource_address, *amount);
+            }
+            InstructionV1::CallMethod {
+                address,
+                method_name,
+                args,
+            } => {
+                self.process_call_args(args);
+                if let DynamicGlobalAddress::Static(address) = address {
+                    if address.as_node_id().is_global_account() {
+                        self.process_account_withdraw(method_name, args);
+                    }
+                }
+            }
+            InstructionV1::CallFunction { args, .. }
+            | InstructionV1::CallRoyaltyMethod { args, .. }
+            | InstructionV1::CallMetadataMethod { args, .. }
+            | InstructionV1::CallRoleAssignmentMethod { args, .. }
+            | InstructionV1::CallDirectVaultMethod { args, .. } => {
+                self.process_call_args(args);
+            }
+            _ => {}
+        }
+    }
+
+    fn process_account_withdraw(&mut self, method_name: &str, args: &ManifestValue) {
+        let withdrawn = match method_name {
+            ACCOUNT_WITHDRAW_IDENT => from_manifest_value::<AccountWithdrawInput>(args)
+                .ok()
+                .map(|input| (input.resource_address, input.amount)),
+            ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT => {
+                from_manifest_value::<AccountLockFeeAndWithdrawInput>(args)
+                    .ok()
+                    .map(|input| (input.resource_address, input.amount))
+            }
+            ACCOUNT_WITHDRAW_NON_FUNGIBLES_IDENT => {
+                from_manifest_value::<AccountWithdrawNonFungiblesInput>(args)
+                    .ok()
+                    .map(|input| (input.resource_address, Decimal::from(input.ids.len())))
+            }
+            ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT => {
+                from_manifest_value::<AccountLockFeeAndWithdrawNonFungiblesInput>(args)
+                    .ok()
+                    .map(|input| (input.resource_address, Decimal::from(input.ids.len())))
+            }
+            _ => None,
+        };
+        if let Some((resource_address, amount)) = withdrawn {
+            self.add(resource_address, amount);
+        }
+    }
+
+    /// Forgets the buckets passed to a call, and the worktop contents if the entire worktop is.
+    fn process_call_args(&mut self, args: &ManifestValue) {
+        if contains_entire_worktop(args) {
+            self.worktop.clear();
+        }
+        let mut buckets = Vec::new();
+        collect_buckets(args, &mut buckets);
+        for bucket_id in buckets {
+            self.buckets.remove(&bucket_id);
+        }
+    }
+
+    fn new_bucket(&mut self, contents: Option<(ResourceAddress, Decimal)>) {
+        let bucket_id = self.id_allocator.new_bucket_id();
+        self.buckets.insert(bucket_id, contents);
+    }
+
+    fn add(&mut self, resource_address: ResourceAddress, amount: Decimal) {
+        let tracked = self.worktop.entry(resource_address).or_default();
+        *tracked = tracked.checked_add(amount).unwrap_or(*tracked);
+    }
+
+    fn remove(&mut self, resource_address: &ResourceAddress, amount: Decimal) {
+        if let Some(tracked) = self.worktop.get_mut(resource_address) {
+            match tracked.checked_sub(amount) {
+                Some(remaining) if remaining.is_positive() => *tracked = remaining,
+                _ => {
+                    self.worktop.shift_remove(resource_address);
+                }
+            }
+        }
+    }
+
+    fn assert_contains(resource_address: ResourceAddress, amount: Decimal) -> InstructionV1 {
+        InstructionV1::AssertWorktopContains {
+            resource_address,
+            amount,
+        }
+    }
+}
+
+fn contains_entire_worktop(value: &ManifestValue) -> bool {
+    match value {
+        ManifestValue::Custom {
+            value: ManifestCustomValue::Expression(ManifestExpression::EntireWorktop),
+        } => true,
+        ManifestValue::Enum { fields, .. } | ManifestValue::Tuple { fields } => {
+            fields.iter().any(contains_entire_worktop)
+        }
+        ManifestValue::Array { elements, .. } => elements.iter().any(contains_entire_worktop),
+        ManifestValue::Map { entries, .. } => entries
+            .iter()
+            .any(|(key, value)| contains_entire_worktop(key) || contains_entire_worktop(value)),
+        _ => false,
+    }
+}
+
+fn contains_bucket(value: &ManifestValue) -> bool {
+    let mut buckets = Vec::new();
+    collect_buckets(value, &mut buckets);
+    !buckets.is_empty()
+}
+
+fn collect_buckets(value: &ManifestValue, buckets: &mut Vec<ManifestBucket>) {
+    match value {
+        ManifestValue::Custom {
+            value: ManifestCustomValue::Bucket(bucket_id),
+        } => buckets.push(*bucket_id),
+        ManifestValue::Enum { fields, .. } | ManifestValue::Tuple { fields } => {
+            for field in fields {
+                collect_buckets(field, buckets);
+            }
+        }
+        ManifestValue::Array { elements, .. } => {
+            for element in elements {
+                collect_buckets(element, buckets);
+            }
+        }
+        ManifestValue::Map { entries, .. } => {
+            for (key, value) in entries {
+                collect_buckets(key, buckets);
+                collect_buckets(value, buckets);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strict_worktop_inserts_assertions() {
+        let account = ComponentAddress::virtual_account_from_public_key(
+            &Secp256k1PrivateKey::from_u64(1).unwrap().public_key(),
+        );
+        let other_account = ComponentAddress::virtual_account_from_public_key(
+            &Secp256k1PrivateKey::from_u64(2).unwrap().public_key(),
+        );
+        let manifest = ManifestBuilder::new()
+            .with_strict_worktop()
+            .lock_fee(account, 10)
+            .withdraw_from_account(account, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(40), "bucket")
+            .try_deposit_or_abort(other_account, None, "bucket")
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build();
+
+        let assertions: Vec<_> = manifest
+            .instructions
+            .iter()
+            .filter(|instruction| {
+                matches!(
+                    instruction,
+                    InstructionV1::AssertWorktopContains { .. }
+                        | InstructionV1::AssertWorktopResourcesOnly { .. }
+                )
+            })
+            .collect();
+        let expected_assertion = InstructionV1::AssertWorktopContains {
+            resource_address: XRD,
+            amount: dec!(60),
+        };
+        assert_eq!(
+            assertions,
+            vec![
+                &expected_assertion,
+                &expected_assertion,
+                &InstructionV1::AssertWorktopResourcesOnly {
+                    resource_addresses: Vec::new(),
+                },
+            ]
+        );
+        // Before the bucket deposit
+        assert_eq!(manifest.instructions[3], expected_assertion);
+        assert!(matches!(
+            manifest.instructions[4],
+            InstructionV1::CallMethod { .. }
+        ));
+        // Before the worktop deposit
+        assert_eq!(
+            manifest.instructions[manifest.instructions.len() - 3],
+            expected_assertion
+        );
+    }
+
+    #[test]
+    fn test_strict_worktop_canonical_string_matches_built_manifest() {
+        let account = ComponentAddress::virtual_account_from_public_key(
+            &Secp256k1PrivateKey::from_u64(1).unwrap().public_key(),
+        );
+        let builder = ManifestBuilder::new()
+            .with_strict_worktop()
+            .withdraw_from_account(account, XRD, dec!(10))
+            .deposit_batch(account);
+        let network = NetworkDefinition::simulator();
+
+        let canonical_string = builder.to_canonical_string(&network).unwrap();
+        let manifest = builder.build();
+
+        assert_eq!(
+            canonical_string,
+            decompile(&manifest.instructions, &network).unwrap()
+        );
+        assert!(canonical_string.contains("ASSERT_WORKTOP_RESOURCES_ONLY"));
+        assert_eq!(
+            manifest.instructions.last(),
+            Some(&InstructionV1::AssertWorktopResourcesOnly {
+                resource_addresses: Vec::new(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_strict_worktop_tracks_returned_buckets() {
+        let account = ComponentAddress::virtual_account_from_public_key(
+            &Secp256k1PrivateKey::from_u64(1).unwrap().public_key(),
+        );
+        let manifest = ManifestBuilder::new()
+            .with_strict_worktop()
+            .withdraw_from_account(account, XRD, dec!(10))
+            .take_all_from_worktop(XRD, "bucket")
+            .return_to_worktop("bucket")
+            .deposit_batch(account)
+            .build();
+
+        let expected_assertion = InstructionV1::AssertWorktopContains {
+            resource_address: XRD,
+            amount: dec!(10),
+        };
+        assert_eq!(manifest.instructions[1], expected_assertion);
+        assert!(matches!(
+            manifest.instructions[2],
+            InstructionV1::TakeAllFromWorktop { .. }
+        ));
+        assert_eq!(manifest.instructions[4], expected_assertion);
+        assert_eq!(manifest.instructions.len(), 7);
+    }
+
+    #[test]
+    #[should_panic(expected = "Strict worktop mode must be enabled before adding any instruction")]
+    fn test_strict_worktop_cannot_be_enabled_after_instructions() {
+        let account = ComponentAddress::virtual_account_from_public_key(
+            &Secp256k1PrivateKey::from_u64(1).unwrap().public_key(),
+        );
+        ManifestBuilder::new()
+            .withdraw_from_account(account, XRD, dec!(10))
+            .with_strict_worktop();
+    }
+}
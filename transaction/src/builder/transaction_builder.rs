@@ -1,8 +1,11 @@
+use super::fee_payer::{check_fee_locks, set_fee_lock};
+use crate::internal_prelude::*;
 use crate::model::*;
 use crate::signing::Signer;
 
 pub struct TransactionBuilder {
     manifest: Option<TransactionManifestV1>,
+    fee_payer: Option<(ComponentAddress, Decimal)>,
     header: Option<TransactionHeaderV1>,
     message: Option<MessageV1>,
     intent_signatures: Vec<SignatureWithPublicKeyV1>,
@@ -13,6 +16,7 @@ impl TransactionBuilder {
     pub fn new() -> Self {
         Self {
             manifest: None,
+            fee_payer: None,
             header: None,
             message: None,
             intent_signatures: vec![],
@@ -25,6 +29,17 @@ impl TransactionBuilder {
         self
     }
 
+    /// Makes the account pay the fee of the transaction, by locking the given amount of XRD
+    /// from it ahead of any instruction which could fail before the fee is locked. Any
+    /// `lock_fee` call on the account in the manifest is replaced by this one.
+    ///
+    /// Building the transaction panics if the manifest locks any other fee after such an
+    /// instruction.
+    pub fn with_fee_payer(mut self, account_address: ComponentAddress, amount: Decimal) -> Self {
+        self.fee_payer = Some((account_address, amount));
+        self
+    }
+
     pub fn header(mut self, header: TransactionHeaderV1) -> Self {
         self.header = Some(header);
         self
@@ -86,11 +101,14 @@ impl TransactionBuilder {
     }
 
     fn transaction_intent(&self) -> IntentV1 {
-        let (instructions, blobs) = self
-            .manifest
-            .clone()
-            .expect("Manifest not specified")
-            .for_intent();
+        let mut manifest = self.manifest.clone().expect("Manifest not specified");
+        if let Some((account_address, amount)) = self.fee_payer {
+            set_fee_lock(&mut manifest.instructions, account_address, amount);
+            if let Err(error) = check_fee_locks(&manifest.instructions) {
+                panic!("Fee is not locked correctly: {:?}", error);
+            }
+        }
+        let (instructions, blobs) = manifest.for_intent();
         IntentV1 {
             header: self.header.clone().expect("Header not specified"),
             instructions,
@@ -121,8 +139,10 @@ mod tests {
     use radix_engine_interface::network::NetworkDefinition;
 
     use super::*;
+    use crate::builder::fee_payer::FeeLockError;
     use crate::builder::*;
     use crate::signing::secp256k1::Secp256k1PrivateKey;
+    use radix_engine_interface::blueprints::account::*;
 
     #[test]
     fn notary_as_signatory() {
@@ -153,4 +173,130 @@ mod tests {
             true
         );
     }
+
+    #[test]
+    fn fee_lock_is_inserted_after_proofs_and_before_other_instructions() {
+        let account = ComponentAddress::virtual_account_from_public_key(
+            &Secp256k1PrivateKey::from_u64(1).unwrap().public_key(),
+        );
+        let manifest = ManifestBuilder::new()
+            .create_proof_from_account_of_amount(account, XRD, 1)
+            .withdraw_from_account(account, XRD, 10)
+            .lock_fee_auto(account, 5)
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build();
+
+        let method_names: Vec<_> = manifest
+            .instructions
+            .iter()
+            .map(|instruction| match instruction {
+                InstructionV1::CallMethod { method_name, .. } => method_name.as_str(),
+                _ => "",
+            })
+            .collect();
+        assert_eq!(
+            method_names,
+            vec![
+                ACCOUNT_CREATE_PROOF_OF_AMOUNT_IDENT,
+                ACCOUNT_LOCK_FEE_IDENT,
+                ACCOUNT_WITHDRAW_IDENT,
+                ACCOUNT_TRY_DEPOSIT_BATCH_OR_ABORT_IDENT,
+            ]
+        );
+        assert_eq!(check_fee_locks(&manifest.instructions), Ok(()));
+    }
+
+    #[test]
+    fn misplaced_fee_locks_are_detected() {
+        let account = ComponentAddress::virtual_account_from_public_key(
+            &Secp256k1PrivateKey::from_u64(1).unwrap().public_key(),
+        );
+
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(account, XRD, 10)
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build();
+        assert_eq!(
+            check_fee_locks(&manifest.instructions),
+            Err(FeeLockError::NoFeeLock)
+        );
+
+        let manifest = ManifestBuilder::new()
+            .withdraw_from_account(account, XRD, 10)
+            .lock_fee(account, 5)
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build();
+        assert_eq!(
+            check_fee_locks(&manifest.instructions),
+            Err(FeeLockError::FeeLockPrecededByOtherInstructions {
+                instruction_index: 1
+            })
+        );
+    }
+
+    #[test]
+    fn fee_lock_methods_on_non_account_components_are_not_fee_locks() {
+        let account = ComponentAddress::virtual_account_from_public_key(
+            &Secp256k1PrivateKey::from_u64(1).unwrap().public_key(),
+        );
+
+        let manifest = ManifestBuilder::new()
+            .call_method(FAUCET, "lock_fee", manifest_args!(dec!("10")))
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build();
+        assert_eq!(
+            check_fee_locks(&manifest.instructions),
+            Err(FeeLockError::NoFeeLock)
+        );
+    }
+
+    #[test]
+    fn fee_payer_replaces_the_existing_fee_lock_of_the_account() {
+        let private_key = Secp256k1PrivateKey::from_u64(1).unwrap();
+        let account = ComponentAddress::virtual_account_from_public_key(&private_key.public_key());
+
+        let transaction = TransactionBuilder::new()
+            .header(TransactionHeaderV1 {
+                network_id: NetworkDefinition::simulator().id,
+                start_epoch_inclusive: Epoch::zero(),
+                end_epoch_exclusive: Epoch::of(100),
+                nonce: 5,
+                notary_public_key: private_key.public_key().into(),
+                notary_is_signatory: true,
+                tip_percentage: 5,
+            })
+            .manifest(
+                ManifestBuilder::new()
+                    .lock_fee(account, 5)
+                    .withdraw_from_account(account, XRD, 10)
+                    .try_deposit_entire_worktop_or_abort(account, None)
+                    .build(),
+            )
+            .with_fee_payer(account, dec!("20"))
+            .notarize(&private_key)
+            .build();
+
+        let fee_locks: Vec<_> = transaction
+            .signed_intent
+            .intent
+            .instructions
+            .0
+            .iter()
+            .filter(|instruction| {
+                matches!(
+                    instruction,
+                    InstructionV1::CallMethod { method_name, .. }
+                        if method_name == ACCOUNT_LOCK_FEE_IDENT
+                )
+            })
+            .collect();
+        assert_eq!(
+            fee_locks,
+            vec![&InstructionV1::CallMethod {
+                address: account.into(),
+                method_name: ACCOUNT_LOCK_FEE_IDENT.to_string(),
+                args: to_manifest_value_and_unwrap!(&AccountLockFeeInput { amount: dec!("20") }),
+            }]
+        );
+    }
 }
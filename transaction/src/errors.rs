@@ -16,6 +16,7 @@ pub enum SignatureValidationError {
     InvalidIntentSignature,
     InvalidNotarySignature,
     DuplicateSigner,
+    CurveTypeNotAllowed,
     SerializationError(EncodeError),
 }
 
@@ -32,6 +33,8 @@ pub enum ManifestIdValidationError {
     BucketLocked(ManifestBucket),
     AddressReservationNotFound(ManifestAddressReservation),
     AddressNotFound(u32),
+    /// A named address was invoked before its address reservation was used.
+    AddressNotYetInstantiated(u32),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -43,6 +46,7 @@ pub enum CallDataValidationError {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TransactionValidationError {
     TransactionTooLarge,
+    ManifestTooLarge { actual: usize, permitted: usize },
     EncodeError(EncodeError),
     PrepareError(PrepareError),
     HeaderValidationError(HeaderValidationError),
@@ -0,0 +1,354 @@
+use crate::internal_prelude::*;
+use radix_engine_interface::blueprints::account::*;
+
+/// A quantity of resources, as far as it can be determined without executing the manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResourceIndicator {
+    /// A known amount of a resource.
+    Amount(ResourceAddress, Decimal),
+    /// A known set of non-fungibles of a resource.
+    Ids(ResourceAddress, IndexSet<NonFungibleLocalId>),
+    /// An amount of a resource only known at runtime (e.g. from `TAKE_ALL_FROM_WORKTOP`).
+    Unknown(ResourceAddress),
+    /// Whatever is left on the worktop (e.g. passed as `Expression("ENTIRE_WORKTOP")`).
+    EntireWorktop,
+}
+
+/// A human-readable overview of what a manifest does, obtained with [`analyze`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ManifestSummary {
+    /// The resources withdrawn from each account.
+    pub account_withdraws: IndexMap<ComponentAddress, Vec<ResourceIndicator>>,
+    /// The resources passed to the methods or functions of each address,
+    /// e.g. deposited into an account.
+    pub deposits: IndexMap<GlobalAddress, Vec<ResourceIndicator>>,
+    /// The proofs created from accounts, the auth zone or buckets.
+    pub proofs_created: Vec<ResourceIndicator>,
+    /// The components whose methods are called, in order of first call.
+    pub components_called: IndexSet<ComponentAddress>,
+}
+
+/// Statically analyzes a manifest, without executing it.
+///
+/// Only addresses known before execution are reported on, i.e. the addresses allocated by the
+/// manifest itself are skipped. Arguments which can't be decoded as expected (e.g. for an
+/// account withdrawal) are skipped too, as the transaction would fail anyway.
+pub fn analyze(manifest: &TransactionManifestV1) -> ManifestSummary {
+    let mut analyzer = ManifestAnalyzer::default();
+    for instruction in &manifest.instructions {
+        analyzer.process_instruction(instruction);
+    }
+    analyzer.summary
+}
+
+#[derive(Default)]
+struct ManifestAnalyzer {
+    id_allocator: ManifestIdAllocator,
+    buckets: IndexMap<ManifestBucket, ResourceIndicator>,
+    summary: ManifestSummary,
+}
+
+impl ManifestAnalyzer {
+    fn process_instruction(&mut self, instruction: &InstructionV1) {
+        match instruction {
+            InstructionV1::TakeAllFromWorktop { resource_address } => {
+                self.new_bucket(ResourceIndicator::Unknown(*resource_address));
+            }
+            InstructionV1::TakeFromWorktop {
+                resource_address,
+                amount,
+            } => {
+                self.new_bucket(ResourceIndicator::Amount(*resource_address, *amount));
+            }
+            InstructionV1::TakeNonFungiblesFromWorktop {
+                resource_address,
+                ids,
+            } => {
+                self.new_bucket(ResourceIndicator::Ids(
+                    *resource_address,
+                    ids.iter().cloned().collect(),
+                ));
+            }
+            InstructionV1::ReturnToWorktop { bucket_id }
+            | InstructionV1::BurnResource { bucket_id } => {
+                self.buckets.remove(bucket_id);
+            }
+            InstructionV1::CreateProofFromAuthZoneOfAmount {
+                resource_address,
+                amount,
+            } => {
+                self.summary
+                    .proofs_created
+                    .push(ResourceIndicator::Amount(*resource_address, *amount));
+            }
+            InstructionV1::CreateProofFromAuthZoneOfNonFungibles {
+                resource_address,
+                ids,
+            } => {
+                self.summary.proofs_created.push(ResourceIndicator::Ids(
+                    *resource_address,
+                    ids.iter().cloned().collect(),
+                ));
+            }
+            InstructionV1::CreateProofFromAuthZoneOfAll { resource_address } => {
+                self.summary
+                    .proofs_created
+                    .push(ResourceIndicator::Unknown(*resource_address));
+            }
+            InstructionV1::CreateProofFromBucketOfAmount { bucket_id, amount } => {
+                if let Some(resource_address) = self.bucket_resource_address(bucket_id) {
+                    self.summary
+                        .proofs_created
+                        .push(ResourceIndicator::Amount(resource_address, *amount));
+                }
+            }
+            InstructionV1::CreateProofFromBucketOfNonFungibles { bucket_id, ids } => {
+                if let Some(resource_address) = self.bucket_resource_address(bucket_id) {
+                    self.summary.proofs_created.push(ResourceIndicator::Ids(
+                        resource_address,
+                        ids.iter().cloned().collect(),
+                    ));
+                }
+            }
+            InstructionV1::CreateProofFromBucketOfAll { bucket_id } => {
+                if let Some(resource) = self.buckets.get(bucket_id) {
+                    self.summary.proofs_created.push(resource.clone());
+                }
+            }
+            InstructionV1::CallFunction {
+                package_address,
+                args,
+                ..
+            } => {
+                let resources = self.take_resources_from_args(args);
+                if let DynamicPackageAddress::Static(package_address) = package_address {
+                    self.add_deposits((*package_address).into(), resources);
+                }
+            }
+            InstructionV1::CallMethod {
+                address,
+                method_name,
+                args,
+            } => {
+                let resources = self.take_resources_from_args(args);
+                if let DynamicGlobalAddress::Static(address) = address {
+                    if let Ok(component_address) = ComponentAddress::try_from(*address) {
+                        self.summary.components_called.insert(component_address);
+                        if is_account(&component_address) {
+                            self.process_account_method(component_address, method_name, args);
+                        }
+                    }
+                    self.add_deposits(*address, resources);
+                }
+            }
+            InstructionV1::CallRoyaltyMethod { address, args, .. }
+            | InstructionV1::CallMetadataMethod { address, args, .. }
+            | InstructionV1::CallRoleAssignmentMethod { address, args, .. } => {
+                let resources = self.take_resources_from_args(args);
+                if let DynamicGlobalAddress::Static(address) = address {
+                    if let Ok(component_address) = ComponentAddress::try_from(*address) {
+                        self.summary.components_called.insert(component_address);
+                    }
+                    self.add_deposits(*address, resources);
+                }
+            }
+            InstructionV1::CallDirectVaultMethod { args, .. } => {
+                self.take_resources_from_args(args);
+            }
+            InstructionV1::AssertWorktopContainsAny { .. }
+            | InstructionV1::AssertWorktopContains { .. }
+            | InstructionV1::AssertWorktopContainsNonFungibles { .. }
+            | InstructionV1::AssertWorktopResourcesOnly { .. }
+            | InstructionV1::YieldWorktopContents
+            | InstructionV1::BurnResourceFromWorktop { .. }
+            | InstructionV1::PopFromAuthZone
+            | InstructionV1::PushToAuthZone { .. }
+            | InstructionV1::PushCompositeToAuthZone { .. }
+            | InstructionV1::DropAuthZoneProofs
+            | InstructionV1::DropAuthZoneRegularProofs
+            | InstructionV1::DropAuthZoneSignatureProofs
+            | InstructionV1::CloneProof { .. }
+            | InstructionV1::DropProof { .. }
+            | InstructionV1::DropNamedProofs
+            | InstructionV1::DropAllProofs
+            | InstructionV1::AllocateGlobalAddress { .. }
+            | InstructionV1::AssertComponentBlueprint { .. }
+            | InstructionV1::SetInstructionCostLimit { .. } => {}
+        }
+    }
+
+    fn process_account_method(
+        &mut self,
+        account: ComponentAddress,
+        method_name: &str,
+        args: &ManifestValue,
+    ) {
+        let withdrawn = match method_name {
+            ACCOUNT_WITHDRAW_IDENT => from_manifest_value::<AccountWithdrawInput>(args)
+                .ok()
+                .map(|input| ResourceIndicator::Amount(input.resource_address, input.amount)),
+            ACCOUNT_LOCK_FEE_AND_WITHDRAW_IDENT => {
+                from_manifest_value::<AccountLockFeeAndWithdrawInput>(args)
+                    .ok()
+                    .map(|input| ResourceIndicator::Amount(input.resource_address, input.amount))
+            }
+            ACCOUNT_WITHDRAW_NON_FUNGIBLES_IDENT => {
+                from_manifest_value::<AccountWithdrawNonFungiblesInput>(args)
+                    .ok()
+                    .map(|input| ResourceIndicator::Ids(input.resource_address, input.ids))
+            }
+            ACCOUNT_LOCK_FEE_AND_WITHDRAW_NON_FUNGIBLES_IDENT => {
+                from_manifest_value::<AccountLockFeeAndWithdrawNonFungiblesInput>(args)
+                    .ok()
+                    .map(|input| ResourceIndicator::Ids(input.resource_address, input.ids))
+            }
+            ACCOUNT_CREATE_PROOF_OF_AMOUNT_IDENT => {
+                if let Ok(input) = from_manifest_value::<AccountCreateProofOfAmountInput>(args) {
+                    self.summary.proofs_created.push(ResourceIndicator::Amount(
+                        input.resource_address,
+                        input.amount,
+                    ));
+                }
+                None
+            }
+            ACCOUNT_CREATE_PROOF_OF_NON_FUNGIBLES_IDENT => {
+                if let Ok(input) =
+                    from_manifest_value::<AccountCreateProofOfNonFungiblesInput>(args)
+                {
+                    self.summary
+                        .proofs_created
+                        .push(ResourceIndicator::Ids(input.resource_address, input.ids));
+                }
+                None
+            }
+            _ => None,
+        };
+
+        if let Some(withdrawn) = withdrawn {
+            self.summary
+                .account_withdraws
+                .entry(account)
+                .or_default()
+                .push(withdrawn);
+        }
+    }
+
+    fn new_bucket(&mut self, resource: ResourceIndicator) {
+        let bucket_id = self.id_allocator.new_bucket_id();
+        self.buckets.insert(bucket_id, resource);
+    }
+
+    fn bucket_resource_address(&self, bucket_id: &ManifestBucket) -> Option<ResourceAddress> {
+        match self.buckets.get(bucket_id)? {
+            ResourceIndicator::Amount(resource_address, _)
+            | ResourceIndicator::Ids(resource_address, _)
+            | ResourceIndicator::Unknown(resource_address) => Some(*resource_address),
+            ResourceIndicator::EntireWorktop => None,
+        }
+    }
+
+    fn add_deposits(&mut self, address: GlobalAddress, resources: Vec<ResourceIndicator>) {
+        if !resources.is_empty() {
+            self.summary
+                .deposits
+                .entry(address)
+                .or_default()
+                .extend(resources);
+        }
+    }
+
+    /// Returns the resources of the buckets (and of the worktop) passed in the given arguments.
+    /// The buckets are consumed by the call, so they are no longer tracked afterwards.
+    fn take_resources_from_args(&mut self, args: &ManifestValue) -> Vec<ResourceIndicator> {
+        let mut resources = Vec::new();
+        self.collect_resources(args, &mut resources);
+        resources
+    }
+
+    fn collect_resources(&mut self, value: &ManifestValue, resources: &mut Vec<ResourceIndicator>) {
+        match value {
+            ManifestValue::Custom {
+                value: ManifestCustomValue::Bucket(bucket_id),
+            } => {
+                if let Some(resource) = self.buckets.remove(bucket_id) {
+                    resources.push(resource);
+                }
+            }
+            ManifestValue::Custom {
+                value: ManifestCustomValue::Expression(ManifestExpression::EntireWorktop),
+            } => {
+                resources.push(ResourceIndicator::EntireWorktop);
+            }
+            ManifestValue::Enum { fields, .. } | ManifestValue::Tuple { fields } => {
+                for field in fields {
+                    self.collect_resources(field, resources);
+                }
+            }
+            ManifestValue::Array { elements, .. } => {
+                for element in elements {
+                    self.collect_resources(element, resources);
+                }
+            }
+            ManifestValue::Map { entries, .. } => {
+                for (key, value) in entries {
+                    self.collect_resources(key, resources);
+                    self.collect_resources(value, resources);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn is_account(address: &ComponentAddress) -> bool {
+    matches!(
+        address.as_node_id().entity_type(),
+        Some(
+            EntityType::GlobalAccount
+                | EntityType::GlobalVirtualSecp256k1Account
+                | EntityType::GlobalVirtualEd25519Account
+        )
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_analyze_transfer_manifest() {
+        let account = ComponentAddress::virtual_account_from_public_key(
+            &Secp256k1PrivateKey::from_u64(1).unwrap().public_key(),
+        );
+        let other_account = ComponentAddress::virtual_account_from_public_key(
+            &Secp256k1PrivateKey::from_u64(2).unwrap().public_key(),
+        );
+        let manifest = ManifestBuilder::new()
+            .lock_fee(account, 10)
+            .create_proof_from_account_of_amount(account, XRD, dec!(1))
+            .withdraw_from_account(account, XRD, dec!(100))
+            .take_from_worktop(XRD, dec!(40), "bucket")
+            .try_deposit_or_abort(other_account, None, "bucket")
+            .try_deposit_entire_worktop_or_abort(account, None)
+            .build();
+
+        let summary = analyze(&manifest);
+
+        assert_eq!(
+            summary.account_withdraws,
+            indexmap!(account => vec![ResourceIndicator::Amount(XRD, dec!(100))])
+        );
+        assert_eq!(
+            summary.deposits,
+            indexmap!(
+                GlobalAddress::from(other_account) => vec![ResourceIndicator::Amount(XRD, dec!(40))],
+                GlobalAddress::from(account) => vec![ResourceIndicator::EntireWorktop]
+            )
+        );
+        assert_eq!(
+            summary.proofs_created,
+            vec![ResourceIndicator::Amount(XRD, dec!(1))]
+        );
+        assert_eq!(summary.components_called, indexset!(account, other_account));
+    }
+}
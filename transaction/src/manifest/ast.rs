@@ -39,6 +39,17 @@ pub enum Instruction {
         resource_address: Value,
     },
 
+    AssertWorktopResourcesOnly {
+        resource_addresses: Value,
+    },
+
+    YieldWorktopContents,
+
+    BurnResourceFromWorktop {
+        resource_address: Value,
+        amount: Value,
+    },
+
     PopFromAuthZone {
         new_proof: Value,
     },
@@ -47,6 +58,10 @@ pub enum Instruction {
         proof: Value,
     },
 
+    PushCompositeToAuthZone {
+        proofs: Value,
+    },
+
     CreateProofFromAuthZoneOfAmount {
         resource_address: Value,
         amount: Value,
@@ -142,6 +157,16 @@ pub enum Instruction {
         named_address: Value,
     },
 
+    AssertComponentBlueprint {
+        component_address: Value,
+        package_address: Value,
+        blueprint_name: Value,
+    },
+
+    SetInstructionCostLimit {
+        cost_units: Value,
+    },
+
     /* Call direct vault method aliases */
     RecallFromVault {
         vault_id: Value,
@@ -180,13 +180,30 @@ impl<'a> DecompilationContext<'a> {
     }
 }
 
+/// How the objects allocated by a manifest (buckets, proofs, address reservations and named
+/// addresses) are named when decompiling it.
+#[derive(Default, Clone)]
+pub enum ObjectNaming {
+    /// Objects are named after their kind and allocation order, e.g. `bucket1` or `proof2`.
+    ///
+    /// This naming only depends on the instructions, so the same binary manifest always
+    /// decompiles to the same text - which is what tools displaying manifests should use.
+    #[default]
+    Stable,
+    /// Objects are given the provided names (e.g. the ones chosen in a `ManifestBuilder`), and
+    /// any other object gets a stable name.
+    Known(ManifestObjectNames),
+}
+
+/// Decompiles the instructions, with [`ObjectNaming::Stable`] naming.
+///
 /// Contract: if the instructions are from a validated notarized transaction, no error
 /// should be returned.
 pub fn decompile(
     instructions: &[InstructionV1],
     network: &NetworkDefinition,
 ) -> Result<String, DecompileError> {
-    decompile_with_known_naming(instructions, network, Default::default())
+    decompile_with_naming(instructions, network, ObjectNaming::Stable)
 }
 
 pub fn decompile_with_known_naming(
@@ -194,9 +211,25 @@ pub fn decompile_with_known_naming(
     network: &NetworkDefinition,
     known_object_names: ManifestObjectNames,
 ) -> Result<String, DecompileError> {
+    decompile_with_naming(
+        instructions,
+        network,
+        ObjectNaming::Known(known_object_names),
+    )
+}
+
+pub fn decompile_with_naming(
+    instructions: &[InstructionV1],
+    network: &NetworkDefinition,
+    naming: ObjectNaming,
+) -> Result<String, DecompileError> {
+    let object_names = match naming {
+        ObjectNaming::Stable => ManifestObjectNames::default(),
+        ObjectNaming::Known(known_object_names) => known_object_names,
+    };
     let address_bech32_encoder = AddressBech32Encoder::new(network);
     let mut buf = String::new();
-    let mut context = DecompilationContext::new(&address_bech32_encoder, known_object_names);
+    let mut context = DecompilationContext::new(&address_bech32_encoder, object_names);
     for inst in instructions {
         decompile_instruction(&mut buf, inst, &mut context)?;
     }
@@ -258,6 +291,18 @@ pub fn decompile_instruction<F: fmt::Write>(
             "ASSERT_WORKTOP_CONTAINS_ANY",
             to_manifest_value(&(resource_address,))?,
         ),
+        InstructionV1::AssertWorktopResourcesOnly { resource_addresses } => (
+            "ASSERT_WORKTOP_RESOURCES_ONLY",
+            to_manifest_value(&(resource_addresses,))?,
+        ),
+        InstructionV1::YieldWorktopContents => ("YIELD_WORKTOP_CONTENTS", to_manifest_value(&())?),
+        InstructionV1::BurnResourceFromWorktop {
+            resource_address,
+            amount,
+        } => (
+            "BURN_RESOURCE_FROM_WORKTOP",
+            to_manifest_value(&(resource_address, amount))?,
+        ),
         InstructionV1::PopFromAuthZone => {
             let proof = context.new_proof();
             ("POP_FROM_AUTH_ZONE", to_manifest_value(&(proof,))?)
@@ -265,6 +310,10 @@ pub fn decompile_instruction<F: fmt::Write>(
         InstructionV1::PushToAuthZone { proof_id } => {
             ("PUSH_TO_AUTH_ZONE", to_manifest_value(&(proof_id,))?)
         }
+        InstructionV1::PushCompositeToAuthZone { proof_ids } => (
+            "PUSH_COMPOSITE_TO_AUTH_ZONE",
+            to_manifest_value(&(proof_ids,))?,
+        ),
         InstructionV1::DropAuthZoneProofs => {
             ("DROP_AUTH_ZONE_PROOFS", to_manifest_value_and_unwrap!(&()))
         }
@@ -658,6 +707,18 @@ pub fn decompile_instruction<F: fmt::Write>(
                 ))?,
             )
         }
+        InstructionV1::AssertComponentBlueprint {
+            component_address,
+            package_address,
+            blueprint_name,
+        } => (
+            "ASSERT_COMPONENT_BLUEPRINT",
+            to_manifest_value(&(component_address, package_address, blueprint_name))?,
+        ),
+        InstructionV1::SetInstructionCostLimit { cost_units } => (
+            "SET_INSTRUCTION_COST_LIMIT",
+            to_manifest_value(&(cost_units,))?,
+        ),
     };
 
     write!(f, "{}", display_name)?;
@@ -132,6 +132,17 @@ TAKE_NON_FUNGIBLES_FROM_WORKTOP
     )
     Bucket("bucket3")
 ;
+ASSERT_WORKTOP_RESOURCES_ONLY
+    Array<Address>(
+        Address("${xrd_resource_address}"),
+        Address("${gumball_resource_address}")
+    )
+;
+YIELD_WORKTOP_CONTENTS;
+BURN_RESOURCE_FROM_WORKTOP
+    Address("${gumball_resource_address}")
+    Decimal("1")
+;
 CALL_METHOD
     Address("${account_address}")
     "deposit_batch"
@@ -222,6 +233,12 @@ CREATE_PROOF_FROM_AUTH_ZONE_OF_ALL
     Address("resource_sim1ngktvyeenvvqetnqwysevcx5fyvl6hqe36y3rkhdfdn6uzvt5366ha")
     Proof("proof8")
 ;
+PUSH_COMPOSITE_TO_AUTH_ZONE
+    Array<Proof>(
+        Proof("proof6"),
+        Proof("proof8")
+    )
+;
 DROP_AUTH_ZONE_SIGNATURE_PROOFS;
 DROP_AUTH_ZONE_REGULAR_PROOFS;
 DROP_AUTH_ZONE_PROOFS;
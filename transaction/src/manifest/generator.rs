@@ -7,6 +7,7 @@ use crate::model::*;
 use crate::validation::*;
 use radix_engine_common::constants::PACKAGE_PACKAGE;
 use radix_engine_common::prelude::CONSENSUS_MANAGER;
+use radix_engine_common::types::ComponentAddress;
 use radix_engine_common::types::NodeId;
 use radix_engine_common::types::PackageAddress;
 use radix_engine_interface::address::AddressBech32Decoder;
@@ -335,6 +336,22 @@ where
                 )?,
             }
         }
+        ast::Instruction::AssertWorktopResourcesOnly { resource_addresses } => {
+            InstructionV1::AssertWorktopResourcesOnly {
+                resource_addresses: generate_resource_addresses(
+                    resource_addresses,
+                    address_bech32_decoder,
+                )?,
+            }
+        }
+        ast::Instruction::YieldWorktopContents => InstructionV1::YieldWorktopContents,
+        ast::Instruction::BurnResourceFromWorktop {
+            resource_address,
+            amount,
+        } => InstructionV1::BurnResourceFromWorktop {
+            resource_address: generate_resource_address(resource_address, address_bech32_decoder)?,
+            amount: generate_decimal(amount)?,
+        },
         ast::Instruction::PopFromAuthZone { new_proof } => {
             let proof_id = id_validator
                 .new_proof(ProofKind::AuthZoneProof)
@@ -350,6 +367,15 @@ where
                 .map_err(GeneratorError::IdValidationError)?;
             InstructionV1::PushToAuthZone { proof_id }
         }
+        ast::Instruction::PushCompositeToAuthZone { proofs } => {
+            let proof_ids = generate_proofs(proofs, resolver)?;
+            for proof_id in &proof_ids {
+                id_validator
+                    .drop_proof(proof_id)
+                    .map_err(GeneratorError::IdValidationError)?;
+            }
+            InstructionV1::PushCompositeToAuthZone { proof_ids }
+        }
         ast::Instruction::DropAuthZoneProofs => InstructionV1::DropAuthZoneProofs,
         ast::Instruction::DropAuthZoneRegularProofs => InstructionV1::DropAuthZoneRegularProofs,
         ast::Instruction::DropAuthZoneSignatureProofs => InstructionV1::DropAuthZoneSignatureProofs,
@@ -597,6 +623,25 @@ where
             }
         }
 
+        ast::Instruction::AssertComponentBlueprint {
+            component_address,
+            package_address,
+            blueprint_name,
+        } => InstructionV1::AssertComponentBlueprint {
+            component_address: generate_component_address(
+                component_address,
+                address_bech32_decoder,
+            )?,
+            package_address: generate_package_address(package_address, address_bech32_decoder)?,
+            blueprint_name: generate_string(&blueprint_name)?,
+        },
+
+        ast::Instruction::SetInstructionCostLimit { cost_units } => {
+            InstructionV1::SetInstructionCostLimit {
+                cost_units: generate_u32(cost_units)?,
+            }
+        }
+
         /* direct vault method aliases */
         ast::Instruction::RecallFromVault { vault_id, args } => {
             InstructionV1::CallDirectVaultMethod {
@@ -845,6 +890,13 @@ fn generate_string(value: &ast::Value) -> Result<String, GeneratorError> {
     }
 }
 
+fn generate_u32(value: &ast::Value) -> Result<u32, GeneratorError> {
+    match value {
+        ast::Value::U32(n) => Ok(*n),
+        v => invalid_type!(v, ast::ValueKind::U32),
+    }
+}
+
 fn generate_decimal(value: &ast::Value) -> Result<Decimal, GeneratorError> {
     match value {
         ast::Value::Decimal(inner) => match &**inner {
@@ -889,6 +941,26 @@ fn generate_package_address(
     }
 }
 
+fn generate_component_address(
+    value: &ast::Value,
+    address_bech32_decoder: &AddressBech32Decoder,
+) -> Result<ComponentAddress, GeneratorError> {
+    match value {
+        ast::Value::Address(inner) => match inner.borrow() {
+            ast::Value::String(s) => {
+                if let Ok((_, full_data)) = address_bech32_decoder.validate_and_decode(&s) {
+                    if let Ok(address) = ComponentAddress::try_from(full_data.as_ref()) {
+                        return Ok(address);
+                    }
+                }
+                return Err(GeneratorError::InvalidGlobalAddress(s.into()));
+            }
+            v => invalid_type!(v, ast::ValueKind::String),
+        },
+        v => invalid_type!(v, ast::ValueKind::ComponentAddress),
+    }
+}
+
 fn generate_resource_address(
     value: &ast::Value,
     address_bech32_decoder: &AddressBech32Decoder,
@@ -909,6 +981,28 @@ fn generate_resource_address(
     }
 }
 
+fn generate_resource_addresses(
+    value: &ast::Value,
+    address_bech32_decoder: &AddressBech32Decoder,
+) -> Result<Vec<ResourceAddress>, GeneratorError> {
+    match value {
+        ast::Value::Array(kind, values) => {
+            if kind != &ast::ValueKind::Address {
+                return Err(GeneratorError::InvalidAstType {
+                    expected_type: ast::ValueKind::Address,
+                    actual: kind.clone(),
+                });
+            }
+
+            values
+                .iter()
+                .map(|v| generate_resource_address(v, address_bech32_decoder))
+                .collect()
+        }
+        v => invalid_type!(v, ast::ValueKind::Array),
+    }
+}
+
 fn generate_dynamic_global_address(
     value: &ast::Value,
     address_bech32_decoder: &AddressBech32Decoder,
@@ -1100,6 +1194,25 @@ fn generate_proof(
     }
 }
 
+fn generate_proofs(
+    value: &ast::Value,
+    resolver: &mut NameResolver,
+) -> Result<Vec<ManifestProof>, GeneratorError> {
+    match value {
+        ast::Value::Array(kind, values) => {
+            if kind != &ast::ValueKind::Proof {
+                return Err(GeneratorError::InvalidAstType {
+                    expected_type: ast::ValueKind::Proof,
+                    actual: kind.clone(),
+                });
+            }
+
+            values.iter().map(|v| generate_proof(v, resolver)).collect()
+        }
+        v => invalid_type!(v, ast::ValueKind::Array),
+    }
+}
+
 fn generate_address_reservation(
     value: &ast::Value,
     resolver: &mut NameResolver,
@@ -1718,6 +1831,20 @@ mod tests {
                 args: manifest_args!(dec!("100")).into()
             },
         );
+        generate_instruction_ok!(
+            r#"ASSERT_COMPONENT_BLUEPRINT Address("component_sim1cqvgx33089ukm2pl97pv4max0x40ruvfy4lt60yvya744cvemygpmu") Address("package_sim1p4r4955skdjq9swg8s5jguvcjvyj7tsxct87a9z6sw76cdfd2jg3zk") "Airdrop";"#,
+            InstructionV1::AssertComponentBlueprint {
+                component_address: component,
+                package_address,
+                blueprint_name: "Airdrop".to_string(),
+            },
+        );
+        generate_instruction_ok!(
+            r#"SET_INSTRUCTION_COST_LIMIT 5000000u32;"#,
+            InstructionV1::SetInstructionCostLimit {
+                cost_units: 5000000
+            },
+        );
     }
 
     #[test]
@@ -0,0 +1,667 @@
+//! A JSON interchange format for [`ManifestValue`]s, so that manifest arguments can be built
+//! outside of Rust without writing manifest text.
+//!
+//! The format is the programmatic SBOR JSON format, as output by [`to_json`]. Every value is an
+//! object with a `kind` field - the SBOR value kind - and the fields of that kind:
+//!
+//! | `kind` | Fields |
+//! |---|---|
+//! | `Bool` | `value`: a boolean |
+//! | `I8`, `I16`, `I32`, `U8`, `U16`, `U32` | `value`: a number (a string is accepted too) |
+//! | `I64`, `I128`, `U64`, `U128` | `value`: a string, as these exceed the JS safe integer range |
+//! | `String` | `value`: a string |
+//! | `Tuple` | `fields`: an array of values |
+//! | `Enum` | `variant_id`: a number up to 255 (a string is accepted too), `fields`: an array of values |
+//! | `Array` | `element_kind`: a value kind, `elements`: an array of values of that kind |
+//! | `Bytes` | `hex`: the hex-encoded bytes of an array of `U8` |
+//! | `Map` | `key_kind`, `value_kind`: value kinds, `entries`: an array of `{ "key", "value" }` |
+//! | `Address` | `value`: a bech32 address, a hex-encoded node id, or the id of a named address |
+//! | `Bucket`, `Proof`, `AddressReservation` | `value`: the id, as a string |
+//! | `Expression` | `value`: `ENTIRE_WORKTOP` or `ENTIRE_AUTH_ZONE` |
+//! | `Blob` | `value`: the hex-encoded blob hash |
+//! | `Decimal`, `PreciseDecimal` | `value`: the decimal, as a string |
+//! | `NonFungibleLocalId` | `value`: the id, as displayed - e.g. `#1#`, `<name>` or `[0a0b]` |
+//!
+//! Any other field - such as the `type_name`, `field_name` or `variant_name` annotations - is
+//! ignored by [`from_json`]. For example, `(5u8, Decimal("1.5"))` is:
+//!
+//! ```json
+//! {
+//!     "kind": "Tuple",
+//!     "fields": [
+//!         { "kind": "U8", "value": 5 },
+//!         { "kind": "Decimal", "value": "1.5" }
+//!     ]
+//! }
+//! ```
+
+use crate::internal_prelude::*;
+use radix_engine_common::data::manifest::converter::*;
+use radix_engine_interface::address::{AddressBech32Decoder, AddressBech32Encoder};
+use sbor::representations::*;
+use sbor::rust::str::FromStr;
+use serde_json::{Map as JsonMap, Value as JsonValue};
+use utils::ContextualSerialize;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ManifestJsonError {
+    EncodeError(EncodeError),
+    SerializationError(String),
+    /// The JSON at the path (a JSON pointer) isn't of the expected shape.
+    UnexpectedJson {
+        path: String,
+        expected: &'static str,
+    },
+    UnknownValueKind {
+        path: String,
+        kind: String,
+    },
+    /// An element, map key or map value doesn't have the kind declared by its container.
+    MismatchingValueKind {
+        path: String,
+        expected: ManifestValueKind,
+        actual: ManifestValueKind,
+    },
+    InvalidValue {
+        path: String,
+        kind: ManifestValueKind,
+        value: String,
+    },
+    DepthLimitExceeded {
+        path: String,
+    },
+}
+
+/// Converts a value to its JSON representation - see the [module documentation](self).
+///
+/// Static addresses are output in bech32 if an encoder is given, and in hex otherwise.
+pub fn to_json(
+    value: &ManifestValue,
+    address_bech32_encoder: Option<&AddressBech32Encoder>,
+) -> Result<JsonValue, ManifestJsonError> {
+    let payload = manifest_encode(value).map_err(ManifestJsonError::EncodeError)?;
+    let raw_payload = ManifestRawPayload::new_from_valid_slice(&payload);
+    let serializable = raw_payload.serializable(SerializationParameters::Schemaless {
+        mode: SerializationMode::Programmatic,
+        custom_context: ManifestValueDisplayContext::with_optional_bech32(address_bech32_encoder),
+        depth_limit: MANIFEST_SBOR_V1_MAX_DEPTH,
+    });
+    serde_json::to_value(&serializable)
+        .map_err(|error| ManifestJsonError::SerializationError(error.to_string()))
+}
+
+/// Parses a value from its JSON representation - see the [module documentation](self).
+///
+/// Bech32 addresses are only accepted if a decoder is given, which checks that they belong to
+/// the expected network.
+pub fn from_json(
+    json: &JsonValue,
+    address_bech32_decoder: Option<&AddressBech32Decoder>,
+) -> Result<ManifestValue, ManifestJsonError> {
+    JsonValueParser {
+        address_bech32_decoder,
+    }
+    .parse_value(json, "", 1)
+}
+
+struct JsonValueParser<'a> {
+    address_bech32_decoder: Option<&'a AddressBech32Decoder>,
+}
+
+impl<'a> JsonValueParser<'a> {
+    fn parse_value(
+        &self,
+        json: &JsonValue,
+        path: &str,
+        depth: usize,
+    ) -> Result<ManifestValue, ManifestJsonError> {
+        if depth > MANIFEST_SBOR_V1_MAX_DEPTH {
+            return Err(ManifestJsonError::DepthLimitExceeded { path: path.into() });
+        }
+        let object = expect_object(json, path)?;
+        let kind = expect_str(object, "kind", path)?;
+
+        let value = match kind {
+            "Bool" => ManifestValue::Bool {
+                value: expect_field(object, "value", path)?
+                    .as_bool()
+                    .ok_or_else(|| unexpected_json(path, "value", "a boolean"))?,
+            },
+            "I8" => ManifestValue::I8 {
+                value: parse_integer(object, ValueKind::I8, path)?,
+            },
+            "I16" => ManifestValue::I16 {
+                value: parse_integer(object, ValueKind::I16, path)?,
+            },
+            "I32" => ManifestValue::I32 {
+                value: parse_integer(object, ValueKind::I32, path)?,
+            },
+            "I64" => ManifestValue::I64 {
+                value: parse_integer(object, ValueKind::I64, path)?,
+            },
+            "I128" => ManifestValue::I128 {
+                value: parse_integer(object, ValueKind::I128, path)?,
+            },
+            "U8" => ManifestValue::U8 {
+                value: parse_integer(object, ValueKind::U8, path)?,
+            },
+            "U16" => ManifestValue::U16 {
+                value: parse_integer(object, ValueKind::U16, path)?,
+            },
+            "U32" => ManifestValue::U32 {
+                value: parse_integer(object, ValueKind::U32, path)?,
+            },
+            "U64" => ManifestValue::U64 {
+                value: parse_integer(object, ValueKind::U64, path)?,
+            },
+            "U128" => ManifestValue::U128 {
+                value: parse_integer(object, ValueKind::U128, path)?,
+            },
+            "String" => ManifestValue::String {
+                value: expect_str(object, "value", path)?.to_string(),
+            },
+            "Tuple" => ManifestValue::Tuple {
+                fields: self.parse_values(object, "fields", None, path, depth)?,
+            },
+            "Enum" => ManifestValue::Enum {
+                discriminator: parse_variant_id(object, path)?,
+                fields: self.parse_values(object, "fields", None, path, depth)?,
+            },
+            "Array" => {
+                let element_value_kind = parse_value_kind(object, "element_kind", path)?;
+                ManifestValue::Array {
+                    element_value_kind,
+                    elements: self.parse_values(
+                        object,
+                        "elements",
+                        Some(element_value_kind),
+                        path,
+                        depth,
+                    )?,
+                }
+            }
+            "Bytes" => {
+                let hex = expect_str(object, "hex", path)?;
+                let bytes =
+                    hex::decode(hex).map_err(|_| invalid_value(path, ValueKind::U8, hex))?;
+                ManifestValue::Array {
+                    element_value_kind: ValueKind::U8,
+                    elements: bytes
+                        .into_iter()
+                        .map(|value| ManifestValue::U8 { value })
+                        .collect(),
+                }
+            }
+            "Map" => {
+                let key_value_kind = parse_value_kind(object, "key_kind", path)?;
+                let value_value_kind = parse_value_kind(object, "value_kind", path)?;
+                let entries_path = format!("{}/entries", path);
+                let mut entries = Vec::new();
+                for (index, entry) in expect_array(object, "entries", path)?.iter().enumerate() {
+                    let entry_path = format!("{}/{}", entries_path, index);
+                    let entry = expect_object(entry, &entry_path)?;
+                    let key = self.parse_child(
+                        expect_field(entry, "key", &entry_path)?,
+                        &format!("{}/key", entry_path),
+                        Some(key_value_kind),
+                        depth,
+                    )?;
+                    let value = self.parse_child(
+                        expect_field(entry, "value", &entry_path)?,
+                        &format!("{}/value", entry_path),
+                        Some(value_value_kind),
+                        depth,
+                    )?;
+                    entries.push((key, value));
+                }
+                ManifestValue::Map {
+                    key_value_kind,
+                    value_value_kind,
+                    entries,
+                }
+            }
+            _ => ManifestValue::Custom {
+                value: self.parse_custom_value(object, kind, path)?,
+            },
+        };
+
+        Ok(value)
+    }
+
+    fn parse_custom_value(
+        &self,
+        object: &JsonMap<String, JsonValue>,
+        kind: &str,
+        path: &str,
+    ) -> Result<ManifestCustomValue, ManifestJsonError> {
+        let custom_value_kind = match kind {
+            "Address" => ManifestCustomValueKind::Address,
+            "Bucket" => ManifestCustomValueKind::Bucket,
+            "Proof" => ManifestCustomValueKind::Proof,
+            "Expression" => ManifestCustomValueKind::Expression,
+            "Blob" => ManifestCustomValueKind::Blob,
+            "Decimal" => ManifestCustomValueKind::Decimal,
+            "PreciseDecimal" => ManifestCustomValueKind::PreciseDecimal,
+            "NonFungibleLocalId" => ManifestCustomValueKind::NonFungibleLocalId,
+            "AddressReservation" => ManifestCustomValueKind::AddressReservation,
+            _ => {
+                return Err(ManifestJsonError::UnknownValueKind {
+                    path: path.into(),
+                    kind: kind.into(),
+                })
+            }
+        };
+        let value = expect_str(object, "value", path)?;
+        let invalid = || invalid_value(path, ValueKind::Custom(custom_value_kind), value);
+
+        let custom_value = match custom_value_kind {
+            ManifestCustomValueKind::Address => {
+                ManifestCustomValue::Address(self.parse_address(value).ok_or_else(invalid)?)
+            }
+            ManifestCustomValueKind::Bucket => {
+                ManifestCustomValue::Bucket(ManifestBucket(value.parse().map_err(|_| invalid())?))
+            }
+            ManifestCustomValueKind::Proof => {
+                ManifestCustomValue::Proof(ManifestProof(value.parse().map_err(|_| invalid())?))
+            }
+            ManifestCustomValueKind::AddressReservation => ManifestCustomValue::AddressReservation(
+                ManifestAddressReservation(value.parse().map_err(|_| invalid())?),
+            ),
+            ManifestCustomValueKind::Expression => ManifestCustomValue::Expression(match value {
+                "ENTIRE_WORKTOP" => ManifestExpression::EntireWorktop,
+                "ENTIRE_AUTH_ZONE" => ManifestExpression::EntireAuthZone,
+                _ => return Err(invalid()),
+            }),
+            ManifestCustomValueKind::Blob => ManifestCustomValue::Blob(ManifestBlobRef(
+                hex::decode(value)
+                    .ok()
+                    .and_then(|bytes| bytes.try_into().ok())
+                    .ok_or_else(invalid)?,
+            )),
+            ManifestCustomValueKind::Decimal => ManifestCustomValue::Decimal(from_decimal(
+                &Decimal::from_str(value).map_err(|_| invalid())?,
+            )),
+            ManifestCustomValueKind::PreciseDecimal => ManifestCustomValue::PreciseDecimal(
+                from_precise_decimal(&PreciseDecimal::from_str(value).map_err(|_| invalid())?),
+            ),
+            ManifestCustomValueKind::NonFungibleLocalId => {
+                ManifestCustomValue::NonFungibleLocalId(from_non_fungible_local_id(
+                    NonFungibleLocalId::from_str(value).map_err(|_| invalid())?,
+                ))
+            }
+        };
+
+        Ok(custom_value)
+    }
+
+    fn parse_address(&self, value: &str) -> Option<ManifestAddress> {
+        if let Ok(address_id) = value.parse::<u32>() {
+            return Some(ManifestAddress::Named(address_id));
+        }
+        let node_id_bytes = match hex::decode(value) {
+            Ok(bytes) => bytes,
+            Err(_) => {
+                self.address_bech32_decoder?
+                    .validate_and_decode(value)
+                    .ok()?
+                    .1
+            }
+        };
+        Some(ManifestAddress::Static(NodeId(
+            node_id_bytes.try_into().ok()?,
+        )))
+    }
+
+    fn parse_values(
+        &self,
+        object: &JsonMap<String, JsonValue>,
+        field: &'static str,
+        expected_value_kind: Option<ManifestValueKind>,
+        path: &str,
+        depth: usize,
+    ) -> Result<Vec<ManifestValue>, ManifestJsonError> {
+        expect_array(object, field, path)?
+            .iter()
+            .enumerate()
+            .map(|(index, element)| {
+                self.parse_child(
+                    element,
+                    &format!("{}/{}/{}", path, field, index),
+                    expected_value_kind,
+                    depth,
+                )
+            })
+            .collect()
+    }
+
+    fn parse_child(
+        &self,
+        json: &JsonValue,
+        path: &str,
+        expected_value_kind: Option<ManifestValueKind>,
+        depth: usize,
+    ) -> Result<ManifestValue, ManifestJsonError> {
+        let value = self.parse_value(json, path, depth + 1)?;
+        if let Some(expected) = expected_value_kind {
+            let actual = value.get_value_kind();
+            if actual != expected {
+                return Err(ManifestJsonError::MismatchingValueKind {
+                    path: path.into(),
+                    expected,
+                    actual,
+                });
+            }
+        }
+        Ok(value)
+    }
+}
+
+fn parse_value_kind(
+    object: &JsonMap<String, JsonValue>,
+    field: &'static str,
+    path: &str,
+) -> Result<ManifestValueKind, ManifestJsonError> {
+    let kind = expect_str(object, field, path)?;
+    let value_kind = match kind {
+        "Bool" => ValueKind::Bool,
+        "I8" => ValueKind::I8,
+        "I16" => ValueKind::I16,
+        "I32" => ValueKind::I32,
+        "I64" => ValueKind::I64,
+        "I128" => ValueKind::I128,
+        "U8" => ValueKind::U8,
+        "U16" => ValueKind::U16,
+        "U32" => ValueKind::U32,
+        "U64" => ValueKind::U64,
+        "U128" => ValueKind::U128,
+        "String" => ValueKind::String,
+        "Tuple" => ValueKind::Tuple,
+        "Enum" => ValueKind::Enum,
+        "Array" => ValueKind::Array,
+        "Map" => ValueKind::Map,
+        "Address" => ValueKind::Custom(ManifestCustomValueKind::Address),
+        "Bucket" => ValueKind::Custom(ManifestCustomValueKind::Bucket),
+        "Proof" => ValueKind::Custom(ManifestCustomValueKind::Proof),
+        "Expression" => ValueKind::Custom(ManifestCustomValueKind::Expression),
+        "Blob" => ValueKind::Custom(ManifestCustomValueKind::Blob),
+        "Decimal" => ValueKind::Custom(ManifestCustomValueKind::Decimal),
+        "PreciseDecimal" => ValueKind::Custom(ManifestCustomValueKind::PreciseDecimal),
+        "NonFungibleLocalId" => ValueKind::Custom(ManifestCustomValueKind::NonFungibleLocalId),
+        "AddressReservation" => ValueKind::Custom(ManifestCustomValueKind::AddressReservation),
+        _ => {
+            return Err(ManifestJsonError::UnknownValueKind {
+                path: format!("{}/{}", path, field),
+                kind: kind.into(),
+            })
+        }
+    };
+    Ok(value_kind)
+}
+
+fn parse_integer<T: FromStr>(
+    object: &JsonMap<String, JsonValue>,
+    kind: ManifestValueKind,
+    path: &str,
+) -> Result<T, ManifestJsonError> {
+    let value = match expect_field(object, "value", path)? {
+        JsonValue::Number(number) => number.to_string(),
+        JsonValue::String(string) => string.clone(),
+        _ => return Err(unexpected_json(path, "value", "a number or a string")),
+    };
+    value.parse().map_err(|_| invalid_value(path, kind, &value))
+}
+
+/// Parses the `variant_id` of an enum, which [`to_json`] outputs as a string.
+fn parse_variant_id(
+    object: &JsonMap<String, JsonValue>,
+    path: &str,
+) -> Result<u8, ManifestJsonError> {
+    let variant_id = match expect_field(object, "variant_id", path)? {
+        JsonValue::Number(number) => number.to_string(),
+        JsonValue::String(string) => string.clone(),
+        _ => return Err(unexpected_json(path, "variant_id", "a number or a string")),
+    };
+    variant_id
+        .parse()
+        .map_err(|_| unexpected_json(path, "variant_id", "a number up to 255"))
+}
+
+fn expect_object<'j>(
+    json: &'j JsonValue,
+    path: &str,
+) -> Result<&'j JsonMap<String, JsonValue>, ManifestJsonError> {
+    json.as_object()
+        .ok_or_else(|| ManifestJsonError::UnexpectedJson {
+            path: path.into(),
+            expected: "an object",
+        })
+}
+
+fn expect_field<'j>(
+    object: &'j JsonMap<String, JsonValue>,
+    field: &'static str,
+    path: &str,
+) -> Result<&'j JsonValue, ManifestJsonError> {
+    object
+        .get(field)
+        .ok_or_else(|| unexpected_json(path, field, "a field"))
+}
+
+fn expect_str<'j>(
+    object: &'j JsonMap<String, JsonValue>,
+    field: &'static str,
+    path: &str,
+) -> Result<&'j str, ManifestJsonError> {
+    expect_field(object, field, path)?
+        .as_str()
+        .ok_or_else(|| unexpected_json(path, field, "a string"))
+}
+
+fn expect_array<'j>(
+    object: &'j JsonMap<String, JsonValue>,
+    field: &'static str,
+    path: &str,
+) -> Result<&'j Vec<JsonValue>, ManifestJsonError> {
+    expect_field(object, field, path)?
+        .as_array()
+        .ok_or_else(|| unexpected_json(path, field, "an array"))
+}
+
+fn unexpected_json(path: &str, field: &str, expected: &'static str) -> ManifestJsonError {
+    ManifestJsonError::UnexpectedJson {
+        path: format!("{}/{}", path, field),
+        expected,
+    }
+}
+
+fn invalid_value(path: &str, kind: ManifestValueKind, value: &str) -> ManifestJsonError {
+    ManifestJsonError::InvalidValue {
+        path: path.into(),
+        kind,
+        value: value.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn sample_value() -> ManifestValue {
+        ManifestValue::Tuple {
+            fields: vec![
+                ManifestValue::Bool { value: true },
+                ManifestValue::I8 { value: -5 },
+                ManifestValue::U64 { value: u64::MAX },
+                ManifestValue::I128 { value: i128::MIN },
+                ManifestValue::String {
+                    value: "hello".into(),
+                },
+                ManifestValue::Enum {
+                    discriminator: 1,
+                    fields: vec![ManifestValue::U32 { value: 7 }],
+                },
+                ManifestValue::Array {
+                    element_value_kind: ValueKind::U8,
+                    elements: vec![
+                        ManifestValue::U8 { value: 10 },
+                        ManifestValue::U8 { value: 11 },
+                    ],
+                },
+                ManifestValue::Array {
+                    element_value_kind: ValueKind::String,
+                    elements: vec![ManifestValue::String { value: "a".into() }],
+                },
+                ManifestValue::Map {
+                    key_value_kind: ValueKind::U16,
+                    value_value_kind: ValueKind::Custom(ManifestCustomValueKind::Decimal),
+                    entries: vec![(
+                        ManifestValue::U16 { value: 1 },
+                        ManifestValue::Custom {
+                            value: ManifestCustomValue::Decimal(from_decimal(&dec!("1.5"))),
+                        },
+                    )],
+                },
+                ManifestValue::Custom {
+                    value: ManifestCustomValue::Address(ManifestAddress::Static(
+                        XRD.into_node_id(),
+                    )),
+                },
+                ManifestValue::Custom {
+                    value: ManifestCustomValue::Address(ManifestAddress::Named(2)),
+                },
+                ManifestValue::Custom {
+                    value: ManifestCustomValue::Bucket(ManifestBucket(3)),
+                },
+                ManifestValue::Custom {
+                    value: ManifestCustomValue::Proof(ManifestProof(4)),
+                },
+                ManifestValue::Custom {
+                    value: ManifestCustomValue::AddressReservation(ManifestAddressReservation(5)),
+                },
+                ManifestValue::Custom {
+                    value: ManifestCustomValue::Expression(ManifestExpression::EntireWorktop),
+                },
+                ManifestValue::Custom {
+                    value: ManifestCustomValue::Blob(ManifestBlobRef([7; 32])),
+                },
+                ManifestValue::Custom {
+                    value: ManifestCustomValue::PreciseDecimal(from_precise_decimal(&pdec!(
+                        "-0.25"
+                    ))),
+                },
+                ManifestValue::Custom {
+                    value: ManifestCustomValue::NonFungibleLocalId(
+                        ManifestNonFungibleLocalId::integer(9).unwrap(),
+                    ),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_json_round_trip_without_network() {
+        let value = sample_value();
+
+        let json = to_json(&value, None).unwrap();
+
+        assert_eq!(from_json(&json, None), Ok(value));
+    }
+
+    #[test]
+    fn test_json_round_trip_with_network() {
+        let network = NetworkDefinition::simulator();
+        let value = sample_value();
+
+        let json = to_json(&value, Some(&AddressBech32Encoder::new(&network))).unwrap();
+
+        assert_eq!(
+            from_json(&json, Some(&AddressBech32Decoder::new(&network))),
+            Ok(value)
+        );
+        assert_eq!(
+            from_json(&json, None),
+            Err(ManifestJsonError::InvalidValue {
+                path: "/fields/9".into(),
+                kind: ValueKind::Custom(ManifestCustomValueKind::Address),
+                value: AddressBech32Encoder::new(&network)
+                    .encode(XRD.as_node_id().as_ref())
+                    .unwrap(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_hand_written_json() {
+        let json = json!({
+            "kind": "Tuple",
+            "fields": [
+                { "kind": "U8", "value": 5 },
+                { "kind": "U64", "value": "5" },
+                { "kind": "Decimal", "value": "1.5", "field_name": "amount" },
+                { "kind": "Bytes", "element_kind": "U8", "hex": "0a0b" }
+            ]
+        });
+
+        assert_eq!(
+            from_json(&json, None),
+            Ok(ManifestValue::Tuple {
+                fields: vec![
+                    ManifestValue::U8 { value: 5 },
+                    ManifestValue::U64 { value: 5 },
+                    ManifestValue::Custom {
+                        value: ManifestCustomValue::Decimal(from_decimal(&dec!("1.5"))),
+                    },
+                    ManifestValue::Array {
+                        element_value_kind: ValueKind::U8,
+                        elements: vec![
+                            ManifestValue::U8 { value: 10 },
+                            ManifestValue::U8 { value: 11 }
+                        ],
+                    },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_invalid_json() {
+        assert_eq!(
+            from_json(&json!({ "kind": "U8", "value": 256 }), None),
+            Err(ManifestJsonError::InvalidValue {
+                path: "".into(),
+                kind: ValueKind::U8,
+                value: "256".into(),
+            })
+        );
+        assert_eq!(
+            from_json(&json!({ "kind": "Unknown" }), None),
+            Err(ManifestJsonError::UnknownValueKind {
+                path: "".into(),
+                kind: "Unknown".into(),
+            })
+        );
+        assert_eq!(
+            from_json(
+                &json!({
+                    "kind": "Array",
+                    "element_kind": "U8",
+                    "elements": [{ "kind": "U16", "value": 1 }]
+                }),
+                None
+            ),
+            Err(ManifestJsonError::MismatchingValueKind {
+                path: "/elements/0".into(),
+                expected: ValueKind::U8,
+                actual: ValueKind::U16,
+            })
+        );
+        assert_eq!(
+            from_json(&json!({ "kind": "Tuple" }), None),
+            Err(ManifestJsonError::UnexpectedJson {
+                path: "/fields".into(),
+                expected: "a field",
+            })
+        );
+    }
+}
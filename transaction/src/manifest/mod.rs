@@ -1,3 +1,4 @@
+pub mod analyzer;
 pub mod ast;
 pub mod blob_provider;
 pub mod compiler;
@@ -6,11 +7,17 @@ pub mod decompiler;
 pub mod dumper;
 pub mod e2e;
 pub mod generator;
+#[cfg(feature = "serde")]
+pub mod json;
 pub mod lexer;
 pub mod manifest_enums;
 pub mod parser;
+pub mod roundtrip;
 
 pub use blob_provider::*;
 pub use compiler::{compile, CompileError};
-pub use decompiler::{decompile, DecompileError};
+pub use decompiler::{decompile, DecompileError, ObjectNaming};
+#[cfg(feature = "serde")]
+pub use json::{from_json, to_json, ManifestJsonError};
 pub use manifest_enums::*;
+pub use roundtrip::{verify_roundtrip, ManifestRoundtripReport};
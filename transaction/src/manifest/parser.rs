@@ -38,9 +38,13 @@ pub enum InstructionIdent {
     AssertWorktopContains,
     AssertWorktopContainsNonFungibles,
     AssertWorktopContainsAny,
+    AssertWorktopResourcesOnly,
+    YieldWorktopContents,
+    BurnResourceFromWorktop,
 
     PopFromAuthZone,
     PushToAuthZone,
+    PushCompositeToAuthZone,
     CreateProofFromAuthZoneOfAmount,
     CreateProofFromAuthZoneOfNonFungibles,
     CreateProofFromAuthZoneOfAll,
@@ -61,6 +65,8 @@ pub enum InstructionIdent {
     DropNamedProofs,
     DropAllProofs,
     AllocateGlobalAddress,
+    AssertComponentBlueprint,
+    SetInstructionCostLimit,
 
     // ==============
     // Call direct vault method aliases
@@ -123,9 +129,13 @@ impl InstructionIdent {
                 InstructionIdent::AssertWorktopContainsNonFungibles
             }
             "ASSERT_WORKTOP_CONTAINS_ANY" => InstructionIdent::AssertWorktopContainsAny,
+            "ASSERT_WORKTOP_RESOURCES_ONLY" => InstructionIdent::AssertWorktopResourcesOnly,
+            "YIELD_WORKTOP_CONTENTS" => InstructionIdent::YieldWorktopContents,
+            "BURN_RESOURCE_FROM_WORKTOP" => InstructionIdent::BurnResourceFromWorktop,
 
             "POP_FROM_AUTH_ZONE" => InstructionIdent::PopFromAuthZone,
             "PUSH_TO_AUTH_ZONE" => InstructionIdent::PushToAuthZone,
+            "PUSH_COMPOSITE_TO_AUTH_ZONE" => InstructionIdent::PushCompositeToAuthZone,
             "CREATE_PROOF_FROM_AUTH_ZONE_OF_AMOUNT" => {
                 InstructionIdent::CreateProofFromAuthZoneOfAmount
             }
@@ -156,6 +166,8 @@ impl InstructionIdent {
             "DROP_NAMED_PROOFS" => InstructionIdent::DropNamedProofs,
             "DROP_ALL_PROOFS" => InstructionIdent::DropAllProofs,
             "ALLOCATE_GLOBAL_ADDRESS" => InstructionIdent::AllocateGlobalAddress,
+            "ASSERT_COMPONENT_BLUEPRINT" => InstructionIdent::AssertComponentBlueprint,
+            "SET_INSTRUCTION_COST_LIMIT" => InstructionIdent::SetInstructionCostLimit,
 
             // ==============
             // Call direct vault method aliases
@@ -516,12 +528,25 @@ impl Parser {
             InstructionIdent::AssertWorktopContainsAny => Instruction::AssertWorktopContainsAny {
                 resource_address: self.parse_value()?,
             },
+            InstructionIdent::AssertWorktopResourcesOnly => {
+                Instruction::AssertWorktopResourcesOnly {
+                    resource_addresses: self.parse_value()?,
+                }
+            }
+            InstructionIdent::YieldWorktopContents => Instruction::YieldWorktopContents,
+            InstructionIdent::BurnResourceFromWorktop => Instruction::BurnResourceFromWorktop {
+                resource_address: self.parse_value()?,
+                amount: self.parse_value()?,
+            },
             InstructionIdent::PopFromAuthZone => Instruction::PopFromAuthZone {
                 new_proof: self.parse_value()?,
             },
             InstructionIdent::PushToAuthZone => Instruction::PushToAuthZone {
                 proof: self.parse_value()?,
             },
+            InstructionIdent::PushCompositeToAuthZone => Instruction::PushCompositeToAuthZone {
+                proofs: self.parse_value()?,
+            },
             InstructionIdent::DropAuthZoneProofs => Instruction::DropAuthZoneProofs,
             InstructionIdent::DropAuthZoneRegularProofs => Instruction::DropAuthZoneRegularProofs,
             InstructionIdent::DropAuthZoneSignatureProofs => {
@@ -613,6 +638,14 @@ impl Parser {
                 address_reservation: self.parse_value()?,
                 named_address: self.parse_value()?,
             },
+            InstructionIdent::AssertComponentBlueprint => Instruction::AssertComponentBlueprint {
+                component_address: self.parse_value()?,
+                package_address: self.parse_value()?,
+                blueprint_name: self.parse_value()?,
+            },
+            InstructionIdent::SetInstructionCostLimit => Instruction::SetInstructionCostLimit {
+                cost_units: self.parse_value()?,
+            },
 
             /* Call direct vault method aliases */
             InstructionIdent::RecallFromVault => Instruction::RecallFromVault {
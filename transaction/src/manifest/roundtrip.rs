@@ -0,0 +1,171 @@
+use crate::internal_prelude::*;
+
+#[derive(Debug, Clone)]
+pub enum ManifestRoundtripError {
+    DecodeError(DecodeError),
+    DecompileError(DecompileError),
+    /// The decompiled manifest couldn't be compiled back.
+    CompileError {
+        decompiled: String,
+        error: CompileError,
+    },
+}
+
+/// A way in which a manifest doesn't survive a decompile-compile round trip.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ManifestRoundtripMismatch {
+    InstructionCount {
+        original: usize,
+        recompiled: usize,
+    },
+    /// The first instruction which differs after recompilation.
+    Instruction {
+        index: usize,
+        original: InstructionV1,
+        recompiled: InstructionV1,
+    },
+    /// The first line (numbered from 1) which differs when decompiling the recompiled manifest.
+    Text {
+        line: usize,
+        original: Option<String>,
+        recompiled: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestRoundtripReport {
+    /// The decompiled manifest, with [`ObjectNaming::Stable`] naming.
+    pub canonical_manifest: String,
+    pub mismatches: Vec<ManifestRoundtripMismatch>,
+}
+
+impl ManifestRoundtripReport {
+    /// Whether decompiling the manifest and compiling it back gives the exact same manifest
+    /// (and the exact same text when decompiled again).
+    pub fn is_exact(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+/// Checks that the given manifest-encoded [`TransactionManifestV1`] round-trips through
+/// [`decompile`] and [`compile`]: i.e. that the text shown for a binary manifest is an accurate
+/// and stable representation of it.
+pub fn verify_roundtrip(
+    manifest_bytes: &[u8],
+    network: &NetworkDefinition,
+) -> Result<ManifestRoundtripReport, ManifestRoundtripError> {
+    let original = manifest_decode::<TransactionManifestV1>(manifest_bytes)
+        .map_err(ManifestRoundtripError::DecodeError)?;
+    verify_manifest_roundtrip(&original, network)
+}
+
+pub fn verify_manifest_roundtrip(
+    original: &TransactionManifestV1,
+    network: &NetworkDefinition,
+) -> Result<ManifestRoundtripReport, ManifestRoundtripError> {
+    let decompiled = decompile(&original.instructions, network)
+        .map_err(ManifestRoundtripError::DecompileError)?;
+    let blob_provider = BlobProvider::new_with_blobs(original.blobs.values().cloned().collect());
+    let recompiled = match compile(&decompiled, network, blob_provider) {
+        Ok(recompiled) => recompiled,
+        Err(error) => return Err(ManifestRoundtripError::CompileError { decompiled, error }),
+    };
+
+    let mut mismatches = Vec::new();
+
+    if original.instructions.len() != recompiled.instructions.len() {
+        mismatches.push(ManifestRoundtripMismatch::InstructionCount {
+            original: original.instructions.len(),
+            recompiled: recompiled.instructions.len(),
+        });
+    }
+    if let Some((index, (original_instruction, recompiled_instruction))) = original
+        .instructions
+        .iter()
+        .zip(recompiled.instructions.iter())
+        .enumerate()
+        .find(|(_, (original_instruction, recompiled_instruction))| {
+            original_instruction != recompiled_instruction
+        })
+    {
+        mismatches.push(ManifestRoundtripMismatch::Instruction {
+            index,
+            original: original_instruction.clone(),
+            recompiled: recompiled_instruction.clone(),
+        });
+    }
+
+    let redecompiled = decompile(&recompiled.instructions, network)
+        .map_err(ManifestRoundtripError::DecompileError)?;
+    if decompiled != redecompiled {
+        let mut original_lines = decompiled.lines();
+        let mut recompiled_lines = redecompiled.lines();
+        let mut line = 1;
+        loop {
+            let original = original_lines.next();
+            let recompiled = recompiled_lines.next();
+            if original != recompiled {
+                mismatches.push(ManifestRoundtripMismatch::Text {
+                    line,
+                    original: original.map(str::to_string),
+                    recompiled: recompiled.map(str::to_string),
+                });
+                break;
+            }
+            if original.is_none() {
+                // Only the line endings differ
+                mismatches.push(ManifestRoundtripMismatch::Text {
+                    line,
+                    original: None,
+                    recompiled: None,
+                });
+                break;
+            }
+            line += 1;
+        }
+    }
+
+    Ok(ManifestRoundtripReport {
+        canonical_manifest: decompiled,
+        mismatches,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manifest::decompiler::decompile_with_naming;
+
+    #[test]
+    fn built_manifest_roundtrips_with_stable_naming() {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .get_free_xrd_from_faucet()
+            .take_from_worktop(XRD, 10, "free_xrd")
+            .create_proof_from_bucket_of_all("free_xrd", "free_xrd_proof")
+            .drop_proof("free_xrd_proof")
+            .return_to_worktop("free_xrd")
+            .try_deposit_entire_worktop_or_abort(FAUCET, None)
+            .build();
+        let network = NetworkDefinition::simulator();
+
+        let report = verify_roundtrip(&manifest_encode(&manifest).unwrap(), &network).unwrap();
+
+        assert!(report.is_exact());
+        assert!(report.canonical_manifest.contains("Bucket(\"bucket1\")"));
+        assert!(report.canonical_manifest.contains("Proof(\"proof1\")"));
+        assert!(!report.canonical_manifest.contains("free_xrd"));
+        assert_eq!(
+            decompile_with_naming(&manifest.instructions, &network, ObjectNaming::Stable).unwrap(),
+            report.canonical_manifest
+        );
+    }
+
+    #[test]
+    fn invalid_manifest_bytes_are_reported() {
+        assert!(matches!(
+            verify_roundtrip(&[0u8, 1, 2], &NetworkDefinition::simulator()),
+            Err(ManifestRoundtripError::DecodeError(_))
+        ));
+    }
+}
@@ -63,7 +63,8 @@ impl From<(BlueprintId, GlobalAddress)> for PreAllocatedAddress {
 
 #[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor, ManifestSbor)]
 pub struct TransactionCostingParameters {
-    pub tip_percentage: u16,
+    /// The tip on top of the cost unit prices
+    pub tip: TipBasisPoints,
     /// Free credit for execution, for preview only!
     pub free_credit_in_xrd: Decimal,
 }
@@ -71,7 +72,7 @@ pub struct TransactionCostingParameters {
 impl Default for TransactionCostingParameters {
     fn default() -> Self {
         Self {
-            tip_percentage: DEFAULT_TIP_PERCENTAGE,
+            tip: TipBasisPoints::from_percentage(DEFAULT_TIP_PERCENTAGE),
             free_credit_in_xrd: Default::default(),
         }
     }
@@ -140,6 +141,11 @@ impl<'a> Executable<'a> {
         self.context.epoch_range = None;
     }
 
+    /// Overwrites the tip, which transaction headers can only set in whole percents.
+    pub fn overwrite_tip(&mut self, tip: TipBasisPoints) {
+        self.context.costing_parameters.tip = tip;
+    }
+
     pub fn costing_parameters(&self) -> &TransactionCostingParameters {
         &self.context.costing_parameters
     }
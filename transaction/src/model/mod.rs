@@ -2,6 +2,7 @@ mod concepts;
 mod executable;
 mod hash;
 mod preparation;
+mod tip;
 mod v1;
 mod versioned;
 
@@ -9,6 +10,7 @@ pub use concepts::*;
 pub use executable::*;
 pub use hash::*;
 pub use preparation::*;
+pub use tip::*;
 pub use v1::*;
 pub use versioned::*;
 
@@ -227,7 +229,7 @@ Enum<3u8>(
                         virtual_resources: btreeset!()
                     },
                     costing_parameters: TransactionCostingParameters {
-                        tip_percentage: 4,
+                        tip: TipBasisPoints(400),
                         free_credit_in_xrd: dec!(0)
                     }
                 }
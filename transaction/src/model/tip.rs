@@ -0,0 +1,296 @@
+use crate::internal_prelude::*;
+
+/// The number of basis points in one percent.
+pub const BASIS_POINTS_PER_PERCENT: u32 = 100;
+
+/// The number of basis points in one hundred percent.
+pub const BASIS_POINTS_PER_UNIT: u32 = 100 * BASIS_POINTS_PER_PERCENT;
+
+/// A tip on top of the cost unit prices, in basis points.
+///
+/// 10_000 means 100%
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, ScryptoSbor, ManifestSbor,
+)]
+#[sbor(transparent)]
+pub struct TipBasisPoints(pub u32);
+
+impl TipBasisPoints {
+    pub const ZERO: Self = Self(0);
+
+    pub fn from_percentage(tip_percentage: u16) -> Self {
+        Self(u32::from(tip_percentage) * BASIS_POINTS_PER_PERCENT)
+    }
+
+    /// Returns the smallest whole percentage which is at least this tip, as set in transaction
+    /// headers, saturating at `u16::MAX`.
+    pub fn to_percentage_rounded_up(&self) -> u16 {
+        let percentage = (self.0 + BASIS_POINTS_PER_PERCENT - 1) / BASIS_POINTS_PER_PERCENT;
+        percentage.try_into().unwrap_or(u16::MAX)
+    }
+
+    /// Returns the largest whole percentage which is at most this tip, saturating at `u16::MAX`.
+    pub fn to_percentage_rounded_down(&self) -> u16 {
+        (self.0 / BASIS_POINTS_PER_PERCENT)
+            .try_into()
+            .unwrap_or(u16::MAX)
+    }
+
+    /// Returns the factor the cost unit prices are multiplied by, i.e. `1 + tip`.
+    pub fn price_multiplier(&self) -> Decimal {
+        // Below unwraps are safe, as a `u32` number of basis points can't overflow
+        Decimal::ONE
+            .checked_add(
+                Decimal::from(self.0)
+                    .checked_div(BASIS_POINTS_PER_UNIT)
+                    .unwrap(),
+            )
+            .unwrap()
+    }
+
+    /// Returns the cost unit price with this tip applied.
+    pub fn apply_to_cost_unit_price(&self, cost_unit_price: Decimal) -> Option<Decimal> {
+        cost_unit_price.checked_mul(self.price_multiplier())
+    }
+
+    /// Returns the tip to pay on top of the given amount of XRD.
+    pub fn tip_on(&self, amount: Decimal) -> Option<Decimal> {
+        amount
+            .checked_mul(self.0)
+            .and_then(|tip| tip.checked_div(BASIS_POINTS_PER_UNIT))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ScryptoSbor)]
+pub enum TipError {
+    BelowFloor {
+        tip: TipBasisPoints,
+        floor: TipBasisPoints,
+    },
+    AboveCap {
+        tip: TipBasisPoints,
+        cap: TipBasisPoints,
+    },
+}
+
+/// The inclusive range of accepted tips.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ScryptoSbor)]
+pub struct TipBounds {
+    pub floor: TipBasisPoints,
+    pub cap: TipBasisPoints,
+}
+
+impl Default for TipBounds {
+    fn default() -> Self {
+        Self::from_percentages(MIN_TIP_PERCENTAGE, MAX_TIP_PERCENTAGE)
+    }
+}
+
+impl TipBounds {
+    pub fn new(floor: TipBasisPoints, cap: TipBasisPoints) -> Self {
+        assert!(floor <= cap, "Tip floor must not be above the cap");
+        Self { floor, cap }
+    }
+
+    pub fn from_percentages(min_tip_percentage: u16, max_tip_percentage: u16) -> Self {
+        Self::new(
+            TipBasisPoints::from_percentage(min_tip_percentage),
+            TipBasisPoints::from_percentage(max_tip_percentage),
+        )
+    }
+
+    pub fn validate(&self, tip: TipBasisPoints) -> Result<(), TipError> {
+        if tip < self.floor {
+            return Err(TipError::BelowFloor {
+                tip,
+                floor: self.floor,
+            });
+        }
+        if tip > self.cap {
+            return Err(TipError::AboveCap { tip, cap: self.cap });
+        }
+        Ok(())
+    }
+
+    pub fn clamp(&self, tip: TipBasisPoints) -> TipBasisPoints {
+        tip.clamp(self.floor, self.cap)
+    }
+
+    /// Returns the tip rounded up to a whole percentage, or down if that is above the cap, as
+    /// long as the result is within the bounds.
+    pub fn whole_percentage_within(&self, tip: TipBasisPoints) -> Option<u16> {
+        [
+            tip.to_percentage_rounded_up(),
+            tip.to_percentage_rounded_down(),
+        ]
+        .into_iter()
+        .find(|tip_percentage| {
+            self.validate(TipBasisPoints::from_percentage(*tip_percentage))
+                .is_ok()
+        })
+    }
+}
+
+/// A policy for suggesting the tip of a transaction, e.g. based on the tips of recent
+/// transactions. Mempool policies and wallets can share one through [`suggested_tip`].
+pub trait TipPolicy {
+    fn suggested_tip(&self, recent_tips: &[TipBasisPoints]) -> TipBasisPoints;
+}
+
+/// Always suggests the same tip.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedTipPolicy(pub TipBasisPoints);
+
+impl TipPolicy for FixedTipPolicy {
+    fn suggested_tip(&self, _recent_tips: &[TipBasisPoints]) -> TipBasisPoints {
+        self.0
+    }
+}
+
+/// Suggests the given percentile of the recent tips, or no tip if there are none.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PercentileTipPolicy {
+    pub percentile: u8,
+}
+
+impl TipPolicy for PercentileTipPolicy {
+    fn suggested_tip(&self, recent_tips: &[TipBasisPoints]) -> TipBasisPoints {
+        if recent_tips.is_empty() {
+            return TipBasisPoints::ZERO;
+        }
+        let mut tips = recent_tips.to_vec();
+        tips.sort();
+        let percentile = usize::from(self.percentile.min(100));
+        // Nearest-rank method
+        let rank = (percentile * tips.len() + 99) / 100;
+        tips[rank.saturating_sub(1)]
+    }
+}
+
+/// Returns the tip suggested by the policy, clamped to the bounds.
+pub fn suggested_tip<P: TipPolicy + ?Sized>(
+    policy: &P,
+    bounds: &TipBounds,
+    recent_tips: &[TipBasisPoints],
+) -> TipBasisPoints {
+    bounds.clamp(policy.suggested_tip(recent_tips))
+}
+
+/// Returns the tip percentage to set in a V1 transaction header, which can only carry whole
+/// percents: the [`suggested_tip`] rounded to a whole percentage within the bounds.
+///
+/// Returns `None` if there is no whole percentage within the bounds, e.g. 150 to 180 basis points.
+pub fn suggested_tip_percentage<P: TipPolicy + ?Sized>(
+    policy: &P,
+    bounds: &TipBounds,
+    recent_tips: &[TipBasisPoints],
+) -> Option<u16> {
+    bounds.whole_percentage_within(suggested_tip(policy, bounds, recent_tips))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tip_math() {
+        let tip = TipBasisPoints(250);
+        assert_eq!(tip.price_multiplier(), dec!("1.025"));
+        assert_eq!(tip.apply_to_cost_unit_price(dec!(2)), Some(dec!("2.05")));
+        assert_eq!(tip.tip_on(dec!(10)), Some(dec!("0.25")));
+        assert_eq!(tip.to_percentage_rounded_up(), 3);
+        assert_eq!(tip.to_percentage_rounded_down(), 2);
+        assert_eq!(TipBasisPoints::from_percentage(3), TipBasisPoints(300));
+    }
+
+    #[test]
+    fn test_tip_bounds() {
+        let bounds = TipBounds::new(TipBasisPoints(100), TipBasisPoints(1050));
+        assert_eq!(bounds.validate(TipBasisPoints(100)), Ok(()));
+        assert_eq!(
+            bounds.validate(TipBasisPoints(99)),
+            Err(TipError::BelowFloor {
+                tip: TipBasisPoints(99),
+                floor: TipBasisPoints(100),
+            })
+        );
+        assert_eq!(
+            bounds.validate(TipBasisPoints(1051)),
+            Err(TipError::AboveCap {
+                tip: TipBasisPoints(1051),
+                cap: TipBasisPoints(1050),
+            })
+        );
+    }
+
+    #[test]
+    fn test_suggested_tip() {
+        let bounds = TipBounds::new(TipBasisPoints(100), TipBasisPoints(1050));
+        let recent_tips = [0, 200, 350, 5000].map(TipBasisPoints);
+
+        assert_eq!(
+            suggested_tip(&FixedTipPolicy(TipBasisPoints::ZERO), &bounds, &[]),
+            TipBasisPoints(100)
+        );
+        assert_eq!(
+            suggested_tip(
+                &PercentileTipPolicy { percentile: 75 },
+                &bounds,
+                &recent_tips
+            ),
+            TipBasisPoints(350)
+        );
+        assert_eq!(
+            suggested_tip(
+                &PercentileTipPolicy { percentile: 100 },
+                &bounds,
+                &recent_tips
+            ),
+            TipBasisPoints(1050)
+        );
+    }
+
+    #[test]
+    fn test_suggested_tip_percentage() {
+        let bounds = TipBounds::new(TipBasisPoints(100), TipBasisPoints(1050));
+        let recent_tips = [0, 200, 350, 5000].map(TipBasisPoints);
+
+        assert_eq!(
+            suggested_tip_percentage(&FixedTipPolicy(TipBasisPoints::ZERO), &bounds, &[]),
+            Some(1)
+        );
+        assert_eq!(
+            suggested_tip_percentage(
+                &PercentileTipPolicy { percentile: 75 },
+                &bounds,
+                &recent_tips
+            ),
+            Some(4)
+        );
+        assert_eq!(
+            suggested_tip_percentage(
+                &PercentileTipPolicy { percentile: 100 },
+                &bounds,
+                &recent_tips
+            ),
+            Some(10)
+        );
+
+        // Both bounds within the same whole percentage
+        let bounds = TipBounds::new(TipBasisPoints(150), TipBasisPoints(180));
+        assert_eq!(
+            suggested_tip_percentage(&FixedTipPolicy(TipBasisPoints(160)), &bounds, &[]),
+            None
+        );
+        let bounds = TipBounds::new(TipBasisPoints(150), TipBasisPoints(200));
+        assert_eq!(
+            suggested_tip_percentage(&FixedTipPolicy(TipBasisPoints(160)), &bounds, &[]),
+            Some(2)
+        );
+        let bounds = TipBounds::new(TipBasisPoints(100), TipBasisPoints(180));
+        assert_eq!(
+            suggested_tip_percentage(&FixedTipPolicy(TipBasisPoints(160)), &bounds, &[]),
+            Some(1)
+        );
+    }
+}
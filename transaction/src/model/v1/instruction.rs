@@ -568,6 +568,24 @@ pub enum InstructionV1 {
         ids: Vec<NonFungibleLocalId>,
     },
 
+    /// Asserts worktop contains no resources other than the given ones.
+    #[sbor(discriminator(INSTRUCTION_ASSERT_WORKTOP_RESOURCES_ONLY_DISCRIMINATOR))]
+    AssertWorktopResourcesOnly {
+        resource_addresses: Vec<ResourceAddress>,
+    },
+
+    /// Outputs the amount of each resource on the worktop at this point, as an
+    /// `IndexMap<ResourceAddress, Decimal>`.
+    #[sbor(discriminator(INSTRUCTION_YIELD_WORKTOP_CONTENTS_DISCRIMINATOR))]
+    YieldWorktopContents,
+
+    /// Takes resource from worktop by the given amount, and burns it.
+    #[sbor(discriminator(INSTRUCTION_BURN_RESOURCE_FROM_WORKTOP_DISCRIMINATOR))]
+    BurnResourceFromWorktop {
+        resource_address: ResourceAddress,
+        amount: Decimal,
+    },
+
     //==============
     // Auth zone
     //==============
@@ -579,6 +597,11 @@ pub enum InstructionV1 {
     #[sbor(discriminator(INSTRUCTION_PUSH_TO_AUTH_ZONE_DISCRIMINATOR))]
     PushToAuthZone { proof_id: ManifestProof },
 
+    /// Adds all the given proofs to the auth zone at once, e.g. to satisfy a rule requiring
+    /// all of them.
+    #[sbor(discriminator(INSTRUCTION_PUSH_COMPOSITE_TO_AUTH_ZONE_DISCRIMINATOR))]
+    PushCompositeToAuthZone { proof_ids: Vec<ManifestProof> },
+
     /// Creates a proof from the auth zone, by the given amount
     #[sbor(discriminator(INSTRUCTION_CREATE_PROOF_FROM_AUTH_ZONE_OF_AMOUNT_DISCRIMINATOR))]
     CreateProofFromAuthZoneOfAmount {
@@ -698,6 +721,22 @@ pub enum InstructionV1 {
         package_address: PackageAddress,
         blueprint_name: String,
     },
+
+    /// Asserts that the given global component is an instance of the given blueprint.
+    #[sbor(discriminator(INSTRUCTION_ASSERT_COMPONENT_BLUEPRINT_DISCRIMINATOR))]
+    AssertComponentBlueprint {
+        component_address: ComponentAddress,
+        package_address: PackageAddress,
+        blueprint_name: String,
+    },
+
+    //==============
+    // Costing
+    //==============
+    /// Limits the execution cost units which each subsequent instruction may consume.
+    /// An instruction which goes over the limit fails the transaction.
+    #[sbor(discriminator(INSTRUCTION_SET_INSTRUCTION_COST_LIMIT_DISCRIMINATOR))]
+    SetInstructionCostLimit { cost_units: u32 },
 }
 
 //===============================================================
@@ -723,6 +762,9 @@ pub const INSTRUCTION_RETURN_TO_WORKTOP_DISCRIMINATOR: u8 = 0x03;
 pub const INSTRUCTION_ASSERT_WORKTOP_CONTAINS_DISCRIMINATOR: u8 = 0x04;
 pub const INSTRUCTION_ASSERT_WORKTOP_CONTAINS_NON_FUNGIBLES_DISCRIMINATOR: u8 = 0x05;
 pub const INSTRUCTION_ASSERT_WORKTOP_CONTAINS_ANY_DISCRIMINATOR: u8 = 0x06;
+pub const INSTRUCTION_ASSERT_WORKTOP_RESOURCES_ONLY_DISCRIMINATOR: u8 = 0x07;
+pub const INSTRUCTION_YIELD_WORKTOP_CONTENTS_DISCRIMINATOR: u8 = 0x08;
+pub const INSTRUCTION_BURN_RESOURCE_FROM_WORKTOP_DISCRIMINATOR: u8 = 0x09;
 
 //==============
 // Auth zone
@@ -735,6 +777,7 @@ pub const INSTRUCTION_CREATE_PROOF_FROM_AUTH_ZONE_OF_ALL_DISCRIMINATOR: u8 = 0x1
 pub const INSTRUCTION_DROP_AUTH_ZONE_PROOFS_DISCRIMINATOR: u8 = 0x12;
 pub const INSTRUCTION_DROP_AUTH_ZONE_REGULAR_PROOFS_DISCRIMINATOR: u8 = 0x13;
 pub const INSTRUCTION_DROP_AUTH_ZONE_SIGNATURE_PROOFS_DISCRIMINATOR: u8 = 0x17;
+pub const INSTRUCTION_PUSH_COMPOSITE_TO_AUTH_ZONE_DISCRIMINATOR: u8 = 0x18;
 
 //==============
 // Named bucket
@@ -766,3 +809,9 @@ pub const INSTRUCTION_CALL_DIRECT_VAULT_METHOD_DISCRIMINATOR: u8 = 0x45;
 pub const INSTRUCTION_DROP_NAMED_PROOFS_DISCRIMINATOR: u8 = 0x52;
 pub const INSTRUCTION_DROP_ALL_PROOFS_DISCRIMINATOR: u8 = 0x50;
 pub const INSTRUCTION_ALLOCATE_GLOBAL_ADDRESS_DISCRIMINATOR: u8 = 0x51;
+pub const INSTRUCTION_ASSERT_COMPONENT_BLUEPRINT_DISCRIMINATOR: u8 = 0x53;
+
+//==============
+// Costing
+//==============
+pub const INSTRUCTION_SET_INSTRUCTION_COST_LIMIT_DISCRIMINATOR: u8 = 0x60;
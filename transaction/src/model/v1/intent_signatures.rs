@@ -2,6 +2,9 @@ use super::*;
 use crate::internal_prelude::*;
 
 /// Represents any natively supported signature, including public key.
+///
+/// BLS12-381 signatures can't sign transactions: a new variant would change the `V1` transaction
+/// format, so they are left to a future transaction version.
 #[cfg_attr(
     feature = "serde",
     derive(serde::Serialize, serde::Deserialize),
@@ -15,14 +15,51 @@ pub struct PreviewIntentV1 {
     pub flags: PreviewFlags,
 }
 
+/// A preview behaviour which is not covered by the [`PreviewFlags`].
+///
+/// Extensions are only available through a [`PreviewIntentV2`], so that they can be added
+/// without changing the encoding of existing preview requests.
+#[derive(Debug, Clone, Copy, Sbor, PartialEq, Eq, Hash)]
+pub enum PreviewExtension {
+    /// Skips all auth checks, e.g. to evaluate a getter regardless of its access rules.
+    DisableAuth,
+    /// Includes the decoded values of the changed substates, before and after the transaction,
+    /// in the receipt.
+    IncludeSubstateValueChanges,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, ManifestSbor)]
+pub struct PreviewIntentV2 {
+    pub intent: IntentV1,
+    pub signer_public_keys: Vec<PublicKey>,
+    pub flags: PreviewFlags,
+    pub extensions: IndexSet<PreviewExtension>,
+}
+
+impl From<PreviewIntentV1> for PreviewIntentV2 {
+    fn from(value: PreviewIntentV1) -> Self {
+        Self {
+            intent: value.intent,
+            signer_public_keys: value.signer_public_keys,
+            flags: value.flags,
+            extensions: index_set_new(),
+        }
+    }
+}
+
 pub struct ValidatedPreviewIntent {
     pub intent: PreparedIntentV1,
     pub encoded_instructions: Vec<u8>,
     pub signer_public_keys: Vec<PublicKey>,
     pub flags: PreviewFlags,
+    pub extensions: IndexSet<PreviewExtension>,
 }
 
 impl ValidatedPreviewIntent {
+    pub fn has_extension(&self, extension: PreviewExtension) -> bool {
+        self.extensions.contains(&extension)
+    }
+
     pub fn get_executable<'a>(&'a self) -> Executable<'a> {
         let intent = &self.intent;
         let flags = &self.flags;
@@ -35,7 +72,7 @@ impl ValidatedPreviewIntent {
 
         let header = &intent.header.inner;
         let fee_payment = TransactionCostingParameters {
-            tip_percentage: header.tip_percentage,
+            tip: TipBasisPoints::from_percentage(header.tip_percentage),
             free_credit_in_xrd: if self.flags.use_free_credit {
                 Decimal::try_from(PREVIEW_CREDIT_IN_XRD).unwrap()
             } else {
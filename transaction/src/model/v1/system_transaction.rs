@@ -116,7 +116,7 @@ impl PreparedSystemTransactionV1 {
                     virtual_resources: BTreeSet::new(),
                 },
                 costing_parameters: TransactionCostingParameters {
-                    tip_percentage: 0,
+                    tip: TipBasisPoints::ZERO,
                     free_credit_in_xrd: Decimal::ZERO,
                 },
                 pre_allocated_addresses: self.pre_allocated_addresses.inner.clone(),
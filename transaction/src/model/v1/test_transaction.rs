@@ -70,7 +70,7 @@ impl PreparedTestTransaction {
                     virtual_resources: BTreeSet::new(),
                 },
                 costing_parameters: TransactionCostingParameters {
-                    tip_percentage: DEFAULT_TIP_PERCENTAGE,
+                    tip: TipBasisPoints::from_percentage(DEFAULT_TIP_PERCENTAGE),
                     free_credit_in_xrd: Decimal::ZERO,
                 },
                 pre_allocated_addresses: vec![],
@@ -57,7 +57,7 @@ impl ValidatedNotarizedTransactionV1 {
                     virtual_resources: BTreeSet::new(),
                 },
                 costing_parameters: TransactionCostingParameters {
-                    tip_percentage: intent.header.inner.tip_percentage,
+                    tip: TipBasisPoints::from_percentage(intent.header.inner.tip_percentage),
                     free_credit_in_xrd,
                 },
                 pre_allocated_addresses: vec![],
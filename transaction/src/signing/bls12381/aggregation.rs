@@ -0,0 +1,126 @@
+use super::{Bls12381G1PublicKey, Bls12381G2Signature};
+use crate::internal_prelude::*;
+use blst::min_pk::{AggregatePublicKey, AggregateSignature, PublicKey, Signature};
+
+/// Aggregates the given signatures into a single signature.
+///
+/// Returns `None` if no signatures are given or any of them is not a valid G2 point.
+pub fn aggregate_bls12381_signatures(
+    signatures: &[Bls12381G2Signature],
+) -> Option<Bls12381G2Signature> {
+    if signatures.is_empty() {
+        return None;
+    }
+
+    let signatures = signatures
+        .iter()
+        .map(|signature| Signature::from_bytes(&signature.0).ok())
+        .collect::<Option<Vec<_>>>()?;
+    let signatures = signatures.iter().collect::<Vec<_>>();
+
+    AggregateSignature::aggregate(&signatures, true)
+        .ok()
+        .map(|aggregate| Bls12381G2Signature(aggregate.to_signature().to_bytes()))
+}
+
+/// Aggregates the given public keys into a single public key.
+///
+/// Returns `None` if no public keys are given or any of them is not a valid G1 point.
+///
+/// # Security
+/// The aggregate key is vulnerable to rogue-key attacks: a party who picks its key after seeing
+/// the others can choose one which cancels them out, and sign for the whole group on its own.
+/// The `POP` ciphersuite relies on every key having a verified proof of possession, so keys
+/// should only be aggregated once their proofs have been checked with
+/// [`verify_bls12381_proof_of_possession_v1`](crate::validation::verify_bls12381_proof_of_possession_v1),
+/// e.g. when they were registered.
+pub fn aggregate_bls12381_public_keys(
+    public_keys: &[Bls12381G1PublicKey],
+) -> Option<Bls12381G1PublicKey> {
+    if public_keys.is_empty() {
+        return None;
+    }
+
+    let public_keys = public_keys
+        .iter()
+        .map(|public_key| PublicKey::from_bytes(&public_key.0).ok())
+        .collect::<Option<Vec<_>>>()?;
+    let public_keys = public_keys.iter().collect::<Vec<_>>();
+
+    AggregatePublicKey::aggregate(&public_keys, true)
+        .ok()
+        .map(|aggregate| Bls12381G1PublicKey(aggregate.to_public_key().to_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::*;
+    use radix_engine_interface::crypto::hash;
+
+    #[test]
+    fn aggregated_signature_verifies_against_all_signers() {
+        let message = hash("Test");
+        let keys = (1..=3u64)
+            .map(|n| Bls12381PrivateKey::from_u64(n).unwrap())
+            .collect::<Vec<_>>();
+        let public_keys = keys.iter().map(|k| k.public_key()).collect::<Vec<_>>();
+        let signatures = keys.iter().map(|k| k.sign(&message)).collect::<Vec<_>>();
+
+        let aggregate_signature = aggregate_bls12381_signatures(&signatures).unwrap();
+        let aggregate_public_key = aggregate_bls12381_public_keys(&public_keys).unwrap();
+
+        assert!(verify_bls12381_v1(
+            message.as_ref(),
+            &aggregate_public_key,
+            &aggregate_signature
+        ));
+        assert!(fast_aggregate_verify_bls12381_v1(
+            message.as_ref(),
+            &public_keys,
+            &aggregate_signature
+        ));
+        assert!(!fast_aggregate_verify_bls12381_v1(
+            message.as_ref(),
+            &public_keys[..2],
+            &aggregate_signature
+        ));
+    }
+
+    #[test]
+    fn aggregated_signature_over_distinct_messages_verifies() {
+        let keys = (1..=3u64)
+            .map(|n| Bls12381PrivateKey::from_u64(n).unwrap())
+            .collect::<Vec<_>>();
+        let messages = (1..=3u64)
+            .map(|n| hash(n.to_be_bytes()))
+            .collect::<Vec<_>>();
+        let signatures = keys
+            .iter()
+            .zip(messages.iter())
+            .map(|(k, m)| k.sign(m))
+            .collect::<Vec<_>>();
+        let aggregate_signature = aggregate_bls12381_signatures(&signatures).unwrap();
+
+        let pairs = keys
+            .iter()
+            .zip(messages.iter())
+            .map(|(k, m)| (k.public_key(), m.as_ref()))
+            .collect::<Vec<_>>();
+        assert!(aggregate_verify_bls12381_v1(&pairs, &aggregate_signature));
+    }
+
+    #[test]
+    fn aggregating_nothing_or_invalid_points_fails() {
+        assert!(aggregate_bls12381_signatures(&[]).is_none());
+        assert!(aggregate_bls12381_public_keys(&[]).is_none());
+        assert!(aggregate_bls12381_signatures(&[Bls12381G2Signature(
+            [0u8; Bls12381G2Signature::LENGTH]
+        )])
+        .is_none());
+        assert!(aggregate_bls12381_public_keys(&[Bls12381G1PublicKey(
+            [0u8; Bls12381G1PublicKey::LENGTH]
+        )])
+        .is_none());
+    }
+}
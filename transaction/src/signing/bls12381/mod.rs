@@ -0,0 +1,15 @@
+mod aggregation;
+mod private_key;
+
+pub use aggregation::*;
+pub use private_key::*;
+pub use radix_engine_common::crypto::{
+    Bls12381G1PublicKey, Bls12381G2Signature, ParseBls12381Error,
+};
+
+/// The domain separation tag of the `BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_` ciphersuite,
+/// used by Ethereum consensus among others, and by the engine's `Bls12381V1Verify` crypto utility.
+pub const BLS12381_CIPHERSUITE_V1: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+
+/// The domain separation tag of the proofs of possession of the `V1` ciphersuite.
+pub const BLS12381_PROOF_OF_POSSESSION_V1: &[u8] = b"BLS_POP_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
@@ -0,0 +1,114 @@
+use super::{
+    Bls12381G1PublicKey, Bls12381G2Signature, BLS12381_CIPHERSUITE_V1,
+    BLS12381_PROOF_OF_POSSESSION_V1,
+};
+use crate::internal_prelude::*;
+use blst::min_pk::SecretKey;
+
+pub struct Bls12381PrivateKey(SecretKey);
+
+impl Bls12381PrivateKey {
+    pub const LENGTH: usize = 32;
+
+    pub fn public_key(&self) -> Bls12381G1PublicKey {
+        Bls12381G1PublicKey(self.0.sk_to_pk().to_bytes())
+    }
+
+    pub fn sign(&self, msg_hash: &impl IsHash) -> Bls12381G2Signature {
+        self.sign_v1(msg_hash.as_ref())
+    }
+
+    /// Signs an arbitrary message with the `V1` ciphersuite.
+    pub fn sign_v1(&self, message: &[u8]) -> Bls12381G2Signature {
+        let signature = self
+            .0
+            .sign(message, BLS12381_CIPHERSUITE_V1, &[])
+            .to_bytes();
+        Bls12381G2Signature(signature)
+    }
+
+    /// Proves the possession of this key, by signing its public key with the proof of possession
+    /// domain separation tag.
+    pub fn prove_possession_v1(&self) -> Bls12381G2Signature {
+        let signature = self
+            .0
+            .sign(&self.public_key().0, BLS12381_PROOF_OF_POSSESSION_V1, &[])
+            .to_bytes();
+        Bls12381G2Signature(signature)
+    }
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_bytes().to_vec()
+    }
+
+    pub fn from_bytes(slice: &[u8]) -> Result<Self, ()> {
+        if slice.len() != Bls12381PrivateKey::LENGTH {
+            return Err(());
+        }
+        Ok(Self(SecretKey::from_bytes(slice).map_err(|_| ())?))
+    }
+
+    pub fn from_u64(n: u64) -> Result<Self, ()> {
+        let mut bytes = [0u8; Bls12381PrivateKey::LENGTH];
+        (&mut bytes[Bls12381PrivateKey::LENGTH - 8..Bls12381PrivateKey::LENGTH])
+            .copy_from_slice(&n.to_be_bytes());
+
+        Ok(Self(SecretKey::from_bytes(&bytes).map_err(|_| ())?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::{verify_bls12381_proof_of_possession_v1, verify_bls12381_v1};
+    use radix_engine_interface::crypto::hash;
+
+    #[test]
+    fn sign_and_verify() {
+        let test_message_hash = hash("Test");
+        let sk = Bls12381PrivateKey::from_u64(1).unwrap();
+        let pk = sk.public_key();
+        let sig = sk.sign(&test_message_hash);
+
+        assert_eq!(
+            Bls12381PrivateKey::from_bytes(&sk.to_bytes())
+                .unwrap()
+                .public_key(),
+            pk
+        );
+        assert!(verify_bls12381_v1(test_message_hash.as_ref(), &pk, &sig));
+        assert!(!verify_bls12381_v1(hash("Other").as_ref(), &pk, &sig));
+    }
+
+    #[test]
+    fn proof_of_possession_verifies_only_for_its_own_key() {
+        let sk = Bls12381PrivateKey::from_u64(1).unwrap();
+        let other_sk = Bls12381PrivateKey::from_u64(2).unwrap();
+        let proof = sk.prove_possession_v1();
+
+        assert!(verify_bls12381_proof_of_possession_v1(
+            &sk.public_key(),
+            &proof
+        ));
+        assert!(!verify_bls12381_proof_of_possession_v1(
+            &other_sk.public_key(),
+            &proof
+        ));
+        // A proof of possession is not a signature of the public key, nor the other way round
+        assert!(!verify_bls12381_v1(
+            &sk.public_key().0,
+            &sk.public_key(),
+            &proof
+        ));
+        assert!(!verify_bls12381_proof_of_possession_v1(
+            &sk.public_key(),
+            &sk.sign_v1(&sk.public_key().0)
+        ));
+    }
+
+    #[test]
+    fn zero_key_is_rejected() {
+        assert!(Bls12381PrivateKey::from_u64(0).is_err());
+        assert!(Bls12381PrivateKey::from_bytes(&[1u8; 31]).is_err());
+    }
+}
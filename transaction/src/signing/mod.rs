@@ -1,3 +1,5 @@
+#[cfg(feature = "bls")]
+pub mod bls12381;
 pub mod ed25519;
 pub mod secp256k1;
 mod signer;
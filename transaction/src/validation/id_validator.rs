@@ -29,6 +29,8 @@ pub struct ManifestValidator {
     address_reservation_ids: IndexSet<ManifestAddressReservation>,
     /// Set of named global address ids
     address_ids: IndexSet<u32>,
+    /// The address reservation allocated alongside each named address
+    named_address_reservations: NonIterMap<u32, ManifestAddressReservation>,
 }
 
 impl ManifestValidator {
@@ -150,6 +152,15 @@ impl ManifestValidator {
         address_id
     }
 
+    /// Allocates the address reservation and the named address of an `ALLOCATE_GLOBAL_ADDRESS`.
+    pub fn allocate_global_address(&mut self) -> (ManifestAddressReservation, u32) {
+        let address_reservation_id = self.new_address_reservation();
+        let address_id = self.new_named_address();
+        self.named_address_reservations
+            .insert(address_id, address_reservation_id);
+        (address_reservation_id, address_id)
+    }
+
     pub fn check_named_address(
         &mut self,
         address_id: &u32,
@@ -163,6 +174,27 @@ impl ManifestValidator {
         }
     }
 
+    /// Checks a named address which is about to be invoked - the object at it can only exist once
+    /// its address reservation has been passed to an earlier instruction.
+    pub fn check_invocable_named_address(
+        &mut self,
+        address_id: &u32,
+    ) -> Result<(), ManifestIdValidationError> {
+        self.check_named_address(address_id)?;
+        match self.named_address_reservations.get(address_id) {
+            Some(address_reservation_id)
+                if self
+                    .address_reservation_ids
+                    .contains(address_reservation_id) =>
+            {
+                Err(ManifestIdValidationError::AddressNotYetInstantiated(
+                    address_id.clone(),
+                ))
+            }
+            _ => Ok(()),
+        }
+    }
+
     pub fn process_call_data(
         &mut self,
         args: &ManifestValue,
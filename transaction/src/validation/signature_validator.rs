@@ -73,3 +73,97 @@ pub fn verify_ed25519(
 
     false
 }
+
+#[cfg(feature = "bls")]
+pub fn verify_bls12381_v1(
+    message: &[u8],
+    public_key: &Bls12381G1PublicKey,
+    signature: &Bls12381G2Signature,
+) -> bool {
+    if let Ok(sig) = ::blst::min_pk::Signature::from_bytes(&signature.0) {
+        if let Ok(pk) = ::blst::min_pk::PublicKey::from_bytes(&public_key.0) {
+            return sig.verify(true, message, BLS12381_CIPHERSUITE_V1, &[], &pk, true)
+                == ::blst::BLST_ERROR::BLST_SUCCESS;
+        }
+    }
+
+    false
+}
+
+/// Verifies a proof of possession of the private key of the given public key, as produced by
+/// `Bls12381PrivateKey::prove_possession_v1`.
+#[cfg(feature = "bls")]
+pub fn verify_bls12381_proof_of_possession_v1(
+    public_key: &Bls12381G1PublicKey,
+    proof: &Bls12381G2Signature,
+) -> bool {
+    if let Ok(sig) = ::blst::min_pk::Signature::from_bytes(&proof.0) {
+        if let Ok(pk) = ::blst::min_pk::PublicKey::from_bytes(&public_key.0) {
+            return sig.verify(
+                true,
+                &public_key.0,
+                BLS12381_PROOF_OF_POSSESSION_V1,
+                &[],
+                &pk,
+                true,
+            ) == ::blst::BLST_ERROR::BLST_SUCCESS;
+        }
+    }
+
+    false
+}
+
+/// Verifies an aggregated signature of the same message by all of the given public keys.
+#[cfg(feature = "bls")]
+pub fn fast_aggregate_verify_bls12381_v1(
+    message: &[u8],
+    public_keys: &[Bls12381G1PublicKey],
+    signature: &Bls12381G2Signature,
+) -> bool {
+    if public_keys.is_empty() {
+        return false;
+    }
+
+    if let Ok(sig) = ::blst::min_pk::Signature::from_bytes(&signature.0) {
+        let pks = public_keys
+            .iter()
+            .map(|public_key| ::blst::min_pk::PublicKey::from_bytes(&public_key.0).ok())
+            .collect::<Option<Vec<_>>>();
+        if let Some(pks) = pks {
+            let pks = pks.iter().collect::<Vec<_>>();
+            return sig.fast_aggregate_verify(true, message, BLS12381_CIPHERSUITE_V1, &pks)
+                == ::blst::BLST_ERROR::BLST_SUCCESS;
+        }
+    }
+
+    false
+}
+
+/// Verifies an aggregated signature of a distinct message per public key.
+#[cfg(feature = "bls")]
+pub fn aggregate_verify_bls12381_v1(
+    public_keys_and_messages: &[(Bls12381G1PublicKey, &[u8])],
+    signature: &Bls12381G2Signature,
+) -> bool {
+    if public_keys_and_messages.is_empty() {
+        return false;
+    }
+
+    if let Ok(sig) = ::blst::min_pk::Signature::from_bytes(&signature.0) {
+        let pks = public_keys_and_messages
+            .iter()
+            .map(|(public_key, _)| ::blst::min_pk::PublicKey::from_bytes(&public_key.0).ok())
+            .collect::<Option<Vec<_>>>();
+        if let Some(pks) = pks {
+            let pks = pks.iter().collect::<Vec<_>>();
+            let messages = public_keys_and_messages
+                .iter()
+                .map(|(_, message)| *message)
+                .collect::<Vec<_>>();
+            return sig.aggregate_verify(true, &messages, BLS12381_CIPHERSUITE_V1, &pks, true)
+                == ::blst::BLST_ERROR::BLST_SUCCESS;
+        }
+    }
+
+    false
+}
@@ -45,6 +45,20 @@ pub trait TransactionValidator<Prepared: TransactionPayloadPreparable> {
     ) -> Result<Self::Validated, TransactionValidationError>;
 }
 
+/// A version of the validation rules, from which the default [`ValidationConfig`] of a network is
+/// derived.
+///
+/// New versions are added, rather than the existing ones changed, so that a network can choose
+/// when to move to the rules of a protocol update.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ValidationRuleset {
+    V1,
+}
+
+impl ValidationRuleset {
+    pub const LATEST: Self = Self::V1;
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct ValidationConfig {
     pub network_id: u8,
@@ -52,18 +66,31 @@ pub struct ValidationConfig {
     pub min_tip_percentage: u16,
     pub max_tip_percentage: u16,
     pub max_epoch_range: u64,
+    pub max_intent_signatures: usize,
+    /// The maximum size of the encoded instructions of a manifest
+    pub max_manifest_size: usize,
+    pub allowed_curve_types: AllowedCurveTypes,
     pub message_validation: MessageValidationConfig,
 }
 
 impl ValidationConfig {
     pub fn default(network_id: u8) -> Self {
-        Self {
-            network_id,
-            max_notarized_payload_size: MAX_TRANSACTION_SIZE,
-            min_tip_percentage: MIN_TIP_PERCENTAGE,
-            max_tip_percentage: MAX_TIP_PERCENTAGE,
-            max_epoch_range: MAX_EPOCH_RANGE,
-            message_validation: MessageValidationConfig::default(),
+        Self::for_ruleset(network_id, ValidationRuleset::LATEST)
+    }
+
+    pub fn for_ruleset(network_id: u8, ruleset: ValidationRuleset) -> Self {
+        match ruleset {
+            ValidationRuleset::V1 => Self {
+                network_id,
+                max_notarized_payload_size: MAX_TRANSACTION_SIZE,
+                min_tip_percentage: MIN_TIP_PERCENTAGE,
+                max_tip_percentage: MAX_TIP_PERCENTAGE,
+                max_epoch_range: MAX_EPOCH_RANGE,
+                max_intent_signatures: MAX_NUMBER_OF_INTENT_SIGNATURES,
+                max_manifest_size: MAX_TRANSACTION_SIZE,
+                allowed_curve_types: AllowedCurveTypes::all(),
+                message_validation: MessageValidationConfig::default(),
+            },
         }
     }
 
@@ -72,6 +99,36 @@ impl ValidationConfig {
     }
 }
 
+/// The curves of the keys which may notarize or sign a transaction.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct AllowedCurveTypes {
+    pub ed25519: bool,
+    pub secp256k1: bool,
+}
+
+impl AllowedCurveTypes {
+    pub fn all() -> Self {
+        Self {
+            ed25519: true,
+            secp256k1: true,
+        }
+    }
+
+    pub fn only(curve_type: CurveType) -> Self {
+        Self {
+            ed25519: curve_type == CurveType::Ed25519,
+            secp256k1: curve_type == CurveType::Secp256k1,
+        }
+    }
+
+    pub fn allows(&self, public_key: &PublicKey) -> bool {
+        match public_key {
+            PublicKey::Ed25519(_) => self.ed25519,
+            PublicKey::Secp256k1(_) => self.secp256k1,
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct MessageValidationConfig {
     pub max_plaintext_message_length: usize,
@@ -111,6 +168,7 @@ impl TransactionValidator<PreparedNotarizedTransactionV1> for NotarizedTransacti
 
         let encoded_instructions =
             manifest_encode(&transaction.signed_intent.intent.instructions.inner.0)?;
+        self.validate_manifest_size(&encoded_instructions)?;
 
         let signer_keys = self
             .validate_signatures_v1(&transaction)
@@ -141,18 +199,27 @@ impl NotarizedTransactionValidator {
     pub fn validate_preview_intent_v1(
         &self,
         preview_intent: PreviewIntentV1,
+    ) -> Result<ValidatedPreviewIntent, TransactionValidationError> {
+        self.validate_preview_intent_v2(preview_intent.into())
+    }
+
+    pub fn validate_preview_intent_v2(
+        &self,
+        preview_intent: PreviewIntentV2,
     ) -> Result<ValidatedPreviewIntent, TransactionValidationError> {
         let intent = preview_intent.intent.prepare()?;
 
         self.validate_intent_v1(&intent)?;
 
         let encoded_instructions = manifest_encode(&intent.instructions.inner.0)?;
+        self.validate_manifest_size(&encoded_instructions)?;
 
         Ok(ValidatedPreviewIntent {
             intent,
             encoded_instructions,
             signer_public_keys: preview_intent.signer_public_keys,
             flags: preview_intent.flags,
+            extensions: preview_intent.extensions,
         })
     }
 
@@ -170,6 +237,20 @@ impl NotarizedTransactionValidator {
         return Ok(());
     }
 
+    pub fn validate_manifest_size(
+        &self,
+        encoded_instructions: &[u8],
+    ) -> Result<(), TransactionValidationError> {
+        if encoded_instructions.len() > self.config.max_manifest_size {
+            return Err(TransactionValidationError::ManifestTooLarge {
+                actual: encoded_instructions.len(),
+                permitted: self.config.max_manifest_size,
+            });
+        }
+
+        Ok(())
+    }
+
     pub fn validate_instructions_v1(
         instructions: &[InstructionV1],
     ) -> Result<(), TransactionValidationError> {
@@ -192,6 +273,9 @@ impl NotarizedTransactionValidator {
                         .map_err(TransactionValidationError::IdValidationError)?;
                 }
                 InstructionV1::AssertWorktopContainsAny { .. } => {}
+                InstructionV1::AssertWorktopResourcesOnly { .. } => {}
+                InstructionV1::YieldWorktopContents => {}
+                InstructionV1::BurnResourceFromWorktop { .. } => {}
                 InstructionV1::AssertWorktopContains { .. } => {}
                 InstructionV1::AssertWorktopContainsNonFungibles { .. } => {}
                 InstructionV1::PopFromAuthZone => {
@@ -204,6 +288,13 @@ impl NotarizedTransactionValidator {
                         .drop_proof(&proof_id)
                         .map_err(TransactionValidationError::IdValidationError)?;
                 }
+                InstructionV1::PushCompositeToAuthZone { proof_ids } => {
+                    for proof_id in proof_ids {
+                        id_validator
+                            .drop_proof(&proof_id)
+                            .map_err(TransactionValidationError::IdValidationError)?;
+                    }
+                }
                 InstructionV1::DropAuthZoneProofs => {}
                 InstructionV1::DropAuthZoneRegularProofs => {}
                 InstructionV1::DropAuthZoneSignatureProofs => {}
@@ -257,12 +348,32 @@ impl NotarizedTransactionValidator {
                         .drop_all_named_proofs()
                         .map_err(TransactionValidationError::IdValidationError)?;
                 }
-                InstructionV1::CallFunction { args, .. }
-                | InstructionV1::CallMethod { args, .. }
-                | InstructionV1::CallRoyaltyMethod { args, .. }
-                | InstructionV1::CallMetadataMethod { args, .. }
-                | InstructionV1::CallRoleAssignmentMethod { args, .. }
-                | InstructionV1::CallDirectVaultMethod { args, .. } => {
+                InstructionV1::CallFunction {
+                    package_address,
+                    args,
+                    ..
+                } => {
+                    if let DynamicPackageAddress::Named(address_id) = package_address {
+                        id_validator
+                            .check_invocable_named_address(address_id)
+                            .map_err(TransactionValidationError::IdValidationError)?;
+                    }
+                    Self::validate_call_args(&args, &mut id_validator)
+                        .map_err(TransactionValidationError::CallDataValidationError)?;
+                }
+                InstructionV1::CallMethod { address, args, .. }
+                | InstructionV1::CallRoyaltyMethod { address, args, .. }
+                | InstructionV1::CallMetadataMethod { address, args, .. }
+                | InstructionV1::CallRoleAssignmentMethod { address, args, .. } => {
+                    if let DynamicGlobalAddress::Named(address_id) = address {
+                        id_validator
+                            .check_invocable_named_address(address_id)
+                            .map_err(TransactionValidationError::IdValidationError)?;
+                    }
+                    Self::validate_call_args(&args, &mut id_validator)
+                        .map_err(TransactionValidationError::CallDataValidationError)?;
+                }
+                InstructionV1::CallDirectVaultMethod { args, .. } => {
                     Self::validate_call_args(&args, &mut id_validator)
                         .map_err(TransactionValidationError::CallDataValidationError)?;
                 }
@@ -272,9 +383,10 @@ impl NotarizedTransactionValidator {
                         .map_err(TransactionValidationError::IdValidationError)?;
                 }
                 InstructionV1::AllocateGlobalAddress { .. } => {
-                    let _ = id_validator.new_address_reservation();
-                    id_validator.new_named_address();
+                    let _ = id_validator.allocate_global_address();
                 }
+                InstructionV1::AssertComponentBlueprint { .. } => {}
+                InstructionV1::SetInstructionCostLimit { .. } => {}
             }
         }
 
@@ -303,11 +415,12 @@ impl NotarizedTransactionValidator {
         }
 
         // tip percentage
-        if header.tip_percentage < self.config.min_tip_percentage
-            || header.tip_percentage > self.config.max_tip_percentage
-        {
-            return Err(HeaderValidationError::InvalidTipPercentage);
-        }
+        TipBounds::from_percentages(
+            self.config.min_tip_percentage,
+            self.config.max_tip_percentage,
+        )
+        .validate(TipBasisPoints::from_percentage(header.tip_percentage))
+        .map_err(|_| HeaderValidationError::InvalidTipPercentage)?;
 
         Ok(())
     }
@@ -323,7 +436,7 @@ impl NotarizedTransactionValidator {
             .inner
             .signatures
             .len()
-            > MAX_NUMBER_OF_INTENT_SIGNATURES
+            > self.config.max_intent_signatures
         {
             return Err(SignatureValidationError::TooManySignatures);
         }
@@ -335,6 +448,10 @@ impl NotarizedTransactionValidator {
             let public_key = recover(&intent_hash, &intent_signature.0)
                 .ok_or(SignatureValidationError::InvalidIntentSignature)?;
 
+            if !self.config.allowed_curve_types.allows(&public_key) {
+                return Err(SignatureValidationError::CurveTypeNotAllowed);
+            }
+
             if !verify(&intent_hash, &public_key, &intent_signature.0.signature()) {
                 return Err(SignatureValidationError::InvalidIntentSignature);
             }
@@ -346,6 +463,14 @@ impl NotarizedTransactionValidator {
 
         let header = &transaction.signed_intent.intent.header.inner;
 
+        if !self
+            .config
+            .allowed_curve_types
+            .allows(&header.notary_public_key)
+        {
+            return Err(SignatureValidationError::CurveTypeNotAllowed);
+        }
+
         if header.notary_is_signatory {
             signers.insert(header.notary_public_key);
         }
@@ -504,6 +629,77 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_max_intent_signatures_is_configurable() {
+        let transaction = create_transaction(Epoch::zero(), Epoch::of(100), 5, vec![1, 2, 3], 4);
+        let mut config = ValidationConfig::simulator();
+        config.max_intent_signatures = 2;
+
+        let result =
+            NotarizedTransactionValidator::new(config).validate(transaction.prepare().unwrap());
+
+        assert_eq!(
+            result.expect_err("Should be an error"),
+            TransactionValidationError::SignatureValidationError(
+                SignatureValidationError::TooManySignatures
+            )
+        );
+    }
+
+    #[test]
+    fn test_disallowed_curve_types_are_rejected() {
+        let transaction = create_transaction(Epoch::zero(), Epoch::of(100), 5, vec![1], 2);
+        let validate = |allowed_curve_types| {
+            let mut config = ValidationConfig::simulator();
+            config.allowed_curve_types = allowed_curve_types;
+            NotarizedTransactionValidator::new(config)
+                .validate(transaction.prepare().unwrap())
+                .map(|_| ())
+        };
+
+        assert!(validate(AllowedCurveTypes::only(CurveType::Secp256k1)).is_ok());
+        assert_eq!(
+            validate(AllowedCurveTypes::only(CurveType::Ed25519)),
+            Err(TransactionValidationError::SignatureValidationError(
+                SignatureValidationError::CurveTypeNotAllowed
+            ))
+        );
+    }
+
+    #[test]
+    fn test_max_manifest_size_is_configurable() {
+        let transaction = create_transaction(Epoch::zero(), Epoch::of(100), 5, vec![1], 2);
+        let manifest_size = manifest_encode(&transaction.signed_intent.intent.instructions.0)
+            .unwrap()
+            .len();
+        let validate = |max_manifest_size| {
+            let mut config = ValidationConfig::simulator();
+            config.max_manifest_size = max_manifest_size;
+            NotarizedTransactionValidator::new(config)
+                .validate(transaction.prepare().unwrap())
+                .map(|_| ())
+        };
+
+        assert!(validate(manifest_size).is_ok());
+        assert_eq!(
+            validate(manifest_size - 1),
+            Err(TransactionValidationError::ManifestTooLarge {
+                actual: manifest_size,
+                permitted: manifest_size - 1,
+            })
+        );
+    }
+
+    #[test]
+    fn test_default_config_uses_latest_ruleset() {
+        // Update when adding a ruleset, so that moving the default is a deliberate change
+        assert_eq!(ValidationRuleset::LATEST, ValidationRuleset::V1);
+        assert_eq!(
+            ValidationConfig::default(NetworkDefinition::simulator().id),
+            ValidationConfig::for_ruleset(NetworkDefinition::simulator().id, ValidationRuleset::V1)
+        );
+    }
+
     #[test]
     fn test_valid_preview() {
         // Build the whole transaction but only really care about the intent
@@ -27,6 +27,7 @@ list=(
     "package_sim1pkgxxxxxxxxxdntyxxxxxxxxxxx008560783089xxxxxxxxxnc59k6" # Identity
     "package_sim1pkgxxxxxxxxxaccntxxxxxxxxxx000929625493xxxxxxxxxrn8jm6" # Account
     "package_sim1pkgxxxxxxxxxplxxxxxxxxxxxxx020379220524xxxxxxxxxl5e8k6" # Pools
+    "package_sim1pkgxxxxxxxxxlckerxxxxxxxxxx440356006040xxxxxxxxxpnfcn6" # Locker
     "package_sim1pkgxxxxxxxxxcntrlrxxxxxxxxx000648572295xxxxxxxxxxc5z0l" # Access Controller
 );
 for address in ${list[@]}; 